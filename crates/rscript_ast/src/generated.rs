@@ -44,6 +44,42 @@ impl<'a> Statement<'a> {
         }
     }
 
+    pub fn data_mut(&mut self) -> &mut NodeData {
+        match self {
+            Statement::VariableStatement(n) => &mut n.data,
+            Statement::FunctionDeclaration(n) => &mut n.data,
+            Statement::ClassDeclaration(n) => &mut n.data,
+            Statement::InterfaceDeclaration(n) => &mut n.data,
+            Statement::TypeAliasDeclaration(n) => &mut n.data,
+            Statement::EnumDeclaration(n) => &mut n.data,
+            Statement::ModuleDeclaration(n) => &mut n.data,
+            Statement::ImportDeclaration(n) => &mut n.data,
+            Statement::ImportEqualsDeclaration(n) => &mut n.data,
+            Statement::ExportDeclaration(n) => &mut n.data,
+            Statement::ExportAssignment(n) => &mut n.data,
+            Statement::NamespaceExportDeclaration(n) => &mut n.data,
+            Statement::Block(n) => &mut n.data,
+            Statement::EmptyStatement(d) => d,
+            Statement::ExpressionStatement(n) => &mut n.data,
+            Statement::IfStatement(n) => &mut n.data,
+            Statement::DoStatement(n) => &mut n.data,
+            Statement::WhileStatement(n) => &mut n.data,
+            Statement::ForStatement(n) => &mut n.data,
+            Statement::ForInStatement(n) => &mut n.data,
+            Statement::ForOfStatement(n) => &mut n.data,
+            Statement::ContinueStatement(n) => &mut n.data,
+            Statement::BreakStatement(n) => &mut n.data,
+            Statement::ReturnStatement(n) => &mut n.data,
+            Statement::WithStatement(n) => &mut n.data,
+            Statement::SwitchStatement(n) => &mut n.data,
+            Statement::LabeledStatement(n) => &mut n.data,
+            Statement::ThrowStatement(n) => &mut n.data,
+            Statement::TryStatement(n) => &mut n.data,
+            Statement::DebuggerStatement(d) => d,
+            Statement::MissingDeclaration(d) => d,
+        }
+    }
+
     pub fn kind(&self) -> SyntaxKind {
         self.data().kind
     }
@@ -95,6 +131,9 @@ impl<'a> Expression<'a> {
             Expression::NonNull(n) => &n.data,
             Expression::MetaProperty(n) => &n.data,
             Expression::Satisfies(n) => &n.data,
+            Expression::JsxElement(n) => &n.data,
+            Expression::JsxSelfClosingElement(n) => &n.data,
+            Expression::JsxFragment(n) => &n.data,
             Expression::ThisKeyword(d) => d,
             Expression::SuperKeyword(d) => d,
             Expression::NullKeyword(d) => d,
@@ -140,3 +179,126 @@ impl<'a> TypeNode<'a> {
         }
     }
 }
+
+/// Helper to get the NodeData from any class element.
+impl<'a> ClassElement<'a> {
+    pub fn data(&self) -> &NodeData {
+        match self {
+            ClassElement::PropertyDeclaration(n) => &n.data,
+            ClassElement::MethodDeclaration(n) => &n.data,
+            ClassElement::Constructor(n) => &n.data,
+            ClassElement::GetAccessor(n) => &n.data,
+            ClassElement::SetAccessor(n) => &n.data,
+            ClassElement::IndexSignature(n) => &n.data,
+            ClassElement::SemicolonClassElement(d) => d,
+            ClassElement::ClassStaticBlockDeclaration(n) => &n.data,
+        }
+    }
+
+    pub fn data_mut(&mut self) -> &mut NodeData {
+        match self {
+            ClassElement::PropertyDeclaration(n) => &mut n.data,
+            ClassElement::MethodDeclaration(n) => &mut n.data,
+            ClassElement::Constructor(n) => &mut n.data,
+            ClassElement::GetAccessor(n) => &mut n.data,
+            ClassElement::SetAccessor(n) => &mut n.data,
+            ClassElement::IndexSignature(n) => &mut n.data,
+            ClassElement::SemicolonClassElement(d) => d,
+            ClassElement::ClassStaticBlockDeclaration(n) => &mut n.data,
+        }
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        self.data().kind
+    }
+}
+
+/// Helper to get the NodeData from any binding name.
+impl<'a> BindingName<'a> {
+    pub fn data(&self) -> &NodeData {
+        match self {
+            BindingName::Identifier(n) => &n.data,
+            BindingName::ObjectBindingPattern(n) => &n.data,
+            BindingName::ArrayBindingPattern(n) => &n.data,
+        }
+    }
+}
+
+/// Helper to get the NodeData from any property name.
+impl<'a> PropertyName<'a> {
+    pub fn data(&self) -> &NodeData {
+        match self {
+            PropertyName::Identifier(n) => &n.data,
+            PropertyName::StringLiteral(n) => &n.data,
+            PropertyName::NumericLiteral(n) => &n.data,
+            PropertyName::ComputedPropertyName(n) => &n.data,
+            PropertyName::PrivateIdentifier(n) => &n.data,
+        }
+    }
+}
+
+/// Helpers for JSX tag names.
+impl<'a> JsxTagName<'a> {
+    pub fn data(&self) -> &NodeData {
+        match self {
+            JsxTagName::Expression(expr) => expr.data(),
+            JsxTagName::NamespacedName(n) => &n.data,
+        }
+    }
+
+    /// The tag name as written, like `div`, `Foo.Bar` or `svg:rect`.
+    pub fn text(&self) -> String {
+        fn expression_text(expr: &Expression<'_>) -> String {
+            match expr {
+                Expression::Identifier(id) => id.text_name.clone(),
+                Expression::ThisKeyword(_) => "this".to_string(),
+                Expression::PropertyAccess(n) => match &n.name {
+                    MemberName::Identifier(name) | MemberName::PrivateIdentifier(name) => {
+                        format!("{}.{}", expression_text(n.expression), name.text_name)
+                    }
+                },
+                _ => String::new(),
+            }
+        }
+        match self {
+            JsxTagName::Expression(expr) => expression_text(expr),
+            JsxTagName::NamespacedName(n) => n.text(),
+        }
+    }
+
+    /// Whether the tag names an intrinsic element (`div`, `my-element`,
+    /// `svg:rect`) rather than a value in scope.
+    pub fn is_intrinsic(&self) -> bool {
+        match self {
+            JsxTagName::Expression(Expression::Identifier(id)) => {
+                id.text_name.starts_with(|c: char| c.is_ascii_lowercase()) || id.text_name.contains('-')
+            }
+            JsxTagName::Expression(_) => false,
+            JsxTagName::NamespacedName(_) => true,
+        }
+    }
+}
+
+impl JsxNamespacedName {
+    /// The name as written, like `xlink:href`.
+    pub fn text(&self) -> String {
+        format!("{}:{}", self.namespace.text_name, self.name.text_name)
+    }
+}
+
+impl JsxAttributeName {
+    pub fn data(&self) -> &NodeData {
+        match self {
+            JsxAttributeName::Identifier(n) => &n.data,
+            JsxAttributeName::NamespacedName(n) => &n.data,
+        }
+    }
+
+    /// The attribute name as written, like `data-id` or `xlink:href`.
+    pub fn text(&self) -> String {
+        match self {
+            JsxAttributeName::Identifier(n) => n.text_name.clone(),
+            JsxAttributeName::NamespacedName(n) => n.text(),
+        }
+    }
+}
@@ -65,6 +65,37 @@ pub struct SourceFile<'a> {
     pub is_declaration_file: bool,
     /// Whether this file has no default lib directive.
     pub has_no_default_lib: bool,
+    /// The `@ts-ignore` and `@ts-expect-error` comments of the file.
+    pub comment_directives: Vec<CommentDirective>,
+    /// The last `@ts-check` or `@ts-nocheck` pragma in the file's leading
+    /// comments.
+    pub check_js_directive: Option<CheckJsDirective>,
+}
+
+impl<'a> SourceFile<'a> {
+    /// Whether the file is an ES module, i.e. it has a top-level import or export.
+    pub fn is_external_module(&self) -> bool {
+        self.statements.iter().any(|statement| {
+            matches!(
+                statement,
+                Statement::ImportDeclaration(_)
+                    | Statement::ImportEqualsDeclaration(_)
+                    | Statement::ExportDeclaration(_)
+                    | Statement::ExportAssignment(_)
+            ) || statement.data().modifier_flags.contains(ModifierFlags::EXPORT)
+        })
+    }
+
+    /// Whether the directive prologue of the file contains `"use strict"`.
+    pub fn has_use_strict_directive(&self) -> bool {
+        self.statements
+            .iter()
+            .map_while(|statement| match statement {
+                Statement::ExpressionStatement(ExpressionStatement { expression: Expression::StringLiteral(literal), .. }) => Some(literal),
+                _ => None,
+            })
+            .any(|literal| literal.text_name == "use strict")
+    }
 }
 
 /// Language variant (standard vs JSX).
@@ -74,6 +105,31 @@ pub enum LanguageVariant {
     JSX,
 }
 
+/// A comment that suppresses errors on the line that follows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentDirective {
+    /// The range of the comment, or of its last line for a multi-line comment.
+    pub range: TextRange,
+    pub kind: CommentDirectiveType,
+}
+
+/// The kind of a comment directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentDirectiveType {
+    /// `@ts-expect-error`: suppresses an error, and is itself an error when
+    /// there is none to suppress.
+    ExpectError,
+    /// `@ts-ignore`: suppresses an error if there is one.
+    Ignore,
+}
+
+/// A `@ts-check` (enabled) or `@ts-nocheck` (disabled) pragma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckJsDirective {
+    pub enabled: bool,
+    pub range: TextRange,
+}
+
 /// The kind of script.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScriptKind {
@@ -300,6 +356,8 @@ pub struct NamedTupleMemberNode<'a> {
 pub struct TemplateLiteralTypeNode<'a> {
     pub data: NodeData,
     pub head: Token, // TemplateHead
+    /// The text of the head as plain text (for type checking without interner).
+    pub head_text: String,
     pub template_spans: NodeList<'a, TemplateLiteralTypeSpan<'a>>,
 }
 
@@ -308,6 +366,8 @@ pub struct TemplateLiteralTypeSpan<'a> {
     pub data: NodeData,
     pub type_node: &'a TypeNode<'a>,
     pub literal: Token,
+    /// The text of the middle or tail literal as plain text.
+    pub literal_text: String,
 }
 
 #[derive(Debug)]
@@ -403,8 +463,8 @@ pub struct ComputedPropertyName<'a> {
 #[derive(Debug)]
 pub enum PropertyName<'a> {
     Identifier(Identifier),
-    StringLiteral(Token),
-    NumericLiteral(Token),
+    StringLiteral(StringLiteral),
+    NumericLiteral(NumericLiteral),
     ComputedPropertyName(&'a ComputedPropertyName<'a>),
     PrivateIdentifier(Identifier),
 }
@@ -534,6 +594,10 @@ pub enum Expression<'a> {
     NonNull(NonNullExpression<'a>),
     MetaProperty(MetaPropertyExpression),
     Satisfies(SatisfiesExpression<'a>),
+    // JSX
+    JsxElement(JsxElement<'a>),
+    JsxSelfClosingElement(JsxSelfClosingElement<'a>),
+    JsxFragment(JsxFragment<'a>),
     // Keyword expressions
     ThisKeyword(NodeData),
     SuperKeyword(NodeData),
@@ -824,6 +888,131 @@ pub struct SatisfiesExpression<'a> {
     pub type_node: &'a TypeNode<'a>,
 }
 
+// -- JSX --
+
+/// `<Tag attr="value">children</Tag>`
+#[derive(Debug)]
+pub struct JsxElement<'a> {
+    pub data: NodeData,
+    pub opening_element: &'a JsxOpeningElement<'a>,
+    pub children: NodeList<'a, JsxChild<'a>>,
+    pub closing_element: &'a JsxClosingElement<'a>,
+}
+
+#[derive(Debug)]
+pub struct JsxOpeningElement<'a> {
+    pub data: NodeData,
+    pub tag_name: JsxTagName<'a>,
+    pub type_arguments: Option<NodeList<'a, TypeNode<'a>>>,
+    pub attributes: JsxAttributes<'a>,
+}
+
+#[derive(Debug)]
+pub struct JsxClosingElement<'a> {
+    pub data: NodeData,
+    pub tag_name: JsxTagName<'a>,
+}
+
+/// `<Tag attr="value" />`
+#[derive(Debug)]
+pub struct JsxSelfClosingElement<'a> {
+    pub data: NodeData,
+    pub tag_name: JsxTagName<'a>,
+    pub type_arguments: Option<NodeList<'a, TypeNode<'a>>>,
+    pub attributes: JsxAttributes<'a>,
+}
+
+/// `<>children</>`
+#[derive(Debug)]
+pub struct JsxFragment<'a> {
+    pub data: NodeData,
+    pub opening_fragment: NodeData,
+    pub children: NodeList<'a, JsxChild<'a>>,
+    pub closing_fragment: NodeData,
+}
+
+/// The tag name of an element: an identifier, `this` or a property access
+/// like `Foo.Bar`, or a namespaced name like `svg:rect`.
+#[derive(Debug)]
+pub enum JsxTagName<'a> {
+    Expression(&'a Expression<'a>),
+    NamespacedName(&'a JsxNamespacedName),
+}
+
+/// `namespace:name`
+#[derive(Debug)]
+pub struct JsxNamespacedName {
+    pub data: NodeData,
+    pub namespace: Identifier,
+    pub name: Identifier,
+}
+
+#[derive(Debug)]
+pub struct JsxAttributes<'a> {
+    pub data: NodeData,
+    pub properties: NodeList<'a, JsxAttributeLike<'a>>,
+}
+
+#[derive(Debug)]
+pub enum JsxAttributeLike<'a> {
+    Attribute(&'a JsxAttribute<'a>),
+    SpreadAttribute(JsxSpreadAttribute<'a>),
+}
+
+/// `name`, `name="value"` or `name={expression}`
+#[derive(Debug)]
+pub struct JsxAttribute<'a> {
+    pub data: NodeData,
+    pub name: JsxAttributeName,
+    pub initializer: Option<JsxAttributeValue<'a>>,
+}
+
+#[derive(Debug)]
+pub enum JsxAttributeName {
+    Identifier(Identifier),
+    NamespacedName(JsxNamespacedName),
+}
+
+#[derive(Debug)]
+pub enum JsxAttributeValue<'a> {
+    StringLiteral(StringLiteral),
+    Expression(JsxExpression<'a>),
+    /// An element or fragment
+    Element(&'a Expression<'a>),
+}
+
+/// `{...expression}`
+#[derive(Debug)]
+pub struct JsxSpreadAttribute<'a> {
+    pub data: NodeData,
+    pub expression: &'a Expression<'a>,
+}
+
+/// `{expression}`, `{...expression}` as a child, or `{}`
+#[derive(Debug)]
+pub struct JsxExpression<'a> {
+    pub data: NodeData,
+    pub dot_dot_dot_token: Option<Token>,
+    pub expression: OptionalNode<'a, Expression<'a>>,
+}
+
+#[derive(Debug)]
+pub enum JsxChild<'a> {
+    Text(JsxText),
+    Expression(JsxExpression<'a>),
+    /// An element or fragment
+    Element(&'a Expression<'a>),
+}
+
+#[derive(Debug)]
+pub struct JsxText {
+    pub data: NodeData,
+    pub text: String,
+    /// Whether the text is only whitespace including a line break, which is
+    /// not a child.
+    pub contains_only_trivia_white_spaces: bool,
+}
+
 // ============================================================================
 // Statements
 // ============================================================================
@@ -1134,6 +1323,8 @@ pub struct SetAccessorDeclaration<'a> {
     pub name: PropertyName<'a>,
     pub type_parameters: Option<NodeList<'a, TypeParameterDeclaration<'a>>>,
     pub parameters: NodeList<'a, ParameterDeclaration<'a>>,
+    /// Not allowed on a set accessor; kept so the checker can report it.
+    pub return_type: OptionalNode<'a, TypeNode<'a>>,
     pub body: Option<Block<'a>>,
 }
 
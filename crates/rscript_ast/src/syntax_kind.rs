@@ -482,6 +482,14 @@ impl SyntaxKind {
         v >= SyntaxKind::BreakKeyword as u16 && v <= SyntaxKind::OfKeyword as u16
     }
 
+    /// Whether this kind is a word reserved only in strict mode code
+    /// (`implements` through `yield`).
+    #[inline]
+    pub fn is_future_reserved_word(self) -> bool {
+        let v = self as u16;
+        v >= SyntaxKind::FIRST_FUTURE_RESERVED_WORD as u16 && v <= SyntaxKind::LAST_FUTURE_RESERVED_WORD as u16
+    }
+
     /// Whether this kind represents a punctuation token.
     #[inline]
     pub fn is_punctuation(self) -> bool {
@@ -371,6 +371,9 @@ pub trait AstVisitor<'a> {
                 self.visit_expression(n.expression);
                 self.visit_type_node(n.type_node);
             }
+            Expression::JsxElement(n) => self.visit_jsx_element(n),
+            Expression::JsxSelfClosingElement(n) => self.visit_jsx_self_closing_element(n),
+            Expression::JsxFragment(n) => self.visit_jsx_fragment(n),
             Expression::ThisKeyword(_) => {}
             Expression::SuperKeyword(_) => {}
             Expression::NullKeyword(_) => {}
@@ -425,6 +428,9 @@ pub trait AstVisitor<'a> {
                 for param in n.parameters.iter() {
                     self.visit_parameter(param);
                 }
+                if let Some(ret) = n.return_type {
+                    self.visit_type_node(ret);
+                }
                 if let Some(ref body) = n.body {
                     self.visit_block(body);
                 }
@@ -537,6 +543,72 @@ pub trait AstVisitor<'a> {
         }
     }
 
+    fn visit_jsx_element(&mut self, node: &JsxElement<'a>) {
+        self.visit_jsx_tag_name(&node.opening_element.tag_name);
+        if let Some(type_args) = node.opening_element.type_arguments {
+            for ta in type_args.iter() {
+                self.visit_type_node(ta);
+            }
+        }
+        self.visit_jsx_attributes(&node.opening_element.attributes);
+        for child in node.children.iter() {
+            self.visit_jsx_child(child);
+        }
+        self.visit_jsx_tag_name(&node.closing_element.tag_name);
+    }
+
+    fn visit_jsx_self_closing_element(&mut self, node: &JsxSelfClosingElement<'a>) {
+        self.visit_jsx_tag_name(&node.tag_name);
+        if let Some(type_args) = node.type_arguments {
+            for ta in type_args.iter() {
+                self.visit_type_node(ta);
+            }
+        }
+        self.visit_jsx_attributes(&node.attributes);
+    }
+
+    fn visit_jsx_fragment(&mut self, node: &JsxFragment<'a>) {
+        for child in node.children.iter() {
+            self.visit_jsx_child(child);
+        }
+    }
+
+    fn visit_jsx_tag_name(&mut self, tag_name: &JsxTagName<'a>) {
+        match tag_name {
+            JsxTagName::Expression(expr) => self.visit_expression(expr),
+            JsxTagName::NamespacedName(_) => {}
+        }
+    }
+
+    fn visit_jsx_attributes(&mut self, node: &JsxAttributes<'a>) {
+        for property in node.properties.iter() {
+            match property {
+                JsxAttributeLike::Attribute(attr) => match &attr.initializer {
+                    Some(JsxAttributeValue::Expression(n)) => {
+                        if let Some(expr) = n.expression {
+                            self.visit_expression(expr);
+                        }
+                    }
+                    Some(JsxAttributeValue::Element(expr)) => self.visit_expression(expr),
+                    Some(JsxAttributeValue::StringLiteral(_)) | None => {}
+                },
+                JsxAttributeLike::SpreadAttribute(n) => self.visit_expression(n.expression),
+            }
+        }
+    }
+
+    fn visit_jsx_child(&mut self, child: &JsxChild<'a>) {
+        match child {
+            JsxChild::Text(_) => {}
+            JsxChild::Expression(n) => {
+                if let Some(expr) = n.expression {
+                    self.visit_expression(expr);
+                }
+            }
+            JsxChild::Element(expr) => self.visit_expression(expr),
+        }
+    }
+
     // -- Type Nodes --
 
     fn visit_type_node(&mut self, ty: &TypeNode<'a>) {
@@ -759,6 +831,9 @@ pub trait AstVisitor<'a> {
                 for param in n.parameters.iter() {
                     self.visit_parameter(param);
                 }
+                if let Some(ret) = n.return_type {
+                    self.visit_type_node(ret);
+                }
                 if let Some(ref body) = n.body {
                     self.visit_block(body);
                 }
@@ -9,14 +9,20 @@
 //! - Class and enum member binding
 //! - Parameter binding
 //! - Import/export binding
-//! - Declaration merging (interfaces, namespaces)
+//! - Declaration merging and duplicate-identifier detection
+//! - Reference tracking for unused-declaration reporting
+//! - Reachability of statements, function ends and switch clauses
+//! - Unused labels
 
-use crate::scope::Scope;
-use crate::symbol::{Symbol, SymbolTable};
+use crate::scope::{PendingReference, Scope};
+use crate::symbol::{get_excluded_flags, Symbol, SymbolTable};
 use rscript_ast::node::*;
+use rscript_ast::syntax_kind::SyntaxKind;
 use rscript_ast::types::*;
 use rscript_core::intern::InternedString;
-use rscript_diagnostics::DiagnosticCollection;
+use rscript_core::text::{TextRange, TextSpan};
+use rscript_diagnostics::{Diagnostic, DiagnosticCollection};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 /// Flow node kinds for control flow analysis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -44,6 +50,83 @@ pub struct FlowNode {
     pub node: Option<NodeId>,
 }
 
+/// The kind of a declaration tracked for unused-declaration reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalDeclarationKind {
+    Variable,
+    Parameter,
+    Function,
+    /// Classes, interfaces, type aliases and enums.
+    Type,
+    Import,
+}
+
+/// A declaration that is not visible outside its file or function, and so is
+/// unused when its symbol has no references.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalDeclaration {
+    pub symbol: SymbolId,
+    pub kind: LocalDeclarationKind,
+    /// The range of the declared name.
+    pub range: TextRange,
+    /// The range of the whole declaration. Import specifiers are grouped
+    /// by their import declaration.
+    pub declaration_range: TextRange,
+}
+
+/// Reachability facts about a function body.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FunctionFlowInfo {
+    /// Whether control can run off the end of the body.
+    pub end_reachable: bool,
+    /// Whether the body contains a `return` with an expression.
+    pub has_return_value: bool,
+    /// Whether the body contains a bare `return;`.
+    pub has_empty_return: bool,
+    /// The flow node at the end of the body. The checker consults it to
+    /// account for calls that never return.
+    pub end_flow: u32,
+}
+
+/// The flow reaching a statement that runs code, for unreachable code detection.
+#[derive(Debug, Clone, Copy)]
+pub struct StatementFlow {
+    /// The flow node reaching the statement.
+    pub flow: u32,
+    /// The flow reaching the previous executable statement of the same list,
+    /// or the enclosing statement for the first one. A dead region is
+    /// reported once, at the statement whose preceding flow is reachable.
+    pub preceding: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlowTargetKind {
+    Loop,
+    Switch,
+    Label,
+}
+
+/// A statement that `break` or `continue` can jump out of.
+#[derive(Debug)]
+struct FlowTarget {
+    kind: FlowTargetKind,
+    label: Option<String>,
+    breaks: Vec<u32>,
+    continues: Vec<u32>,
+    /// Whether a labeled `break` or `continue` names this target.
+    referenced: bool,
+}
+
+/// Flow state saved while binding a nested function body.
+#[derive(Debug)]
+struct FunctionContext {
+    range: TextRange,
+    info: FunctionFlowInfo,
+    saved_flow: u32,
+    saved_targets: Vec<FlowTarget>,
+    saved_preceding_flow: Option<u32>,
+}
+
 /// The binder creates symbols and links declarations.
 pub struct Binder {
     /// All symbols created during binding.
@@ -69,6 +152,51 @@ pub struct Binder {
     in_strict_mode: bool,
     /// Nesting depth for scope tracking.
     scope_depth: u32,
+    /// Whether the file being bound is a module; top-level declarations of
+    /// scripts are globals and never count as local.
+    is_external_module: bool,
+    /// Declarations that may be reported as unused.
+    local_declarations: Vec<LocalDeclaration>,
+    /// Symbols already in `local_declarations`.
+    recorded_locals: FxHashSet<SymbolId>,
+    /// Reachability of every bound function body, keyed by the function's range.
+    function_flows: FxHashMap<TextRange, FunctionFlowInfo>,
+    /// The functions enclosing the current position, innermost last.
+    function_stack: Vec<FunctionContext>,
+    /// The break/continue targets enclosing the current position.
+    flow_targets: Vec<FlowTarget>,
+    /// Case and default clauses whose statements fall through to the next clause.
+    fallthrough_clauses: FxHashSet<TextRange>,
+    /// The shared unreachable flow node, created on first use.
+    unreachable_flow: Option<u32>,
+    /// The flow reaching each executable statement, keyed by its range.
+    statement_flows: FxHashMap<TextRange, StatementFlow>,
+    /// The flow reaching the last executable statement bound in the current
+    /// statement list, or the statement enclosing the list.
+    preceding_flow: Option<u32>,
+    /// Call flow nodes of expression statements, mapped to the range of the
+    /// call. Such a call ends the flow when it returns `never`.
+    call_flows: FxHashMap<u32, TextRange>,
+    /// The ranges of labels no `break` or `continue` refers to.
+    unused_labels: Vec<TextRange>,
+    /// Whether the current position is inside an ambient namespace, ambient
+    /// module or global augmentation, whose declarations are never local.
+    in_ambient_context: bool,
+    /// Whether the names being bound are read as types, in positions that
+    /// JavaScript emit erases: type annotations, `implements` clauses and
+    /// interface heritage.
+    in_type_reference: bool,
+    /// The name of the file being bound, for the location of diagnostics.
+    file_name: String,
+    /// The names of the conflicting declarations already reported.
+    reported_conflicts: FxHashSet<TextRange>,
+    /// The symbol each declaring or referencing identifier names, keyed by
+    /// the identifier's range.
+    symbol_locations: FxHashMap<TextRange, SymbolId>,
+    /// The symbols `import x = N.y` aliases refer to, when declared in this file.
+    alias_targets: FxHashMap<SymbolId, SymbolId>,
+    /// The symbols declared by parameters, including destructured ones.
+    parameter_symbols: FxHashSet<SymbolId>,
 }
 
 impl Binder {
@@ -88,6 +216,25 @@ impl Binder {
             diagnostics: DiagnosticCollection::new(),
             in_strict_mode: false,
             scope_depth: 0,
+            is_external_module: false,
+            local_declarations: Vec::new(),
+            recorded_locals: FxHashSet::default(),
+            function_flows: FxHashMap::default(),
+            function_stack: Vec::new(),
+            flow_targets: Vec::new(),
+            fallthrough_clauses: FxHashSet::default(),
+            unreachable_flow: None,
+            statement_flows: FxHashMap::default(),
+            preceding_flow: None,
+            call_flows: FxHashMap::default(),
+            unused_labels: Vec::new(),
+            in_ambient_context: false,
+            in_type_reference: false,
+            file_name: String::new(),
+            reported_conflicts: FxHashSet::default(),
+            symbol_locations: FxHashMap::default(),
+            alias_targets: FxHashMap::default(),
+            parameter_symbols: FxHashSet::default(),
         };
         // Create the start flow node
         binder.create_flow_node(FlowNodeKind::Start, None);
@@ -114,6 +261,70 @@ impl Binder {
         self.symbols.get(id.index())
     }
 
+    /// Get the declarations that may be reported as unused, in binding order.
+    pub fn local_declarations(&self) -> &[LocalDeclaration] {
+        &self.local_declarations
+    }
+
+    /// Get the reachability facts for the body of the function-like node at `range`.
+    pub fn function_flow(&self, range: TextRange) -> Option<FunctionFlowInfo> {
+        self.function_flows.get(&range).copied()
+    }
+
+    /// Whether the non-empty case or default clause at `range` falls through
+    /// to the next clause.
+    pub fn is_fallthrough_clause(&self, range: TextRange) -> bool {
+        self.fallthrough_clauses.contains(&range)
+    }
+
+    /// Get the flow reaching the executable statement at `range`.
+    pub fn statement_flow(&self, range: TextRange) -> Option<StatementFlow> {
+        self.statement_flows.get(&range).copied()
+    }
+
+    /// Get the range of the call expression statement a call flow node stands for.
+    pub fn flow_call(&self, flow: u32) -> Option<TextRange> {
+        self.call_flows.get(&flow).copied()
+    }
+
+    /// Get the ranges of labels that no `break` or `continue` refers to.
+    pub fn unused_labels(&self) -> &[TextRange] {
+        &self.unused_labels
+    }
+
+    /// Get the symbol declared or referenced by the identifier at `range`.
+    pub fn get_symbol_at_location(&self, range: TextRange) -> Option<SymbolId> {
+        self.symbol_locations.get(&range).copied()
+    }
+
+    /// Get every identifier that declares or references a symbol, with the
+    /// symbol it names, in no particular order.
+    pub fn symbol_locations(&self) -> impl Iterator<Item = (TextRange, SymbolId)> + '_ {
+        self.symbol_locations.iter().map(|(&range, &id)| (range, id))
+    }
+
+    /// Get the symbol an `import x = N.y` alias refers to, if it is declared
+    /// in the bound file.
+    pub fn get_alias_target(&self, alias: SymbolId) -> Option<SymbolId> {
+        self.alias_targets.get(&alias).copied()
+    }
+
+    /// Whether a symbol is declared by a parameter.
+    pub fn is_parameter(&self, symbol: SymbolId) -> bool {
+        self.parameter_symbols.contains(&symbol)
+    }
+
+    /// Get the symbols declared at the top level of the bound file, in
+    /// declaration order. For script files these are the global declarations.
+    pub fn top_level_symbols(&self) -> Vec<&Symbol> {
+        let Some(scope) = self.current_scope.as_ref() else { return Vec::new() };
+        let mut symbols: Vec<&Symbol> = scope.names.values()
+            .filter_map(|&id| self.symbols.get(id.index()))
+            .collect();
+        symbols.sort_by_key(|symbol| symbol.id.index());
+        symbols
+    }
+
     // ========================================================================
     // Source file binding
     // ========================================================================
@@ -129,6 +340,9 @@ impl Binder {
             }
         }
 
+        self.file_name = source_file.file_name.clone();
+        self.is_external_module = source_file.is_external_module();
+
         // Hoist function declarations and var declarations
         self.hoist_declarations(source_file.statements);
 
@@ -136,6 +350,16 @@ impl Binder {
         for statement in source_file.statements.iter() {
             self.bind_statement(statement);
         }
+
+        // Forward references to top-level declarations
+        if let Some(scope) = self.current_scope.as_mut() {
+            let pending = std::mem::take(&mut scope.pending_references);
+            for reference in pending {
+                if let Some(id) = self.resolve_reference(&reference.name) {
+                    self.add_reference(id, &reference);
+                }
+            }
+        }
     }
 
     // ========================================================================
@@ -149,17 +373,20 @@ impl Binder {
                 Statement::FunctionDeclaration(n) => {
                     // Function declarations are hoisted entirely
                     if let Some(ref name) = n.name {
-                        self.declare_symbol_with_text(name.text, name.text_name.clone(), SymbolFlags::FUNCTION, n.data.id);
+                        let id = self.declare_identifier(name, SymbolFlags::FUNCTION, n.data.id);
+                        if self.is_local_declaration(n.data.modifier_flags) {
+                            self.record_local(id, LocalDeclarationKind::Function, name.data.range);
+                        }
                     }
                 }
-                Statement::VariableStatement(n) => {
-                    // Only `var` is hoisted (not let/const)
+                // Only `var` is hoisted (not let/const)
+                Statement::VariableStatement(n)
                     if !n.declaration_list.data.flags.contains(NodeFlags::LET)
-                        && !n.declaration_list.data.flags.contains(NodeFlags::CONST)
-                    {
-                        for decl in n.declaration_list.declarations.iter() {
-                            self.hoist_binding_name(&decl.name, decl.data.id);
-                        }
+                        && !n.declaration_list.data.flags.contains(NodeFlags::CONST) =>
+                {
+                    let local = self.is_local_declaration(n.data.modifier_flags);
+                    for decl in n.declaration_list.declarations.iter() {
+                        self.hoist_binding_name(&decl.name, decl.data.id, local);
                     }
                 }
                 _ => {}
@@ -167,31 +394,70 @@ impl Binder {
         }
     }
 
-    fn hoist_binding_name(&mut self, name: &BindingName<'_>, node_id: NodeId) {
+    fn hoist_binding_name(&mut self, name: &BindingName<'_>, node_id: NodeId, local: bool) {
         match name {
             BindingName::Identifier(id) => {
-                self.declare_symbol_with_text(id.text, id.text_name.clone(), SymbolFlags::FUNCTION_SCOPED_VARIABLE, node_id);
+                let symbol = self.declare_identifier(id, SymbolFlags::FUNCTION_SCOPED_VARIABLE, node_id);
+                if local {
+                    self.record_local(symbol, LocalDeclarationKind::Variable, id.data.range);
+                }
             }
             BindingName::ObjectBindingPattern(pattern) => {
                 for elem in pattern.elements.iter() {
-                    self.hoist_binding_name(&elem.name, node_id);
+                    self.hoist_binding_name(&elem.name, node_id, local);
                 }
             }
             BindingName::ArrayBindingPattern(pattern) => {
                 for elem in pattern.elements.iter() {
                     if let ArrayBindingElement::BindingElement(e) = elem {
-                        self.hoist_binding_name(&e.name, node_id);
+                        self.hoist_binding_name(&e.name, node_id, local);
                     }
                 }
             }
         }
     }
 
+    /// Whether a declaration with these modifiers is only visible locally:
+    /// not exported, not ambient, and not a global of a script file.
+    fn is_local_declaration(&self, modifier_flags: ModifierFlags) -> bool {
+        !self.in_ambient_context
+            && !modifier_flags.intersects(ModifierFlags::EXPORT | ModifierFlags::AMBIENT)
+            && (self.scope_depth > 0 || self.is_external_module)
+    }
+
+    fn record_local(&mut self, symbol: SymbolId, kind: LocalDeclarationKind, range: TextRange) {
+        self.record_local_in_declaration(symbol, kind, range, range);
+    }
+
+    fn record_local_in_declaration(&mut self, symbol: SymbolId, kind: LocalDeclarationKind, range: TextRange, declaration_range: TextRange) {
+        if self.recorded_locals.insert(symbol) {
+            self.local_declarations.push(LocalDeclaration { symbol, kind, range, declaration_range });
+        }
+    }
+
     // ========================================================================
     // Statement binding
     // ========================================================================
 
     fn bind_statement(&mut self, stmt: &Statement<'_>) {
+        let saved_preceding_flow = self.record_statement_flow(stmt);
+        self.bind_statement_worker(stmt);
+        self.preceding_flow = saved_preceding_flow;
+    }
+
+    /// Record the flow reaching an executable statement. Returns the flow
+    /// that statements following it in the same list are preceded by.
+    fn record_statement_flow(&mut self, stmt: &Statement<'_>) -> Option<u32> {
+        if !is_executable_statement(stmt) {
+            return self.preceding_flow;
+        }
+        let flow = self.current_flow;
+        self.statement_flows.insert(stmt.data().range, StatementFlow { flow, preceding: self.preceding_flow });
+        self.preceding_flow = Some(flow);
+        Some(flow)
+    }
+
+    fn bind_statement_worker(&mut self, stmt: &Statement<'_>) {
         match stmt {
             Statement::VariableStatement(n) => self.bind_variable_statement(n),
             Statement::FunctionDeclaration(n) => self.bind_function_declaration(n),
@@ -202,6 +468,7 @@ impl Binder {
             Statement::ModuleDeclaration(n) => self.bind_module_declaration(n),
             Statement::ImportDeclaration(n) => self.bind_import_declaration(n),
             Statement::ExportDeclaration(n) => self.bind_export_declaration(n),
+            Statement::ImportEqualsDeclaration(n) => self.bind_import_equals_declaration(n),
             Statement::ExportAssignment(n) => self.bind_export_assignment(n),
             Statement::Block(n) => {
                 self.push_block_scope();
@@ -219,18 +486,65 @@ impl Binder {
             Statement::SwitchStatement(n) => self.bind_switch_statement(n),
             Statement::TryStatement(n) => self.bind_try_statement(n),
             Statement::LabeledStatement(n) => {
+                self.flow_targets.push(FlowTarget {
+                    kind: FlowTargetKind::Label,
+                    label: Some(n.label.text_name.clone()),
+                    breaks: Vec::new(),
+                    continues: Vec::new(),
+                    referenced: false,
+                });
                 self.bind_statement(n.statement);
+                if let Some(target) = self.flow_targets.pop() {
+                    if !target.referenced {
+                        self.unused_labels.push(n.label.data.range);
+                    }
+                    let mut exits = target.breaks;
+                    exits.push(self.current_flow);
+                    self.current_flow = self.create_branch_label(&exits);
+                }
             }
             Statement::ReturnStatement(n) => {
                 if let Some(expr) = n.expression {
                     self.bind_expression(expr);
                 }
+                if let Some(function) = self.function_stack.last_mut() {
+                    if n.expression.is_some() {
+                        function.info.has_return_value = true;
+                    } else {
+                        function.info.has_empty_return = true;
+                    }
+                }
+                self.current_flow = self.unreachable_flow();
             }
             Statement::ThrowStatement(n) => {
                 self.bind_expression(n.expression);
+                self.current_flow = self.unreachable_flow();
+            }
+            Statement::BreakStatement(n) => {
+                let label = n.label.as_ref().map(|l| l.text_name.as_str());
+                let flow = self.current_flow;
+                if let Some(target) = self.find_break_target(label) {
+                    target.breaks.push(flow);
+                    target.referenced = true;
+                }
+                self.current_flow = self.unreachable_flow();
+            }
+            Statement::ContinueStatement(n) => {
+                let label = n.label.as_ref().map(|l| l.text_name.as_str());
+                let flow = self.current_flow;
+                if let Some(target) = self.find_continue_target(label) {
+                    target.continues.push(flow);
+                }
+                self.current_flow = self.unreachable_flow();
             }
             Statement::ExpressionStatement(n) => {
                 self.bind_expression(n.expression);
+                if let Expression::Call(call) = n.expression {
+                    let flow = self.current_flow;
+                    if self.flow_nodes.get(flow as usize).is_some_and(|f| f.kind == FlowNodeKind::Call) {
+                        self.call_flows.insert(flow, call.data.range);
+                    }
+                }
             }
             Statement::WithStatement(n) => {
                 self.bind_expression(n.expression);
@@ -244,6 +558,8 @@ impl Binder {
         let is_block_scoped = node.declaration_list.data.flags.contains(NodeFlags::LET)
             || node.declaration_list.data.flags.contains(NodeFlags::CONST);
 
+        let local = self.is_local_declaration(node.data.modifier_flags).then_some(LocalDeclarationKind::Variable);
+
         for decl in node.declaration_list.declarations.iter() {
             let flags = if is_block_scoped {
                 SymbolFlags::BLOCK_SCOPED_VARIABLE
@@ -252,8 +568,11 @@ impl Binder {
             };
 
             // Always bind the variable name (both var and let/const)
-            self.bind_binding_name(&decl.name, flags, decl.data.id);
+            self.bind_binding_name(&decl.name, flags, decl.data.id, local);
 
+            if let Some(type_node) = decl.type_annotation {
+                self.bind_type_node(type_node);
+            }
             if let Some(init) = decl.initializer {
                 self.bind_expression(init);
                 // Create flow node for assignment
@@ -262,20 +581,25 @@ impl Binder {
         }
     }
 
-    fn bind_binding_name(&mut self, name: &BindingName<'_>, flags: SymbolFlags, node_id: NodeId) {
+    /// Declare the names in a binding, recording each as a local declaration
+    /// of kind `local` when given.
+    fn bind_binding_name(&mut self, name: &BindingName<'_>, flags: SymbolFlags, node_id: NodeId, local: Option<LocalDeclarationKind>) {
         match name {
             BindingName::Identifier(id) => {
-                self.declare_symbol_with_text(id.text, id.text_name.clone(), flags, node_id);
+                let symbol = self.declare_identifier(id, flags, node_id);
+                if let Some(kind) = local {
+                    self.record_local(symbol, kind, id.data.range);
+                }
             }
             BindingName::ObjectBindingPattern(pattern) => {
                 for elem in pattern.elements.iter() {
-                    self.bind_binding_name(&elem.name, flags, node_id);
+                    self.bind_binding_name(&elem.name, flags, node_id, local);
                 }
             }
             BindingName::ArrayBindingPattern(pattern) => {
                 for elem in pattern.elements.iter() {
                     if let ArrayBindingElement::BindingElement(e) = elem {
-                        self.bind_binding_name(&e.name, flags, node_id);
+                        self.bind_binding_name(&e.name, flags, node_id, local);
                     }
                 }
             }
@@ -290,34 +614,32 @@ impl Binder {
         }
 
         // Bind parameters in a new scope
-        self.push_function_scope(node.data.id);
-        for param in node.parameters.iter() {
-            self.bind_parameter(param);
+        self.push_function_scope(&node.data);
+        self.bind_type_parameters(node.type_parameters);
+        self.bind_parameters(node.parameters, node.body.is_some());
+        if let Some(return_type) = node.return_type {
+            self.bind_type_node(return_type);
         }
         if let Some(ref body) = node.body {
-            for s in body.statements.iter() {
-                self.bind_statement(s);
-            }
+            self.bind_function_body(body.statements);
         }
-        self.pop_scope();
+        self.pop_function_scope();
     }
 
     fn bind_class_declaration(&mut self, node: &ClassDeclaration<'_>) {
         if let Some(ref name) = node.name {
-            self.declare_symbol_with_text(name.text, name.text_name.clone(), SymbolFlags::CLASS, node.data.id);
+            let id = self.declare_identifier(name, SymbolFlags::CLASS, node.data.id);
+            if self.is_local_declaration(node.data.modifier_flags) {
+                self.record_local(id, LocalDeclarationKind::Type, name.data.range);
+            }
         }
 
+        self.bind_type_parameters(node.type_parameters);
+
         // Create a scope for class members
         self.push_block_scope();
 
-        // Bind heritage clauses
-        if let Some(heritage) = node.heritage_clauses {
-            for clause in heritage.iter() {
-                for ty in clause.types.iter() {
-                    self.bind_expression(ty.expression);
-                }
-            }
-        }
+        self.bind_heritage_clauses(node.heritage_clauses);
 
         // Bind class members
         for member in node.members.iter() {
@@ -348,6 +670,9 @@ impl Binder {
                 // Create symbol for the property name with visibility
                 let vis = Self::visibility_flags(n.data.modifier_flags);
                 self.declare_property_name_symbol(&n.name, SymbolFlags::PROPERTY | vis, n.data.id);
+                if let Some(type_node) = n.type_annotation {
+                    self.bind_type_node(type_node);
+                }
                 if let Some(init) = n.initializer {
                     self.bind_expression(init);
                 }
@@ -356,61 +681,54 @@ impl Binder {
                 // Create symbol for the method name with visibility
                 let vis = Self::visibility_flags(n.data.modifier_flags);
                 self.declare_property_name_symbol(&n.name, SymbolFlags::METHOD | vis, n.data.id);
-                self.push_function_scope(n.data.id);
-                for param in n.parameters.iter() {
-                    self.bind_parameter(param);
+                self.push_function_scope(&n.data);
+                self.bind_parameters(n.parameters, n.body.is_some());
+                if let Some(return_type) = n.return_type {
+                    self.bind_type_node(return_type);
                 }
                 if let Some(ref body) = n.body {
-                    for s in body.statements.iter() {
-                        self.bind_statement(s);
-                    }
+                    self.bind_function_body(body.statements);
                 }
-                self.pop_scope();
+                self.pop_function_scope();
             }
             ClassElement::Constructor(n) => {
-                self.push_function_scope(n.data.id);
+                self.push_function_scope(&n.data);
                 for param in n.parameters.iter() {
-                    self.bind_parameter(param);
+                    self.bind_parameter(param, n.body.is_some());
                     // Constructor parameter properties
                     if param.data.modifier_flags.intersects(
                         ModifierFlags::PUBLIC | ModifierFlags::PRIVATE
                         | ModifierFlags::PROTECTED | ModifierFlags::READONLY
                     ) {
                         if let BindingName::Identifier(ref id) = param.name {
-                            self.declare_symbol_with_text(id.text, id.text_name.clone(), SymbolFlags::PROPERTY, param.data.id);
+                            self.declare_identifier(id, SymbolFlags::PROPERTY, param.data.id);
                         }
                     }
                 }
                 if let Some(ref body) = n.body {
-                    for s in body.statements.iter() {
-                        self.bind_statement(s);
-                    }
+                    self.bind_function_body(body.statements);
                 }
-                self.pop_scope();
+                self.pop_function_scope();
             }
             ClassElement::GetAccessor(n) => {
-                self.push_function_scope(n.data.id);
-                for param in n.parameters.iter() {
-                    self.bind_parameter(param);
+                self.push_function_scope(&n.data);
+                self.bind_parameters(n.parameters, n.body.is_some());
+                if let Some(return_type) = n.return_type {
+                    self.bind_type_node(return_type);
                 }
                 if let Some(ref body) = n.body {
-                    for s in body.statements.iter() {
-                        self.bind_statement(s);
-                    }
+                    self.bind_function_body(body.statements);
                 }
-                self.pop_scope();
+                self.pop_function_scope();
             }
             ClassElement::SetAccessor(n) => {
-                self.push_function_scope(n.data.id);
-                for param in n.parameters.iter() {
-                    self.bind_parameter(param);
-                }
+                // A setter must declare its parameter, so it is never reported as unused.
+                self.push_function_scope(&n.data);
+                self.bind_parameters(n.parameters, false);
                 if let Some(ref body) = n.body {
-                    for s in body.statements.iter() {
-                        self.bind_statement(s);
-                    }
+                    self.bind_function_body(body.statements);
                 }
-                self.pop_scope();
+                self.pop_function_scope();
             }
             ClassElement::ClassStaticBlockDeclaration(n) => {
                 self.push_block_scope();
@@ -419,47 +737,62 @@ impl Binder {
                 }
                 self.pop_scope();
             }
-            ClassElement::IndexSignature(_) | ClassElement::SemicolonClassElement(_) => {}
+            ClassElement::IndexSignature(n) => {
+                self.bind_signature_types(n.parameters, n.type_annotation);
+            }
+            ClassElement::SemicolonClassElement(_) => {}
         }
     }
 
     fn bind_interface_declaration(&mut self, node: &InterfaceDeclaration<'_>) {
-        // Declaration merging: check if symbol already exists
-        if let Some(scope) = &self.current_scope {
-            if let Some(existing_id) = scope.locals.get(&node.name.text) {
-                // Merge: add this declaration to existing symbol
-                if let Some(symbol) = self.symbols.get_mut(existing_id.index()) {
-                    if symbol.flags.contains(SymbolFlags::INTERFACE) {
-                        symbol.declarations.push(node.data.id);
-                        return;
-                    }
-                }
-            }
+        let id = self.declare_identifier(&node.name, SymbolFlags::INTERFACE, node.data.id);
+        if self.is_local_declaration(node.data.modifier_flags) {
+            self.record_local(id, LocalDeclarationKind::Type, node.name.data.range);
+        }
+        self.bind_interface_members(node);
+    }
+
+    fn bind_interface_members(&mut self, node: &InterfaceDeclaration<'_>) {
+        self.bind_type_parameters(node.type_parameters);
+        self.with_type_reference(|binder| binder.bind_heritage_clauses(node.heritage_clauses));
+        for member in node.members.iter() {
+            self.bind_type_element(member);
         }
-        self.declare_symbol_with_text(node.name.text, node.name.text_name.clone(), SymbolFlags::INTERFACE, node.data.id);
     }
 
     fn bind_type_alias_declaration(&mut self, node: &TypeAliasDeclaration<'_>) {
-        self.declare_symbol_with_text(node.name.text, node.name.text_name.clone(), SymbolFlags::TYPE_ALIAS, node.data.id);
+        let id = self.declare_identifier(&node.name, SymbolFlags::TYPE_ALIAS, node.data.id);
+        if self.is_local_declaration(node.data.modifier_flags) {
+            self.record_local(id, LocalDeclarationKind::Type, node.name.data.range);
+        }
+        self.bind_type_parameters(node.type_parameters);
+        self.bind_type_node(node.type_node);
     }
 
     fn bind_enum_declaration(&mut self, node: &EnumDeclaration<'_>) {
-        let enum_symbol = self.declare_symbol_with_text(node.name.text, node.name.text_name.clone(), SymbolFlags::REGULAR_ENUM, node.data.id);
+        let flags = if node.data.modifier_flags.contains(ModifierFlags::CONST) {
+            SymbolFlags::CONST_ENUM
+        } else {
+            SymbolFlags::REGULAR_ENUM
+        };
+        let enum_symbol = self.declare_identifier(&node.name, flags, node.data.id);
+        if self.is_local_declaration(node.data.modifier_flags) {
+            self.record_local(enum_symbol, LocalDeclarationKind::Type, node.name.data.range);
+        }
 
-        // Bind enum members — handle all property name variants
+        // Bind enum members — handle all property name variants. Members live
+        // in their own scope so they never clash with the enclosing names.
+        self.push_block_scope();
         for member in node.members.iter() {
             let member_name: Option<(InternedString, String)> = match &member.name {
                 PropertyName::Identifier(ref id) => {
                     Some((id.text, id.text_name.clone()))
                 }
-                PropertyName::StringLiteral(ref tok) => {
-                    // Use position-based name since Token doesn't carry text
-                    let name = format!("__string_enum_{}_{}", tok.data.range.pos, tok.data.range.end);
-                    Some((InternedString::dummy(), name))
+                PropertyName::StringLiteral(ref lit) => {
+                    Some((InternedString::dummy(), lit.text_name.clone()))
                 }
-                PropertyName::NumericLiteral(ref tok) => {
-                    let name = format!("__numeric_enum_{}_{}", tok.data.range.pos, tok.data.range.end);
-                    Some((InternedString::dummy(), name))
+                PropertyName::NumericLiteral(ref lit) => {
+                    Some((InternedString::dummy(), lit.text_name.clone()))
                 }
                 PropertyName::ComputedPropertyName(_) => {
                     // Computed enum member names cannot be statically bound
@@ -471,7 +804,7 @@ impl Binder {
             };
 
             if let Some((interned, text_name)) = member_name {
-                let member_symbol = self.declare_symbol_with_text(interned, text_name, SymbolFlags::ENUM_MEMBER, member.data.id);
+                let member_symbol = self.declare_symbol_with_text(interned, text_name, None, SymbolFlags::ENUM_MEMBER, member.data.id);
                 // Set parent
                 if let Some(sym) = self.symbols.get_mut(member_symbol.index()) {
                     sym.parent = Some(enum_symbol);
@@ -482,19 +815,36 @@ impl Binder {
                 self.bind_expression(init);
             }
         }
+        self.pop_scope();
     }
 
     fn bind_module_declaration(&mut self, node: &ModuleDeclaration<'_>) {
-        let sym_id = if let ModuleName::Identifier(ref name) = node.name {
-            Some(self.declare_symbol_with_text(name.text, name.text_name.clone(), SymbolFlags::VALUE_MODULE, node.data.id))
-        } else {
-            None
+        // A global augmentation declares no symbol of its own, and an ambient
+        // module is named by its quoted specifier.
+        let sym_id = match node.name {
+            _ if node.data.flags.contains(NodeFlags::GLOBAL_AUGMENTATION) => None,
+            ModuleName::Identifier(ref name) => {
+                let flags = if is_instantiated_module(node) {
+                    SymbolFlags::VALUE_MODULE
+                } else {
+                    SymbolFlags::NAMESPACE_MODULE
+                };
+                Some(self.declare_identifier(name, flags, node.data.id))
+            }
+            ModuleName::StringLiteral(ref name) => {
+                Some(self.declare_symbol_with_text(name.text, format!("\"{}\"", name.text_name), Some(name.data.range), SymbolFlags::VALUE_MODULE, node.data.id))
+            }
         };
+        let saved_ambient_context = self.in_ambient_context;
+        self.in_ambient_context |= node.data.modifier_flags.contains(ModifierFlags::AMBIENT)
+            || node.data.flags.contains(NodeFlags::GLOBAL_AUGMENTATION)
+            || matches!(node.name, ModuleName::StringLiteral(_));
 
         if let Some(ref body) = node.body {
             match body {
                 ModuleBody::ModuleBlock(block) => {
                     self.push_block_scope();
+                    self.hoist_declarations(block.statements);
                     for s in block.statements.iter() {
                         self.bind_statement(s);
                         // Track exported declarations in the namespace symbol's exports table
@@ -505,10 +855,13 @@ impl Binder {
                     self.pop_scope();
                 }
                 ModuleBody::ModuleDeclaration(inner) => {
+                    self.push_block_scope();
                     self.bind_module_declaration(inner);
+                    self.pop_scope();
                 }
             }
         }
+        self.in_ambient_context = saved_ambient_context;
     }
 
     /// If a statement is an export declaration, record its symbols in the
@@ -523,32 +876,17 @@ impl Binder {
                         // Create an alias symbol for the export
                         let export_sym = self.declare_symbol(interned, SymbolFlags::EXPORT_VALUE, ed.data.id);
                         if let Some(ns) = self.symbols.get_mut(ns_symbol.index()) {
-                            if ns.exports.is_none() {
-                                ns.exports = Some(SymbolTable::new());
-                            }
-                            if let Some(ref mut exports) = ns.exports {
-                                exports.set(interned, export_sym);
-                            }
-                            if !name_text.is_empty() {
-                                // Also set by name_text for lookup
-                            }
+                            ns.exports.get_or_insert_with(SymbolTable::new).set_with_name(interned, name_text, export_sym);
                         }
                     }
                 }
             }
             // Track exported function/class/variable declarations
-            Statement::FunctionDeclaration(n) => {
-                if n.data.modifier_flags.contains(ModifierFlags::EXPORT) {
-                    if let Some(ref name) = n.name {
-                        let resolved = self.resolve_name(&name.text_name);
-                        if let Some(sym_id) = resolved {
-                            if let Some(ns) = self.symbols.get_mut(ns_symbol.index()) {
-                                if ns.exports.is_none() { ns.exports = Some(SymbolTable::new()); }
-                                if let Some(ref mut exports) = ns.exports {
-                                    exports.set(name.text, sym_id);
-                                }
-                            }
-                        }
+            _ if stmt.data().modifier_flags.contains(ModifierFlags::EXPORT) => {
+                for name in declared_identifiers(stmt) {
+                    let Some(sym_id) = self.resolve_name(&name.text_name) else { continue };
+                    if let Some(ns) = self.symbols.get_mut(ns_symbol.index()) {
+                        ns.exports.get_or_insert_with(SymbolTable::new).set_with_name(name.text, name.text_name.clone(), sym_id);
                     }
                 }
             }
@@ -560,18 +898,21 @@ impl Binder {
         if let Some(ref clause) = node.import_clause {
             // Default import
             if let Some(ref name) = clause.name {
-                self.declare_symbol_with_text(name.text, name.text_name.clone(), SymbolFlags::ALIAS, node.data.id);
+                let id = self.declare_identifier(name, SymbolFlags::ALIAS, node.data.id);
+                self.record_local_in_declaration(id, LocalDeclarationKind::Import, name.data.range, node.data.range);
             }
 
             // Named imports
             if let Some(ref bindings) = clause.named_bindings {
                 match bindings {
                     NamedImportBindings::NamespaceImport(ns) => {
-                        self.declare_symbol_with_text(ns.name.text, ns.name.text_name.clone(), SymbolFlags::ALIAS, node.data.id);
+                        let id = self.declare_identifier(&ns.name, SymbolFlags::ALIAS, node.data.id);
+                        self.record_local_in_declaration(id, LocalDeclarationKind::Import, ns.name.data.range, node.data.range);
                     }
                     NamedImportBindings::NamedImports(named) => {
                         for spec in named.elements.iter() {
-                            self.declare_symbol_with_text(spec.name.text, spec.name.text_name.clone(), SymbolFlags::ALIAS, spec.data.id);
+                            let id = self.declare_identifier(&spec.name, SymbolFlags::ALIAS, spec.data.id);
+                            self.record_local_in_declaration(id, LocalDeclarationKind::Import, spec.name.data.range, node.data.range);
                         }
                     }
                 }
@@ -579,30 +920,109 @@ impl Binder {
         }
     }
 
+    fn bind_import_equals_declaration(&mut self, node: &ImportEqualsDeclaration<'_>) {
+        let id = self.declare_identifier(&node.name, SymbolFlags::ALIAS, node.data.id);
+        if let ModuleReference::EntityName(ref entity) = node.module_reference {
+            self.bind_entity_name(entity);
+            if let Some(target) = self.resolve_entity_name(entity) {
+                self.alias_targets.insert(id, target);
+            }
+        }
+    }
+
+    /// Resolve a possibly qualified name through the exports of the
+    /// namespaces it names.
+    fn resolve_entity_name(&self, name: &EntityName<'_>) -> Option<SymbolId> {
+        match name {
+            EntityName::Identifier(id) => self.resolve_reference(&id.text_name),
+            EntityName::QualifiedName(q) => {
+                let container = self.resolve_entity_name(&q.left)?;
+                self.get_symbol(container)?.exports.as_ref()?.get_by_name(&q.right.text_name)
+            }
+        }
+    }
+
     fn bind_export_declaration(&mut self, node: &ExportDeclaration<'_>) {
         if let Some(ref clause) = node.export_clause {
             match clause {
                 NamedExportBindings::NamespaceExport(ns) => {
-                    self.declare_symbol_with_text(ns.name.text, ns.name.text_name.clone(), SymbolFlags::ALIAS, node.data.id);
+                    self.declare_identifier(&ns.name, SymbolFlags::ALIAS, node.data.id);
                 }
-                NamedExportBindings::NamedExports(_named) => {
-                    // Export specifiers don't create new symbols in the scope
+                NamedExportBindings::NamedExports(named) => {
+                    // Export specifiers don't create new symbols in the scope,
+                    // but a local export reads the exported declaration.
+                    if node.module_specifier.is_none() {
+                        for spec in named.elements.iter() {
+                            let local = spec.property_name.as_ref().unwrap_or(&spec.name);
+                            if node.is_type_only || spec.is_type_only {
+                                self.with_type_reference(|binder| binder.mark_reference(local));
+                            } else {
+                                self.mark_reference(local);
+                            }
+                        }
+                    }
                 }
             }
         }
     }
 
-    fn bind_export_assignment(&mut self, _node: &ExportAssignment<'_>) {
+    fn bind_export_assignment(&mut self, node: &ExportAssignment<'_>) {
         // Export assignments don't create named symbols
+        self.bind_expression(node.expression);
+    }
+
+    fn bind_parameters(&mut self, params: &[ParameterDeclaration<'_>], has_body: bool) {
+        for param in params.iter() {
+            self.bind_parameter(param, has_body);
+        }
     }
 
-    fn bind_parameter(&mut self, param: &ParameterDeclaration<'_>) {
-        self.bind_binding_name(&param.name, SymbolFlags::FUNCTION_SCOPED_VARIABLE, param.data.id);
+    /// Bind a parameter. Parameters of bodyless signatures, `this` parameters
+    /// and parameter properties are never reported as unused.
+    fn bind_parameter(&mut self, param: &ParameterDeclaration<'_>, has_body: bool) {
+        let is_this = matches!(&param.name, BindingName::Identifier(id) if id.text_name == "this");
+        let is_property = param.data.modifier_flags.intersects(
+            ModifierFlags::PUBLIC | ModifierFlags::PRIVATE | ModifierFlags::PROTECTED | ModifierFlags::READONLY,
+        );
+        let local = (has_body && !is_this && !is_property).then_some(LocalDeclarationKind::Parameter);
+        self.bind_binding_name(&param.name, SymbolFlags::FUNCTION_SCOPED_VARIABLE, param.data.id, local);
+        let mut names = Vec::new();
+        collect_binding_identifiers(&param.name, &mut names);
+        for name in names {
+            if let Some(&symbol) = self.symbol_locations.get(&name.data.range) {
+                self.parameter_symbols.insert(symbol);
+            }
+        }
+        if let Some(type_node) = param.type_annotation {
+            self.bind_type_node(type_node);
+        }
         if let Some(init) = param.initializer {
             self.bind_expression(init);
         }
     }
 
+    /// Bind the statements of a function body, hoisting its declarations first.
+    fn bind_function_body(&mut self, statements: &[Statement<'_>]) {
+        self.hoist_declarations(statements);
+        for s in statements.iter() {
+            self.bind_statement(s);
+        }
+    }
+
+    fn bind_heritage_clauses(&mut self, heritage_clauses: Option<&[HeritageClause<'_>]>) {
+        if let Some(heritage) = heritage_clauses {
+            for clause in heritage.iter() {
+                let saved = self.in_type_reference;
+                self.in_type_reference |= clause.token == SyntaxKind::ImplementsKeyword;
+                for ty in clause.types.iter() {
+                    self.bind_expression(ty.expression);
+                    self.bind_type_arguments(ty.type_arguments);
+                }
+                self.in_type_reference = saved;
+            }
+        }
+    }
+
     // ========================================================================
     // Control flow statement binding
     // ========================================================================
@@ -617,53 +1037,42 @@ impl Binder {
         self.bind_statement(node.then_statement);
         let post_then_flow = self.current_flow;
 
+        self.current_flow = pre_if_flow;
+        let false_flow = self.create_flow_node(FlowNodeKind::FalseCondition, None);
+        self.current_flow = false_flow;
         if let Some(else_stmt) = node.else_statement {
-            self.current_flow = pre_if_flow;
-            let false_flow = self.create_flow_node(FlowNodeKind::FalseCondition, None);
-            self.current_flow = false_flow;
             self.bind_statement(else_stmt);
-            let post_else_flow = self.current_flow;
-
-            // Merge
-            let merge = self.create_flow_node(FlowNodeKind::BranchLabel, None);
-            if let Some(node) = self.flow_nodes.get_mut(merge as usize) {
-                node.antecedents.push(post_then_flow);
-                node.antecedents.push(post_else_flow);
-            }
-            self.current_flow = merge;
-        } else {
-            let merge = self.create_flow_node(FlowNodeKind::BranchLabel, None);
-            if let Some(node) = self.flow_nodes.get_mut(merge as usize) {
-                node.antecedents.push(post_then_flow);
-                node.antecedents.push(pre_if_flow);
-            }
-            self.current_flow = merge;
         }
+        let post_else_flow = self.current_flow;
+
+        // Merge
+        self.current_flow = self.create_branch_label(&[post_then_flow, post_else_flow]);
     }
 
     fn bind_while_statement(&mut self, node: &WhileStatement<'_>) {
-        let loop_label = self.create_flow_node(FlowNodeKind::LoopLabel, None);
+        let loop_label = self.create_loop_label();
         self.current_flow = loop_label;
         self.bind_expression(node.expression);
+        let post_condition_flow = self.current_flow;
         let true_flow = self.create_flow_node(FlowNodeKind::TrueCondition, None);
         self.current_flow = true_flow;
-        self.bind_statement(node.statement);
+        self.bind_loop_body(node.statement);
         // Loop back
-        if let Some(node) = self.flow_nodes.get_mut(loop_label as usize) {
-            node.antecedents.push(self.current_flow);
-        }
-        let false_flow = self.create_flow_node(FlowNodeKind::FalseCondition, None);
-        self.current_flow = false_flow;
+        self.add_antecedent(loop_label, self.current_flow);
+        let exit_condition = (!is_true_literal(node.expression)).then_some(post_condition_flow);
+        self.finish_loop(exit_condition);
     }
 
     fn bind_do_statement(&mut self, node: &DoStatement<'_>) {
-        let loop_label = self.create_flow_node(FlowNodeKind::LoopLabel, None);
+        let loop_label = self.create_loop_label();
         self.current_flow = loop_label;
-        self.bind_statement(node.statement);
+        self.bind_loop_body(node.statement);
         self.bind_expression(node.expression);
-        if let Some(n) = self.flow_nodes.get_mut(loop_label as usize) {
-            n.antecedents.push(self.current_flow);
-        }
+        let post_condition_flow = self.current_flow;
+        let true_flow = self.create_flow_node(FlowNodeKind::TrueCondition, None);
+        self.add_antecedent(loop_label, true_flow);
+        let exit_condition = (!is_true_literal(node.expression)).then_some(post_condition_flow);
+        self.finish_loop(exit_condition);
     }
 
     fn bind_for_statement(&mut self, node: &ForStatement<'_>) {
@@ -675,7 +1084,7 @@ impl Binder {
                         || list.data.flags.contains(NodeFlags::CONST);
                     for decl in list.declarations.iter() {
                         if is_block {
-                            self.bind_binding_name(&decl.name, SymbolFlags::BLOCK_SCOPED_VARIABLE, decl.data.id);
+                            self.bind_binding_name(&decl.name, SymbolFlags::BLOCK_SCOPED_VARIABLE, decl.data.id, Some(LocalDeclarationKind::Variable));
                         }
                         if let Some(init_expr) = decl.initializer {
                             self.bind_expression(init_expr);
@@ -685,95 +1094,173 @@ impl Binder {
                 ForInitializer::Expression(expr) => self.bind_expression(expr),
             }
         }
-        let loop_label = self.create_flow_node(FlowNodeKind::LoopLabel, None);
+        let loop_label = self.create_loop_label();
         self.current_flow = loop_label;
         if let Some(cond) = node.condition {
             self.bind_expression(cond);
         }
-        self.bind_statement(node.statement);
+        let post_condition_flow = self.current_flow;
+        let true_flow = self.create_flow_node(FlowNodeKind::TrueCondition, None);
+        self.current_flow = true_flow;
+        self.bind_loop_body(node.statement);
         if let Some(incr) = node.incrementor {
             self.bind_expression(incr);
         }
-        if let Some(n) = self.flow_nodes.get_mut(loop_label as usize) {
-            n.antecedents.push(self.current_flow);
-        }
+        self.add_antecedent(loop_label, self.current_flow);
+        // `for (;;)` and `for (; true;)` only exit through `break`
+        let exit_condition = node.condition
+            .filter(|cond| !is_true_literal(cond))
+            .map(|_| post_condition_flow);
+        self.finish_loop(exit_condition);
         self.pop_scope();
     }
 
     fn bind_for_in_statement(&mut self, node: &ForInStatement<'_>) {
         self.push_block_scope();
-        match &node.initializer {
-            ForInitializer::VariableDeclarationList(list) => {
-                for decl in list.declarations.iter() {
-                    self.bind_binding_name(&decl.name, SymbolFlags::BLOCK_SCOPED_VARIABLE, decl.data.id);
-                }
-            }
-            ForInitializer::Expression(expr) => self.bind_expression(expr),
-        }
+        self.bind_for_in_or_of_initializer(&node.initializer);
         self.bind_expression(node.expression);
-        self.bind_statement(node.statement);
+        let loop_label = self.create_loop_label();
+        self.current_flow = loop_label;
+        self.bind_loop_body(node.statement);
+        self.add_antecedent(loop_label, self.current_flow);
+        self.finish_loop(Some(loop_label));
         self.pop_scope();
     }
 
     fn bind_for_of_statement(&mut self, node: &ForOfStatement<'_>) {
         self.push_block_scope();
-        match &node.initializer {
+        self.bind_for_in_or_of_initializer(&node.initializer);
+        self.bind_expression(node.expression);
+        let loop_label = self.create_loop_label();
+        self.current_flow = loop_label;
+        self.bind_loop_body(node.statement);
+        self.add_antecedent(loop_label, self.current_flow);
+        self.finish_loop(Some(loop_label));
+        self.pop_scope();
+    }
+
+    fn bind_for_in_or_of_initializer(&mut self, initializer: &ForInitializer<'_>) {
+        match initializer {
             ForInitializer::VariableDeclarationList(list) => {
                 for decl in list.declarations.iter() {
-                    self.bind_binding_name(&decl.name, SymbolFlags::BLOCK_SCOPED_VARIABLE, decl.data.id);
+                    self.bind_binding_name(&decl.name, SymbolFlags::BLOCK_SCOPED_VARIABLE, decl.data.id, Some(LocalDeclarationKind::Variable));
                 }
             }
             ForInitializer::Expression(expr) => self.bind_expression(expr),
         }
-        self.bind_expression(node.expression);
-        self.bind_statement(node.statement);
-        self.pop_scope();
+    }
+
+    /// Bind a loop body as a `break`/`continue` target. Flow reaching a
+    /// `continue` rejoins the flow at the end of the body.
+    fn bind_loop_body(&mut self, statement: &Statement<'_>) {
+        self.flow_targets.push(FlowTarget {
+            kind: FlowTargetKind::Loop,
+            label: None,
+            breaks: Vec::new(),
+            continues: Vec::new(),
+            referenced: false,
+        });
+        self.bind_statement(statement);
+        if let Some(target) = self.flow_targets.last_mut() {
+            let mut ends = std::mem::take(&mut target.continues);
+            ends.push(self.current_flow);
+            self.current_flow = self.create_branch_label(&ends);
+        }
+    }
+
+    /// Merge the loop exits: the flow after a false condition (if the
+    /// condition can be false) and every `break` out of the loop body.
+    fn finish_loop(&mut self, exit_condition: Option<u32>) {
+        let mut exits = self.flow_targets.pop().map(|t| t.breaks).unwrap_or_default();
+        if let Some(flow) = exit_condition {
+            self.current_flow = flow;
+            exits.push(self.create_flow_node(FlowNodeKind::FalseCondition, None));
+        }
+        self.current_flow = self.create_branch_label(&exits);
     }
 
     fn bind_switch_statement(&mut self, node: &SwitchStatement<'_>) {
         self.bind_expression(node.expression);
-        for clause in node.case_block.clauses.iter() {
-            match clause {
+        let pre_switch_flow = self.current_flow;
+        self.flow_targets.push(FlowTarget {
+            kind: FlowTargetKind::Switch,
+            label: None,
+            breaks: Vec::new(),
+            continues: Vec::new(),
+            referenced: false,
+        });
+
+        let clauses = node.case_block.clauses;
+        let mut has_default = false;
+        let mut fallthrough_flow = None;
+        for (i, clause) in clauses.iter().enumerate() {
+            let mut entries = vec![pre_switch_flow];
+            entries.extend(fallthrough_flow);
+            self.current_flow = self.create_branch_label(&entries);
+            let (clause_range, statements) = match clause {
                 CaseOrDefaultClause::CaseClause(c) => {
                     self.bind_expression(c.expression);
-                    for s in c.statements.iter() {
-                        self.bind_statement(s);
-                    }
+                    (c.data.range, c.statements)
                 }
                 CaseOrDefaultClause::DefaultClause(d) => {
-                    for s in d.statements.iter() {
-                        self.bind_statement(s);
-                    }
+                    has_default = true;
+                    (d.data.range, d.statements)
                 }
+            };
+            for s in statements.iter() {
+                self.bind_statement(s);
             }
+            if !statements.is_empty() && i + 1 < clauses.len() && self.is_reachable() {
+                self.fallthrough_clauses.insert(clause_range);
+            }
+            fallthrough_flow = Some(self.current_flow);
+        }
+
+        let mut exits = self.flow_targets.pop().map(|t| t.breaks).unwrap_or_default();
+        exits.extend(fallthrough_flow);
+        if !has_default {
+            exits.push(pre_switch_flow);
         }
+        self.current_flow = self.create_branch_label(&exits);
     }
 
     fn bind_try_statement(&mut self, node: &TryStatement<'_>) {
+        let pre_try_flow = self.current_flow;
         self.push_block_scope();
         for s in node.try_block.statements.iter() {
             self.bind_statement(s);
         }
         self.pop_scope();
+        let mut post_flows = vec![self.current_flow];
 
         if let Some(ref catch) = node.catch_clause {
+            // Any point of the try block may throw into the catch clause
+            self.current_flow = pre_try_flow;
             self.push_block_scope();
             if let Some(ref var_decl) = catch.variable_declaration {
-                self.bind_binding_name(&var_decl.name, SymbolFlags::BLOCK_SCOPED_VARIABLE, var_decl.data.id);
+                self.bind_binding_name(&var_decl.name, SymbolFlags::BLOCK_SCOPED_VARIABLE, var_decl.data.id, None);
             }
             for s in catch.block.statements.iter() {
                 self.bind_statement(s);
             }
             self.pop_scope();
+            post_flows.push(self.current_flow);
         }
+        let post_try_flow = self.create_branch_label(&post_flows);
 
         if let Some(ref finally) = node.finally_block {
+            // The finally block runs however the try block completes
+            self.current_flow = pre_try_flow;
             self.push_block_scope();
             for s in finally.statements.iter() {
                 self.bind_statement(s);
             }
             self.pop_scope();
+            if !self.is_reachable() {
+                return;
+            }
         }
+        self.current_flow = post_try_flow;
     }
 
     // ========================================================================
@@ -782,8 +1269,13 @@ impl Binder {
 
     fn bind_expression(&mut self, expr: &Expression<'_>) {
         match expr {
+            Expression::Identifier(id) => self.mark_reference(id),
             Expression::Binary(n) => {
-                self.bind_expression(n.left);
+                // A plain assignment writes its target without reading it
+                match n.left {
+                    Expression::Identifier(id) if n.operator_token.data.kind == SyntaxKind::EqualsToken => self.mark_write(id),
+                    _ => self.bind_expression(n.left),
+                }
                 self.bind_expression(n.right);
                 if n.operator_token.data.kind.is_assignment_operator() {
                     self.create_flow_node(FlowNodeKind::Assignment, None);
@@ -791,6 +1283,7 @@ impl Binder {
             }
             Expression::Call(n) => {
                 self.bind_expression(n.expression);
+                self.bind_type_arguments(n.type_arguments);
                 for arg in n.arguments.iter() {
                     self.bind_expression(arg);
                 }
@@ -798,6 +1291,7 @@ impl Binder {
             }
             Expression::New(n) => {
                 self.bind_expression(n.expression);
+                self.bind_type_arguments(n.type_arguments);
                 if let Some(args) = n.arguments {
                     for arg in args.iter() {
                         self.bind_expression(arg);
@@ -823,52 +1317,55 @@ impl Binder {
                 self.current_flow = false_flow;
                 self.bind_expression(n.when_false);
                 let post_false = self.current_flow;
-                let merge = self.create_flow_node(FlowNodeKind::BranchLabel, None);
-                if let Some(node) = self.flow_nodes.get_mut(merge as usize) {
-                    node.antecedents.push(post_true);
-                    node.antecedents.push(post_false);
-                }
-                self.current_flow = merge;
+                self.current_flow = self.create_branch_label(&[post_true, post_false]);
             }
             Expression::ArrowFunction(n) => {
-                self.push_function_scope(n.data.id);
-                for param in n.parameters.iter() {
-                    self.bind_parameter(param);
+                self.push_function_scope(&n.data);
+                self.bind_parameters(n.parameters, true);
+                if let Some(return_type) = n.return_type {
+                    self.bind_type_node(return_type);
                 }
                 match &n.body {
                     ArrowFunctionBody::Block(block) => {
-                        for s in block.statements.iter() {
-                            self.bind_statement(s);
-                        }
+                        self.bind_function_body(block.statements);
                     }
                     ArrowFunctionBody::Expression(e) => {
                         self.bind_expression(e);
+                        // An expression body is an implicit return
+                        if let Some(function) = self.function_stack.last_mut() {
+                            function.info.has_return_value = true;
+                        }
+                        self.current_flow = self.unreachable_flow();
                     }
                 }
-                self.pop_scope();
+                self.pop_function_scope();
             }
             Expression::FunctionExpression(n) => {
-                self.push_function_scope(n.data.id);
+                self.push_function_scope(&n.data);
                 if let Some(ref name) = n.name {
-                    self.declare_symbol_with_text(name.text, name.text_name.clone(), SymbolFlags::FUNCTION, n.data.id);
+                    self.declare_identifier(name, SymbolFlags::FUNCTION, n.data.id);
                 }
-                for param in n.parameters.iter() {
-                    self.bind_parameter(param);
-                }
-                for s in n.body.statements.iter() {
-                    self.bind_statement(s);
+                self.bind_parameters(n.parameters, true);
+                if let Some(return_type) = n.return_type {
+                    self.bind_type_node(return_type);
                 }
-                self.pop_scope();
+                self.bind_function_body(n.body.statements);
+                self.pop_function_scope();
             }
             Expression::ClassExpression(n) => {
+                // The name of a class expression is only visible inside it.
+                self.push_block_scope();
                 if let Some(ref name) = n.name {
-                    self.declare_symbol_with_text(name.text, name.text_name.clone(), SymbolFlags::CLASS, n.data.id);
+                    self.declare_identifier(name, SymbolFlags::CLASS, n.data.id);
                 }
+                self.bind_type_parameters(n.type_parameters);
+                self.bind_heritage_clauses(n.heritage_clauses);
                 self.push_block_scope();
                 for member in n.members.iter() {
                     self.bind_class_element(member);
                 }
                 self.pop_scope();
+                self.pop_scope();
             }
             Expression::ArrayLiteral(n) => {
                 for elem in n.elements.iter() {
@@ -882,6 +1379,7 @@ impl Binder {
                             self.bind_expression(p.initializer);
                         }
                         ObjectLiteralElement::ShorthandPropertyAssignment(p) => {
+                            self.mark_reference(&p.name);
                             if let Some(init) = p.object_assignment_initializer {
                                 self.bind_expression(init);
                             }
@@ -890,31 +1388,33 @@ impl Binder {
                             self.bind_expression(p.expression);
                         }
                         ObjectLiteralElement::MethodDeclaration(m) => {
-                            self.push_function_scope(m.data.id);
-                            for param in m.parameters.iter() {
-                                self.bind_parameter(param);
+                            self.push_function_scope(&m.data);
+                            self.bind_parameters(m.parameters, m.body.is_some());
+                            if let Some(return_type) = m.return_type {
+                                self.bind_type_node(return_type);
                             }
                             if let Some(ref body) = m.body {
-                                for s in body.statements.iter() {
-                                    self.bind_statement(s);
-                                }
+                                self.bind_function_body(body.statements);
                             }
-                            self.pop_scope();
+                            self.pop_function_scope();
                         }
                         ObjectLiteralElement::GetAccessor(g) => {
-                            self.push_function_scope(g.data.id);
+                            self.push_function_scope(&g.data);
+                            if let Some(return_type) = g.return_type {
+                                self.bind_type_node(return_type);
+                            }
                             if let Some(ref body) = g.body {
-                                for s in body.statements.iter() { self.bind_statement(s); }
+                                self.bind_function_body(body.statements);
                             }
-                            self.pop_scope();
+                            self.pop_function_scope();
                         }
                         ObjectLiteralElement::SetAccessor(s_decl) => {
-                            self.push_function_scope(s_decl.data.id);
-                            for param in s_decl.parameters.iter() { self.bind_parameter(param); }
+                            self.push_function_scope(&s_decl.data);
+                            self.bind_parameters(s_decl.parameters, false);
                             if let Some(ref body) = s_decl.body {
-                                for s in body.statements.iter() { self.bind_statement(s); }
+                                self.bind_function_body(body.statements);
                             }
-                            self.pop_scope();
+                            self.pop_function_scope();
                         }
                     }
                 }
@@ -939,14 +1439,225 @@ impl Binder {
             Expression::TypeOf(n) => self.bind_expression(n.expression),
             Expression::Delete(n) => self.bind_expression(n.expression),
             Expression::Void(n) => self.bind_expression(n.expression),
-            Expression::As(n) => self.bind_expression(n.expression),
-            Expression::Satisfies(n) => self.bind_expression(n.expression),
+            Expression::As(n) => {
+                self.bind_expression(n.expression);
+                self.bind_type_node(n.type_node);
+            }
+            Expression::Satisfies(n) => {
+                self.bind_expression(n.expression);
+                self.bind_type_node(n.type_node);
+            }
             Expression::NonNull(n) => self.bind_expression(n.expression),
-            Expression::TypeAssertion(n) => self.bind_expression(n.expression),
+            Expression::TypeAssertion(n) => {
+                self.bind_type_node(n.type_node);
+                self.bind_expression(n.expression);
+            }
+            Expression::JsxElement(n) => {
+                self.bind_jsx_opening(&n.opening_element.tag_name, n.opening_element.type_arguments, &n.opening_element.attributes);
+                self.bind_jsx_children(n.children);
+            }
+            Expression::JsxSelfClosingElement(n) => self.bind_jsx_opening(&n.tag_name, n.type_arguments, &n.attributes),
+            Expression::JsxFragment(n) => self.bind_jsx_children(n.children),
             _ => {}
         }
     }
 
+    fn bind_jsx_opening(&mut self, tag_name: &JsxTagName<'_>, type_arguments: Option<NodeList<'_, TypeNode<'_>>>, attributes: &JsxAttributes<'_>) {
+        // Intrinsic tags like `div` are not references to values
+        if let JsxTagName::Expression(expression) = tag_name {
+            if !tag_name.is_intrinsic() {
+                self.bind_expression(expression);
+            }
+        }
+        self.bind_type_arguments(type_arguments);
+        for property in attributes.properties.iter() {
+            match property {
+                JsxAttributeLike::Attribute(attr) => match &attr.initializer {
+                    Some(JsxAttributeValue::Expression(e)) => {
+                        if let Some(expression) = e.expression { self.bind_expression(expression); }
+                    }
+                    Some(JsxAttributeValue::Element(element)) => self.bind_expression(element),
+                    _ => {}
+                },
+                JsxAttributeLike::SpreadAttribute(spread) => self.bind_expression(spread.expression),
+            }
+        }
+    }
+
+    fn bind_jsx_children(&mut self, children: NodeList<'_, JsxChild<'_>>) {
+        for child in children.iter() {
+            match child {
+                JsxChild::Expression(e) => {
+                    if let Some(expression) = e.expression { self.bind_expression(expression); }
+                }
+                JsxChild::Element(element) => self.bind_expression(element),
+                JsxChild::Text(_) => {}
+            }
+        }
+    }
+
+    // ========================================================================
+    // Type binding
+    // ========================================================================
+
+    /// Bind the names a type node refers to. Types declare no symbols of
+    /// their own; this only records references.
+    fn bind_type_node(&mut self, type_node: &TypeNode<'_>) {
+        self.with_type_reference(|binder| binder.bind_type_node_worker(type_node));
+    }
+
+    fn bind_type_node_worker(&mut self, type_node: &TypeNode<'_>) {
+        match type_node {
+            TypeNode::TypeReference(n) => {
+                self.bind_entity_name(&n.type_name);
+                self.bind_type_arguments(n.type_arguments);
+            }
+            TypeNode::TypeQuery(n) => {
+                self.bind_entity_name(&n.expr_name);
+                self.bind_type_arguments(n.type_arguments);
+            }
+            TypeNode::FunctionType(n) => {
+                self.bind_type_parameters(n.type_parameters);
+                self.bind_signature_types(n.parameters, n.return_type);
+            }
+            TypeNode::ConstructorType(n) => {
+                self.bind_type_parameters(n.type_parameters);
+                self.bind_signature_types(n.parameters, n.return_type);
+            }
+            TypeNode::TypeLiteral(n) => {
+                for member in n.members.iter() {
+                    self.bind_type_element(member);
+                }
+            }
+            TypeNode::ArrayType(n) => self.bind_type_node(n.element_type),
+            TypeNode::TupleType(n) => {
+                for element in n.elements.iter() {
+                    self.bind_type_node(element);
+                }
+            }
+            TypeNode::OptionalType(n) => self.bind_type_node(n.type_node),
+            TypeNode::RestType(n) => self.bind_type_node(n.type_node),
+            TypeNode::UnionType(n) => {
+                for ty in n.types.iter() {
+                    self.bind_type_node(ty);
+                }
+            }
+            TypeNode::IntersectionType(n) => {
+                for ty in n.types.iter() {
+                    self.bind_type_node(ty);
+                }
+            }
+            TypeNode::ConditionalType(n) => {
+                self.bind_type_node(n.check_type);
+                self.bind_type_node(n.extends_type);
+                self.bind_type_node(n.true_type);
+                self.bind_type_node(n.false_type);
+            }
+            TypeNode::InferType(n) => {
+                if let Some(constraint) = n.type_parameter.constraint {
+                    self.bind_type_node(constraint);
+                }
+            }
+            TypeNode::ParenthesizedType(n) => self.bind_type_node(n.type_node),
+            TypeNode::TypeOperator(n) => self.bind_type_node(n.type_node),
+            TypeNode::IndexedAccessType(n) => {
+                self.bind_type_node(n.object_type);
+                self.bind_type_node(n.index_type);
+            }
+            TypeNode::MappedType(n) => {
+                if let Some(constraint) = n.type_parameter.constraint {
+                    self.bind_type_node(constraint);
+                }
+                if let Some(name_type) = n.name_type {
+                    self.bind_type_node(name_type);
+                }
+                if let Some(ty) = n.type_node {
+                    self.bind_type_node(ty);
+                }
+            }
+            TypeNode::NamedTupleMember(n) => self.bind_type_node(n.type_node),
+            TypeNode::TemplateLiteralType(n) => {
+                for span in n.template_spans.iter() {
+                    self.bind_type_node(span.type_node);
+                }
+            }
+            TypeNode::ImportType(n) => self.bind_type_arguments(n.type_arguments),
+            TypeNode::TypePredicate(n) => {
+                if let Some(ty) = n.type_node {
+                    self.bind_type_node(ty);
+                }
+            }
+            TypeNode::ExpressionWithTypeArguments(n) => {
+                self.bind_expression(n.expression);
+                self.bind_type_arguments(n.type_arguments);
+            }
+            TypeNode::KeywordType(_) | TypeNode::ThisType(_) | TypeNode::LiteralType(_) => {}
+        }
+    }
+
+    fn bind_type_element(&mut self, element: &TypeElement<'_>) {
+        match element {
+            TypeElement::PropertySignature(n) => {
+                if let Some(ty) = n.type_annotation {
+                    self.bind_type_node(ty);
+                }
+            }
+            TypeElement::MethodSignature(n) => {
+                self.bind_type_parameters(n.type_parameters);
+                self.bind_signature_types(n.parameters, n.return_type);
+            }
+            TypeElement::CallSignature(n) => {
+                self.bind_type_parameters(n.type_parameters);
+                self.bind_signature_types(n.parameters, n.return_type);
+            }
+            TypeElement::ConstructSignature(n) => {
+                self.bind_type_parameters(n.type_parameters);
+                self.bind_signature_types(n.parameters, n.return_type);
+            }
+            TypeElement::IndexSignature(n) => {
+                self.bind_signature_types(n.parameters, n.type_annotation);
+            }
+        }
+    }
+
+    /// Bind the parameter and return types of a signature without declaring
+    /// its parameters.
+    fn bind_signature_types(&mut self, parameters: &[ParameterDeclaration<'_>], return_type: Option<&TypeNode<'_>>) {
+        for param in parameters.iter() {
+            if let Some(ty) = param.type_annotation {
+                self.bind_type_node(ty);
+            }
+        }
+        if let Some(ty) = return_type {
+            self.bind_type_node(ty);
+        }
+    }
+
+    fn bind_type_parameters(&mut self, type_parameters: Option<&[TypeParameterDeclaration<'_>]>) {
+        for type_parameter in type_parameters.unwrap_or_default().iter() {
+            if let Some(constraint) = type_parameter.constraint {
+                self.bind_type_node(constraint);
+            }
+            if let Some(default) = type_parameter.default {
+                self.bind_type_node(default);
+            }
+        }
+    }
+
+    fn bind_type_arguments(&mut self, type_arguments: Option<&[TypeNode<'_>]>) {
+        for ty in type_arguments.unwrap_or_default().iter() {
+            self.bind_type_node(ty);
+        }
+    }
+
+    /// A qualified name reads the namespace at its root.
+    fn bind_entity_name(&mut self, name: &EntityName<'_>) {
+        match name {
+            EntityName::Identifier(id) => self.mark_reference(id),
+            EntityName::QualifiedName(q) => self.bind_entity_name(&q.left),
+        }
+    }
+
     // ========================================================================
     // Symbol resolution
     // ========================================================================
@@ -986,6 +1697,78 @@ impl Binder {
         None
     }
 
+    /// Resolve a name read by an expression or type. Class and enum members
+    /// share the binder's scopes but are never in scope for a bare name.
+    fn resolve_reference(&self, name: &str) -> Option<SymbolId> {
+        let mut scope = self.current_scope.as_ref();
+        let mut depth = 0u32;
+        while let Some(s) = scope {
+            if let Some(&id) = s.names.get(name) {
+                if self.is_referenceable(id) {
+                    return Some(id);
+                }
+            }
+            depth += 1;
+            if depth > Self::MAX_SCOPE_DEPTH {
+                break;
+            }
+            scope = s.parent.as_ref();
+        }
+        None
+    }
+
+    fn is_referenceable(&self, id: SymbolId) -> bool {
+        self.get_symbol(id)
+            .is_some_and(|symbol| !symbol.flags.intersects(SymbolFlags::CLASS_MEMBER | SymbolFlags::ENUM_MEMBER))
+    }
+
+    /// Record that `node` reads `name`. Names that do not resolve yet may be
+    /// declared later in an enclosing scope, so they are retried when their
+    /// scope is popped.
+    fn mark_reference(&mut self, name: &Identifier) {
+        self.record_reference(name, false);
+    }
+
+    /// Record that `name` is assigned without being read.
+    fn mark_write(&mut self, name: &Identifier) {
+        self.record_reference(name, true);
+    }
+
+    fn record_reference(&mut self, name: &Identifier, is_write_only: bool) {
+        let reference = PendingReference {
+            name: name.text_name.clone(),
+            node: name.data.id,
+            range: name.data.range,
+            is_value: !self.in_type_reference,
+            is_write_only,
+        };
+        match self.resolve_reference(&reference.name) {
+            Some(id) => self.add_reference(id, &reference),
+            None => {
+                if let Some(scope) = self.current_scope.as_mut() {
+                    scope.pending_references.push(reference);
+                }
+            }
+        }
+    }
+
+    fn add_reference(&mut self, id: SymbolId, reference: &PendingReference) {
+        if let Some(symbol) = self.symbols.get_mut(id.index()) {
+            if !reference.is_write_only {
+                symbol.references.push(reference.node);
+                symbol.is_referenced_as_value |= reference.is_value;
+            }
+            self.symbol_locations.entry(reference.range).or_insert(id);
+        }
+    }
+
+    /// Bind names read as types while `f` runs.
+    fn with_type_reference(&mut self, f: impl FnOnce(&mut Self)) {
+        let saved = std::mem::replace(&mut self.in_type_reference, true);
+        f(self);
+        self.in_type_reference = saved;
+    }
+
     /// Resolve a name starting from a specific symbol's members.
     pub fn resolve_member(&self, container: SymbolId, name: &InternedString) -> Option<SymbolId> {
         if let Some(symbol) = self.get_symbol(container) {
@@ -1004,10 +1787,10 @@ impl Binder {
     fn declare_property_name_symbol(&mut self, name: &PropertyName<'_>, flags: SymbolFlags, node_id: NodeId) {
         match name {
             PropertyName::Identifier(id) => {
-                self.declare_symbol_with_text(id.text, id.text_name.clone(), flags, node_id);
+                self.declare_identifier(id, flags, node_id);
             }
             PropertyName::PrivateIdentifier(id) => {
-                self.declare_symbol_with_text(id.text, id.text_name.clone(), flags, node_id);
+                self.declare_identifier(id, flags, node_id);
             }
             PropertyName::StringLiteral(_) | PropertyName::NumericLiteral(_) | PropertyName::ComputedPropertyName(_) => {
                 // Computed/literal property names don't create named symbols in the scope
@@ -1015,40 +1798,47 @@ impl Binder {
         }
     }
 
+    /// Declare the symbol an identifier names, recording the identifier as
+    /// the location of one of its declarations. Hoisted declarations are
+    /// declared again when bound; the location is recorded once.
+    fn declare_identifier(&mut self, name: &Identifier, flags: SymbolFlags, declaration: NodeId) -> SymbolId {
+        let id = self.declare_symbol_with_text(name.text, name.text_name.clone(), Some(name.data.range), flags, declaration);
+        self.symbol_locations.entry(name.data.range).or_insert(id);
+        if let Some(symbol) = self.symbols.get_mut(id.index()) {
+            if !symbol.declaration_ranges.contains(&name.data.range) {
+                symbol.declaration_ranges.push(name.data.range);
+            }
+        }
+        id
+    }
+
     fn declare_symbol(&mut self, name: InternedString, flags: SymbolFlags, declaration: NodeId) -> SymbolId {
-        self.declare_symbol_with_text(name, String::new(), flags, declaration)
+        self.declare_symbol_with_text(name, String::new(), None, flags, declaration)
     }
 
-    fn declare_symbol_with_text(&mut self, name: InternedString, name_text: String, flags: SymbolFlags, declaration: NodeId) -> SymbolId {
+    /// Declare a symbol in the current scope, merging it with the symbol of
+    /// the same name when their flags allow. `name_range` locates a conflict.
+    fn declare_symbol_with_text(
+        &mut self,
+        name: InternedString,
+        name_text: String,
+        name_range: Option<TextRange>,
+        flags: SymbolFlags,
+        declaration: NodeId,
+    ) -> SymbolId {
         // Check for existing symbol in current scope for merging
         if !name_text.is_empty() {
             if let Some(scope) = &self.current_scope {
                 if let Some(&existing_id) = scope.names.get(&name_text) {
-                    let (can_merge, is_duplicate_block_scoped) = if let Some(existing) = self.symbols.get(existing_id.index()) {
-                        let can_merge =
-                            // Interface declaration merging
-                            (existing.flags.contains(SymbolFlags::INTERFACE) && flags.contains(SymbolFlags::INTERFACE))
-                            // Namespace declaration merging
-                            || (existing.flags.contains(SymbolFlags::VALUE_MODULE) && flags.contains(SymbolFlags::VALUE_MODULE))
-                            // Function overload merging
-                            || (existing.flags.contains(SymbolFlags::FUNCTION) && flags.contains(SymbolFlags::FUNCTION))
-                            // Enum declaration merging
-                            || (existing.flags.intersects(SymbolFlags::ENUM) && flags.intersects(SymbolFlags::ENUM));
-
-                        // Detect duplicate block-scoped declarations (TDZ errors)
-                        let is_dup = existing.flags.contains(SymbolFlags::BLOCK_SCOPED_VARIABLE)
-                            && flags.contains(SymbolFlags::BLOCK_SCOPED_VARIABLE);
-
-                        (can_merge, is_dup)
-                    } else {
-                        (false, false)
-                    };
-
-                    if is_duplicate_block_scoped {
-                        self.diagnostics.add(rscript_diagnostics::Diagnostic::new(
-                            &rscript_diagnostics::messages::DUPLICATE_IDENTIFIER_0,
-                            &[&name_text],
-                        ));
+                    let existing_flags = self.symbols.get(existing_id.index()).map_or(SymbolFlags::NONE, |s| s.flags);
+                    // Members and type parameters share scopes with unrelated
+                    // names; they simply shadow what came before.
+                    let can_merge = !(existing_flags | flags).intersects(
+                        SymbolFlags::CLASS_MEMBER | SymbolFlags::ENUM_MEMBER | SymbolFlags::TYPE_PARAMETER | SymbolFlags::EXPORT_VALUE,
+                    );
+
+                    if can_merge && existing_flags.intersects(get_excluded_flags(flags)) {
+                        self.report_conflicting_declaration(existing_id, &name_text, name_range, existing_flags, flags);
                         // Return existing symbol anyway
                         return existing_id;
                     }
@@ -1082,47 +1872,183 @@ impl Binder {
         id
     }
 
+    /// Report a declaration that cannot merge with the symbol already in
+    /// scope, at its name. The first conflict reports the earlier
+    /// declarations too. A hoisted declaration is declared again when its
+    /// statement is bound; each name is reported once.
+    fn report_conflicting_declaration(
+        &mut self,
+        existing: SymbolId,
+        name: &str,
+        name_range: Option<TextRange>,
+        existing_flags: SymbolFlags,
+        flags: SymbolFlags,
+    ) {
+        let message = if (existing_flags | flags).contains(SymbolFlags::BLOCK_SCOPED_VARIABLE) {
+            &rscript_diagnostics::messages::CANNOT_REDECLARE_BLOCK_SCOPED_VARIABLE_0
+        } else {
+            &rscript_diagnostics::messages::DUPLICATE_IDENTIFIER_0
+        };
+        let mut ranges = self.symbols.get(existing.index()).map_or_else(Vec::new, |s| s.declaration_ranges.clone());
+        ranges.extend(name_range);
+        for range in ranges {
+            if self.reported_conflicts.insert(range) {
+                let span = TextSpan::from_bounds(range.pos, range.end);
+                self.diagnostics.add(Diagnostic::with_location(self.file_name.clone(), span, message, &[name]));
+            }
+        }
+    }
+
     fn push_block_scope(&mut self) {
         let parent = self.current_scope.take();
         self.current_scope = Some(Box::new(Scope::new(parent)));
         self.scope_depth += 1;
     }
 
-    fn push_function_scope(&mut self, container_node: NodeId) {
+    fn push_function_scope(&mut self, container: &NodeData) {
         let parent = self.current_scope.take();
         self.current_scope = Some(Box::new(Scope::new(parent)));
         self.scope_depth += 1;
-        // Create flow node for function start
-        self.create_flow_node(FlowNodeKind::Start, Some(container_node));
-        let _ = container_node;
+        self.function_stack.push(FunctionContext {
+            range: container.range,
+            info: FunctionFlowInfo::default(),
+            saved_flow: self.current_flow,
+            saved_targets: std::mem::take(&mut self.flow_targets),
+            saved_preceding_flow: self.preceding_flow.take(),
+        });
+        // Create flow node for function start; a function body is reachable
+        // even when the function itself is declared in dead code.
+        self.current_flow = self.push_flow_node(FlowNodeKind::Start, Vec::new(), Some(container.id));
+    }
+
+    /// Pop a scope pushed by `push_function_scope`, recording the reachability
+    /// of the function body and restoring the enclosing flow.
+    fn pop_function_scope(&mut self) {
+        if let Some(function) = self.function_stack.pop() {
+            let info = FunctionFlowInfo { end_reachable: self.is_reachable(), end_flow: self.current_flow, ..function.info };
+            self.function_flows.insert(function.range, info);
+            self.current_flow = function.saved_flow;
+            self.flow_targets = function.saved_targets;
+            self.preceding_flow = function.saved_preceding_flow;
+        }
+        self.pop_scope();
     }
 
     fn pop_scope(&mut self) {
-        if let Some(scope) = self.current_scope.take() {
-            self.current_scope = scope.parent;
+        if let Some(mut scope) = self.current_scope.take() {
+            let mut parent = scope.parent.take();
+            for reference in std::mem::take(&mut scope.pending_references) {
+                match scope.names.get(&reference.name).copied().filter(|&id| self.is_referenceable(id)) {
+                    Some(id) => self.add_reference(id, &reference),
+                    None => {
+                        if let Some(parent) = parent.as_mut() {
+                            parent.pending_references.push(reference);
+                        }
+                    }
+                }
+            }
+            self.current_scope = parent;
             if self.scope_depth > 0 {
                 self.scope_depth -= 1;
             }
         }
     }
 
+    /// Create a flow node following the current flow. Code after an
+    /// unreachable point stays unreachable.
     fn create_flow_node(&mut self, kind: FlowNodeKind, node: Option<NodeId>) -> u32 {
+        if !self.is_reachable() {
+            return self.current_flow;
+        }
+        let antecedents = if self.current_flow > 0 || !self.flow_nodes.is_empty() {
+            vec![self.current_flow]
+        } else {
+            vec![]
+        };
+        let id = self.push_flow_node(kind, antecedents, node);
+        self.current_flow = id;
+        id
+    }
+
+    fn push_flow_node(&mut self, kind: FlowNodeKind, antecedents: Vec<u32>, node: Option<NodeId>) -> u32 {
         let id = self.next_flow_id;
         self.next_flow_id += 1;
-        self.flow_nodes.push(FlowNode {
-            kind,
-            id,
-            antecedents: if self.current_flow > 0 || !self.flow_nodes.is_empty() {
-                vec![self.current_flow]
-            } else {
-                vec![]
-            },
-            node,
-        });
-        self.current_flow = id;
+        self.flow_nodes.push(FlowNode { kind, id, antecedents, node });
         id
     }
 
+    /// Create a label joining `antecedents`. The join is unreachable when
+    /// every antecedent is.
+    fn create_branch_label(&mut self, antecedents: &[u32]) -> u32 {
+        let unreachable = self.unreachable_flow;
+        let mut reachable: Vec<u32> = antecedents.iter().copied().filter(|&a| Some(a) != unreachable).collect();
+        reachable.dedup();
+        match reachable.as_slice() {
+            [] => self.unreachable_flow(),
+            [single] => *single,
+            _ => self.push_flow_node(FlowNodeKind::BranchLabel, reachable, None),
+        }
+    }
+
+    /// Create the label at the top of a loop, entered from the current flow.
+    fn create_loop_label(&mut self) -> u32 {
+        if !self.is_reachable() {
+            return self.current_flow;
+        }
+        let entry = self.current_flow;
+        self.push_flow_node(FlowNodeKind::LoopLabel, vec![entry], None)
+    }
+
+    fn add_antecedent(&mut self, label: u32, antecedent: u32) {
+        if Some(label) == self.unreachable_flow || Some(antecedent) == self.unreachable_flow {
+            return;
+        }
+        if let Some(node) = self.flow_nodes.get_mut(label as usize) {
+            if !node.antecedents.contains(&antecedent) {
+                node.antecedents.push(antecedent);
+            }
+        }
+    }
+
+    fn unreachable_flow(&mut self) -> u32 {
+        match self.unreachable_flow {
+            Some(id) => id,
+            None => {
+                let id = self.push_flow_node(FlowNodeKind::Unreachable, Vec::new(), None);
+                self.unreachable_flow = Some(id);
+                id
+            }
+        }
+    }
+
+    /// Whether the current point of the flow graph can be reached.
+    fn is_reachable(&self) -> bool {
+        Some(self.current_flow) != self.unreachable_flow
+    }
+
+    /// The statement a `break` jumps out of: the labeled statement, or the
+    /// innermost loop or switch.
+    fn find_break_target(&mut self, label: Option<&str>) -> Option<&mut FlowTarget> {
+        self.flow_targets.iter_mut().rev().find(|t| match label {
+            Some(label) => t.label.as_deref() == Some(label),
+            None => t.kind != FlowTargetKind::Label,
+        })
+    }
+
+    /// The loop a `continue` jumps to: the loop directly under the label, or
+    /// the innermost loop.
+    fn find_continue_target(&mut self, label: Option<&str>) -> Option<&mut FlowTarget> {
+        let index = match label {
+            Some(label) => {
+                let label_index = self.flow_targets.iter().rposition(|t| t.label.as_deref() == Some(label))?;
+                self.flow_targets[label_index].referenced = true;
+                label_index + 1
+            }
+            None => self.flow_targets.iter().rposition(|t| t.kind == FlowTargetKind::Loop)?,
+        };
+        self.flow_targets.get_mut(index).filter(|t| t.kind == FlowTargetKind::Loop)
+    }
+
     /// Get a mutable symbol by its ID.
     pub fn get_symbol_mut(&mut self, id: SymbolId) -> Option<&mut Symbol> {
         self.symbols.get_mut(id.index())
@@ -1144,6 +2070,96 @@ impl Binder {
     }
 }
 
+/// Whether a statement runs code, and so is reported when unreachable.
+/// Declarations other than classes run nothing, nor does a `var` without
+/// initializers, whose declarations are hoisted.
+fn is_executable_statement(stmt: &Statement<'_>) -> bool {
+    match stmt {
+        Statement::VariableStatement(n) => {
+            n.declaration_list.data.flags.intersects(NodeFlags::LET | NodeFlags::CONST)
+                || n.declaration_list.declarations.iter().any(|d| d.initializer.is_some())
+        }
+        Statement::FunctionDeclaration(_)
+        | Statement::InterfaceDeclaration(_)
+        | Statement::TypeAliasDeclaration(_)
+        | Statement::EnumDeclaration(_)
+        | Statement::ModuleDeclaration(_)
+        | Statement::ImportDeclaration(_)
+        | Statement::ImportEqualsDeclaration(_)
+        | Statement::ExportDeclaration(_)
+        | Statement::ExportAssignment(_)
+        | Statement::NamespaceExportDeclaration(_)
+        | Statement::EmptyStatement(_)
+        | Statement::MissingDeclaration(_) => false,
+        _ => true,
+    }
+}
+
+/// The identifiers a declaration statement declares at its top level.
+fn declared_identifiers<'s>(stmt: &'s Statement<'_>) -> Vec<&'s Identifier> {
+    match stmt {
+        Statement::FunctionDeclaration(n) => n.name.iter().collect(),
+        Statement::ClassDeclaration(n) => n.name.iter().collect(),
+        Statement::InterfaceDeclaration(n) => vec![&n.name],
+        Statement::TypeAliasDeclaration(n) => vec![&n.name],
+        Statement::EnumDeclaration(n) => vec![&n.name],
+        Statement::ModuleDeclaration(n) => match n.name {
+            ModuleName::Identifier(ref name) => vec![name],
+            ModuleName::StringLiteral(_) => Vec::new(),
+        },
+        Statement::VariableStatement(n) => n.declaration_list.declarations.iter()
+            .filter_map(|decl| match decl.name {
+                BindingName::Identifier(ref name) => Some(name),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The identifiers a binding name declares, including those nested in
+/// destructuring patterns.
+fn collect_binding_identifiers<'s>(name: &'s BindingName<'_>, identifiers: &mut Vec<&'s Identifier>) {
+    match name {
+        BindingName::Identifier(id) => identifiers.push(id),
+        BindingName::ObjectBindingPattern(pattern) => {
+            for elem in pattern.elements.iter() {
+                collect_binding_identifiers(&elem.name, identifiers);
+            }
+        }
+        BindingName::ArrayBindingPattern(pattern) => {
+            for elem in pattern.elements.iter() {
+                if let ArrayBindingElement::BindingElement(e) = elem {
+                    collect_binding_identifiers(&e.name, identifiers);
+                }
+            }
+        }
+    }
+}
+
+/// Whether a namespace declares any values, and so exists at runtime.
+/// Namespaces holding only types and uninstantiated namespaces do not.
+pub fn is_instantiated_module(node: &ModuleDeclaration<'_>) -> bool {
+    match &node.body {
+        Some(ModuleBody::ModuleBlock(block)) => block.statements.iter().any(|stmt| match stmt {
+            Statement::InterfaceDeclaration(_) | Statement::TypeAliasDeclaration(_) | Statement::EmptyStatement(_) => false,
+            Statement::ModuleDeclaration(inner) => is_instantiated_module(inner),
+            _ => true,
+        }),
+        Some(ModuleBody::ModuleDeclaration(inner)) => is_instantiated_module(inner),
+        None => false,
+    }
+}
+
+/// Whether a loop condition is the literal `true`.
+fn is_true_literal(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::TrueKeyword(_) => true,
+        Expression::Parenthesized(n) => is_true_literal(n.expression),
+        _ => false,
+    }
+}
+
 impl Default for Binder {
     fn default() -> Self {
         Self::new()
@@ -8,5 +8,5 @@ mod binder;
 mod scope;
 mod symbol;
 
-pub use binder::Binder;
-pub use symbol::{Symbol, SymbolTable};
+pub use binder::{is_instantiated_module, Binder, FlowNode, FlowNodeKind, FunctionFlowInfo, LocalDeclaration, LocalDeclarationKind, StatementFlow};
+pub use symbol::{get_excluded_flags, Symbol, SymbolTable};
@@ -1,7 +1,8 @@
 //! Scope management for the binder.
 
 use crate::symbol::SymbolTable;
-use rscript_ast::types::SymbolId;
+use rscript_ast::types::{NodeId, SymbolId};
+use rscript_core::text::TextRange;
 use std::collections::HashMap;
 
 /// A scope in the binding phase. Scopes form a chain from inner to outer.
@@ -16,6 +17,24 @@ pub struct Scope {
     /// The block-scoped container symbol.
     #[allow(dead_code)]
     pub container: Option<SymbolId>,
+    /// References that did not resolve when they were bound. They are retried
+    /// against this scope's declarations when it is popped (to pick up names
+    /// declared later in the scope) and otherwise handed to the parent.
+    pub pending_references: Vec<PendingReference>,
+}
+
+/// A name read by an identifier, waiting to be resolved.
+#[derive(Debug)]
+pub struct PendingReference {
+    pub name: String,
+    /// The referencing identifier.
+    pub node: NodeId,
+    pub range: TextRange,
+    /// Whether the identifier reads the value rather than naming a type.
+    pub is_value: bool,
+    /// Whether the identifier only assigns the symbol, as the target of `=`.
+    /// Writes are located but do not count as references.
+    pub is_write_only: bool,
 }
 
 impl Scope {
@@ -25,6 +44,7 @@ impl Scope {
             names: HashMap::new(),
             parent,
             container: None,
+            pending_references: Vec::new(),
         }
     }
 
@@ -35,6 +55,7 @@ impl Scope {
             names: HashMap::new(),
             parent,
             container: Some(container),
+            pending_references: Vec::new(),
         }
     }
 }
@@ -1,7 +1,8 @@
 //! Symbol and symbol table definitions.
 
-use rscript_ast::types::{SymbolFlags, SymbolId, NodeId};
+use rscript_ast::types::{NodeId, SymbolFlags, SymbolId};
 use rscript_core::intern::InternedString;
+use rscript_core::text::TextRange;
 use rustc_hash::FxHashMap;
 
 /// A symbol represents a named entity in the program (variable, function,
@@ -20,12 +21,19 @@ pub struct Symbol {
     pub declarations: Vec<NodeId>,
     /// The value declaration (if any).
     pub value_declaration: Option<NodeId>,
+    /// The ranges of the identifiers naming the declarations.
+    pub declaration_ranges: Vec<TextRange>,
     /// Members of this symbol (for classes, interfaces, etc.).
     pub members: Option<SymbolTable>,
     /// Exports of this symbol (for modules).
     pub exports: Option<SymbolTable>,
     /// The parent symbol (for nested symbols).
     pub parent: Option<SymbolId>,
+    /// The identifier nodes that read this symbol.
+    pub references: Vec<NodeId>,
+    /// Whether any reference reads the value, rather than naming a type in a
+    /// position JavaScript emit erases.
+    pub is_referenced_as_value: bool,
 }
 
 impl Symbol {
@@ -37,9 +45,12 @@ impl Symbol {
             flags,
             declarations: Vec::new(),
             value_declaration: None,
+            declaration_ranges: Vec::new(),
             members: None,
             exports: None,
             parent: None,
+            references: Vec::new(),
+            is_referenced_as_value: false,
         }
     }
 
@@ -51,9 +62,12 @@ impl Symbol {
             flags,
             declarations: Vec::new(),
             value_declaration: None,
+            declaration_ranges: Vec::new(),
             members: None,
             exports: None,
             parent: None,
+            references: Vec::new(),
+            is_referenced_as_value: false,
         }
     }
 }
@@ -62,12 +76,15 @@ impl Symbol {
 #[derive(Debug, Clone, Default)]
 pub struct SymbolTable {
     table: FxHashMap<InternedString, SymbolId>,
+    /// String-based name lookup for actual identifier resolution.
+    names: FxHashMap<String, SymbolId>,
 }
 
 impl SymbolTable {
     pub fn new() -> Self {
         Self {
             table: FxHashMap::default(),
+            names: FxHashMap::default(),
         }
     }
 
@@ -79,6 +96,22 @@ impl SymbolTable {
         self.table.insert(name, symbol);
     }
 
+    /// Look up a symbol by its text name.
+    pub fn get_by_name(&self, name: &str) -> Option<SymbolId> {
+        self.names.get(name).copied()
+    }
+
+    /// Add a symbol under both its interned and its text name.
+    pub fn set_with_name(&mut self, name: InternedString, name_text: String, symbol: SymbolId) {
+        self.table.insert(name, symbol);
+        self.names.insert(name_text, symbol);
+    }
+
+    /// Iterate the symbols added with a text name.
+    pub fn iter_names(&self) -> impl Iterator<Item = (&String, &SymbolId)> {
+        self.names.iter()
+    }
+
     pub fn has(&self, name: &InternedString) -> bool {
         self.table.contains_key(name)
     }
@@ -95,3 +128,44 @@ impl SymbolTable {
         self.table.iter()
     }
 }
+
+/// The symbol flags a new declaration with `flags` may not merge with.
+///
+/// Mirrors TypeScript's `*Excludes` table: a declaration conflicts with an
+/// existing symbol when the symbol's flags intersect the result.
+pub fn get_excluded_flags(flags: SymbolFlags) -> SymbolFlags {
+    let mut excludes = SymbolFlags::NONE;
+    if flags.contains(SymbolFlags::FUNCTION_SCOPED_VARIABLE) {
+        excludes |= SymbolFlags::VALUE - SymbolFlags::FUNCTION_SCOPED_VARIABLE;
+    }
+    if flags.contains(SymbolFlags::BLOCK_SCOPED_VARIABLE) {
+        excludes |= SymbolFlags::VALUE;
+    }
+    if flags.contains(SymbolFlags::FUNCTION) {
+        excludes |= SymbolFlags::VALUE - (SymbolFlags::FUNCTION | SymbolFlags::VALUE_MODULE | SymbolFlags::CLASS);
+    }
+    if flags.contains(SymbolFlags::CLASS) {
+        excludes |= (SymbolFlags::VALUE | SymbolFlags::TYPE)
+            - (SymbolFlags::VALUE_MODULE | SymbolFlags::INTERFACE | SymbolFlags::FUNCTION);
+    }
+    if flags.contains(SymbolFlags::INTERFACE) {
+        excludes |= SymbolFlags::TYPE - (SymbolFlags::INTERFACE | SymbolFlags::CLASS);
+    }
+    if flags.contains(SymbolFlags::REGULAR_ENUM) {
+        excludes |= (SymbolFlags::VALUE | SymbolFlags::TYPE) - (SymbolFlags::REGULAR_ENUM | SymbolFlags::VALUE_MODULE);
+    }
+    if flags.contains(SymbolFlags::CONST_ENUM) {
+        excludes |= (SymbolFlags::VALUE | SymbolFlags::TYPE) - SymbolFlags::CONST_ENUM;
+    }
+    if flags.contains(SymbolFlags::VALUE_MODULE) {
+        excludes |= SymbolFlags::VALUE
+            - (SymbolFlags::FUNCTION | SymbolFlags::CLASS | SymbolFlags::REGULAR_ENUM | SymbolFlags::VALUE_MODULE);
+    }
+    if flags.contains(SymbolFlags::TYPE_ALIAS) {
+        excludes |= SymbolFlags::TYPE;
+    }
+    if flags.contains(SymbolFlags::ALIAS) {
+        excludes |= SymbolFlags::ALIAS;
+    }
+    excludes
+}
@@ -3,7 +3,7 @@
 //! Tests the parse -> bind pipeline and verifies symbol creation.
 
 use bumpalo::Bump;
-use rscript_binder::Binder;
+use rscript_binder::{Binder, LocalDeclarationKind};
 use rscript_parser::Parser;
 
 /// Helper: parse and bind source, return the number of symbols created.
@@ -1143,3 +1143,222 @@ fn test_bind_for_in_variable() {
     let count = bind_and_count_symbols("for (const key in obj) { }");
     assert!(count >= 1);
 }
+
+// ============================================================================
+// References and reachability
+// ============================================================================
+
+/// Helper: the number of references to each top-level name.
+fn reference_count(binder: &Binder, name: &str) -> usize {
+    let id = binder.resolve_name(name).unwrap_or_else(|| panic!("{} should be resolvable", name));
+    binder.get_symbol(id).unwrap().references.len()
+}
+
+#[test]
+fn test_references_are_tracked_per_symbol() {
+    let src = r#"
+        let a = 1;
+        function f() { return a + later; }
+        let later = 2;
+        let written;
+        written = 3;
+        type T = { value: typeof a };
+        const obj = { f };
+        let t: T;
+    "#;
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, "test.ts", src);
+    let sf = parser.parse_source_file();
+    let mut binder = Binder::new();
+    binder.bind_source_file(&sf);
+    assert_eq!(reference_count(&binder, "a"), 2);
+    assert_eq!(reference_count(&binder, "later"), 1, "forward references resolve once declared");
+    assert_eq!(reference_count(&binder, "written"), 0, "assignment targets are not reads");
+    assert_eq!(reference_count(&binder, "f"), 1, "shorthand properties read their name");
+    assert_eq!(reference_count(&binder, "T"), 1);
+    assert_eq!(reference_count(&binder, "obj"), 0);
+}
+
+#[test]
+fn test_references_distinguish_values_from_types() {
+    let src = r#"
+        import { Value, Type, Query, Contract, Shape, Later, Reexported, TypeReexported } from "./m";
+        Value();
+        let t: Type;
+        let q: typeof Query;
+        class C implements Contract {}
+        interface I extends Shape {}
+        function f() { return Later; }
+        export { Reexported, type TypeReexported };
+    "#;
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, "test.ts", src);
+    let sf = parser.parse_source_file();
+    let mut binder = Binder::new();
+    binder.bind_source_file(&sf);
+    let is_referenced_as_value = |name: &str| {
+        let id = binder.resolve_name(name).unwrap();
+        binder.get_symbol(id).unwrap().is_referenced_as_value
+    };
+    for name in ["Value", "Later", "Reexported"] {
+        assert!(is_referenced_as_value(name), "{name} is read as a value");
+    }
+    for name in ["Type", "Query", "Contract", "Shape", "TypeReexported"] {
+        assert!(!is_referenced_as_value(name), "{name} is only named as a type");
+    }
+}
+
+#[test]
+fn test_local_declarations_exclude_exports_and_script_globals() {
+    let src = r#"
+        import { a } from "./a";
+        export const b = 1;
+        const c = 2;
+        export function f(p: number) { const d = p; }
+        declare const e: number;
+    "#;
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, "test.ts", src);
+    let sf = parser.parse_source_file();
+    let mut binder = Binder::new();
+    binder.bind_source_file(&sf);
+    let locals: Vec<(String, LocalDeclarationKind)> = binder.local_declarations().iter()
+        .map(|d| (binder.get_symbol(d.symbol).unwrap().name_text.clone(), d.kind))
+        .collect();
+    assert_eq!(locals, vec![
+        ("a".to_string(), LocalDeclarationKind::Import),
+        ("c".to_string(), LocalDeclarationKind::Variable),
+        ("p".to_string(), LocalDeclarationKind::Parameter),
+        ("d".to_string(), LocalDeclarationKind::Variable),
+    ]);
+
+    // A script's top-level declarations are globals.
+    let parser = Parser::new(&arena, "test.ts", "const g = 1; function h() { let i = 0; }");
+    let sf = parser.parse_source_file();
+    let mut binder = Binder::new();
+    binder.bind_source_file(&sf);
+    assert_eq!(binder.local_declarations().len(), 1);
+}
+
+#[test]
+fn test_function_end_reachability() {
+    let src = r#"
+        function returns(x: boolean) { if (x) { return 1; } else { return 2; } }
+        function falls(x: boolean) { if (x) { return 1; } }
+        function throws() { throw new Error(); }
+        function loops() { while (true) { } }
+        function breaks() { while (true) { break; } }
+        function labeled() { outer: for (;;) { for (;;) { break outer; } } }
+        function tried() { try { return 1; } catch (e) { } }
+        function finalized() { try { return 1; } finally { } }
+        function switched(x: number) { switch (x) { case 0: return 1; default: return 2; } }
+    "#;
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, "test.ts", src);
+    let sf = parser.parse_source_file();
+    let mut binder = Binder::new();
+    binder.bind_source_file(&sf);
+    let reachable: Vec<bool> = sf.statements.iter()
+        .map(|s| binder.function_flow(s.data().range).expect("function flow").end_reachable)
+        .collect();
+    assert_eq!(reachable, vec![false, true, false, false, true, true, true, false, false]);
+    let falls = binder.function_flow(sf.statements[1].data().range).unwrap();
+    assert!(falls.has_return_value && !falls.has_empty_return);
+}
+
+#[test]
+fn test_switch_fallthrough_clauses() {
+    use rscript_ast::node::{CaseOrDefaultClause, Statement};
+
+    let src = "switch (x) { case 0: case 1: f(); case 2: f(); break; case 3: f(); }";
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, "test.ts", src);
+    let sf = parser.parse_source_file();
+    let mut binder = Binder::new();
+    binder.bind_source_file(&sf);
+    let Statement::SwitchStatement(switch) = &sf.statements[0] else {
+        panic!("expected switch statement, got {:?}", sf.statements[0]);
+    };
+    let fallthrough: Vec<bool> = switch.case_block.clauses.iter()
+        .map(|clause| match clause {
+            CaseOrDefaultClause::CaseClause(c) => binder.is_fallthrough_clause(c.data.range),
+            CaseOrDefaultClause::DefaultClause(d) => binder.is_fallthrough_clause(d.data.range),
+        })
+        .collect();
+    assert_eq!(fallthrough, vec![false, true, false, false]);
+}
+
+#[test]
+fn test_statement_flows_and_unused_labels() {
+    use rscript_ast::node::Statement;
+    use rscript_binder::FlowNodeKind;
+
+    let src = "f(); return; g(); var v; h(); used: while (x) { break used; } unused: h();";
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, "test.ts", src);
+    let sf = parser.parse_source_file();
+    let mut binder = Binder::new();
+    binder.bind_source_file(&sf);
+    let reachable: Vec<Option<bool>> = sf.statements.iter()
+        .map(|s| binder.statement_flow(s.data().range))
+        .map(|flow| flow.map(|f| binder.flow_nodes()[f.flow as usize].kind != FlowNodeKind::Unreachable))
+        .collect();
+    // `var v;` runs no code and has no recorded flow
+    assert_eq!(reachable, vec![Some(true), Some(true), Some(false), None, Some(false), Some(false), Some(false)]);
+    let Statement::ExpressionStatement(call) = &sf.statements[0] else {
+        panic!("expected expression statement, got {:?}", sf.statements[0]);
+    };
+    let after_call = binder.statement_flow(sf.statements[1].data().range).unwrap().flow;
+    assert_eq!(binder.flow_call(after_call), Some(call.expression.data().range));
+    assert_eq!(binder.unused_labels().len(), 1);
+}
+
+#[test]
+fn test_declaration_merging_and_conflicts() {
+    let src = "interface I { a: string } interface I { b: number }
+        function f() {} namespace f { export const x = 1; }
+        class C {} interface C {} namespace C { export const y = 1; }
+        enum E { A } enum E { B = 1 } namespace E { export const z = 1; }
+        namespace Types { export interface T {} }
+        let x = 1; let x = 2; var x;
+        class D {} function D() {} var D;
+        const enum K { A } enum K { B }
+        type T = string; interface T {}";
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, "test.ts", src);
+    let sf = parser.parse_source_file();
+    let mut binder = Binder::new();
+    binder.bind_source_file(&sf);
+
+    let declarations = |name: &str| binder.top_level_symbols().iter()
+        .find(|symbol| symbol.name_text == name)
+        .map_or(0, |symbol| symbol.declarations.len());
+    assert_eq!(declarations("I"), 2);
+    assert_eq!(declarations("f"), 2);
+    assert_eq!(declarations("C"), 3);
+    assert_eq!(declarations("E"), 3);
+    assert_eq!(declarations("D"), 2);
+    let types = binder.top_level_symbols().into_iter().find(|symbol| symbol.name_text == "Types").unwrap();
+    assert!(types.flags.contains(rscript_ast::types::SymbolFlags::NAMESPACE_MODULE));
+
+    // One diagnostic per declaration, at its name.
+    let messages: Vec<String> = binder.take_diagnostics().into_diagnostics().into_iter()
+        .map(|d| {
+            let span = d.span.expect("conflicts are reported at a declaration name");
+            format!("{}@{}: {}", d.code, &src[span.start as usize..span.end() as usize], d.message_text)
+        })
+        .collect();
+    // Hoisting declares `function D` and `var D` first.
+    assert_eq!(messages, vec![
+        "2300@D: Duplicate identifier 'D'.",
+        "2300@D: Duplicate identifier 'D'.",
+        "2451@x: Cannot redeclare block-scoped variable 'x'.",
+        "2451@x: Cannot redeclare block-scoped variable 'x'.",
+        "2451@x: Cannot redeclare block-scoped variable 'x'.",
+        "2300@D: Duplicate identifier 'D'.",
+        "2300@K: Duplicate identifier 'K'.",
+        "2300@K: Duplicate identifier 'K'.",
+        "2300@T: Duplicate identifier 'T'.",
+        "2300@T: Duplicate identifier 'T'.",
+    ]);
+}
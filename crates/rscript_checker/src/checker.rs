@@ -4,19 +4,48 @@
 //! It implements type resolution, structural type checking, call resolution,
 //! generic instantiation, assignment checking, and basic type narrowing.
 
-use crate::types::{TypeTable, TypeKind, Signature, SignatureParameter, IndexInfo};
+use crate::types::{
+    TypeTable, TypeKind, Signature, SignatureKind, SignatureParameter, IndexInfo, ElementFlags, MappedTypeModifier,
+    PropertyFlags, StringMappingKind, TypeFormatFlags, TypePredicate, TypePredicateKind,
+};
 use indexmap::IndexMap;
 use rscript_ast::node::*;
 use rscript_ast::syntax_kind::SyntaxKind;
 use rscript_ast::types::*;
-use rscript_binder::Binder;
-use rscript_diagnostics::{DiagnosticCollection, Diagnostic, messages};
+use rscript_binder::{is_instantiated_module, Binder, FlowNodeKind, LocalDeclarationKind, Symbol};
+use rscript_core::text::{TextRange, TextSpan};
+use rscript_diagnostics::{DiagnosticCategory, DiagnosticCollection, Diagnostic, DiagnosticMessageChain, messages};
+use rscript_module::{match_pattern_or_exact, resolve_program_file};
+use rscript_nodebuilder::NodeBuilder;
+use rscript_tsoptions::{CompilerOptions, JsxEmit, ModuleKind, ScriptTarget};
 use rustc_hash::FxHashSet;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+mod node_builder;
 
 /// Maximum recursion depth for type stringification to prevent stack overflow.
 const MAX_TYPE_TO_STRING_DEPTH: u32 = 20;
 
+/// Maximum nesting of type instantiations before giving up on a type.
+const MAX_INSTANTIATION_DEPTH: u32 = 100;
+
+/// Maximum number of times a conditional type may evaluate to another
+/// instantiation of a conditional alias in place of itself.
+const MAX_TAIL_RECURSION_DEPTH: u32 = 1000;
+
+/// Maximum nesting of type relations, reached comparing ever-expanding
+/// recursive types.
+const MAX_RELATION_DEPTH: u32 = 100;
+
+/// Maximum number of type instantiations performed for one statement.
+const MAX_INSTANTIATION_COUNT: u32 = 5_000_000;
+
+/// Maximum number of string literals a template literal type may expand to.
+const MAX_TEMPLATE_LITERAL_EXPANSION: usize = 100_000;
+
+/// Maximum nesting of elaborations under an assignability error.
+const MAX_ELABORATION_DEPTH: u32 = 10;
+
 /// The type checker resolves types and reports type errors.
 pub struct Checker {
     /// The type table (type arena).
@@ -31,19 +60,729 @@ pub struct Checker {
     no_implicit_any: bool,
     /// Whether strict function types (contravariant parameter checking) is enabled.
     strict_function_types: bool,
+    /// Whether `call`, `apply` and `bind` are typed by the function they are
+    /// invoked on (strictBindCallApply).
+    strict_bind_call_apply: bool,
+    /// Whether an implicitly `any` typed `this` is an error (noImplicitThis).
+    no_implicit_this: bool,
+    /// Whether catch clause variables are `unknown` rather than `any`
+    /// (useUnknownInCatchVariables).
+    use_unknown_in_catch_variables: bool,
+    /// Whether every file is checked as strict mode code (alwaysStrict).
+    always_strict: bool,
+    /// Whether the code being checked is strict mode code.
+    in_strict_mode: bool,
+    /// Whether the current function body has no declared `this`: it belongs to
+    /// a function declaration or expression without a `this` parameter.
+    this_is_implicit: bool,
+    /// The polymorphic `this` type of the class or interface whose members
+    /// are being resolved: a type parameter constrained to its instance type.
+    current_this_type: Option<TypeId>,
+    /// The polymorphic `this` types a class or interface type's members may
+    /// refer to, its own first and then those of its bases. Each is
+    /// instantiated with the object a member is accessed on.
+    this_type_parameters: HashMap<TypeId, Vec<TypeId>>,
+    /// `ThisType<T>` marker types, mapped to `T`.
+    this_type_markers: HashMap<TypeId, TypeId>,
+    /// The type of `this` in the methods of the object literal being
+    /// checked, from a `ThisType<T>` in its contextual type.
+    contextual_this_type: Option<TypeId>,
+    /// The object the most recently checked property access read from.
+    last_property_access_receiver: Option<TypeId>,
+    /// The `this` argument of the call whose signatures are being resolved.
+    call_this_argument: Option<TypeId>,
     /// Map of declared identifier names to their resolved types.
     /// Populated during checking as declarations are encountered.
     declared_types: HashMap<String, TypeId>,
     /// RegExp object type (lazily created).
     regexp_type: Option<TypeId>,
-    /// Memoization cache for type assignability checks.
+    /// Memoization cache for type relation checks.
     /// Prevents infinite recursion on circular types and gives O(1) for
-    /// repeated checks on the same (source, target) pair.
-    assignability_cache: HashMap<(TypeId, TypeId), bool>,
-    /// Type guard functions: function_name → (parameter_name, guard_type).
-    /// When a call to this function is used in a condition, the parameter
-    /// is narrowed to the guard type in the then-branch.
-    type_guards: HashMap<String, (String, TypeId)>,
+    /// repeated checks on the same (source, target, relation) triple.
+    relation_cache: HashMap<(TypeId, TypeId, TypeRelation), bool>,
+    /// The nesting of type relations being checked.
+    relation_depth: u32,
+    /// Whether a relation being checked compared types nested deeper than
+    /// `MAX_RELATION_DEPTH`.
+    relation_depth_exceeded: bool,
+    /// Type predicates of the signatures calls resolved to, by call range.
+    /// A guard call in a condition narrows its argument in the branches; an
+    /// assertion call narrows it for the rest of the block.
+    call_type_predicates: HashMap<TextRange, TypePredicate>,
+    /// The type predicate of the signature `resolve_call` last chose.
+    resolved_type_predicate: Option<TypePredicate>,
+    /// The signature `resolve_call` last chose, or its only candidate when
+    /// none applies.
+    resolved_signature: Option<Signature>,
+    /// The signatures calls and `new` expressions resolved to, by range.
+    resolved_signatures: HashMap<TextRange, Signature>,
+    /// The type of each expression checked, by range. A property access
+    /// also records its type at the range of the property name, and a
+    /// declaration its declared type at the range of its name.
+    node_types: HashMap<TextRange, TypeId>,
+    /// The contextual type of each expression checked against one, by range.
+    contextual_types: HashMap<TextRange, TypeId>,
+    /// Declared types replaced by assertion calls, with the types they
+    /// replaced, restored when the enclosing block ends.
+    assertion_narrowings: Vec<(String, TypeId)>,
+    /// The signature a function expression or arrow function about to be
+    /// checked is contextually typed by, typing its unannotated parameters.
+    contextual_signature: Option<Signature>,
+    /// Type parameters of generic type aliases and interfaces, by declared name.
+    /// References with type arguments instantiate the declared type over these.
+    generic_type_parameters: HashMap<String, Vec<TypeId>>,
+    /// The generic interfaces of the standard library (`Iterable<T>`,
+    /// `Generator<T, TReturn, TNext>`, `Promise<T>`, ...), with their type
+    /// parameters.
+    global_generic_types: HashMap<&'static str, (TypeId, Vec<TypeId>)>,
+    /// Instantiations of the standard library's generic interfaces, by name
+    /// and type arguments.
+    global_type_instantiations: HashMap<(&'static str, Vec<TypeId>), TypeId>,
+    /// The standard library interface and type arguments each of those
+    /// instantiations was created from, which is how it is displayed.
+    global_type_references: HashMap<TypeId, (&'static str, Vec<TypeId>)>,
+    /// Type aliases declared so far, by name, including those hoisted from
+    /// later in the file whose types are not resolved yet.
+    type_aliases: HashMap<String, TypeAliasInfo>,
+    /// The type alias and type arguments each aliased type was resolved or
+    /// instantiated from, which is how it is displayed. Deferred references
+    /// to aliases being resolved are aliased types too.
+    type_alias_references: HashMap<TypeId, (String, Vec<TypeId>)>,
+    /// The interface, and type arguments of a generic one, each interface
+    /// type and instantiation was created from, which is how it is displayed.
+    interface_type_references: HashMap<TypeId, (String, Vec<TypeId>)>,
+    /// Instantiations of generic type aliases, by name and type arguments.
+    type_alias_instantiations: HashMap<(String, Vec<TypeId>), TypeId>,
+    /// The type alias instantiations whose creation hit an instantiation
+    /// limit. Those created while declaring globals, whose diagnostics are
+    /// dropped, are created again by the check of the file using them.
+    excessive_type_alias_instantiations: FxHashSet<(String, Vec<TypeId>)>,
+    /// Instantiations of type aliases whose creation was deferred, created
+    /// when their members are accessed.
+    deferred_type_alias_instantiations: FxHashSet<TypeId>,
+    /// The type aliases being instantiated, innermost last, with the
+    /// `member_instantiation_depth` each instantiation started at.
+    type_alias_instantiation_stack: Vec<(String, u32)>,
+    /// How many object types' members are being instantiated.
+    member_instantiation_depth: u32,
+    /// The type parameters of the declarations being checked, outermost first.
+    type_parameters_in_scope: Vec<TypeId>,
+    /// The generator functions whose bodies are being checked, innermost last.
+    generator_contexts: Vec<GeneratorContext>,
+    /// Current nesting depth of `substitute_type_by_id`.
+    instantiation_depth: u32,
+    /// Instantiations performed for the current statement.
+    instantiation_count: u32,
+    /// Whether the current statement hit an instantiation limit (TS2589).
+    instantiation_limit_reported: bool,
+    /// How many times an instantiation limit was hit, reported or not.
+    instantiation_limit_hits: u32,
+    /// Regular (non-fresh) counterparts of fresh object literal and literal types.
+    regular_types: HashMap<TypeId, TypeId>,
+    /// Regular string, number and bigint literal types, interned by value.
+    literal_types: HashMap<LiteralKey, TypeId>,
+    /// Fresh counterparts of regular literal types. A literal expression has
+    /// the fresh type, which widens at mutable locations.
+    fresh_literal_types: HashMap<TypeId, TypeId>,
+    /// Overload signatures of the function currently being declared, by name,
+    /// keyed by the file and range of their declarations so that declaring
+    /// one again does not add it twice. Cleared when the implementation (the
+    /// declaration with a body) is seen.
+    overload_signatures: HashMap<String, IndexMap<(String, TextRange), Signature>>,
+    /// Whether class properties must be initialized (strictPropertyInitialization).
+    strict_property_initialization: bool,
+    /// Whether members overriding a base class member must be marked `override`.
+    no_implicit_override: bool,
+    /// Whether `keyof` yields only string keys (keyofStringsOnly).
+    keyof_strings_only: bool,
+    /// Whether reads through index signatures include `undefined`
+    /// (noUncheckedIndexedAccess).
+    no_unchecked_indexed_access: bool,
+    /// Whether optional properties reject an explicit `undefined`
+    /// (exactOptionalPropertyTypes).
+    exact_optional_property_types: bool,
+    /// Whether unreferenced local declarations are reported (noUnusedLocals).
+    no_unused_locals: bool,
+    /// Whether unreferenced parameters are reported (noUnusedParameters).
+    no_unused_parameters: bool,
+    /// Whether functions that return a value on only some paths are
+    /// reported (noImplicitReturns).
+    no_implicit_returns: bool,
+    /// Whether non-empty switch clauses that fall through are reported
+    /// (noFallthroughCasesInSwitch).
+    no_fallthrough_cases_in_switch: bool,
+    /// Whether unreachable code is allowed (allowUnreachableCode). Unset
+    /// reports it as a suggestion, `false` as an error.
+    allow_unreachable_code: Option<bool>,
+    /// Whether unused labels are allowed (allowUnusedLabels). Unset reports
+    /// them as suggestions, `false` as errors.
+    allow_unused_labels: Option<bool>,
+    /// The language version code is checked for (target). Below ES2015 only
+    /// arrays, and strings in `for...of`, can be iterated.
+    language_version: ScriptTarget,
+    /// Whether iterables can be iterated below ES2015 (downlevelIteration).
+    downlevel_iteration: bool,
+    /// Whether every file must be transpilable on its own, without type
+    /// information from other files (isolatedModules).
+    isolated_modules: bool,
+    /// Whether imports and exports are emitted as written, so types must be
+    /// imported with `import type` (verbatimModuleSyntax). Implies
+    /// `isolated_modules`.
+    verbatim_module_syntax: bool,
+    /// Whether modules are emitted as CommonJS (module: CommonJS).
+    commonjs_module: bool,
+    /// Whether types in diagnostics are written in full rather than with
+    /// members elided past a length (noErrorTruncation).
+    no_error_truncation: bool,
+    /// How JSX is emitted, or `None` when JSX is not allowed (jsx).
+    jsx: Option<JsxEmit>,
+    /// The module whose `jsx-runtime` declares the `JSX` namespace under the
+    /// automatic runtime (jsxImportSource).
+    jsx_import_source: Option<String>,
+    /// Whether the declarations being checked are ambient: those of a
+    /// declaration file or of an ambient module or namespace.
+    in_ambient_context: bool,
+    /// The enum objects of ambient const enums, whose members a single-file
+    /// transpiler cannot inline.
+    ambient_const_enum_objects: FxHashSet<TypeId>,
+    /// Import bindings whose module exports them only as types.
+    type_only_imports: FxHashSet<String>,
+    /// Names the checked file re-exports that denote only types, without
+    /// marking them `type`.
+    type_only_exports: FxHashSet<String>,
+    /// Call expression statements that never return: calls of functions
+    /// returning `never`, and assertion calls passed `false`.
+    never_calls: FxHashSet<TextRange>,
+    /// Memoized reachability of flow nodes, accounting for `never_calls`.
+    flow_reachability: HashMap<u32, bool>,
+    /// Unreachable statements already reported.
+    reported_unreachable: FxHashSet<TextRange>,
+    /// Set while checking the left side of `=`, where property and element
+    /// accesses denote the declared (write) type of the property.
+    is_assignment_target: bool,
+    /// Set while checking the target of a compound assignment or of
+    /// `++`/`--`, which reads the property before writing it.
+    is_compound_assignment_target: bool,
+    /// The object type and property key the current assignment target
+    /// resolved to.
+    assignment_target_property: Option<(TypeId, String)>,
+    /// Classes declared so far, by name.
+    classes: HashMap<String, ClassInfo>,
+    /// The class owning each instance type and constructor type, and whether
+    /// the type is the static side.
+    class_of_type: HashMap<TypeId, (String, bool)>,
+    /// Names of the classes whose bodies are being checked, innermost last.
+    enclosing_classes: Vec<String>,
+    /// Inside the constructor of a derived class: whether `super()` has been
+    /// called yet.
+    super_call_state: Option<bool>,
+    /// The class whose constructor body is being checked, where its own
+    /// readonly properties may be assigned through `this`.
+    constructor_class: Option<String>,
+    /// The union of member literal types of each enum, by enum name.
+    enum_types: HashMap<String, TypeId>,
+    /// The enum type each enum literal type (regular or fresh) belongs to.
+    enum_literal_bases: HashMap<TypeId, TypeId>,
+    /// Display names of enum types, enum literal types and enum objects.
+    enum_type_names: HashMap<TypeId, String>,
+    /// `unique symbol` types by the property key they denote (`[name]`).
+    unique_symbols: HashMap<String, TypeId>,
+    /// The value of each namespace declared so far, by name: its own object
+    /// type, or the class, function or enum type it merged into.
+    namespaces: HashMap<String, TypeId>,
+    /// Names of the namespaces whose bodies are being checked, outermost
+    /// first.
+    enclosing_namespaces: Vec<String>,
+    /// The namespaces each type declared in a namespace is declared in, by
+    /// the type's name, e.g. `N.M` for `C` in `namespace N.M { class C {} }`.
+    declaration_namespaces: HashMap<String, String>,
+    /// The interfaces and aliases declared in the enclosing namespaces, by
+    /// name, mapped to the qualified names they are registered by, e.g.
+    /// `Foo` to `N.Foo` in `namespace N { interface Foo {} }`.
+    namespace_type_names: HashMap<String, String>,
+    /// The interface declarations merged so far, by file and range.
+    merged_interface_declarations: FxHashSet<(String, TextRange)>,
+    /// Builds and prints the type nodes types are displayed as.
+    node_builder: NodeBuilder,
+    /// Ambient modules declared so far, by their name or wildcard pattern.
+    ambient_modules: IndexMap<String, AmbientModule>,
+    /// The module files of the program declared so far, by file name.
+    program_modules: HashMap<String, ProgramModule>,
+    /// The file being checked, for diagnostic locations.
+    file_name: String,
+    /// The innermost statement or expression being checked; diagnostics are
+    /// reported at its range.
+    current_node_range: Option<TextRange>,
+}
+
+/// The value identifying an interned literal type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum LiteralKey {
+    String(String),
+    /// The bits of the `f64` value.
+    Number(u64),
+}
+
+/// The exports of an ambient module (`declare module "name"`), merged
+/// across its declarations and augmentations.
+#[derive(Clone, Default)]
+struct AmbientModule {
+    /// Exported values by name; `default` and `export=` for default exports.
+    values: IndexMap<String, TypeId>,
+    /// Exported interfaces and type aliases, with their type parameters.
+    types: IndexMap<String, (TypeId, Vec<TypeId>)>,
+    /// Whether the module was declared without a body, so that everything
+    /// imported from it is `any`.
+    is_shorthand: bool,
+}
+
+/// The exports of a module file of the program: the names that denote only
+/// types, told from those that denote values, and once the module is
+/// checked, what each denotes.
+#[derive(Clone, Default)]
+struct ProgramModule {
+    /// Names the module's own declarations export, and whether each denotes
+    /// only a type.
+    exports: HashMap<String, bool>,
+    /// The local names of the module's own exports, by exported name.
+    local_names: HashMap<String, String>,
+    /// What the module's own exports denote, by exported name. Empty until
+    /// the module is checked.
+    checked_exports: HashMap<String, ProgramModuleExport>,
+    /// Names re-exported from other modules: the module specifier and the
+    /// name exported there.
+    re_exports: HashMap<String, (String, String)>,
+    /// The module specifiers of `export * from` declarations.
+    star_exports: Vec<String>,
+}
+
+/// What an export of a checked module file of the program denotes, bound
+/// to the names that import it.
+#[derive(Clone, Default)]
+struct ProgramModuleExport {
+    /// The type of the exported value.
+    value: Option<TypeId>,
+    /// The exported interface or type alias, with its type parameters.
+    declared_type: Option<(TypeId, Vec<TypeId>)>,
+    /// The exported type alias, instantiated by name.
+    type_alias: Option<TypeAliasInfo>,
+    /// The exported class, whose name denotes its instance type.
+    class: Option<ClassInfo>,
+    /// The union of the exported enum's members, which its name denotes.
+    enum_type: Option<TypeId>,
+}
+
+/// What checking a file of the program found: its diagnostics, and the
+/// imports and re-exports that JavaScript emit elides.
+pub struct FileCheckResult {
+    pub diagnostics: DiagnosticCollection,
+    pub elided_import_names: HashSet<String>,
+    pub elided_export_names: HashSet<String>,
+}
+
+/// What checking a file records by node range, with the file's
+/// diagnostics. Each file of the program starts without any.
+#[derive(Default)]
+struct FileCheckState {
+    file_name: String,
+    diagnostics: DiagnosticCollection,
+    flow_reachability: HashMap<u32, bool>,
+    never_calls: FxHashSet<TextRange>,
+    reported_unreachable: FxHashSet<TextRange>,
+    call_type_predicates: HashMap<TextRange, TypePredicate>,
+    resolved_signatures: HashMap<TextRange, Signature>,
+    node_types: HashMap<TextRange, TypeId>,
+    contextual_types: HashMap<TextRange, TypeId>,
+}
+
+/// The declarations in scope by name before a file of the program is
+/// checked: the program's globals. They are restored afterwards, so a
+/// module's own declarations stay out of the files checked after it.
+struct GlobalDeclarations {
+    declared_types: HashMap<String, TypeId>,
+    generic_type_parameters: HashMap<String, Vec<TypeId>>,
+    type_aliases: HashMap<String, TypeAliasInfo>,
+    type_alias_instantiations: HashMap<(String, Vec<TypeId>), TypeId>,
+    overload_signatures: HashMap<String, IndexMap<(String, TextRange), Signature>>,
+    classes: HashMap<String, ClassInfo>,
+    enum_types: HashMap<String, TypeId>,
+    unique_symbols: HashMap<String, TypeId>,
+    namespaces: HashMap<String, TypeId>,
+    declaration_namespaces: HashMap<String, String>,
+    namespace_type_names: HashMap<String, String>,
+    type_only_imports: FxHashSet<String>,
+    type_only_exports: FxHashSet<String>,
+}
+
+/// Whether a statement is an ambient module declaration (`declare module
+/// "name"`) or a global augmentation (`declare global`).
+fn is_global_augmentation_or_ambient_module(stmt: &Statement<'_>) -> bool {
+    matches!(stmt, Statement::ModuleDeclaration(n)
+        if n.data.flags.contains(NodeFlags::GLOBAL_AUGMENTATION) || matches!(n.name, ModuleName::StringLiteral(_)))
+}
+
+/// Declaration-level facts about a class that its structural types do not
+/// carry: the base class, abstractness and member modifiers.
+#[derive(Clone)]
+struct ClassInfo {
+    /// Whether the class has an `extends` clause, even one naming no known class.
+    is_derived: bool,
+    base_class: Option<String>,
+    is_abstract: bool,
+    instance_type: TypeId,
+    static_type: TypeId,
+    /// Modifiers of the instance members declared in this class.
+    instance_members: IndexMap<String, ModifierFlags>,
+    /// Modifiers of the static members declared in this class.
+    static_members: IndexMap<String, ModifierFlags>,
+}
+
+impl ClassInfo {
+    fn member_flags(&self, name: &str, is_static: bool) -> Option<ModifierFlags> {
+        let members = if is_static { &self.static_members } else { &self.instance_members };
+        members.get(name).copied()
+    }
+
+    /// Names of the members declared in this class with any of the given modifiers.
+    fn members_with(&self, flags: ModifierFlags) -> impl Iterator<Item = &String> {
+        self.instance_members.iter()
+            .chain(self.static_members.iter())
+            .filter(move |(_, member_flags)| member_flags.intersects(flags))
+            .map(|(name, _)| name)
+    }
+}
+
+/// A type alias declaration: the type it stands for and its type parameters.
+#[derive(Clone)]
+struct TypeAliasInfo {
+    /// The resolved type, or the placeholder references resolve to until it is.
+    declared_type: TypeId,
+    /// The type parameters of the enclosing declarations the alias may refer
+    /// to, followed by its own. Instantiations are keyed by arguments for all.
+    type_parameters: Vec<TypeId>,
+    /// How many of `type_parameters` belong to enclosing declarations.
+    outer_type_parameter_count: usize,
+    state: TypeAliasState,
+    /// References with type arguments made before the alias was resolved,
+    /// instantiated once it is.
+    deferred_references: Vec<TypeId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeAliasState {
+    /// Hoisted: references resolve to its placeholder.
+    Declared,
+    /// Its type is being resolved; references in it are self-references.
+    Resolving,
+    Resolved,
+}
+
+/// The members collected for one side (instance or static) of a class.
+#[derive(Default)]
+struct ClassSide {
+    members: IndexMap<String, TypeId>,
+    /// Modifiers of the members declared in the class itself.
+    modifiers: IndexMap<String, ModifierFlags>,
+    property_flags: IndexMap<String, PropertyFlags>,
+    /// Types accepted by accessor properties whose setter takes a type other
+    /// than the getter returns.
+    write_types: IndexMap<String, TypeId>,
+    /// The accessors declared for each accessor property.
+    accessors: IndexMap<String, AccessorTypes>,
+    index_infos: Vec<IndexInfo>,
+}
+
+impl ClassSide {
+    fn add(&mut self, name: String, type_id: TypeId, modifiers: ModifierFlags, flags: PropertyFlags) {
+        if !flags.is_empty() {
+            self.property_flags.insert(name.clone(), flags);
+        }
+        self.modifiers.insert(name.clone(), modifiers);
+        self.members.insert(name, type_id);
+    }
+}
+
+/// The get and set accessors declared for a property, with the types their
+/// annotations give it.
+#[derive(Default, Clone, Copy)]
+struct AccessorTypes {
+    has_getter: bool,
+    has_setter: bool,
+    /// The getter's return type annotation.
+    getter_type: Option<TypeId>,
+    /// The setter's parameter type annotation.
+    setter_type: Option<TypeId>,
+    /// The type the getter's body returns, once checked.
+    body_type: Option<TypeId>,
+}
+
+impl AccessorTypes {
+    /// Whether the property's type is inferred from the getter's body: no
+    /// accessor annotates it.
+    fn is_inferred(&self) -> bool {
+        self.has_getter && self.getter_type.is_none() && self.setter_type.is_none()
+    }
+
+    /// The type the property is read as: the getter's, falling back to the
+    /// setter's annotation and then to what the getter returns.
+    fn read_type(&self) -> Option<TypeId> {
+        self.getter_type.or(self.setter_type).or(self.body_type)
+    }
+
+    /// The type the property accepts when written, if it differs from the
+    /// read type. TS 5.1 allows the two to be unrelated.
+    fn write_type(&self) -> Option<TypeId> {
+        self.setter_type.filter(|&t| Some(t) != self.read_type())
+    }
+
+    /// A property with only a getter cannot be written.
+    fn is_readonly(&self) -> bool {
+        self.has_getter && !self.has_setter
+    }
+}
+
+/// Orders member visibility: public, then protected, then private.
+/// The primitive kind a primitive or literal type belongs to, like
+/// `STRING_LIKE` for `string` and `"a"`.
+fn primitive_group(flags: TypeFlags) -> Option<TypeFlags> {
+    [TypeFlags::STRING_LIKE, TypeFlags::NUMBER_LIKE, TypeFlags::BIG_INT_LIKE, TypeFlags::BOOLEAN_LIKE, TypeFlags::ES_SYMBOL_LIKE]
+        .into_iter()
+        .find(|&group| flags.intersects(group))
+}
+
+fn accessibility_rank(flags: ModifierFlags) -> u8 {
+    if flags.contains(ModifierFlags::PRIVATE) {
+        2
+    } else if flags.contains(ModifierFlags::PROTECTED) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Whether a top-level statement of a constructor body assigns `this.<name>`.
+fn is_property_assigned_in(body: &Block<'_>, name: &str) -> bool {
+    body.statements.iter().any(|stmt| {
+        let Statement::ExpressionStatement(s) = stmt else {
+            return false;
+        };
+        let Expression::Binary(bin) = s.expression else {
+            return false;
+        };
+        if bin.operator_token.data.kind != SyntaxKind::EqualsToken {
+            return false;
+        }
+        match bin.left {
+            Expression::PropertyAccess(access) => {
+                matches!(access.expression, Expression::ThisKeyword(_))
+                    && matches!(&access.name, MemberName::Identifier(id) if id.text_name == name)
+            }
+            _ => false,
+        }
+    })
+}
+
+/// The numeric value of a numeric literal's source text, including hex,
+/// octal and binary literals and `_` separators.
+fn parse_numeric_literal_text(text: &str) -> f64 {
+    let text = text.replace('_', "");
+    let radix = match text.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0o" | "0O") => 8,
+        Some("0b" | "0B") => 2,
+        _ => return text.parse().unwrap_or(0.0),
+    };
+    u64::from_str_radix(&text[2..], radix).map_or(0.0, |v| v as f64)
+}
+
+/// Format a number the way JavaScript converts it to a string.
+/// Whether a property name is the canonical text of a number, like `0` or
+/// `1.5` but not `01` or `1e3`, so that `keyof` produces a number literal.
+fn is_numeric_literal_name(name: &str) -> bool {
+    name.parse::<f64>().is_ok_and(|value| number_to_string(value) == name)
+}
+
+/// Whether an expression is a call of the global `Symbol` function.
+/// Split off a leading `this` parameter, which types `this` in the body
+/// rather than declaring an argument.
+fn split_this_parameter<'p, 'a>(
+    parameters: &'p [ParameterDeclaration<'a>],
+) -> (Option<&'p ParameterDeclaration<'a>>, &'p [ParameterDeclaration<'a>]) {
+    match parameters.split_first() {
+        Some((first, rest))
+            if matches!(&first.name, BindingName::Identifier(id) if id.original_keyword_kind == Some(SyntaxKind::ThisKeyword)) =>
+        {
+            (Some(first), rest)
+        }
+        _ => (None, parameters),
+    }
+}
+
+/// The dotted text of an entity name, like `N.M.T`.
+fn entity_name_text(name: &EntityName<'_>) -> String {
+    match name {
+        EntityName::Identifier(id) => id.text_name.clone(),
+        EntityName::QualifiedName(q) => format!("{}.{}", entity_name_text(&q.left), q.right.text_name),
+    }
+}
+
+/// The dotted text of an expression naming an entity, like `N.M.C` in
+/// `class D extends N.M.C {}`.
+fn entity_name_expression_text(expr: &Expression<'_>) -> Option<String> {
+    match expr {
+        Expression::Identifier(id) => Some(id.text_name.clone()),
+        Expression::PropertyAccess(access) => match &access.name {
+            MemberName::Identifier(name) => Some(format!("{}.{}", entity_name_expression_text(access.expression)?, name.text_name)),
+            MemberName::PrivateIdentifier(_) => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_symbol_call(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::Parenthesized(p) => is_symbol_call(p.expression),
+        Expression::Call(call) => matches!(call.expression, Expression::Identifier(id) if id.text_name == "Symbol"),
+        _ => false,
+    }
+}
+
+fn number_to_string(value: f64) -> String {
+    if value.is_infinite() {
+        if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Whether `+text` is a finite number in JavaScript, which is what a
+/// `${number}` placeholder in a template literal type accepts.
+fn is_valid_number_string(text: &str) -> bool {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return false;
+    }
+    if let Some(digits) = trimmed.get(..2).and_then(|prefix| match prefix {
+        "0x" | "0X" => Some((16, &trimmed[2..])),
+        "0o" | "0O" => Some((8, &trimmed[2..])),
+        "0b" | "0B" => Some((2, &trimmed[2..])),
+        _ => None,
+    }) {
+        return u64::from_str_radix(digits.1, digits.0).is_ok();
+    }
+    // Rust also parses "inf" and "NaN", which JavaScript does not accept as numbers
+    trimmed.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+        && trimmed.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+/// Whether a string is an integer as accepted by a `${bigint}` placeholder.
+fn is_valid_bigint_string(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// ECMAScript ToInt32, for bitwise operators in enum initializers.
+fn to_int32(value: f64) -> i32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    value.trunc().rem_euclid(4_294_967_296.0) as u32 as i32
+}
+
+/// The constant value of an enum member.
+#[derive(Debug, Clone)]
+enum EnumValue {
+    Number(f64),
+    String(String),
+}
+
+/// The relation under which two types are compared. Overload resolution
+/// tries the stricter subtype relation before falling back to assignability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TypeRelation {
+    Subtype,
+    Assignable,
+}
+
+/// A call argument after spread expansion.
+#[derive(Debug, Clone, Copy)]
+enum CallArgument {
+    /// An argument bound to exactly one parameter.
+    Fixed(TypeId),
+    /// A spread of an array with the given element type, which may cover any
+    /// number of parameters.
+    Spread(TypeId),
+}
+
+/// A property of the object a JSX element passes as props: an attribute
+/// written on the element, located by its name, or the children, located by
+/// the tag name.
+struct JsxAttributeSite<'n> {
+    name: String,
+    range: TextRange,
+    value: Option<&'n Expression<'n>>,
+}
+
+/// The props object of a JSX element, built from its attributes and children.
+struct JsxAttributesType<'n> {
+    type_id: TypeId,
+    sites: Vec<JsxAttributeSite<'n>>,
+    /// Whether an attribute spreads `any`, which leaves the props unchecked.
+    is_any: bool,
+}
+
+/// How a value is iterated, which decides the types it may have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IterationUse {
+    /// `for (... of x)`, which iterates strings even below ES2015.
+    ForOf,
+    /// `for await (... of x)`, which iterates async iterables.
+    ForAwaitOf,
+    /// Spread elements and array destructuring.
+    Spread,
+    /// `yield*` in a generator.
+    YieldStar,
+    /// `yield*` in an async generator, which delegates to async iterables.
+    AsyncYieldStar,
+}
+
+impl IterationUse {
+    fn allows_async_iterables(self) -> bool {
+        matches!(self, IterationUse::ForAwaitOf | IterationUse::AsyncYieldStar)
+    }
+}
+
+/// The types an iterator produces: the values it yields, the value it
+/// returns when done, and the values its `next` method accepts.
+#[derive(Debug, Clone, Copy)]
+struct IterationTypes {
+    yield_type: TypeId,
+    return_type: TypeId,
+    next_type: TypeId,
+}
+
+/// A generator function whose body is being checked.
+struct GeneratorContext {
+    is_async: bool,
+    /// The iteration types of its declared return type, if annotated.
+    declared: Option<IterationTypes>,
+    /// The types of the values it yields, for an inferred return type.
+    yield_types: Vec<TypeId>,
+}
+
+/// The parameter list of a signature with any tuple-typed rest parameter
+/// expanded into positional parameters.
+struct EffectiveParameters {
+    types: Vec<TypeId>,
+    min_argument_count: usize,
+    /// Element type of a remaining array rest parameter.
+    rest: Option<TypeId>,
+}
+
+impl EffectiveParameters {
+    fn type_at(&self, index: usize) -> Option<TypeId> {
+        self.types.get(index).copied().or(self.rest)
+    }
 }
 
 impl Checker {
@@ -55,29 +794,145 @@ impl Checker {
             strict_null_checks: true,
             no_implicit_any: false,
             strict_function_types: true,
+            strict_bind_call_apply: true,
+            no_implicit_this: false,
+            use_unknown_in_catch_variables: true,
+            always_strict: false,
+            in_strict_mode: false,
+            this_is_implicit: false,
+            current_this_type: None,
+            this_type_parameters: HashMap::new(),
+            this_type_markers: HashMap::new(),
+            contextual_this_type: None,
+            last_property_access_receiver: None,
+            call_this_argument: None,
             declared_types: HashMap::new(),
             regexp_type: None,
-            assignability_cache: HashMap::new(),
-            type_guards: HashMap::new(),
+            relation_cache: HashMap::new(),
+            relation_depth: 0,
+            relation_depth_exceeded: false,
+            call_type_predicates: HashMap::new(),
+            resolved_type_predicate: None,
+            resolved_signature: None,
+            resolved_signatures: HashMap::new(),
+            node_types: HashMap::new(),
+            contextual_types: HashMap::new(),
+            assertion_narrowings: Vec::new(),
+            contextual_signature: None,
+            generic_type_parameters: HashMap::new(),
+            global_generic_types: HashMap::new(),
+            global_type_instantiations: HashMap::new(),
+            global_type_references: HashMap::new(),
+            type_aliases: HashMap::new(),
+            type_alias_references: HashMap::new(),
+            interface_type_references: HashMap::new(),
+            type_alias_instantiations: HashMap::new(),
+            excessive_type_alias_instantiations: FxHashSet::default(),
+            deferred_type_alias_instantiations: FxHashSet::default(),
+            type_alias_instantiation_stack: Vec::new(),
+            member_instantiation_depth: 0,
+            type_parameters_in_scope: Vec::new(),
+            generator_contexts: Vec::new(),
+            instantiation_depth: 0,
+            instantiation_count: 0,
+            instantiation_limit_reported: false,
+            instantiation_limit_hits: 0,
+            regular_types: HashMap::new(),
+            literal_types: HashMap::new(),
+            fresh_literal_types: HashMap::new(),
+            overload_signatures: HashMap::new(),
+            strict_property_initialization: true,
+            no_implicit_override: false,
+            keyof_strings_only: false,
+            no_unchecked_indexed_access: false,
+            exact_optional_property_types: false,
+            no_unused_locals: false,
+            no_unused_parameters: false,
+            no_implicit_returns: false,
+            no_fallthrough_cases_in_switch: false,
+            allow_unreachable_code: None,
+            allow_unused_labels: None,
+            language_version: ScriptTarget::ES5,
+            downlevel_iteration: false,
+            isolated_modules: false,
+            verbatim_module_syntax: false,
+            commonjs_module: false,
+            no_error_truncation: false,
+            jsx: None,
+            jsx_import_source: None,
+            in_ambient_context: false,
+            ambient_const_enum_objects: FxHashSet::default(),
+            type_only_imports: FxHashSet::default(),
+            type_only_exports: FxHashSet::default(),
+            never_calls: FxHashSet::default(),
+            flow_reachability: HashMap::new(),
+            reported_unreachable: FxHashSet::default(),
+            is_assignment_target: false,
+            is_compound_assignment_target: false,
+            assignment_target_property: None,
+            classes: HashMap::new(),
+            class_of_type: HashMap::new(),
+            enclosing_classes: Vec::new(),
+            super_call_state: None,
+            constructor_class: None,
+            enum_types: HashMap::new(),
+            namespaces: HashMap::new(),
+            enclosing_namespaces: Vec::new(),
+            declaration_namespaces: HashMap::new(),
+            namespace_type_names: HashMap::new(),
+            merged_interface_declarations: FxHashSet::default(),
+            node_builder: NodeBuilder::new(),
+            ambient_modules: IndexMap::new(),
+            program_modules: HashMap::new(),
+            file_name: String::new(),
+            current_node_range: None,
+            enum_literal_bases: HashMap::new(),
+            enum_type_names: HashMap::new(),
+            unique_symbols: HashMap::new(),
         };
         checker.register_globals();
         checker
     }
 
     pub fn with_options(binder: Binder, strict_null_checks: bool, no_implicit_any: bool) -> Self {
-        let mut checker = Self {
-            type_table: TypeTable::new(),
-            binder,
-            diagnostics: DiagnosticCollection::new(),
-            strict_null_checks,
-            no_implicit_any,
-            strict_function_types: true,
-            declared_types: HashMap::new(),
-            regexp_type: None,
-            assignability_cache: HashMap::new(),
-            type_guards: HashMap::new(),
-        };
-        checker.register_globals();
+        let mut checker = Self::new(binder);
+        checker.strict_null_checks = strict_null_checks;
+        checker.no_implicit_any = no_implicit_any;
+        checker.use_unknown_in_catch_variables = strict_null_checks;
+        checker.strict_property_initialization = strict_null_checks;
+        checker
+    }
+
+    /// Create a checker configured from the program's compiler options.
+    /// Members of the `strict` family left unset follow `strict`.
+    pub fn with_compiler_options(binder: Binder, options: &CompilerOptions) -> Self {
+        let mut checker = Self::new(binder);
+        checker.strict_null_checks = options.get_strict_option_value(options.strict_null_checks);
+        checker.no_implicit_any = options.get_strict_option_value(options.no_implicit_any);
+        checker.strict_function_types = options.get_strict_option_value(options.strict_function_types);
+        checker.strict_bind_call_apply = options.get_strict_option_value(options.strict_bind_call_apply);
+        checker.strict_property_initialization = options.get_strict_option_value(options.strict_property_initialization);
+        checker.no_implicit_this = options.get_strict_option_value(options.no_implicit_this);
+        checker.use_unknown_in_catch_variables = options.get_strict_option_value(options.use_unknown_in_catch_variables);
+        checker.always_strict = options.get_strict_option_value(options.always_strict);
+        checker.no_implicit_override = options.no_implicit_override.unwrap_or(false);
+        checker.keyof_strings_only = options.keyof_strings_only.unwrap_or(false);
+        checker.no_unchecked_indexed_access = options.no_unchecked_indexed_access.unwrap_or(false);
+        checker.exact_optional_property_types = options.exact_optional_property_types.unwrap_or(false);
+        checker.no_unused_locals = options.no_unused_locals.unwrap_or(false);
+        checker.no_unused_parameters = options.no_unused_parameters.unwrap_or(false);
+        checker.no_implicit_returns = options.no_implicit_returns.unwrap_or(false);
+        checker.no_fallthrough_cases_in_switch = options.no_fallthrough_cases_in_switch.unwrap_or(false);
+        checker.allow_unreachable_code = options.allow_unreachable_code;
+        checker.allow_unused_labels = options.allow_unused_labels;
+        checker.language_version = options.target.unwrap_or(ScriptTarget::ES5);
+        checker.downlevel_iteration = options.downlevel_iteration.unwrap_or(false);
+        checker.verbatim_module_syntax = options.get_verbatim_module_syntax();
+        checker.isolated_modules = checker.verbatim_module_syntax || options.isolated_modules.unwrap_or(false);
+        checker.commonjs_module = options.module == Some(ModuleKind::CommonJS);
+        checker.no_error_truncation = options.no_error_truncation.unwrap_or(false);
+        checker.jsx = options.jsx.filter(|&jsx| jsx != JsxEmit::None);
+        checker.jsx_import_source = options.jsx_import_source.clone();
         checker
     }
 
@@ -106,22 +961,8 @@ impl Checker {
         );
         self.register_type("Array", array_type);
 
-        // Promise<T> — simplified
-        let promise_type = self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::INTERFACE,
-                members: IndexMap::from([
-                    ("then".to_string(), self.type_table.any_type),
-                    ("catch".to_string(), self.type_table.any_type),
-                    ("finally".to_string(), self.type_table.any_type),
-                ]),
-                call_signatures: vec![],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
-        );
-        self.register_type("Promise", promise_type);
+        // Promise<T>, and the iterator and generator interfaces
+        self.register_iteration_types();
 
         // Map, Set, WeakMap, WeakSet — simplified as any
         for name in &["Map", "Set", "WeakMap", "WeakSet", "WeakRef"] {
@@ -149,6 +990,9 @@ impl Checker {
                     return_type: self.type_table.any_type, // placeholder, will be itself
                     min_argument_count: 0,
                     has_rest_parameter: false,
+                    this_type: None,
+                    is_method: false,
+                    type_predicate: None,
                 }],
                 index_infos: vec![],
             },
@@ -220,8 +1064,42 @@ impl Checker {
         // Object
         self.register_type("Object", self.type_table.any_type);
 
-        // Symbol, BigInt
-        self.register_type("Symbol", self.type_table.any_type);
+        // Symbol, with the well-known symbols as unique symbol members
+        let mut symbol_members = IndexMap::new();
+        for name in [
+            "asyncIterator", "hasInstance", "isConcatSpreadable", "iterator", "match", "matchAll",
+            "replace", "search", "species", "split", "toPrimitive", "toStringTag", "unscopables",
+        ] {
+            let unique_symbol = self.create_unique_symbol_type(&format!("Symbol.{}", name));
+            symbol_members.insert(name.to_string(), unique_symbol);
+        }
+        let symbol_description_type = self.create_union_type(vec![self.type_table.string_type, self.type_table.number_type]);
+        let symbol_constructor = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::INTERFACE,
+                members: symbol_members,
+                call_signatures: vec![Signature {
+                    type_parameters: vec![],
+                    parameters: vec![SignatureParameter {
+                        name: "description".to_string(),
+                        type_id: symbol_description_type,
+                        optional: true,
+                    }],
+                    return_type: self.type_table.symbol_type,
+                    min_argument_count: 0,
+                    has_rest_parameter: false,
+                    this_type: None,
+                    is_method: false,
+                    type_predicate: None,
+                }],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        );
+        self.register_type("Symbol", symbol_constructor);
+
+        // BigInt
         self.register_type("BigInt", self.type_table.any_type);
 
         // globalThis, window, self
@@ -249,6 +1127,255 @@ impl Checker {
         }
     }
 
+    /// Define the generic interfaces of the standard library that iteration,
+    /// generators and `await` are typed by, and the `Promise` constructor.
+    /// Instantiations are created eagerly, so members that would refer back
+    /// to the interface being defined are simplified: `then` returns `any`,
+    /// and `[Symbol.iterator]()` of an iterable iterator returns an `Iterator`.
+    fn register_iteration_types(&mut self) {
+        let any = self.type_table.any_type;
+        let unknown = self.type_table.unknown_type;
+        let void = self.type_table.void_type;
+
+        // interface IteratorYieldResult<TYield> { done?: false; value: TYield }
+        let t = self.create_type_parameter("TYield", None);
+        let body = self.create_global_interface_type(vec![
+            ("done", self.type_table.false_type, true),
+            ("value", t, false),
+        ]);
+        self.define_global_generic_type("IteratorYieldResult", body, vec![t]);
+
+        // interface IteratorReturnResult<TReturn> { done: true; value: TReturn }
+        let t = self.create_type_parameter("TReturn", None);
+        let body = self.create_global_interface_type(vec![
+            ("done", self.type_table.true_type, false),
+            ("value", t, false),
+        ]);
+        self.define_global_generic_type("IteratorReturnResult", body, vec![t]);
+
+        // type IteratorResult<T, TReturn = any> = IteratorYieldResult<T> | IteratorReturnResult<TReturn>
+        let t = self.create_type_parameter("T", None);
+        let r = self.create_type_parameter_with_default("TReturn", any);
+        let yield_result = self.create_global_type_reference("IteratorYieldResult", &[t]);
+        let return_result = self.create_global_type_reference("IteratorReturnResult", &[r]);
+        let body = self.create_union_type(vec![yield_result, return_result]);
+        self.define_global_generic_type("IteratorResult", body, vec![t, r]);
+
+        // PromiseLike<T> and Promise<T>: then(onfulfilled?, onrejected?)
+        for name in ["PromiseLike", "Promise"] {
+            let t = self.create_type_parameter("T", None);
+            let onfulfilled = self.create_method_type(vec![("value", t, false)], any);
+            let onrejected = self.create_method_type(vec![("reason", any, false)], any);
+            let then = self.create_method_type(vec![("onfulfilled", onfulfilled, true), ("onrejected", onrejected, true)], any);
+            let mut members = vec![("then", then, false)];
+            if name == "Promise" {
+                let catch = self.create_method_type(vec![("onrejected", onrejected, true)], any);
+                let onfinally = self.create_method_type(vec![], void);
+                let finally = self.create_method_type(vec![("onfinally", onfinally, true)], any);
+                members.extend([("catch", catch, false), ("finally", finally, false)]);
+            }
+            let body = self.create_global_interface_type(members);
+            self.define_global_generic_type(name, body, vec![t]);
+        }
+
+        // Iterator<T, TReturn = any, TNext = any> and its async counterpart:
+        // next(value?), return?(value?) and throw?(e?)
+        for (name, is_async) in [("Iterator", false), ("AsyncIterator", true)] {
+            let t = self.create_type_parameter("T", None);
+            let r = self.create_type_parameter_with_default("TReturn", any);
+            let n = self.create_type_parameter_with_default("TNext", any);
+            let members = self.create_iterator_members(t, r, n, is_async, true);
+            let body = self.create_global_interface_type(members);
+            self.define_global_generic_type(name, body, vec![t, r, n]);
+        }
+
+        // Iterable<T, TReturn = any, TNext = any> { [Symbol.iterator](): Iterator<T, TReturn, TNext> }
+        // IterableIterator<T, TReturn = any, TNext = any> extends Iterator<T, TReturn, TNext>
+        // Generator<T = unknown, TReturn = any, TNext = any> extends Iterator<T, TReturn, TNext>
+        // and their async counterparts
+        for (name, is_async, is_iterator, is_generator) in [
+            ("Iterable", false, false, false),
+            ("IterableIterator", false, true, false),
+            ("Generator", false, true, true),
+            ("AsyncIterable", true, false, false),
+            ("AsyncIterableIterator", true, true, false),
+            ("AsyncGenerator", true, true, true),
+        ] {
+            let t = if is_generator {
+                self.create_type_parameter_with_default("T", unknown)
+            } else {
+                self.create_type_parameter("T", None)
+            };
+            let r = self.create_type_parameter_with_default("TReturn", any);
+            let n = self.create_type_parameter_with_default("TNext", any);
+            let mut members = if is_iterator {
+                self.create_iterator_members(t, r, n, is_async, !is_generator)
+            } else {
+                Vec::new()
+            };
+            let (iterator_name, key) = if is_async {
+                ("AsyncIterator", "[Symbol.asyncIterator]")
+            } else {
+                ("Iterator", "[Symbol.iterator]")
+            };
+            let iterator = self.create_global_type_reference(iterator_name, &[t, r, n]);
+            let iterator_method = self.create_method_type(vec![], iterator);
+            members.push((key, iterator_method, false));
+            let body = self.create_global_interface_type(members);
+            self.define_global_generic_type(name, body, vec![t, r, n]);
+        }
+
+        // The Promise constructor:
+        // resolve(): Promise<void>; resolve<T>(value: T): Promise<T>; reject<T = never>(reason?: any): Promise<T>
+        let void_promise = self.create_global_type_reference("Promise", &[void]);
+        let t = self.create_type_parameter("T", None);
+        let promise = self.create_global_type_reference("Promise", &[t]);
+        let resolve = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS,
+                members: IndexMap::new(),
+                call_signatures: vec![
+                    Signature {
+                        type_parameters: vec![],
+                        parameters: vec![],
+                        return_type: void_promise,
+                        min_argument_count: 0,
+                        has_rest_parameter: false,
+                        this_type: None,
+                        is_method: true,
+                        type_predicate: None,
+                    },
+                    Signature {
+                        type_parameters: vec![t],
+                        parameters: vec![SignatureParameter { name: "value".to_string(), type_id: t, optional: false }],
+                        return_type: promise,
+                        min_argument_count: 1,
+                        has_rest_parameter: false,
+                        this_type: None,
+                        is_method: true,
+                        type_predicate: None,
+                    },
+                ],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        );
+        let t = self.create_type_parameter_with_default("T", self.type_table.never_type);
+        let promise = self.create_global_type_reference("Promise", &[t]);
+        let reject = self.create_function_type(Signature {
+            type_parameters: vec![t],
+            parameters: vec![SignatureParameter { name: "reason".to_string(), type_id: any, optional: true }],
+            return_type: promise,
+            min_argument_count: 0,
+            has_rest_parameter: false,
+            this_type: None,
+            is_method: true,
+            type_predicate: None,
+        });
+        let promise_constructor = self.create_global_interface_type(vec![
+            ("resolve", resolve, false),
+            ("reject", reject, false),
+        ]);
+        self.register_type("Promise", promise_constructor);
+    }
+
+    /// The members of `Iterator<T, TReturn, TNext>`, or of `AsyncIterator`,
+    /// whose methods return promises. A generator's `return` and `throw` are
+    /// not optional.
+    fn create_iterator_members(
+        &mut self,
+        t: TypeId,
+        r: TypeId,
+        n: TypeId,
+        is_async: bool,
+        optional_return_and_throw: bool,
+    ) -> Vec<(&'static str, TypeId, bool)> {
+        let any = self.type_table.any_type;
+        let mut result = self.create_global_type_reference("IteratorResult", &[t, r]);
+        if is_async {
+            result = self.create_global_type_reference("Promise", &[result]);
+        }
+        let next = self.create_method_type(vec![("value", n, true)], result);
+        let return_method = self.create_method_type(vec![("value", r, optional_return_and_throw)], result);
+        let throw = self.create_method_type(vec![("e", any, optional_return_and_throw)], result);
+        vec![
+            ("next", next, false),
+            ("return", return_method, optional_return_and_throw),
+            ("throw", throw, optional_return_and_throw),
+        ]
+    }
+
+    /// Create the object type of a standard library interface from its
+    /// members' names, types and whether they are optional.
+    fn create_global_interface_type(&mut self, members: Vec<(&str, TypeId, bool)>) -> TypeId {
+        let object = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::INTERFACE,
+                members: members.iter().map(|&(name, type_id, _)| (name.to_string(), type_id)).collect(),
+                call_signatures: vec![],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        );
+        for (name, _, optional) in members {
+            if optional {
+                self.type_table.set_property_flags(object, name, PropertyFlags::OPTIONAL);
+            }
+        }
+        object
+    }
+
+    /// Create the type of a standard library method from its parameters'
+    /// names, types and whether they are optional.
+    fn create_method_type(&mut self, parameters: Vec<(&str, TypeId, bool)>, return_type: TypeId) -> TypeId {
+        let min_argument_count = parameters.iter().filter(|&&(_, _, optional)| !optional).count() as u32;
+        self.create_function_type(Signature {
+            type_parameters: vec![],
+            parameters: parameters.into_iter()
+                .map(|(name, type_id, optional)| SignatureParameter { name: name.to_string(), type_id, optional })
+                .collect(),
+            return_type,
+            min_argument_count,
+            has_rest_parameter: false,
+            this_type: None,
+            is_method: true,
+            type_predicate: None,
+        })
+    }
+
+    fn define_global_generic_type(&mut self, name: &'static str, body: TypeId, type_parameters: Vec<TypeId>) {
+        self.global_generic_types.insert(name, (body, type_parameters));
+    }
+
+    /// Instantiate a generic interface of the standard library. Missing type
+    /// arguments take their parameter's default, then `any`. Instantiations
+    /// are shared, and display as references like `Generator<number, void, unknown>`.
+    fn create_global_type_reference(&mut self, name: &'static str, type_args: &[TypeId]) -> TypeId {
+        let Some((body, type_params)) = self.global_generic_types.get(name).cloned() else {
+            return self.type_table.any_type;
+        };
+        let mut args: Vec<TypeId> = type_args.iter().copied().take(type_params.len()).collect();
+        for &param in &type_params[args.len()..] {
+            args.push(match self.type_table.get(param).kind {
+                TypeKind::TypeParameter { default: Some(default_type), .. } => default_type,
+                _ => self.type_table.any_type,
+            });
+        }
+        // The interface's own parameters denote its declaration
+        if args == type_params {
+            return body;
+        }
+        if let Some(&instantiation) = self.global_type_instantiations.get(&(name, args.clone())) {
+            return instantiation;
+        }
+        let instantiation = self.substitute_type_by_id(body, &type_params, &args);
+        self.global_type_instantiations.insert((name, args.clone()), instantiation);
+        self.global_type_references.entry(instantiation).or_insert((name, args));
+        instantiation
+    }
+
     /// Register a declared name with its resolved type.
     fn register_type(&mut self, name: &str, type_id: TypeId) {
         if !name.is_empty() {
@@ -256,6 +1383,16 @@ impl Checker {
         }
     }
 
+    /// Register the type of a declaration, also recording it at the
+    /// declaration's name so that it can be found from the symbol however
+    /// the name is shadowed later.
+    fn register_declaration_type(&mut self, name_range: TextRange, name: &str, type_id: TypeId) {
+        if !name.is_empty() {
+            self.register_type(name, type_id);
+            self.node_types.insert(name_range, type_id);
+        }
+    }
+
     /// Look up a declared name's type.
     fn get_declared_type(&self, name: &str) -> Option<TypeId> {
         self.declared_types.get(name).copied()
@@ -289,46 +1426,695 @@ impl Checker {
 
     /// Check a source file for type errors.
     pub fn check_source_file(&mut self, source_file: &SourceFile<'_>) {
-        for statement in source_file.statements.iter() {
+        self.file_name = source_file.file_name.clone();
+        self.in_strict_mode = self.always_strict
+            || source_file.is_external_module()
+            || source_file.has_use_strict_directive();
+        self.in_ambient_context = source_file.is_declaration_file;
+        if self.isolated_modules && !source_file.is_declaration_file {
+            self.check_isolated_module_file(source_file);
+        }
+        // Ambient modules and global augmentations apply wherever they
+        // appear, so they are declared before anything imports from them.
+        self.declare_type_aliases(source_file.statements);
+        for statement in source_file.statements.iter().filter(|s| is_global_augmentation_or_ambient_module(s)) {
             self.check_statement(statement);
         }
+        for statement in source_file.statements.iter().filter(|s| !is_global_augmentation_or_ambient_module(s)) {
+            self.check_statement(statement);
+        }
+        self.check_unused_declarations();
+        self.check_unused_labels();
     }
 
-    pub fn diagnostics(&self) -> &DiagnosticCollection { &self.diagnostics }
-    pub fn take_diagnostics(&mut self) -> DiagnosticCollection { std::mem::take(&mut self.diagnostics) }
+    /// Declare the global declarations of a file of the program, bound by
+    /// `binder`, so their names resolve in the files checked afterwards: the
+    /// top-level declarations of a script, and the ambient modules and global
+    /// augmentations of any file. Diagnostics in that file are left to its
+    /// own check.
+    pub fn add_global_declarations(&mut self, source_file: &SourceFile<'_>, binder: &mut Binder) {
+        std::mem::swap(&mut self.binder, binder);
+        let saved_state = self.replace_file_check_state(FileCheckState {
+            file_name: source_file.file_name.clone(),
+            ..FileCheckState::default()
+        });
+        let saved_ambient_context = std::mem::replace(&mut self.in_ambient_context, source_file.is_declaration_file);
+        let is_script = !source_file.is_external_module();
+        if is_script {
+            self.declare_type_aliases(source_file.statements);
+        } else {
+            let module = self.get_program_module(source_file);
+            self.program_modules.insert(source_file.file_name.clone(), module);
+        }
+        for statement in source_file.statements.iter() {
+            let is_global = is_global_augmentation_or_ambient_module(statement)
+                || is_script && matches!(
+                    statement,
+                    Statement::VariableStatement(_)
+                        | Statement::FunctionDeclaration(_)
+                        | Statement::ClassDeclaration(_)
+                        | Statement::InterfaceDeclaration(_)
+                        | Statement::TypeAliasDeclaration(_)
+                        | Statement::EnumDeclaration(_)
+                        | Statement::ModuleDeclaration(_)
+                );
+            if is_global {
+                self.check_statement(statement);
+            }
+        }
+        std::mem::swap(&mut self.binder, binder);
+        self.replace_file_check_state(saved_state);
+        self.in_ambient_context = saved_ambient_context;
+        for key in std::mem::take(&mut self.excessive_type_alias_instantiations) {
+            self.type_alias_instantiations.remove(&key);
+        }
+    }
 
-    /// Look up a declared type by name and return its string representation.
-    /// Useful for testing type inference.
-    pub fn get_type_string(&self, name: &str) -> String {
-        match self.declared_types.get(name) {
-            Some(&type_id) => self.type_to_string(type_id),
-            None => "<undeclared>".to_string(),
+    /// Check a file of the program, bound by `binder`, in the checker its
+    /// globals were declared in. The types created while checking it are
+    /// shared with the files checked after it; its own declarations are not.
+    pub fn check_program_file(&mut self, source_file: &SourceFile<'_>, binder: Binder) -> FileCheckResult {
+        let saved_binder = std::mem::replace(&mut self.binder, binder);
+        let globals = self.save_global_declarations();
+        let saved_state = self.replace_file_check_state(FileCheckState::default());
+        self.check_source_file(source_file);
+        self.record_program_module_exports();
+        let result = FileCheckResult {
+            diagnostics: std::mem::take(&mut self.diagnostics),
+            elided_import_names: self.get_elided_import_names(),
+            elided_export_names: self.get_elided_export_names(),
+        };
+        self.replace_file_check_state(saved_state);
+        self.restore_global_declarations(globals);
+        self.binder = saved_binder;
+        result
+    }
+
+    /// Record what the own exports of the module file just checked denote,
+    /// so that the files checked after it bind their imports of them.
+    fn record_program_module_exports(&mut self) {
+        let Some(module) = self.program_modules.get(&self.file_name) else {
+            return;
+        };
+        let local_names = module.local_names.clone();
+        let exports = module.exports.clone();
+        let checked_exports = local_names.into_iter()
+            .map(|(export_name, local_name)| {
+                let is_type = exports.get(&export_name).copied().unwrap_or(false);
+                let export = ProgramModuleExport {
+                    value: if is_type { None } else { self.get_type_of_local_export(&local_name) },
+                    declared_type: self.get_declared_type(&local_name).filter(|_| is_type).map(|type_id| {
+                        (type_id, self.generic_type_parameters.get(&local_name).cloned().unwrap_or_default())
+                    }),
+                    type_alias: self.type_aliases.get(&local_name).filter(|_| is_type).cloned(),
+                    class: self.classes.get(&local_name).cloned(),
+                    enum_type: self.enum_types.get(&local_name).copied(),
+                };
+                (export_name, export)
+            })
+            .collect();
+        if let Some(module) = self.program_modules.get_mut(&self.file_name) {
+            module.checked_exports = checked_exports;
         }
     }
 
-    fn error(&mut self, msg: &rscript_diagnostics::DiagnosticMessage, args: &[&str]) {
-        // Note: The checker doesn't have direct access to file names or current node positions
-        // without more extensive refactoring. This is best-effort - diagnostics without span info.
-        self.diagnostics.add(Diagnostic::new(msg, args));
+    /// The type of a value declared at the top level of the checked file,
+    /// found from its symbol however the name is shadowed in the file.
+    fn get_type_of_local_export(&self, name: &str) -> Option<TypeId> {
+        let symbol = self.binder.resolve_name(name).and_then(|symbol_id| self.binder.get_symbol(symbol_id));
+        symbol.and_then(|symbol| symbol.declaration_ranges.iter().find_map(|range| self.node_types.get(range)).copied())
+            .or_else(|| self.get_declared_type(name))
+            .or_else(|| self.namespaces.get(name).copied())
     }
 
-    // ========================================================================
-    // Statement checking
-    // ========================================================================
+    /// Replace what checking the current file recorded, returning it.
+    fn replace_file_check_state(&mut self, state: FileCheckState) -> FileCheckState {
+        FileCheckState {
+            file_name: std::mem::replace(&mut self.file_name, state.file_name),
+            diagnostics: std::mem::replace(&mut self.diagnostics, state.diagnostics),
+            flow_reachability: std::mem::replace(&mut self.flow_reachability, state.flow_reachability),
+            never_calls: std::mem::replace(&mut self.never_calls, state.never_calls),
+            reported_unreachable: std::mem::replace(&mut self.reported_unreachable, state.reported_unreachable),
+            call_type_predicates: std::mem::replace(&mut self.call_type_predicates, state.call_type_predicates),
+            resolved_signatures: std::mem::replace(&mut self.resolved_signatures, state.resolved_signatures),
+            node_types: std::mem::replace(&mut self.node_types, state.node_types),
+            contextual_types: std::mem::replace(&mut self.contextual_types, state.contextual_types),
+        }
+    }
 
-    fn check_statement(&mut self, stmt: &Statement<'_>) {
-        match stmt {
+    fn save_global_declarations(&self) -> GlobalDeclarations {
+        GlobalDeclarations {
+            declared_types: self.declared_types.clone(),
+            generic_type_parameters: self.generic_type_parameters.clone(),
+            type_aliases: self.type_aliases.clone(),
+            type_alias_instantiations: self.type_alias_instantiations.clone(),
+            overload_signatures: self.overload_signatures.clone(),
+            classes: self.classes.clone(),
+            enum_types: self.enum_types.clone(),
+            unique_symbols: self.unique_symbols.clone(),
+            namespaces: self.namespaces.clone(),
+            declaration_namespaces: self.declaration_namespaces.clone(),
+            namespace_type_names: self.namespace_type_names.clone(),
+            type_only_imports: self.type_only_imports.clone(),
+            type_only_exports: self.type_only_exports.clone(),
+        }
+    }
+
+    fn restore_global_declarations(&mut self, globals: GlobalDeclarations) {
+        self.declared_types = globals.declared_types;
+        self.generic_type_parameters = globals.generic_type_parameters;
+        self.type_aliases = globals.type_aliases;
+        self.type_alias_instantiations = globals.type_alias_instantiations;
+        self.overload_signatures = globals.overload_signatures;
+        self.classes = globals.classes;
+        self.enum_types = globals.enum_types;
+        self.unique_symbols = globals.unique_symbols;
+        self.namespaces = globals.namespaces;
+        self.declaration_namespaces = globals.declaration_namespaces;
+        self.namespace_type_names = globals.namespace_type_names;
+        self.type_only_imports = globals.type_only_imports;
+        self.type_only_exports = globals.type_only_exports;
+    }
+
+    /// Report what a transpiler that sees one file at a time cannot compile
+    /// (isolatedModules): a global script, which it cannot tell from a
+    /// module, and, when modules are emitted as CommonJS under
+    /// verbatimModuleSyntax, `export` modifiers on values, which it would
+    /// have to rewrite into assignments.
+    fn check_isolated_module_file(&mut self, source_file: &SourceFile<'_>) {
+        let is_typescript = !matches!(source_file.script_kind, ScriptKind::JS | ScriptKind::JSX | ScriptKind::JSON);
+        if is_typescript && !source_file.is_external_module() {
+            let base_name = source_file.file_name.rsplit(['/', '\\']).next().unwrap_or_default();
+            self.diagnostics.add(Diagnostic::with_location(
+                self.file_name.clone(),
+                TextSpan::from_bounds(0, 0),
+                &messages::_0_CANNOT_BE_COMPILED_UNDER_ISOLATEDMODULES_BECAUSE_IT_IS_CONSIDERED_A_GLOBAL_SCRIPT_FILE,
+                &[base_name],
+            ));
+        }
+        if !self.verbatim_module_syntax || !self.commonjs_module {
+            return;
+        }
+        for statement in source_file.statements.iter() {
+            let modifier_flags = statement.data().modifier_flags;
+            let is_value_declaration = match statement {
+                Statement::VariableStatement(_)
+                | Statement::FunctionDeclaration(_)
+                | Statement::ClassDeclaration(_)
+                | Statement::EnumDeclaration(_) => true,
+                Statement::ModuleDeclaration(n) => is_instantiated_module(n),
+                _ => false,
+            };
+            if is_value_declaration && modifier_flags.contains(ModifierFlags::EXPORT) && !modifier_flags.contains(ModifierFlags::AMBIENT) {
+                let saved_range = self.current_node_range.replace(statement.data().range);
+                self.error(&messages::A_TOP_LEVEL_EXPORT_MODIFIER_CANNOT_BE_USED_ON_VALUE_DECLARATIONS_IN_A_COMMONJS_MODULE, &[]);
+                self.current_node_range = saved_range;
+            }
+        }
+    }
+
+    /// The option that makes the checker reject what a single-file
+    /// transpiler cannot compile, as named in diagnostics.
+    fn isolated_modules_flag_name(&self) -> &'static str {
+        if self.verbatim_module_syntax { "verbatimModuleSyntax" } else { "isolatedModules" }
+    }
+
+    /// The import bindings of the checked file that JavaScript emit elides:
+    /// those never read as values, and those naming only types. Under
+    /// verbatimModuleSyntax imports are emitted as written, so none are.
+    pub fn get_elided_import_names(&self) -> HashSet<String> {
+        if self.verbatim_module_syntax {
+            return HashSet::new();
+        }
+        self.binder.local_declarations().iter()
+            .filter(|declaration| declaration.kind == LocalDeclarationKind::Import)
+            .filter_map(|declaration| self.binder.get_symbol(declaration.symbol))
+            .filter(|symbol| !symbol.is_referenced_as_value || self.type_only_imports.contains(&symbol.name_text))
+            .map(|symbol| symbol.name_text.clone())
+            .collect()
+    }
+
+    /// The re-exports of the checked file that JavaScript emit elides, by
+    /// exported name: those naming only types. Under verbatimModuleSyntax
+    /// exports are emitted as written, so none are.
+    pub fn get_elided_export_names(&self) -> HashSet<String> {
+        if self.verbatim_module_syntax {
+            return HashSet::new();
+        }
+        self.type_only_exports.iter().cloned().collect()
+    }
+
+    pub fn diagnostics(&self) -> &DiagnosticCollection { &self.diagnostics }
+    pub fn take_diagnostics(&mut self) -> DiagnosticCollection { std::mem::take(&mut self.diagnostics) }
+
+    /// Look up a declared type by name and return its string representation.
+    /// Useful for testing type inference. The type of a type alias is
+    /// printed as declared, rather than as the alias itself.
+    pub fn get_type_string(&self, name: &str) -> String {
+        match self.declared_types.get(name) {
+            Some(&type_id) if self.type_aliases.get(name).is_some_and(|alias| alias.declared_type == type_id) => {
+                self.type_to_string(type_id, TypeFormatFlags::IN_TYPE_ALIAS)
+            }
+            Some(&type_id) => self.format_type(type_id),
+            None => "<undeclared>".to_string(),
+        }
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+    //
+    // Locations are the ranges of nodes of the checked file: an identifier,
+    // whose range excludes leading trivia, or an expression.
+
+    /// Get the type of the expression or identifier at `range`. An
+    /// identifier that is not checked as an expression, like a declaration
+    /// name or a type reference, has the declared type of its symbol.
+    pub fn get_type_at_location(&self, range: TextRange) -> Option<TypeId> {
+        if let Some(&type_id) = self.node_types.get(&range) {
+            return Some(type_id);
+        }
+        let symbol = self.get_symbol(self.get_symbol_at_location(range)?)?;
+        if let Some(&type_id) = symbol.declaration_ranges.iter().find_map(|range| self.node_types.get(range)) {
+            return Some(type_id);
+        }
+        // Declarations whose types are not recorded at their names, like
+        // interfaces and type aliases, are looked up by name.
+        self.declared_types.get(&symbol.name_text).copied()
+    }
+
+    /// Get the symbol the identifier at `range` declares or refers to.
+    pub fn get_symbol_at_location(&self, range: TextRange) -> Option<SymbolId> {
+        self.binder.get_symbol_at_location(range)
+    }
+
+    /// Get a symbol of the checked file.
+    pub fn get_symbol(&self, symbol: SymbolId) -> Option<&Symbol> {
+        self.binder.get_symbol(symbol)
+    }
+
+    /// Whether a symbol of the checked file is declared by a parameter.
+    pub fn is_parameter(&self, symbol: SymbolId) -> bool {
+        self.binder.is_parameter(symbol)
+    }
+
+    /// Get the call or construct signatures of a type. Type parameters have
+    /// the signatures of their constraints, and intersections those of all
+    /// their constituents.
+    pub fn get_signatures_of_type(&self, type_id: TypeId, kind: SignatureKind) -> Vec<Signature> {
+        match &self.type_table.get(self.get_apparent_type(type_id)).kind {
+            TypeKind::ObjectType { call_signatures, .. } if kind == SignatureKind::Call => call_signatures.clone(),
+            TypeKind::ObjectType { construct_signatures, .. } => construct_signatures.clone(),
+            TypeKind::Intersection { types } => types.iter()
+                .flat_map(|&t| self.get_signatures_of_type(t, kind))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get the properties of a type, in declaration order, with the types
+    /// they have in each constituent that declares them. An intersection has
+    /// the properties of any constituent and a union those of every
+    /// constituent; a property's type is the intersection or union of its
+    /// types. Primitives have the properties of their wrapper interfaces.
+    pub fn get_properties_of_type(&self, type_id: TypeId) -> Vec<(String, Vec<TypeId>)> {
+        let apparent = self.get_apparent_type(type_id);
+        let (types, is_union) = match &self.type_table.get(apparent).kind {
+            TypeKind::ObjectType { members, .. } => {
+                return members.iter().map(|(name, &t)| (name.clone(), vec![t])).collect();
+            }
+            TypeKind::Union { types } => (types, true),
+            TypeKind::Intersection { types } => (types, false),
+            _ => return Vec::new(),
+        };
+        let mut properties: IndexMap<String, Vec<TypeId>> = IndexMap::new();
+        let mut declared_in: IndexMap<String, usize> = IndexMap::new();
+        for &t in types {
+            for (name, property_types) in self.get_properties_of_type(t) {
+                *declared_in.entry(name.clone()).or_default() += 1;
+                let entry = properties.entry(name).or_default();
+                for property_type in property_types {
+                    if !entry.contains(&property_type) {
+                        entry.push(property_type);
+                    }
+                }
+            }
+        }
+        properties.into_iter()
+            .filter(|(name, _)| !is_union || declared_in[name] == types.len())
+            .collect()
+    }
+
+    /// Get the signature the call or `new` expression at `range` resolved
+    /// to. A call no signature accepts resolves to its only candidate, if it
+    /// has one.
+    pub fn get_resolved_signature(&self, range: TextRange) -> Option<&Signature> {
+        self.resolved_signatures.get(&range)
+    }
+
+    /// Get the type the context of the expression at `range` expects it to
+    /// have: that of the parameter an argument is passed to, of an annotated
+    /// variable or return type, and so on.
+    pub fn get_contextual_type(&self, range: TextRange) -> Option<TypeId> {
+        self.contextual_types.get(&range).copied()
+    }
+
+    /// Format a type as text, e.g. for hover or generated code.
+    pub fn type_to_string(&self, type_id: TypeId, flags: TypeFormatFlags) -> String {
+        self.type_to_string_worker(type_id, flags)
+    }
+
+    /// Get the type whose members a type has: type parameters, including
+    /// polymorphic `this` types, have those of their constraints, and
+    /// primitives those of their global wrapper interfaces, e.g. `String`
+    /// for `string` and `"a"`. A primitive whose wrapper is not declared is
+    /// its own apparent type.
+    pub fn get_apparent_type(&self, type_id: TypeId) -> TypeId {
+        let apparent = self.get_apparent_type_of_type_parameter(type_id);
+        let flags = self.type_table.get(apparent).flags;
+        let wrapper = if flags.intersects(TypeFlags::STRING_LIKE) {
+            "String"
+        } else if flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ENUM_LITERAL) {
+            "Number"
+        } else if flags.intersects(TypeFlags::BOOLEAN_LIKE) {
+            "Boolean"
+        } else if flags.intersects(TypeFlags::BIG_INT_LIKE) {
+            "BigInt"
+        } else if flags.intersects(TypeFlags::ES_SYMBOL_LIKE) {
+            "Symbol"
+        } else {
+            return apparent;
+        };
+        self.get_declared_type(wrapper).unwrap_or(apparent)
+    }
+
+    /// Get the symbol an alias refers to, following aliases of aliases.
+    /// Only `import x = N.y` aliases of declarations in the checked file
+    /// resolve; imports from other modules do not.
+    pub fn get_aliased_symbol(&self, symbol: SymbolId) -> Option<SymbolId> {
+        let mut target = self.binder.get_alias_target(symbol)?;
+        let mut seen = FxHashSet::default();
+        while let Some(next) = self.binder.get_alias_target(target) {
+            if !seen.insert(target) {
+                return None;
+            }
+            target = next;
+        }
+        Some(target)
+    }
+
+    /// Get the exported members of a namespace or ambient module symbol, in
+    /// declaration order.
+    pub fn get_exports_of_module(&self, symbol: SymbolId) -> Vec<SymbolId> {
+        let Some(exports) = self.get_symbol(symbol).and_then(|symbol| symbol.exports.as_ref()) else {
+            return Vec::new();
+        };
+        let mut exports: Vec<SymbolId> = exports.iter_names().map(|(_, &id)| id).collect();
+        exports.sort_by_key(|id| id.index());
+        exports.dedup();
+        exports
+    }
+
+    fn error(&mut self, msg: &rscript_diagnostics::DiagnosticMessage, args: &[&str]) {
+        let diagnostic = self.create_diagnostic(msg, args);
+        self.diagnostics.add(diagnostic);
+    }
+
+    /// Report a diagnostic built elsewhere, at the node being checked unless
+    /// it already has a location.
+    fn add_diagnostic(&mut self, mut diagnostic: Diagnostic) {
+        if diagnostic.span.is_none() {
+            if let Some(range) = self.current_node_range {
+                diagnostic.file = Some(self.file_name.clone());
+                diagnostic.span = Some(TextSpan::from_bounds(range.pos, range.end));
+            }
+        }
+        self.diagnostics.add(diagnostic);
+    }
+
+    /// Create a diagnostic at the statement or expression being checked.
+    /// Diagnostics reported outside of any node have no location.
+    fn create_diagnostic(&self, msg: &rscript_diagnostics::DiagnosticMessage, args: &[&str]) -> Diagnostic {
+        match self.current_node_range {
+            Some(range) => Diagnostic::with_location(self.file_name.clone(), TextSpan::from_bounds(range.pos, range.end), msg, args),
+            None => Diagnostic::new(msg, args),
+        }
+    }
+
+    /// Report a diagnostic as a suggestion, which editors show without
+    /// failing the build.
+    fn suggestion(&mut self, msg: &rscript_diagnostics::DiagnosticMessage, args: &[&str]) {
+        let mut diagnostic = self.create_diagnostic(msg, args);
+        diagnostic.category = DiagnosticCategory::Suggestion;
+        self.diagnostics.add(diagnostic);
+    }
+
+    /// Report a diagnostic at `range` rather than at the node being checked.
+    fn error_at(&mut self, range: TextRange, msg: &rscript_diagnostics::DiagnosticMessage, args: &[&str]) {
+        let saved_range = self.current_node_range.replace(range);
+        self.error(msg, args);
+        self.current_node_range = saved_range;
+    }
+
+    /// Report a suggestion at `range` rather than at the node being checked.
+    fn suggestion_at(&mut self, range: TextRange, msg: &rscript_diagnostics::DiagnosticMessage, args: &[&str]) {
+        let saved_range = self.current_node_range.replace(range);
+        self.suggestion(msg, args);
+        self.current_node_range = saved_range;
+    }
+
+    /// Report local declarations the binder saw no references to, at their
+    /// names, under noUnusedLocals and noUnusedParameters. Parameters named
+    /// with a leading underscore are intentionally unused. Unused imports
+    /// are errors under noUnusedLocals and suggestions otherwise; when every
+    /// binding of an import declaration is unused, the whole declaration is
+    /// reported once.
+    fn check_unused_declarations(&mut self) {
+        let mut unused = Vec::new();
+        let mut imports: IndexMap<TextRange, (usize, Vec<(String, TextRange)>)> = IndexMap::new();
+        // Hoisted declarations are bound first; report in source order.
+        let mut declarations = self.binder.local_declarations().to_vec();
+        declarations.sort_by_key(|d| d.range.pos);
+        for declaration in &declarations {
+            let Some(symbol) = self.binder.get_symbol(declaration.symbol) else { continue };
+            let is_unused = symbol.references.is_empty();
+            match declaration.kind {
+                LocalDeclarationKind::Import => {
+                    let group = imports.entry(declaration.declaration_range).or_default();
+                    group.0 += 1;
+                    if is_unused {
+                        group.1.push((symbol.name_text.clone(), declaration.range));
+                    }
+                }
+                _ if is_unused => unused.push((declaration.kind, symbol.name_text.clone(), declaration.range)),
+                _ => {}
+            }
+        }
+        for (kind, name, range) in unused {
+            match kind {
+                LocalDeclarationKind::Parameter => {
+                    if self.no_unused_parameters && !name.starts_with('_') {
+                        self.error_at(range, &messages::_0_IS_DECLARED_BUT_ITS_VALUE_IS_NEVER_READ, &[&name]);
+                    }
+                }
+                LocalDeclarationKind::Type => {
+                    if self.no_unused_locals {
+                        self.error_at(range, &messages::_0_IS_DECLARED_BUT_NEVER_USED, &[&name]);
+                    }
+                }
+                _ => {
+                    if self.no_unused_locals {
+                        self.error_at(range, &messages::_0_IS_DECLARED_BUT_ITS_VALUE_IS_NEVER_READ, &[&name]);
+                    }
+                }
+            }
+        }
+        for (declaration_range, (count, names)) in imports {
+            let report = if self.no_unused_locals { Self::error_at } else { Self::suggestion_at };
+            match names.as_slice() {
+                [] => {}
+                [(name, _)] if count == 1 => report(self, declaration_range, &messages::_0_IS_DECLARED_BUT_ITS_VALUE_IS_NEVER_READ, &[name]),
+                _ if names.len() == count => report(self, declaration_range, &messages::ALL_IMPORTS_IN_IMPORT_DECLARATION_ARE_UNUSED, &[]),
+                _ => {
+                    for (name, range) in &names {
+                        report(self, *range, &messages::_0_IS_DECLARED_BUT_ITS_VALUE_IS_NEVER_READ, &[name]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Report labels no `break` or `continue` refers to, at the label,
+    /// unless allowUnusedLabels is set.
+    fn check_unused_labels(&mut self) {
+        if self.allow_unused_labels == Some(true) {
+            return;
+        }
+        for range in self.binder.unused_labels().to_vec() {
+            if self.allow_unused_labels == Some(false) {
+                self.error_at(range, &messages::UNUSED_LABEL, &[]);
+            } else {
+                self.suggestion_at(range, &messages::UNUSED_LABEL, &[]);
+            }
+        }
+    }
+
+    // ========================================================================
+    // Reachability
+    // ========================================================================
+
+    /// Whether control can reach a flow node. Besides the unreachable points
+    /// the binder found, a call statement that never returns ends the flow.
+    /// Calls on the way to a node precede it, so they have been checked by
+    /// the time it is asked about.
+    fn is_reachable_flow_node(&mut self, flow: u32) -> bool {
+        if let Some(&reachable) = self.flow_reachability.get(&flow) {
+            return reachable;
+        }
+        let mut current = flow;
+        let reachable = loop {
+            let Some(node) = self.binder.flow_nodes().get(current as usize) else { break true };
+            match node.kind {
+                FlowNodeKind::Unreachable => break false,
+                FlowNodeKind::Start => break true,
+                FlowNodeKind::BranchLabel => {
+                    let antecedents = node.antecedents.clone();
+                    break antecedents.into_iter().any(|a| self.is_reachable_flow_node(a));
+                }
+                FlowNodeKind::Call
+                    if self.binder.flow_call(current).is_some_and(|call| self.never_calls.contains(&call)) =>
+                {
+                    break false;
+                }
+                // A loop is reachable when it is entered; its back edges
+                // come from inside it.
+                _ => match node.antecedents.first() {
+                    Some(&antecedent) => current = antecedent,
+                    None => break true,
+                },
+            }
+        };
+        self.flow_reachability.insert(flow, reachable);
+        reachable
+    }
+
+    /// Whether control can run off the end of the function-like node at `range`.
+    fn is_function_end_reachable(&mut self, range: TextRange) -> bool {
+        match self.binder.function_flow(range) {
+            Some(flow) => flow.end_reachable && self.is_reachable_flow_node(flow.end_flow),
+            None => true,
+        }
+    }
+
+    /// Whether the body of the function-like node at `range` can only
+    /// complete abruptly, making `never` its inferred return type.
+    fn function_never_returns(&mut self, range: TextRange) -> bool {
+        match self.binder.function_flow(range) {
+            Some(flow) => !flow.has_return_value && !flow.has_empty_return && !self.is_function_end_reachable(range),
+            None => false,
+        }
+    }
+
+    /// Report a statement no path reaches, once per dead region, unless
+    /// allowUnreachableCode is set.
+    fn check_statement_reachability(&mut self, stmt: &Statement<'_>) {
+        if self.allow_unreachable_code == Some(true) {
+            return;
+        }
+        let range = stmt.data().range;
+        let Some(flow) = self.binder.statement_flow(range) else { return };
+        if self.is_reachable_flow_node(flow.flow) {
+            return;
+        }
+        if flow.preceding.is_some_and(|preceding| !self.is_reachable_flow_node(preceding)) {
+            return;
+        }
+        if !self.reported_unreachable.insert(range) {
+            return;
+        }
+        if self.allow_unreachable_code == Some(false) {
+            self.error(&messages::UNREACHABLE_CODE_DETECTED, &[]);
+        } else {
+            self.suggestion(&messages::UNREACHABLE_CODE_DETECTED, &[]);
+        }
+    }
+
+    /// Whether a call made as a statement never returns: its callee returns
+    /// `never`, or it is an assertion call asserting `false`.
+    fn is_terminating_call(&self, call: &CallExpression<'_>, call_type: TypeId) -> bool {
+        if self.type_table.get(call_type).flags.contains(TypeFlags::NEVER) {
+            return true;
+        }
+        let Some(predicate) = self.call_type_predicates.get(&call.data.range) else { return false };
+        predicate.kind == TypePredicateKind::AssertsIdentifier
+            && predicate.type_id.is_none()
+            && predicate.parameter_index
+                .and_then(|index| call.arguments.get(index))
+                .is_some_and(|arg| matches!(arg, Expression::FalseKeyword(_)))
+    }
+
+    /// Narrow the reference an assertion call asserts on for the rest of the
+    /// enclosing block: `assertIsString(x)` makes `x` a `string` and
+    /// `assert(x)` removes `null` and `undefined` from it.
+    fn narrow_by_assertion_call(&mut self, call: &CallExpression<'_>) {
+        let Some(predicate) = self.call_type_predicates.get(&call.data.range).cloned() else { return };
+        if !predicate.is_assertion() {
+            return;
+        }
+        let Some(name) = self.get_type_predicate_reference(call, &predicate) else { return };
+        let Some(declared) = self.get_declared_type(&name) else { return };
+        let narrowed = match predicate.type_id {
+            Some(candidate) => self.narrow_type_by_type_predicate(declared, candidate, true),
+            None => self.get_non_nullable_type(declared),
+        };
+        self.assertion_narrowings.push((name.clone(), declared));
+        self.register_type(&name, narrowed);
+    }
+
+    /// Check the statements of a block, then undo the narrowings assertion
+    /// calls made in it.
+    fn check_block_statements(&mut self, statements: &[Statement<'_>]) {
+        let mark = self.assertion_narrowings.len();
+        for statement in statements {
+            self.check_statement(statement);
+        }
+        for (name, declared) in self.assertion_narrowings.split_off(mark).into_iter().rev() {
+            self.register_type(&name, declared);
+        }
+    }
+
+    // ========================================================================
+    // Statement checking
+    // ========================================================================
+
+    fn check_statement(&mut self, stmt: &Statement<'_>) {
+        let saved_range = self.current_node_range.replace(stmt.data().range);
+        self.instantiation_count = 0;
+        self.instantiation_limit_reported = false;
+        self.check_statement_worker(stmt);
+        self.current_node_range = saved_range;
+    }
+
+    fn check_statement_worker(&mut self, stmt: &Statement<'_>) {
+        self.check_statement_reachability(stmt);
+        match stmt {
             Statement::VariableStatement(n) => self.check_variable_statement(n),
-            Statement::ExpressionStatement(n) => { self.check_expression(n.expression); }
+            Statement::ExpressionStatement(n) => {
+                let expr_type = self.check_expression(n.expression);
+                if let Expression::Call(call) = n.expression {
+                    if self.is_terminating_call(call, expr_type) {
+                        self.never_calls.insert(call.data.range);
+                    }
+                    self.narrow_by_assertion_call(call);
+                }
+            }
             Statement::ReturnStatement(n) => {
                 if let Some(expr) = n.expression {
                     self.check_expression(expr);
                 }
             }
             Statement::IfStatement(n) => self.check_if_statement(n),
-            Statement::Block(n) => {
-                for s in n.statements.iter() { self.check_statement(s); }
-            }
+            Statement::Block(n) => self.check_block_statements(n.statements),
             Statement::FunctionDeclaration(n) => self.check_function_declaration(n),
             Statement::ClassDeclaration(n) => self.check_class_declaration(n),
             Statement::ForStatement(n) => self.check_for_statement(n),
@@ -339,7 +2125,7 @@ impl Checker {
                     for decl in list.declarations.iter() {
                         if let BindingName::Identifier(ref id) = decl.name {
                             if !id.text_name.is_empty() {
-                                self.register_type(&id.text_name, self.type_table.string_type);
+                                self.register_declaration_type(id.data.range, &id.text_name, self.type_table.string_type);
                             }
                         }
                     }
@@ -348,16 +2134,19 @@ impl Checker {
             }
             Statement::ForOfStatement(n) => {
                 let iterable_type = self.check_expression(n.expression);
+                let iteration_use = if n.await_modifier.is_some() { IterationUse::ForAwaitOf } else { IterationUse::ForOf };
+                let element_type = self.check_iteration_types(iterable_type, iteration_use).yield_type;
                 // Register for-of loop variable
                 if let ForInitializer::VariableDeclarationList(list) = &n.initializer {
                     for decl in list.declarations.iter() {
-                        if let BindingName::Identifier(ref id) = decl.name {
-                            if !id.text_name.is_empty() {
-                                // Infer element type from iterable or declared type
+                        match &decl.name {
+                            BindingName::Identifier(id) if !id.text_name.is_empty() => {
                                 let elem_type = self.get_type_from_type_annotation(decl.type_annotation)
-                                    .unwrap_or_else(|| self.get_element_type_of_iterable(iterable_type));
-                                self.register_type(&id.text_name, elem_type);
+                                    .unwrap_or(element_type);
+                                self.register_declaration_type(id.data.range, &id.text_name, elem_type);
                             }
+                            BindingName::ArrayBindingPattern(pattern) => self.check_array_binding_pattern(pattern, element_type),
+                            _ => {}
                         }
                     }
                 }
@@ -377,10 +2166,56 @@ impl Checker {
             Statement::EnumDeclaration(n) => self.check_enum_declaration(n),
             Statement::TypeAliasDeclaration(n) => self.check_type_alias_declaration(n),
             Statement::InterfaceDeclaration(n) => self.check_interface_declaration(n),
+            Statement::ModuleDeclaration(n) => { self.check_module_declaration(n); }
+            Statement::ImportDeclaration(n) => self.check_import_declaration(n),
+            Statement::ImportEqualsDeclaration(n) => self.check_import_equals_declaration(n),
+            Statement::ExportDeclaration(n) => self.check_export_declaration(n),
+            Statement::WithStatement(n) => {
+                if self.in_strict_mode {
+                    self.error(&messages::WITH_STATEMENTS_ARE_NOT_ALLOWED_IN_STRICT_MODE, &[]);
+                }
+                self.check_expression(n.expression);
+                self.check_statement(n.statement);
+            }
             _ => {}
         }
     }
 
+    /// Report names that strict mode code may not declare: `eval`,
+    /// `arguments` and the future reserved words.
+    fn check_strict_mode_binding_name(&mut self, name: &BindingName<'_>) {
+        if !self.in_strict_mode {
+            return;
+        }
+        match name {
+            BindingName::Identifier(id) => {
+                if id.text_name == "eval" || id.text_name == "arguments" {
+                    self.error(&messages::INVALID_USE_OF_0_IN_STRICT_MODE, &[&id.text_name]);
+                } else if id.original_keyword_kind.is_some_and(SyntaxKind::is_future_reserved_word) {
+                    self.error(&messages::IDENTIFIER_EXPECTED_0_IS_A_RESERVED_WORD_IN_STRICT_MODE, &[&id.text_name]);
+                }
+            }
+            BindingName::ObjectBindingPattern(pattern) => {
+                for element in pattern.elements.iter() {
+                    self.check_strict_mode_binding_name(&element.name);
+                }
+            }
+            BindingName::ArrayBindingPattern(pattern) => {
+                for element in pattern.elements.iter() {
+                    if let ArrayBindingElement::BindingElement(element) = element {
+                        self.check_strict_mode_binding_name(&element.name);
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_strict_mode_parameters(&mut self, parameters: &[ParameterDeclaration<'_>]) {
+        for parameter in parameters {
+            self.check_strict_mode_binding_name(&parameter.name);
+        }
+    }
+
     fn check_variable_statement(&mut self, node: &VariableStatement<'_>) {
         let is_const = node.declaration_list.data.flags.contains(NodeFlags::CONST);
         for decl in node.declaration_list.declarations.iter() {
@@ -393,52 +2228,64 @@ impl Checker {
     }
 
     fn check_variable_declaration_with_const(&mut self, decl: &VariableDeclaration<'_>, is_const: bool) {
-        let declared_type = if decl.type_annotation.is_some() {
-            self.get_type_from_type_annotation(decl.type_annotation)
-        } else {
-            None
-        };
-
+        self.check_strict_mode_binding_name(&decl.name);
         // Extract the variable name for type registration
         let var_name = match &decl.name {
             BindingName::Identifier(id) => Some(id.text_name.clone()),
             _ => None,
         };
 
+        let declared_type = match (decl.type_annotation, &var_name) {
+            // `const s: unique symbol` is a symbol distinct from every other
+            (Some(TypeNode::TypeOperator(op)), Some(name)) if is_const && op.operator == SyntaxKind::UniqueKeyword => {
+                Some(self.create_unique_symbol_type(name))
+            }
+            (annotation, _) => self.get_type_from_type_annotation(annotation),
+        };
+
         if let Some(init) = decl.initializer {
-            let init_type = self.check_expression(init);
+            let init_type = match declared_type {
+                Some(declared) => self.check_expression_with_contextual_type(init, declared),
+                None => self.check_expression(init),
+            };
+            if let BindingName::ArrayBindingPattern(pattern) = &decl.name {
+                let source_type = declared_type.unwrap_or(init_type);
+                self.check_array_binding_pattern(pattern, source_type);
+            }
 
             if let Some(declared) = declared_type {
-                // Check that initializer is assignable to declared type
-                if !self.is_type_assignable_to(init_type, declared) {
-                    let source_name = self.type_to_string(init_type);
-                    let target_name = self.type_to_string(declared);
-                    self.error(
-                        &messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1,
-                        &[&source_name, &target_name],
-                    );
+                // Check that initializer is assignable to declared type. A
+                // `Symbol()` call initializes a `unique symbol` declaration.
+                let is_unique_symbol_init = self.type_table.get(declared).flags.contains(TypeFlags::UNIQUE_ES_SYMBOL)
+                    && is_symbol_call(init);
+                if !is_unique_symbol_init {
+                    self.check_type_assignable_to_and_elaborate(init_type, declared, init, decl.name.data().range);
                 }
                 // Register the declared type
                 if let Some(ref name) = var_name {
-                    self.register_type(name, declared);
+                    self.register_declaration_type(decl.name.data().range, name, declared);
                 }
             } else {
                 // Infer type from initializer
-                let inferred = if is_const && declared_type.is_none() {
-                    // For `const` declarations without type annotation, narrow to literal types
-                    self.narrow_to_literal(init, init_type)
+                let inferred = if is_const && var_name.is_some() && is_symbol_call(init) {
+                    // `const s = Symbol()` declares a unique symbol
+                    self.create_unique_symbol_type(var_name.as_deref().unwrap_or_default())
+                } else if is_const {
+                    // `const` declarations keep the literal type of their initializer
+                    init_type
                 } else {
                     // For `let`/`var`, widen literal types to their base types
                     self.widen_type(init_type)
                 };
+                let inferred = self.get_widened_type(inferred);
                 if let Some(ref name) = var_name {
-                    self.register_type(name, inferred);
+                    self.register_declaration_type(decl.name.data().range, name, inferred);
                 }
             }
         } else if let Some(declared) = declared_type {
             // No initializer, but has type annotation
             if let Some(ref name) = var_name {
-                self.register_type(name, declared);
+                self.register_declaration_type(decl.name.data().range, name, declared);
             }
         } else if self.no_implicit_any {
             // Variable without type annotation or initializer implicitly has 'any' type
@@ -447,19 +2294,61 @@ impl Checker {
                     &messages::VARIABLE_0_IMPLICITLY_HAS_AN_0_TYPE,
                     &[name, "any"],
                 );
-                self.register_type(name, self.type_table.any_type);
+                self.register_declaration_type(decl.name.data().range, name, self.type_table.any_type);
             }
         } else {
             // Register as any
             if let Some(ref name) = var_name {
-                self.register_type(name, self.type_table.any_type);
+                self.register_declaration_type(decl.name.data().range, name, self.type_table.any_type);
+            }
+        }
+    }
+
+    /// Declare the names an array destructuring pattern binds, typed by the
+    /// elements of the iterable it destructures: by position for a tuple.
+    fn check_array_binding_pattern(&mut self, pattern: &ArrayBindingPattern<'_>, source_type: TypeId) {
+        let element_type = self.check_iteration_types(source_type, IterationUse::Spread).yield_type;
+        let tuple_elements = match &self.type_table.get(source_type).kind {
+            TypeKind::Tuple { element_types, element_flags } => Some((element_types.clone(), element_flags.clone())),
+            _ => None,
+        };
+        for (i, element) in pattern.elements.iter().enumerate() {
+            let ArrayBindingElement::BindingElement(element) = element else {
+                continue;
+            };
+            if let Some(init) = element.initializer {
+                self.check_expression(init);
+            }
+            let binding_type = if element.dot_dot_dot_token.is_some() {
+                self.create_array_type(element_type)
+            } else {
+                match &tuple_elements {
+                    Some((types, flags)) if matches!(flags.get(i), Some(ElementFlags::Required | ElementFlags::Optional)) => types[i],
+                    _ => element_type,
+                }
+            };
+            match &element.name {
+                BindingName::Identifier(id) => self.register_declaration_type(id.data.range, &id.text_name, binding_type),
+                BindingName::ArrayBindingPattern(nested) => self.check_array_binding_pattern(nested, binding_type),
+                _ => {}
             }
         }
     }
 
     fn check_function_declaration(&mut self, node: &FunctionDeclaration<'_>) {
+        let (type_params, saved_type_params) = self.push_type_parameters(node.type_parameters, &[]);
+        let (this_parameter, parameters) = split_this_parameter(node.parameters);
+        let this_type = self.get_this_parameter_type(this_parameter);
+        let saved_this = self.enter_function_this_binding(this_type);
+        if let Some(name) = &node.name {
+            if self.in_strict_mode && (name.text_name == "eval" || name.text_name == "arguments") {
+                self.error(&messages::INVALID_USE_OF_0_IN_STRICT_MODE, &[&name.text_name]);
+            }
+        }
+        self.check_strict_mode_parameters(parameters);
+
         // Build parameter types for the function signature
-        let params: Vec<SignatureParameter> = node.parameters.iter().map(|p| {
+        let params: Vec<SignatureParameter> = parameters.iter().map(|p| {
             if let Some(init) = p.initializer {
                 self.check_expression(init);
             }
@@ -470,9 +2359,7 @@ impl Checker {
             };
             // Register parameter in declared_types for body checking
             let resolved_type = param_type.unwrap_or(self.type_table.any_type);
-            if !param_name.is_empty() {
-                self.register_type(&param_name, resolved_type);
-            }
+            self.register_declaration_type(p.name.data().range, &param_name, resolved_type);
             SignatureParameter {
                 name: param_name,
                 type_id: resolved_type,
@@ -481,22 +2368,27 @@ impl Checker {
         }).collect();
 
         let declared_return = self.get_type_from_type_annotation(node.return_type);
+        let type_predicate = self.get_type_predicate_of_return_type(node.return_type, parameters);
+        let is_async = node.data.modifier_flags.contains(ModifierFlags::ASYNC);
+        let is_generator = node.asterisk_token.is_some();
+        if is_generator {
+            self.enter_generator_context(is_async, declared_return);
+        }
 
         // Infer return type from body if no explicit annotation
         let return_type = if let Some(declared) = declared_return {
             declared
         } else if let Some(ref body) = node.body {
             // Check body statements first
-            for stmt in body.statements.iter() {
-                self.check_statement(stmt);
-            }
+            self.check_block_statements(body.statements);
             // Infer return type from return statements
             let return_types = self.collect_return_types(body);
-            if return_types.is_empty() {
+            let return_type = if return_types.is_empty() {
                 self.type_table.void_type
             } else {
                 self.create_union_type(return_types)
-            }
+            };
+            self.get_inferred_return_type(return_type, is_async, is_generator)
         } else {
             self.type_table.void_type
         };
@@ -504,48 +2396,52 @@ impl Checker {
         // Register the function type
         if let Some(ref name) = node.name {
             let sig = Signature {
-                type_parameters: vec![],
+                type_parameters: type_params,
                 parameters: params,
                 return_type,
-                min_argument_count: node.parameters.iter()
+                min_argument_count: parameters.iter()
                     .filter(|p| p.question_token.is_none() && p.initializer.is_none() && p.dot_dot_dot_token.is_none())
                     .count() as u32,
-                has_rest_parameter: node.parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
+                has_rest_parameter: parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
+                this_type,
+                is_method: false,
+                type_predicate,
+            };
+            // Bodyless declarations are overloads; the implementation that
+            // follows them is not itself visible to callers.
+            let call_signatures = if node.body.is_none() {
+                let declaration = (self.file_name.clone(), node.data.range);
+                let overloads = self.overload_signatures.entry(name.text_name.clone()).or_default();
+                overloads.insert(declaration, sig);
+                overloads.values().cloned().collect()
+            } else if let Some(overloads) = self.overload_signatures.remove(&name.text_name) {
+                let overloads: Vec<Signature> = overloads.into_values().collect();
+                for overload in &overloads {
+                    if !self.is_implementation_compatible_with_overload(&sig, overload) {
+                        self.error(&messages::THIS_OVERLOAD_SIGNATURE_IS_NOT_COMPATIBLE_WITH_ITS_IMPLEMENTATION_SIGNATURE, &[]);
+                    }
+                }
+                overloads
+            } else {
+                vec![sig]
             };
             let func_type = self.type_table.add_type(
                 TypeFlags::OBJECT,
                 TypeKind::ObjectType {
                     object_flags: ObjectFlags::ANONYMOUS,
                     members: IndexMap::new(),
-                    call_signatures: vec![sig],
+                    call_signatures,
                     construct_signatures: vec![],
                     index_infos: vec![],
                 },
             );
-            self.register_type(&name.text_name, func_type);
-
-            // Check for type predicate return type (type guard: `x is T`)
-            if let Some(ret_node) = node.return_type {
-                if let TypeNode::TypePredicate(pred) = ret_node {
-                    if let TypePredicateParameterName::Identifier(param_id) = &pred.parameter_name {
-                        if let Some(guard_type_node) = pred.type_node {
-                            let guard_type = self.get_type_from_type_node(guard_type_node);
-                            self.type_guards.insert(
-                                name.text_name.clone(),
-                                (param_id.text_name.clone(), guard_type),
-                            );
-                        }
-                    }
-                }
-            }
+            self.register_declaration_type(name.data.range, &name.text_name, func_type);
         }
 
         // Check body (if not already checked above for inference)
         if declared_return.is_some() {
             if let Some(ref body) = node.body {
-                for stmt in body.statements.iter() {
-                    self.check_statement(stmt);
-                }
+                self.check_block_statements(body.statements);
                 // Check return type compatibility
                 // Skip for async functions (Promise return types), generator
                 // functions, or types that don't require a return value.
@@ -555,13 +2451,16 @@ impl Checker {
                     false
                 };
                 let ret_type = self.type_table.get(return_type);
-                if !is_promise_return
+                if ret_type.flags.contains(TypeFlags::NEVER) {
+                    self.check_never_function_end(node.data.range, declared_return);
+                } else if !is_promise_return
+                    && !is_generator
                     && !ret_type.flags.contains(TypeFlags::VOID)
                     && !ret_type.flags.contains(TypeFlags::UNDEFINED)
                     && !ret_type.flags.contains(TypeFlags::ANY)
                 {
                     let has_return = self.body_has_return(body);
-                    if !has_return {
+                    if !has_return && self.is_function_end_reachable(node.data.range) {
                         self.error(
                             &messages::A_FUNCTION_WHOSE_DECLARED_TYPE_IS_NEITHER_UNDEFINED_NOR_VOID_MUST_RETURN_A_VALUE,
                             &[],
@@ -570,28 +2469,171 @@ impl Checker {
                 }
             }
         }
+        if node.body.is_some() && !is_generator {
+            self.check_all_code_paths_return(node.data.range, declared_return);
+        }
+        if is_generator {
+            self.generator_contexts.pop();
+        }
+        self.restore_this_binding(saved_this);
+        self.pop_type_parameters(saved_type_params);
     }
 
-    fn body_has_return(&self, body: &Block<'_>) -> bool {
-        self.statements_have_return(body.statements)
+    /// Under noImplicitReturns, report a function body that returns a value
+    /// on some paths but can also complete without one, either by running
+    /// off its end or through a bare `return;`. Bodies whose return type
+    /// admits no value (void, any, unknown, undefined) are exempt, as are
+    /// annotated bodies with no returns at all, which report 2355 instead.
+    fn check_all_code_paths_return(&mut self, range: TextRange, declared_return: Option<TypeId>) {
+        if !self.no_implicit_returns {
+            return;
+        }
+        let Some(flow) = self.binder.function_flow(range) else { return };
+        let has_returns = flow.has_return_value || flow.has_empty_return;
+        let applies = match declared_return {
+            Some(declared) => has_returns && !self.admits_implicit_return(declared),
+            None => flow.has_return_value,
+        };
+        if applies && (flow.has_empty_return || self.is_function_end_reachable(range)) {
+            self.error(&messages::NOT_ALL_CODE_PATHS_RETURN_A_VALUE, &[]);
+        }
     }
 
-    fn statements_have_return(&self, stmts: &[Statement<'_>]) -> bool {
-        for stmt in stmts.iter() {
-            if self.statement_has_return(stmt) { return true; }
+    /// Report a body declared to return `never` whose end can be reached.
+    fn check_never_function_end(&mut self, range: TextRange, declared_return: Option<TypeId>) {
+        let returns_never = declared_return.is_some_and(|t| self.type_table.get(t).flags.contains(TypeFlags::NEVER));
+        if returns_never && self.is_function_end_reachable(range) {
+            self.error(&messages::A_FUNCTION_RETURNING_NEVER_CANNOT_HAVE_A_REACHABLE_END_POINT, &[]);
         }
-        false
     }
 
-    fn statement_has_return(&self, stmt: &Statement<'_>) -> bool {
-        match stmt {
-            Statement::ReturnStatement(r) => r.expression.is_some(),
-            Statement::ThrowStatement(_) => true,
-            Statement::IfStatement(n) => {
-                // Check then branch
-                if self.statement_has_return(n.then_statement) { return true; }
-                // Check else branch
-                if let Some(else_stmt) = n.else_statement {
+    /// `check_all_code_paths_return` for a class member, whose return type
+    /// may refer to the member's own type parameters.
+    fn check_member_code_paths_return(
+        &mut self,
+        range: TextRange,
+        type_parameters: Option<&[TypeParameterDeclaration<'_>]>,
+        return_type: Option<&TypeNode<'_>>,
+    ) {
+        if !self.no_implicit_returns {
+            return;
+        }
+        let (_, saved_type_params) = self.push_type_parameters(type_parameters, &[]);
+        let declared_return = self.get_type_from_type_annotation(return_type);
+        self.pop_type_parameters(saved_type_params);
+        self.check_all_code_paths_return(range, declared_return);
+    }
+
+    fn admits_implicit_return(&self, type_id: TypeId) -> bool {
+        let ty = self.type_table.get(type_id);
+        if let TypeKind::Union { types } = &ty.kind {
+            return types.iter().any(|&t| self.admits_implicit_return(t));
+        }
+        ty.flags.intersects(TypeFlags::VOID | TypeFlags::ANY | TypeFlags::UNKNOWN | TypeFlags::UNDEFINED)
+    }
+
+    /// The declared type of a `this` parameter; `any` when it has no annotation.
+    fn get_this_parameter_type(&mut self, this_parameter: Option<&ParameterDeclaration<'_>>) -> Option<TypeId> {
+        this_parameter.map(|p| self.get_type_from_type_annotation(p.type_annotation).unwrap_or(self.type_table.any_type))
+    }
+
+    /// The type predicate a return type annotation declares over `parameters`
+    /// (which exclude a `this` parameter), if it is one.
+    fn get_type_predicate_of_return_type(
+        &mut self,
+        return_type: Option<&TypeNode<'_>>,
+        parameters: &[ParameterDeclaration<'_>],
+    ) -> Option<TypePredicate> {
+        let Some(TypeNode::TypePredicate(node)) = return_type else { return None };
+        let asserts = node.asserts_modifier.is_some();
+        let type_id = node.type_node.map(|t| self.get_type_from_type_node(t));
+        Some(match &node.parameter_name {
+            TypePredicateParameterName::Identifier(id) => TypePredicate {
+                kind: if asserts { TypePredicateKind::AssertsIdentifier } else { TypePredicateKind::Identifier },
+                parameter_name: Some(id.text_name.clone()),
+                parameter_index: parameters.iter().position(|p| {
+                    matches!(&p.name, BindingName::Identifier(param) if param.text_name == id.text_name)
+                }),
+                type_id,
+            },
+            TypePredicateParameterName::ThisType(_) => TypePredicate {
+                kind: if asserts { TypePredicateKind::AssertsThis } else { TypePredicateKind::This },
+                parameter_name: None,
+                parameter_index: None,
+                type_id,
+            },
+        })
+    }
+
+    /// Bind `this` for the body of a function with its own `this`: the type of
+    /// its `this` parameter, or an implicit `any` when it has none. Returns the
+    /// enclosing binding for `restore_this_binding`.
+    fn enter_function_this_binding(&mut self, this_type: Option<TypeId>) -> (Option<TypeId>, bool) {
+        let saved = (self.declared_types.get("this").copied(), self.this_is_implicit);
+        match this_type {
+            Some(this_type) => { self.register_type("this", this_type); }
+            None => { self.declared_types.remove("this"); }
+        }
+        self.this_is_implicit = this_type.is_none();
+        saved
+    }
+
+    fn restore_this_binding(&mut self, (saved_this, saved_is_implicit): (Option<TypeId>, bool)) {
+        match saved_this {
+            Some(prev) => { self.register_type("this", prev); }
+            None => { self.declared_types.remove("this"); }
+        }
+        self.this_is_implicit = saved_is_implicit;
+    }
+
+    /// An overload is compatible with its implementation when each of its
+    /// parameters relates to the implementation's in either direction and the
+    /// return types relate in either direction.
+    fn is_implementation_compatible_with_overload(&mut self, implementation: &Signature, overload: &Signature) -> bool {
+        let implementation_params = self.get_effective_parameters(implementation);
+        if overload.min_argument_count as usize > implementation_params.types.len()
+            && implementation_params.rest.is_none()
+        {
+            return false;
+        }
+        if (overload.parameters.len() as u32) < implementation.min_argument_count && !overload.has_rest_parameter {
+            return false;
+        }
+        let overload_params = self.get_effective_parameters(overload);
+        for (i, &overload_param) in overload_params.types.iter().enumerate() {
+            let Some(implementation_param) = implementation_params.type_at(i) else { break };
+            if !self.is_type_assignable_to(overload_param, implementation_param)
+                && !self.is_type_assignable_to(implementation_param, overload_param)
+            {
+                return false;
+            }
+        }
+        let void_type = self.type_table.void_type;
+        overload.return_type == void_type
+            || self.is_type_assignable_to(implementation.return_type, overload.return_type)
+            || self.is_type_assignable_to(overload.return_type, implementation.return_type)
+    }
+
+    fn body_has_return(&self, body: &Block<'_>) -> bool {
+        self.statements_have_return(body.statements)
+    }
+
+    fn statements_have_return(&self, stmts: &[Statement<'_>]) -> bool {
+        for stmt in stmts.iter() {
+            if self.statement_has_return(stmt) { return true; }
+        }
+        false
+    }
+
+    fn statement_has_return(&self, stmt: &Statement<'_>) -> bool {
+        match stmt {
+            Statement::ReturnStatement(r) => r.expression.is_some(),
+            Statement::ThrowStatement(_) => true,
+            Statement::IfStatement(n) => {
+                // Check then branch
+                if self.statement_has_return(n.then_statement) { return true; }
+                // Check else branch
+                if let Some(else_stmt) = n.else_statement {
                     if self.statement_has_return(else_stmt) { return true; }
                 }
                 false
@@ -640,6 +2682,8 @@ impl Checker {
                 Statement::ReturnStatement(r) => {
                     if let Some(expr) = r.expression {
                         let t = self.check_expression(expr);
+                        let t = self.widen_type(t);
+                        let t = self.get_widened_type(t);
                         types.push(t);
                     } else {
                         types.push(self.type_table.undefined_type);
@@ -683,196 +2727,364 @@ impl Checker {
     }
 
     fn check_class_declaration(&mut self, node: &ClassDeclaration<'_>) {
-        // Check heritage
+        let class_name = node.name.as_ref().map(|n| n.text_name.clone()).unwrap_or_default();
+        if !class_name.is_empty() {
+            self.record_declaration_namespace(&class_name);
+        }
+        let is_abstract = node.data.modifier_flags.contains(ModifierFlags::ABSTRACT);
+
+        // Resolve heritage: the base class is a value, implemented interfaces are types.
+        let mut is_derived = false;
+        let mut base_class: Option<String> = None;
+        let mut implemented: Vec<(String, TypeId)> = Vec::new();
         if let Some(heritage) = node.heritage_clauses {
             for clause in heritage.iter() {
                 for ty in clause.types.iter() {
-                    self.check_expression(ty.expression);
+                    if clause.token == SyntaxKind::ExtendsKeyword {
+                        is_derived = true;
+                        self.check_expression(ty.expression);
+                        if let Expression::Identifier(id) = ty.expression {
+                            if self.classes.contains_key(&id.text_name) {
+                                base_class = Some(id.text_name.clone());
+                            }
+                        }
+                    } else if let Expression::Identifier(id) = ty.expression {
+                        let implemented_type = self.get_type_from_heritage_type(ty);
+                        implemented.push((id.text_name.clone(), implemented_type));
+                    }
                 }
             }
         }
 
         // Save previous `this` type for restoration after class body
         let saved_this = self.declared_types.get("this").copied();
+        // Class bodies are always strict mode code.
+        let saved_strict_mode = std::mem::replace(&mut self.in_strict_mode, true);
 
-        // Register a preliminary `this` type for the class body
-        // (will be refined to the instance type after all members are collected)
+        // Member types are resolved before any body is checked, so `this` is
+        // unknown while collecting them.
         let preliminary_this = self.type_table.any_type;
         self.register_type("this", preliminary_this);
+        // The polymorphic `this` type, constrained to the instance type once
+        // that is built.
+        let this_type = self.create_this_type_parameter();
+        let saved_this_type = self.current_this_type.replace(this_type);
 
-        // Collect class members and build a class type
-        let mut class_members: IndexMap<String, TypeId> = IndexMap::new();
+        let mut instance = ClassSide::default();
+        let mut statics = ClassSide::default();
         let mut construct_sigs: Vec<Signature> = Vec::new();
+        // Bodyless methods and constructors are overloads of the implementation that follows.
+        let mut method_overloads: HashMap<(bool, String), Vec<Signature>> = HashMap::new();
+        let mut constructor_overloads: Vec<Signature> = Vec::new();
 
         for member in node.members.iter() {
+            let modifiers = member.data().modifier_flags;
+            let is_static = modifiers.contains(ModifierFlags::STATIC);
             match member {
                 ClassElement::PropertyDeclaration(p) => {
                     let prop_name = self.property_name_text(&p.name);
-                    let prop_type = if let Some(init) = p.initializer {
-                        let init_type = self.check_expression(init);
-                        if let Some(declared) = self.get_type_from_type_annotation(p.type_annotation) {
-                            if !self.is_type_assignable_to(init_type, declared) {
-                                let src = self.type_to_string(init_type);
-                                let tgt = self.type_to_string(declared);
-                                self.error(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&src, &tgt]);
-                            }
-                            declared
-                        } else {
-                            init_type
+                    let prop_type = match (self.get_type_from_type_annotation(p.type_annotation), p.initializer) {
+                        (Some(declared), _) => declared,
+                        // An unannotated property takes its type from the initializer.
+                        (None, Some(init)) => {
+                            let init_type = self.check_expression(init);
+                            // Readonly properties keep literal types, like `const` declarations
+                            let init_type = if modifiers.contains(ModifierFlags::READONLY) {
+                                init_type
+                            } else {
+                                self.widen_type(init_type)
+                            };
+                            self.get_widened_type(init_type)
                         }
-                    } else {
-                        self.get_type_from_type_annotation(p.type_annotation)
-                            .unwrap_or(self.type_table.any_type)
+                        (None, None) => self.type_table.any_type,
                     };
-                    class_members.insert(prop_name, prop_type);
+                    let mut flags = PropertyFlags::empty();
+                    if p.question_token.is_some() { flags |= PropertyFlags::OPTIONAL; }
+                    if modifiers.contains(ModifierFlags::READONLY) { flags |= PropertyFlags::READONLY; }
+                    let side = if is_static { &mut statics } else { &mut instance };
+                    side.add(prop_name, prop_type, modifiers, flags);
                 }
                 ClassElement::MethodDeclaration(m) => {
                     let method_name = self.property_name_text(&m.name);
-                    let method_params: Vec<SignatureParameter> = m.parameters.iter().map(|p| {
-                        if let Some(init) = p.initializer { self.check_expression(init); }
-                        let ptype = self.get_type_from_type_annotation(p.type_annotation)
-                            .unwrap_or(self.type_table.any_type);
-                        let pname = match &p.name {
-                            BindingName::Identifier(id) => id.text_name.clone(),
-                            _ => String::new(),
-                        };
-                        self.register_type(&pname, ptype);
-                        SignatureParameter { name: pname, type_id: ptype, optional: p.question_token.is_some() }
-                    }).collect();
-                    if let Some(ref body) = m.body {
-                        for s in body.statements.iter() { self.check_statement(s); }
-                    }
-                    let return_type = self.get_type_from_type_annotation(m.return_type)
-                        .unwrap_or(self.type_table.any_type);
-                    let sig = Signature {
-                        type_parameters: vec![],
-                        parameters: method_params,
-                        return_type,
-                        min_argument_count: m.parameters.iter()
-                            .filter(|p| p.question_token.is_none() && p.initializer.is_none())
-                            .count() as u32,
-                        has_rest_parameter: m.parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
+                    let sig = self.get_signature_of_class_member(m.type_parameters, m.parameters, m.return_type);
+                    let key = (is_static, method_name.clone());
+                    let call_signatures = if m.body.is_none() {
+                        let overloads = method_overloads.entry(key).or_default();
+                        overloads.push(sig);
+                        overloads.clone()
+                    } else {
+                        method_overloads.remove(&key).unwrap_or_else(|| vec![sig])
                     };
                     let method_type = self.type_table.add_type(
                         TypeFlags::OBJECT,
                         TypeKind::ObjectType {
                             object_flags: ObjectFlags::ANONYMOUS,
                             members: IndexMap::new(),
-                            call_signatures: vec![sig],
+                            call_signatures,
                             construct_signatures: vec![],
                             index_infos: vec![],
                         },
                     );
-                    class_members.insert(method_name, method_type);
+                    let flags = if m.question_token.is_some() { PropertyFlags::OPTIONAL } else { PropertyFlags::empty() };
+                    let side = if is_static { &mut statics } else { &mut instance };
+                    side.add(method_name, method_type, modifiers, flags);
                 }
                 ClassElement::Constructor(c) => {
-                    let ctor_params: Vec<SignatureParameter> = c.parameters.iter().map(|p| {
-                        if let Some(init) = p.initializer { self.check_expression(init); }
-                        let ptype = self.get_type_from_type_annotation(p.type_annotation)
-                            .unwrap_or(self.type_table.any_type);
-                        let pname = match &p.name {
-                            BindingName::Identifier(id) => id.text_name.clone(),
-                            _ => String::new(),
-                        };
-                        self.register_type(&pname, ptype);
-                        SignatureParameter { name: pname, type_id: ptype, optional: p.question_token.is_some() }
-                    }).collect();
-                    if let Some(ref body) = c.body {
-                        for s in body.statements.iter() { self.check_statement(s); }
+                    let sig = self.get_signature_of_class_member(c.type_parameters, c.parameters, None);
+                    // Parameter properties declare instance members.
+                    for (p, param) in c.parameters.iter().zip(&sig.parameters) {
+                        let param_modifiers = p.data.modifier_flags;
+                        if !param_modifiers.intersects(ModifierFlags::PARAMETER_PROPERTY_MODIFIER) {
+                            continue;
+                        }
+                        let mut flags = PropertyFlags::empty();
+                        if p.question_token.is_some() { flags |= PropertyFlags::OPTIONAL; }
+                        if param_modifiers.contains(ModifierFlags::READONLY) { flags |= PropertyFlags::READONLY; }
+                        instance.add(param.name.clone(), param.type_id, param_modifiers, flags);
+                    }
+                    if c.body.is_none() {
+                        constructor_overloads.push(sig);
+                    } else {
+                        construct_sigs.push(sig);
                     }
-                    construct_sigs.push(Signature {
-                        type_parameters: vec![],
-                        parameters: ctor_params,
-                        return_type: self.type_table.any_type, // instance type
-                        min_argument_count: c.parameters.iter()
-                            .filter(|p| p.question_token.is_none() && p.initializer.is_none())
-                            .count() as u32,
-                        has_rest_parameter: c.parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
-                    });
                 }
+                // Accessor properties are typed once both accessors are seen.
                 ClassElement::GetAccessor(g) => {
                     let prop_name = self.property_name_text(&g.name);
-                    // Register any getter parameters (unusual, but valid in parser output)
-                    for p in g.parameters.iter() {
-                        let ptype = self.get_type_from_type_annotation(p.type_annotation)
-                            .unwrap_or(self.type_table.any_type);
-                        if let BindingName::Identifier(ref id) = p.name {
-                            if !id.text_name.is_empty() {
-                                self.register_type(&id.text_name, ptype);
-                            }
-                        }
-                    }
-                    if let Some(ref body) = g.body {
-                        for s in body.statements.iter() { self.check_statement(s); }
+                    let getter_type = self.get_type_from_type_annotation(g.return_type);
+                    let side = if is_static { &mut statics } else { &mut instance };
+                    let accessors = side.accessors.entry(prop_name.clone()).or_default();
+                    accessors.has_getter = true;
+                    accessors.getter_type = getter_type;
+                    if !side.members.contains_key(&prop_name) {
+                        side.add(prop_name, self.type_table.any_type, modifiers, PropertyFlags::empty());
                     }
-                    let ret = self.get_type_from_type_annotation(g.return_type)
-                        .unwrap_or(self.type_table.any_type);
-                    class_members.insert(prop_name, ret);
                 }
                 ClassElement::SetAccessor(s) => {
                     let prop_name = self.property_name_text(&s.name);
-                    // Register setter parameters so they're available in the body
-                    for p in s.parameters.iter() {
-                        let ptype = self.get_type_from_type_annotation(p.type_annotation)
-                            .unwrap_or(self.type_table.any_type);
-                        if let BindingName::Identifier(ref id) = p.name {
-                            if !id.text_name.is_empty() {
-                                self.register_type(&id.text_name, ptype);
+                    let setter_type = s.parameters.first()
+                        .and_then(|p| self.get_type_from_type_annotation(p.type_annotation));
+                    let side = if is_static { &mut statics } else { &mut instance };
+                    let accessors = side.accessors.entry(prop_name.clone()).or_default();
+                    accessors.has_setter = true;
+                    accessors.setter_type = setter_type;
+                    if !side.members.contains_key(&prop_name) {
+                        side.add(prop_name, self.type_table.any_type, modifiers, PropertyFlags::empty());
+                    }
+                }
+                ClassElement::IndexSignature(idx) => {
+                    let infos = self.get_index_infos_of_index_signature(idx);
+                    let side = if is_static { &mut statics } else { &mut instance };
+                    side.index_infos.extend(infos);
+                }
+                _ => {}
+            }
+        }
+
+        if !constructor_overloads.is_empty() {
+            construct_sigs = constructor_overloads;
+        }
+        self.resolve_class_accessors(&mut instance);
+        self.resolve_class_accessors(&mut statics);
+
+        // Members not redeclared are inherited from the base class, and so is
+        // the constructor when the class declares none.
+        let base_types = base_class.as_ref()
+            .and_then(|b| self.classes.get(b))
+            .map(|info| (info.instance_type, info.static_type));
+        if let Some((base_instance, base_static)) = base_types {
+            self.inherit_class_members(&mut instance, base_instance);
+            self.inherit_class_members(&mut statics, base_static);
+            if construct_sigs.is_empty() {
+                if let TypeKind::ObjectType { construct_signatures, .. } = &self.type_table.get(base_static).kind {
+                    construct_sigs = construct_signatures.clone();
+                }
+            }
+        }
+
+        // Build the instance type first, then set construct signature return types
+        let instance_type = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::INTERFACE,
+                members: instance.members.clone(),
+                call_signatures: vec![],
+                construct_signatures: vec![],
+                index_infos: instance.index_infos.clone(),
+            },
+        );
+        for (name, flags) in &instance.property_flags {
+            self.type_table.set_property_flags(instance_type, name, *flags);
+        }
+        for (name, write_type) in &instance.write_types {
+            self.type_table.set_write_type(instance_type, name, *write_type);
+        }
+        let mut base_this_types = base_types
+            .and_then(|(base_instance, _)| self.this_type_parameters.get(&base_instance))
+            .cloned()
+            .unwrap_or_default();
+        // An interface declared earlier under the class name merges into
+        // the instance type.
+        if let Some(interface_type) = self.get_declared_type(&class_name).filter(|_| !self.classes.contains_key(&class_name)) {
+            base_this_types.extend(self.this_type_parameters.get(&interface_type).cloned().unwrap_or_default());
+            if let TypeKind::ObjectType { object_flags, members, index_infos, .. } = &self.type_table.get(interface_type).kind {
+                if object_flags.contains(ObjectFlags::INTERFACE) {
+                    let members = members.clone();
+                    let index_infos = index_infos.clone();
+                    let member_flags = members.keys()
+                        .map(|name| (name.clone(), self.type_table.get_property_flags(interface_type, name)))
+                        .collect();
+                    self.merge_interface_into_class(instance_type, members, member_flags, index_infos);
+                }
+            }
+        }
+        self.set_this_type_constraint(this_type, instance_type, base_this_types);
+        self.check_index_constraints(instance_type);
+
+        // Update construct signatures to return the instance type
+        for sig in &mut construct_sigs {
+            sig.return_type = instance_type;
+        }
+
+        // If no explicit constructor, add a default one
+        if construct_sigs.is_empty() {
+            construct_sigs.push(Signature {
+                type_parameters: vec![],
+                parameters: vec![],
+                return_type: instance_type,
+                min_argument_count: 0,
+                has_rest_parameter: false,
+                this_type: None,
+                is_method: true,
+                type_predicate: None,
+            });
+        }
+
+        let class_type = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::empty(),
+                members: statics.members.clone(),
+                call_signatures: vec![],
+                construct_signatures: construct_sigs,
+                index_infos: statics.index_infos.clone(),
+            },
+        );
+        for (name, flags) in &statics.property_flags {
+            self.type_table.set_property_flags(class_type, name, *flags);
+        }
+        for (name, write_type) in &statics.write_types {
+            self.type_table.set_write_type(class_type, name, *write_type);
+        }
+        self.check_index_constraints(class_type);
+
+        if !class_name.is_empty() {
+            if let Some(name) = &node.name {
+                self.register_declaration_type(name.data.range, &class_name, class_type);
+            }
+            self.class_of_type.insert(instance_type, (class_name.clone(), false));
+            self.class_of_type.insert(class_type, (class_name.clone(), true));
+            self.classes.insert(class_name.clone(), ClassInfo {
+                is_derived,
+                base_class: base_class.clone(),
+                is_abstract,
+                instance_type,
+                static_type: class_type,
+                instance_members: instance.modifiers,
+                static_members: statics.modifiers,
+            });
+        }
+
+        // Check member bodies with `this` bound to the side they belong to.
+        self.enclosing_classes.push(class_name.clone());
+        let saved_super_call_state = self.super_call_state.take();
+        for member in node.members.iter() {
+            let is_static = member.data().modifier_flags.contains(ModifierFlags::STATIC);
+            self.register_type("this", if is_static { class_type } else { this_type });
+            match member {
+                ClassElement::PropertyDeclaration(p) => {
+                    if let (Some(annotation), Some(init)) = (p.type_annotation, p.initializer) {
+                        let declared = self.get_type_from_type_node(annotation);
+                        let init_type = self.check_expression_with_contextual_type(init, declared);
+                        self.check_type_assignable_to_and_elaborate(init_type, declared, init, p.name.data().range);
+                    }
+                }
+                ClassElement::MethodDeclaration(m) => {
+                    if let Some(ref body) = m.body {
+                        let is_generator = m.asterisk_token.is_some();
+                        if is_generator {
+                            let declared_return = self.get_type_from_type_annotation(m.return_type);
+                            self.enter_generator_context(m.data.modifier_flags.contains(ModifierFlags::ASYNC), declared_return);
+                        }
+                        self.check_class_member_body(m.type_parameters, m.parameters, body);
+                        if is_generator {
+                            self.generator_contexts.pop();
+                        } else {
+                            self.check_member_code_paths_return(m.data.range, m.type_parameters, m.return_type);
+                        }
+                    }
+                }
+                ClassElement::Constructor(c) => {
+                    if let Some(ref body) = c.body {
+                        self.super_call_state = is_derived.then_some(false);
+                        let saved_constructor_class = self.constructor_class.replace(class_name.clone());
+                        self.check_class_member_body(c.type_parameters, c.parameters, body);
+                        self.constructor_class = saved_constructor_class;
+                        if self.super_call_state == Some(false) {
+                            self.error(&messages::CONSTRUCTORS_FOR_DERIVED_CLASSES_MUST_CONTAIN_A_SUPER_CALL, &[]);
+                        }
+                        self.super_call_state = None;
+                    }
+                }
+                ClassElement::GetAccessor(g) => {
+                    if let Some(ref body) = g.body {
+                        self.check_class_member_body(g.type_parameters, g.parameters, body);
+                        self.check_member_code_paths_return(g.data.range, g.type_parameters, g.return_type);
+                        let prop_name = self.property_name_text(&g.name);
+                        let (accessors, side_type) = if is_static {
+                            (&statics.accessors, class_type)
+                        } else {
+                            (&instance.accessors, instance_type)
+                        };
+                        if accessors.get(&prop_name).is_some_and(AccessorTypes::is_inferred) {
+                            let getter_type = self.get_return_type_of_accessor_body(body);
+                            if let TypeKind::ObjectType { members, .. } = &mut self.type_table.get_mut(side_type).kind {
+                                members.insert(prop_name, getter_type);
                             }
                         }
                     }
+                }
+                ClassElement::SetAccessor(s) => {
+                    if s.return_type.is_some() {
+                        self.error(&messages::A_SET_ACCESSOR_CANNOT_HAVE_A_RETURN_TYPE_ANNOTATION, &[]);
+                    }
                     if let Some(ref body) = s.body {
-                        for stmt in body.statements.iter() { self.check_statement(stmt); }
+                        self.check_class_member_body(s.type_parameters, s.parameters, body);
                     }
-                    class_members.insert(prop_name, self.type_table.any_type);
                 }
                 _ => {}
             }
         }
+        self.super_call_state = saved_super_call_state;
+        self.enclosing_classes.pop();
 
-        // Build the instance type first, then set construct signature return types
-        if let Some(ref name) = node.name {
-            let instance_type = self.type_table.add_type(
-                TypeFlags::OBJECT,
-                TypeKind::ObjectType {
-                    object_flags: ObjectFlags::INTERFACE,
-                    members: class_members.clone(),
-                    call_signatures: vec![],
-                    construct_signatures: vec![],
-                    index_infos: vec![],
-                },
-            );
-
-            // Update construct signatures to return the instance type
-            for sig in &mut construct_sigs {
-                sig.return_type = instance_type;
+        if !class_name.is_empty() {
+            match &base_class {
+                Some(base_name) => self.check_class_against_base(&class_name, base_name),
+                None if self.classes[&class_name].members_with(ModifierFlags::OVERRIDE).next().is_some() => {
+                    self.error(
+                        &messages::THIS_MEMBER_CANNOT_HAVE_AN_OVERRIDE_MODIFIER_BECAUSE_ITS_CONTAINING_CLASS_0_DOES_NOT_EXTEND_ANOTHER_CLASS,
+                        &[&class_name],
+                    );
+                }
+                None => {}
             }
-
-            // If no explicit constructor, add a default one
-            if construct_sigs.is_empty() {
-                construct_sigs.push(Signature {
-                    type_parameters: vec![],
-                    parameters: vec![],
-                    return_type: instance_type,
-                    min_argument_count: 0,
-                    has_rest_parameter: false,
-                });
+            for (interface_name, interface_type) in implemented {
+                self.check_class_implements(&class_name, instance_type, &interface_name, interface_type);
             }
-
-            let class_type = self.type_table.add_type(
-                TypeFlags::OBJECT,
-                TypeKind::ObjectType {
-                    object_flags: ObjectFlags::empty(),
-                    members: class_members,
-                    call_signatures: vec![],
-                    construct_signatures: construct_sigs,
-                    index_infos: vec![],
-                },
-            );
-            self.register_type(&name.text_name, class_type);
-
-            // Refine `this` to point to the instance type
-            self.register_type("this", instance_type);
+        }
+        if self.strict_property_initialization && self.strict_null_checks {
+            self.check_property_initialization(node, instance_type);
         }
 
         // Restore previous `this` type
@@ -880,64 +3092,559 @@ impl Checker {
             Some(prev) => { self.register_type("this", prev); }
             None => { self.declared_types.remove("this"); }
         }
+        self.current_this_type = saved_this_type;
+        self.in_strict_mode = saved_strict_mode;
     }
 
-    fn check_if_statement(&mut self, node: &IfStatement<'_>) {
-        self.check_expression(node.expression);
-
-        // Attempt to extract narrowing information from the condition
-        let narrowing = self.extract_narrowing(node.expression);
-
-        if let Some((var_name, narrowed_type, else_type)) = narrowing {
-            // Save the original type
-            let original_type = self.get_declared_type(&var_name);
-
-            // Apply narrowed type for the then-branch
-            self.register_type(&var_name, narrowed_type);
-            self.check_statement(node.then_statement);
+    /// Create the polymorphic `this` type of a class or interface: a type
+    /// parameter whose constraint is set once the instance type is built.
+    fn create_this_type_parameter(&mut self) -> TypeId {
+        self.type_table.add_type(
+            TypeFlags::TYPE_PARAMETER,
+            TypeKind::TypeParameter { name: "this".to_string(), constraint: None, default: None },
+        )
+    }
 
-            // For the else-branch, apply the inverse narrowing
-            if let Some(else_stmt) = node.else_statement {
-                if let Some(et) = else_type {
-                    self.register_type(&var_name, et);
-                } else if let Some(orig) = original_type {
-                    self.register_type(&var_name, orig);
-                }
-                self.check_statement(else_stmt);
+    /// Constrain a polymorphic `this` type to the class or interface type it
+    /// belongs to, whose members then instantiate it along with the `this`
+    /// types of its bases.
+    fn set_this_type_constraint(&mut self, this_type: TypeId, object_type: TypeId, base_this_types: Vec<TypeId>) {
+        if let TypeKind::TypeParameter { constraint, .. } = &mut self.type_table.get_mut(this_type).kind {
+            *constraint = Some(object_type);
+        }
+        let mut this_types = vec![this_type];
+        for base in base_this_types {
+            if !this_types.contains(&base) {
+                this_types.push(base);
             }
+        }
+        self.this_type_parameters.insert(object_type, this_types);
+    }
 
-            // Restore original type after the if/else
-            if let Some(orig) = original_type {
-                self.register_type(&var_name, orig);
-            }
-        } else {
-            // No narrowing — just check both branches normally
-            self.check_statement(node.then_statement);
-            if let Some(else_stmt) = node.else_statement {
-                self.check_statement(else_stmt);
+    /// Resolve a type parameter to its constraint, for looking up members.
+    fn get_apparent_type_of_type_parameter(&self, type_id: TypeId) -> TypeId {
+        let mut apparent = type_id;
+        while let TypeKind::TypeParameter { constraint: Some(constraint), .. } = self.type_table.get(apparent).kind {
+            if constraint == apparent {
+                break;
             }
+            apparent = constraint;
         }
+        apparent
     }
 
-    /// Extract narrowing information from a condition expression.
-    /// Returns (variable_name, narrowed_type_for_then, narrowed_type_for_else).
-    fn extract_narrowing(&mut self, expr: &Expression<'_>) -> Option<(String, TypeId, Option<TypeId>)> {
-        match expr {
-            // typeof x === "string" / typeof x === "number" / etc.
-            Expression::Binary(bin) => {
-                let is_eq = matches!(
-                    bin.operator_token.data.kind,
-                    SyntaxKind::EqualsEqualsEqualsToken | SyntaxKind::EqualsEqualsToken
-                );
-                let is_neq = matches!(
-                    bin.operator_token.data.kind,
-                    SyntaxKind::ExclamationEqualsEqualsToken | SyntaxKind::ExclamationEqualsToken
-                );
-
-                if !is_eq && !is_neq { return None; }
+    /// Instantiate the polymorphic `this` types in the type of a member of
+    /// `object_type` with `receiver`, the object the member is accessed on.
+    /// Class and interface types the member refers to keep their own `this`.
+    fn instantiate_this_type(&mut self, member_type: TypeId, object_type: TypeId, receiver: TypeId) -> TypeId {
+        match self.this_type_parameters.get(&object_type) {
+            Some(this_types) => {
+                let this_types = this_types.clone();
+                self.instantiate_this_types_worker(member_type, &this_types, receiver, 0)
+            }
+            None => member_type,
+        }
+    }
 
-                // Check for: typeof x === "string" or "string" === typeof x
-                let (typeof_expr, string_lit) = match (bin.left, bin.right) {
+    fn instantiate_this_types_worker(&mut self, type_id: TypeId, this_types: &[TypeId], receiver: TypeId, depth: u32) -> TypeId {
+        if this_types.contains(&type_id) {
+            return receiver;
+        }
+        if depth >= MAX_INSTANTIATION_DEPTH {
+            return type_id;
+        }
+        match self.type_table.get(type_id).kind.clone() {
+            TypeKind::Union { types } => {
+                let instantiated: Vec<TypeId> = types.iter()
+                    .map(|&t| self.instantiate_this_types_worker(t, this_types, receiver, depth + 1))
+                    .collect();
+                if instantiated == types { type_id } else { self.create_union_type(instantiated) }
+            }
+            TypeKind::ObjectType { object_flags, members, call_signatures, construct_signatures, index_infos }
+                if !object_flags.intersects(ObjectFlags::CLASS_OR_INTERFACE) =>
+            {
+                let mut changed = false;
+                let mut instantiate_signatures = |checker: &mut Self, sigs: &[Signature]| -> Vec<Signature> {
+                    sigs.iter().map(|sig| {
+                        let new_sig = Signature {
+                            parameters: sig.parameters.iter().map(|param| SignatureParameter {
+                                type_id: checker.instantiate_this_types_worker(param.type_id, this_types, receiver, depth + 1),
+                                ..param.clone()
+                            }).collect(),
+                            return_type: checker.instantiate_this_types_worker(sig.return_type, this_types, receiver, depth + 1),
+                            this_type: sig.this_type.map(|t| checker.instantiate_this_types_worker(t, this_types, receiver, depth + 1)),
+                            ..sig.clone()
+                        };
+                        changed |= new_sig.return_type != sig.return_type
+                            || new_sig.this_type != sig.this_type
+                            || new_sig.parameters.iter().zip(&sig.parameters).any(|(a, b)| a.type_id != b.type_id);
+                        new_sig
+                    }).collect()
+                };
+                let new_calls = instantiate_signatures(self, &call_signatures);
+                let new_constructs = instantiate_signatures(self, &construct_signatures);
+                let new_members: IndexMap<String, TypeId> = members.iter()
+                    .map(|(name, &member)| (name.clone(), self.instantiate_this_types_worker(member, this_types, receiver, depth + 1)))
+                    .collect();
+                if !changed && new_members == members {
+                    return type_id;
+                }
+                let new_type = self.type_table.add_type(
+                    TypeFlags::OBJECT,
+                    TypeKind::ObjectType {
+                        object_flags,
+                        members: new_members,
+                        call_signatures: new_calls,
+                        construct_signatures: new_constructs,
+                        index_infos,
+                    },
+                );
+                self.type_table.copy_property_flags(type_id, new_type);
+                new_type
+            }
+            _ => type_id,
+        }
+    }
+
+    /// Build the signature of a class method or constructor from its declaration.
+    fn get_signature_of_class_member(
+        &mut self,
+        type_parameters: Option<&[TypeParameterDeclaration<'_>]>,
+        parameters: &[ParameterDeclaration<'_>],
+        return_type: Option<&TypeNode<'_>>,
+    ) -> Signature {
+        let (type_params, saved_type_params) = self.push_type_parameters(type_parameters, &[]);
+        let (this_parameter, parameters) = split_this_parameter(parameters);
+        let this_type = self.get_this_parameter_type(this_parameter);
+        let params: Vec<SignatureParameter> = parameters.iter().map(|p| {
+            let ptype = self.get_type_from_type_annotation(p.type_annotation)
+                .unwrap_or(self.type_table.any_type);
+            let pname = match &p.name {
+                BindingName::Identifier(id) => id.text_name.clone(),
+                _ => String::new(),
+            };
+            SignatureParameter { name: pname, type_id: ptype, optional: p.question_token.is_some() }
+        }).collect();
+        let type_predicate = self.get_type_predicate_of_return_type(return_type, parameters);
+        let return_type = self.get_type_from_type_annotation(return_type)
+            .unwrap_or(self.type_table.any_type);
+        self.pop_type_parameters(saved_type_params);
+        Signature {
+            type_parameters: type_params,
+            parameters: params,
+            return_type,
+            min_argument_count: parameters.iter()
+                .filter(|p| p.question_token.is_none() && p.initializer.is_none() && p.dot_dot_dot_token.is_none())
+                .count() as u32,
+            has_rest_parameter: parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
+            this_type,
+            is_method: true,
+            type_predicate,
+        }
+    }
+
+    /// Check the body of a class method, constructor or accessor.
+    fn check_class_member_body(
+        &mut self,
+        type_parameters: Option<&[TypeParameterDeclaration<'_>]>,
+        parameters: &[ParameterDeclaration<'_>],
+        body: &Block<'_>,
+    ) {
+        let (_, saved_type_params) = self.push_type_parameters(type_parameters, &[]);
+        self.check_strict_mode_parameters(parameters);
+        for p in parameters.iter() {
+            if let Some(init) = p.initializer { self.check_expression(init); }
+            let ptype = self.get_type_from_type_annotation(p.type_annotation)
+                .unwrap_or(self.type_table.any_type);
+            if let BindingName::Identifier(ref id) = p.name {
+                self.register_declaration_type(id.data.range, &id.text_name, ptype);
+            }
+        }
+        self.check_block_statements(body.statements);
+        self.pop_type_parameters(saved_type_params);
+    }
+
+    /// Give the accessor properties of a class side their types. A property
+    /// whose type no accessor annotates is typed by its getter's body once
+    /// that is checked.
+    fn resolve_class_accessors(&mut self, side: &mut ClassSide) {
+        for (name, accessors) in &side.accessors {
+            side.members.insert(name.clone(), accessors.read_type().unwrap_or(self.type_table.any_type));
+            if let Some(write_type) = accessors.write_type() {
+                side.write_types.insert(name.clone(), write_type);
+            }
+            if accessors.is_readonly() {
+                *side.property_flags.entry(name.clone()).or_default() |= PropertyFlags::READONLY;
+            }
+        }
+    }
+
+    /// The type a getter without annotations returns: the union of the
+    /// types of its `return` statements, or `void` if it has none.
+    fn get_return_type_of_accessor_body(&mut self, body: &Block<'_>) -> TypeId {
+        let return_types = self.collect_return_types(body);
+        if return_types.is_empty() {
+            self.type_table.void_type
+        } else {
+            self.create_union_type(return_types)
+        }
+    }
+
+    /// Add the members of a base class type that the derived class does not redeclare.
+    fn inherit_class_members(&self, side: &mut ClassSide, base_type: TypeId) {
+        let TypeKind::ObjectType { members, index_infos, .. } = &self.type_table.get(base_type).kind else {
+            return;
+        };
+        for info in index_infos {
+            if !side.index_infos.iter().any(|own| own.key_type == info.key_type) {
+                side.index_infos.push(info.clone());
+            }
+        }
+        for (name, &type_id) in members {
+            if side.members.contains_key(name) {
+                continue;
+            }
+            side.members.insert(name.clone(), type_id);
+            if let Some(write_type) = self.type_table.get_write_type(base_type, name) {
+                side.write_types.insert(name.clone(), write_type);
+            }
+            let flags = self.type_table.get_property_flags(base_type, name);
+            if !flags.is_empty() {
+                side.property_flags.insert(name.clone(), flags);
+            }
+        }
+    }
+
+    /// The named class followed by its base classes, nearest first.
+    fn class_and_ancestors(&self, class_name: &str) -> Vec<String> {
+        let mut chain: Vec<String> = Vec::new();
+        let mut current = Some(class_name.to_string());
+        while let Some(name) = current {
+            if chain.contains(&name) {
+                break;
+            }
+            current = self.classes.get(&name).and_then(|info| info.base_class.clone());
+            chain.push(name);
+        }
+        chain
+    }
+
+    /// Find the class in the inheritance chain that declares a member, with
+    /// the member's modifiers.
+    fn find_class_member(&self, class_name: &str, member: &str, is_static: bool) -> Option<(String, ModifierFlags)> {
+        self.class_and_ancestors(class_name).into_iter().find_map(|name| {
+            let flags = self.classes.get(&name)?.member_flags(member, is_static)?;
+            Some((name, flags))
+        })
+    }
+
+    /// Check members that override or implement members of the base class.
+    fn check_class_against_base(&mut self, class_name: &str, base_name: &str) {
+        let info = &self.classes[class_name];
+        let base = &self.classes[base_name];
+        let (instance_type, static_type) = (info.instance_type, info.static_type);
+        let (base_instance, base_static) = (base.instance_type, base.static_type);
+        let is_abstract = info.is_abstract;
+        let own_members: Vec<(bool, String, ModifierFlags)> = info.instance_members.iter()
+            .map(|(name, flags)| (false, name.clone(), *flags))
+            .chain(info.static_members.iter().map(|(name, flags)| (true, name.clone(), *flags)))
+            .collect();
+
+        let mut incorrectly_extends: Option<DiagnosticMessageChain> = None;
+        let mut static_side_mismatch: Option<DiagnosticMessageChain> = None;
+        for (is_static, name, flags) in own_members {
+            let (derived_side, base_side) = if is_static { (static_type, base_static) } else { (instance_type, base_instance) };
+            let Some(base_member_type) = self.get_member_type(base_side, &name) else {
+                if flags.contains(ModifierFlags::OVERRIDE) {
+                    self.error(
+                        &messages::THIS_MEMBER_CANNOT_HAVE_AN_OVERRIDE_MODIFIER_BECAUSE_IT_IS_NOT_DECLARED_IN_THE_BASE_CLASS_0,
+                        &[base_name],
+                    );
+                }
+                continue;
+            };
+            let base_flags = self.find_class_member(base_name, &name, is_static)
+                .map(|(_, flags)| flags)
+                .unwrap_or(ModifierFlags::NONE);
+            if self.no_implicit_override
+                && !flags.contains(ModifierFlags::OVERRIDE)
+                && !base_flags.contains(ModifierFlags::ABSTRACT)
+            {
+                self.error(
+                    &messages::THIS_MEMBER_MUST_HAVE_AN_OVERRIDE_MODIFIER_BECAUSE_IT_OVERRIDES_A_MEMBER_IN_THE_BASE_CLASS_0,
+                    &[base_name],
+                );
+            }
+            // A member may not be less visible than the one it overrides.
+            if incorrectly_extends.is_none() && accessibility_rank(flags) > accessibility_rank(base_flags) {
+                let message = if accessibility_rank(flags) == 2 {
+                    &messages::PROPERTY_0_IS_PRIVATE_IN_TYPE_1_BUT_NOT_IN_TYPE_2
+                } else {
+                    &messages::PROPERTY_0_IS_PROTECTED_IN_TYPE_1_BUT_PUBLIC_IN_TYPE_2
+                };
+                incorrectly_extends = Some(DiagnosticMessageChain::new(message, &[&name, class_name, base_name]));
+            }
+            let Some(member_type) = self.get_member_type(derived_side, &name) else {
+                continue;
+            };
+            // Members of both classes are compared with `this` as the derived class.
+            let member_type = self.instantiate_this_type(member_type, derived_side, derived_side);
+            let base_member_type = self.instantiate_this_type(base_member_type, base_side, derived_side);
+            if self.is_type_assignable_to(member_type, base_member_type) {
+                continue;
+            }
+            let source = self.format_type(member_type);
+            let target = self.format_type(base_member_type);
+            let next = self.elaborate_assignability_error(member_type, base_member_type, 0);
+            let mismatch = DiagnosticMessageChain::new(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&source, &target])
+                .with_next(next);
+            if is_static {
+                if static_side_mismatch.is_none() {
+                    static_side_mismatch = Some(
+                        DiagnosticMessageChain::new(&messages::TYPES_OF_PROPERTY_0_ARE_INCOMPATIBLE, &[&name])
+                            .with_next(vec![mismatch]),
+                    );
+                }
+                continue;
+            }
+            self.add_diagnostic(
+                Diagnostic::new(
+                    &messages::PROPERTY_0_IN_TYPE_1_IS_NOT_ASSIGNABLE_TO_THE_SAME_PROPERTY_IN_BASE_TYPE_2,
+                    &[&name, class_name, base_name],
+                )
+                .with_message_chain(vec![mismatch]),
+            );
+        }
+        if let Some(explanation) = incorrectly_extends {
+            self.add_diagnostic(
+                Diagnostic::new(&messages::CLASS_0_INCORRECTLY_EXTENDS_BASE_CLASS_1, &[class_name, base_name])
+                    .with_message_chain(vec![explanation]),
+            );
+        }
+        if let Some(explanation) = static_side_mismatch {
+            self.add_diagnostic(
+                Diagnostic::new(
+                    &messages::CLASS_STATIC_SIDE_0_INCORRECTLY_EXTENDS_BASE_CLASS_STATIC_SIDE_1,
+                    &[&format!("typeof {}", class_name), &format!("typeof {}", base_name)],
+                )
+                .with_message_chain(vec![explanation]),
+            );
+        }
+
+        if is_abstract {
+            return;
+        }
+        // Every abstract member inherited by a concrete class must be implemented
+        // by the nearest class that redeclares it.
+        let mut seen: FxHashSet<String> = FxHashSet::default();
+        for ancestor in self.class_and_ancestors(class_name) {
+            let members: Vec<(String, ModifierFlags)> = self.classes[&ancestor].instance_members.iter()
+                .map(|(name, flags)| (name.clone(), *flags))
+                .collect();
+            for (name, flags) in members {
+                if !seen.insert(name.clone()) || ancestor == class_name || !flags.contains(ModifierFlags::ABSTRACT) {
+                    continue;
+                }
+                self.error(
+                    &messages::NON_ABSTRACT_CLASS_0_DOES_NOT_IMPLEMENT_INHERITED_ABSTRACT_MEMBER_1_FROM_CLASS_2,
+                    &[class_name, &name, &ancestor],
+                );
+            }
+        }
+    }
+
+    /// Check that a class instance type conforms to an interface it implements.
+    fn check_class_implements(&mut self, class_name: &str, instance_type: TypeId, interface_name: &str, interface_type: TypeId) {
+        if self.is_type_assignable_to(instance_type, interface_type) {
+            return;
+        }
+        let mut explanation = None;
+        let required: Vec<(String, TypeId)> = match &self.type_table.get(interface_type).kind {
+            TypeKind::ObjectType { members, .. } => members.iter().map(|(n, t)| (n.clone(), *t)).collect(),
+            _ => Vec::new(),
+        };
+        for (name, target) in required {
+            if self.type_table.get_property_flags(interface_type, &name).contains(PropertyFlags::OPTIONAL) {
+                continue;
+            }
+            let target = self.instantiate_this_type(target, interface_type, instance_type);
+            let source = self.get_member_type(instance_type, &name)
+                .map(|source| self.instantiate_this_type(source, instance_type, instance_type));
+            match source {
+                None => {
+                    explanation = Some(DiagnosticMessageChain::new(
+                        &messages::PROPERTY_0_IS_MISSING_IN_TYPE_1_BUT_REQUIRED_IN_TYPE_2,
+                        &[&name, class_name, interface_name],
+                    ));
+                    break;
+                }
+                Some(source) if !self.is_type_assignable_to(source, target) => {
+                    let next = self.elaborate_assignability_error(source, target, 0);
+                    let source = self.format_type(source);
+                    let target = self.format_type(target);
+                    explanation = Some(
+                        DiagnosticMessageChain::new(&messages::TYPES_OF_PROPERTY_0_ARE_INCOMPATIBLE, &[&name]).with_next(vec![
+                            DiagnosticMessageChain::new(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&source, &target])
+                                .with_next(next),
+                        ]),
+                    );
+                    break;
+                }
+                Some(_) => {}
+            }
+        }
+        self.add_diagnostic(
+            Diagnostic::new(&messages::CLASS_0_INCORRECTLY_IMPLEMENTS_INTERFACE_1, &[class_name, interface_name])
+                .with_message_chain(explanation.into_iter().collect()),
+        );
+    }
+
+    /// Report instance properties that are neither initialized nor definitely
+    /// assigned in the constructor (TS2564).
+    fn check_property_initialization(&mut self, node: &ClassDeclaration<'_>, instance_type: TypeId) {
+        let constructor_body = node.members.iter().find_map(|m| match m {
+            ClassElement::Constructor(c) => c.body.as_ref(),
+            _ => None,
+        });
+        for member in node.members.iter() {
+            let ClassElement::PropertyDeclaration(p) = member else {
+                continue;
+            };
+            if p.initializer.is_some()
+                || p.question_token.is_some()
+                || p.exclamation_token.is_some()
+                || p.type_annotation.is_none()
+                || p.data.modifier_flags.intersects(ModifierFlags::STATIC | ModifierFlags::ABSTRACT | ModifierFlags::AMBIENT)
+            {
+                continue;
+            }
+            let name = self.property_name_text(&p.name);
+            let Some(declared) = self.get_member_type(instance_type, &name) else {
+                continue;
+            };
+            if self.is_type_assignable_to(self.type_table.undefined_type, declared)
+                || constructor_body.is_some_and(|body| is_property_assigned_in(body, &name))
+            {
+                continue;
+            }
+            self.error(&messages::PROPERTY_0_HAS_NO_INITIALIZER_AND_IS_NOT_DEFINITELY_ASSIGNED_IN_THE_CONSTRUCTOR, &[&name]);
+        }
+    }
+
+    /// The type of a named member of an object type.
+    fn get_member_type(&self, type_id: TypeId, name: &str) -> Option<TypeId> {
+        match &self.type_table.get(type_id).kind {
+            TypeKind::ObjectType { members, .. } => members.get(name).copied(),
+            _ => None,
+        }
+    }
+
+    /// Report access to a private or protected member from outside the
+    /// classes allowed to see it.
+    fn check_property_accessibility(&mut self, class_name: &str, is_static: bool, prop_name: &str) {
+        let Some((declaring, flags)) = self.find_class_member(class_name, prop_name, is_static) else {
+            return;
+        };
+        if flags.contains(ModifierFlags::PRIVATE) {
+            if !self.enclosing_classes.contains(&declaring) {
+                self.error(&messages::PROPERTY_0_IS_PRIVATE_AND_ONLY_ACCESSIBLE_WITHIN_CLASS_1, &[prop_name, &declaring]);
+            }
+        } else if flags.contains(ModifierFlags::PROTECTED) {
+            let accessible = self.enclosing_classes.iter()
+                .any(|enclosing| self.class_and_ancestors(enclosing).contains(&declaring));
+            if !accessible {
+                self.error(
+                    &messages::PROPERTY_0_IS_PROTECTED_AND_ONLY_ACCESSIBLE_WITHIN_CLASS_1_AND_ITS_SUBCLASSES,
+                    &[prop_name, &declaring],
+                );
+            }
+        }
+    }
+
+    /// Check a `super(...)` call against the base class constructor.
+    fn check_super_call(&mut self, node: &CallExpression<'_>) -> TypeId {
+        let args = self.check_call_argument_list(node.arguments, &[]);
+        let enclosing = self.enclosing_classes.last()
+            .and_then(|name| self.classes.get(name))
+            .map(|info| (info.is_derived, info.base_class.clone()));
+        match enclosing {
+            Some((false, _)) => {
+                self.error(&messages::SUPER_CAN_ONLY_BE_REFERENCED_IN_A_DERIVED_CLASS, &[]);
+            }
+            Some((true, Some(base_name))) => {
+                let base_static = self.classes[&base_name].static_type;
+                let signatures = match &self.type_table.get(base_static).kind {
+                    TypeKind::ObjectType { construct_signatures, .. } => construct_signatures.clone(),
+                    _ => Vec::new(),
+                };
+                if !signatures.is_empty() {
+                    self.resolve_call(&signatures, &args, &[]);
+                }
+            }
+            _ => {}
+        }
+        if let Some(called) = self.super_call_state.as_mut() {
+            *called = true;
+        }
+        self.type_table.void_type
+    }
+
+    fn check_if_statement(&mut self, node: &IfStatement<'_>) {
+        self.check_expression(node.expression);
+
+        // Attempt to extract narrowing information from the condition
+        let narrowing = self.extract_narrowing(node.expression);
+
+        if let Some((var_name, narrowed_type, else_type)) = narrowing {
+            // Save the original type
+            let original_type = self.get_declared_type(&var_name);
+
+            // Apply narrowed type for the then-branch
+            self.register_type(&var_name, narrowed_type);
+            self.check_statement(node.then_statement);
+
+            // For the else-branch, apply the inverse narrowing
+            if let Some(else_stmt) = node.else_statement {
+                if let Some(et) = else_type {
+                    self.register_type(&var_name, et);
+                } else if let Some(orig) = original_type {
+                    self.register_type(&var_name, orig);
+                }
+                self.check_statement(else_stmt);
+            }
+
+            // Restore original type after the if/else
+            if let Some(orig) = original_type {
+                self.register_type(&var_name, orig);
+            }
+        } else {
+            // No narrowing — just check both branches normally
+            self.check_statement(node.then_statement);
+            if let Some(else_stmt) = node.else_statement {
+                self.check_statement(else_stmt);
+            }
+        }
+    }
+
+    /// Extract narrowing information from a condition expression.
+    /// Returns (variable_name, narrowed_type_for_then, narrowed_type_for_else).
+    fn extract_narrowing(&mut self, expr: &Expression<'_>) -> Option<(String, TypeId, Option<TypeId>)> {
+        match expr {
+            // typeof x === "string" / typeof x === "number" / etc.
+            Expression::Binary(bin) => {
+                let is_eq = matches!(
+                    bin.operator_token.data.kind,
+                    SyntaxKind::EqualsEqualsEqualsToken | SyntaxKind::EqualsEqualsToken
+                );
+                let is_neq = matches!(
+                    bin.operator_token.data.kind,
+                    SyntaxKind::ExclamationEqualsEqualsToken | SyntaxKind::ExclamationEqualsToken
+                );
+
+                if !is_eq && !is_neq { return None; }
+
+                // Check for: typeof x === "string" or "string" === typeof x
+                let (typeof_expr, string_lit) = match (bin.left, bin.right) {
                     (Expression::TypeOf(t), Expression::StringLiteral(s)) => (Some(t), Some(s)),
                     (Expression::StringLiteral(s), Expression::TypeOf(t)) => (Some(t), Some(s)),
                     _ => (None, None),
@@ -976,23 +3683,37 @@ impl Checker {
                     }
                 }
 
-                // Check for: x !== null, x !== undefined, x != null
-                if is_neq {
-                    if let (Expression::Identifier(id), Expression::NullKeyword(_)) | (Expression::NullKeyword(_), Expression::Identifier(id)) = (bin.left, bin.right) {
-                        let var_name = id.text_name.clone();
-                        if let Some(orig) = self.get_declared_type(&var_name) {
-                            let narrowed = self.get_non_nullable_type(orig);
-                            return Some((var_name, narrowed, None));
+                // Check for: x === null, x !== undefined, x != null. The loose
+                // operators match both null and undefined.
+                let (null_type, undefined_type) = (self.type_table.null_type, self.type_table.undefined_type);
+                let nullish_type = |e: &Expression<'_>| match e {
+                    Expression::NullKeyword(_) => Some(null_type),
+                    Expression::Identifier(id) if id.text_name == "undefined" => Some(undefined_type),
+                    _ => None,
+                };
+                let comparison = [(bin.left, bin.right), (bin.right, bin.left)].into_iter()
+                    .find_map(|(reference, literal)| match reference {
+                        Expression::Identifier(id) if id.text_name != "undefined" => {
+                            nullish_type(literal).map(|t| (id.text_name.clone(), t))
                         }
-                    }
-                }
-
-                // Check for: x === null
-                if is_eq {
-                    if let (Expression::Identifier(id), Expression::NullKeyword(_)) | (Expression::NullKeyword(_), Expression::Identifier(id)) = (bin.left, bin.right) {
-                        let var_name = id.text_name.clone();
-                        return Some((var_name, self.type_table.null_type, None));
-                    }
+                        _ => None,
+                    });
+                if let Some((var_name, nullish)) = comparison {
+                    let declared = self.get_declared_type(&var_name)?;
+                    let loose = matches!(
+                        bin.operator_token.data.kind,
+                        SyntaxKind::EqualsEqualsToken | SyntaxKind::ExclamationEqualsToken
+                    );
+                    let (matching, rest) = if loose {
+                        (self.create_union_type(vec![null_type, undefined_type]), self.get_non_nullable_type(declared))
+                    } else {
+                        (nullish, self.remove_type_from_union(declared, nullish))
+                    };
+                    return if is_eq {
+                        Some((var_name, matching, Some(rest)))
+                    } else {
+                        Some((var_name, rest, Some(matching)))
+                    };
                 }
 
                 // Discriminated union narrowing: x.tag === "value" or "value" === x.tag
@@ -1051,28 +3772,71 @@ impl Checker {
                 }
                 None
             }
-            // Type guard call: if (isString(x)) where isString has `x is string` return type
+            // Type guard call: `if (isString(x))` where `isString` returns `x is string`
             Expression::Call(call) => {
-                if let Expression::Identifier(func_id) = call.expression {
-                    let func_name = &func_id.text_name;
-                    if let Some((param_name, guard_type)) = self.type_guards.get(func_name).cloned() {
-                        // Find the argument matching the parameter name
-                        if let Some(first_arg) = call.arguments.first() {
-                            if let Expression::Identifier(arg_id) = first_arg {
-                                let var_name = arg_id.text_name.clone();
-                                return Some((var_name, guard_type, None));
-                            }
-                        }
-                        // If the parameter name matches a variable
-                        let _ = param_name; // used for future full matching
-                    }
-                }
-                None
+                let predicate = self.call_type_predicates.get(&call.data.range).cloned()?;
+                let candidate = predicate.type_id.filter(|_| !predicate.is_assertion())?;
+                let var_name = self.get_type_predicate_reference(call, &predicate)?;
+                let declared = self.get_declared_type(&var_name)?;
+                let narrowed = self.narrow_type_by_type_predicate(declared, candidate, true);
+                let else_type = self.narrow_type_by_type_predicate(declared, candidate, false);
+                Some((var_name, narrowed, Some(else_type)))
+            }
+            _ => None,
+        }
+    }
+
+    /// The name of the variable a call's type predicate narrows: the argument
+    /// for the predicate's parameter, or the receiver of a `this is T` method.
+    fn get_type_predicate_reference(&self, call: &CallExpression<'_>, predicate: &TypePredicate) -> Option<String> {
+        let reference = match predicate.kind {
+            TypePredicateKind::Identifier | TypePredicateKind::AssertsIdentifier => {
+                call.arguments.get(predicate.parameter_index?)?
             }
+            TypePredicateKind::This | TypePredicateKind::AssertsThis => match call.expression {
+                Expression::PropertyAccess(access) => access.expression,
+                _ => return None,
+            },
+        };
+        match reference {
+            Expression::Identifier(id) => Some(id.text_name.clone()),
             _ => None,
         }
     }
 
+    /// Narrow a declared type by a type predicate's type. Where the predicate
+    /// holds, each union constituent is kept if it is a `candidate` and
+    /// replaced by `candidate` if it is a supertype of it; where it does not,
+    /// the constituents that are `candidate`s are removed.
+    fn narrow_type_by_type_predicate(&mut self, declared: TypeId, candidate: TypeId, assume_true: bool) -> TypeId {
+        if self.type_table.get(declared).flags.intersects(TypeFlags::ANY | TypeFlags::UNKNOWN) {
+            return if assume_true { candidate } else { declared };
+        }
+        let constituents = match &self.type_table.get(declared).kind {
+            TypeKind::Union { types } => types.clone(),
+            _ => vec![declared],
+        };
+        let mut narrowed = Vec::with_capacity(constituents.len());
+        for t in constituents {
+            let is_candidate = self.is_type_assignable_to(t, candidate);
+            if !assume_true {
+                if !is_candidate {
+                    narrowed.push(t);
+                }
+            } else if is_candidate {
+                narrowed.push(t);
+            } else if self.is_type_assignable_to(candidate, t) {
+                narrowed.push(candidate);
+            }
+        }
+        match narrowed.len() {
+            0 if assume_true => candidate,
+            0 => self.type_table.never_type,
+            1 => narrowed[0],
+            _ => self.create_union_type(narrowed),
+        }
+    }
+
     /// Filter a union type to only include members that have a specific discriminant property
     /// matching a literal value. For discriminated union narrowing like `x.kind === "circle"`.
     fn filter_union_by_discriminant(&mut self, type_id: TypeId, prop_name: &str, lit_value: &str) -> TypeId {
@@ -1158,50 +3922,28 @@ impl Checker {
     }
 
     /// Convert an expression's type to a deep-readonly literal type for `as const`.
-    /// - Primitive literals become literal types (string/number/boolean)
+    /// - Literals keep their literal types, which no longer widen
     /// - Arrays become readonly tuples with literal element types
     /// - Objects get readonly properties with literal types
     fn get_const_type(&mut self, expr: &Expression<'_>, expr_type: TypeId) -> TypeId {
         match expr {
-            Expression::StringLiteral(s) => {
-                self.type_table.add_type(
-                    TypeFlags::STRING_LITERAL,
-                    TypeKind::StringLiteral { value: s.text_name.clone(), regular: false },
-                )
-            }
-            Expression::NumericLiteral(n) => {
-                let val: f64 = n.text_name.parse().unwrap_or(0.0);
-                self.type_table.add_type(
-                    TypeFlags::NUMBER_LITERAL,
-                    TypeKind::NumberLiteral { value: val },
-                )
-            }
-            Expression::TrueKeyword(_) => {
-                self.type_table.add_type(
-                    TypeFlags::BOOLEAN_LITERAL,
-                    TypeKind::BooleanLiteral { value: true },
-                )
-            }
-            Expression::FalseKeyword(_) => {
-                self.type_table.add_type(
-                    TypeFlags::BOOLEAN_LITERAL,
-                    TypeKind::BooleanLiteral { value: false },
-                )
-            }
+            Expression::Parenthesized(p) => self.get_const_type(p.expression, expr_type),
             Expression::ArrayLiteral(arr) => {
                 // as const on array → readonly tuple
                 let element_types: Vec<TypeId> = arr.elements.iter().map(|elem| {
                     let elem_type = self.check_expression(elem);
-                    self.narrow_to_literal(elem, elem_type)
+                    self.get_const_type(elem, elem_type)
                 }).collect();
                 let element_flags = vec![crate::types::ElementFlags::Required; element_types.len()];
-                self.type_table.add_type(
+                let tuple_type = self.type_table.add_type(
                     TypeFlags::OBJECT,
                     TypeKind::Tuple {
                         element_types,
                         element_flags,
                     },
-                )
+                );
+                self.type_table.set_readonly_tuple(tuple_type);
+                tuple_type
             }
             Expression::ObjectLiteral(obj) => {
                 // as const on object → readonly object with literal property types
@@ -1211,7 +3953,7 @@ impl Checker {
                         ObjectLiteralElement::PropertyAssignment(pa) => {
                             let name = self.property_name_text(&pa.name);
                             let val_type = self.check_expression(pa.initializer);
-                            let const_type = self.narrow_to_literal(pa.initializer, val_type);
+                            let const_type = self.get_const_type(pa.initializer, val_type);
                             members.insert(name, const_type);
                         }
                         ObjectLiteralElement::ShorthandPropertyAssignment(spa) => {
@@ -1222,7 +3964,8 @@ impl Checker {
                         _ => {}
                     }
                 }
-                self.type_table.add_type(
+                let names: Vec<String> = members.keys().cloned().collect();
+                let const_type = self.type_table.add_type(
                     TypeFlags::OBJECT,
                     TypeKind::ObjectType {
                         object_flags: ObjectFlags::ANONYMOUS,
@@ -1231,9 +3974,13 @@ impl Checker {
                         construct_signatures: vec![],
                         index_infos: vec![],
                     },
-                )
+                );
+                for name in names {
+                    self.type_table.set_property_flags(const_type, &name, PropertyFlags::READONLY);
+                }
+                const_type
             }
-            _ => expr_type,
+            _ => self.get_regular_type_of_literal_type(expr_type),
         }
     }
 
@@ -1304,6 +4051,12 @@ impl Checker {
                         return_type: self.substitute_type(sig.return_type, type_args),
                         min_argument_count: sig.min_argument_count,
                         has_rest_parameter: sig.has_rest_parameter,
+                        this_type: sig.this_type.map(|t| self.substitute_type(t, type_args)),
+                        is_method: sig.is_method,
+                        type_predicate: sig.type_predicate.as_ref().map(|predicate| TypePredicate {
+                            type_id: predicate.type_id.map(|t| self.substitute_type(t, type_args)),
+                            ..predicate.clone()
+                        }),
                     }
                 }).collect();
 
@@ -1384,2058 +4137,7194 @@ impl Checker {
                     let case_type = self.check_expression(c.expression);
                     // Check comparability
                     let _ = (switch_type, case_type);
-                    for s in c.statements.iter() { self.check_statement(s); }
+                    self.check_block_statements(c.statements);
                 }
                 CaseOrDefaultClause::DefaultClause(d) => {
-                    for s in d.statements.iter() { self.check_statement(s); }
+                    self.check_block_statements(d.statements);
                 }
             }
+            let clause_range = match clause {
+                CaseOrDefaultClause::CaseClause(c) => c.data.range,
+                CaseOrDefaultClause::DefaultClause(d) => d.data.range,
+            };
+            if self.no_fallthrough_cases_in_switch && self.binder.is_fallthrough_clause(clause_range) {
+                self.error(&messages::FALLTHROUGH_CASE_IN_SWITCH, &[]);
+            }
         }
     }
 
     fn check_try_statement(&mut self, node: &TryStatement<'_>) {
-        for s in node.try_block.statements.iter() { self.check_statement(s); }
+        self.check_block_statements(node.try_block.statements);
         if let Some(ref catch) = node.catch_clause {
             // Register catch clause variable (e.g., `catch (e)`)
             if let Some(ref var_decl) = catch.variable_declaration {
-                if let BindingName::Identifier(ref id) = var_decl.name {
-                    if !id.text_name.is_empty() {
-                        // Catch clause variable is `unknown` in strict mode, `any` otherwise
-                        let catch_type = if self.strict_null_checks {
-                            self.type_table.unknown_type
-                        } else {
-                            self.type_table.any_type
-                        };
-                        self.register_type(&id.text_name, catch_type);
+                // The variable may only be annotated `any` or `unknown`; without an
+                // annotation it is `unknown` under useUnknownInCatchVariables.
+                let catch_type = match var_decl.type_annotation {
+                    Some(annotation) => {
+                        let declared = self.get_type_from_type_node(annotation);
+                        if !self.type_table.get(declared).flags.intersects(TypeFlags::ANY | TypeFlags::UNKNOWN) {
+                            self.error(&messages::CATCH_CLAUSE_VARIABLE_TYPE_ANNOTATION_MUST_BE_ANY_OR_UNKNOWN_IF_SPECIFIED, &[]);
+                        }
+                        declared
                     }
+                    None if self.use_unknown_in_catch_variables => self.type_table.unknown_type,
+                    None => self.type_table.any_type,
+                };
+                if let BindingName::Identifier(ref id) = var_decl.name {
+                    self.register_declaration_type(id.data.range, &id.text_name, catch_type);
                 }
             }
-            for s in catch.block.statements.iter() { self.check_statement(s); }
+            self.check_block_statements(catch.block.statements);
         }
         if let Some(ref finally) = node.finally_block {
-            for s in finally.statements.iter() { self.check_statement(s); }
+            self.check_block_statements(finally.statements);
         }
     }
 
+    /// Check an enum declaration. Each member with a constant value gets an
+    /// enum literal type of its own; the enum type is the union of those, and
+    /// the enum name as a value is an object holding the members.
     fn check_enum_declaration(&mut self, node: &EnumDeclaration<'_>) {
+        let enum_name = node.name.text_name.clone();
+        self.record_declaration_namespace(&enum_name);
+        let mut values: IndexMap<String, Option<EnumValue>> = IndexMap::new();
+        let mut members: IndexMap<String, TypeId> = IndexMap::new();
+        // A later declaration of the same enum extends the earlier enum
+        // object; its members may refer to the earlier ones.
+        let merged_object = self.enum_types.contains_key(&enum_name)
+            .then(|| self.get_declared_type(&enum_name))
+            .flatten();
+        if let Some(TypeKind::ObjectType { members: earlier, .. }) = merged_object.map(|id| &self.type_table.get(id).kind) {
+            for (member_name, &member_type) in earlier {
+                let value = match &self.type_table.get(member_type).kind {
+                    TypeKind::NumberLiteral { value } => Some(EnumValue::Number(*value)),
+                    TypeKind::StringLiteral { value, .. } => Some(EnumValue::String(value.clone())),
+                    _ => None,
+                };
+                values.insert(member_name.clone(), value);
+                members.insert(member_name.clone(), member_type);
+            }
+        }
+        let mut next_value = Some(0.0);
         for member in node.members.iter() {
-            if let Some(init) = member.initializer {
-                let init_type = self.check_expression(init);
-                let ty = self.type_table.get(init_type);
-                if !ty.flags.intersects(TypeFlags::NUMBER_LIKE) && !ty.flags.contains(TypeFlags::STRING_LIKE) {
-                    // Enum initializer must be number or string
+            let member_name = self.property_name_text(&member.name);
+            let value = match member.initializer {
+                Some(init) => {
+                    let value = self.evaluate_enum_initializer(init, &enum_name, &values);
+                    if value.is_none() {
+                        self.check_expression(init);
+                    }
+                    value
+                }
+                None => {
+                    // Members after a string or computed member need an initializer
+                    if next_value.is_none() {
+                        self.error(&messages::ENUM_MEMBER_MUST_HAVE_INITIALIZER, &[]);
+                    }
+                    next_value.map(EnumValue::Number)
                 }
+            };
+            next_value = match &value {
+                Some(EnumValue::Number(v)) => Some(v + 1.0),
+                _ => None,
+            };
+            let member_type = match &value {
+                Some(EnumValue::Number(v)) => self.type_table.add_type(
+                    TypeFlags::NUMBER_LITERAL | TypeFlags::ENUM_LITERAL,
+                    TypeKind::NumberLiteral { value: *v },
+                ),
+                Some(EnumValue::String(text)) => self.type_table.add_type(
+                    TypeFlags::STRING_LITERAL | TypeFlags::ENUM_LITERAL,
+                    TypeKind::StringLiteral { value: text.clone(), regular: true },
+                ),
+                None => self.type_table.number_type,
+            };
+            if value.is_some() {
+                self.enum_type_names.insert(member_type, format!("{}.{}", enum_name, member_name));
             }
+            values.insert(member_name.clone(), value);
+            members.insert(member_name, member_type);
         }
-    }
 
-    /// Resolve a type alias declaration:  `type Name = UnderlyingType;`
-    ///
-    /// This registers the alias name so that later `TypeReference` lookups
-    /// (e.g. `let x: Name`) resolve to the underlying type.
-    fn check_type_alias_declaration(&mut self, node: &TypeAliasDeclaration<'_>) {
-        let name = node.name.text_name.clone();
-        let resolved = self.get_type_from_type_node(node.type_node);
-        self.register_type(&name, resolved);
+        // An enum with computed members is just a number
+        let is_literal_enum = values.values().all(Option::is_some);
+        let enum_type = if is_literal_enum && !members.is_empty() {
+            let member_types: Vec<TypeId> = members.values().copied().collect();
+            let enum_type = self.create_union_type(member_types.clone());
+            for member_type in member_types {
+                self.enum_literal_bases.insert(member_type, enum_type);
+            }
+            enum_type
+        } else {
+            self.type_table.number_type
+        };
+        if !self.enum_type_names.contains_key(&enum_type) && enum_type != self.type_table.number_type {
+            self.enum_type_names.insert(enum_type, enum_name.clone());
+        }
+        self.enum_types.insert(enum_name.clone(), enum_type);
+
+        let names: Vec<String> = members.keys().cloned().collect();
+        let object_type = match merged_object {
+            Some(object_type) => {
+                if let TypeKind::ObjectType { members: existing, .. } = &mut self.type_table.get_mut(object_type).kind {
+                    *existing = members;
+                }
+                object_type
+            }
+            None => self.type_table.add_type(
+                TypeFlags::OBJECT,
+                TypeKind::ObjectType {
+                    object_flags: ObjectFlags::ANONYMOUS,
+                    members,
+                    call_signatures: vec![],
+                    construct_signatures: vec![],
+                    index_infos: vec![],
+                },
+            ),
+        };
+        for name in names {
+            self.type_table.set_property_flags(object_type, &name, PropertyFlags::READONLY);
+        }
+        self.enum_type_names.insert(object_type, format!("typeof {}", enum_name));
+        let modifier_flags = node.data.modifier_flags;
+        if modifier_flags.contains(ModifierFlags::CONST) && (self.in_ambient_context || modifier_flags.contains(ModifierFlags::AMBIENT)) {
+            self.ambient_const_enum_objects.insert(object_type);
+        }
+        self.register_declaration_type(node.name.data.range, &enum_name, object_type);
     }
 
-    /// Resolve an interface declaration and register it as a proper ObjectType
-    /// so that subsequent `TypeReference` lookups produce the right shape.
-    fn check_interface_declaration(&mut self, node: &InterfaceDeclaration<'_>) {
-        let name = node.name.text_name.clone();
+    /// The constant value of an enum member initializer, or `None` when it is
+    /// computed at runtime. Earlier members may be referenced by name or as
+    /// `E.Member`.
+    fn evaluate_enum_initializer(
+        &self,
+        expr: &Expression<'_>,
+        enum_name: &str,
+        values: &IndexMap<String, Option<EnumValue>>,
+    ) -> Option<EnumValue> {
+        match expr {
+            Expression::NumericLiteral(n) => Some(EnumValue::Number(parse_numeric_literal_text(&n.text_name))),
+            Expression::StringLiteral(s) => Some(EnumValue::String(s.text_name.clone())),
+            Expression::Parenthesized(p) => self.evaluate_enum_initializer(p.expression, enum_name, values),
+            Expression::Identifier(id) => values.get(&id.text_name).cloned().flatten(),
+            Expression::PropertyAccess(access) => match (access.expression, &access.name) {
+                (Expression::Identifier(object), MemberName::Identifier(member)) if object.text_name == enum_name => {
+                    values.get(&member.text_name).cloned().flatten()
+                }
+                _ => None,
+            },
+            Expression::PrefixUnary(unary) => {
+                let Some(EnumValue::Number(v)) = self.evaluate_enum_initializer(unary.operand, enum_name, values) else {
+                    return None;
+                };
+                match unary.operator {
+                    SyntaxKind::PlusToken => Some(EnumValue::Number(v)),
+                    SyntaxKind::MinusToken => Some(EnumValue::Number(-v)),
+                    SyntaxKind::TildeToken => Some(EnumValue::Number(f64::from(!to_int32(v)))),
+                    _ => None,
+                }
+            }
+            Expression::Binary(binary) => {
+                let left = self.evaluate_enum_initializer(binary.left, enum_name, values)?;
+                let right = self.evaluate_enum_initializer(binary.right, enum_name, values)?;
+                let operator = binary.operator_token.data.kind;
+                let (l, r) = match (left, right) {
+                    (EnumValue::Number(l), EnumValue::Number(r)) => (l, r),
+                    (left, right) if operator == SyntaxKind::PlusToken => {
+                        let text = |value: EnumValue| match value {
+                            EnumValue::Number(v) => number_to_string(v),
+                            EnumValue::String(s) => s,
+                        };
+                        return Some(EnumValue::String(text(left) + &text(right)));
+                    }
+                    _ => return None,
+                };
+                let shift = (to_int32(r) as u32) & 31;
+                let value = match operator {
+                    SyntaxKind::PlusToken => l + r,
+                    SyntaxKind::MinusToken => l - r,
+                    SyntaxKind::AsteriskToken => l * r,
+                    SyntaxKind::SlashToken => l / r,
+                    SyntaxKind::PercentToken => l % r,
+                    SyntaxKind::AsteriskAsteriskToken => l.powf(r),
+                    SyntaxKind::BarToken => f64::from(to_int32(l) | to_int32(r)),
+                    SyntaxKind::AmpersandToken => f64::from(to_int32(l) & to_int32(r)),
+                    SyntaxKind::CaretToken => f64::from(to_int32(l) ^ to_int32(r)),
+                    SyntaxKind::LessThanLessThanToken => f64::from(to_int32(l).wrapping_shl(shift)),
+                    SyntaxKind::GreaterThanGreaterThanToken => f64::from(to_int32(l).wrapping_shr(shift)),
+                    SyntaxKind::GreaterThanGreaterThanGreaterThanToken => f64::from((to_int32(l) as u32).wrapping_shr(shift)),
+                    _ => return None,
+                };
+                Some(EnumValue::Number(value))
+            }
+            _ => None,
+        }
+    }
 
-        // If this interface was already registered (declaration merging),
-        // we merge members into the existing object type.
-        let existing = self.get_declared_type(&name);
+    /// The member type named by `E.A` in a type position, if `E` is an enum.
+    fn get_enum_member_type_from_qualified_name(&self, name: &QualifiedName<'_>) -> Option<TypeId> {
+        let EntityName::Identifier(enum_name) = &name.left else {
+            return None;
+        };
+        if !self.enum_types.contains_key(&enum_name.text_name) {
+            return None;
+        }
+        let enum_object = self.get_declared_type(&enum_name.text_name)?;
+        match &self.type_table.get(enum_object).kind {
+            TypeKind::ObjectType { members, .. } => members.get(&name.right.text_name).copied(),
+            _ => None,
+        }
+    }
 
-        let mut members: IndexMap<String, TypeId> = IndexMap::new();
-        let mut call_signatures: Vec<Signature> = Vec::new();
-        let mut construct_signatures: Vec<Signature> = Vec::new();
-        let mut index_infos: Vec<IndexInfo> = Vec::new();
+    /// Check a namespace declaration. Its exported values become members of
+    /// the namespace object, which merges into an earlier class, function,
+    /// enum or namespace of the same name. Names declared in the body go out
+    /// of scope afterwards, except exported interfaces and aliases, which
+    /// are registered by their qualified names, like `N.Foo`. Returns those
+    /// names.
+    fn check_module_declaration(&mut self, node: &ModuleDeclaration<'_>) -> Vec<String> {
+        let is_ambient = node.data.modifier_flags.contains(ModifierFlags::AMBIENT)
+            || node.data.flags.contains(NodeFlags::GLOBAL_AUGMENTATION)
+            || matches!(node.name, ModuleName::StringLiteral(_));
+        let saved_ambient_context = self.in_ambient_context;
+        self.in_ambient_context |= is_ambient;
+        let exported_types = self.check_module_declaration_worker(node);
+        self.in_ambient_context = saved_ambient_context;
+        exported_types
+    }
 
-        // Pull existing members when merging declarations
-        if let Some(existing_id) = existing {
-            if let TypeKind::ObjectType {
-                members: ref existing_members,
-                call_signatures: ref existing_calls,
-                construct_signatures: ref existing_constructs,
-                index_infos: ref existing_indexes,
-                ..
-            } = self.type_table.get(existing_id).kind
-            {
-                members = existing_members.clone();
-                call_signatures = existing_calls.clone();
-                construct_signatures = existing_constructs.clone();
-                index_infos = existing_indexes.clone();
-            }
+    /// Record the namespaces a type is declared in, which qualify its name
+    /// when it is displayed.
+    fn record_declaration_namespace(&mut self, name: &str) {
+        if !self.enclosing_namespaces.is_empty() {
+            self.declaration_namespaces.insert(name.to_string(), self.enclosing_namespaces.join("."));
         }
+    }
 
-        // Process each member of the interface
-        for member in node.members.iter() {
-            match member {
-                TypeElement::PropertySignature(prop) => {
-                    let prop_name = self.get_property_name_text(&prop.name);
-                    let prop_type = self
-                        .get_type_from_type_annotation(prop.type_annotation)
-                        .unwrap_or(self.type_table.any_type);
-
-                    // If the property is optional (has `?`), wrap in union with undefined
-                    let final_type = if prop.question_token.is_some() {
-                        self.create_union_type(vec![prop_type, self.type_table.undefined_type])
-                    } else {
-                        prop_type
-                    };
+    /// The name an interface or alias is registered by: qualified by the
+    /// namespaces it is declared in, so that those of different namespaces,
+    /// and of the global scope, stay apart.
+    fn qualified_declaration_name(&self, name: &str) -> String {
+        if self.enclosing_namespaces.is_empty() {
+            return name.to_string();
+        }
+        format!("{}.{}", self.enclosing_namespaces.join("."), name)
+    }
 
-                    // Overwrite if the name already exists (merge semantics)
-                    members.insert(prop_name, final_type);
-                }
-                TypeElement::MethodSignature(method) => {
-                    let method_name = self.get_property_name_text(&method.name);
-                    let method_type = self.build_method_type(
-                        method.parameters,
-                        method.return_type,
-                        method.question_token.is_some(),
-                    );
+    /// The name a type reference like `Foo` or `N.Foo` resolves by: that of
+    /// the interface or alias it names in the enclosing namespaces, or else
+    /// its last name.
+    fn resolve_type_reference_name(&self, name: &str) -> String {
+        let Some((_, last)) = name.rsplit_once('.') else {
+            return self.namespace_type_names.get(name).cloned().unwrap_or_else(|| name.to_string());
+        };
+        (0..=self.enclosing_namespaces.len()).rev()
+            .map(|depth| match depth {
+                0 => name.to_string(),
+                _ => format!("{}.{}", self.enclosing_namespaces[..depth].join("."), name),
+            })
+            .find(|qualified| self.declared_types.contains_key(qualified))
+            .unwrap_or_else(|| last.to_string())
+    }
 
-                    members.insert(method_name, method_type);
+    fn check_module_declaration_worker(&mut self, node: &ModuleDeclaration<'_>) -> Vec<String> {
+        // The declarations of a global augmentation are globals.
+        if node.data.flags.contains(NodeFlags::GLOBAL_AUGMENTATION) {
+            if let Some(ModuleBody::ModuleBlock(block)) = &node.body {
+                for stmt in block.statements.iter() {
+                    self.check_statement(stmt);
                 }
-                TypeElement::CallSignature(call) => {
-                    let sig = self.build_signature(call.parameters, call.return_type);
-                    call_signatures.push(sig);
+            }
+            return Vec::new();
+        }
+        let name = match &node.name {
+            ModuleName::Identifier(name) => name,
+            ModuleName::StringLiteral(name) => {
+                self.check_ambient_module_declaration(&name.text_name, node);
+                return Vec::new();
+            }
+        };
+        let name = name.text_name.clone();
+        let saved_types = self.declared_types.clone();
+        let saved_type_names = self.namespace_type_names.clone();
+        let mut exports: IndexMap<String, (TypeId, PropertyFlags)> = IndexMap::new();
+        let mut exported_types: Vec<String> = Vec::new();
+        self.enclosing_namespaces.push(name.clone());
+        match &node.body {
+            Some(ModuleBody::ModuleBlock(block)) => {
+                for stmt in block.statements.iter() {
+                    let type_name = match stmt {
+                        Statement::InterfaceDeclaration(n) => &n.name.text_name,
+                        Statement::TypeAliasDeclaration(n) => &n.name.text_name,
+                        _ => continue,
+                    };
+                    let qualified = self.qualified_declaration_name(type_name);
+                    self.namespace_type_names.insert(type_name.clone(), qualified);
                 }
-                TypeElement::ConstructSignature(ctor) => {
-                    let sig = self.build_signature(ctor.parameters, ctor.return_type);
-                    construct_signatures.push(sig);
+                // Declarations of an ambient namespace are exported unless it
+                // has export declarations of its own.
+                let is_export_context = self.in_ambient_context
+                    && !block.statements.iter().any(|stmt| matches!(stmt, Statement::ExportDeclaration(_) | Statement::ExportAssignment(_)));
+                for stmt in block.statements.iter() {
+                    let is_exported = is_export_context || stmt.data().modifier_flags.contains(ModifierFlags::EXPORT);
+                    match stmt {
+                        Statement::ModuleDeclaration(inner) => {
+                            let inner_types = self.check_module_declaration(inner);
+                            if is_exported {
+                                exported_types.extend(inner_types);
+                            }
+                        }
+                        _ => self.check_statement(stmt),
+                    }
+                    if is_exported {
+                        self.collect_namespace_exports(stmt, &mut exports, &mut exported_types);
+                    }
                 }
-                TypeElement::IndexSignature(idx) => {
-                    // Index signature: [key: KeyType]: ValueType
-                    let key_type = if let Some(first_param) = idx.parameters.iter().next() {
-                        self.get_type_from_type_annotation(first_param.type_annotation)
-                            .unwrap_or(self.type_table.string_type)
-                    } else {
-                        self.type_table.string_type
-                    };
-                    let value_type = self
-                        .get_type_from_type_annotation(idx.type_annotation)
-                        .unwrap_or(self.type_table.any_type);
-                    index_infos.push(IndexInfo {
-                        key_type,
-                        type_id: value_type,
-                        is_readonly: false,
-                    });
+            }
+            Some(ModuleBody::ModuleDeclaration(inner)) => {
+                exported_types.extend(self.check_module_declaration(inner));
+                if let ModuleName::Identifier(inner_name) = &inner.name {
+                    if let Some(&inner_type) = self.namespaces.get(&inner_name.text_name) {
+                        exports.insert(inner_name.text_name.clone(), (inner_type, PropertyFlags::empty()));
+                    }
                 }
             }
+            None => {}
         }
+        self.enclosing_namespaces.pop();
+        self.namespace_type_names = saved_type_names;
 
-        // Resolve heritage clauses (extends)
-        if let Some(heritage) = node.heritage_clauses {
-            for clause in heritage.iter() {
-                for expr_with_args in clause.types.iter() {
-                    // Resolve the base type from the expression
-                    let base_type_id = self.check_expression(expr_with_args.expression);
-                    // Merge members from the base type into this interface
-                    let base_members: IndexMap<String, TypeId> =
-                        if let TypeKind::ObjectType { members: ref bm, .. } =
-                            self.type_table.get(base_type_id).kind
-                        {
-                            bm.clone()
-                        } else {
-                            IndexMap::new()
-                        };
-                    for (base_name, base_tid) in base_members {
-                        // Only add if not already overridden
-                        if !members.contains_key(&base_name) {
-                            members.insert(base_name, base_tid);
-                        }
+        let kept: Vec<(String, TypeId)> = exported_types.iter()
+            .filter_map(|type_name| self.get_declared_type(type_name).map(|id| (type_name.clone(), id)))
+            .collect();
+        self.declared_types = saved_types;
+        for (type_name, type_id) in kept {
+            self.declared_types.insert(type_name, type_id);
+        }
+
+        let target = self.get_declared_type(&name).filter(|&id| {
+            self.namespaces.contains_key(&name)
+                || self.classes.contains_key(&name)
+                || self.enum_types.contains_key(&name)
+                || matches!(&self.type_table.get(id).kind, TypeKind::ObjectType { call_signatures, .. } if !call_signatures.is_empty())
+        });
+        if target.is_none() && !is_instantiated_module(node) {
+            return exported_types;
+        }
+        let namespace_type = match target {
+            Some(target) => {
+                if let TypeKind::ObjectType { members, .. } = &mut self.type_table.get_mut(target).kind {
+                    for (member_name, (member_type, _)) in &exports {
+                        members.insert(member_name.clone(), *member_type);
                     }
                 }
+                target
             }
+            None => self.type_table.add_type(
+                TypeFlags::OBJECT,
+                TypeKind::ObjectType {
+                    object_flags: ObjectFlags::ANONYMOUS,
+                    members: exports.iter().map(|(member_name, (member_type, _))| (member_name.clone(), *member_type)).collect(),
+                    call_signatures: vec![],
+                    construct_signatures: vec![],
+                    index_infos: vec![],
+                },
+            ),
+        };
+        for (member_name, (_, flags)) in &exports {
+            self.type_table.set_property_flags(namespace_type, member_name, *flags);
         }
-
-        let interface_type = self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::INTERFACE,
-                members,
-                call_signatures,
-                construct_signatures,
-                index_infos,
-            },
-        );
-        self.register_type(&name, interface_type);
+        self.register_type(&name, namespace_type);
+        self.namespaces.insert(name, namespace_type);
+        exported_types
     }
 
-    /// Extract a textual name from a `PropertyName` node.
-    fn get_property_name_text(&self, name: &PropertyName<'_>) -> String {
-        match name {
-            PropertyName::Identifier(id) => id.text_name.clone(),
-            PropertyName::StringLiteral(_tok) => {
-                // Token doesn't carry source text; fall back to empty for now.
-                // A full implementation would resolve from the source map.
-                String::new()
-            }
-            PropertyName::NumericLiteral(_tok) => String::new(),
-            PropertyName::PrivateIdentifier(id) => id.text_name.clone(),
-            PropertyName::ComputedPropertyName(_) => "[computed]".to_string(),
+    /// Check an ambient module declaration, adding its declarations to the
+    /// exports of the module. The exports of earlier declarations are in
+    /// scope, so that an augmentation merges with them. Every declaration of
+    /// an ambient module is exported.
+    fn check_ambient_module_declaration(&mut self, module_name: &str, node: &ModuleDeclaration<'_>) {
+        let mut module = self.ambient_modules.get(module_name).cloned().unwrap_or_default();
+        let Some(ModuleBody::ModuleBlock(block)) = &node.body else {
+            module.is_shorthand = true;
+            self.ambient_modules.insert(module_name.to_string(), module);
+            return;
+        };
+        let saved_types = self.declared_types.clone();
+        for (value_name, &value_type) in &module.values {
+            self.register_type(value_name, value_type);
+        }
+        for (type_name, (type_id, type_params)) in &module.types {
+            self.register_generic_type(type_name, *type_id, type_params.clone());
         }
-    }
 
-    /// Build a `Signature` from parameter + return-type AST nodes.
-    fn build_signature(
-        &mut self,
-        parameters: &[ParameterDeclaration<'_>],
-        return_type: Option<&TypeNode<'_>>,
-    ) -> Signature {
-        let mut min_args: u32 = 0;
-        let mut has_rest = false;
-        let params: Vec<SignatureParameter> = parameters
-            .iter()
-            .map(|p| {
-                let param_name = match &p.name {
-                    BindingName::Identifier(id) => id.text_name.clone(),
-                    _ => String::new(),
-                };
-                let param_type = self
-                    .get_type_from_type_annotation(p.type_annotation)
-                    .unwrap_or(self.type_table.any_type);
-                let optional = p.question_token.is_some() || p.initializer.is_some();
-                if p.dot_dot_dot_token.is_some() {
-                    has_rest = true;
-                }
-                if !optional && p.dot_dot_dot_token.is_none() {
-                    min_args += 1;
+        let mut exports: IndexMap<String, (TypeId, PropertyFlags)> = IndexMap::new();
+        let mut exported_types: Vec<String> = Vec::new();
+        for stmt in block.statements.iter() {
+            match stmt {
+                Statement::ModuleDeclaration(inner) => exported_types.extend(self.check_module_declaration(inner)),
+                Statement::ExportAssignment(n) => {
+                    let export_type = self.check_expression(n.expression);
+                    let export_name = if n.is_export_equals { "export=" } else { "default" };
+                    exports.insert(export_name.to_string(), (export_type, PropertyFlags::empty()));
                 }
-                SignatureParameter {
-                    name: param_name,
-                    type_id: param_type,
-                    optional,
+                _ => self.check_statement(stmt),
+            }
+            self.collect_namespace_exports(stmt, &mut exports, &mut exported_types);
+            if stmt.data().modifier_flags.contains(ModifierFlags::DEFAULT) {
+                let default_name = match stmt {
+                    Statement::FunctionDeclaration(FunctionDeclaration { name: Some(id), .. })
+                    | Statement::ClassDeclaration(ClassDeclaration { name: Some(id), .. }) => Some(&id.text_name),
+                    _ => None,
+                };
+                if let Some(&(default_type, _)) = default_name.and_then(|name| exports.get(name)) {
+                    exports.insert("default".to_string(), (default_type, PropertyFlags::empty()));
                 }
-            })
-            .collect();
-
-        let ret = return_type
-            .map(|rt| self.get_type_from_type_node(rt))
-            .unwrap_or(self.type_table.void_type);
-
-        Signature {
-            type_parameters: vec![],
-            parameters: params,
-            return_type: ret,
-            min_argument_count: min_args,
-            has_rest_parameter: has_rest,
+            }
+        }
+        for type_name in exported_types {
+            if let Some(type_id) = self.get_declared_type(&type_name) {
+                let type_params = self.generic_type_parameters.get(&type_name).cloned().unwrap_or_default();
+                module.types.insert(type_name, (type_id, type_params));
+            }
+        }
+        for (export_name, (export_type, _)) in exports {
+            module.values.insert(export_name, export_type);
         }
+        self.declared_types = saved_types;
+        self.ambient_modules.insert(module_name.to_string(), module);
     }
 
-    /// Build a function-typed TypeId for a method signature.
-    fn build_method_type(
-        &mut self,
-        parameters: &[ParameterDeclaration<'_>],
-        return_type: Option<&TypeNode<'_>>,
-        _optional: bool,
-    ) -> TypeId {
-        let sig = self.build_signature(parameters, return_type);
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::ANONYMOUS,
-                members: IndexMap::new(),
-                call_signatures: vec![sig],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
-        )
-    }
-
-    // ========================================================================
-    // Expression checking
-    // ========================================================================
-
-    fn check_expression(&mut self, expr: &Expression<'_>) -> TypeId {
-        match expr {
-            Expression::Identifier(id) => {
-                self.check_identifier(id)
-            }
-            Expression::NumericLiteral(_) => self.type_table.number_type,
-            Expression::StringLiteral(_) => self.type_table.string_type,
-            Expression::BigIntLiteral(_) => self.type_table.bigint_type,
-            Expression::NoSubstitutionTemplateLiteral(_) => self.type_table.string_type,
-            Expression::TemplateExpression(n) => {
-                for span in n.template_spans.iter() { self.check_expression(span.expression); }
-                self.type_table.string_type
-            }
-            Expression::RegularExpressionLiteral(_) => {
-                self.get_regexp_type()
-            }
-            Expression::TrueKeyword(_) => self.type_table.true_type,
-            Expression::FalseKeyword(_) => self.type_table.false_type,
-            Expression::NullKeyword(_) => self.type_table.null_type,
-            Expression::ThisKeyword(_) => {
-                // In a class context, `this` is the instance type
-                // Simplified: return any (would need class context tracking)
-                self.type_table.any_type
-            }
-            Expression::SuperKeyword(_) => {
-                // super refers to the base class
-                self.type_table.any_type
-            }
-
-            Expression::Binary(n) => self.check_binary_expression(n),
-            Expression::Call(n) => self.check_call_expression(n),
-            Expression::New(n) => self.check_new_expression(n),
-            Expression::PropertyAccess(n) => self.check_property_access(n),
-            Expression::ElementAccess(n) => self.check_element_access(n),
-            Expression::Conditional(n) => self.check_conditional_expression(n),
-            Expression::Parenthesized(n) => self.check_expression(n.expression),
-            Expression::PrefixUnary(n) => self.check_prefix_unary(n),
-            Expression::PostfixUnary(n) => self.check_postfix_unary(n),
-            Expression::TypeOf(n) => { self.check_expression(n.expression); self.type_table.string_type }
-            Expression::Delete(n) => { self.check_expression(n.expression); self.type_table.boolean_type }
-            Expression::Void(n) => { self.check_expression(n.expression); self.type_table.undefined_type }
-            Expression::Await(n) => self.check_await_expression(n),
-            Expression::Yield(n) => {
-                if let Some(e) = n.expression {
-                    self.check_expression(e)
-                } else {
-                    self.type_table.undefined_type
+    /// Declare the bindings of an import from an ambient module. A module
+    /// name matching no ambient module is left to module resolution, and its
+    /// bindings stay `any`.
+    fn check_import_declaration(&mut self, node: &ImportDeclaration<'_>) {
+        let (Expression::StringLiteral(specifier), Some(clause)) = (node.module_specifier, &node.import_clause) else {
+            return;
+        };
+        let Some(pattern) = match_pattern_or_exact(self.ambient_modules.keys().map(String::as_str), &specifier.text_name) else {
+            self.check_program_module_import(&specifier.text_name, clause);
+            return;
+        };
+        let module = self.ambient_modules[pattern].clone();
+        let module_display = format!("\"{}\"", specifier.text_name);
+        let any = self.type_table.any_type;
+        if let Some(default_name) = &clause.name {
+            let default_type = match module.values.get("default").or_else(|| module.values.get("export=")) {
+                Some(&default_type) => default_type,
+                None => {
+                    if !module.is_shorthand {
+                        self.error(&messages::MODULE_0_HAS_NO_DEFAULT_EXPORT, &[&module_display]);
+                    }
+                    any
                 }
-            }
-            Expression::Spread(n) => self.check_expression(n.expression),
-            Expression::As(n) => {
-                let expr_type = self.check_expression(n.expression);
-                // Check for `as const` assertion
-                if self.is_const_type_node(n.type_node) {
-                    return self.get_const_type(n.expression, expr_type);
+            };
+            self.register_type(&default_name.text_name, default_type);
+        }
+        match &clause.named_bindings {
+            Some(NamedImportBindings::NamespaceImport(namespace)) => {
+                let namespace_type = if module.is_shorthand {
+                    any
+                } else {
+                    self.type_table.add_type(
+                        TypeFlags::OBJECT,
+                        TypeKind::ObjectType {
+                            object_flags: ObjectFlags::ANONYMOUS,
+                            members: module.values.iter()
+                                .filter(|(export_name, _)| export_name.as_str() != "export=")
+                                .map(|(export_name, &export_type)| (export_name.clone(), export_type))
+                                .collect(),
+                            call_signatures: vec![],
+                            construct_signatures: vec![],
+                            index_infos: vec![],
+                        },
+                    )
+                };
+                self.register_type(&namespace.name.text_name, namespace_type);
+            }
+            Some(NamedImportBindings::NamedImports(named)) => {
+                for element in named.elements.iter() {
+                    let local_name = &element.name.text_name;
+                    let imported_name = element.property_name.as_ref().map_or(local_name, |id| &id.text_name);
+                    if module.is_shorthand {
+                        self.register_type(local_name, any);
+                        continue;
+                    }
+                    let exported_type = module.types.get(imported_name);
+                    let exported_value = module.values.get(imported_name);
+                    if let Some((type_id, type_params)) = exported_type {
+                        self.register_generic_type(local_name, *type_id, type_params.clone());
+                    }
+                    if let Some(&value_type) = exported_value {
+                        self.register_type(local_name, value_type);
+                    }
+                    if exported_type.is_some() && exported_value.is_none() {
+                        self.type_only_imports.insert(local_name.clone());
+                        // A type imported as a value would be kept by a
+                        // verbatim emit, and fail to load at runtime.
+                        let is_type_only_import = clause.is_type_only || element.is_type_only;
+                        if self.verbatim_module_syntax && !is_type_only_import {
+                            self.error_at(element.data.range, &messages::_0_IS_A_TYPE_AND_MUST_BE_IMPORTED_USING_A_TYPE_ONLY_IMPORT_WHEN_VERBATIMMODULESYNTAX_IS_ENABLED, &[local_name]);
+                        }
+                    }
+                    if exported_type.is_none() && exported_value.is_none() {
+                        self.error(&messages::MODULE_0_HAS_NO_EXPORTED_MEMBER_1, &[&module_display, imported_name]);
+                        self.register_type(local_name, any);
+                    }
                 }
-                self.get_type_from_type_node(n.type_node)
             }
-            Expression::Satisfies(n) => {
-                let expr_type = self.check_expression(n.expression);
-                let target_type = self.get_type_from_type_node(n.type_node);
-                if !self.is_type_assignable_to(expr_type, target_type) {
-                    let src = self.type_to_string(expr_type);
-                    let tgt = self.type_to_string(target_type);
-                    self.error(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&src, &tgt]);
-                }
-                expr_type
+            None => {}
+        }
+    }
+
+    /// Declare the bindings of an import from a module file of the program
+    /// with what the exports they name denote, and record those that name
+    /// only types there, which a verbatim emit would keep. Exports of a
+    /// module not checked yet, as in an import cycle, stay `any`.
+    fn check_program_module_import(&mut self, module_name: &str, clause: &ImportClause<'_>) {
+        // The local name, the name imported, whether the import is marked
+        // `type`, and the range errors about it are reported at.
+        let mut bindings: Vec<(&Identifier, &str, bool, TextRange)> = Vec::new();
+        if let Some(default_name) = &clause.name {
+            bindings.push((default_name, "default", false, default_name.data.range));
+        }
+        if let Some(NamedImportBindings::NamedImports(named)) = &clause.named_bindings {
+            for element in named.elements.iter() {
+                let imported_name = element.property_name.as_ref().unwrap_or(&element.name);
+                bindings.push((&element.name, &imported_name.text_name, element.is_type_only, element.data.range));
             }
-            Expression::NonNull(n) => {
-                let inner = self.check_expression(n.expression);
-                // Remove null and undefined from union
-                self.get_non_nullable_type(inner)
+        }
+        let file_name = self.file_name.clone();
+        for (local_name, imported_name, is_type_only, range) in bindings {
+            if let Some(export) = self.resolve_program_export(&file_name, module_name, imported_name, 0).cloned() {
+                self.declare_program_module_import(&local_name.text_name, export);
             }
-            Expression::TypeAssertion(n) => {
-                self.check_expression(n.expression);
-                self.get_type_from_type_node(n.type_node)
+            if self.is_type_only_program_export(&file_name, module_name, imported_name, 0) != Some(true) {
+                continue;
             }
-            Expression::ArrowFunction(n) => self.check_arrow_function(n),
-            Expression::FunctionExpression(n) => self.check_function_expression(n),
-            Expression::ArrayLiteral(n) => self.check_array_literal(n),
-            Expression::ObjectLiteral(n) => self.check_object_literal(n),
-            Expression::ClassExpression(n) => { self.check_class_expression(n); self.type_table.any_type }
-            Expression::TaggedTemplate(n) => {
-                self.check_expression(n.tag);
-                self.check_expression(n.template);
-                self.type_table.any_type
+            self.type_only_imports.insert(local_name.text_name.clone());
+            if self.verbatim_module_syntax && !clause.is_type_only && !is_type_only {
+                self.error_at(range, &messages::_0_IS_A_TYPE_AND_MUST_BE_IMPORTED_USING_A_TYPE_ONLY_IMPORT_WHEN_VERBATIMMODULESYNTAX_IS_ENABLED, &[&local_name.text_name]);
             }
-            Expression::MetaProperty(_) => self.type_table.any_type,
-            Expression::OmittedExpression(_) => self.type_table.undefined_type,
         }
     }
 
-    /// Well-known globals that don't require declaration.
-    const BUILTIN_GLOBALS: &'static [&'static str] = &[
-        "console", "setTimeout", "setInterval", "clearTimeout", "clearInterval",
-        "parseInt", "parseFloat", "isNaN", "isFinite", "NaN", "Infinity",
-        "undefined", "JSON", "Math", "Date", "Object", "Array", "String",
-        "Number", "Boolean", "Symbol", "Map", "Set", "WeakMap", "WeakSet",
-        "Promise", "Proxy", "Reflect", "Error", "TypeError", "RangeError",
-        "SyntaxError", "ReferenceError", "URIError", "EvalError",
-        "RegExp", "Function", "ArrayBuffer", "DataView",
-        "Int8Array", "Uint8Array", "Int16Array", "Uint16Array",
-        "Int32Array", "Uint32Array", "Float32Array", "Float64Array",
-        "BigInt64Array", "BigUint64Array",
-        "globalThis", "window", "document", "navigator",
-        "process", "require", "module", "exports", "__dirname", "__filename",
-        "fetch", "URL", "URLSearchParams", "Headers", "Request", "Response",
-        "TextEncoder", "TextDecoder", "AbortController", "AbortSignal",
-        "queueMicrotask", "structuredClone", "atob", "btoa",
-        "alert", "confirm", "prompt",
-        "eval", "encodeURI", "decodeURI", "encodeURIComponent", "decodeURIComponent",
-    ];
+    /// Declare an import binding with what the export it names denotes.
+    fn declare_program_module_import(&mut self, local_name: &str, export: ProgramModuleExport) {
+        if let Some((type_id, type_params)) = export.declared_type {
+            self.register_generic_type(local_name, type_id, type_params);
+        }
+        if let Some(type_alias) = export.type_alias {
+            self.type_aliases.insert(local_name.to_string(), type_alias);
+        }
+        if let Some(value_type) = export.value {
+            self.register_type(local_name, value_type);
+        }
+        if let Some(class) = export.class {
+            self.classes.insert(local_name.to_string(), class);
+        }
+        if let Some(enum_type) = export.enum_type {
+            self.enum_types.insert(local_name.to_string(), enum_type);
+        }
+    }
 
-    fn check_identifier(&mut self, id: &Identifier) -> TypeId {
-        let name = &id.text_name;
+    /// What the export `name` of the program module that `module_name`
+    /// names, imported by `containing_file`, denotes, following re-exports.
+    /// `None` when no checked module file of the program exports it.
+    fn resolve_program_export(&self, containing_file: &str, module_name: &str, name: &str, depth: usize) -> Option<&ProgramModuleExport> {
+        // Longer chains of re-exports than there are modules are cycles.
+        if depth > self.program_modules.len() {
+            return None;
+        }
+        let file_name = resolve_program_file(module_name, containing_file, self.program_modules.keys().map(String::as_str))?;
+        let module = &self.program_modules[file_name];
+        if let Some(export) = module.checked_exports.get(name) {
+            return Some(export);
+        }
+        if let Some((module_name, exported_name)) = module.re_exports.get(name) {
+            return self.resolve_program_export(file_name, module_name, exported_name, depth + 1);
+        }
+        if name == "default" || module.exports.contains_key(name) {
+            return None;
+        }
+        module.star_exports.iter()
+            .find_map(|module_name| self.resolve_program_export(file_name, module_name, name, depth + 1))
+    }
 
-        // Empty names are synthetic/dummy identifiers
-        if name.is_empty() {
-            return self.type_table.any_type;
+    /// Declare an `import x = N.y` alias with the type of the value it names.
+    /// An `import x = require("m")` is left to module resolution.
+    fn check_import_equals_declaration(&mut self, node: &ImportEqualsDeclaration<'_>) {
+        if let ModuleReference::EntityName(name) = &node.module_reference {
+            let alias_type = self.get_type_of_entity_name(name);
+            self.register_type(&node.name.text_name, alias_type);
         }
+    }
 
-        // 1. Check declared_types (from checking phase)
-        if let Some(type_id) = self.get_declared_type(name) {
-            return type_id;
+    /// Record the re-exports of types not marked `type`, which JavaScript
+    /// emit elides. Under isolatedModules they are errors: a transpiler
+    /// cannot tell them from values, and would export a binding that does
+    /// not exist at runtime. Types are known from this file, ambient modules
+    /// and the module files of the program.
+    fn check_export_declaration(&mut self, node: &ExportDeclaration<'_>) {
+        if node.is_type_only {
+            return;
         }
+        let Some(NamedExportBindings::NamedExports(named)) = &node.export_clause else {
+            return;
+        };
+        let module_name = match node.module_specifier {
+            None => None,
+            Some(Expression::StringLiteral(specifier)) => Some(specifier.text_name.as_str()),
+            Some(_) => return,
+        };
+        let ambient_module = module_name
+            .and_then(|module_name| match_pattern_or_exact(self.ambient_modules.keys().map(String::as_str), module_name))
+            .map(|pattern| self.ambient_modules[pattern].clone());
+        let file_name = self.file_name.clone();
+        for element in named.elements.iter().filter(|element| !element.is_type_only) {
+            let exported_name = &element.property_name.as_ref().unwrap_or(&element.name).text_name;
+            let is_type = match (&ambient_module, module_name) {
+                (Some(module), _) => module.types.contains_key(exported_name) && !module.values.contains_key(exported_name),
+                (None, Some(module_name)) => self.is_type_only_program_export(&file_name, module_name, exported_name, 0) == Some(true),
+                (None, None) => self.is_type_only_local(exported_name),
+            };
+            if !is_type {
+                continue;
+            }
+            self.type_only_exports.insert(element.name.text_name.clone());
+            if self.isolated_modules {
+                let flag_name = self.isolated_modules_flag_name();
+                self.error(&messages::RE_EXPORTING_A_TYPE_WHEN_0_IS_ENABLED_REQUIRES_USING_EXPORT_TYPE, &[flag_name]);
+            }
+        }
+    }
 
-        // 2. Check binder's symbol table
-        if let Some(symbol_id) = self.binder.resolve_name(name) {
-            // Determine type from symbol flags
-            if let Some(symbol) = self.binder.get_symbol(symbol_id) {
-                let flags = symbol.flags;
-                if flags.contains(SymbolFlags::FUNCTION) {
-                    return self.type_table.any_type; // function - type registered later
+    /// Whether a name declared in the file bound by the current binder
+    /// denotes only a type.
+    fn is_type_only_local(&self, name: &str) -> bool {
+        self.binder.resolve_name(name)
+            .and_then(|symbol_id| self.binder.get_symbol(symbol_id))
+            .is_some_and(|symbol| symbol.flags.intersects(SymbolFlags::TYPE) && !symbol.flags.intersects(SymbolFlags::VALUE))
+    }
+
+    /// Collect what a module file of the program, bound by the current
+    /// binder, exports, telling types from values.
+    fn get_program_module(&self, source_file: &SourceFile<'_>) -> ProgramModule {
+        let mut module = ProgramModule::default();
+        // Import bindings: the module specifier, the name imported, and
+        // whether the import is marked `type`.
+        let mut imports: HashMap<&str, (&str, &str, bool)> = HashMap::new();
+        for statement in source_file.statements.iter() {
+            let is_exported = statement.data().modifier_flags.contains(ModifierFlags::EXPORT);
+            let is_default = statement.data().modifier_flags.contains(ModifierFlags::DEFAULT);
+            match statement {
+                Statement::ImportDeclaration(n) => {
+                    let (Expression::StringLiteral(specifier), Some(clause)) = (n.module_specifier, &n.import_clause) else { continue };
+                    if let Some(default_name) = &clause.name {
+                        imports.insert(&default_name.text_name, (&specifier.text_name, "default", clause.is_type_only));
+                    }
+                    if let Some(NamedImportBindings::NamedImports(named)) = &clause.named_bindings {
+                        for element in named.elements.iter() {
+                            let imported_name = element.property_name.as_ref().unwrap_or(&element.name);
+                            let is_type_only = clause.is_type_only || element.is_type_only;
+                            imports.insert(&element.name.text_name, (&specifier.text_name, &imported_name.text_name, is_type_only));
+                        }
+                    }
                 }
-                if flags.contains(SymbolFlags::CLASS) {
-                    return self.type_table.any_type; // class - type registered later
+                Statement::ExportDeclaration(n) => {
+                    let module_name = match n.module_specifier {
+                        Some(Expression::StringLiteral(specifier)) => Some(specifier.text_name.as_str()),
+                        _ => None,
+                    };
+                    match &n.export_clause {
+                        Some(NamedExportBindings::NamedExports(named)) => {
+                            for element in named.elements.iter() {
+                                let exported_name = element.name.text_name.clone();
+                                let local_name = element.property_name.as_ref().unwrap_or(&element.name).text_name.as_str();
+                                if module_name.is_none() && !imports.contains_key(local_name) {
+                                    module.local_names.insert(exported_name.clone(), local_name.to_string());
+                                }
+                                if n.is_type_only || element.is_type_only {
+                                    module.exports.insert(exported_name, true);
+                                } else if let Some(module_name) = module_name {
+                                    module.re_exports.insert(exported_name, (module_name.to_string(), local_name.to_string()));
+                                } else if let Some(&(module_name, imported_name, is_type_only)) = imports.get(local_name) {
+                                    if is_type_only {
+                                        module.exports.insert(exported_name, true);
+                                    } else {
+                                        module.re_exports.insert(exported_name, (module_name.to_string(), imported_name.to_string()));
+                                    }
+                                } else {
+                                    module.exports.insert(exported_name, self.is_type_only_local(local_name));
+                                }
+                            }
+                        }
+                        Some(NamedExportBindings::NamespaceExport(namespace)) => {
+                            module.exports.insert(namespace.name.text_name.clone(), n.is_type_only);
+                        }
+                        None if !n.is_type_only => module.star_exports.extend(module_name.map(str::to_string)),
+                        None => {}
+                    }
                 }
-                if flags.intersects(SymbolFlags::FUNCTION_SCOPED_VARIABLE | SymbolFlags::BLOCK_SCOPED_VARIABLE) {
-                    return self.type_table.any_type; // var type registered during check
+                Statement::ExportAssignment(n) => {
+                    let export_name = if n.is_export_equals { "export=" } else { "default" };
+                    module.exports.insert(export_name.to_string(), false);
+                    if let Expression::Identifier(id) = n.expression {
+                        module.local_names.insert(export_name.to_string(), id.text_name.clone());
+                    }
                 }
-                if flags.contains(SymbolFlags::ENUM_MEMBER) {
-                    return self.type_table.number_type;
+                Statement::InterfaceDeclaration(n) if is_exported => {
+                    let export_name = if is_default { "default" } else { &n.name.text_name };
+                    module.exports.insert(export_name.to_string(), true);
+                    module.local_names.insert(export_name.to_string(), n.name.text_name.clone());
                 }
-                if flags.contains(SymbolFlags::REGULAR_ENUM) {
-                    return self.type_table.number_type;
+                Statement::TypeAliasDeclaration(n) if is_exported => {
+                    module.exports.insert(n.name.text_name.clone(), true);
+                    module.local_names.insert(n.name.text_name.clone(), n.name.text_name.clone());
                 }
-                if flags.contains(SymbolFlags::ALIAS) {
-                    return self.type_table.any_type;
+                Statement::ModuleDeclaration(n) if is_exported => {
+                    if let ModuleName::Identifier(id) = &n.name {
+                        module.exports.insert(id.text_name.clone(), !is_instantiated_module(n));
+                        module.local_names.insert(id.text_name.clone(), id.text_name.clone());
+                    }
                 }
+                Statement::VariableStatement(n) if is_exported => {
+                    for declaration in n.declaration_list.declarations.iter() {
+                        if let BindingName::Identifier(id) = &declaration.name {
+                            module.exports.insert(id.text_name.clone(), false);
+                            module.local_names.insert(id.text_name.clone(), id.text_name.clone());
+                        }
+                    }
+                }
+                Statement::FunctionDeclaration(FunctionDeclaration { name, .. })
+                | Statement::ClassDeclaration(ClassDeclaration { name, .. }) if is_exported => {
+                    let export_name = if is_default { "default" } else { name.as_ref().map_or("", |name| name.text_name.as_str()) };
+                    if !export_name.is_empty() {
+                        module.exports.insert(export_name.to_string(), false);
+                    }
+                    if let Some(name) = name.as_ref().filter(|_| !export_name.is_empty()) {
+                        module.local_names.insert(export_name.to_string(), name.text_name.clone());
+                    }
+                }
+                Statement::EnumDeclaration(n) if is_exported => {
+                    module.exports.insert(n.name.text_name.clone(), false);
+                    module.local_names.insert(n.name.text_name.clone(), n.name.text_name.clone());
+                }
+                _ => {}
             }
-            return self.type_table.any_type;
         }
+        module
+    }
 
-        // 3. Check built-in globals
-        if Self::BUILTIN_GLOBALS.contains(&name.as_str()) {
-            return self.type_table.any_type;
+    /// Whether the export `name` of the program module that `module_name`
+    /// names, imported by `containing_file`, denotes only a type. `None`
+    /// when no module file of the program exports it.
+    fn is_type_only_program_export(&self, containing_file: &str, module_name: &str, name: &str, depth: usize) -> Option<bool> {
+        // Longer chains of re-exports than there are modules are cycles.
+        if depth > self.program_modules.len() {
+            return None;
         }
-
-        // 4. Check common keywords used as identifiers
-        if name == "arguments" {
-            return self.type_table.any_type;
+        let file_name = resolve_program_file(module_name, containing_file, self.program_modules.keys().map(String::as_str))?;
+        let module = &self.program_modules[file_name];
+        if let Some(&is_type) = module.exports.get(name) {
+            return Some(is_type);
         }
-
-        // Not found - report error
-        self.error(
-            &messages::CANNOT_FIND_NAME_0,
-            &[name],
-        );
-        // Use any_type for error recovery (prevents cascading errors)
-        self.type_table.any_type
+        if let Some((module_name, exported_name)) = module.re_exports.get(name) {
+            return self.is_type_only_program_export(file_name, module_name, exported_name, depth + 1);
+        }
+        if name == "default" {
+            return None;
+        }
+        module.star_exports.iter()
+            .find_map(|module_name| self.is_type_only_program_export(file_name, module_name, name, depth + 1))
     }
 
-    fn check_binary_expression(&mut self, node: &BinaryExpression<'_>) -> TypeId {
-        let left_type = self.check_expression(node.left);
-        let right_type = self.check_expression(node.right);
-
-        match node.operator_token.data.kind {
-            SyntaxKind::PlusToken => {
-                let left = self.type_table.get(left_type);
-                let right = self.type_table.get(right_type);
-                if left.flags.contains(TypeFlags::STRING_LIKE) || right.flags.contains(TypeFlags::STRING_LIKE) {
-                    self.type_table.string_type
-                } else if left.flags.intersects(TypeFlags::NUMBER_LIKE) && right.flags.intersects(TypeFlags::NUMBER_LIKE) {
-                    self.type_table.number_type
-                } else if left.flags.contains(TypeFlags::ANY) || right.flags.contains(TypeFlags::ANY) {
-                    self.type_table.any_type
+    /// Record the values an exported namespace member declares, and the
+    /// names of exported interfaces and aliases.
+    fn collect_namespace_exports(
+        &self,
+        stmt: &Statement<'_>,
+        exports: &mut IndexMap<String, (TypeId, PropertyFlags)>,
+        exported_types: &mut Vec<String>,
+    ) {
+        let mut export = |name: &str, flags: PropertyFlags| {
+            if let Some(type_id) = self.get_declared_type(name) {
+                exports.insert(name.to_string(), (type_id, flags));
+            }
+        };
+        match stmt {
+            Statement::VariableStatement(n) => {
+                let flags = if n.declaration_list.data.flags.contains(NodeFlags::CONST) {
+                    PropertyFlags::READONLY
                 } else {
-                    // Could be string or number - return string | number
-                    self.create_union_type(vec![self.type_table.string_type, self.type_table.number_type])
+                    PropertyFlags::empty()
+                };
+                for decl in n.declaration_list.declarations.iter() {
+                    if let BindingName::Identifier(id) = &decl.name {
+                        export(&id.text_name, flags);
+                    }
                 }
             }
-            SyntaxKind::MinusToken | SyntaxKind::AsteriskToken | SyntaxKind::SlashToken
-            | SyntaxKind::PercentToken | SyntaxKind::AsteriskAsteriskToken => {
-                // Arithmetic: operands must be number-like
-                let left_flags = self.type_table.get(left_type).flags;
-                let right_flags = self.type_table.get(right_type).flags;
-                if !left_flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ANY)
-                    && !left_flags.contains(TypeFlags::BIG_INT)
-                {
-                    self.error(
-                        &messages::THE_LEFT_HAND_SIDE_OF_AN_ARITHMETIC_OPERATION_MUST_BE_OF_TYPE_ANY_NUMBER_BIGINT_OR_AN_ENUM_TYPE,
-                        &[],
-                    );
-                }
-                if !right_flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ANY)
-                    && !right_flags.contains(TypeFlags::BIG_INT)
-                {
-                    self.error(
-                        &messages::THE_RIGHT_HAND_SIDE_OF_AN_ARITHMETIC_OPERATION_MUST_BE_OF_TYPE_ANY_NUMBER_BIGINT_OR_AN_ENUM_TYPE,
-                        &[],
-                    );
+            Statement::FunctionDeclaration(FunctionDeclaration { name: Some(id), .. })
+            | Statement::ClassDeclaration(ClassDeclaration { name: Some(id), .. }) => export(&id.text_name, PropertyFlags::empty()),
+            Statement::EnumDeclaration(n) => export(&n.name.text_name, PropertyFlags::empty()),
+            Statement::ModuleDeclaration(ModuleDeclaration { name: ModuleName::Identifier(id), .. }) => {
+                if let Some(&type_id) = self.namespaces.get(&id.text_name) {
+                    exports.insert(id.text_name.clone(), (type_id, PropertyFlags::empty()));
                 }
-                self.type_table.number_type
             }
-            // Comparison operators
-            SyntaxKind::EqualsEqualsToken | SyntaxKind::ExclamationEqualsToken
-            | SyntaxKind::EqualsEqualsEqualsToken | SyntaxKind::ExclamationEqualsEqualsToken
-            | SyntaxKind::LessThanToken | SyntaxKind::GreaterThanToken
-            | SyntaxKind::LessThanEqualsToken | SyntaxKind::GreaterThanEqualsToken => {
-                self.type_table.boolean_type
-            }
-            // instanceof
-            SyntaxKind::InstanceOfKeyword => self.type_table.boolean_type,
-            // in
-            SyntaxKind::InKeyword => {
-                let left = self.type_table.get(left_type);
-                if !left.flags.intersects(TypeFlags::STRING_LIKE | TypeFlags::NUMBER_LIKE | TypeFlags::ES_SYMBOL | TypeFlags::ANY) {
-                    self.error(
-                        &messages::THE_LEFT_HAND_SIDE_OF_AN_IN_EXPRESSION_MUST_BE_A_PRIVATE_IDENTIFIER,
-                        &[],
-                    );
-                }
-                self.type_table.boolean_type
-            }
-            // Logical operators
-            SyntaxKind::AmpersandAmpersandToken => right_type,
-            SyntaxKind::BarBarToken => {
-                self.create_union_type(vec![left_type, right_type])
-            }
-            SyntaxKind::QuestionQuestionToken => {
-                // Result is right_type | NonNullable<left_type>
-                let non_null_left = self.get_non_nullable_type(left_type);
-                self.create_union_type(vec![non_null_left, right_type])
-            }
-            // Assignment operators
-            SyntaxKind::EqualsToken => {
-                if !self.is_type_assignable_to(right_type, left_type) {
-                    let src = self.type_to_string(right_type);
-                    let tgt = self.type_to_string(left_type);
-                    self.error(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&src, &tgt]);
-                }
-                right_type
-            }
-            SyntaxKind::PlusEqualsToken | SyntaxKind::MinusEqualsToken
-            | SyntaxKind::AsteriskEqualsToken | SyntaxKind::SlashEqualsToken
-            | SyntaxKind::PercentEqualsToken | SyntaxKind::AsteriskAsteriskEqualsToken => {
-                self.type_table.number_type
-            }
-            SyntaxKind::AmpersandAmpersandEqualsToken | SyntaxKind::BarBarEqualsToken
-            | SyntaxKind::QuestionQuestionEqualsToken => {
-                right_type
-            }
-            // Bitwise
-            SyntaxKind::AmpersandToken | SyntaxKind::BarToken | SyntaxKind::CaretToken
-            | SyntaxKind::LessThanLessThanToken | SyntaxKind::GreaterThanGreaterThanToken
-            | SyntaxKind::GreaterThanGreaterThanGreaterThanToken => {
-                self.type_table.number_type
-            }
-            SyntaxKind::CommaToken => right_type,
-            _ => self.type_table.any_type,
+            Statement::InterfaceDeclaration(n) => exported_types.push(self.qualified_declaration_name(&n.name.text_name)),
+            Statement::TypeAliasDeclaration(n) => exported_types.push(self.qualified_declaration_name(&n.name.text_name)),
+            _ => {}
         }
     }
 
-    fn check_call_expression(&mut self, node: &CallExpression<'_>) -> TypeId {
-        let func_type = self.check_expression(node.expression);
-        let mut arg_types = Vec::new();
-        for arg in node.arguments.iter() {
-            arg_types.push(self.check_expression(arg));
-        }
-
-        // Try to resolve call signature
-        let func_flags = self.type_table.get(func_type).flags;
-        if func_flags.contains(TypeFlags::ANY) {
-            return self.type_table.any_type;
-        }
+    /// Resolve a type alias declaration:  `type Name = UnderlyingType;`
+    ///
+    /// This registers the alias name so that later `TypeReference` lookups
+    /// (e.g. `let x: Name`) resolve to the underlying type. References made
+    /// before the type is resolved, including those in the type itself,
+    /// resolve to a placeholder that becomes the resolved type, so aliases
+    /// may be recursive through object, array and function types.
+    fn check_type_alias_declaration(&mut self, node: &TypeAliasDeclaration<'_>) {
+        let name = self.qualified_declaration_name(&node.name.text_name);
+        let (placeholder, deferred_references) = match self.type_aliases.remove(&name) {
+            Some(alias) if alias.state == TypeAliasState::Declared => (alias.declared_type, alias.deferred_references),
+            _ => (self.create_type_alias_placeholder(), Vec::new()),
+        };
+        let mut all_type_params = self.type_parameters_in_scope.clone();
+        let outer_type_parameter_count = all_type_params.len();
+        let (type_params, saved) = self.push_type_parameters(node.type_parameters, &[]);
+        all_type_params.extend(&type_params);
+        self.type_aliases.insert(name.clone(), TypeAliasInfo {
+            declared_type: placeholder,
+            type_parameters: all_type_params.clone(),
+            outer_type_parameter_count,
+            state: TypeAliasState::Resolving,
+            deferred_references: Vec::new(),
+        });
+        self.register_generic_type(&name, placeholder, type_params.clone());
+        let resolved = self.get_type_from_type_node(node.type_node);
+        self.pop_type_parameters(saved);
 
-        // Get signature count and check for type parameters (generic inference)
-        let (sig_count, has_type_params) = if let TypeKind::ObjectType { call_signatures, .. } = &self.type_table.get(func_type).kind {
-            let has_tp = call_signatures.first().map(|s| !s.type_parameters.is_empty()).unwrap_or(false);
-            (call_signatures.len(), has_tp)
+        let declared_type = if self.is_circular_type_alias_type(resolved, placeholder) {
+            self.error(&messages::TYPE_ALIAS_0_CIRCULARLY_REFERENCES_ITSELF, &[&node.name.text_name]);
+            self.type_table.any_type
         } else {
-            return self.type_table.any_type;
+            self.complete_type_alias_type(placeholder, resolved, &name, all_type_params.clone())
         };
-
-        if sig_count == 0 {
-            self.error(&messages::CANNOT_INVOKE_AN_EXPRESSION_WHOSE_TYPE_LACKS_A_CALL_SIGNATURE, &[]);
-            return self.type_table.any_type;
+        let self_references = match self.type_aliases.get_mut(&name) {
+            Some(alias) => {
+                alias.declared_type = declared_type;
+                alias.state = TypeAliasState::Resolved;
+                std::mem::take(&mut alias.deferred_references)
+            }
+            None => Vec::new(),
+        };
+        self.register_generic_type(&name, declared_type, type_params);
+
+        // References from elsewhere are instantiated now, as are references
+        // in the type itself that do not depend on its type parameters.
+        // `type Deep<T> = { next: Deep<T[]> }` stays deferred until
+        // instantiated, as expanding it would never end.
+        let self_references: Vec<TypeId> = self_references.into_iter()
+            .filter(|&reference| {
+                let args = self.type_alias_references.get(&reference).map(|(_, args)| args.clone()).unwrap_or_default();
+                !args.iter().any(|&arg| self.references_type_parameters(arg, &all_type_params, 0))
+            })
+            .collect();
+        for reference in deferred_references.into_iter().chain(self_references) {
+            let Some((_, args)) = self.type_alias_references.get(&reference).cloned() else { continue };
+            let args = self.fill_type_alias_arguments(&name, &args);
+            self.type_alias_instantiations.remove(&(name.clone(), args.clone()));
+            let instantiation = self.get_type_alias_instantiation(&name, &args);
+            self.assign_placeholder_type(reference, instantiation);
+            self.type_alias_instantiations.insert((name.clone(), args), reference);
         }
+    }
 
-        // If the function has type parameters and no explicit type arguments are provided,
-        // attempt to infer type arguments from argument types
-        if has_type_params && node.type_arguments.is_none() {
-            if let Some(inferred) = self.infer_type_arguments(func_type, &arg_types) {
-                return inferred;
-            }
+    /// Declare the type aliases among `statements` before any is resolved,
+    /// so references to aliases declared later in the file resolve to them.
+    fn declare_type_aliases(&mut self, statements: &[Statement<'_>]) {
+        for statement in statements {
+            let Statement::TypeAliasDeclaration(node) = statement else { continue };
+            let name = node.name.text_name.clone();
+            if name.is_empty() {
+                continue;
+            }
+            let placeholder = self.create_type_alias_placeholder();
+            self.type_aliases.insert(name.clone(), TypeAliasInfo {
+                declared_type: placeholder,
+                type_parameters: Vec::new(),
+                outer_type_parameter_count: 0,
+                state: TypeAliasState::Declared,
+                deferred_references: Vec::new(),
+            });
+            self.register_type(&name, placeholder);
         }
+    }
 
-        // Extract first signature's return type as fallback (before mutable borrows)
-        let first_return_type = self.extract_call_sig_return_type(func_type, 0);
+    /// A type standing for a type alias, or an instantiation of one, that is
+    /// not resolved yet. It is `any` until `assign_placeholder_type` fills it.
+    fn create_type_alias_placeholder(&mut self) -> TypeId {
+        self.type_table.add_type(TypeFlags::ANY, TypeKind::Intrinsic { name: "any" })
+    }
 
-        // Try each overload one-at-a-time, extracting only what we need per iteration
-        for sig_idx in 0..sig_count {
-            let (param_types, return_type, min_args, max_args) = self.extract_call_sig_data(func_type, sig_idx);
-            if self.check_call_arguments(&arg_types, &param_types, min_args, max_args).is_some() {
-                return return_type;
-            }
+    /// Make a placeholder the same type as `source`, so that the references
+    /// already resolved to it denote that type.
+    fn assign_placeholder_type(&mut self, placeholder: TypeId, source: TypeId) {
+        if placeholder == source {
+            return;
         }
-
-        // No overload matched
-        if sig_count > 1 {
-            self.error(&messages::NO_OVERLOAD_MATCHES_THIS_CALL, &[]);
+        let source_type = self.type_table.get(source);
+        let (flags, symbol, kind) = (source_type.flags, source_type.symbol, source_type.kind.clone());
+        let target = self.type_table.get_mut(placeholder);
+        target.flags = flags;
+        target.symbol = symbol;
+        target.kind = kind;
+        self.type_table.copy_property_flags(source, placeholder);
+        if let Some(labels) = self.type_table.get_tuple_labels(source).map(|labels| labels.to_vec()) {
+            self.type_table.set_tuple_labels(placeholder, labels);
+        }
+        if let Some(this_types) = self.this_type_parameters.get(&source).cloned() {
+            self.this_type_parameters.insert(placeholder, this_types);
         }
-        first_return_type
     }
 
-    /// Infer type arguments for a generic function call from argument types.
-    /// Returns the instantiated return type, or None if inference fails.
-    fn infer_type_arguments(&mut self, func_type: TypeId, arg_types: &[TypeId]) -> Option<TypeId> {
-        let sig = if let TypeKind::ObjectType { call_signatures, .. } = &self.type_table.get(func_type).kind {
-            if let Some(sig) = call_signatures.first() {
-                let type_params = sig.type_parameters.clone();
-                let params = sig.parameters.clone();
-                let return_type = sig.return_type;
-                Some((type_params, params, return_type))
-            } else {
-                None
-            }
+    /// Finish resolving a type alias, or an instantiation of one, to
+    /// `resolved`: the placeholder that stood for it becomes that type. An
+    /// object type (other than an array), union or intersection created for
+    /// the alias is displayed
+    /// as a reference to it, and is represented by the placeholder so that
+    /// recursive references are the type itself. Other types, like `string`
+    /// in `type S = string`, are used as they are.
+    fn complete_type_alias_type(&mut self, placeholder: TypeId, resolved: TypeId, name: &str, type_args: Vec<TypeId>) -> TypeId {
+        self.assign_placeholder_type(placeholder, resolved);
+        let is_structured = match &self.type_table.get(resolved).kind {
+            TypeKind::Union { .. } | TypeKind::Intersection { .. } => true,
+            // Arrays and tuples are displayed by their element types
+            TypeKind::ObjectType { object_flags, .. } => !object_flags.contains(ObjectFlags::REFERENCE),
+            _ => false,
+        };
+        let is_new_structured_type = is_structured
+            && resolved.index() > placeholder.index()
+            && !self.global_type_references.contains_key(&resolved)
+            && !self.type_alias_references.contains_key(&resolved)
+            && !self.class_of_type.contains_key(&resolved);
+        if is_new_structured_type {
+            self.type_alias_references.insert(placeholder, (name.to_string(), type_args));
+            placeholder
         } else {
-            None
-        }?;
-
-        let (type_param_ids, params, return_type) = sig;
-
-        if type_param_ids.is_empty() {
-            return None;
+            resolved
         }
+    }
 
-        // Simple inference: match argument types to parameter types
-        let mut inferred_types: Vec<Option<TypeId>> = vec![None; type_param_ids.len()];
-
-        for (i, param) in params.iter().enumerate() {
-            if i >= arg_types.len() { break; }
-            let arg_type = arg_types[i];
-
-            // Check if the parameter type matches any of the type parameter IDs
-            for (tp_idx, &tp_id) in type_param_ids.iter().enumerate() {
-                if param.type_id == tp_id {
-                    inferred_types[tp_idx] = Some(arg_type);
-                }
+    /// Whether an alias's type is the alias itself, directly or as a member
+    /// of a union or intersection (`type A = string | A`).
+    fn is_circular_type_alias_type(&self, type_id: TypeId, placeholder: TypeId) -> bool {
+        if type_id == placeholder {
+            return true;
+        }
+        match &self.type_table.get(type_id).kind {
+            TypeKind::Union { types } | TypeKind::Intersection { types } => {
+                types.iter().any(|&t| self.is_circular_type_alias_type(t, placeholder))
             }
+            _ => false,
         }
+    }
 
-        // Build inferred type arguments
-        let type_args: Vec<TypeId> = inferred_types.iter().map(|t| {
-            t.unwrap_or(self.type_table.any_type)
-        }).collect();
+    /// Resolve a reference to a type alias with the given type arguments.
+    fn get_type_from_type_alias_reference(&mut self, name: &str, type_args: &[TypeId]) -> TypeId {
+        let outer: Vec<TypeId> = match self.type_aliases.get(name) {
+            Some(alias) => alias.type_parameters[..alias.outer_type_parameter_count].to_vec(),
+            None => return self.type_table.any_type,
+        };
+        let mut args = outer;
+        args.extend_from_slice(type_args);
+        let args = self.fill_type_alias_arguments(name, &args);
+        self.get_type_alias_instantiation(name, &args)
+    }
 
-        // Substitute type parameters in return type
-        let result = self.substitute_type_by_id(return_type, &type_param_ids, &type_args);
-        Some(result)
+    /// Complete the type arguments of a type alias reference: missing
+    /// arguments fall back to the parameter's default, then to `any`.
+    /// Arguments of aliases whose type parameters are not known yet are
+    /// returned as they are.
+    fn fill_type_alias_arguments(&mut self, name: &str, type_args: &[TypeId]) -> Vec<TypeId> {
+        let type_params = match self.type_aliases.get(name) {
+            Some(alias) if alias.state != TypeAliasState::Declared => alias.type_parameters.clone(),
+            _ => return type_args.to_vec(),
+        };
+        let mut args: Vec<TypeId> = Vec::with_capacity(type_params.len());
+        for (i, &param) in type_params.iter().enumerate() {
+            let arg = match type_args.get(i) {
+                Some(&arg) => arg,
+                None => match self.type_table.get(param).kind {
+                    TypeKind::TypeParameter { default: Some(default_type), .. } => {
+                        self.substitute_type_by_id(default_type, &type_params[..i], &args)
+                    }
+                    _ => self.type_table.any_type,
+                },
+            };
+            args.push(arg);
+        }
+        args
     }
 
-    /// Substitute type parameter IDs with concrete types in a type.
-    fn substitute_type_by_id(&mut self, type_id: TypeId, type_param_ids: &[TypeId], type_args: &[TypeId]) -> TypeId {
-        // Check if this type is one of the type parameters
-        for (i, &tp_id) in type_param_ids.iter().enumerate() {
-            if type_id == tp_id {
-                if let Some(&arg) = type_args.get(i) {
-                    return arg;
-                }
-            }
+    /// Get the instantiation of a type alias for the given (complete) type
+    /// arguments, which includes those of its enclosing declarations.
+    /// Instantiations are created once per arguments, and a placeholder
+    /// stands for one while it is created, so that recursive references to
+    /// the same instantiation resolve to it. References to aliases not
+    /// resolved yet are deferred until they are.
+    fn get_type_alias_instantiation(&mut self, name: &str, type_args: &[TypeId]) -> TypeId {
+        let Some(alias) = self.type_aliases.get(name) else {
+            return self.type_table.any_type;
+        };
+        if type_args == alias.type_parameters.as_slice() {
+            return alias.declared_type;
+        }
+        let key = (name.to_string(), type_args.to_vec());
+        if let Some(&instantiation) = self.type_alias_instantiations.get(&key) {
+            return instantiation;
+        }
+        let (declared_type, type_params, state) = (alias.declared_type, alias.type_parameters.clone(), alias.state);
+        if state != TypeAliasState::Resolved {
+            let deferred = self.create_type_alias_placeholder();
+            self.type_alias_references.insert(deferred, key.clone());
+            self.type_alias_instantiations.insert(key, deferred);
+            if let Some(alias) = self.type_aliases.get_mut(name) {
+                alias.deferred_references.push(deferred);
+            }
+            return deferred;
+        }
+        // An instantiation in the members of another instantiation of the
+        // same alias, like `Deep<T[]>` in `type Deep<T> = { next: Deep<T[]> }`,
+        // is created only once its members are accessed: expanding it now
+        // might never end.
+        let is_nested_in_members = self.type_alias_instantiation_stack.iter()
+            .any(|(outer, member_depth)| outer == name && *member_depth < self.member_instantiation_depth);
+        if is_nested_in_members {
+            let deferred = self.create_type_alias_placeholder();
+            self.type_alias_references.insert(deferred, key.clone());
+            self.type_alias_instantiations.insert(key, deferred);
+            self.deferred_type_alias_instantiations.insert(deferred);
+            return deferred;
+        }
+        if self.instantiation_depth >= MAX_INSTANTIATION_DEPTH || self.instantiation_count >= MAX_INSTANTIATION_COUNT {
+            self.report_instantiation_limit();
+            return self.type_table.any_type;
+        }
+        let placeholder = self.create_type_alias_placeholder();
+        self.type_alias_instantiations.insert(key.clone(), placeholder);
+        self.type_alias_instantiation_stack.push((name.to_string(), self.member_instantiation_depth));
+        self.instantiation_depth += 1;
+        let limit_hits = self.instantiation_limit_hits;
+        let resolved = self.substitute_type_structure(declared_type, &type_params, type_args);
+        self.instantiation_depth -= 1;
+        self.type_alias_instantiation_stack.pop();
+        let instantiation = self.complete_type_alias_type(placeholder, resolved, name, type_args.to_vec());
+        if self.instantiation_limit_hits != limit_hits {
+            self.excessive_type_alias_instantiations.insert(key.clone());
         }
+        self.type_alias_instantiations.insert(key, instantiation);
+        instantiation
+    }
 
-        let ty = self.type_table.get(type_id);
-        match &ty.kind {
-            TypeKind::Union { types } => {
-                let types = types.clone();
-                let substituted: Vec<TypeId> = types.iter().map(|&t| {
-                    self.substitute_type_by_id(t, type_param_ids, type_args)
-                }).collect();
-                self.create_union_type(substituted)
+    /// The properties of a type, with their types, in declaration order, as
+    /// `get_properties_of_type` but creating the deferred instantiations and
+    /// the unions or intersections of repeated properties it needs.
+    fn resolve_properties_of_type(&mut self, type_id: TypeId) -> Vec<(String, TypeId)> {
+        self.resolve_deferred_type(type_id);
+        let apparent = self.get_apparent_type_of_type_parameter(type_id);
+        let (types, is_union) = match &self.type_table.get(apparent).kind {
+            TypeKind::ObjectType { members, .. } => {
+                return members.iter().map(|(name, &t)| (name.clone(), t)).collect();
             }
-            TypeKind::ObjectType { members, call_signatures, construct_signatures, index_infos, object_flags } => {
-                let members = members.clone();
-                let sigs = call_signatures.clone();
-                let csigs = construct_signatures.clone();
-                let iinfos = index_infos.clone();
-                let flags = *object_flags;
-                let new_members: IndexMap<String, TypeId> = members.iter().map(|(k, &v)| {
-                    (k.clone(), self.substitute_type_by_id(v, type_param_ids, type_args))
-                }).collect();
-                self.type_table.add_type(
-                    TypeFlags::OBJECT,
-                    TypeKind::ObjectType {
-                        object_flags: flags,
-                        members: new_members,
-                        call_signatures: sigs,
-                        construct_signatures: csigs,
-                        index_infos: iinfos,
-                    },
-                )
+            TypeKind::Union { types } => (types.clone(), true),
+            TypeKind::Intersection { types } => (types.clone(), false),
+            _ => return Vec::new(),
+        };
+        let mut properties: IndexMap<String, Vec<TypeId>> = IndexMap::new();
+        for &t in &types {
+            for (name, property_type) in self.resolve_properties_of_type(t) {
+                properties.entry(name).or_default().push(property_type);
             }
-            _ => type_id,
         }
+        properties.into_iter()
+            .filter(|(_, property_types)| !is_union || property_types.len() == types.len())
+            .map(|(name, property_types)| {
+                let property_type = match property_types.as_slice() {
+                    [single] => *single,
+                    _ if is_union => self.create_union_type(property_types),
+                    _ => self.create_intersection_type(property_types),
+                };
+                (name, property_type)
+            })
+            .collect()
     }
 
-    /// Extract lightweight data for a call signature at the given index.
-    /// Re-borrows the type table each time to avoid holding a mutable reference.
-    fn extract_call_sig_data(&self, type_id: TypeId, sig_idx: usize) -> (Vec<TypeId>, TypeId, usize, usize) {
-        if let TypeKind::ObjectType { call_signatures, .. } = &self.type_table.get(type_id).kind {
-            let sig = &call_signatures[sig_idx];
-            (
-                sig.parameters.iter().map(|p| p.type_id).collect(),
-                sig.return_type,
-                sig.min_argument_count as usize,
-                if sig.has_rest_parameter { usize::MAX } else { sig.parameters.len() },
-            )
-        } else {
-            (vec![], self.type_table.any_type, 0, 0)
+    /// Create the deferred type alias instantiations that `type_id` is, or
+    /// has as constituents, so that their members can be accessed.
+    fn resolve_deferred_type(&mut self, type_id: TypeId) {
+        if let TypeKind::Union { types } | TypeKind::Intersection { types } = &self.type_table.get(type_id).kind {
+            for t in types.clone() {
+                self.resolve_deferred_type(t);
+            }
+            return;
         }
+        if !self.deferred_type_alias_instantiations.remove(&type_id) {
+            return;
+        }
+        let Some(key) = self.type_alias_references.get(&type_id).cloned() else { return };
+        self.type_alias_instantiations.remove(&key);
+        let instantiation = self.get_type_alias_instantiation(&key.0, &key.1);
+        self.assign_placeholder_type(type_id, instantiation);
+        self.type_alias_instantiations.insert(key, type_id);
     }
 
-    /// Extract just the return type from a call signature.
-    fn extract_call_sig_return_type(&self, type_id: TypeId, sig_idx: usize) -> TypeId {
-        if let TypeKind::ObjectType { call_signatures, .. } = &self.type_table.get(type_id).kind {
-            call_signatures.get(sig_idx).map_or(self.type_table.any_type, |s| s.return_type)
-        } else {
-            self.type_table.any_type
+    /// Whether a type mentions any of `type_params`. References to aliases
+    /// are looked at through their type arguments.
+    fn references_type_parameters(&self, type_id: TypeId, type_params: &[TypeId], depth: u32) -> bool {
+        if type_params.contains(&type_id) {
+            return true;
+        }
+        if depth >= MAX_INSTANTIATION_DEPTH {
+            return false;
+        }
+        let references = |types: &[TypeId]| types.iter().any(|&t| self.references_type_parameters(t, type_params, depth + 1));
+        if let Some((_, args)) = self.type_alias_references.get(&type_id) {
+            return references(args);
+        }
+        if let Some((_, args)) = self.global_type_references.get(&type_id) {
+            return references(args);
+        }
+        match &self.type_table.get(type_id).kind {
+            TypeKind::Union { types } | TypeKind::Intersection { types } => references(types),
+            TypeKind::Tuple { element_types, .. } => references(element_types),
+            TypeKind::TemplateLiteral { types, .. } => references(types),
+            TypeKind::ObjectType { members, call_signatures, construct_signatures, index_infos, .. } => {
+                members.values().any(|&t| self.references_type_parameters(t, type_params, depth + 1))
+                    || index_infos.iter().any(|info| self.references_type_parameters(info.type_id, type_params, depth + 1))
+                    || call_signatures.iter().chain(construct_signatures).any(|sig| {
+                        self.references_type_parameters(sig.return_type, type_params, depth + 1)
+                            || sig.parameters.iter().any(|p| self.references_type_parameters(p.type_id, type_params, depth + 1))
+                    })
+            }
+            TypeKind::Index { target } | TypeKind::StringMapping { target, .. } => references(&[*target]),
+            TypeKind::IndexedAccess { object_type, index_type } => references(&[*object_type, *index_type]),
+            TypeKind::Conditional { check_type, extends_type, true_type, false_type } => {
+                references(&[*check_type, *extends_type, *true_type, *false_type])
+            }
+            TypeKind::Mapped { constraint_type, template_type, .. } => {
+                references(&[*constraint_type]) || template_type.is_some_and(|t| references(&[t]))
+            }
+            _ => false,
         }
     }
 
-    /// Extract lightweight data for a construct signature at the given index.
-    fn extract_construct_sig_data(&self, type_id: TypeId, sig_idx: usize) -> (Vec<TypeId>, TypeId, usize, usize) {
-        if let TypeKind::ObjectType { construct_signatures, .. } = &self.type_table.get(type_id).kind {
-            let sig = &construct_signatures[sig_idx];
-            (
-                sig.parameters.iter().map(|p| p.type_id).collect(),
-                sig.return_type,
-                sig.min_argument_count as usize,
-                if sig.has_rest_parameter { usize::MAX } else { sig.parameters.len() },
-            )
-        } else {
-            (vec![], self.type_table.any_type, 0, 0)
+    /// Report that a type instantiation went too deep, or that too many were
+    /// performed, once per statement.
+    fn report_instantiation_limit(&mut self) {
+        self.instantiation_limit_hits += 1;
+        if !self.instantiation_limit_reported {
+            self.instantiation_limit_reported = true;
+            self.error(&messages::TYPE_INSTANTIATION_IS_EXCESSIVELY_DEEP_AND_POSSIBLY_INFINITE, &[]);
         }
     }
 
-    /// Extract just the return type from a construct signature.
-    fn extract_construct_sig_return_type(&self, type_id: TypeId, sig_idx: usize) -> TypeId {
-        if let TypeKind::ObjectType { construct_signatures, .. } = &self.type_table.get(type_id).kind {
-            construct_signatures.get(sig_idx).map_or(self.type_table.any_type, |s| s.return_type)
+    /// Register a declared type along with its type parameters (if any), so
+    /// that references like `Name<Args>` instantiate it.
+    fn register_generic_type(&mut self, name: &str, type_id: TypeId, type_params: Vec<TypeId>) {
+        if type_params.is_empty() {
+            self.generic_type_parameters.remove(name);
         } else {
-            self.type_table.any_type
+            self.generic_type_parameters.insert(name.to_string(), type_params);
         }
+        self.register_type(name, type_id);
     }
 
-    /// Checks that the argument types match the parameter types.
-    /// Takes flattened signature data (param TypeIds, min/max args) instead of
-    /// a full `&Signature` to avoid cloning String-heavy `SignatureParameter`s.
-    fn check_call_arguments(
+    /// Declare the type parameters of a generic declaration so their names
+    /// resolve while the declaration is checked. Parameters already created by
+    /// an earlier (merged) declaration are passed in `existing` and reused.
+    /// Returns the type parameters and the shadowed names to restore with
+    /// `pop_type_parameters`.
+    fn push_type_parameters(
         &mut self,
-        arg_types: &[TypeId],
-        param_types: &[TypeId],
-        min_args: usize,
-        max_args: usize,
-    ) -> Option<()> {
-        if arg_types.len() < min_args {
-            self.error(
-                &messages::EXPECTED_0_ARGUMENTS_BUT_GOT_1,
-                &[&min_args.to_string(), &arg_types.len().to_string()],
-            );
-            return None;
+        type_parameters: Option<&[TypeParameterDeclaration<'_>]>,
+        existing: &[TypeId],
+    ) -> (Vec<TypeId>, Vec<(String, Option<TypeId>)>) {
+        let decls = type_parameters.unwrap_or(&[]);
+        let mut ids = Vec::with_capacity(decls.len());
+        let mut saved = Vec::with_capacity(decls.len());
+        for (i, decl) in decls.iter().enumerate() {
+            let name = decl.name.text_name.clone();
+            let id = match existing.get(i) {
+                Some(&id) => id,
+                None => self.create_type_parameter(&name, None),
+            };
+            saved.push((name.clone(), self.get_declared_type(&name)));
+            self.register_type(&name, id);
+            ids.push(id);
         }
-        if arg_types.len() > max_args {
-            self.error(
-                &messages::EXPECTED_0_ARGUMENTS_BUT_GOT_1,
-                &[&max_args.to_string(), &arg_types.len().to_string()],
-            );
-            return None;
+        self.type_parameters_in_scope.extend(&ids);
+        // Constraints and defaults may refer to any parameter in the list.
+        for (decl, &id) in decls.iter().zip(&ids).skip(existing.len()) {
+            let constraint = decl.constraint.map(|c| self.get_type_from_type_node(c));
+            let default_type = decl.default.map(|d| self.get_type_from_type_node(d));
+            if let TypeKind::TypeParameter { constraint: c, default: d, .. } = &mut self.type_table.get_mut(id).kind {
+                *c = constraint;
+                *d = default_type;
+            }
         }
+        (ids, saved)
+    }
 
-        // Check each argument against its parameter type
-        for (i, arg_type) in arg_types.iter().enumerate() {
-            if i < param_types.len() {
-                let param_type = param_types[i];
-                if !self.is_type_assignable_to(*arg_type, param_type) {
-                    let src = self.type_to_string(*arg_type);
-                    let tgt = self.type_to_string(param_type);
-                    self.error(
-                        &messages::ARGUMENT_OF_TYPE_0_IS_NOT_ASSIGNABLE_TO_PARAMETER_OF_TYPE_1,
-                        &[&src, &tgt],
-                    );
-                    return None;
-                }
+    /// Restore the names shadowed by `push_type_parameters`.
+    fn pop_type_parameters(&mut self, saved: Vec<(String, Option<TypeId>)>) {
+        let in_scope = self.type_parameters_in_scope.len().saturating_sub(saved.len());
+        self.type_parameters_in_scope.truncate(in_scope);
+        for (name, previous) in saved.into_iter().rev() {
+            match previous {
+                Some(type_id) => { self.declared_types.insert(name, type_id); }
+                None => { self.declared_types.remove(&name); }
             }
         }
+    }
 
-        Some(())
+    fn create_type_parameter(&mut self, name: &str, constraint: Option<TypeId>) -> TypeId {
+        self.type_table.add_type(
+            TypeFlags::TYPE_PARAMETER,
+            TypeKind::TypeParameter { name: name.to_string(), constraint, default: None },
+        )
     }
 
-    fn check_new_expression(&mut self, node: &NewExpression<'_>) -> TypeId {
-        let class_type = self.check_expression(node.expression);
-        let mut arg_types = Vec::new();
-        if let Some(args) = node.arguments {
-            for arg in args.iter() {
-                arg_types.push(self.check_expression(arg));
-            }
-        }
+    fn create_type_parameter_with_default(&mut self, name: &str, default: TypeId) -> TypeId {
+        self.type_table.add_type(
+            TypeFlags::TYPE_PARAMETER,
+            TypeKind::TypeParameter { name: name.to_string(), constraint: None, default: Some(default) },
+        )
+    }
 
-        let class_flags = self.type_table.get(class_type).flags;
-        if class_flags.contains(TypeFlags::ANY) {
-            return self.type_table.any_type;
+    /// Instantiate a generic alias or interface with the given type arguments.
+    /// Missing arguments fall back to the parameter's default, then to `any`.
+    fn instantiate_declared_type(&mut self, type_id: TypeId, type_params: &[TypeId], type_args: &[TypeId]) -> TypeId {
+        let mut args: Vec<TypeId> = Vec::with_capacity(type_params.len());
+        for (i, &param) in type_params.iter().enumerate() {
+            let arg = match type_args.get(i) {
+                Some(&arg) => arg,
+                None => match self.type_table.get(param).kind {
+                    TypeKind::TypeParameter { default: Some(default_type), .. } => {
+                        self.substitute_type_by_id(default_type, &type_params[..i], &args)
+                    }
+                    _ => self.type_table.any_type,
+                },
+            };
+            args.push(arg);
         }
+        self.substitute_type_by_id(type_id, type_params, &args)
+    }
+
+    /// Resolve an interface declaration and register it as a proper ObjectType
+    /// so that subsequent `TypeReference` lookups produce the right shape.
+    fn check_interface_declaration(&mut self, node: &InterfaceDeclaration<'_>) {
+        let name = self.qualified_declaration_name(&node.name.text_name);
+        // The globals of a program are declared before each of its files is
+        // checked, so a global interface of the file may be merged already.
+        // It is then checked on its own.
+        let is_merged = !self.merged_interface_declarations.insert((self.file_name.clone(), node.data.range));
 
-        // Get construct signature count without cloning
-        let sig_count = if let TypeKind::ObjectType { construct_signatures, .. } = &self.type_table.get(class_type).kind {
-            construct_signatures.len()
+        // If this interface was already registered (declaration merging),
+        // we merge members into the existing object type. An interface
+        // merging with a class adds members to the class instance type.
+        let merged_class = self.classes.get(&name).map(|info| info.instance_type).filter(|_| !is_merged);
+        let existing = if merged_class.is_some() || is_merged { None } else { self.get_declared_type(&name) };
+        let existing_params = if existing.is_some() {
+            self.generic_type_parameters.get(&name).cloned().unwrap_or_default()
         } else {
-            0
+            Vec::new()
         };
+        let (type_params, saved) = self.push_type_parameters(node.type_parameters, &existing_params);
+        let mut member_flags: Vec<(String, PropertyFlags)> = Vec::new();
+        // Merged declarations share one polymorphic `this` type.
+        let this_type = merged_class.or(existing)
+            .and_then(|declared| self.this_type_parameters.get(&declared))
+            .map(|this_types| this_types[0])
+            .unwrap_or_else(|| self.create_this_type_parameter());
+        let saved_this_type = self.current_this_type.replace(this_type);
+        let mut base_this_types: Vec<TypeId> = Vec::new();
 
-        if sig_count == 0 {
-            // Only report "not constructable" for types we can definitively say are not constructable.
-            // If the type is an ObjectType with members (e.g., known built-in or declared class),
-            // it may just be missing explicit construct signatures in our simplified type system.
-            let is_known_type = match &self.type_table.get(class_type).kind {
-                TypeKind::ObjectType { members, call_signatures, .. } => {
-                    !members.is_empty() || !call_signatures.is_empty()
-                }
-                _ => false,
-            };
-            if !is_known_type {
-                // Check if the expression is a class name or known global
-                let is_class = if let Expression::Identifier(id) = node.expression {
-                    self.binder.resolve_name(&id.text_name)
-                        .and_then(|sid| self.binder.get_symbol(sid))
-                        .map(|s| s.flags.contains(SymbolFlags::CLASS))
-                        .unwrap_or(false)
-                        || Self::BUILTIN_GLOBALS.contains(&id.text_name.as_str())
-                } else {
-                    false
-                };
-                if !is_class {
-                    self.error(&messages::THIS_EXPRESSION_IS_NOT_CONSTRUCTABLE, &[]);
-                }
-            }
-            return self.type_table.any_type;
-        }
-
-        // Extract first construct signature's return type as fallback
-        let first_return_type = self.extract_construct_sig_return_type(class_type, 0);
+        let mut members: IndexMap<String, TypeId> = IndexMap::new();
+        let mut call_signatures: Vec<Signature> = Vec::new();
+        let mut construct_signatures: Vec<Signature> = Vec::new();
+        let mut index_infos: Vec<IndexInfo> = Vec::new();
+        // Methods declared by this declaration, which merge ahead of the
+        // overloads of earlier declarations.
+        let mut own_methods: IndexMap<String, TypeId> = IndexMap::new();
+        let mut existing_call_count = 0;
+        let mut existing_construct_count = 0;
 
-        // Try each overload one-at-a-time
-        for sig_idx in 0..sig_count {
-            let (param_types, return_type, min_args, max_args) = self.extract_construct_sig_data(class_type, sig_idx);
-            if self.check_call_arguments(&arg_types, &param_types, min_args, max_args).is_some() {
-                return return_type;
+        // Pull existing members when merging declarations
+        if let Some(existing_id) = existing {
+            if let TypeKind::ObjectType {
+                members: ref existing_members,
+                call_signatures: ref existing_calls,
+                construct_signatures: ref existing_constructs,
+                index_infos: ref existing_indexes,
+                ..
+            } = self.type_table.get(existing_id).kind
+            {
+                members = existing_members.clone();
+                call_signatures = existing_calls.clone();
+                construct_signatures = existing_constructs.clone();
+                index_infos = existing_indexes.clone();
+                existing_call_count = call_signatures.len();
+                existing_construct_count = construct_signatures.len();
             }
         }
+        let existing_members = members.clone();
 
-        // Return the first construct signature's return type as fallback
-        first_return_type
-    }
-
-    fn check_property_access(&mut self, node: &PropertyAccessExpression<'_>) -> TypeId {
-        let obj_type = self.check_expression(node.expression);
+        // Process each member of the interface
+        for member in node.members.iter() {
+            match member {
+                TypeElement::PropertySignature(prop) => {
+                    let prop_name = self.get_property_name_text(&prop.name);
+                    let prop_type = self
+                        .get_type_from_type_annotation(prop.type_annotation)
+                        .unwrap_or(self.type_table.any_type);
 
-        let obj_flags = self.type_table.get(obj_type).flags;
-        if obj_flags.contains(TypeFlags::ANY) {
-            return self.type_table.any_type;
-        }
-
-        // Check for null/undefined access
-        if self.strict_null_checks {
-            if obj_flags.contains(TypeFlags::NULL) {
-                self.error(&messages::OBJECT_IS_POSSIBLY_NULL, &[]);
-            }
-            if obj_flags.contains(TypeFlags::UNDEFINED) {
-                self.error(&messages::OBJECT_IS_POSSIBLY_UNDEFINED, &[]);
-            }
-        }
+                    // If the property is optional (has `?`), wrap in union with undefined
+                    let final_type = if prop.question_token.is_some() {
+                        self.add_optionality(prop_type)
+                    } else {
+                        prop_type
+                    };
 
-        // Get the property name
-        let prop_name = match &node.name {
-            MemberName::Identifier(id) => id.text_name.clone(),
-            MemberName::PrivateIdentifier(id) => id.text_name.clone(),
-        };
+                    let mut flags = PropertyFlags::empty();
+                    flags.set(PropertyFlags::OPTIONAL, prop.question_token.is_some());
+                    flags.set(PropertyFlags::READONLY, prop.data.modifier_flags.contains(ModifierFlags::READONLY));
+                    member_flags.push((prop_name.clone(), flags));
 
-        // O(1) property lookup via IndexMap.
-        let prop_type_id = if let TypeKind::ObjectType { members, .. } = &self.type_table.get(obj_type).kind {
-            members.get(&prop_name).copied()
-        } else {
-            return self.type_table.any_type;
-        };
+                    // Overwrite if the name already exists (merge semantics)
+                    members.insert(prop_name, final_type);
+                }
+                TypeElement::MethodSignature(method) => {
+                    let method_name = self.get_property_name_text(&method.name);
+                    let method_type = self.build_method_type(
+                        own_methods.get(&method_name).copied(),
+                        method.type_parameters,
+                        method.parameters,
+                        method.return_type,
+                    );
 
-        if let Some(tid) = prop_type_id {
-            return tid;
+                    own_methods.insert(method_name.clone(), method_type);
+                    members.insert(method_name, method_type);
+                }
+                TypeElement::CallSignature(call) => {
+                    let sig = self.build_signature(call.type_parameters, call.parameters, call.return_type);
+                    call_signatures.push(sig);
+                }
+                TypeElement::ConstructSignature(ctor) => {
+                    let sig = self.build_signature(ctor.type_parameters, ctor.parameters, ctor.return_type);
+                    construct_signatures.push(sig);
+                }
+                TypeElement::IndexSignature(idx) => {
+                    // Index signature: [key: KeyType]: ValueType
+                    let infos = self.get_index_infos_of_index_signature(idx);
+                    index_infos.extend(infos);
+                }
+            }
         }
 
-        // Property not found on a known object type — for now return any
-        // (a full implementation would report TS2339: Property 'x' does not exist on type 'Y')
-        self.type_table.any_type
-    }
-
-    fn check_element_access(&mut self, node: &ElementAccessExpression<'_>) -> TypeId {
-        let obj_type = self.check_expression(node.expression);
-        let index_type = self.check_expression(node.argument_expression);
-
-        let obj_flags = self.type_table.get(obj_type).flags;
-        if obj_flags.contains(TypeFlags::ANY) {
-            return self.type_table.any_type;
+        // Overloads of a later declaration come before those of earlier ones.
+        call_signatures.rotate_left(existing_call_count);
+        construct_signatures.rotate_left(existing_construct_count);
+        for (method_name, method_type) in own_methods {
+            let Some(&earlier) = existing_members.get(&method_name) else { continue };
+            let merged = match (&self.type_table.get(method_type).kind, &self.type_table.get(earlier).kind) {
+                (
+                    TypeKind::ObjectType { call_signatures: own, .. },
+                    TypeKind::ObjectType { members: earlier_members, call_signatures: earlier_sigs, .. },
+                ) if earlier_members.is_empty() && !earlier_sigs.is_empty() => {
+                    own.iter().chain(earlier_sigs).cloned().collect::<Vec<_>>()
+                }
+                _ => continue,
+            };
+            let merged_type = self.type_table.add_type(
+                TypeFlags::OBJECT,
+                TypeKind::ObjectType {
+                    object_flags: ObjectFlags::ANONYMOUS,
+                    members: IndexMap::new(),
+                    call_signatures: merged,
+                    construct_signatures: vec![],
+                    index_infos: vec![],
+                },
+            );
+            members.insert(method_name, merged_type);
         }
 
-        // Extract index info pairs to release the borrow before is_type_assignable_to
-        let index_pairs: Vec<(TypeId, TypeId)> = if let TypeKind::ObjectType { index_infos, .. } = &self.type_table.get(obj_type).kind {
-            index_infos.iter().map(|info| (info.key_type, info.type_id)).collect()
-        } else {
-            vec![]
-        };
-
-        for (key_type, result_type) in index_pairs {
-            if self.is_type_assignable_to(index_type, key_type) {
-                return result_type;
+        // Resolve heritage clauses (extends)
+        if let Some(heritage) = node.heritage_clauses {
+            for clause in heritage.iter() {
+                for expr_with_args in clause.types.iter() {
+                    // Resolve the base type from the expression
+                    let base_type_id = self.get_type_from_heritage_type(expr_with_args);
+                    if let Some(this_types) = self.this_type_parameters.get(&base_type_id) {
+                        base_this_types.extend(this_types.iter().copied());
+                    }
+                    // Merge members from the base type into this interface
+                    let (base_members, base_index_infos): (IndexMap<String, TypeId>, Vec<IndexInfo>) =
+                        if let TypeKind::ObjectType { members: ref bm, index_infos: ref bi, .. } =
+                            self.type_table.get(base_type_id).kind
+                        {
+                            (bm.clone(), bi.clone())
+                        } else {
+                            (IndexMap::new(), Vec::new())
+                        };
+                    for base_info in base_index_infos {
+                        if !index_infos.iter().any(|info| info.key_type == base_info.key_type) {
+                            index_infos.push(base_info);
+                        }
+                    }
+                    for (base_name, base_tid) in base_members {
+                        // Only add if not already overridden
+                        if !members.contains_key(&base_name) {
+                            let flags = self.type_table.get_property_flags(base_type_id, &base_name);
+                            member_flags.push((base_name.clone(), flags));
+                            members.insert(base_name, base_tid);
+                        }
+                    }
+                }
             }
         }
+        self.pop_type_parameters(saved);
+        self.current_this_type = saved_this_type;
+
+        if let Some(instance_type) = merged_class {
+            self.merge_interface_into_class(instance_type, members, member_flags, index_infos);
+            let earlier_bases = self.this_type_parameters.get(&instance_type).cloned().unwrap_or_default();
+            base_this_types.extend(earlier_bases);
+            self.set_this_type_constraint(this_type, instance_type, base_this_types);
+            return;
+        }
 
-        self.type_table.any_type
-    }
-
-    fn check_conditional_expression(&mut self, node: &ConditionalExpression<'_>) -> TypeId {
-        self.check_expression(node.condition);
-        let true_type = self.check_expression(node.when_true);
-        let false_type = self.check_expression(node.when_false);
-        self.create_union_type(vec![true_type, false_type])
+        let interface_type = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::INTERFACE,
+                members,
+                call_signatures,
+                construct_signatures,
+                index_infos,
+            },
+        );
+        if let Some(existing_id) = existing {
+            self.type_table.copy_property_flags(existing_id, interface_type);
+        }
+        for (member_name, flags) in member_flags {
+            self.type_table.set_property_flags(interface_type, &member_name, flags);
+        }
+        if let Some(existing_id) = existing {
+            let earlier_bases = self.this_type_parameters.get(&existing_id).cloned().unwrap_or_default();
+            base_this_types.extend(earlier_bases);
+        }
+        self.set_this_type_constraint(this_type, interface_type, base_this_types);
+        self.check_index_constraints(interface_type);
+        self.interface_type_references.insert(interface_type, (name.clone(), type_params.clone()));
+        if !is_merged {
+            self.register_generic_type(&name, interface_type, type_params);
+        }
     }
 
-    fn check_prefix_unary(&mut self, node: &PrefixUnaryExpression<'_>) -> TypeId {
-        let operand_type = self.check_expression(node.operand);
-        match node.operator {
-            SyntaxKind::PlusToken | SyntaxKind::MinusToken | SyntaxKind::TildeToken => {
-                self.type_table.number_type
+    /// Add the members of an interface declaration merging with a class to
+    /// the class instance type. Members the class declares itself win.
+    fn merge_interface_into_class(
+        &mut self,
+        instance_type: TypeId,
+        members: IndexMap<String, TypeId>,
+        member_flags: Vec<(String, PropertyFlags)>,
+        index_infos: Vec<IndexInfo>,
+    ) {
+        let mut added = Vec::new();
+        if let TypeKind::ObjectType { members: instance_members, index_infos: instance_infos, .. } =
+            &mut self.type_table.get_mut(instance_type).kind
+        {
+            for (member_name, member_type) in members {
+                if !instance_members.contains_key(&member_name) {
+                    instance_members.insert(member_name.clone(), member_type);
+                    added.push(member_name);
+                }
             }
-            SyntaxKind::ExclamationToken => self.type_table.boolean_type,
-            SyntaxKind::PlusPlusToken | SyntaxKind::MinusMinusToken => {
-                let ty = self.type_table.get(operand_type);
-                if !ty.flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ANY) {
-                    self.error(
-                        &messages::THE_OPERAND_OF_AN_INCREMENT_OR_DECREMENT_OPERATOR_MUST_BE_A_VARIABLE_OR_A_PROPERTY_ACCESS,
-                        &[],
-                    );
+            for info in index_infos {
+                if !instance_infos.iter().any(|existing| existing.key_type == info.key_type) {
+                    instance_infos.push(info);
                 }
-                self.type_table.number_type
             }
-            _ => self.type_table.any_type,
         }
-    }
-
-    fn check_postfix_unary(&mut self, node: &PostfixUnaryExpression<'_>) -> TypeId {
-        let operand_type = self.check_expression(node.operand);
-        let ty = self.type_table.get(operand_type);
-        if !ty.flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ANY) {
-            self.error(
-                &messages::THE_OPERAND_OF_AN_INCREMENT_OR_DECREMENT_OPERATOR_MUST_BE_A_VARIABLE_OR_A_PROPERTY_ACCESS,
-                &[],
-            );
+        for (member_name, flags) in member_flags {
+            if added.contains(&member_name) {
+                self.type_table.set_property_flags(instance_type, &member_name, flags);
+            }
         }
-        self.type_table.number_type
     }
 
-    fn check_await_expression(&mut self, node: &AwaitExpression<'_>) -> TypeId {
-        let operand_type = self.check_expression(node.expression);
-        // If the operand is any, return any
-        let ty = self.type_table.get(operand_type);
-        if ty.flags.contains(TypeFlags::ANY) {
-            return self.type_table.any_type;
+    /// Resolve the type named by a heritage clause entry, instantiating it
+    /// when it refers to a generic alias or interface. A class name denotes
+    /// its instance type.
+    fn get_type_from_heritage_type(&mut self, node: &ExpressionWithTypeArgumentsNode<'_>) -> TypeId {
+        // Qualified names are looked up only among namespace interfaces.
+        let name = entity_name_expression_text(node.expression)
+            .map(|text| (self.resolve_type_reference_name(&text), text))
+            .filter(|(name, text)| !text.contains('.') || name.contains('.'))
+            .map(|(name, _)| name);
+        if let Some(name) = name {
+            if let Some(info) = self.classes.get(&name) {
+                return info.instance_type;
+            }
+            if let (Some(type_id), Some(type_params)) = (
+                self.get_declared_type(&name),
+                self.generic_type_parameters.get(&name).cloned(),
+            ) {
+                let type_args: Vec<TypeId> = node.type_arguments.unwrap_or(&[]).iter()
+                    .map(|arg| self.get_type_from_type_node(arg))
+                    .collect();
+                return self.instantiate_declared_type(type_id, &type_params, &type_args);
+            }
         }
-        // For non-any types, the awaited result is the operand type itself
-        // (a real implementation would unwrap Promise<T> to T via the thenable protocol)
-        operand_type
+        self.check_expression(node.expression)
     }
 
-    fn check_arrow_function(&mut self, node: &ArrowFunction<'_>) -> TypeId {
-        // Register parameter types so they're available in the body
-        for param in node.parameters.iter() {
-            if let Some(init) = param.initializer { self.check_expression(init); }
-            let param_type = self.get_type_from_type_annotation(param.type_annotation)
-                .unwrap_or(self.type_table.any_type);
-            if let BindingName::Identifier(id) = &param.name {
-                if !id.text_name.is_empty() {
-                    self.register_type(&id.text_name, param_type);
-                }
+    /// Extract a textual name from a `PropertyName` node.
+    fn get_property_name_text(&self, name: &PropertyName<'_>) -> String {
+        match name {
+            PropertyName::Identifier(id) => id.text_name.clone(),
+            PropertyName::StringLiteral(lit) => lit.text_name.clone(),
+            PropertyName::NumericLiteral(lit) => number_to_string(parse_numeric_literal_text(&lit.text_name)),
+            PropertyName::PrivateIdentifier(id) => id.text_name.clone(),
+            PropertyName::ComputedPropertyName(c) => {
+                self.get_computed_property_key(c.expression).unwrap_or_else(|| "[computed]".to_string())
             }
         }
+    }
 
-        let declared_return = self.get_type_from_type_annotation(node.return_type);
-
-        // Infer return type
-        let return_type = if let Some(declared) = declared_return {
-            // Check body with declared return type
-            match &node.body {
-                ArrowFunctionBody::Block(block) => {
-                    for s in block.statements.iter() { self.check_statement(s); }
+    /// Build a `Signature` from parameter + return-type AST nodes.
+    fn build_signature(
+        &mut self,
+        type_parameters: Option<&[TypeParameterDeclaration<'_>]>,
+        parameters: &[ParameterDeclaration<'_>],
+        return_type: Option<&TypeNode<'_>>,
+    ) -> Signature {
+        let (type_params, saved) = self.push_type_parameters(type_parameters, &[]);
+        let (this_parameter, parameters) = split_this_parameter(parameters);
+        let this_type = self.get_this_parameter_type(this_parameter);
+        let mut min_args: u32 = 0;
+        let mut has_rest = false;
+        let params: Vec<SignatureParameter> = parameters
+            .iter()
+            .map(|p| {
+                let param_name = match &p.name {
+                    BindingName::Identifier(id) => id.text_name.clone(),
+                    _ => String::new(),
+                };
+                let param_type = self
+                    .get_type_from_type_annotation(p.type_annotation)
+                    .unwrap_or(self.type_table.any_type);
+                let optional = p.question_token.is_some() || p.initializer.is_some();
+                if p.dot_dot_dot_token.is_some() {
+                    has_rest = true;
                 }
-                ArrowFunctionBody::Expression(expr) => { self.check_expression(expr); }
-            }
-            declared
-        } else {
-            // Infer return type from body
-            match &node.body {
-                ArrowFunctionBody::Block(block) => {
-                    for s in block.statements.iter() { self.check_statement(s); }
-                    let return_types = self.collect_return_types(block);
-                    if return_types.is_empty() {
-                        self.type_table.void_type
-                    } else {
-                        self.create_union_type(return_types)
-                    }
+                if !optional && p.dot_dot_dot_token.is_none() {
+                    min_args += 1;
                 }
-                ArrowFunctionBody::Expression(expr) => {
-                    // Expression body: return type is the expression's type
-                    self.check_expression(expr)
+                SignatureParameter {
+                    name: param_name,
+                    type_id: param_type,
+                    optional,
                 }
-            }
-        };
+            })
+            .collect();
 
-        // Create function type
-        let params: Vec<SignatureParameter> = node.parameters.iter().map(|p| {
-            let param_type = self.get_type_from_type_annotation(p.type_annotation);
-            let param_name = match &p.name {
-                BindingName::Identifier(id) => id.text_name.clone(),
-                _ => String::new(),
-            };
-            SignatureParameter {
-                name: param_name,
-                type_id: param_type.unwrap_or(self.type_table.any_type),
-                optional: p.question_token.is_some(),
-            }
-        }).collect();
+        let type_predicate = self.get_type_predicate_of_return_type(return_type, parameters);
+        let ret = return_type
+            .map(|rt| self.get_type_from_type_node(rt))
+            .unwrap_or(self.type_table.void_type);
+        self.pop_type_parameters(saved);
 
-        let sig = Signature {
-            type_parameters: vec![],
+        Signature {
+            type_parameters: type_params,
             parameters: params,
-            return_type,
-            min_argument_count: node.parameters.iter()
-                .filter(|p| p.question_token.is_none() && p.initializer.is_none() && p.dot_dot_dot_token.is_none())
-                .count() as u32,
-            has_rest_parameter: node.parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
-        };
+            return_type: ret,
+            min_argument_count: min_args,
+            has_rest_parameter: has_rest,
+            this_type,
+            is_method: false,
+            type_predicate,
+        }
+    }
 
+    /// Build a function-typed TypeId for a method signature.
+    /// A method already declared under the same name gains an overload.
+    fn build_method_type(
+        &mut self,
+        existing: Option<TypeId>,
+        type_parameters: Option<&[TypeParameterDeclaration<'_>]>,
+        parameters: &[ParameterDeclaration<'_>],
+        return_type: Option<&TypeNode<'_>>,
+    ) -> TypeId {
+        let mut sig = self.build_signature(type_parameters, parameters, return_type);
+        sig.is_method = true;
+        let mut call_signatures = match existing.map(|id| &self.type_table.get(id).kind) {
+            Some(TypeKind::ObjectType { members, call_signatures, .. }) if members.is_empty() => call_signatures.clone(),
+            _ => Vec::new(),
+        };
+        call_signatures.push(sig);
         self.type_table.add_type(
             TypeFlags::OBJECT,
             TypeKind::ObjectType {
                 object_flags: ObjectFlags::ANONYMOUS,
                 members: IndexMap::new(),
-                call_signatures: vec![sig],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
-        )
-    }
-
-    fn check_function_expression(&mut self, node: &FunctionExpression<'_>) -> TypeId {
-        let params: Vec<SignatureParameter> = node.parameters.iter().map(|p| {
-            if let Some(init) = p.initializer { self.check_expression(init); }
-            let param_type = self.get_type_from_type_annotation(p.type_annotation);
-            let param_name = match &p.name {
-                BindingName::Identifier(id) => id.text_name.clone(),
-                _ => String::new(),
-            };
-            let resolved_type = param_type.unwrap_or(self.type_table.any_type);
-            if !param_name.is_empty() {
-                self.register_type(&param_name, resolved_type);
-            }
-            SignatureParameter {
-                name: param_name,
-                type_id: resolved_type,
-                optional: p.question_token.is_some(),
-            }
-        }).collect();
-
-        for s in node.body.statements.iter() { self.check_statement(s); }
-
-        let return_type = self.get_type_from_type_annotation(node.return_type)
-            .unwrap_or(self.type_table.any_type);
-
-        let sig = Signature {
-            type_parameters: vec![],
-            parameters: params,
-            return_type,
-            min_argument_count: node.parameters.iter()
-                .filter(|p| p.question_token.is_none() && p.initializer.is_none() && p.dot_dot_dot_token.is_none())
-                .count() as u32,
-            has_rest_parameter: node.parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
-        };
-
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::ANONYMOUS,
-                members: IndexMap::new(),
-                call_signatures: vec![sig],
+                call_signatures,
                 construct_signatures: vec![],
                 index_infos: vec![],
             },
         )
     }
 
-    fn check_array_literal(&mut self, node: &ArrayLiteralExpression<'_>) -> TypeId {
-        let mut element_types = Vec::new();
-        for elem in node.elements.iter() {
-            element_types.push(self.check_expression(elem));
-        }
+    // ========================================================================
+    // Expression checking
+    // ========================================================================
 
-        if element_types.is_empty() {
-            // Empty array: any[] — create Array<any> object type
-            return self.create_array_type(self.type_table.any_type);
+    fn check_expression(&mut self, expr: &Expression<'_>) -> TypeId {
+        let saved_range = self.current_node_range.replace(expr.data().range);
+        let expr_type = self.check_expression_worker(expr);
+        self.current_node_range = saved_range;
+        self.node_types.insert(expr.data().range, expr_type);
+        if let Expression::PropertyAccess(access) = expr {
+            let (MemberName::Identifier(name) | MemberName::PrivateIdentifier(name)) = &access.name;
+            self.node_types.insert(name.data.range, expr_type);
         }
-
-        // Infer array element type as union of all element types
-        let elem_type = if element_types.len() == 1 {
-            element_types[0]
-        } else {
-            self.create_union_type(element_types)
-        };
-
-        // Return T[] type as an object type with numeric index signature
-        self.create_array_type(elem_type)
+        expr_type
     }
 
-    fn check_object_literal(&mut self, node: &ObjectLiteralExpression<'_>) -> TypeId {
-        let mut members: IndexMap<String, TypeId> = IndexMap::new();
-        for prop in node.properties.iter() {
-            match prop {
-                ObjectLiteralElement::PropertyAssignment(p) => {
-                    let value_type = self.check_expression(p.initializer);
-                    let prop_name = self.property_name_text(&p.name);
-                    members.insert(prop_name, value_type);
-                }
-                ObjectLiteralElement::ShorthandPropertyAssignment(p) => {
-                    // Shorthand { x } is equivalent to { x: x }
-                    let name = p.name.text_name.clone();
-                    let value_type = if let Some(init) = p.object_assignment_initializer {
-                        self.check_expression(init)
-                    } else {
-                        // Look up the identifier's type
-                        self.check_identifier(&p.name)
-                    };
-                    members.insert(name, value_type);
-                }
-                ObjectLiteralElement::SpreadAssignment(p) => {
-                    self.check_expression(p.expression);
-                    // Spread merges in members from the spread object
+    fn check_expression_worker(&mut self, expr: &Expression<'_>) -> TypeId {
+        match expr {
+            Expression::Identifier(id) => {
+                self.check_identifier(id)
+            }
+            Expression::NumericLiteral(n) => {
+                let literal = self.create_number_literal_type(parse_numeric_literal_text(&n.text_name));
+                self.get_fresh_type_of_literal_type(literal)
+            }
+            Expression::StringLiteral(s) => {
+                let literal = self.create_string_literal_type(s.text_name.clone());
+                self.get_fresh_type_of_literal_type(literal)
+            }
+            Expression::BigIntLiteral(_) => self.type_table.bigint_type,
+            Expression::NoSubstitutionTemplateLiteral(_) => self.type_table.string_type,
+            Expression::TemplateExpression(n) => {
+                for span in n.template_spans.iter() { self.check_expression(span.expression); }
+                self.type_table.string_type
+            }
+            Expression::RegularExpressionLiteral(_) => {
+                self.get_regexp_type()
+            }
+            Expression::TrueKeyword(_) => self.get_fresh_type_of_literal_type(self.type_table.true_type),
+            Expression::FalseKeyword(_) => self.get_fresh_type_of_literal_type(self.type_table.false_type),
+            Expression::NullKeyword(_) => self.type_table.null_type,
+            Expression::ThisKeyword(_) => {
+                if self.super_call_state == Some(false) {
+                    self.error(&messages::SUPER_MUST_BE_CALLED_BEFORE_ACCESSING_THIS_IN_THE_CONSTRUCTOR_OF_A_DERIVED_CLASS, &[]);
                 }
-                ObjectLiteralElement::MethodDeclaration(m) => {
-                    // Build method type
-                    let method_params: Vec<SignatureParameter> = m.parameters.iter().map(|p| {
-                        if let Some(init) = p.initializer { self.check_expression(init); }
-                        let param_type = self.get_type_from_type_annotation(p.type_annotation);
-                        SignatureParameter {
-                            name: match &p.name {
-                                BindingName::Identifier(id) => id.text_name.clone(),
-                                _ => String::new(),
-                            },
-                            type_id: param_type.unwrap_or(self.type_table.any_type),
-                            optional: p.question_token.is_some(),
+                // In a class body, `this` is the instance type (or the constructor
+                // type in static members).
+                match self.get_declared_type("this") {
+                    Some(this_type) => this_type,
+                    None => {
+                        if self.this_is_implicit && self.no_implicit_this {
+                            self.error(&messages::THIS_IMPLICITLY_HAS_TYPE_ANY_BECAUSE_IT_DOES_NOT_HAVE_A_TYPE_ANNOTATION, &[]);
                         }
-                    }).collect();
-
-                    let return_type = self.get_type_from_type_annotation(m.return_type)
-                        .unwrap_or(self.type_table.any_type);
-
-                    if let Some(ref body) = m.body {
-                        for s in body.statements.iter() { self.check_statement(s); }
+                        self.type_table.any_type
                     }
-
-                    let sig = Signature {
-                        type_parameters: vec![],
-                        parameters: method_params,
-                        return_type,
-                        min_argument_count: m.parameters.iter()
-                            .filter(|p| p.question_token.is_none() && p.initializer.is_none())
-                            .count() as u32,
-                        has_rest_parameter: m.parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
-                    };
-                    let method_type = self.type_table.add_type(
-                        TypeFlags::OBJECT,
-                        TypeKind::ObjectType {
-                            object_flags: ObjectFlags::ANONYMOUS,
-                            members: IndexMap::new(),
-                            call_signatures: vec![sig],
-                            construct_signatures: vec![],
-                            index_infos: vec![],
-                        },
-                    );
-                    let method_name = self.property_name_text(&m.name);
-                    members.insert(method_name, method_type);
                 }
-                _ => {}
             }
-        }
-
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::ANONYMOUS,
-                members,
-                call_signatures: vec![],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
-        )
-    }
-
-    fn check_class_expression(&mut self, node: &ClassExpression<'_>) {
-        for member in node.members.iter() {
-            match member {
-                ClassElement::MethodDeclaration(m) => {
-                    if let Some(ref body) = m.body {
-                        for s in body.statements.iter() { self.check_statement(s); }
+            Expression::SuperKeyword(_) => {
+                // `super.member` refers to the base class instance
+                let enclosing = self.enclosing_classes.last()
+                    .and_then(|name| self.classes.get(name))
+                    .map(|info| (info.is_derived, info.base_class.clone()));
+                match enclosing {
+                    Some((false, _)) => {
+                        self.error(&messages::SUPER_CAN_ONLY_BE_REFERENCED_IN_A_DERIVED_CLASS, &[]);
+                        self.type_table.any_type
                     }
+                    Some((true, Some(base_name))) => self.classes[&base_name].instance_type,
+                    _ => self.type_table.any_type,
                 }
-                ClassElement::PropertyDeclaration(p) => {
-                    if let Some(init) = p.initializer { self.check_expression(init); }
+            }
+
+            Expression::Binary(n) => self.check_binary_expression(n),
+            Expression::Call(n) => self.check_call_expression(n),
+            Expression::New(n) => self.check_new_expression(n),
+            Expression::PropertyAccess(n) => self.check_property_access(n),
+            Expression::ElementAccess(n) => self.check_element_access(n),
+            Expression::Conditional(n) => self.check_conditional_expression(n),
+            Expression::Parenthesized(n) => self.check_expression(n.expression),
+            Expression::PrefixUnary(n) => self.check_prefix_unary(n),
+            Expression::PostfixUnary(n) => self.check_postfix_unary(n),
+            Expression::TypeOf(n) => { self.check_expression(n.expression); self.type_table.string_type }
+            Expression::Delete(n) => {
+                if self.in_strict_mode && matches!(n.expression, Expression::Identifier(_)) {
+                    self.error(&messages::DELETE_CANNOT_BE_CALLED_ON_AN_IDENTIFIER_IN_STRICT_MODE, &[]);
                 }
-                _ => {}
+                self.check_expression(n.expression);
+                self.type_table.boolean_type
+            }
+            Expression::Void(n) => { self.check_expression(n.expression); self.type_table.undefined_type }
+            Expression::Await(n) => self.check_await_expression(n),
+            Expression::Yield(n) => self.check_yield_expression(n),
+            Expression::Spread(n) => self.check_expression(n.expression),
+            Expression::As(n) => {
+                let expr_type = self.check_expression(n.expression);
+                // Check for `as const` assertion
+                if self.is_const_type_node(n.type_node) {
+                    return self.get_const_type(n.expression, expr_type);
+                }
+                self.get_type_from_type_node(n.type_node)
+            }
+            Expression::Satisfies(n) => {
+                let target_type = self.get_type_from_type_node(n.type_node);
+                let expr_type = self.check_expression_with_contextual_type(n.expression, target_type);
+                self.check_type_assignable_to_and_elaborate(expr_type, target_type, n.expression, n.type_node.data().range);
+                expr_type
+            }
+            Expression::NonNull(n) => {
+                let inner = self.check_expression(n.expression);
+                // Remove null and undefined from union
+                self.get_non_nullable_type(inner)
+            }
+            Expression::TypeAssertion(n) => {
+                self.check_expression(n.expression);
+                self.get_type_from_type_node(n.type_node)
+            }
+            Expression::ArrowFunction(n) => self.check_arrow_function(n),
+            Expression::FunctionExpression(n) => self.check_function_expression(n),
+            Expression::ArrayLiteral(n) => self.check_array_literal(n),
+            Expression::ObjectLiteral(n) => self.check_object_literal(n),
+            Expression::ClassExpression(n) => { self.check_class_expression(n); self.type_table.any_type }
+            Expression::TaggedTemplate(n) => {
+                self.check_expression(n.tag);
+                self.check_expression(n.template);
+                self.type_table.any_type
+            }
+            Expression::MetaProperty(_) => self.type_table.any_type,
+            Expression::OmittedExpression(_) => self.type_table.undefined_type,
+            Expression::JsxElement(n) => {
+                let opening = n.opening_element;
+                self.check_jsx_opening_like_element(opening.data.range, &opening.tag_name, opening.type_arguments, &opening.attributes, n.children)
+            }
+            Expression::JsxSelfClosingElement(n) => {
+                self.check_jsx_opening_like_element(n.data.range, &n.tag_name, n.type_arguments, &n.attributes, &[])
+            }
+            Expression::JsxFragment(n) => {
+                self.check_jsx_preconditions(n.opening_fragment.range);
+                self.check_jsx_children(n.children, None);
+                self.get_jsx_element_type()
             }
         }
     }
 
-    // ========================================================================
-    // Type resolution
-    // ========================================================================
+    /// Well-known globals that don't require declaration.
+    const BUILTIN_GLOBALS: &'static [&'static str] = &[
+        "console", "setTimeout", "setInterval", "clearTimeout", "clearInterval",
+        "parseInt", "parseFloat", "isNaN", "isFinite", "NaN", "Infinity",
+        "undefined", "JSON", "Math", "Date", "Object", "Array", "String",
+        "Number", "Boolean", "Symbol", "Map", "Set", "WeakMap", "WeakSet",
+        "Promise", "Proxy", "Reflect", "Error", "TypeError", "RangeError",
+        "SyntaxError", "ReferenceError", "URIError", "EvalError",
+        "RegExp", "Function", "ArrayBuffer", "DataView",
+        "Int8Array", "Uint8Array", "Int16Array", "Uint16Array",
+        "Int32Array", "Uint32Array", "Float32Array", "Float64Array",
+        "BigInt64Array", "BigUint64Array",
+        "globalThis", "window", "document", "navigator",
+        "process", "require", "module", "exports", "__dirname", "__filename",
+        "fetch", "URL", "URLSearchParams", "Headers", "Request", "Response",
+        "TextEncoder", "TextDecoder", "AbortController", "AbortSignal",
+        "queueMicrotask", "structuredClone", "atob", "btoa",
+        "alert", "confirm", "prompt",
+        "eval", "encodeURI", "decodeURI", "encodeURIComponent", "decodeURIComponent",
+    ];
 
-    fn get_type_from_type_annotation(&mut self, annotation: Option<&TypeNode<'_>>) -> Option<TypeId> {
-        annotation.map(|ty| self.get_type_from_type_node(ty))
-    }
+    fn check_identifier(&mut self, id: &Identifier) -> TypeId {
+        let name = &id.text_name;
 
-    fn get_type_from_type_node(&mut self, type_node: &TypeNode<'_>) -> TypeId {
-        match type_node {
-            TypeNode::KeywordType(n) => self.get_type_from_keyword(n.data.kind),
-            TypeNode::TypeReference(n) => {
-                // Resolve the type reference through the binder/declared types
-                let ref_name = match &n.type_name {
-                    EntityName::Identifier(id) => id.text_name.clone(),
-                    EntityName::QualifiedName(q) => {
-                        // For qualified names like A.B, just use the right name for now
-                        q.right.text_name.clone()
-                    }
-                };
+        // Empty names are synthetic/dummy identifiers
+        if name.is_empty() {
+            return self.type_table.any_type;
+        }
 
-                // Check well-known type names
-                match ref_name.as_str() {
-                    "Array" => {
-                        let elem_type = if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                self.get_type_from_type_node(&type_args[0])
-                            } else {
-                                self.type_table.any_type
-                            }
-                        } else {
-                            self.type_table.any_type
-                        };
-                        return self.create_array_type(elem_type);
+        // 1. Check declared_types (from checking phase)
+        if let Some(type_id) = self.get_declared_type(name) {
+            return type_id;
+        }
+
+        // 2. Check binder's symbol table
+        if let Some(symbol_id) = self.binder.resolve_name(name) {
+            // Determine type from symbol flags
+            if let Some(symbol) = self.binder.get_symbol(symbol_id) {
+                let flags = symbol.flags;
+                if flags.contains(SymbolFlags::FUNCTION) {
+                    return self.type_table.any_type; // function - type registered later
+                }
+                if flags.contains(SymbolFlags::CLASS) {
+                    return self.type_table.any_type; // class - type registered later
+                }
+                if flags.intersects(SymbolFlags::FUNCTION_SCOPED_VARIABLE | SymbolFlags::BLOCK_SCOPED_VARIABLE) {
+                    return self.type_table.any_type; // var type registered during check
+                }
+                if flags.contains(SymbolFlags::ENUM_MEMBER) {
+                    return self.type_table.number_type;
+                }
+                if flags.contains(SymbolFlags::REGULAR_ENUM) {
+                    return self.type_table.number_type;
+                }
+                if flags.contains(SymbolFlags::ALIAS) {
+                    return self.type_table.any_type;
+                }
+            }
+            return self.type_table.any_type;
+        }
+
+        // 3. Check built-in globals
+        if Self::BUILTIN_GLOBALS.contains(&name.as_str()) {
+            return self.type_table.any_type;
+        }
+
+        // 4. Check common keywords used as identifiers
+        if name == "arguments" {
+            return self.type_table.any_type;
+        }
+
+        // Not found - report error
+        self.error(
+            &messages::CANNOT_FIND_NAME_0,
+            &[name],
+        );
+        // Use any_type for error recovery (prevents cascading errors)
+        self.type_table.any_type
+    }
+
+    fn check_binary_expression(&mut self, node: &BinaryExpression<'_>) -> TypeId {
+        let operator = node.operator_token.data.kind;
+        self.is_assignment_target = operator == SyntaxKind::EqualsToken;
+        self.is_compound_assignment_target = operator.is_compound_assignment();
+        let left_type = self.check_expression(node.left);
+        self.is_assignment_target = false;
+        self.is_compound_assignment_target = false;
+        let target_property = self.assignment_target_property.take();
+        let right_type = self.check_expression(node.right);
+
+        match node.operator_token.data.kind {
+            SyntaxKind::PlusToken => {
+                let left = self.type_table.get(left_type);
+                let right = self.type_table.get(right_type);
+                if left.flags.intersects(TypeFlags::STRING_LIKE) || right.flags.intersects(TypeFlags::STRING_LIKE) {
+                    self.type_table.string_type
+                } else if left.flags.intersects(TypeFlags::NUMBER_LIKE) && right.flags.intersects(TypeFlags::NUMBER_LIKE) {
+                    self.type_table.number_type
+                } else if left.flags.contains(TypeFlags::ANY) || right.flags.contains(TypeFlags::ANY) {
+                    self.type_table.any_type
+                } else {
+                    // Could be string or number - return string | number
+                    self.create_union_type(vec![self.type_table.string_type, self.type_table.number_type])
+                }
+            }
+            SyntaxKind::MinusToken | SyntaxKind::AsteriskToken | SyntaxKind::SlashToken
+            | SyntaxKind::PercentToken | SyntaxKind::AsteriskAsteriskToken => {
+                // Arithmetic: operands must be number-like
+                let left_flags = self.type_table.get(left_type).flags;
+                let right_flags = self.type_table.get(right_type).flags;
+                if !left_flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ANY)
+                    && !left_flags.contains(TypeFlags::BIG_INT)
+                {
+                    self.error(
+                        &messages::THE_LEFT_HAND_SIDE_OF_AN_ARITHMETIC_OPERATION_MUST_BE_OF_TYPE_ANY_NUMBER_BIGINT_OR_AN_ENUM_TYPE,
+                        &[],
+                    );
+                }
+                if !right_flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ANY)
+                    && !right_flags.contains(TypeFlags::BIG_INT)
+                {
+                    self.error(
+                        &messages::THE_RIGHT_HAND_SIDE_OF_AN_ARITHMETIC_OPERATION_MUST_BE_OF_TYPE_ANY_NUMBER_BIGINT_OR_AN_ENUM_TYPE,
+                        &[],
+                    );
+                }
+                self.type_table.number_type
+            }
+            // Comparison operators
+            SyntaxKind::EqualsEqualsToken | SyntaxKind::ExclamationEqualsToken
+            | SyntaxKind::EqualsEqualsEqualsToken | SyntaxKind::ExclamationEqualsEqualsToken
+            | SyntaxKind::LessThanToken | SyntaxKind::GreaterThanToken
+            | SyntaxKind::LessThanEqualsToken | SyntaxKind::GreaterThanEqualsToken => {
+                self.type_table.boolean_type
+            }
+            // instanceof
+            SyntaxKind::InstanceOfKeyword => self.type_table.boolean_type,
+            // in
+            SyntaxKind::InKeyword => {
+                let left = self.type_table.get(left_type);
+                if !left.flags.intersects(TypeFlags::STRING_LIKE | TypeFlags::NUMBER_LIKE | TypeFlags::ES_SYMBOL | TypeFlags::ANY) {
+                    self.error(
+                        &messages::THE_LEFT_HAND_SIDE_OF_AN_IN_EXPRESSION_MUST_BE_A_PRIVATE_IDENTIFIER,
+                        &[],
+                    );
+                }
+                self.type_table.boolean_type
+            }
+            // Logical operators
+            SyntaxKind::AmpersandAmpersandToken => right_type,
+            SyntaxKind::BarBarToken => {
+                self.create_union_type(vec![left_type, right_type])
+            }
+            SyntaxKind::QuestionQuestionToken => {
+                // Result is right_type | NonNullable<left_type>
+                let non_null_left = self.get_non_nullable_type(left_type);
+                self.create_union_type(vec![non_null_left, right_type])
+            }
+            // Assignment operators
+            SyntaxKind::EqualsToken => {
+                if let Some((object_type, name)) = target_property {
+                    if self.is_explicit_undefined_for_exact_optional_property(object_type, &name, right_type, left_type) {
+                        let source = self.format_type(right_type);
+                        let target = self.format_type(left_type);
+                        self.error(
+                            &messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1_WITH_EXACTOPTIONALPROPERTYTYPES_CONSIDER_ADDING_UNDEFINED_TO_THE_TARGET,
+                            &[&source, &target],
+                        );
+                        return right_type;
                     }
-                    "ReadonlyArray" => {
-                        let elem_type = if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                self.get_type_from_type_node(&type_args[0])
-                            } else {
-                                self.type_table.any_type
-                            }
-                        } else {
-                            self.type_table.any_type
-                        };
-                        return self.create_array_type(elem_type);
+                }
+                self.check_type_assignable_to_and_elaborate(right_type, left_type, node.right, node.left.data().range);
+                right_type
+            }
+            SyntaxKind::PlusEqualsToken | SyntaxKind::MinusEqualsToken
+            | SyntaxKind::AsteriskEqualsToken | SyntaxKind::SlashEqualsToken
+            | SyntaxKind::PercentEqualsToken | SyntaxKind::AsteriskAsteriskEqualsToken => {
+                self.type_table.number_type
+            }
+            SyntaxKind::AmpersandAmpersandEqualsToken | SyntaxKind::BarBarEqualsToken
+            | SyntaxKind::QuestionQuestionEqualsToken => {
+                right_type
+            }
+            // Bitwise
+            SyntaxKind::AmpersandToken | SyntaxKind::BarToken | SyntaxKind::CaretToken
+            | SyntaxKind::LessThanLessThanToken | SyntaxKind::GreaterThanGreaterThanToken
+            | SyntaxKind::GreaterThanGreaterThanGreaterThanToken => {
+                self.type_table.number_type
+            }
+            SyntaxKind::CommaToken => right_type,
+            _ => self.type_table.any_type,
+        }
+    }
+
+    fn check_call_expression(&mut self, node: &CallExpression<'_>) -> TypeId {
+        if let Expression::SuperKeyword(_) = node.expression {
+            return self.check_super_call(node);
+        }
+        let func_type = self.check_expression(node.expression);
+        // A method is called on the object it is read from; anything else
+        // is called with a `void` this.
+        let this_argument = match node.expression {
+            Expression::PropertyAccess(_) => self.last_property_access_receiver,
+            Expression::ElementAccess(_) | Expression::Parenthesized(_) => None,
+            _ => Some(self.type_table.void_type),
+        };
+        let signatures = match &self.type_table.get(func_type).kind {
+            TypeKind::ObjectType { call_signatures, .. } => Some(call_signatures.clone()),
+            _ => None,
+        };
+        let args = self.check_call_argument_list(node.arguments, signatures.as_deref().unwrap_or(&[]));
+
+        // Try to resolve call signature
+        let func_flags = self.type_table.get(func_type).flags;
+        if func_flags.contains(TypeFlags::ANY) {
+            return self.type_table.any_type;
+        }
+
+        let Some(signatures) = signatures else {
+            return self.type_table.any_type;
+        };
+
+        if signatures.is_empty() {
+            self.error(&messages::CANNOT_INVOKE_AN_EXPRESSION_WHOSE_TYPE_LACKS_A_CALL_SIGNATURE, &[]);
+            return self.type_table.any_type;
+        }
+
+        let type_args: Vec<TypeId> = node.type_arguments.unwrap_or(&[]).iter()
+            .map(|arg| self.get_type_from_type_node(arg))
+            .collect();
+        let saved_this_argument = std::mem::replace(&mut self.call_this_argument, this_argument);
+        let return_type = self.resolve_call(&signatures, &args, &type_args);
+        self.call_this_argument = saved_this_argument;
+        if let Some(predicate) = self.resolved_type_predicate.take() {
+            self.call_type_predicates.insert(node.data.range, predicate);
+        }
+        if let Some(signature) = self.resolved_signature.take() {
+            self.resolved_signatures.insert(node.data.range, signature);
+        }
+        return_type
+    }
+
+    /// Check the arguments of a call. Spreads of tuples are expanded into one
+    /// argument per element; spreads of arrays stay a single spread argument.
+    /// When the callee has a single signature, its parameter types are the
+    /// contextual types of the arguments. Function expressions, whose
+    /// parameters need one, are typed by the first signature of an overloaded
+    /// callee, with the callee's own type parameters read as `any`.
+    fn check_call_argument_list(&mut self, arguments: &[Expression<'_>], signatures: &[Signature]) -> Vec<CallArgument> {
+        let params = match signatures {
+            [sig] => Some(self.get_effective_parameters(sig)),
+            _ => None,
+        };
+        let function_params = signatures.first().map(|sig| {
+            let any_args = vec![self.type_table.any_type; sig.type_parameters.len()];
+            let erased = self.substitute_signature(sig, &sig.type_parameters, &any_args);
+            self.get_effective_parameters(&erased)
+        });
+        let mut args = Vec::with_capacity(arguments.len());
+        for (i, arg) in arguments.iter().enumerate() {
+            let Expression::Spread(spread) = arg else {
+                let params = match arg {
+                    Expression::ArrowFunction(_) | Expression::FunctionExpression(_) => function_params.as_ref(),
+                    _ => params.as_ref(),
+                };
+                let arg_type = match params.and_then(|p| p.type_at(i)) {
+                    Some(contextual_type) => self.check_expression_with_contextual_type(arg, contextual_type),
+                    None => self.check_expression(arg),
+                };
+                args.push(CallArgument::Fixed(arg_type));
+                continue;
+            };
+            let spread_type = self.check_expression(spread.expression);
+            match &self.type_table.get(spread_type).kind {
+                TypeKind::Tuple { element_types, element_flags } => {
+                    for (i, &element_type) in element_types.iter().enumerate() {
+                        args.push(match element_flags.get(i).copied().unwrap_or(ElementFlags::Required) {
+                            ElementFlags::Required | ElementFlags::Optional => CallArgument::Fixed(element_type),
+                            ElementFlags::Rest => CallArgument::Spread(element_type),
+                            ElementFlags::Variadic => CallArgument::Spread(self.get_element_type_of_iterable(element_type)),
+                        });
                     }
-                    "Promise" => {
-                        let _inner = if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                self.get_type_from_type_node(&type_args[0])
-                            } else {
-                                self.type_table.any_type
+                }
+                _ => {
+                    let element_type = self.check_iteration_types(spread_type, IterationUse::Spread).yield_type;
+                    args.push(CallArgument::Spread(element_type));
+                }
+            }
+        }
+        args
+    }
+
+    /// Choose the signature a call resolves to and return its return type.
+    /// Candidates of the right arity are tried in declaration order, first
+    /// under the subtype relation and then under assignability, so that an
+    /// earlier overload accepting `any` does not shadow a more specific one.
+    fn resolve_call(&mut self, signatures: &[Signature], args: &[CallArgument], type_args: &[TypeId]) -> TypeId {
+        self.resolved_type_predicate = None;
+        self.resolved_signature = None;
+        let mut candidates: Vec<Signature> = Vec::with_capacity(signatures.len());
+        for sig in signatures {
+            if !type_args.is_empty() && !self.has_correct_type_argument_arity(sig, type_args.len()) {
+                continue;
+            }
+            if self.has_correct_arity(sig, args) {
+                candidates.push(self.instantiate_signature_for_call(sig, args, type_args));
+            }
+        }
+
+        if candidates.is_empty() {
+            self.report_argument_arity_error(signatures, args, type_args);
+            return signatures[0].return_type;
+        }
+
+        let relations: &[TypeRelation] = if candidates.len() > 1 {
+            &[TypeRelation::Subtype, TypeRelation::Assignable]
+        } else {
+            &[TypeRelation::Assignable]
+        };
+        for &relation in relations {
+            for candidate in &candidates {
+                if self.get_signature_applicability_error(candidate, args, relation).is_none() {
+                    self.resolved_type_predicate = candidate.type_predicate.clone();
+                    self.resolved_signature = Some(candidate.clone());
+                    return candidate.return_type;
+                }
+            }
+        }
+
+        // No candidate applies: elaborate the failure of each one.
+        if let [candidate] = candidates.as_slice() {
+            if let Some(error) = self.get_signature_applicability_error(candidate, args, TypeRelation::Assignable) {
+                self.add_diagnostic(error);
+            }
+            self.resolved_signature = Some(candidate.clone());
+            return candidate.return_type;
+        }
+        let candidate_count = candidates.len().to_string();
+        let mut message_chain = Vec::new();
+        for (i, candidate) in candidates.iter().enumerate() {
+            if let Some(error) = self.get_signature_applicability_error(candidate, args, TypeRelation::Assignable) {
+                let sig_text = self.signature_to_string(candidate, TypeFormatFlags::NONE);
+                message_chain.push(
+                    DiagnosticMessageChain::new(
+                        &messages::OVERLOAD_0_OF_1_2_GAVE_THE_FOLLOWING_ERROR,
+                        &[&(i + 1).to_string(), &candidate_count, &sig_text],
+                    )
+                    .with_next(vec![error.into_message_chain()]),
+                );
+            }
+        }
+        self.add_diagnostic(
+            Diagnostic::new(&messages::NO_OVERLOAD_MATCHES_THIS_CALL, &[]).with_message_chain(message_chain),
+        );
+        let return_types = candidates.iter().map(|c| c.return_type).collect();
+        self.create_union_type(return_types)
+    }
+
+    /// The parameters of a signature as seen by a call: a rest parameter of
+    /// tuple type contributes one parameter per element.
+    fn get_effective_parameters(&self, sig: &Signature) -> EffectiveParameters {
+        let mut types: Vec<TypeId> = sig.parameters.iter().map(|p| p.type_id).collect();
+        let mut min_argument_count = sig.min_argument_count as usize;
+        let mut rest = None;
+        if sig.has_rest_parameter {
+            if let Some(rest_type) = types.pop() {
+                min_argument_count = min_argument_count.min(types.len());
+                match &self.type_table.get(rest_type).kind {
+                    TypeKind::Tuple { element_types, element_flags } => {
+                        for (i, &element_type) in element_types.iter().enumerate() {
+                            match element_flags.get(i).copied().unwrap_or(ElementFlags::Required) {
+                                ElementFlags::Required => {
+                                    types.push(element_type);
+                                    min_argument_count = types.len();
+                                }
+                                ElementFlags::Optional => types.push(element_type),
+                                ElementFlags::Rest => rest = Some(element_type),
+                                ElementFlags::Variadic => rest = Some(self.get_element_type_of_iterable(element_type)),
                             }
-                        } else {
-                            self.type_table.any_type
-                        };
-                        // Promise<T> is an object type; full implementation would track T
-                        return self.type_table.add_type(
-                            TypeFlags::OBJECT,
-                            TypeKind::ObjectType {
-                                object_flags: ObjectFlags::empty(),
-                                members: IndexMap::new(),
-                                call_signatures: vec![],
-                                construct_signatures: vec![],
-                                index_infos: vec![],
-                            },
-                        );
+                        }
                     }
-                    "Record" => return self.type_table.any_type,
-                    "Partial" => {
-                        if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                let inner = self.get_type_from_type_node(&type_args[0]);
-                                return self.create_partial_type(inner);
-                            }
-                        }
-                        return self.type_table.any_type;
+                    _ => rest = Some(self.get_element_type_of_iterable(rest_type)),
+                }
+            }
+        }
+        EffectiveParameters { types, min_argument_count, rest }
+    }
+
+    fn has_correct_type_argument_arity(&self, sig: &Signature, type_arg_count: usize) -> bool {
+        let min_type_args = sig.type_parameters.iter()
+            .filter(|&&tp| !matches!(self.type_table.get(tp).kind, TypeKind::TypeParameter { default: Some(_), .. }))
+            .count();
+        type_arg_count >= min_type_args && type_arg_count <= sig.type_parameters.len()
+    }
+
+    fn has_correct_arity(&self, sig: &Signature, args: &[CallArgument]) -> bool {
+        let params = self.get_effective_parameters(sig);
+        // A spread of unknown length may supply any number of trailing arguments.
+        if let Some(spread_index) = args.iter().position(|a| matches!(a, CallArgument::Spread(_))) {
+            return spread_index >= params.min_argument_count
+                && (params.rest.is_some() || spread_index < params.types.len());
+        }
+        args.len() >= params.min_argument_count && (params.rest.is_some() || args.len() <= params.types.len())
+    }
+
+    /// Report why no signature accepts this number of (type) arguments.
+    fn report_argument_arity_error(&mut self, signatures: &[Signature], args: &[CallArgument], type_args: &[TypeId]) {
+        if !type_args.is_empty() && !signatures.iter().any(|sig| self.has_correct_type_argument_arity(sig, type_args.len())) {
+            let expected = signatures[0].type_parameters.len().to_string();
+            self.error(&messages::EXPECTED_0_TYPE_ARGUMENTS_BUT_GOT_1, &[&expected, &type_args.len().to_string()]);
+            return;
+        }
+        if args.iter().any(|a| matches!(a, CallArgument::Spread(_))) {
+            self.error(&messages::A_SPREAD_ARGUMENT_MUST_EITHER_HAVE_A_TUPLE_TYPE_OR_BE_PASSED_TO_A_REST_PARAMETER, &[]);
+            return;
+        }
+
+        let arg_count = args.len();
+        let mut min = usize::MAX;
+        let mut max = 0;
+        let mut has_rest = false;
+        // Closest parameter counts on either side of the argument count.
+        let mut below: Option<usize> = None;
+        let mut above: Option<usize> = None;
+        for sig in signatures {
+            let params = self.get_effective_parameters(sig);
+            min = min.min(params.min_argument_count);
+            if params.rest.is_some() {
+                has_rest = true;
+            } else {
+                max = max.max(params.types.len());
+                if params.types.len() < arg_count {
+                    below = Some(below.map_or(params.types.len(), |b| b.max(params.types.len())));
+                }
+            }
+            if params.min_argument_count > arg_count {
+                above = Some(above.map_or(params.min_argument_count, |a| a.min(params.min_argument_count)));
+            }
+        }
+
+        let arg_count_text = arg_count.to_string();
+        if has_rest && arg_count < min {
+            self.error(&messages::EXPECTED_AT_LEAST_0_ARGUMENTS_BUT_GOT_1, &[&min.to_string(), &arg_count_text]);
+        } else if let (true, Some(below), Some(above)) = (min < arg_count && arg_count < max, below, above) {
+            self.error(
+                &messages::NO_OVERLOAD_EXPECTS_0_ARGUMENTS,
+                &[&arg_count_text, &below.to_string(), &above.to_string()],
+            );
+        } else {
+            let range = if min < max { format!("{}-{}", min, max) } else { min.to_string() };
+            self.error(&messages::EXPECTED_0_ARGUMENTS_BUT_GOT_1, &[&range, &arg_count_text]);
+        }
+    }
+
+    /// The error a call would produce against this signature, if any.
+    fn get_signature_applicability_error(
+        &mut self,
+        sig: &Signature,
+        args: &[CallArgument],
+        relation: TypeRelation,
+    ) -> Option<Diagnostic> {
+        if let (Some(this_type), Some(this_argument)) = (sig.this_type, self.call_this_argument) {
+            if !self.is_type_related_to(this_argument, this_type, relation) {
+                let src = self.format_type(this_argument);
+                let tgt = self.format_type(this_type);
+                return Some(Diagnostic::new(
+                    &messages::THE_THIS_CONTEXT_OF_TYPE_0_IS_NOT_ASSIGNABLE_TO_METHOD_S_THIS_OF_TYPE_1,
+                    &[&src, &tgt],
+                ));
+            }
+        }
+        let params = self.get_effective_parameters(sig);
+        for (i, arg) in args.iter().enumerate() {
+            let (arg_type, param_types): (TypeId, Vec<TypeId>) = match *arg {
+                CallArgument::Fixed(arg_type) => (arg_type, params.type_at(i).into_iter().collect()),
+                CallArgument::Spread(element_type) => (
+                    element_type,
+                    params.types.iter().skip(i).copied().chain(params.rest).collect(),
+                ),
+            };
+            for param_type in param_types {
+                if !self.is_type_related_to(arg_type, param_type, relation) {
+                    if let Some(error) = self.get_excess_property_error(arg_type, param_type) {
+                        return Some(error);
                     }
-                    "Required" => {
-                        if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                let inner = self.get_type_from_type_node(&type_args[0]);
-                                return self.create_required_type(inner);
-                            }
-                        }
-                        return self.type_table.any_type;
+                    let reported_type = self.get_reported_source_type(arg_type, param_type);
+                    let src = self.format_type(reported_type);
+                    let tgt = self.format_type(param_type);
+                    let message_chain = self.elaborate_assignability_error(arg_type, param_type, 0);
+                    return Some(Diagnostic::new(
+                        &messages::ARGUMENT_OF_TYPE_0_IS_NOT_ASSIGNABLE_TO_PARAMETER_OF_TYPE_1,
+                        &[&src, &tgt],
+                    ).with_message_chain(message_chain));
+                }
+            }
+        }
+        None
+    }
+
+    /// Whether a type is, or is a union containing, a literal or template
+    /// literal type. Errors against such targets report the source literal
+    /// instead of widening it.
+    fn could_contain_literal_type(&self, type_id: TypeId) -> bool {
+        let literal_like = TypeFlags::LITERAL | TypeFlags::TEMPLATE_LITERAL | TypeFlags::STRING_MAPPING;
+        match &self.type_table.get(type_id).kind {
+            TypeKind::Union { types } => types.iter().any(|&t| self.type_table.get(t).flags.intersects(literal_like)),
+            _ => self.type_table.get(type_id).flags.intersects(literal_like),
+        }
+    }
+
+    /// Whether a property, element or index signature type of a type could
+    /// contain literal types, looking a few levels deep.
+    fn could_contain_literal_member_type(&self, type_id: TypeId, depth: u32) -> bool {
+        if depth > 3 {
+            return false;
+        }
+        let member_types: Vec<TypeId> = match &self.type_table.get(type_id).kind {
+            TypeKind::Union { types } | TypeKind::Intersection { types } => {
+                return types.iter().any(|&t| self.could_contain_literal_member_type(t, depth));
+            }
+            TypeKind::ObjectType { members, index_infos, .. } => {
+                members.values().copied().chain(index_infos.iter().map(|info| info.type_id)).collect()
+            }
+            TypeKind::Tuple { element_types, .. } => element_types.clone(),
+            _ => return false,
+        };
+        member_types.into_iter().any(|t| self.could_contain_literal_type(t) || self.could_contain_literal_member_type(t, depth + 1))
+    }
+
+    /// The source type as shown in a relation error: `Type 'string' is not
+    /// assignable to type 'number'` rather than `'"a"'`, unless the target
+    /// could itself contain literals. The literal elements and properties of
+    /// array and object literals are widened likewise, so `[1]` shows as
+    /// `number[]` unless the target's members could contain literals.
+    fn get_reported_source_type(&mut self, source: TypeId, target: TypeId) -> TypeId {
+        if self.could_contain_literal_type(target) {
+            return source;
+        }
+        let source = self.get_base_type_of_literal_type(source);
+        if self.could_contain_literal_member_type(target, 0) {
+            source
+        } else {
+            self.get_widened_type(source)
+        }
+    }
+
+    /// Instantiate a generic signature with explicit or inferred type arguments.
+    fn instantiate_signature_for_call(&mut self, sig: &Signature, args: &[CallArgument], type_args: &[TypeId]) -> Signature {
+        if sig.type_parameters.is_empty() {
+            return sig.clone();
+        }
+        let type_args = if type_args.is_empty() {
+            self.infer_type_arguments(sig, args)
+        } else {
+            let mut filled = type_args.to_vec();
+            for &tp in &sig.type_parameters[type_args.len()..] {
+                filled.push(match self.type_table.get(tp).kind {
+                    TypeKind::TypeParameter { default: Some(default_type), .. } => default_type,
+                    _ => self.type_table.unknown_type,
+                });
+            }
+            filled
+        };
+        let mut instantiated = self.substitute_signature(sig, &sig.type_parameters, &type_args);
+        instantiated.type_parameters.clear();
+        instantiated
+    }
+
+    /// Infer type arguments for a generic signature from the call's argument
+    /// types. Uninferred parameters fall back to their default, then their
+    /// constraint, then `unknown`; an inference that violates the constraint
+    /// is replaced by the constraint.
+    fn infer_type_arguments(&mut self, sig: &Signature, args: &[CallArgument]) -> Vec<TypeId> {
+        let type_params = &sig.type_parameters;
+        let mut inferences: Vec<Option<TypeId>> = vec![None; type_params.len()];
+        let params = self.get_effective_parameters(sig);
+        // A generic rest parameter (`...args: T` or `...args: [A, ...T]`) is
+        // inferred from a tuple of all the arguments it receives.
+        let generic_rest = sig.parameters.last()
+            .filter(|_| sig.has_rest_parameter)
+            .map(|p| p.type_id)
+            .filter(|&t| self.is_generic_type(t) || self.is_variadic_tuple_type(t));
+        let fixed_count = if generic_rest.is_some() { sig.parameters.len() - 1 } else { args.len() };
+        for (i, arg) in args.iter().enumerate().take(fixed_count) {
+            let (CallArgument::Fixed(arg_type) | CallArgument::Spread(arg_type)) = *arg;
+            if let Some(param_type) = params.type_at(i) {
+                self.infer_from_types(arg_type, param_type, type_params, &mut inferences);
+            }
+        }
+        if let Some(rest_type) = generic_rest {
+            let rest_args = args.get(fixed_count..).unwrap_or(&[]);
+            let mut element_types = Vec::with_capacity(rest_args.len());
+            let mut element_flags = Vec::with_capacity(rest_args.len());
+            for arg in rest_args {
+                match *arg {
+                    CallArgument::Fixed(t) => {
+                        element_types.push(self.widen_type(t));
+                        element_flags.push(ElementFlags::Required);
                     }
-                    "Readonly" => {
-                        if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                let inner = self.get_type_from_type_node(&type_args[0]);
-                                return self.create_readonly_type(inner);
-                            }
-                        }
-                        return self.type_table.any_type;
+                    CallArgument::Spread(t) => {
+                        element_types.push(t);
+                        element_flags.push(ElementFlags::Rest);
                     }
-                    "Pick" => {
-                        if let Some(type_args) = n.type_arguments {
-                            if type_args.len() >= 2 {
-                                let base = self.get_type_from_type_node(&type_args[0]);
-                                let keys_type = self.get_type_from_type_node(&type_args[1]);
-                                return self.create_pick_type(base, &[keys_type]);
-                            }
+                }
+            }
+            let args_tuple = self.create_normalized_tuple_type(element_types, element_flags, None);
+            self.infer_from_types(args_tuple, rest_type, type_params, &mut inferences);
+        }
+
+        let mut type_args: Vec<TypeId> = Vec::with_capacity(type_params.len());
+        for (i, &tp) in type_params.iter().enumerate() {
+            let (constraint, default_type) = match self.type_table.get(tp).kind {
+                TypeKind::TypeParameter { constraint, default, .. } => (constraint, default),
+                _ => (None, None),
+            };
+            let constraint = constraint.map(|c| self.substitute_type_by_id(c, &type_params[..i], &type_args));
+            let inferred = match (inferences[i], constraint) {
+                (Some(inferred), Some(constraint)) if !self.is_type_assignable_to(inferred, constraint) => constraint,
+                (Some(inferred), _) => inferred,
+                (None, _) => default_type.or(constraint).unwrap_or(self.type_table.unknown_type),
+            };
+            type_args.push(inferred);
+        }
+        type_args
+    }
+
+    /// Walk a parameter type alongside an argument type, recording a candidate
+    /// for each type parameter that appears in the parameter type.
+    fn infer_from_types(&mut self, source: TypeId, target: TypeId, type_params: &[TypeId], inferences: &mut [Option<TypeId>]) {
+        if let Some(i) = type_params.iter().position(|&tp| tp == target) {
+            inferences[i] = match inferences[i] {
+                // Keep the first candidate unless a later one is a supertype of it.
+                Some(existing) if !self.is_type_assignable_to(existing, source) => Some(existing),
+                _ => Some(source),
+            };
+            return;
+        }
+        match (self.type_table.get(source).kind.clone(), self.type_table.get(target).kind.clone()) {
+            (_, TypeKind::Union { types: target_types }) => {
+                // Infer to the single naked type parameter, minus the constituents
+                // the source shares with the rest of the target union.
+                let naked: Vec<TypeId> = target_types.iter().copied().filter(|t| type_params.contains(t)).collect();
+                if let [naked] = naked.as_slice() {
+                    let source = match &self.type_table.get(source).kind {
+                        TypeKind::Union { types } => {
+                            let remaining: Vec<TypeId> = types.iter().copied().filter(|t| !target_types.contains(t)).collect();
+                            if remaining.is_empty() { return; }
+                            self.create_union_type(remaining)
                         }
-                        return self.type_table.any_type;
+                        _ if target_types.contains(&source) => return,
+                        _ => source,
+                    };
+                    self.infer_from_types(source, *naked, type_params, inferences);
+                } else {
+                    for t in target_types {
+                        self.infer_from_types(source, t, type_params, inferences);
                     }
-                    "Omit" => {
-                        if let Some(type_args) = n.type_arguments {
-                            if type_args.len() >= 2 {
-                                let base = self.get_type_from_type_node(&type_args[0]);
-                                let keys_type = self.get_type_from_type_node(&type_args[1]);
-                                return self.create_omit_type(base, &[keys_type]);
-                            }
-                        }
-                        return self.type_table.any_type;
+                }
+            }
+            (
+                TypeKind::Tuple { element_types: source_elements, element_flags: source_flags },
+                TypeKind::Tuple { element_types: target_elements, element_flags: target_flags },
+            ) => {
+                let variadic = target_flags.iter().position(|&f| f == ElementFlags::Variadic);
+                let Some(variadic) = variadic.filter(|_| target_flags.iter().filter(|&&f| f == ElementFlags::Variadic).count() == 1) else {
+                    for (s, t) in source_elements.into_iter().zip(target_elements) {
+                        self.infer_from_types(s, t, type_params, inferences);
                     }
-                    "ReturnType" => {
-                        if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                let inner = self.get_type_from_type_node(&type_args[0]);
-                                return self.get_return_type_of(inner);
-                            }
-                        }
-                        return self.type_table.any_type;
+                    return;
+                };
+                // `[A, ...T, B]`: match the fixed elements at each end, then
+                // infer `T` from the tuple of whatever is left in the middle.
+                let suffix = target_elements.len() - variadic - 1;
+                if source_elements.len() < variadic + suffix {
+                    return;
+                }
+                let middle_end = source_elements.len() - suffix;
+                for i in 0..variadic {
+                    self.infer_from_types(source_elements[i], target_elements[i], type_params, inferences);
+                }
+                for i in 0..suffix {
+                    self.infer_from_types(source_elements[middle_end + i], target_elements[variadic + 1 + i], type_params, inferences);
+                }
+                let middle_types: Vec<TypeId> = source_elements[variadic..middle_end].iter()
+                    .map(|&t| self.widen_type(t))
+                    .collect();
+                let middle_flags = (variadic..middle_end)
+                    .map(|i| source_flags.get(i).copied().unwrap_or(ElementFlags::Required))
+                    .collect();
+                let labels = self.type_table.get_tuple_labels(source)
+                    .map(|l| l[variadic..middle_end].to_vec());
+                let middle = self.create_normalized_tuple_type(middle_types, middle_flags, labels);
+                self.infer_from_types(middle, target_elements[variadic], type_params, inferences);
+            }
+            (TypeKind::ObjectType { .. }, TypeKind::Tuple { element_types: target_elements, element_flags: target_flags })
+                if self.get_array_readonly_flag(source).is_some() =>
+            {
+                // An array spread into `[...T]` infers the array itself for `T`.
+                if let ([target_element], [ElementFlags::Variadic]) = (target_elements.as_slice(), target_flags.as_slice()) {
+                    self.infer_from_types(source, *target_element, type_params, inferences);
+                }
+            }
+            (TypeKind::Tuple { element_types, .. }, TypeKind::ObjectType { .. })
+                if self.get_array_readonly_flag(target).is_some() =>
+            {
+                let target_element = self.get_element_type_of_iterable(target);
+                if !element_types.is_empty() {
+                    let source_element = self.create_union_type(element_types);
+                    self.infer_from_types(source_element, target_element, type_params, inferences);
+                }
+            }
+            (
+                TypeKind::ObjectType { members: source_members, call_signatures: source_sigs, index_infos: source_indexes, .. },
+                TypeKind::ObjectType { members: target_members, call_signatures: target_sigs, index_infos: target_indexes, .. },
+            ) => {
+                for (name, target_member) in &target_members {
+                    if let Some(&source_member) = source_members.get(name) {
+                        self.infer_from_types(source_member, *target_member, type_params, inferences);
                     }
-                    "Parameters" => {
-                        if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                let inner = self.get_type_from_type_node(&type_args[0]);
-                                return self.get_parameters_type_of(inner);
-                            }
+                }
+                for (source_index, target_index) in source_indexes.iter().zip(&target_indexes) {
+                    self.infer_from_types(source_index.type_id, target_index.type_id, type_params, inferences);
+                }
+                if let (Some(source_sig), Some(target_sig)) = (source_sigs.first(), target_sigs.first()) {
+                    let instantiated;
+                    let source_sig = if source_sig.type_parameters.is_empty() {
+                        source_sig
+                    } else {
+                        instantiated = self.instantiate_signature_in_context_of(source_sig, target_sig);
+                        &instantiated
+                    };
+                    // A generic rest parameter in the target collects the
+                    // corresponding source parameters as a tuple.
+                    let rest_index = target_sig.parameters.len().wrapping_sub(1);
+                    let generic_rest = target_sig.parameters.last()
+                        .filter(|p| target_sig.has_rest_parameter && (self.is_generic_type(p.type_id) || self.is_variadic_tuple_type(p.type_id)))
+                        .map(|p| p.type_id);
+                    for (i, (s, t)) in source_sig.parameters.iter().zip(&target_sig.parameters).enumerate() {
+                        if generic_rest.is_some() && i == rest_index {
+                            break;
                         }
-                        return self.type_table.any_type;
+                        self.infer_from_types(s.type_id, t.type_id, type_params, inferences);
                     }
-                    "NonNullable" => {
-                        if let Some(type_args) = n.type_arguments {
-                            if !type_args.is_empty() {
-                                let inner = self.get_type_from_type_node(&type_args[0]);
-                                return self.get_non_nullable_type(inner);
-                            }
-                        }
-                        return self.type_table.any_type;
+                    if let Some(rest_type) = generic_rest {
+                        let source_params = self.get_parameters_tuple(source_sig, rest_index);
+                        self.infer_from_types(source_params, rest_type, type_params, inferences);
                     }
-                    "Exclude" | "Extract" | "InstanceType" | "ConstructorParameters"
-                    | "ThisParameterType" | "OmitThisParameter" | "Uppercase" | "Lowercase"
-                    | "Capitalize" | "Uncapitalize" => {
-                        return self.type_table.any_type;
+                    self.infer_from_types(source_sig.return_type, target_sig.return_type, type_params, inferences);
+                    let predicate_types = source_sig.type_predicate.as_ref().and_then(|p| p.type_id)
+                        .zip(target_sig.type_predicate.as_ref().and_then(|p| p.type_id));
+                    if let Some((source_predicate, target_predicate)) = predicate_types {
+                        self.infer_from_types(source_predicate, target_predicate, type_params, inferences);
                     }
-                    _ => {}
                 }
+            }
+            _ => {}
+        }
+    }
+
+    /// Substitute type parameter IDs with concrete types in a type.
+    /// Deferred generic types (`keyof T`, `T[K]`, mapped and conditional types)
+    /// are re-resolved once their type parameters are replaced.
+    fn substitute_type_by_id(&mut self, type_id: TypeId, type_param_ids: &[TypeId], type_args: &[TypeId]) -> TypeId {
+        // Check if this type is one of the type parameters
+        if let Some(i) = type_param_ids.iter().position(|&tp_id| tp_id == type_id) {
+            return type_args.get(i).copied().unwrap_or(type_id);
+        }
+        if self.instantiation_depth >= MAX_INSTANTIATION_DEPTH || self.instantiation_count >= MAX_INSTANTIATION_COUNT {
+            self.report_instantiation_limit();
+            return self.type_table.any_type;
+        }
+        self.instantiation_depth += 1;
+        self.instantiation_count += 1;
+        let result = self.substitute_type_worker(type_id, type_param_ids, type_args);
+        self.instantiation_depth -= 1;
+        result
+    }
+
+    fn substitute_type_worker(&mut self, type_id: TypeId, type_param_ids: &[TypeId], type_args: &[TypeId]) -> TypeId {
+        // Instantiations of standard library interfaces are instantiated anew
+        // from their type arguments, so they keep displaying as references.
+        if let Some((name, args)) = self.global_type_references.get(&type_id).cloned() {
+            let new_args: Vec<TypeId> = args.iter()
+                .map(|&arg| self.substitute_type_by_id(arg, type_param_ids, type_args))
+                .collect();
+            return if new_args == args { type_id } else { self.create_global_type_reference(name, &new_args) };
+        }
+        // So are aliased types, which may be recursive.
+        if let Some((name, args)) = self.type_alias_references.get(&type_id).cloned() {
+            let new_args: Vec<TypeId> = args.iter()
+                .map(|&arg| self.substitute_type_by_id(arg, type_param_ids, type_args))
+                .collect();
+            return if new_args == args { type_id } else { self.get_type_alias_instantiation(&name, &new_args) };
+        }
+        let result = self.substitute_type_structure(type_id, type_param_ids, type_args);
+        // Instantiations of interfaces display as references to them.
+        if result != type_id && !self.interface_type_references.contains_key(&result) {
+            if let Some((name, args)) = self.interface_type_references.get(&type_id).cloned() {
+                let new_args: Vec<TypeId> = args.iter()
+                    .map(|&arg| self.substitute_type_by_id(arg, type_param_ids, type_args))
+                    .collect();
+                self.interface_type_references.insert(result, (name, new_args));
+            }
+        }
+        result
+    }
+
+    /// Substitute type parameters in a conditional type. Only the branch
+    /// taken is instantiated, so that an alias recursing through one branch
+    /// ends at the other. A branch that is itself an instantiation of a
+    /// conditional alias, like `Count<N, [...A, 0]>` in
+    /// `type Count<N, A> = A["length"] extends N ? A : Count<N, [...A, 0]>`,
+    /// is evaluated in place rather than nested, up to
+    /// `MAX_TAIL_RECURSION_DEPTH` times.
+    fn substitute_conditional_type(&mut self, type_id: TypeId, type_param_ids: &[TypeId], type_args: &[TypeId]) -> TypeId {
+        let mut type_id = type_id;
+        let mut type_param_ids = type_param_ids.to_vec();
+        let mut type_args = type_args.to_vec();
+        let mut tail_count = 0;
+        loop {
+            let TypeKind::Conditional { check_type, extends_type, true_type, false_type } = self.type_table.get(type_id).kind else {
+                return self.substitute_type_by_id(type_id, &type_param_ids, &type_args);
+            };
+            let check = self.substitute_type_by_id(check_type, &type_param_ids, &type_args);
+            // A conditional whose check type is a naked type parameter distributes over unions.
+            let is_distributive = matches!(self.type_table.get(check_type).kind, TypeKind::TypeParameter { .. });
+            if is_distributive {
+                if let TypeKind::Union { types } = &self.type_table.get(check).kind {
+                    let types = types.clone();
+                    let mut param_ids = type_param_ids.clone();
+                    let mut args = type_args.clone();
+                    param_ids.insert(0, check_type);
+                    args.insert(0, check);
+                    let results: Vec<TypeId> = types.iter().map(|&t| {
+                        args[0] = t;
+                        self.substitute_type_by_id(type_id, &param_ids, &args)
+                    }).collect();
+                    return self.create_union_type(results);
+                }
+            }
+            let extends = self.substitute_type_by_id(extends_type, &type_param_ids, &type_args);
+            let is_resolved = !self.is_generic_type(check) && !self.is_generic_type(extends)
+                && !self.type_table.get(check).flags.contains(TypeFlags::ANY);
+            if !is_resolved {
+                let true_type = self.substitute_type_by_id(true_type, &type_param_ids, &type_args);
+                let false_type = self.substitute_type_by_id(false_type, &type_param_ids, &type_args);
+                return self.get_conditional_type(check, extends, true_type, false_type);
+            }
+            let branch = if self.is_type_assignable_to(check, extends) { true_type } else { false_type };
+            let tail_alias = self.type_alias_references.get(&branch).cloned().and_then(|(name, args)| {
+                let alias = self.type_aliases.get(&name)?;
+                let is_conditional = matches!(self.type_table.get(alias.declared_type).kind, TypeKind::Conditional { .. });
+                (alias.state == TypeAliasState::Resolved && is_conditional).then_some((name, args))
+            });
+            let Some((name, args)) = tail_alias else {
+                return self.substitute_type_by_id(branch, &type_param_ids, &type_args);
+            };
+            if tail_count >= MAX_TAIL_RECURSION_DEPTH {
+                self.report_instantiation_limit();
+                return self.type_table.any_type;
+            }
+            tail_count += 1;
+            let new_args: Vec<TypeId> = args.iter()
+                .map(|&arg| self.substitute_type_by_id(arg, &type_param_ids, &type_args))
+                .collect();
+            let new_args = self.fill_type_alias_arguments(&name, &new_args);
+            if let Some(&instantiation) = self.type_alias_instantiations.get(&(name.clone(), new_args.clone())) {
+                return instantiation;
+            }
+            let alias = &self.type_aliases[&name];
+            type_id = alias.declared_type;
+            type_param_ids = alias.type_parameters.clone();
+            type_args = new_args;
+        }
+    }
+
+    /// Substitute type parameters in the members or constituents of a type.
+    fn substitute_type_structure(&mut self, type_id: TypeId, type_param_ids: &[TypeId], type_args: &[TypeId]) -> TypeId {
+        match self.type_table.get(type_id).kind.clone() {
+            TypeKind::Union { types } => {
+                let substituted: Vec<TypeId> = types.iter()
+                    .map(|&t| self.substitute_type_by_id(t, type_param_ids, type_args))
+                    .collect();
+                if substituted == types { type_id } else { self.create_union_type(substituted) }
+            }
+            TypeKind::Intersection { types } => {
+                let substituted: Vec<TypeId> = types.iter()
+                    .map(|&t| self.substitute_type_by_id(t, type_param_ids, type_args))
+                    .collect();
+                if substituted == types { type_id } else { self.create_intersection_type(substituted) }
+            }
+            TypeKind::ObjectType { .. } => {
+                self.member_instantiation_depth += 1;
+                let result = self.substitute_object_type(type_id, type_param_ids, type_args);
+                self.member_instantiation_depth -= 1;
+                result
+            }
+            TypeKind::Tuple { element_types, element_flags } => {
+                let substituted: Vec<TypeId> = element_types.iter()
+                    .map(|&t| self.substitute_type_by_id(t, type_param_ids, type_args))
+                    .collect();
+                if substituted == element_types {
+                    return type_id;
+                }
+                // Instantiating a variadic element may produce a tuple or array to spread.
+                let labels = self.type_table.get_tuple_labels(type_id).map(|l| l.to_vec());
+                self.create_normalized_tuple_type(substituted, element_flags, labels)
+            }
+            TypeKind::Index { target } => {
+                let target = self.substitute_type_by_id(target, type_param_ids, type_args);
+                self.get_index_type(target)
+            }
+            TypeKind::IndexedAccess { object_type, index_type } => {
+                let object_type = self.substitute_type_by_id(object_type, type_param_ids, type_args);
+                let index_type = self.substitute_type_by_id(index_type, type_param_ids, type_args);
+                self.get_indexed_access_type(object_type, index_type)
+            }
+            TypeKind::Conditional { .. } => self.substitute_conditional_type(type_id, type_param_ids, type_args),
+            TypeKind::Mapped {
+                type_parameter, constraint_type, name_type, template_type, modifiers_type,
+                readonly_modifier, optional_modifier,
+            } => {
+                // Keep `keyof T` unresolved so the instantiation stays homomorphic.
+                let constraint_type = match self.type_table.get(constraint_type).kind {
+                    TypeKind::Index { target } => {
+                        let target = self.substitute_type_by_id(target, type_param_ids, type_args);
+                        self.create_index_type(target)
+                    }
+                    _ => self.substitute_type_by_id(constraint_type, type_param_ids, type_args),
+                };
+                let name_type = name_type.map(|t| self.substitute_type_by_id(t, type_param_ids, type_args));
+                let template_type = template_type.map(|t| self.substitute_type_by_id(t, type_param_ids, type_args));
+                let modifiers_type = modifiers_type.map(|t| self.substitute_type_by_id(t, type_param_ids, type_args));
+                self.create_mapped_type(
+                    type_parameter, constraint_type, name_type, template_type, modifiers_type,
+                    readonly_modifier, optional_modifier,
+                )
+            }
+            TypeKind::TemplateLiteral { texts, types } => {
+                let substituted: Vec<TypeId> = types.iter()
+                    .map(|&t| self.substitute_type_by_id(t, type_param_ids, type_args))
+                    .collect();
+                if substituted == types { type_id } else { self.get_template_literal_type(texts, substituted) }
+            }
+            TypeKind::StringMapping { mapping, target } => {
+                let substituted = self.substitute_type_by_id(target, type_param_ids, type_args);
+                if substituted == target { type_id } else { self.get_string_mapping_type(mapping, substituted) }
+            }
+            _ => type_id,
+        }
+    }
+
+    /// Substitute type parameters in the members and signatures of an object type.
+    fn substitute_object_type(&mut self, type_id: TypeId, type_param_ids: &[TypeId], type_args: &[TypeId]) -> TypeId {
+        let TypeKind::ObjectType { object_flags, members, call_signatures, construct_signatures, index_infos } =
+            self.type_table.get(type_id).kind.clone()
+        else {
+            return type_id;
+        };
+        let mut changed = false;
+        let mut new_members = IndexMap::with_capacity(members.len());
+        for (name, &member) in &members {
+            let new_member = self.substitute_type_by_id(member, type_param_ids, type_args);
+            changed |= new_member != member;
+            new_members.insert(name.clone(), new_member);
+        }
+        let mut substitute_signatures = |checker: &mut Self, sigs: &[Signature]| -> Vec<Signature> {
+            sigs.iter().map(|sig| {
+                let new_sig = checker.substitute_signature(sig, type_param_ids, type_args);
+                changed |= new_sig.return_type != sig.return_type
+                    || new_sig.parameters.iter().zip(&sig.parameters).any(|(a, b)| a.type_id != b.type_id)
+                    || new_sig.type_predicate != sig.type_predicate;
+                new_sig
+            }).collect()
+        };
+        let new_calls = substitute_signatures(self, &call_signatures);
+        let new_constructs = substitute_signatures(self, &construct_signatures);
+        let new_index_infos: Vec<IndexInfo> = index_infos.iter().map(|info| {
+            let value_type = self.substitute_type_by_id(info.type_id, type_param_ids, type_args);
+            changed |= value_type != info.type_id;
+            IndexInfo { type_id: value_type, ..info.clone() }
+        }).collect();
+        if !changed {
+            return type_id;
+        }
+        let new_type = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: object_flags | ObjectFlags::INSTANTIATED,
+                members: new_members,
+                call_signatures: new_calls,
+                construct_signatures: new_constructs,
+                index_infos: new_index_infos,
+            },
+        );
+        self.type_table.copy_property_flags(type_id, new_type);
+        if let Some(this_types) = self.this_type_parameters.get(&type_id).cloned() {
+            self.this_type_parameters.insert(new_type, this_types);
+        }
+        new_type
+    }
+
+    fn substitute_signature(&mut self, sig: &Signature, type_param_ids: &[TypeId], type_args: &[TypeId]) -> Signature {
+        Signature {
+            type_parameters: sig.type_parameters.clone(),
+            parameters: sig.parameters.iter().map(|p| SignatureParameter {
+                name: p.name.clone(),
+                type_id: self.substitute_type_by_id(p.type_id, type_param_ids, type_args),
+                optional: p.optional,
+            }).collect(),
+            return_type: self.substitute_type_by_id(sig.return_type, type_param_ids, type_args),
+            min_argument_count: sig.min_argument_count,
+            has_rest_parameter: sig.has_rest_parameter,
+            this_type: sig.this_type.map(|t| self.substitute_type_by_id(t, type_param_ids, type_args)),
+            is_method: sig.is_method,
+            type_predicate: sig.type_predicate.as_ref().map(|predicate| TypePredicate {
+                type_id: predicate.type_id.map(|t| self.substitute_type_by_id(t, type_param_ids, type_args)),
+                ..predicate.clone()
+            }),
+        }
+    }
+
+    /// Whether a type still depends on unresolved type parameters, so that
+    /// operations on it (`keyof`, indexed access, mapping) must be deferred.
+    fn is_generic_type(&self, type_id: TypeId) -> bool {
+        match &self.type_table.get(type_id).kind {
+            TypeKind::TypeParameter { .. }
+            | TypeKind::Mapped { .. }
+            | TypeKind::Conditional { .. }
+            | TypeKind::Substitution { .. } => true,
+            TypeKind::Index { target } => self.is_generic_type(*target),
+            TypeKind::IndexedAccess { object_type, index_type } => {
+                self.is_generic_type(*object_type) || self.is_generic_type(*index_type)
+            }
+            TypeKind::Union { types } | TypeKind::Intersection { types } => {
+                types.iter().any(|&t| self.is_generic_type(t))
+            }
+            TypeKind::TemplateLiteral { types, .. } => types.iter().any(|&t| self.is_generic_type(t)),
+            TypeKind::StringMapping { target, .. } => self.is_generic_type(*target),
+            _ => false,
+        }
+    }
+
+    fn check_new_expression(&mut self, node: &NewExpression<'_>) -> TypeId {
+        let class_type = self.check_expression(node.expression);
+        let args = self.check_call_argument_list(node.arguments.unwrap_or(&[]), &[]);
+
+        if let Some((class_name, true)) = self.class_of_type.get(&class_type) {
+            if self.classes[class_name].is_abstract {
+                self.error(&messages::CANNOT_CREATE_AN_INSTANCE_OF_AN_ABSTRACT_CLASS, &[]);
+            }
+        }
+
+        let class_flags = self.type_table.get(class_type).flags;
+        if class_flags.contains(TypeFlags::ANY) {
+            return self.type_table.any_type;
+        }
+
+        let signatures = if let TypeKind::ObjectType { construct_signatures, .. } = &self.type_table.get(class_type).kind {
+            construct_signatures.clone()
+        } else {
+            Vec::new()
+        };
+
+        if signatures.is_empty() {
+            // Only report "not constructable" for types we can definitively say are not constructable.
+            // If the type is an ObjectType with members (e.g., known built-in or declared class),
+            // it may just be missing explicit construct signatures in our simplified type system.
+            let is_known_type = match &self.type_table.get(class_type).kind {
+                TypeKind::ObjectType { members, call_signatures, .. } => {
+                    !members.is_empty() || !call_signatures.is_empty()
+                }
+                _ => false,
+            };
+            if !is_known_type {
+                // Check if the expression is a class name or known global
+                let is_class = if let Expression::Identifier(id) = node.expression {
+                    self.binder.resolve_name(&id.text_name)
+                        .and_then(|sid| self.binder.get_symbol(sid))
+                        .map(|s| s.flags.contains(SymbolFlags::CLASS))
+                        .unwrap_or(false)
+                        || Self::BUILTIN_GLOBALS.contains(&id.text_name.as_str())
+                } else {
+                    false
+                };
+                if !is_class {
+                    self.error(&messages::THIS_EXPRESSION_IS_NOT_CONSTRUCTABLE, &[]);
+                }
+            }
+            return self.type_table.any_type;
+        }
+
+        let type_args: Vec<TypeId> = node.type_arguments.unwrap_or(&[]).iter()
+            .map(|arg| self.get_type_from_type_node(arg))
+            .collect();
+        let instance_type = self.resolve_call(&signatures, &args, &type_args);
+        if let Some(signature) = self.resolved_signature.take() {
+            self.resolved_signatures.insert(node.data.range, signature);
+        }
+        instance_type
+    }
+
+    fn check_property_access(&mut self, node: &PropertyAccessExpression<'_>) -> TypeId {
+        let is_write = std::mem::take(&mut self.is_assignment_target);
+        let is_compound_write = std::mem::take(&mut self.is_compound_assignment_target);
+        let receiver = self.check_expression(node.expression);
+        // The elements of an array literal are mutable locations: its members
+        // see the literal element types widened, as a declaration would.
+        let receiver = match node.expression {
+            Expression::ArrayLiteral(_) => self.get_widened_type(receiver),
+            _ => receiver,
+        };
+        self.resolve_deferred_type(receiver);
+        self.last_property_access_receiver = Some(receiver);
+        self.check_ambient_const_enum_access(receiver);
+        // Members of a type parameter, like a polymorphic `this`, are those
+        // of its constraint.
+        let obj_type = self.get_apparent_type_of_type_parameter(receiver);
+
+        let obj_flags = self.type_table.get(obj_type).flags;
+        if obj_flags.contains(TypeFlags::ANY) {
+            return self.type_table.any_type;
+        }
+        if obj_flags.contains(TypeFlags::UNKNOWN) {
+            match node.expression {
+                Expression::Identifier(id) => self.error(&messages::_0_IS_OF_TYPE_UNKNOWN, &[&id.text_name]),
+                _ => self.error(&messages::OBJECT_IS_OF_TYPE_UNKNOWN, &[]),
+            }
+            return self.type_table.any_type;
+        }
+
+        // Check for null/undefined access
+        if self.strict_null_checks {
+            if obj_flags.contains(TypeFlags::NULL) {
+                self.error(&messages::OBJECT_IS_POSSIBLY_NULL, &[]);
+            }
+            if obj_flags.contains(TypeFlags::UNDEFINED) {
+                self.error(&messages::OBJECT_IS_POSSIBLY_UNDEFINED, &[]);
+            }
+        }
+
+        // Get the property name
+        let prop_name = match &node.name {
+            MemberName::Identifier(id) => id.text_name.clone(),
+            MemberName::PrivateIdentifier(id) => id.text_name.clone(),
+        };
+
+        if let Some((class_name, is_static)) = self.class_of_type.get(&obj_type).cloned() {
+            self.check_property_accessibility(&class_name, is_static, &prop_name);
+        }
+
+        // O(1) property lookup via IndexMap.
+        let prop_type_id = if let TypeKind::ObjectType { members, .. } = &self.type_table.get(obj_type).kind {
+            members.get(&prop_name).copied()
+        } else {
+            return self.type_table.any_type;
+        };
+
+        if let Some(tid) = prop_type_id {
+            // Once the write is rejected, the assigned value is not checked as well.
+            if (is_write || is_compound_write) && self.is_assignment_to_readonly_property(node.expression, obj_type, &prop_name) {
+                self.error(&messages::CANNOT_ASSIGN_TO_0_BECAUSE_IT_IS_A_READ_ONLY_PROPERTY, &[&prop_name]);
+                return self.type_table.any_type;
+            }
+            let tid = self.instantiate_this_type(tid, obj_type, receiver);
+            // Enum members are fresh, so that `let e = E.A` widens to `E`
+            if self.enum_literal_bases.contains_key(&tid) {
+                return self.get_fresh_type_of_literal_type(tid);
+            }
+            if is_write {
+                let write_type = match self.type_table.get_write_type(obj_type, &prop_name) {
+                    Some(write_type) => self.instantiate_this_type(write_type, obj_type, receiver),
+                    None => tid,
+                };
+                self.assignment_target_property = Some((obj_type, prop_name));
+                return write_type;
+            }
+            return self.get_read_type_of_property(obj_type, &prop_name, tid);
+        }
+
+        if self.strict_bind_call_apply {
+            if let Some(method_type) = self.get_bind_call_apply_type(obj_type, &prop_name) {
+                return method_type;
+            }
+        }
+        if let Some(method_type) = self.get_array_method_type(obj_type, &prop_name) {
+            return method_type;
+        }
+
+        let key_type = self.create_string_literal_type(prop_name);
+        if let Some(info) = self.get_applicable_index_info(obj_type, key_type) {
+            if (is_write || is_compound_write) && info.is_readonly {
+                self.report_readonly_index_signature_write(obj_type);
+            }
+            return self.get_index_access_read_type(info.type_id, is_write);
+        }
+
+        // Property not found on a known object type — for now return any
+        // (a full implementation would report TS2339: Property 'x' does not exist on type 'Y')
+        self.type_table.any_type
+    }
+
+    /// The type of a method of an array type that takes part in inference.
+    /// Array types only carry `length` and their index signature; other
+    /// methods are untyped.
+    fn get_array_method_type(&mut self, array_type: TypeId, name: &str) -> Option<TypeId> {
+        self.get_array_readonly_flag(array_type)?;
+        let element_type = self.get_element_type_of_iterable(array_type);
+        match name {
+            // filter<S extends T>(predicate: (value: T, index: number, array: T[]) => value is S): S[]
+            // filter(predicate: (value: T, index: number, array: T[]) => unknown): T[]
+            "filter" => {
+                let narrowed_type = self.create_type_parameter("S", Some(element_type));
+                let callback_params = vec![
+                    SignatureParameter { name: "value".to_string(), type_id: element_type, optional: false },
+                    SignatureParameter { name: "index".to_string(), type_id: self.type_table.number_type, optional: false },
+                    SignatureParameter { name: "array".to_string(), type_id: array_type, optional: false },
+                ];
+                let callback = |return_type, type_predicate| Signature {
+                    type_parameters: vec![],
+                    parameters: callback_params.clone(),
+                    return_type,
+                    min_argument_count: 3,
+                    has_rest_parameter: false,
+                    this_type: None,
+                    is_method: false,
+                    type_predicate,
+                };
+                let guard = callback(self.type_table.boolean_type, Some(TypePredicate {
+                    kind: TypePredicateKind::Identifier,
+                    parameter_name: Some("value".to_string()),
+                    parameter_index: Some(0),
+                    type_id: Some(narrowed_type),
+                }));
+                let guard_type = self.create_function_type(guard);
+                let predicate = callback(self.type_table.unknown_type, None);
+                let predicate_type = self.create_function_type(predicate);
+                let method = |type_parameters, parameter_type, return_type| Signature {
+                    type_parameters,
+                    parameters: vec![SignatureParameter { name: "predicate".to_string(), type_id: parameter_type, optional: false }],
+                    return_type,
+                    min_argument_count: 1,
+                    has_rest_parameter: false,
+                    this_type: None,
+                    is_method: true,
+                    type_predicate: None,
+                };
+                let narrowed_array = self.create_array_type(narrowed_type);
+                let element_array = self.create_array_type(element_type);
+                let signatures = vec![
+                    method(vec![narrowed_type], guard_type, narrowed_array),
+                    method(vec![], predicate_type, element_array),
+                ];
+                Some(self.type_table.add_type(
+                    TypeFlags::OBJECT,
+                    TypeKind::ObjectType {
+                        object_flags: ObjectFlags::ANONYMOUS,
+                        members: IndexMap::new(),
+                        call_signatures: signatures,
+                        construct_signatures: vec![],
+                        index_infos: vec![],
+                    },
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Create an anonymous function type with a single call signature.
+    fn create_function_type(&mut self, signature: Signature) -> TypeId {
+        self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS,
+                members: IndexMap::new(),
+                call_signatures: vec![signature],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        )
+    }
+
+    /// The type of `call`, `apply` or `bind` on a function under
+    /// strictBindCallApply, typed by the function's (last) call signature.
+    /// `bind` has one overload per number of leading arguments it binds.
+    fn get_bind_call_apply_type(&mut self, function_type: TypeId, name: &str) -> Option<TypeId> {
+        let sig = match &self.type_table.get(function_type).kind {
+            TypeKind::ObjectType { call_signatures, .. } => call_signatures.last()?.clone(),
+            _ => return None,
+        };
+        if !sig.type_parameters.is_empty() {
+            return None;
+        }
+        let this_arg = SignatureParameter {
+            name: "thisArg".to_string(),
+            type_id: sig.this_type.unwrap_or(self.type_table.unknown_type),
+            optional: false,
+        };
+        let signatures = match name {
+            "call" => vec![Signature {
+                parameters: std::iter::once(this_arg).chain(sig.parameters.iter().cloned()).collect(),
+                min_argument_count: sig.min_argument_count + 1,
+                this_type: None,
+                is_method: true,
+                type_predicate: None,
+                ..sig
+            }],
+            "apply" => {
+                let args = SignatureParameter {
+                    name: "args".to_string(),
+                    type_id: self.get_parameters_tuple(&sig, 0),
+                    optional: sig.min_argument_count == 0,
+                };
+                vec![Signature {
+                    type_parameters: vec![],
+                    min_argument_count: if args.optional { 1 } else { 2 },
+                    parameters: vec![this_arg, args],
+                    return_type: sig.return_type,
+                    has_rest_parameter: false,
+                    this_type: None,
+                    is_method: true,
+                    type_predicate: None,
+                }]
+            }
+            "bind" => {
+                let fixed_count = sig.parameters.len() - usize::from(sig.has_rest_parameter);
+                (0..=fixed_count).map(|bound| {
+                    let remaining = Signature {
+                        parameters: sig.parameters[bound..].to_vec(),
+                        min_argument_count: sig.min_argument_count.saturating_sub(bound as u32),
+                        this_type: None,
+                        is_method: false,
+                        type_predicate: None,
+                        ..sig.clone()
+                    };
+                    let return_type = self.type_table.add_type(
+                        TypeFlags::OBJECT,
+                        TypeKind::ObjectType {
+                            object_flags: ObjectFlags::ANONYMOUS,
+                            members: IndexMap::new(),
+                            call_signatures: vec![remaining],
+                            construct_signatures: vec![],
+                            index_infos: vec![],
+                        },
+                    );
+                    Signature {
+                        type_parameters: vec![],
+                        parameters: std::iter::once(this_arg.clone()).chain(sig.parameters[..bound].iter().cloned()).collect(),
+                        return_type,
+                        min_argument_count: sig.min_argument_count.min(bound as u32) + 1,
+                        has_rest_parameter: false,
+                        this_type: None,
+                        is_method: true,
+                        type_predicate: None,
+                    }
+                }).collect()
+            }
+            _ => return None,
+        };
+        Some(self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS,
+                members: IndexMap::new(),
+                call_signatures: signatures,
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        ))
+    }
+
+    /// Under isolatedModules, report accesses of ambient const enum members:
+    /// their values live in another file, so a transpiler cannot inline them,
+    /// and no enum object exists at runtime.
+    fn check_ambient_const_enum_access(&mut self, object_type: TypeId) {
+        if self.isolated_modules && self.ambient_const_enum_objects.contains(&object_type) {
+            let flag_name = self.isolated_modules_flag_name();
+            self.error(&messages::CANNOT_ACCESS_AMBIENT_CONST_ENUMS_WHEN_0_IS_ENABLED, &[flag_name]);
+        }
+    }
+
+    fn check_element_access(&mut self, node: &ElementAccessExpression<'_>) -> TypeId {
+        let is_write = std::mem::take(&mut self.is_assignment_target);
+        let is_compound_write = std::mem::take(&mut self.is_compound_assignment_target);
+        let obj_type = self.check_expression(node.expression);
+        self.resolve_deferred_type(obj_type);
+        let index_type = self.check_expression(node.argument_expression);
+        self.check_ambient_const_enum_access(obj_type);
+
+        let obj_flags = self.type_table.get(obj_type).flags;
+        if obj_flags.contains(TypeFlags::ANY) {
+            return self.type_table.any_type;
+        }
+
+        let key = self.get_literal_key_name(index_type);
+        if let TypeKind::Tuple { element_types, element_flags } = &self.type_table.get(obj_type).kind {
+            // Only the fixed elements and `length` are known to be present.
+            let is_fixed_element = key.as_deref().is_some_and(|key| {
+                key == "length"
+                    || key.parse::<usize>().is_ok_and(|i| {
+                        i < element_types.len()
+                            && !element_flags[..=i].iter().any(|f| matches!(f, ElementFlags::Rest | ElementFlags::Variadic))
+                    })
+            });
+            if (is_write || is_compound_write) && self.type_table.is_readonly_tuple(obj_type) {
+                match key.as_deref() {
+                    Some(key) if is_fixed_element => {
+                        self.error(&messages::CANNOT_ASSIGN_TO_0_BECAUSE_IT_IS_A_READ_ONLY_PROPERTY, &[key]);
+                        return self.type_table.any_type;
+                    }
+                    _ => self.report_readonly_index_signature_write(obj_type),
+                }
+            }
+            let element_type = self.resolve_indexed_access(obj_type, index_type);
+            return if is_fixed_element {
+                element_type
+            } else {
+                self.get_index_access_read_type(element_type, is_write)
+            };
+        }
+        // A literal or unique symbol index names a property: `o["a"]`, `o[Symbol.iterator]`
+        if let Some(key) = key {
+            if let TypeKind::ObjectType { members, .. } = &self.type_table.get(obj_type).kind {
+                if let Some(&prop_type) = members.get(&key) {
+                    if (is_write || is_compound_write) && self.is_assignment_to_readonly_property(node.expression, obj_type, &key) {
+                        self.error(&messages::CANNOT_ASSIGN_TO_0_BECAUSE_IT_IS_A_READ_ONLY_PROPERTY, &[&key]);
+                        return self.type_table.any_type;
+                    }
+                    if is_write {
+                        let write_type = self.type_table.get_write_type(obj_type, &key).unwrap_or(prop_type);
+                        self.assignment_target_property = Some((obj_type, key));
+                        return write_type;
+                    }
+                    return self.get_read_type_of_property(obj_type, &key, prop_type);
+                }
+            }
+        }
+
+        if let Some(info) = self.get_applicable_index_info(obj_type, index_type) {
+            if (is_write || is_compound_write) && info.is_readonly {
+                self.report_readonly_index_signature_write(obj_type);
+            }
+            return self.get_index_access_read_type(info.type_id, is_write);
+        }
+
+        self.type_table.any_type
+    }
+
+    /// Whether writing property `name` of `object_type`, accessed on
+    /// `object`, assigns a readonly property. A class's own readonly
+    /// properties may be assigned through `this` in its constructor.
+    fn is_assignment_to_readonly_property(&self, object: &Expression<'_>, object_type: TypeId, name: &str) -> bool {
+        if !self.type_table.get_property_flags(object_type, name).contains(PropertyFlags::READONLY) {
+            return false;
+        }
+        let Expression::ThisKeyword(_) = object else { return true };
+        let Some(class_name) = &self.constructor_class else { return true };
+        let is_own_instance_type = self.class_of_type.get(&object_type)
+            .is_some_and(|(owner, is_static)| owner == class_name && !is_static);
+        !(is_own_instance_type && self.classes[class_name].member_flags(name, false).is_some())
+    }
+
+    /// Report a write through a readonly index signature of `object_type`,
+    /// including the elements of readonly arrays and tuples (TS2542).
+    fn report_readonly_index_signature_write(&mut self, object_type: TypeId) {
+        let object = self.format_type(object_type);
+        self.error(&messages::INDEX_SIGNATURE_IN_TYPE_0_ONLY_PERMITS_READING, &[&object]);
+    }
+
+    /// The type of an access through an index signature of type `type_id`.
+    /// Under noUncheckedIndexedAccess the key may be absent, so reads may
+    /// yield `undefined`.
+    fn get_index_access_read_type(&mut self, type_id: TypeId, is_write: bool) -> TypeId {
+        if self.no_unchecked_indexed_access && !is_write {
+            return self.create_union_type(vec![type_id, self.type_table.undefined_type]);
+        }
+        type_id
+    }
+
+    fn check_conditional_expression(&mut self, node: &ConditionalExpression<'_>) -> TypeId {
+        self.check_expression(node.condition);
+        let true_type = self.check_expression(node.when_true);
+        let false_type = self.check_expression(node.when_false);
+        self.create_union_type(vec![true_type, false_type])
+    }
+
+    fn check_prefix_unary(&mut self, node: &PrefixUnaryExpression<'_>) -> TypeId {
+        self.is_compound_assignment_target = matches!(node.operator, SyntaxKind::PlusPlusToken | SyntaxKind::MinusMinusToken);
+        let operand_type = self.check_expression(node.operand);
+        self.is_compound_assignment_target = false;
+        match node.operator {
+            SyntaxKind::PlusToken | SyntaxKind::MinusToken => {
+                // `-1` has a literal type of its own
+                if let Expression::NumericLiteral(n) = node.operand {
+                    let value = parse_numeric_literal_text(&n.text_name);
+                    let value = if node.operator == SyntaxKind::MinusToken { -value } else { value };
+                    let literal = self.create_number_literal_type(value);
+                    return self.get_fresh_type_of_literal_type(literal);
+                }
+                self.type_table.number_type
+            }
+            SyntaxKind::TildeToken => self.type_table.number_type,
+            SyntaxKind::ExclamationToken => self.type_table.boolean_type,
+            SyntaxKind::PlusPlusToken | SyntaxKind::MinusMinusToken => {
+                let ty = self.type_table.get(operand_type);
+                if !ty.flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ANY) {
+                    self.error(
+                        &messages::THE_OPERAND_OF_AN_INCREMENT_OR_DECREMENT_OPERATOR_MUST_BE_A_VARIABLE_OR_A_PROPERTY_ACCESS,
+                        &[],
+                    );
+                }
+                self.type_table.number_type
+            }
+            _ => self.type_table.any_type,
+        }
+    }
+
+    fn check_postfix_unary(&mut self, node: &PostfixUnaryExpression<'_>) -> TypeId {
+        self.is_compound_assignment_target = true;
+        let operand_type = self.check_expression(node.operand);
+        self.is_compound_assignment_target = false;
+        let ty = self.type_table.get(operand_type);
+        if !ty.flags.intersects(TypeFlags::NUMBER_LIKE | TypeFlags::ANY) {
+            self.error(
+                &messages::THE_OPERAND_OF_AN_INCREMENT_OR_DECREMENT_OPERATOR_MUST_BE_A_VARIABLE_OR_A_PROPERTY_ACCESS,
+                &[],
+            );
+        }
+        self.type_table.number_type
+    }
+
+    /// Check a `yield` or `yield*` expression in a generator: the values it
+    /// yields must be assignable to the generator's declared yield type. A
+    /// `yield` produces the value passed to `next`, a `yield*` the value the
+    /// delegated iterator returns.
+    fn check_yield_expression(&mut self, node: &YieldExpression<'_>) -> TypeId {
+        let any = self.type_table.any_type;
+        let (is_async, declared) = match self.generator_contexts.last() {
+            Some(context) => (context.is_async, context.declared),
+            None => (false, None),
+        };
+        let is_delegating = node.asterisk_token.is_some();
+        let operand_type = node.expression.map(|expr| match declared {
+            Some(declared) if !is_delegating => self.check_expression_with_contextual_type(expr, declared.yield_type),
+            _ => self.check_expression(expr),
+        });
+        if self.generator_contexts.is_empty() {
+            return operand_type.unwrap_or(any);
+        }
+        let (yielded_type, result_type) = if is_delegating {
+            let iteration_use = if is_async { IterationUse::AsyncYieldStar } else { IterationUse::YieldStar };
+            let types = self.check_iteration_types(operand_type.unwrap_or(any), iteration_use);
+            (types.yield_type, types.return_type)
+        } else {
+            let yielded_type = operand_type.unwrap_or(self.type_table.undefined_type);
+            let yielded_type = if is_async { self.get_awaited_type(yielded_type) } else { yielded_type };
+            (yielded_type, declared.map_or(any, |d| d.next_type))
+        };
+        match declared {
+            Some(declared) => {
+                self.check_type_assignable_to(yielded_type, declared.yield_type);
+            }
+            None => {
+                let widened = self.widen_type(yielded_type);
+                let widened = self.get_widened_type(widened);
+                if let Some(context) = self.generator_contexts.last_mut() {
+                    context.yield_types.push(widened);
+                }
+            }
+        }
+        result_type
+    }
+
+    /// Start checking the body of a generator function, whose `yield`
+    /// expressions are checked against its declared return type.
+    fn enter_generator_context(&mut self, is_async: bool, declared_return: Option<TypeId>) {
+        let declared = declared_return
+            .and_then(|return_type| self.get_iteration_types_of_generator_return_type(return_type, is_async));
+        self.generator_contexts.push(GeneratorContext { is_async, declared, yield_types: Vec::new() });
+    }
+
+    /// The return type of a function without a return type annotation, from
+    /// the type it returns: a `Promise` for an async function, and for a
+    /// generator a `Generator` (or `AsyncGenerator`) of the types it yielded,
+    /// which must be the innermost generator context.
+    fn get_inferred_return_type(&mut self, return_type: TypeId, is_async: bool, is_generator: bool) -> TypeId {
+        if is_generator {
+            let yield_types = self.generator_contexts.last()
+                .map(|context| context.yield_types.clone())
+                .unwrap_or_default();
+            let yield_type = if yield_types.is_empty() {
+                self.type_table.never_type
+            } else {
+                self.create_union_type(yield_types)
+            };
+            let name = if is_async { "AsyncGenerator" } else { "Generator" };
+            return self.create_global_type_reference(name, &[yield_type, return_type, self.type_table.unknown_type]);
+        }
+        if is_async {
+            let awaited = self.get_awaited_type(return_type);
+            return self.create_global_type_reference("Promise", &[awaited]);
+        }
+        return_type
+    }
+
+    fn check_await_expression(&mut self, node: &AwaitExpression<'_>) -> TypeId {
+        let operand_type = self.check_expression(node.expression);
+        self.get_awaited_type(operand_type)
+    }
+
+    fn check_arrow_function(&mut self, node: &ArrowFunction<'_>) -> TypeId {
+        let contextual_signature = self.contextual_signature.take();
+        self.check_strict_mode_parameters(node.parameters);
+        // Register parameter types so they're available in the body
+        let param_types: Vec<TypeId> = node.parameters.iter().enumerate().map(|(i, param)| {
+            if let Some(init) = param.initializer { self.check_expression(init); }
+            let param_type = self.get_parameter_type(param, i, contextual_signature.as_ref());
+            if let BindingName::Identifier(id) = &param.name {
+                self.register_declaration_type(id.data.range, &id.text_name, param_type);
+            }
+            param_type
+        }).collect();
+
+        let declared_return = self.get_type_from_type_annotation(node.return_type);
+        let mut type_predicate = self.get_type_predicate_of_return_type(node.return_type, node.parameters);
+
+        // Infer return type
+        let return_type = if let Some(declared) = declared_return {
+            // Check body with declared return type
+            match &node.body {
+                ArrowFunctionBody::Block(block) => {
+                    self.check_block_statements(block.statements);
+                }
+                ArrowFunctionBody::Expression(expr) => { self.check_expression(expr); }
+            }
+            declared
+        } else {
+            // Infer return type from body
+            match &node.body {
+                ArrowFunctionBody::Block(block) => {
+                    self.check_block_statements(block.statements);
+                    let return_types = self.collect_return_types(block);
+                    if return_types.is_empty() {
+                        if self.function_never_returns(node.data.range) {
+                            self.type_table.never_type
+                        } else {
+                            self.type_table.void_type
+                        }
+                    } else {
+                        self.create_union_type(return_types)
+                    }
+                }
+                ArrowFunctionBody::Expression(expr) => {
+                    // Expression body: return type is the expression's widened type
+                    let expr_type = self.check_expression(expr);
+                    let expr_type = self.widen_type(expr_type);
+                    self.get_widened_type(expr_type)
+                }
+            }
+        };
+        let is_async = node.data.modifier_flags.contains(ModifierFlags::ASYNC);
+        if declared_return.is_none() && return_type == self.type_table.boolean_type && !is_async {
+            type_predicate = self.get_inferred_type_predicate(&node.body, node.parameters);
+        }
+        let return_type = match declared_return {
+            Some(_) => return_type,
+            None => self.get_inferred_return_type(return_type, is_async, false),
+        };
+
+        // Create function type
+        let params: Vec<SignatureParameter> = node.parameters.iter().zip(param_types).map(|(p, param_type)| {
+            let param_name = match &p.name {
+                BindingName::Identifier(id) => id.text_name.clone(),
+                _ => String::new(),
+            };
+            SignatureParameter {
+                name: param_name,
+                type_id: param_type,
+                optional: p.question_token.is_some(),
+            }
+        }).collect();
+
+        if let ArrowFunctionBody::Block(_) = node.body {
+            self.check_all_code_paths_return(node.data.range, declared_return);
+            self.check_never_function_end(node.data.range, declared_return);
+        }
+
+        let sig = Signature {
+            type_parameters: vec![],
+            parameters: params,
+            return_type,
+            min_argument_count: node.parameters.iter()
+                .filter(|p| p.question_token.is_none() && p.initializer.is_none() && p.dot_dot_dot_token.is_none())
+                .count() as u32,
+            has_rest_parameter: node.parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
+            this_type: None,
+            is_method: false,
+            type_predicate,
+        };
+
+        self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS,
+                members: IndexMap::new(),
+                call_signatures: vec![sig],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        )
+    }
+
+    fn check_function_expression(&mut self, node: &FunctionExpression<'_>) -> TypeId {
+        let contextual_signature = self.contextual_signature.take();
+        let (this_parameter, parameters) = split_this_parameter(node.parameters);
+        self.check_strict_mode_parameters(parameters);
+        let this_type = self.get_this_parameter_type(this_parameter);
+        let params: Vec<SignatureParameter> = parameters.iter().enumerate().map(|(i, p)| {
+            if let Some(init) = p.initializer { self.check_expression(init); }
+            let param_name = match &p.name {
+                BindingName::Identifier(id) => id.text_name.clone(),
+                _ => String::new(),
+            };
+            let resolved_type = self.get_parameter_type(p, i, contextual_signature.as_ref());
+            self.register_declaration_type(p.name.data().range, &param_name, resolved_type);
+            SignatureParameter {
+                name: param_name,
+                type_id: resolved_type,
+                optional: p.question_token.is_some(),
+            }
+        }).collect();
+
+        let declared_return = self.get_type_from_type_annotation(node.return_type);
+        let is_async = node.data.modifier_flags.contains(ModifierFlags::ASYNC);
+        let is_generator = node.asterisk_token.is_some();
+        if is_generator {
+            self.enter_generator_context(is_async, declared_return);
+        }
+
+        // A function expression has its own `this`, even inside a constructor.
+        let saved_super_call_state = self.super_call_state.take();
+        let saved_this = self.enter_function_this_binding(this_type);
+        self.check_block_statements(node.body.statements);
+        self.restore_this_binding(saved_this);
+        self.super_call_state = saved_super_call_state;
+
+        let type_predicate = self.get_type_predicate_of_return_type(node.return_type, parameters);
+        if !is_generator {
+            self.check_all_code_paths_return(node.data.range, declared_return);
+            self.check_never_function_end(node.data.range, declared_return);
+        }
+        // Only async functions and generators infer their return type
+        let return_type = match declared_return {
+            Some(declared) => declared,
+            None if is_async || is_generator => {
+                let return_types = self.collect_return_types(node.body);
+                let return_type = if return_types.is_empty() {
+                    self.type_table.void_type
+                } else {
+                    self.create_union_type(return_types)
+                };
+                self.get_inferred_return_type(return_type, is_async, is_generator)
+            }
+            None if self.function_never_returns(node.data.range) => self.type_table.never_type,
+            None => self.type_table.any_type,
+        };
+        if is_generator {
+            self.generator_contexts.pop();
+        }
+
+        let sig = Signature {
+            type_parameters: vec![],
+            parameters: params,
+            return_type,
+            min_argument_count: parameters.iter()
+                .filter(|p| p.question_token.is_none() && p.initializer.is_none() && p.dot_dot_dot_token.is_none())
+                .count() as u32,
+            has_rest_parameter: parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
+            this_type,
+            is_method: false,
+            type_predicate,
+        };
+
+        self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS,
+                members: IndexMap::new(),
+                call_signatures: vec![sig],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        )
+    }
+
+    /// Check an expression whose value flows to a location of known type. An
+    /// array literal written where a tuple is expected gets a tuple type
+    /// rather than an array type.
+    fn check_expression_with_contextual_type(&mut self, expr: &Expression<'_>, contextual_type: TypeId) -> TypeId {
+        self.contextual_types.insert(expr.data().range, contextual_type);
+        match expr {
+            Expression::Parenthesized(p) => self.check_expression_with_contextual_type(p.expression, contextual_type),
+            Expression::ArrayLiteral(arr) => match self.get_contextual_tuple_type(contextual_type) {
+                Some(tuple) => self.check_array_literal_as_tuple(arr, tuple),
+                None => self.check_expression(expr),
+            },
+            Expression::ObjectLiteral(_) => {
+                self.contextual_this_type = self.get_this_type_from_contextual_type(contextual_type);
+                self.check_expression(expr)
+            }
+            Expression::ArrowFunction(_) | Expression::FunctionExpression(_) => {
+                self.contextual_signature = self.get_contextual_call_signature(contextual_type);
+                self.check_expression(expr)
+            }
+            _ => self.check_expression(expr),
+        }
+    }
+
+    /// The call signature a contextual type gives a function expression: that
+    /// of a type with a single call signature, looking through unions and
+    /// type parameter constraints.
+    fn get_contextual_call_signature(&self, contextual_type: TypeId) -> Option<Signature> {
+        match &self.type_table.get(contextual_type).kind {
+            TypeKind::ObjectType { call_signatures, .. } if call_signatures.len() == 1 => Some(call_signatures[0].clone()),
+            TypeKind::Union { types } => types.iter().find_map(|&t| self.get_contextual_call_signature(t)),
+            TypeKind::TypeParameter { constraint: Some(constraint), .. } => self.get_contextual_call_signature(*constraint),
+            _ => None,
+        }
+    }
+
+    /// The type of a function expression's parameter: its annotation, else the
+    /// type of the corresponding parameter of its contextual signature.
+    fn get_parameter_type(&mut self, param: &ParameterDeclaration<'_>, index: usize, contextual_signature: Option<&Signature>) -> TypeId {
+        if let Some(declared) = self.get_type_from_type_annotation(param.type_annotation) {
+            return declared;
+        }
+        contextual_signature
+            .filter(|_| param.dot_dot_dot_token.is_none())
+            .and_then(|sig| self.get_effective_parameters(sig).type_at(index))
+            .unwrap_or(self.type_table.any_type)
+    }
+
+    /// Infer a type predicate for a function whose body returns a single
+    /// condition narrowing one of its parameters: `x => x !== undefined`
+    /// guards `x is T` for the rest `T` of the parameter's type.
+    fn get_inferred_type_predicate(&mut self, body: &ArrowFunctionBody<'_>, parameters: &[ParameterDeclaration<'_>]) -> Option<TypePredicate> {
+        let mut expr = match body {
+            ArrowFunctionBody::Expression(expr) => *expr,
+            ArrowFunctionBody::Block(block) => match block.statements {
+                [Statement::ReturnStatement(ret)] => ret.expression?,
+                _ => return None,
+            },
+        };
+        while let Expression::Parenthesized(p) = expr {
+            expr = p.expression;
+        }
+        // Truthiness narrowing does not say the parameter is falsy when the
+        // function returns false, so it does not make a type guard.
+        if !matches!(expr, Expression::Binary(_) | Expression::Call(_)) {
+            return None;
+        }
+        let (name, narrowed, _) = self.extract_narrowing(expr)?;
+        let parameter_index = parameters.iter().position(|p| {
+            matches!(&p.name, BindingName::Identifier(id) if id.text_name == name)
+        })?;
+        let declared = self.get_declared_type(&name)?;
+        if narrowed == declared || self.type_table.get(declared).flags.contains(TypeFlags::ANY) {
+            return None;
+        }
+        Some(TypePredicate {
+            kind: TypePredicateKind::Identifier,
+            parameter_name: Some(name),
+            parameter_index: Some(parameter_index),
+            type_id: Some(narrowed),
+        })
+    }
+
+    /// The `T` of a `ThisType<T>` in a contextual type, looking through
+    /// intersections, unions and type parameter constraints.
+    fn get_this_type_from_contextual_type(&self, contextual_type: TypeId) -> Option<TypeId> {
+        if let Some(&this_type) = self.this_type_markers.get(&contextual_type) {
+            return Some(this_type);
+        }
+        match &self.type_table.get(contextual_type).kind {
+            TypeKind::Intersection { types } | TypeKind::Union { types } => {
+                types.iter().find_map(|&t| self.get_this_type_from_contextual_type(t))
+            }
+            TypeKind::TypeParameter { constraint: Some(constraint), .. } => self.get_this_type_from_contextual_type(*constraint),
+            _ => None,
+        }
+    }
+
+    /// The tuple type a contextual type asks for, looking through unions and
+    /// type parameter constraints.
+    fn get_contextual_tuple_type(&self, contextual_type: TypeId) -> Option<TypeId> {
+        match &self.type_table.get(contextual_type).kind {
+            TypeKind::Tuple { .. } => Some(contextual_type),
+            TypeKind::Union { types } => types.iter().find_map(|&t| self.get_contextual_tuple_type(t)),
+            TypeKind::TypeParameter { constraint: Some(constraint), .. } => self.get_contextual_tuple_type(*constraint),
+            _ => None,
+        }
+    }
+
+    /// Type an array literal as a tuple, each element contextually typed by
+    /// the corresponding element of `contextual_tuple`.
+    fn check_array_literal_as_tuple(&mut self, node: &ArrayLiteralExpression<'_>, contextual_tuple: TypeId) -> TypeId {
+        let (contextual_types, contextual_flags) = match &self.type_table.get(contextual_tuple).kind {
+            TypeKind::Tuple { element_types, element_flags } => (element_types.clone(), element_flags.clone()),
+            _ => (vec![], vec![]),
+        };
+        let mut element_types = Vec::with_capacity(node.elements.len());
+        let mut element_flags = Vec::with_capacity(node.elements.len());
+        for (i, elem) in node.elements.iter().enumerate() {
+            if let Expression::Spread(spread) = elem {
+                element_types.push(self.check_expression(spread.expression));
+                element_flags.push(ElementFlags::Variadic);
+                continue;
+            }
+            let contextual = contextual_types.get(i).copied().filter(|_| {
+                !matches!(contextual_flags.get(i), Some(ElementFlags::Rest | ElementFlags::Variadic))
+            });
+            element_types.push(match contextual {
+                Some(contextual_type) => self.check_expression_with_contextual_type(elem, contextual_type),
+                None => self.check_expression(elem),
+            });
+            element_flags.push(ElementFlags::Required);
+        }
+        self.create_normalized_tuple_type(element_types, element_flags, None)
+    }
+
+    fn check_array_literal(&mut self, node: &ArrayLiteralExpression<'_>) -> TypeId {
+        let mut element_types = Vec::new();
+        for elem in node.elements.iter() {
+            let element_type = match elem {
+                // `[...xs]` contributes the elements of `xs`, not `xs` itself.
+                Expression::Spread(spread) => {
+                    let spread_type = self.check_expression(spread.expression);
+                    self.check_iteration_types(spread_type, IterationUse::Spread).yield_type
+                }
+                _ => self.check_expression(elem),
+            };
+            element_types.push(element_type);
+        }
+
+        if element_types.is_empty() {
+            // Empty array: any[] — create Array<any> object type
+            return self.create_array_type(self.type_table.any_type);
+        }
+
+        // Infer array element type as union of all element types
+        let elem_type = if element_types.len() == 1 {
+            element_types[0]
+        } else {
+            self.create_union_type(element_types)
+        };
+
+        // Return T[] type as an object type with numeric index signature
+        self.create_array_type(elem_type)
+    }
+
+    fn check_object_literal(&mut self, node: &ObjectLiteralExpression<'_>) -> TypeId {
+        // Nested object literals have contextual types of their own.
+        let contextual_this_type = self.contextual_this_type.take();
+        let mut members: IndexMap<String, TypeId> = IndexMap::new();
+        let mut accessors: IndexMap<String, AccessorTypes> = IndexMap::new();
+        for prop in node.properties.iter() {
+            match prop {
+                ObjectLiteralElement::PropertyAssignment(p) => {
+                    let value_type = self.check_expression(p.initializer);
+                    let prop_name = self.property_name_text(&p.name);
+                    members.insert(prop_name, value_type);
+                }
+                ObjectLiteralElement::ShorthandPropertyAssignment(p) => {
+                    // Shorthand { x } is equivalent to { x: x }
+                    let name = p.name.text_name.clone();
+                    let value_type = if let Some(init) = p.object_assignment_initializer {
+                        self.check_expression(init)
+                    } else {
+                        // Look up the identifier's type
+                        self.check_identifier(&p.name)
+                    };
+                    members.insert(name, value_type);
+                }
+                ObjectLiteralElement::SpreadAssignment(p) => {
+                    self.check_expression(p.expression);
+                    // Spread merges in members from the spread object
+                }
+                ObjectLiteralElement::MethodDeclaration(m) => {
+                    // Build method type
+                    let (this_parameter, parameters) = split_this_parameter(m.parameters);
+                    let this_type = self.get_this_parameter_type(this_parameter);
+                    let method_params: Vec<SignatureParameter> = parameters.iter().map(|p| {
+                        if let Some(init) = p.initializer { self.check_expression(init); }
+                        let param_type = self.get_type_from_type_annotation(p.type_annotation);
+                        SignatureParameter {
+                            name: match &p.name {
+                                BindingName::Identifier(id) => id.text_name.clone(),
+                                _ => String::new(),
+                            },
+                            type_id: param_type.unwrap_or(self.type_table.any_type),
+                            optional: p.question_token.is_some(),
+                        }
+                    }).collect();
+
+                    let return_type = self.get_type_from_type_annotation(m.return_type)
+                        .unwrap_or(self.type_table.any_type);
+                    let type_predicate = self.get_type_predicate_of_return_type(m.return_type, parameters);
+
+                    if let Some(ref body) = m.body {
+                        let method_this = this_type.or(contextual_this_type).unwrap_or(self.type_table.any_type);
+                        let saved_this = self.enter_function_this_binding(Some(method_this));
+                        self.check_block_statements(body.statements);
+                        self.restore_this_binding(saved_this);
+                    }
+
+                    let sig = Signature {
+                        type_parameters: vec![],
+                        parameters: method_params,
+                        return_type,
+                        min_argument_count: parameters.iter()
+                            .filter(|p| p.question_token.is_none() && p.initializer.is_none())
+                            .count() as u32,
+                        has_rest_parameter: parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
+                        this_type,
+                        is_method: true,
+                        type_predicate,
+                    };
+                    let method_type = self.type_table.add_type(
+                        TypeFlags::OBJECT,
+                        TypeKind::ObjectType {
+                            object_flags: ObjectFlags::ANONYMOUS,
+                            members: IndexMap::new(),
+                            call_signatures: vec![sig],
+                            construct_signatures: vec![],
+                            index_infos: vec![],
+                        },
+                    );
+                    let method_name = self.property_name_text(&m.name);
+                    members.insert(method_name, method_type);
+                }
+                ObjectLiteralElement::GetAccessor(g) => {
+                    let prop_name = self.property_name_text(&g.name);
+                    let getter_type = self.get_type_from_type_annotation(g.return_type);
+                    let mut body_type = None;
+                    if let Some(ref body) = g.body {
+                        let saved_this = self.enter_function_this_binding(Some(contextual_this_type.unwrap_or(self.type_table.any_type)));
+                        self.check_class_member_body(g.type_parameters, g.parameters, body);
+                        if getter_type.is_none() {
+                            body_type = Some(self.get_return_type_of_accessor_body(body));
+                        }
+                        self.restore_this_binding(saved_this);
+                    }
+                    let entry = accessors.entry(prop_name.clone()).or_default();
+                    entry.has_getter = true;
+                    entry.getter_type = getter_type;
+                    entry.body_type = body_type;
+                    members.entry(prop_name).or_insert(self.type_table.any_type);
+                }
+                ObjectLiteralElement::SetAccessor(s) => {
+                    if s.return_type.is_some() {
+                        self.error(&messages::A_SET_ACCESSOR_CANNOT_HAVE_A_RETURN_TYPE_ANNOTATION, &[]);
+                    }
+                    let prop_name = self.property_name_text(&s.name);
+                    let setter_type = s.parameters.first()
+                        .and_then(|p| self.get_type_from_type_annotation(p.type_annotation));
+                    if let Some(ref body) = s.body {
+                        let saved_this = self.enter_function_this_binding(Some(contextual_this_type.unwrap_or(self.type_table.any_type)));
+                        self.check_class_member_body(s.type_parameters, s.parameters, body);
+                        self.restore_this_binding(saved_this);
+                    }
+                    let entry = accessors.entry(prop_name.clone()).or_default();
+                    entry.has_setter = true;
+                    entry.setter_type = setter_type;
+                    members.entry(prop_name).or_insert(self.type_table.any_type);
+                }
+            }
+        }
+
+        for (name, accessor) in &accessors {
+            members.insert(name.clone(), accessor.read_type().unwrap_or(self.type_table.any_type));
+        }
+        let object_type = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS | ObjectFlags::OBJECT_LITERAL | ObjectFlags::FRESH_LITERAL,
+                members,
+                call_signatures: vec![],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        );
+        for (name, accessor) in &accessors {
+            if let Some(write_type) = accessor.write_type() {
+                self.type_table.set_write_type(object_type, name, write_type);
+            }
+            if accessor.is_readonly() {
+                self.type_table.set_property_flags(object_type, name, PropertyFlags::READONLY);
+            }
+        }
+        object_type
+    }
+
+    fn check_class_expression(&mut self, node: &ClassExpression<'_>) {
+        for member in node.members.iter() {
+            match member {
+                ClassElement::MethodDeclaration(m) => {
+                    if let Some(ref body) = m.body {
+                        self.check_block_statements(body.statements);
+                    }
+                }
+                ClassElement::PropertyDeclaration(p) => {
+                    if let Some(init) = p.initializer { self.check_expression(init); }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // ========================================================================
+    // JSX
+    // ========================================================================
+
+    /// A type of the `JSX` namespace with its type parameters: that of
+    /// `<jsxImportSource>/jsx-runtime` under the automatic runtime, when the
+    /// module is declared, and otherwise `React.JSX` or the global `JSX`.
+    fn get_jsx_namespace_type(&self, name: &str) -> Option<(TypeId, Vec<TypeId>)> {
+        let qualified = format!("JSX.{name}");
+        if self.jsx.is_some_and(JsxEmit::is_automatic_runtime) {
+            let runtime = format!("{}/jsx-runtime", self.jsx_import_source.as_deref().unwrap_or("react"));
+            if let Some(module) = self.ambient_modules.get(&runtime) {
+                return module.types.get(&qualified).cloned();
+            }
+        }
+        ["React.", ""].iter().find_map(|namespace| {
+            let full_name = format!("{namespace}{qualified}");
+            let type_id = self.get_declared_type(&full_name)?;
+            Some((type_id, self.generic_type_parameters.get(&full_name).cloned().unwrap_or_default()))
+        })
+    }
+
+    /// The type of JSX expressions, `JSX.Element`, or `any` without one.
+    fn get_jsx_element_type(&self) -> TypeId {
+        self.get_jsx_namespace_type("Element").map_or(self.type_table.any_type, |(type_id, _)| type_id)
+    }
+
+    /// The name of the only property of `JSX.ElementAttributesProperty` or
+    /// `JSX.ElementChildrenAttribute`: `None` when the interface is not
+    /// declared or has more than one property, and `Some(None)` when it is
+    /// empty.
+    fn get_jsx_property_name(&mut self, interface_name: &str, error_range: TextRange) -> Option<Option<String>> {
+        let (type_id, _) = self.get_jsx_namespace_type(interface_name)?;
+        match self.resolve_properties_of_type(type_id).as_slice() {
+            [] => Some(None),
+            [(name, _)] => Some(Some(name.clone())),
+            _ => {
+                self.error_at(error_range, &messages::THE_GLOBAL_TYPE_JSX_0_MAY_NOT_HAVE_MORE_THAN_ONE_PROPERTY, &[interface_name]);
+                None
+            }
+        }
+    }
+
+    /// Report JSX when the `jsx` option is not set, and an element type that
+    /// is implicitly `any` under noImplicitAny.
+    fn check_jsx_preconditions(&mut self, range: TextRange) {
+        if self.jsx.is_none() {
+            self.error_at(range, &messages::CANNOT_USE_JSX_UNLESS_THE_JSX_FLAG_IS_PROVIDED, &[]);
+        }
+        if self.no_implicit_any && self.get_jsx_namespace_type("Element").is_none() {
+            self.error_at(range, &messages::JSX_ELEMENT_IMPLICITLY_HAS_TYPE_ANY_BECAUSE_THE_GLOBAL_TYPE_JSX_ELEMENT_DOES_NOT_EXIST, &[]);
+        }
+    }
+
+    /// Check an element against the props its tag accepts and return its
+    /// type. An intrinsic tag takes its property of `JSX.IntrinsicElements`.
+    /// A value takes the first parameter of its call signature or, for a
+    /// construct signature, the property of the instance that
+    /// `JSX.ElementAttributesProperty` names; overloads are tried in order and
+    /// type arguments not written are inferred from the attributes.
+    fn check_jsx_opening_like_element<'n>(
+        &mut self,
+        range: TextRange,
+        tag_name: &JsxTagName<'_>,
+        type_arguments: Option<NodeList<'_, TypeNode<'_>>>,
+        attributes: &'n JsxAttributes<'n>,
+        children: &'n [JsxChild<'n>],
+    ) -> TypeId {
+        self.check_jsx_preconditions(range);
+        let tag_range = tag_name.data().range;
+        if tag_name.is_intrinsic() {
+            let props = self.get_intrinsic_element_props(tag_name);
+            let attributes_type = self.check_jsx_attributes(attributes, children, props, tag_range);
+            if let Some(props) = props {
+                self.check_jsx_attributes_assignable(&attributes_type, props, tag_range);
+            }
+            return self.get_jsx_element_type();
+        }
+
+        let JsxTagName::Expression(tag_expression) = tag_name else {
+            unreachable!("namespaced tag names are intrinsic");
+        };
+        let tag_type = self.check_expression(tag_expression);
+        let type_args: Vec<TypeId> = type_arguments.unwrap_or(&[]).iter()
+            .map(|arg| self.get_type_from_type_node(arg))
+            .collect();
+        let construct_signatures = self.get_signatures_of_type(tag_type, SignatureKind::Construct);
+        let (signatures, is_construct) = if construct_signatures.is_empty() {
+            (self.get_signatures_of_type(tag_type, SignatureKind::Call), false)
+        } else {
+            (construct_signatures, true)
+        };
+        if self.type_table.get(tag_type).flags.contains(TypeFlags::ANY) || signatures.is_empty() {
+            if !self.type_table.get(tag_type).flags.contains(TypeFlags::ANY) {
+                let tag_text = tag_name.text();
+                self.error_at(tag_range, &messages::JSX_ELEMENT_TYPE_0_DOES_NOT_HAVE_ANY_CONSTRUCT_OR_CALL_SIGNATURES, &[&tag_text]);
+            }
+            self.check_jsx_attributes(attributes, children, None, tag_range);
+            return self.get_jsx_element_type();
+        }
+
+        let attributes_property = if is_construct {
+            self.get_jsx_property_name("ElementAttributesProperty", tag_range)
+        } else {
+            None
+        };
+        // The attributes are contextually typed by the props of the first
+        // signature, with type parameters not given read as `any`.
+        let first = &signatures[0];
+        let erased_args: Vec<TypeId> = (0..first.type_parameters.len())
+            .map(|i| type_args.get(i).copied().unwrap_or(self.type_table.any_type))
+            .collect();
+        let erased = self.substitute_signature(first, &first.type_parameters, &erased_args);
+        let contextual_props = self.get_jsx_props_of_signature(&erased, is_construct, &attributes_property);
+        let attributes_type = self.check_jsx_attributes(attributes, children, Some(contextual_props), tag_range);
+
+        let args = [CallArgument::Fixed(attributes_type.type_id)];
+        let mut resolved = None;
+        for sig in &signatures {
+            let candidate = self.instantiate_signature_for_call(sig, &args, &type_args);
+            let props = self.get_jsx_props_of_signature(&candidate, is_construct, &attributes_property);
+            let applies = attributes_type.is_any || self.is_type_assignable_to(attributes_type.type_id, props);
+            if applies || resolved.is_none() {
+                resolved = Some((candidate, props));
+            }
+            if applies {
+                break;
+            }
+        }
+        let (signature, props) = resolved.expect("a JSX component has signatures");
+        self.check_jsx_component_return_type(tag_name, &signature, is_construct);
+        self.check_jsx_attributes_assignable(&attributes_type, props, tag_range);
+        self.get_jsx_element_type()
+    }
+
+    /// The props of an intrinsic element, or `None`, after reporting, when
+    /// `JSX.IntrinsicElements` does not declare the tag.
+    fn get_intrinsic_element_props(&mut self, tag_name: &JsxTagName<'_>) -> Option<TypeId> {
+        let tag_range = tag_name.data().range;
+        let Some((intrinsic_elements, _)) = self.get_jsx_namespace_type("IntrinsicElements") else {
+            if self.no_implicit_any {
+                self.error_at(tag_range, &messages::JSX_ELEMENT_IMPLICITLY_HAS_TYPE_ANY_BECAUSE_NO_INTERFACE_JSX_0_EXISTS, &["IntrinsicElements"]);
+            }
+            return None;
+        };
+        let name = tag_name.text();
+        if let Some(props) = self.get_property_type_of_type(intrinsic_elements, &name) {
+            return Some(props);
+        }
+        let string_type = self.type_table.string_type;
+        if let Some(info) = self.get_applicable_index_info(intrinsic_elements, string_type) {
+            return Some(info.type_id);
+        }
+        let type_name = self.format_type(intrinsic_elements);
+        self.error_at(tag_range, &messages::PROPERTY_0_DOES_NOT_EXIST_ON_TYPE_1, &[&name, &type_name]);
+        None
+    }
+
+    /// The props a signature of a component accepts, as an intersection with
+    /// `JSX.IntrinsicAttributes`, and for a class component with
+    /// `JSX.IntrinsicClassAttributes<Instance>`. A class component without
+    /// `JSX.ElementAttributesProperty` takes its first constructor parameter.
+    fn get_jsx_props_of_signature(&mut self, sig: &Signature, is_construct: bool, attributes_property: &Option<Option<String>>) -> TypeId {
+        let first_parameter = sig.parameters.first().map(|p| p.type_id);
+        let props = match (is_construct, attributes_property) {
+            (true, Some(None)) => Some(sig.return_type),
+            (true, Some(Some(name))) => Some(self.get_property_type_of_type(sig.return_type, name).unwrap_or_else(|| self.create_empty_object_type())),
+            _ => first_parameter,
+        };
+        let props = props.unwrap_or_else(|| self.create_empty_object_type());
+        let mut types = Vec::new();
+        if let Some((intrinsic_attributes, _)) = self.get_jsx_namespace_type("IntrinsicAttributes") {
+            types.push(intrinsic_attributes);
+        }
+        if is_construct {
+            if let Some((class_attributes, type_params)) = self.get_jsx_namespace_type("IntrinsicClassAttributes") {
+                types.push(match type_params.as_slice() {
+                    [] => class_attributes,
+                    _ => self.instantiate_declared_type(class_attributes, &type_params, &[sig.return_type]),
+                });
+            }
+        }
+        // Empty interfaces add nothing, and would exempt the props from the
+        // check for attributes they do not declare.
+        types.retain(|&t| self.is_excess_property_check_target(t));
+        types.push(props);
+        self.create_intersection_type(types)
+    }
+
+    /// Report a component that does not return a `JSX.Element` (or `null`),
+    /// or whose instances are not a `JSX.ElementClass`.
+    fn check_jsx_component_return_type(&mut self, tag_name: &JsxTagName<'_>, sig: &Signature, is_construct: bool) {
+        let (expected, message) = if is_construct {
+            let Some((element_class, _)) = self.get_jsx_namespace_type("ElementClass") else { return };
+            (element_class, &messages::ITS_INSTANCE_TYPE_0_IS_NOT_A_VALID_JSX_ELEMENT)
+        } else {
+            let Some((element, _)) = self.get_jsx_namespace_type("Element") else { return };
+            (self.create_union_type(vec![element, self.type_table.null_type]), &messages::ITS_RETURN_TYPE_0_IS_NOT_A_VALID_JSX_ELEMENT)
+        };
+        if self.is_type_assignable_to(sig.return_type, expected) {
+            return;
+        }
+        let tag_text = tag_name.text();
+        let return_type = self.format_type(sig.return_type);
+        let next = self.elaborate_assignability_error(sig.return_type, expected, 0);
+        let saved_range = self.current_node_range.replace(tag_name.data().range);
+        let diagnostic = self.create_diagnostic(&messages::_0_CANNOT_BE_USED_AS_A_JSX_COMPONENT, &[&tag_text])
+            .with_message_chain(vec![DiagnosticMessageChain::new(message, &[&return_type]).with_next(next)]);
+        self.diagnostics.add(diagnostic);
+        self.current_node_range = saved_range;
+    }
+
+    /// Build the props object of an element: attribute values are checked
+    /// against the types of the props they set, string values and bare
+    /// attributes are literals, spreads add the properties of their object,
+    /// and children are passed as the property `JSX.ElementChildrenAttribute`
+    /// names: a single child as its type, several as an array.
+    fn check_jsx_attributes<'n>(
+        &mut self,
+        attributes: &'n JsxAttributes<'n>,
+        children: &'n [JsxChild<'n>],
+        contextual_props: Option<TypeId>,
+        tag_range: TextRange,
+    ) -> JsxAttributesType<'n> {
+        let mut members: IndexMap<String, TypeId> = IndexMap::new();
+        let mut optional: FxHashSet<String> = FxHashSet::default();
+        let mut sites: Vec<JsxAttributeSite<'n>> = Vec::new();
+        let mut is_any = false;
+        for property in attributes.properties.iter() {
+            match property {
+                JsxAttributeLike::Attribute(attr) => {
+                    let name = attr.name.text();
+                    let range = attr.name.data().range;
+                    let contextual_type = contextual_props.and_then(|props| self.get_property_type_of_type(props, &name));
+                    let mut value = None;
+                    let value_type = match &attr.initializer {
+                        None => self.type_table.true_type,
+                        Some(JsxAttributeValue::StringLiteral(literal)) => {
+                            let literal_type = self.create_string_literal_type(literal.text_name.clone());
+                            self.get_widened_jsx_attribute_type(literal_type, contextual_type)
+                        }
+                        Some(JsxAttributeValue::Expression(expression)) => match expression.expression {
+                            Some(expr) => {
+                                value = Some(expr);
+                                let expr_type = match contextual_type {
+                                    Some(contextual_type) => self.check_expression_with_contextual_type(expr, contextual_type),
+                                    None => self.check_expression(expr),
+                                };
+                                self.get_widened_jsx_attribute_type(expr_type, contextual_type)
+                            }
+                            None => {
+                                self.error_at(expression.data.range, &messages::JSX_ATTRIBUTES_MUST_ONLY_BE_ASSIGNED_A_NON_EMPTY_EXPRESSION, &[]);
+                                self.type_table.any_type
+                            }
+                        },
+                        Some(JsxAttributeValue::Element(element)) => {
+                            value = Some(*element);
+                            self.check_expression(element)
+                        }
+                    };
+                    if sites.iter().any(|site| site.name == name) {
+                        self.error_at(range, &messages::JSX_ELEMENTS_CANNOT_HAVE_MULTIPLE_ATTRIBUTES_WITH_THE_SAME_NAME, &[]);
+                        sites.retain(|site| site.name != name);
+                    }
+                    optional.remove(&name);
+                    members.insert(name.clone(), value_type);
+                    sites.push(JsxAttributeSite { name, range, value });
+                }
+                JsxAttributeLike::SpreadAttribute(spread) => {
+                    let spread_type = self.check_expression(spread.expression);
+                    if self.type_table.get(spread_type).flags.contains(TypeFlags::ANY) {
+                        is_any = true;
+                        continue;
+                    }
+                    for (name, property_type) in self.resolve_properties_of_type(spread_type) {
+                        let is_optional = self.is_optional_property_of_type(spread_type, &name);
+                        // A required property of the spread overwrites an
+                        // attribute written before it.
+                        if !is_optional {
+                            if let Some(site) = sites.iter().find(|site| site.name == name) {
+                                self.error_at(site.range, &messages::_0_IS_SPECIFIED_MORE_THAN_ONCE_SO_THIS_USAGE_WILL_BE_OVERWRITTEN, &[&name]);
+                            }
+                            sites.retain(|site| site.name != name);
+                            optional.remove(&name);
+                        } else if !members.contains_key(&name) {
+                            optional.insert(name.clone());
+                        }
+                        members.insert(name, property_type);
+                    }
+                }
+            }
+        }
+
+        let children: Vec<&JsxChild<'_>> = children.iter()
+            .filter(|child| match child {
+                JsxChild::Text(text) => !text.contains_only_trivia_white_spaces,
+                JsxChild::Expression(expression) => expression.expression.is_some(),
+                JsxChild::Element(_) => true,
+            })
+            .collect();
+        let children_property = match children.is_empty() {
+            true => None,
+            false => self.get_jsx_property_name("ElementChildrenAttribute", tag_range).flatten(),
+        };
+        let contextual_children = match (&children_property, children.as_slice()) {
+            (Some(name), [_]) => contextual_props.and_then(|props| self.get_property_type_of_type(props, name)),
+            _ => None,
+        };
+        let child_types = self.check_jsx_children(children.iter().copied(), contextual_children);
+        if let Some(name) = children_property {
+            if let Some(site) = sites.iter().find(|site| site.name == name) {
+                self.error_at(site.range, &messages::_0_ARE_SPECIFIED_TWICE_THE_ATTRIBUTE_NAMED_0_WILL_BE_OVERWRITTEN, &[&name]);
+            }
+            sites.retain(|site| site.name != name);
+            let children_type = match child_types.as_slice() {
+                [(child_type, false)] => *child_type,
+                _ => {
+                    let element_types = child_types.iter().map(|&(child_type, _)| child_type).collect();
+                    let element_type = self.create_union_type(element_types);
+                    self.create_array_type(element_type)
+                }
+            };
+            optional.remove(&name);
+            members.insert(name.clone(), children_type);
+            sites.push(JsxAttributeSite { name, range: tag_range, value: None });
+        }
+
+        let type_id = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS | ObjectFlags::OBJECT_LITERAL,
+                members,
+                call_signatures: vec![],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        );
+        for name in &optional {
+            self.type_table.set_property_flags(type_id, name, PropertyFlags::OPTIONAL);
+        }
+        JsxAttributesType { type_id, sites, is_any }
+    }
+
+    /// Check the children of an element or fragment and return their types,
+    /// each with whether it is spread (`{...items}`) and so stands for the
+    /// elements of an array.
+    fn check_jsx_children<'c, 'n: 'c>(
+        &mut self,
+        children: impl IntoIterator<Item = &'c JsxChild<'n>>,
+        contextual_type: Option<TypeId>,
+    ) -> Vec<(TypeId, bool)> {
+        let mut types = Vec::new();
+        for child in children {
+            match child {
+                JsxChild::Text(text) => {
+                    if !text.contains_only_trivia_white_spaces {
+                        types.push((self.type_table.string_type, false));
+                    }
+                }
+                JsxChild::Expression(expression) => {
+                    let Some(expr) = expression.expression else { continue };
+                    if expression.dot_dot_dot_token.is_some() {
+                        let spread_type = self.check_expression(expr);
+                        types.push((self.get_element_type_of_iterable(spread_type), true));
+                        continue;
+                    }
+                    let expr_type = match contextual_type {
+                        Some(contextual_type) => self.check_expression_with_contextual_type(expr, contextual_type),
+                        None => self.check_expression(expr),
+                    };
+                    types.push((expr_type, false));
+                }
+                JsxChild::Element(element) => types.push((self.check_expression(element), false)),
+            }
+        }
+        types
+    }
+
+    /// The type of an attribute value as a property of the props object:
+    /// literals widen unless the prop could itself be a literal.
+    fn get_widened_jsx_attribute_type(&mut self, type_id: TypeId, contextual_type: Option<TypeId>) -> TypeId {
+        let regular = self.get_regular_type_of_literal_type(type_id);
+        if contextual_type.is_some_and(|contextual_type| self.could_contain_literal_type(contextual_type)) {
+            regular
+        } else {
+            self.get_base_type_of_literal_type(regular)
+        }
+    }
+
+    /// Report props that do not fit: an attribute the props do not declare
+    /// (hyphenated names like `data-id` are exempt), an attribute whose value
+    /// does not fit its prop, each at the attribute's name, and otherwise the
+    /// whole props object, with the required props it lacks, at the tag name.
+    fn check_jsx_attributes_assignable(&mut self, attributes: &JsxAttributesType<'_>, props: TypeId, tag_range: TextRange) {
+        if attributes.is_any || self.type_table.get(props).flags.contains(TypeFlags::ANY) {
+            return;
+        }
+        let source = attributes.type_id;
+        if self.is_excess_property_check_target(props) {
+            let excess = attributes.sites.iter()
+                .find(|site| !site.name.contains('-') && !self.is_known_property(props, &site.name));
+            if let Some(site) = excess {
+                let src = self.format_type(source);
+                let tgt = self.format_type(props);
+                let saved_range = self.current_node_range.replace(site.range);
+                let diagnostic = self.create_diagnostic(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&src, &tgt])
+                    .with_message_chain(vec![DiagnosticMessageChain::new(&messages::PROPERTY_0_DOES_NOT_EXIST_ON_TYPE_1, &[&site.name, &tgt])]);
+                self.diagnostics.add(diagnostic);
+                self.current_node_range = saved_range;
+                return;
+            }
+        }
+        if self.is_type_assignable_to(source, props) {
+            return;
+        }
+        let mut reported = false;
+        for site in &attributes.sites {
+            let (Some(source_prop), Some(target_prop)) =
+                (self.get_member_type(source, &site.name), self.get_property_type_of_type(props, &site.name))
+            else {
+                continue;
+            };
+            let target_prop = if self.is_optional_property_of_type(props, &site.name) {
+                self.create_union_type(vec![target_prop, self.type_table.undefined_type])
+            } else {
+                target_prop
+            };
+            if self.is_type_assignable_to(source_prop, target_prop) {
+                continue;
+            }
+            match site.value {
+                Some(value) => {
+                    self.check_type_assignable_to_and_elaborate(source_prop, target_prop, value, site.range);
+                }
+                None => {
+                    let saved_range = self.current_node_range.replace(site.range);
+                    self.check_type_assignable_to(source_prop, target_prop);
+                    self.current_node_range = saved_range;
+                }
+            }
+            reported = true;
+        }
+        if !reported {
+            let saved_range = self.current_node_range.replace(tag_range);
+            self.check_type_assignable_to(source, props);
+            self.current_node_range = saved_range;
+        }
+    }
+
+    /// The type of a property of an object, union or intersection type.
+    fn get_property_type_of_type(&mut self, type_id: TypeId, name: &str) -> Option<TypeId> {
+        self.resolve_properties_of_type(type_id).into_iter()
+            .find_map(|(property_name, property_type)| (property_name == name).then_some(property_type))
+    }
+
+    /// Whether a property is optional: in an intersection, in every
+    /// constituent that declares it.
+    fn is_optional_property_of_type(&self, type_id: TypeId, name: &str) -> bool {
+        match &self.type_table.get(type_id).kind {
+            TypeKind::ObjectType { .. } => self.type_table.get_property_flags(type_id, name).contains(PropertyFlags::OPTIONAL),
+            TypeKind::Intersection { types } => types.iter()
+                .filter(|&&t| self.is_known_property(t, name))
+                .all(|&t| self.is_optional_property_of_type(t, name)),
+            TypeKind::Union { types } => types.iter().any(|&t| self.is_optional_property_of_type(t, name)),
+            _ => false,
+        }
+    }
+
+    fn create_empty_object_type(&mut self) -> TypeId {
+        self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS,
+                members: IndexMap::new(),
+                call_signatures: vec![],
+                construct_signatures: vec![],
+                index_infos: vec![],
+            },
+        )
+    }
+
+    // ========================================================================
+    // Type resolution
+    // ========================================================================
+
+    fn get_type_from_type_annotation(&mut self, annotation: Option<&TypeNode<'_>>) -> Option<TypeId> {
+        annotation.map(|ty| self.get_type_from_type_node(ty))
+    }
+
+    fn get_type_from_type_node(&mut self, type_node: &TypeNode<'_>) -> TypeId {
+        match type_node {
+            TypeNode::KeywordType(n) => self.get_type_from_keyword(n.data.kind),
+            TypeNode::TypeReference(n) => {
+                // Resolve the type reference through the binder/declared types
+                let ref_name = match &n.type_name {
+                    EntityName::Identifier(id) => self.resolve_type_reference_name(&id.text_name),
+                    EntityName::QualifiedName(q) => {
+                        // `E.A` is the type of an enum member
+                        if let Some(member_type) = self.get_enum_member_type_from_qualified_name(q) {
+                            return member_type;
+                        }
+                        self.resolve_type_reference_name(&entity_name_text(&n.type_name))
+                    }
+                };
+
+                // Check well-known type names
+                match ref_name.as_str() {
+                    "Array" => {
+                        let elem_type = if let Some(type_args) = n.type_arguments {
+                            if !type_args.is_empty() {
+                                self.get_type_from_type_node(&type_args[0])
+                            } else {
+                                self.type_table.any_type
+                            }
+                        } else {
+                            self.type_table.any_type
+                        };
+                        return self.create_array_type(elem_type);
+                    }
+                    "ReadonlyArray" => {
+                        let elem_type = if let Some(type_args) = n.type_arguments {
+                            if !type_args.is_empty() {
+                                self.get_type_from_type_node(&type_args[0])
+                            } else {
+                                self.type_table.any_type
+                            }
+                        } else {
+                            self.type_table.any_type
+                        };
+                        return self.create_array_type_with_readonly(elem_type, true);
+                    }
+                    // `Promise` also names the Promise constructor value
+                    "Promise" => {
+                        let type_args: Vec<TypeId> = n.type_arguments.unwrap_or(&[]).iter()
+                            .map(|arg| self.get_type_from_type_node(arg))
+                            .collect();
+                        return self.create_global_type_reference("Promise", &type_args);
+                    }
+                    "Awaited" => {
+                        let Some(arg) = n.type_arguments.and_then(|args| args.first()) else {
+                            return self.type_table.any_type;
+                        };
+                        let inner = self.get_type_from_type_node(arg);
+                        return self.get_awaited_type(inner);
+                    }
+                    "Record" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if type_args.len() >= 2 {
+                                let keys_type = self.get_type_from_type_node(&type_args[0]);
+                                let value_type = self.get_type_from_type_node(&type_args[1]);
+                                return self.create_record_type(keys_type, value_type);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "Partial" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if !type_args.is_empty() {
+                                let inner = self.get_type_from_type_node(&type_args[0]);
+                                return self.create_partial_type(inner);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "Required" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if !type_args.is_empty() {
+                                let inner = self.get_type_from_type_node(&type_args[0]);
+                                return self.create_required_type(inner);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "Readonly" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if !type_args.is_empty() {
+                                let inner = self.get_type_from_type_node(&type_args[0]);
+                                return self.create_readonly_type(inner);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "Pick" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if type_args.len() >= 2 {
+                                let base = self.get_type_from_type_node(&type_args[0]);
+                                let keys_type = self.get_type_from_type_node(&type_args[1]);
+                                return self.create_pick_type(base, keys_type);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "Omit" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if type_args.len() >= 2 {
+                                let base = self.get_type_from_type_node(&type_args[0]);
+                                let keys_type = self.get_type_from_type_node(&type_args[1]);
+                                return self.create_omit_type(base, keys_type);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "ReturnType" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if !type_args.is_empty() {
+                                let inner = self.get_type_from_type_node(&type_args[0]);
+                                return self.get_return_type_of(inner);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "Parameters" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if !type_args.is_empty() {
+                                let inner = self.get_type_from_type_node(&type_args[0]);
+                                return self.get_parameters_type_of(inner);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "NonNullable" => {
+                        if let Some(type_args) = n.type_arguments {
+                            if !type_args.is_empty() {
+                                let inner = self.get_type_from_type_node(&type_args[0]);
+                                return self.get_non_nullable_type(inner);
+                            }
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "Uppercase" | "Lowercase" | "Capitalize" | "Uncapitalize" => {
+                        let mapping = StringMappingKind::from_name(&ref_name);
+                        if let (Some(mapping), Some(arg)) = (mapping, n.type_arguments.and_then(|args| args.first())) {
+                            let target = self.get_type_from_type_node(arg);
+                            return self.get_string_mapping_type(mapping, target);
+                        }
+                        return self.type_table.any_type;
+                    }
+                    "ThisType" => {
+                        // A marker for the type of `this` in object literal
+                        // methods, and otherwise an empty object type.
+                        let Some(arg) = n.type_arguments.and_then(|args| args.first()) else {
+                            return self.type_table.any_type;
+                        };
+                        let this_type = self.get_type_from_type_node(arg);
+                        let marker = self.type_table.add_type(
+                            TypeFlags::OBJECT,
+                            TypeKind::ObjectType {
+                                object_flags: ObjectFlags::ANONYMOUS,
+                                members: IndexMap::new(),
+                                call_signatures: vec![],
+                                construct_signatures: vec![],
+                                index_infos: vec![],
+                            },
+                        );
+                        self.this_type_markers.insert(marker, this_type);
+                        return marker;
+                    }
+                    "Exclude" | "Extract" | "InstanceType" | "ConstructorParameters"
+                    | "ThisParameterType" | "OmitThisParameter" => {
+                        return self.type_table.any_type;
+                    }
+                    _ => {}
+                }
+
+                // A class name in a type position denotes its instance type
+                if let Some(info) = self.classes.get(&ref_name) {
+                    return info.instance_type;
+                }
+
+                // An enum name denotes the union of its members
+                if let Some(&enum_type) = self.enum_types.get(&ref_name) {
+                    return enum_type;
+                }
+
+                // Look up in declared_types
+                if let Some(type_id) = self.get_declared_type(&ref_name) {
+                    if self.type_aliases.get(&ref_name).is_some_and(|alias| alias.declared_type == type_id) {
+                        let type_args: Vec<TypeId> = n.type_arguments.unwrap_or(&[]).iter()
+                            .map(|arg| self.get_type_from_type_node(arg))
+                            .collect();
+                        return self.get_type_from_type_alias_reference(&ref_name, &type_args);
+                    }
+                    if let Some(type_params) = self.generic_type_parameters.get(&ref_name).cloned() {
+                        let type_args: Vec<TypeId> = n.type_arguments.unwrap_or(&[]).iter()
+                            .map(|arg| self.get_type_from_type_node(arg))
+                            .collect();
+                        return self.instantiate_declared_type(type_id, &type_params, &type_args);
+                    }
+                    // If there are type arguments, create a TypeReference for instantiation
+                    if let Some(type_args) = n.type_arguments {
+                        if !type_args.is_empty() {
+                            let resolved_args: Vec<TypeId> = type_args.iter()
+                                .map(|arg| self.get_type_from_type_node(arg))
+                                .collect();
+                            return self.instantiate_generic_type(type_id, &resolved_args);
+                        }
+                    }
+                    return type_id;
+                }
+
+                // Generic interfaces of the standard library
+                if let Some((&name, _)) = self.global_generic_types.get_key_value(ref_name.as_str()) {
+                    let type_args: Vec<TypeId> = n.type_arguments.unwrap_or(&[]).iter()
+                        .map(|arg| self.get_type_from_type_node(arg))
+                        .collect();
+                    return self.create_global_type_reference(name, &type_args);
+                }
+
+                // Look up in binder
+                if let Some(symbol_id) = self.binder.resolve_name(&ref_name) {
+                    if let Some(symbol) = self.binder.get_symbol(symbol_id) {
+                        if symbol.flags.contains(SymbolFlags::INTERFACE) || symbol.flags.contains(SymbolFlags::TYPE_ALIAS) {
+                            return self.type_table.any_type; // Would need type resolution
+                        }
+                        if symbol.flags.contains(SymbolFlags::CLASS) {
+                            return self.type_table.any_type; // Would need instance type
+                        }
+                        if symbol.flags.contains(SymbolFlags::REGULAR_ENUM) {
+                            return self.type_table.number_type;
+                        }
+                    }
+                }
+
+                self.type_table.any_type
+            }
+            TypeNode::ArrayType(n) => {
+                let elem_type = self.get_type_from_type_node(n.element_type);
+                self.create_array_type(elem_type)
+            }
+            TypeNode::TupleType(n) => {
+                let mut element_types = Vec::with_capacity(n.elements.len());
+                let mut element_flags = Vec::with_capacity(n.elements.len());
+                let mut labels = Vec::with_capacity(n.elements.len());
+                for element in n.elements.iter() {
+                    // Every `...` element starts out variadic; normalization turns
+                    // array spreads into rest elements and flattens tuple spreads.
+                    let (type_node, flag, label) = match element {
+                        TypeNode::NamedTupleMember(m) => {
+                            let flag = if m.dot_dot_dot_token.is_some() {
+                                ElementFlags::Variadic
+                            } else if m.question_token.is_some() {
+                                ElementFlags::Optional
+                            } else {
+                                ElementFlags::Required
+                            };
+                            (m.type_node, flag, Some(m.name.text_name.clone()))
+                        }
+                        TypeNode::OptionalType(o) => (o.type_node, ElementFlags::Optional, None),
+                        TypeNode::RestType(r) => (r.type_node, ElementFlags::Variadic, None),
+                        _ => (element, ElementFlags::Required, None),
+                    };
+                    element_types.push(self.get_type_from_type_node(type_node));
+                    element_flags.push(flag);
+                    labels.push(label);
+                }
+                // Labels are only kept when every element has one.
+                let labels = labels.into_iter().collect::<Option<Vec<String>>>();
+                self.create_normalized_tuple_type(element_types, element_flags, labels)
+            }
+            TypeNode::UnionType(n) => {
+                let types: Vec<TypeId> = n.types.iter()
+                    .map(|t| self.get_type_from_type_node(t))
+                    .collect();
+                self.create_union_type(types)
+            }
+            TypeNode::IntersectionType(n) => {
+                let types: Vec<TypeId> = n.types.iter()
+                    .map(|t| self.get_type_from_type_node(t))
+                    .collect();
+                self.create_intersection_type(types)
+            }
+            TypeNode::FunctionType(n) => {
+                let (type_params, saved) = self.push_type_parameters(n.type_parameters, &[]);
+                let (this_parameter, parameters) = split_this_parameter(n.parameters);
+                let this_type = self.get_this_parameter_type(this_parameter);
+                let params: Vec<SignatureParameter> = parameters.iter().map(|p| {
+                    let param_type = self.get_type_from_type_annotation(p.type_annotation);
+                    SignatureParameter {
+                        name: match &p.name {
+                            BindingName::Identifier(id) => id.text_name.clone(),
+                            _ => String::new(),
+                        },
+                        type_id: param_type.unwrap_or(self.type_table.any_type),
+                        optional: p.question_token.is_some(),
+                    }
+                }).collect();
+                let type_predicate = self.get_type_predicate_of_return_type(n.return_type, parameters);
+                let return_type = n.return_type
+                    .map(|r| self.get_type_from_type_node(r))
+                    .unwrap_or(self.type_table.any_type);
+                self.pop_type_parameters(saved);
+
+                let sig = Signature {
+                    type_parameters: type_params,
+                    parameters: params,
+                    return_type,
+                    min_argument_count: parameters.iter()
+                        .filter(|p| p.question_token.is_none() && p.initializer.is_none() && p.dot_dot_dot_token.is_none())
+                        .count() as u32,
+                    has_rest_parameter: parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
+                    this_type,
+                    is_method: false,
+                    type_predicate,
+                };
+                self.type_table.add_type(
+                    TypeFlags::OBJECT,
+                    TypeKind::ObjectType {
+                        object_flags: ObjectFlags::ANONYMOUS,
+                        members: IndexMap::new(),
+                        call_signatures: vec![sig],
+                        construct_signatures: vec![],
+                        index_infos: vec![],
+                    },
+                )
+            }
+            TypeNode::ConditionalType(n) => {
+                let check = self.get_type_from_type_node(n.check_type);
+                let extends = self.get_type_from_type_node(n.extends_type);
+                let true_type = self.get_type_from_type_node(n.true_type);
+                let false_type = self.get_type_from_type_node(n.false_type);
+
+                self.get_conditional_type(check, extends, true_type, false_type)
+            }
+            TypeNode::IndexedAccessType(n) => {
+                let obj = self.get_type_from_type_node(n.object_type);
+                let idx = self.get_type_from_type_node(n.index_type);
+                if self.is_generic_type(obj) || self.is_generic_type(idx) {
+                    return self.get_indexed_access_type(obj, idx);
+                }
+                match self.try_resolve_indexed_access(obj, idx) {
+                    Some(result) => result,
+                    None => {
+                        self.report_invalid_indexed_access(obj, idx);
+                        self.type_table.any_type
+                    }
+                }
+            }
+            TypeNode::TypeOperator(n) => {
+                let operand = self.get_type_from_type_node(n.type_node);
+                match n.operator {
+                    SyntaxKind::KeyOfKeyword => self.get_index_type(operand),
+                    SyntaxKind::ReadonlyKeyword => match self.type_table.get(operand).kind.clone() {
+                        TypeKind::ObjectType { index_infos, .. } if self.get_array_readonly_flag(operand).is_some() => {
+                            self.create_array_type_with_readonly(index_infos[0].type_id, true)
+                        }
+                        kind @ TypeKind::Tuple { .. } => {
+                            let flags = self.type_table.get(operand).flags;
+                            let tuple_type = self.type_table.add_type(flags, kind);
+                            if let Some(labels) = self.type_table.get_tuple_labels(operand) {
+                                let labels = labels.to_vec();
+                                self.type_table.set_tuple_labels(tuple_type, labels);
+                            }
+                            self.type_table.set_readonly_tuple(tuple_type);
+                            tuple_type
+                        }
+                        _ => operand,
+                    },
+                    SyntaxKind::UniqueKeyword => self.type_table.symbol_type,
+                    _ => operand,
+                }
+            }
+            TypeNode::TypeLiteral(n) => {
+                let mut members: IndexMap<String, TypeId> = IndexMap::new();
+                let mut member_flags: Vec<(String, PropertyFlags)> = Vec::new();
+                let mut index_infos = Vec::new();
+                let mut call_signatures = Vec::new();
+                let mut construct_signatures = Vec::new();
+                for member in n.members.iter() {
+                    match member {
+                        TypeElement::PropertySignature(p) => {
+                            let prop_name = self.get_property_name_text(&p.name);
+                            let prop_type = self.get_type_from_type_annotation(p.type_annotation)
+                                .unwrap_or(self.type_table.any_type);
+                            let mut flags = PropertyFlags::empty();
+                            flags.set(PropertyFlags::READONLY, p.data.modifier_flags.contains(ModifierFlags::READONLY));
+                            let prop_type = if p.question_token.is_some() {
+                                flags.insert(PropertyFlags::OPTIONAL);
+                                self.add_optionality(prop_type)
+                            } else {
+                                prop_type
+                            };
+                            member_flags.push((prop_name.clone(), flags));
+                            members.insert(prop_name, prop_type);
+                        }
+                        TypeElement::IndexSignature(idx) => {
+                            let infos = self.get_index_infos_of_index_signature(idx);
+                            index_infos.extend(infos);
+                        }
+                        TypeElement::MethodSignature(method) => {
+                            let method_name = self.get_property_name_text(&method.name);
+                            let method_type = self.build_method_type(
+                                members.get(&method_name).copied(),
+                                method.type_parameters,
+                                method.parameters,
+                                method.return_type,
+                            );
+                            members.insert(method_name, method_type);
+                        }
+                        TypeElement::CallSignature(call) => {
+                            call_signatures.push(self.build_signature(call.type_parameters, call.parameters, call.return_type));
+                        }
+                        TypeElement::ConstructSignature(ctor) => {
+                            construct_signatures.push(self.build_signature(ctor.type_parameters, ctor.parameters, ctor.return_type));
+                        }
+                    }
+                }
+                let literal_type = self.type_table.add_type(
+                    TypeFlags::OBJECT,
+                    TypeKind::ObjectType {
+                        object_flags: ObjectFlags::ANONYMOUS,
+                        members, call_signatures, construct_signatures,
+                        index_infos,
+                    },
+                );
+                for (member_name, flags) in member_flags {
+                    self.type_table.set_property_flags(literal_type, &member_name, flags);
+                }
+                self.check_index_constraints(literal_type);
+                literal_type
+            }
+            TypeNode::ParenthesizedType(n) => self.get_type_from_type_node(n.type_node),
+            TypeNode::LiteralType(n) => {
+                match n.literal {
+                    Expression::TrueKeyword(_) => self.type_table.true_type,
+                    Expression::FalseKeyword(_) => self.type_table.false_type,
+                    Expression::NullKeyword(_) => self.type_table.null_type,
+                    Expression::StringLiteral(lit) => self.create_string_literal_type(lit.text_name.clone()),
+                    Expression::NumericLiteral(lit) => {
+                        self.create_number_literal_type(parse_numeric_literal_text(&lit.text_name))
+                    }
+                    Expression::PrefixUnary(unary) if unary.operator == SyntaxKind::MinusToken => {
+                        match unary.operand {
+                            Expression::NumericLiteral(lit) => {
+                                self.create_number_literal_type(-parse_numeric_literal_text(&lit.text_name))
+                            }
+                            _ => self.type_table.any_type,
+                        }
+                    }
+                    _ => self.type_table.any_type,
+                }
+            }
+            TypeNode::ThisType(_) => match self.current_this_type {
+                Some(this_type) => this_type,
+                None => {
+                    self.error(&messages::A_THIS_TYPE_IS_AVAILABLE_ONLY_IN_A_NON_STATIC_MEMBER_OF_A_CLASS_OR_INTERFACE, &[]);
+                    self.type_table.any_type
+                }
+            },
+            TypeNode::TypeQuery(n) => {
+                // typeof expr — resolve the expression name to its declared type
+                let query_type = self.get_type_of_entity_name(&n.expr_name);
+                match n.type_arguments {
+                    Some(type_arguments) => {
+                        let type_args: Vec<TypeId> = type_arguments.iter()
+                            .map(|arg| self.get_type_from_type_node(arg))
+                            .collect();
+                        self.get_instantiation_expression_type(query_type, &type_args)
+                    }
+                    None => query_type,
+                }
+            }
+            TypeNode::InferType(_) => self.type_table.any_type,
+            // A type guard returns a boolean; an assertion returns nothing.
+            TypeNode::TypePredicate(n) if n.asserts_modifier.is_some() => self.type_table.void_type,
+            TypeNode::TypePredicate(_) => self.type_table.boolean_type,
+            TypeNode::MappedType(m) => self.evaluate_mapped_type(m),
+            TypeNode::TemplateLiteralType(t) => self.evaluate_template_literal_type(t),
+            _ => self.type_table.any_type,
+        }
+    }
+
+    /// The type of the value `a.b.c` names in a `typeof` query.
+    fn get_type_of_entity_name(&mut self, name: &EntityName<'_>) -> TypeId {
+        match name {
+            EntityName::Identifier(id) => self.get_declared_type(&id.text_name).unwrap_or(self.type_table.any_type),
+            EntityName::QualifiedName(q) => {
+                let left_type = self.get_type_of_entity_name(&q.left);
+                let property = &q.right.text_name;
+                let member = match &self.type_table.get(left_type).kind {
+                    TypeKind::ObjectType { members, .. } => members.get(property).copied(),
+                    _ => return self.type_table.any_type,
+                };
+                member.unwrap_or_else(|| {
+                    let left_text = self.format_type(left_type);
+                    self.error(&messages::PROPERTY_0_DOES_NOT_EXIST_ON_TYPE_1, &[property, &left_text]);
+                    self.type_table.any_type
+                })
+            }
+        }
+    }
+
+    /// The type of an instantiation expression like `typeof f<string>`: the
+    /// signatures that accept the type arguments, instantiated with them.
+    fn get_instantiation_expression_type(&mut self, expr_type: TypeId, type_args: &[TypeId]) -> TypeId {
+        let TypeKind::ObjectType { object_flags, members, call_signatures, construct_signatures, index_infos } =
+            self.type_table.get(expr_type).kind.clone()
+        else {
+            return expr_type;
+        };
+        let instantiate = |checker: &mut Self, signatures: &[Signature]| -> Vec<Signature> {
+            let applicable: Vec<&Signature> = signatures.iter()
+                .filter(|sig| !sig.type_parameters.is_empty() && checker.has_correct_type_argument_arity(sig, type_args.len()))
+                .collect();
+            applicable.into_iter()
+                .map(|sig| checker.instantiate_signature_for_call(sig, &[], type_args))
+                .collect()
+        };
+        let call_signatures = instantiate(self, &call_signatures);
+        let construct_signatures = instantiate(self, &construct_signatures);
+        if call_signatures.is_empty() && construct_signatures.is_empty() {
+            let type_text = self.format_type(expr_type);
+            self.error(&messages::TYPE_0_HAS_NO_SIGNATURES_FOR_WHICH_THE_TYPE_ARGUMENT_LIST_IS_APPLICABLE, &[&type_text]);
+            return self.type_table.any_type;
+        }
+        self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: object_flags | ObjectFlags::INSTANTIATED,
+                members,
+                call_signatures,
+                construct_signatures,
+                index_infos,
+            },
+        )
+    }
+
+    fn get_type_from_keyword(&self, kind: SyntaxKind) -> TypeId {
+        match kind {
+            SyntaxKind::StringKeyword => self.type_table.string_type,
+            SyntaxKind::NumberKeyword => self.type_table.number_type,
+            SyntaxKind::BooleanKeyword => self.type_table.boolean_type,
+            SyntaxKind::AnyKeyword => self.type_table.any_type,
+            SyntaxKind::VoidKeyword => self.type_table.void_type,
+            SyntaxKind::NeverKeyword => self.type_table.never_type,
+            SyntaxKind::UndefinedKeyword => self.type_table.undefined_type,
+            SyntaxKind::NullKeyword => self.type_table.null_type,
+            SyntaxKind::UnknownKeyword => self.type_table.unknown_type,
+            SyntaxKind::ObjectKeyword => self.type_table.object_type,
+            SyntaxKind::BigIntKeyword => self.type_table.bigint_type,
+            SyntaxKind::SymbolKeyword => self.type_table.symbol_type,
+            _ => self.type_table.any_type,
+        }
+    }
+
+    // ========================================================================
+    // Type utilities
+    // ========================================================================
+
+    /// Create an Array<T> type with a numeric index signature.
+    fn create_array_type(&mut self, element_type: TypeId) -> TypeId {
+        self.create_array_type_with_readonly(element_type, false)
+    }
+
+    /// Create a tuple type in normal form:
+    /// - variadic elements of tuple type are spliced in, and variadic elements
+    ///   of array type become rest elements (`[...A[]]` is `[...A[]]`, whereas
+    ///   `[...[A, B]]` is `[A, B]`); only generic variadic elements remain
+    /// - optional elements before the last required element become required
+    /// - everything from the first rest element to the last optional or rest
+    ///   element is folded into a single rest element
+    fn create_normalized_tuple_type(
+        &mut self,
+        element_types: Vec<TypeId>,
+        element_flags: Vec<ElementFlags>,
+        labels: Option<Vec<String>>,
+    ) -> TypeId {
+        let mut elements: Vec<(TypeId, ElementFlags, Option<String>)> = Vec::with_capacity(element_types.len());
+        for (i, &element_type) in element_types.iter().enumerate() {
+            let flag = element_flags.get(i).copied().unwrap_or(ElementFlags::Required);
+            let label = labels.as_ref().and_then(|l| l.get(i).cloned());
+            if flag != ElementFlags::Variadic {
+                elements.push((element_type, flag, label));
+                continue;
+            }
+            if let TypeKind::Tuple { element_types: inner_types, element_flags: inner_flags } =
+                self.type_table.get(element_type).kind.clone()
+            {
+                let inner_labels = self.type_table.get_tuple_labels(element_type).map(|l| l.to_vec());
+                for (j, &inner_type) in inner_types.iter().enumerate() {
+                    let inner_flag = inner_flags.get(j).copied().unwrap_or(ElementFlags::Required);
+                    let inner_label = inner_labels.as_ref().map(|l| l[j].clone());
+                    elements.push((inner_type, inner_flag, inner_label));
+                }
+            } else if self.type_table.get(element_type).flags.contains(TypeFlags::ANY) {
+                elements.push((element_type, ElementFlags::Rest, label));
+            } else if self.is_generic_type(element_type) {
+                elements.push((element_type, ElementFlags::Variadic, label));
+            } else {
+                let rest_type = self.get_element_type_of_iterable(element_type);
+                elements.push((rest_type, ElementFlags::Rest, label));
+            }
+        }
+
+        if let Some(last_required) = elements.iter().rposition(|e| e.1 == ElementFlags::Required) {
+            for element in &mut elements[..last_required] {
+                if element.1 == ElementFlags::Optional {
+                    element.1 = ElementFlags::Required;
+                }
+            }
+        }
+        let first_rest = elements.iter().position(|e| e.1 == ElementFlags::Rest);
+        let last_variable = elements.iter()
+            .rposition(|e| matches!(e.1, ElementFlags::Optional | ElementFlags::Rest));
+        if let (Some(first), Some(last)) = (first_rest, last_variable) {
+            if first < last {
+                let folded: Vec<TypeId> = elements[first..=last].iter()
+                    .map(|&(t, flag, _)| match flag {
+                        ElementFlags::Variadic => self.get_element_type_of_iterable(t),
+                        _ => t,
+                    })
+                    .collect();
+                elements[first].0 = self.create_union_type(folded);
+                elements.drain(first + 1..=last);
+            }
+        }
+
+        let labels: Option<Vec<String>> = elements.iter().map(|e| e.2.clone()).collect();
+        let tuple = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::Tuple {
+                element_types: elements.iter().map(|e| e.0).collect(),
+                element_flags: elements.iter().map(|e| e.1).collect(),
+            },
+        );
+        if let Some(labels) = labels.filter(|l| !l.is_empty()) {
+            self.type_table.set_tuple_labels(tuple, labels);
+        }
+        tuple
+    }
+
+    /// The type of the elements produced by spreading a value of this type.
+    fn get_spread_element_type(&mut self, spread_type: TypeId) -> TypeId {
+        match self.type_table.get(spread_type).kind.clone() {
+            TypeKind::Tuple { element_types, element_flags } => {
+                let types = element_types.iter().enumerate()
+                    .map(|(i, &t)| match element_flags.get(i) {
+                        Some(ElementFlags::Variadic) => self.get_element_type_of_iterable(t),
+                        _ => t,
+                    })
+                    .collect();
+                self.create_union_type(types)
+            }
+            _ => self.get_element_type_of_iterable(spread_type),
+        }
+    }
+
+    /// Whether a type is a tuple with a generic variadic element.
+    fn is_variadic_tuple_type(&self, type_id: TypeId) -> bool {
+        matches!(
+            &self.type_table.get(type_id).kind,
+            TypeKind::Tuple { element_flags, .. } if element_flags.contains(&ElementFlags::Variadic)
+        )
+    }
+
+    /// Create an Array<T> or ReadonlyArray<T> type.
+    fn create_array_type_with_readonly(&mut self, element_type: TypeId, is_readonly: bool) -> TypeId {
+        let array_type = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::REFERENCE,
+                members: IndexMap::from([
+                    ("length".to_string(), self.type_table.number_type),
+                ]),
+                call_signatures: vec![],
+                construct_signatures: vec![],
+                index_infos: vec![IndexInfo {
+                    key_type: self.type_table.number_type,
+                    type_id: element_type,
+                    is_readonly,
+                }],
+            },
+        );
+        if is_readonly {
+            self.type_table.set_property_flags(array_type, "length", PropertyFlags::READONLY);
+        }
+        array_type
+    }
+
+    /// For an array type, whether it is a `ReadonlyArray`; `None` for non-arrays.
+    fn get_array_readonly_flag(&self, type_id: TypeId) -> Option<bool> {
+        match &self.type_table.get(type_id).kind {
+            TypeKind::ObjectType { object_flags, index_infos, .. } if object_flags.contains(ObjectFlags::REFERENCE) => {
+                index_infos.first().map(|info| info.is_readonly)
+            }
+            _ => None,
+        }
+    }
+
+    /// Extract the text name from a PropertyName node.
+    fn property_name_text(&self, name: &PropertyName<'_>) -> String {
+        match name {
+            PropertyName::Identifier(id) => id.text_name.clone(),
+            PropertyName::StringLiteral(lit) => lit.text_name.clone(),
+            PropertyName::NumericLiteral(lit) => number_to_string(parse_numeric_literal_text(&lit.text_name)),
+            PropertyName::ComputedPropertyName(c) => self.get_computed_property_key(c.expression).unwrap_or_default(),
+            PropertyName::PrivateIdentifier(id) => id.text_name.clone(),
+        }
+    }
+
+    fn create_union_type(&mut self, types: Vec<TypeId>) -> TypeId {
+        // Flatten nested unions, then O(n) dedup using FxHashSet while preserving insertion order.
+        // A fresh literal is the same constituent as its regular type.
+        let mut flattened: Vec<TypeId> = Vec::with_capacity(types.len());
+        for t in types {
+            match &self.type_table.get(t).kind {
+                TypeKind::Union { types: nested } => flattened.extend(nested.iter().copied()),
+                _ => flattened.push(t),
+            }
+        }
+        let mut seen = FxHashSet::default();
+        let mut unique: Vec<TypeId> = Vec::with_capacity(flattened.len());
+        let mut includes = TypeFlags::NONE;
+        for t in flattened {
+            let flags = self.type_table.get(t).flags;
+            if flags.contains(TypeFlags::NEVER) {
+                continue;
+            }
+            let key = if flags.intersects(TypeFlags::LITERAL) {
+                self.regular_types.get(&t).copied().unwrap_or(t)
+            } else {
+                t
+            };
+            if seen.insert(key) {
+                includes |= flags;
+                unique.push(t);
+            }
+        }
+        if includes.intersects(TypeFlags::LITERAL) {
+            self.remove_redundant_literal_types(&mut unique, includes);
+        }
+        if unique.is_empty() {
+            return self.type_table.never_type;
+        }
+        if unique.len() == 1 {
+            return unique[0];
+        }
+        self.type_table.add_type(
+            TypeFlags::UNION,
+            TypeKind::Union { types: unique },
+        )
+    }
+
+    /// `true | false` is `boolean`, and literals are absorbed by their
+    /// primitive: `string | "a"` is `string`.
+    fn remove_redundant_literal_types(&self, types: &mut Vec<TypeId>, mut includes: TypeFlags) {
+        let boolean_literal = |t: TypeId| match self.type_table.get(t).kind {
+            TypeKind::BooleanLiteral { value } => Some(value),
+            _ => None,
+        };
+        if !includes.contains(TypeFlags::BOOLEAN) {
+            let values: Vec<bool> = types.iter().filter_map(|&t| boolean_literal(t)).collect();
+            if values.contains(&true) && values.contains(&false) {
+                if let Some(first) = types.iter().position(|&t| boolean_literal(t).is_some()) {
+                    types[first] = self.type_table.boolean_type;
+                }
+                types.retain(|&t| boolean_literal(t).is_none());
+                includes |= TypeFlags::BOOLEAN;
+            }
+        }
+        types.retain(|&t| {
+            let flags = self.type_table.get(t).flags;
+            !(flags.contains(TypeFlags::STRING_LITERAL) && includes.contains(TypeFlags::STRING)
+                || flags.contains(TypeFlags::NUMBER_LITERAL) && includes.contains(TypeFlags::NUMBER)
+                || flags.contains(TypeFlags::BIG_INT_LITERAL) && includes.contains(TypeFlags::BIG_INT)
+                || flags.contains(TypeFlags::BOOLEAN_LITERAL) && includes.contains(TypeFlags::BOOLEAN))
+        });
+    }
+
+    /// Intersect `types`. An intersection with a primitive type reduces, as
+    /// in `K & string` once `K` is known: `"a" & string` is `"a"` and
+    /// `1 & string` is `never`, with unions distributed over first so that
+    /// `("a" | 1) & string` is `"a"`.
+    fn create_intersection_type(&mut self, types: Vec<TypeId>) -> TypeId {
+        let mut seen = FxHashSet::default();
+        let mut unique: Vec<TypeId> = types.into_iter().filter(|t| seen.insert(*t)).collect();
+        if unique.is_empty() {
+            return self.type_table.any_type;
+        }
+        if unique.len() == 1 { return unique[0]; }
+        let primitives = TypeFlags::STRING | TypeFlags::NUMBER | TypeFlags::BIG_INT | TypeFlags::ES_SYMBOL;
+        if unique.iter().any(|&t| self.type_table.get(t).flags.intersects(primitives)) {
+            if let Some(i) = unique.iter().position(|&t| matches!(self.type_table.get(t).kind, TypeKind::Union { .. })) {
+                let results: Vec<TypeId> = self.get_union_constituents(unique[i]).into_iter()
+                    .map(|member| {
+                        let mut types = unique.clone();
+                        types[i] = member;
+                        self.create_intersection_type(types)
+                    })
+                    .collect();
+                return self.create_union_type(results);
+            }
+            let groups: Vec<Option<TypeFlags>> = unique.iter()
+                .map(|&t| primitive_group(self.type_table.get(t).flags))
+                .collect();
+            let mut distinct_groups = groups.iter().flatten().copied().collect::<Vec<_>>();
+            distinct_groups.dedup();
+            if distinct_groups.windows(2).any(|pair| pair[0] != pair[1]) {
+                return self.type_table.never_type;
+            }
+            // A primitive is redundant next to a more specific type of its kind
+            let has_unit = groups.iter().zip(&unique)
+                .any(|(group, &t)| group.is_some() && !self.type_table.get(t).flags.intersects(primitives));
+            if has_unit {
+                unique.retain(|&t| !self.type_table.get(t).flags.intersects(primitives));
+            }
+            if unique.len() == 1 { return unique[0]; }
+        }
+        self.type_table.add_type(
+            TypeFlags::INTERSECTION,
+            TypeKind::Intersection { types: unique },
+        )
+    }
+
+    fn get_non_nullable_type(&mut self, type_id: TypeId) -> TypeId {
+        let ty = self.type_table.get(type_id);
+        if ty.flags.contains(TypeFlags::NULL) || ty.flags.contains(TypeFlags::UNDEFINED) {
+            return self.type_table.never_type;
+        }
+        if let TypeKind::Union { types } = &ty.kind {
+            let filtered: Vec<TypeId> = types.iter()
+                .filter(|&&t| {
+                    let inner = self.type_table.get(t);
+                    !inner.flags.contains(TypeFlags::NULL) && !inner.flags.contains(TypeFlags::UNDEFINED)
+                })
+                .copied()
+                .collect();
+            if filtered.is_empty() {
+                return self.type_table.never_type;
+            }
+            if filtered.len() == 1 {
+                return filtered[0];
+            }
+            return self.create_union_type(filtered);
+        }
+        type_id
+    }
+
+    /// Get the element type of an iterable type (e.g., `T[]` -> `T`).
+    fn get_element_type_of_iterable(&self, iterable: TypeId) -> TypeId {
+        let ty = self.type_table.get(iterable);
+        match &ty.kind {
+            TypeKind::ObjectType { index_infos, .. } => {
+                // Array-like: has a numeric index signature
+                for info in index_infos {
+                    if info.key_type == self.type_table.number_type {
+                        return info.type_id;
+                    }
+                }
+                self.type_table.any_type
+            }
+            TypeKind::TypeReference { type_arguments, .. } => {
+                // Generic types like Array<T> — return the first type argument
+                if let Some(first) = type_arguments.first() {
+                    return *first;
+                }
+                self.type_table.any_type
+            }
+            _ => self.type_table.any_type,
+        }
+    }
+
+    /// The types iterating a value of this type produces, reporting a type
+    /// that cannot be iterated this way. Below ES2015, without
+    /// downlevelIteration, only arrays (and strings, in `for...of`) can be
+    /// iterated synchronously.
+    fn check_iteration_types(&mut self, type_id: TypeId, iteration_use: IterationUse) -> IterationTypes {
+        let any = self.type_table.any_type;
+        let any_types = IterationTypes { yield_type: any, return_type: any, next_type: any };
+        if self.type_table.get(type_id).flags.contains(TypeFlags::ANY) {
+            return any_types;
+        }
+        let allows_async = iteration_use.allows_async_iterables();
+        let uplevel = self.language_version >= ScriptTarget::ES2015;
+        if uplevel || self.downlevel_iteration || allows_async {
+            if let Some(types) = self.get_iteration_types_of_iterable(type_id, allows_async) {
+                return types;
+            }
+            if uplevel || allows_async {
+                let type_string = self.format_type(type_id);
+                let message = if allows_async {
+                    &messages::TYPE_0_MUST_HAVE_A_SYMBOL_ASYNC_ITERATOR_METHOD_THAT_RETURNS_AN_ASYNC_ITERATOR
+                } else {
+                    &messages::TYPE_0_MUST_HAVE_A_SYMBOL_ITERATOR_METHOD_THAT_RETURNS_AN_ITERATOR
+                };
+                self.error(message, &[&type_string]);
+                return any_types;
+            }
+        }
+        let allows_strings = iteration_use == IterationUse::ForOf;
+        if let Some(element_type) = self.get_array_like_iterated_type(type_id, allows_strings) {
+            return IterationTypes { yield_type: element_type, return_type: any, next_type: any };
+        }
+        let type_string = self.format_type(type_id);
+        if !self.downlevel_iteration && self.get_iteration_types_of_iterable(type_id, false).is_some() {
+            self.error(&messages::TYPE_0_CAN_ONLY_BE_ITERATED_THROUGH_WHEN_USING_DOWNLEVEL_ITERATION, &[&type_string]);
+        } else if allows_strings {
+            self.error(&messages::TYPE_0_IS_NOT_AN_ARRAY_TYPE_OR_A_STRING_TYPE, &[&type_string]);
+        } else {
+            self.error(&messages::TYPE_0_IS_NOT_AN_ARRAY_TYPE, &[&type_string]);
+        }
+        any_types
+    }
+
+    /// The element type of an array or tuple, or of a string when strings
+    /// are allowed: what iterating it produces below ES2015.
+    fn get_array_like_iterated_type(&mut self, type_id: TypeId, allows_strings: bool) -> Option<TypeId> {
+        let type_id = self.get_apparent_type_of_type_parameter(type_id);
+        let ty = self.type_table.get(type_id);
+        if allows_strings && ty.flags.intersects(TypeFlags::STRING_LIKE) {
+            return Some(self.type_table.string_type);
+        }
+        match ty.kind.clone() {
+            TypeKind::Union { types } => {
+                let element_types = types.iter()
+                    .map(|&t| self.get_array_like_iterated_type(t, allows_strings))
+                    .collect::<Option<Vec<TypeId>>>()?;
+                Some(self.create_union_type(element_types))
+            }
+            TypeKind::Tuple { .. } => Some(self.get_spread_element_type(type_id)),
+            _ if self.get_array_readonly_flag(type_id).is_some() => Some(self.get_element_type_of_iterable(type_id)),
+            _ => None,
+        }
+    }
+
+    /// The iteration types of an iterable: arrays, tuples and strings, and
+    /// objects with a `[Symbol.iterator]()` method (or, for async iteration,
+    /// an `[Symbol.asyncIterator]()` method, falling back to a sync iterable
+    /// whose values are awaited).
+    fn get_iteration_types_of_iterable(&mut self, type_id: TypeId, is_async: bool) -> Option<IterationTypes> {
+        let any = self.type_table.any_type;
+        let type_id = self.get_apparent_type_of_type_parameter(type_id);
+        if self.type_table.get(type_id).flags.contains(TypeFlags::ANY) {
+            return Some(IterationTypes { yield_type: any, return_type: any, next_type: any });
+        }
+        if let TypeKind::Union { types } = self.type_table.get(type_id).kind.clone() {
+            let constituents = types.iter()
+                .map(|&t| self.get_iteration_types_of_iterable(t, is_async))
+                .collect::<Option<Vec<IterationTypes>>>()?;
+            return Some(self.combine_iteration_types(&constituents));
+        }
+        if is_async {
+            if let Some(types) = self.get_iteration_types_of_iterator_method(type_id, "[Symbol.asyncIterator]", true) {
+                return Some(types);
+            }
+        }
+        let types = match self.get_array_like_iterated_type(type_id, true) {
+            Some(element_type) => IterationTypes { yield_type: element_type, return_type: any, next_type: any },
+            None => self.get_iteration_types_of_iterator_method(type_id, "[Symbol.iterator]", false)?,
+        };
+        if is_async {
+            return Some(IterationTypes {
+                yield_type: self.get_awaited_type(types.yield_type),
+                return_type: self.get_awaited_type(types.return_type),
+                next_type: types.next_type,
+            });
+        }
+        Some(types)
+    }
+
+    /// The iteration types of the iterator an object's `[Symbol.iterator]()`
+    /// (or `[Symbol.asyncIterator]()`) method returns.
+    fn get_iteration_types_of_iterator_method(&mut self, type_id: TypeId, key: &str, is_async: bool) -> Option<IterationTypes> {
+        let method = self.get_member_type(type_id, key)?;
+        let method = self.instantiate_this_type(method, type_id, type_id);
+        let iterator = match &self.type_table.get(method).kind {
+            TypeKind::ObjectType { call_signatures, .. } => call_signatures.first()?.return_type,
+            _ if self.type_table.get(method).flags.contains(TypeFlags::ANY) => method,
+            _ => return None,
+        };
+        self.get_iteration_types_of_iterator(iterator, is_async)
+    }
+
+    /// The iteration types of an iterator, from the results of its `next`
+    /// method: the values of results that are not `done` are yielded, and
+    /// those of results that are `done` returned. Async iterators' results
+    /// are awaited.
+    fn get_iteration_types_of_iterator(&mut self, type_id: TypeId, is_async: bool) -> Option<IterationTypes> {
+        let any = self.type_table.any_type;
+        let type_id = self.get_apparent_type_of_type_parameter(type_id);
+        if self.type_table.get(type_id).flags.contains(TypeFlags::ANY) {
+            return Some(IterationTypes { yield_type: any, return_type: any, next_type: any });
+        }
+        let next = self.get_member_type(type_id, "next")?;
+        let next = self.instantiate_this_type(next, type_id, type_id);
+        let signature = match &self.type_table.get(next).kind {
+            TypeKind::ObjectType { call_signatures, .. } => call_signatures.first()?.clone(),
+            _ => return None,
+        };
+        let next_type = signature.parameters.first()
+            .map_or(self.type_table.unknown_type, |p| p.type_id);
+        let mut result = signature.return_type;
+        if is_async {
+            result = self.get_awaited_type(result);
+        }
+        let results = match &self.type_table.get(result).kind {
+            TypeKind::Union { types } => types.clone(),
+            _ => vec![result],
+        };
+        let mut yield_types = Vec::new();
+        let mut return_types = Vec::new();
+        for result in results {
+            if self.type_table.get(result).flags.contains(TypeFlags::ANY) {
+                yield_types.push(any);
+                return_types.push(any);
+                continue;
+            }
+            let value = self.get_member_type(result, "value")?;
+            match self.get_member_type(result, "done") {
+                Some(done) if done == self.type_table.true_type => return_types.push(value),
+                Some(done) if done == self.type_table.false_type => yield_types.push(value),
+                None => yield_types.push(value),
+                Some(_) => {
+                    yield_types.push(value);
+                    return_types.push(value);
+                }
+            }
+        }
+        Some(IterationTypes {
+            yield_type: self.create_union_type(yield_types),
+            return_type: self.create_union_type(return_types),
+            next_type,
+        })
+    }
 
-                // Look up in declared_types
-                if let Some(type_id) = self.get_declared_type(&ref_name) {
-                    // If there are type arguments, create a TypeReference for instantiation
-                    if let Some(type_args) = n.type_arguments {
-                        if !type_args.is_empty() {
-                            let resolved_args: Vec<TypeId> = type_args.iter()
-                                .map(|arg| self.get_type_from_type_node(arg))
-                                .collect();
-                            return self.instantiate_generic_type(type_id, &resolved_args);
-                        }
+    /// The iteration types of a union of iterables.
+    fn combine_iteration_types(&mut self, constituents: &[IterationTypes]) -> IterationTypes {
+        let yield_type = self.create_union_type(constituents.iter().map(|t| t.yield_type).collect());
+        let return_type = self.create_union_type(constituents.iter().map(|t| t.return_type).collect());
+        let next_types: Vec<TypeId> = constituents.iter().map(|t| t.next_type).collect();
+        let next_type = if next_types.windows(2).all(|pair| pair[0] == pair[1]) {
+            next_types.first().copied().unwrap_or(self.type_table.unknown_type)
+        } else {
+            self.create_intersection_type(next_types)
+        };
+        IterationTypes { yield_type, return_type, next_type }
+    }
+
+    /// The iteration types a generator function's declared return type
+    /// allows: those of a `Generator`, `Iterator` or `Iterable` (or their
+    /// async counterparts). `None` when they are not checked.
+    fn get_iteration_types_of_generator_return_type(&mut self, return_type: TypeId, is_async: bool) -> Option<IterationTypes> {
+        if self.type_table.get(return_type).flags.intersects(TypeFlags::ANY | TypeFlags::UNKNOWN) {
+            return None;
+        }
+        let return_type = self.get_apparent_type_of_type_parameter(return_type);
+        if self.get_member_type(return_type, "next").is_some() {
+            return self.get_iteration_types_of_iterator(return_type, is_async);
+        }
+        let key = if is_async { "[Symbol.asyncIterator]" } else { "[Symbol.iterator]" };
+        self.get_iteration_types_of_iterator_method(return_type, key, is_async)
+    }
+
+    /// The type `await` produces for an operand of this type (`Awaited<T>`):
+    /// the value a thenable is fulfilled with, unwrapped recursively, and
+    /// any other type itself.
+    fn get_awaited_type(&mut self, type_id: TypeId) -> TypeId {
+        self.get_awaited_type_worker(type_id, 0)
+    }
+
+    fn get_awaited_type_worker(&mut self, type_id: TypeId, depth: u32) -> TypeId {
+        if depth >= MAX_INSTANTIATION_DEPTH {
+            return type_id;
+        }
+        match self.type_table.get(type_id).kind.clone() {
+            TypeKind::Union { types } => {
+                let awaited: Vec<TypeId> = types.iter().map(|&t| self.get_awaited_type_worker(t, depth + 1)).collect();
+                if awaited == types { type_id } else { self.create_union_type(awaited) }
+            }
+            TypeKind::ObjectType { .. } => {
+                // then(onfulfilled: (value: T) => ...) fulfills with T
+                let Some(then) = self.get_member_type(type_id, "then") else {
+                    return type_id;
+                };
+                let onfulfilled = match &self.type_table.get(then).kind {
+                    TypeKind::ObjectType { call_signatures, .. } => {
+                        call_signatures.first().and_then(|sig| sig.parameters.first()).map(|p| p.type_id)
                     }
+                    _ => None,
+                };
+                let Some(onfulfilled) = onfulfilled else {
                     return type_id;
+                };
+                let onfulfilled = self.get_non_nullable_type(onfulfilled);
+                let value = match &self.type_table.get(onfulfilled).kind {
+                    TypeKind::ObjectType { call_signatures, .. } => {
+                        call_signatures.first().and_then(|sig| sig.parameters.first()).map(|p| p.type_id)
+                    }
+                    _ => None,
+                };
+                match value {
+                    Some(value) => self.get_awaited_type_worker(value, depth + 1),
+                    None => self.type_table.never_type,
                 }
+            }
+            _ => type_id,
+        }
+    }
 
-                // Look up in binder
-                if let Some(symbol_id) = self.binder.resolve_name(&ref_name) {
-                    if let Some(symbol) = self.binder.get_symbol(symbol_id) {
-                        if symbol.flags.contains(SymbolFlags::INTERFACE) || symbol.flags.contains(SymbolFlags::TYPE_ALIAS) {
-                            return self.type_table.any_type; // Would need type resolution
-                        }
-                        if symbol.flags.contains(SymbolFlags::CLASS) {
-                            return self.type_table.any_type; // Would need instance type
-                        }
-                        if symbol.flags.contains(SymbolFlags::REGULAR_ENUM) {
-                            return self.type_table.number_type;
-                        }
-                    }
+    /// Format a type for a diagnostic message.
+    fn format_type(&self, type_id: TypeId) -> String {
+        self.type_to_string_worker(type_id, TypeFormatFlags::NONE)
+    }
+
+    /// Format a signature as it appears in a declaration, e.g. `(x: string): number`.
+    pub fn signature_to_string(&self, sig: &Signature, flags: TypeFormatFlags) -> String {
+        self.signature_to_string_worker(sig, flags)
+    }
+
+    // ========================================================================
+    // Type relationship checking
+    // ========================================================================
+
+    pub fn is_type_assignable_to(&mut self, source: TypeId, target: TypeId) -> bool {
+        self.is_type_related_to(source, target, TypeRelation::Assignable)
+    }
+
+    /// Check assignability and report TS2322, or TS2353 when the failure is
+    /// an excess property in a fresh object literal.
+    fn check_type_assignable_to(&mut self, source: TypeId, target: TypeId) -> bool {
+        if self.is_type_assignable_to(source, target) {
+            return true;
+        }
+        if let Some(error) = self.get_excess_property_error(source, target) {
+            self.add_diagnostic(error);
+        } else if self.has_explicit_undefined_for_exact_optional_property(source, target) {
+            let src = self.format_type(source);
+            let tgt = self.format_type(target);
+            self.error(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1_WITH_EXACTOPTIONALPROPERTYTYPES, &[&src, &tgt]);
+        } else {
+            let reported_source = self.get_reported_source_type(source, target);
+            let src = self.format_type(reported_source);
+            let tgt = self.format_type(target);
+            let message_chain = self.elaborate_assignability_error(source, target, 0);
+            let diagnostic = self.create_diagnostic(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&src, &tgt])
+                .with_message_chain(message_chain);
+            self.diagnostics.add(diagnostic);
+        }
+        false
+    }
+
+    /// Check that the value of `expr` is assignable to `target`. The error is
+    /// reported at the node at fault: a property of an object literal whose
+    /// value does not fit or that is not known in `target`, or otherwise
+    /// `error_range`.
+    fn check_type_assignable_to_and_elaborate(
+        &mut self,
+        source: TypeId,
+        target: TypeId,
+        expr: &Expression<'_>,
+        error_range: TextRange,
+    ) -> bool {
+        if self.is_type_assignable_to(source, target) {
+            return true;
+        }
+        if !self.elaborate_object_literal(source, target, expr) {
+            let saved_range = self.current_node_range.replace(error_range);
+            self.check_type_assignable_to(source, target);
+            self.current_node_range = saved_range;
+        }
+        false
+    }
+
+    /// Report the properties of an object literal `expr` that make its type
+    /// `source` not assignable to `target`, each at the property's name.
+    /// Returns whether anything was reported.
+    fn elaborate_object_literal(&mut self, source: TypeId, target: TypeId, expr: &Expression<'_>) -> bool {
+        let literal = match expr {
+            Expression::Parenthesized(p) => return self.elaborate_object_literal(source, target, p.expression),
+            Expression::ObjectLiteral(literal) => literal,
+            _ => return false,
+        };
+        let elements: Vec<(String, TextRange, Option<&Expression<'_>>)> = literal.properties.iter()
+            .filter_map(|element| match element {
+                ObjectLiteralElement::PropertyAssignment(p) => {
+                    Some((self.property_name_text(&p.name), p.name.data().range, Some(p.initializer)))
+                }
+                ObjectLiteralElement::ShorthandPropertyAssignment(p) => Some((p.name.text_name.clone(), p.name.data.range, None)),
+                _ => None,
+            })
+            .collect();
+        if let Some(excess) = self.find_excess_property(source, target) {
+            let Some(&(_, range, _)) = elements.iter().find(|(name, ..)| *name == excess) else {
+                return false;
+            };
+            let Some(error) = self.get_excess_property_error(source, target) else {
+                return false;
+            };
+            let saved_range = self.current_node_range.replace(range);
+            self.add_diagnostic(error);
+            self.current_node_range = saved_range;
+            return true;
+        }
+        // A union target is narrowed to the object type the literal is meant
+        // to be: the one its discriminants select, or the only one besides
+        // `null` and `undefined`.
+        let target = match self.type_table.get(target).kind.clone() {
+            TypeKind::Union { types } => {
+                let objects: Vec<TypeId> = types.iter().copied()
+                    .filter(|&t| !self.type_table.get(t).flags.intersects(TypeFlags::NULL | TypeFlags::UNDEFINED))
+                    .collect();
+                match (self.find_matching_discriminant_type(source, &types), objects.as_slice()) {
+                    (Some(target), _) | (None, &[target]) => target,
+                    _ => return false,
+                }
+            }
+            _ => target,
+        };
+        let mut reported = false;
+        for (name, range, value) in elements {
+            let (Some(source_prop), Some(target_prop)) = (self.get_member_type(source, &name), self.get_member_type(target, &name)) else {
+                continue;
+            };
+            // An optional property may also be given `undefined` explicitly,
+            // which exactOptionalPropertyTypes reports for the whole literal.
+            let target_prop = if self.type_table.get_property_flags(target, &name).contains(PropertyFlags::OPTIONAL) {
+                self.create_union_type(vec![target_prop, self.type_table.undefined_type])
+            } else {
+                target_prop
+            };
+            if self.is_type_assignable_to(source_prop, target_prop) {
+                continue;
+            }
+            match value {
+                Some(value) => {
+                    self.check_type_assignable_to_and_elaborate(source_prop, target_prop, value, range);
                 }
+                None => {
+                    let saved_range = self.current_node_range.replace(range);
+                    self.check_type_assignable_to(source_prop, target_prop);
+                    self.current_node_range = saved_range;
+                }
+            }
+            reported = true;
+        }
+        reported
+    }
 
-                self.type_table.any_type
+    /// Explain why `source` is not assignable to `target` by following one
+    /// path through the two types: the first member of a source union that
+    /// is not assignable, the first member of a target intersection that it
+    /// is not assignable to, or the first target property that the source is
+    /// missing or has an incompatible type for, elaborated in turn.
+    fn elaborate_assignability_error(&mut self, source: TypeId, target: TypeId, depth: u32) -> Vec<DiagnosticMessageChain> {
+        if depth >= MAX_ELABORATION_DEPTH {
+            return Vec::new();
+        }
+        if let TypeKind::Union { types } = &self.type_table.get(source).kind {
+            for member in types.clone() {
+                if !self.is_type_assignable_to(member, target) {
+                    let src = self.format_type(member);
+                    let tgt = self.format_type(target);
+                    let next = self.elaborate_assignability_error(member, target, depth + 1);
+                    return vec![DiagnosticMessageChain::new(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&src, &tgt]).with_next(next)];
+                }
             }
-            TypeNode::ArrayType(n) => {
-                let elem_type = self.get_type_from_type_node(n.element_type);
-                self.create_array_type(elem_type)
+            return Vec::new();
+        }
+        // An intersection target is explained by the constituent that the
+        // source is not assignable to.
+        if let TypeKind::Intersection { types } = &self.type_table.get(target).kind {
+            for member in types.clone() {
+                if !self.is_type_assignable_to(source, member) {
+                    return self.elaborate_assignability_error(source, member, depth + 1);
+                }
             }
-            TypeNode::TupleType(n) => {
-                let elem_types: Vec<TypeId> = n.elements.iter()
-                    .map(|e| self.get_type_from_type_node(e))
-                    .collect();
-                self.type_table.add_type(
-                    TypeFlags::OBJECT,
-                    TypeKind::Tuple {
-                        element_types: elem_types,
-                        element_flags: vec![],
-                    },
-                )
+            return Vec::new();
+        }
+        let (TypeKind::ObjectType { members: source_members, .. }, TypeKind::ObjectType { members: target_members, .. }) =
+            (self.type_table.get(source).kind.clone(), self.type_table.get(target).kind.clone())
+        else {
+            return Vec::new();
+        };
+        for (name, target_prop) in target_members {
+            let Some(&source_prop) = source_members.get(&name) else {
+                if self.type_table.get_property_flags(target, &name).contains(PropertyFlags::OPTIONAL) {
+                    continue;
+                }
+                let src = self.format_type(source);
+                let tgt = self.format_type(target);
+                return vec![DiagnosticMessageChain::new(&messages::PROPERTY_0_IS_MISSING_IN_TYPE_1_BUT_REQUIRED_IN_TYPE_2, &[&name, &src, &tgt])];
+            };
+            let source_prop = self.instantiate_this_type(source_prop, source, source);
+            let target_prop = self.instantiate_this_type(target_prop, target, source);
+            if self.is_type_assignable_to(source_prop, target_prop) {
+                continue;
+            }
+            let reported_source = self.get_reported_source_type(source_prop, target_prop);
+            let src = self.format_type(reported_source);
+            let tgt = self.format_type(target_prop);
+            let next = self.elaborate_assignability_error(source_prop, target_prop, depth + 1);
+            return vec![DiagnosticMessageChain::new(&messages::TYPES_OF_PROPERTY_0_ARE_INCOMPATIBLE, &[&name]).with_next(vec![
+                DiagnosticMessageChain::new(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &[&src, &tgt]).with_next(next),
+            ])];
+        }
+        Vec::new()
+    }
+
+    fn is_type_related_to(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> bool {
+        if source == target { return true; }
+
+        // Instantiations of recursive aliases deferred until their members
+        // are needed are created once related.
+        self.resolve_deferred_type(source);
+        self.resolve_deferred_type(target);
+
+        // Check cache — O(1) for repeated checks & breaks cycles
+        if let Some(&result) = self.relation_cache.get(&(source, target, relation)) {
+            return result;
+        }
+
+        // Types nested too deeply to compare, like ever-expanding recursive
+        // aliases, are not known to be related. Results that depended on
+        // such a comparison are not cached.
+        if self.relation_depth >= MAX_RELATION_DEPTH {
+            self.relation_depth_exceeded = true;
+            return false;
+        }
+
+        // Insert optimistic sentinel before recursing.
+        // If a cycle re-enters this (source, target) pair, it returns `true`
+        // (assumes related), which is the correct conservative answer for
+        // recursive / circular types.
+        self.relation_cache.insert((source, target, relation), true);
+
+        let saved_depth_exceeded = std::mem::replace(&mut self.relation_depth_exceeded, false);
+        self.relation_depth += 1;
+        let result = self.is_type_related_to_worker(source, target, relation);
+        self.relation_depth -= 1;
+
+        // Update cache with actual result
+        if self.relation_depth_exceeded {
+            self.relation_cache.remove(&(source, target, relation));
+        } else {
+            self.relation_cache.insert((source, target, relation), result);
+        }
+        self.relation_depth_exceeded |= saved_depth_exceeded;
+        result
+    }
+
+    /// Core relation logic.  Separated from the entry point so that the
+    /// cache look-up / cycle-sentinel lives in one place.
+    fn is_type_related_to_worker(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> bool {
+        // Extract flags upfront (TypeFlags is Copy, no borrow held)
+        let target_flags = self.type_table.get(target).flags;
+        let source_flags = self.type_table.get(source).flags;
+
+        if target_flags.contains(TypeFlags::ANY) || target_flags.contains(TypeFlags::UNKNOWN) {
+            return true;
+        }
+        // `any` is assignable to everything but is only a subtype of `any`/`unknown`.
+        if source_flags.contains(TypeFlags::ANY) { return relation == TypeRelation::Assignable; }
+        if source_flags.contains(TypeFlags::NEVER) { return true; }
+
+        // A fresh object literal may not specify properties the target doesn't
+        // know about; past that check it relates like any other object.
+        if self.is_fresh_object_literal(source) {
+            if self.find_excess_property(source, target).is_some() {
+                return false;
             }
-            TypeNode::UnionType(n) => {
-                let types: Vec<TypeId> = n.types.iter()
-                    .map(|t| self.get_type_from_type_node(t))
-                    .collect();
-                self.create_union_type(types)
+            let regular = self.get_regular_type_of_object_literal(source);
+            return self.is_type_related_to(regular, target, relation);
+        }
+
+        // null/undefined assignability
+        if !self.strict_null_checks
+            && (source_flags.contains(TypeFlags::NULL) || source_flags.contains(TypeFlags::UNDEFINED)) {
+                return true;
             }
-            TypeNode::IntersectionType(n) => {
-                let types: Vec<TypeId> = n.types.iter()
-                    .map(|t| self.get_type_from_type_node(t))
-                    .collect();
-                self.create_intersection_type(types)
+
+        // Union source: each constituent must be assignable to target
+        if let TypeKind::Union { types } = &self.type_table.get(source).kind {
+            let source_types = types.clone();
+            return source_types.iter().all(|&t| self.is_type_related_to(t, target, relation));
+        }
+
+        // Union target: source must be assignable to at least one constituent.
+        // Clone the types vec to release the borrow before recursive calls.
+        if let TypeKind::Union { types } = &self.type_table.get(target).kind {
+            let target_types = types.clone();
+            for t in target_types {
+                if self.is_type_related_to(source, t, relation) {
+                    return true;
+                }
             }
-            TypeNode::FunctionType(n) => {
-                let params: Vec<SignatureParameter> = n.parameters.iter().map(|p| {
-                    let param_type = self.get_type_from_type_annotation(p.type_annotation);
-                    SignatureParameter {
-                        name: String::new(),
-                        type_id: param_type.unwrap_or(self.type_table.any_type),
-                        optional: p.question_token.is_some(),
-                    }
-                }).collect();
-                let return_type = n.return_type
-                    .map(|r| self.get_type_from_type_node(r))
-                    .unwrap_or(self.type_table.any_type);
+            return false;
+        }
 
-                let sig = Signature {
-                    type_parameters: vec![],
-                    parameters: params,
-                    return_type,
-                    min_argument_count: n.parameters.iter()
-                        .filter(|p| p.question_token.is_none() && p.initializer.is_none())
-                        .count() as u32,
-                    has_rest_parameter: n.parameters.iter().any(|p| p.dot_dot_dot_token.is_some()),
-                };
-                self.type_table.add_type(
-                    TypeFlags::OBJECT,
-                    TypeKind::ObjectType {
-                        object_flags: ObjectFlags::ANONYMOUS,
-                        members: IndexMap::new(),
-                        call_signatures: vec![sig],
-                        construct_signatures: vec![],
-                        index_infos: vec![],
-                    },
-                )
+        // Intersection target: source must be assignable to every constituent.
+        if let TypeKind::Intersection { types } = &self.type_table.get(target).kind {
+            let target_types = types.clone();
+            return target_types.iter().all(|&t| self.is_type_related_to(source, t, relation));
+        }
+
+        // Intersection source: any constituent assignable to target is sufficient
+        if let TypeKind::Intersection { types } = &self.type_table.get(source).kind {
+            let source_types = types.clone();
+            for t in source_types {
+                if self.is_type_related_to(t, target, relation) { return true; }
             }
-            TypeNode::ConditionalType(n) => {
-                let check = self.get_type_from_type_node(n.check_type);
-                let extends = self.get_type_from_type_node(n.extends_type);
-                let true_type = self.get_type_from_type_node(n.true_type);
-                let false_type = self.get_type_from_type_node(n.false_type);
+        }
+
+        // A type parameter relates to whatever its constraint relates to.
+        if let TypeKind::TypeParameter { constraint: Some(constraint), .. } = self.type_table.get(source).kind {
+            return self.is_type_related_to(constraint, target, relation);
+        }
+
+        if let Some(related) = self.is_simple_type_related_to(source, target, relation) {
+            return related;
+        }
+
+        if let Some(related) = self.is_tuple_type_related_to(source, target, relation) {
+            return related;
+        }
+
+        // Structural type checking for object types.
+        // IndexMap members provide O(1) property lookup per target member.
+        let member_pairs: Option<Vec<(TypeId, TypeId)>> = {
+            let source_type = self.type_table.get(source);
+            let target_type = self.type_table.get(target);
+            match (&source_type.kind, &target_type.kind) {
+                (
+                    TypeKind::ObjectType { members: source_members, .. },
+                    TypeKind::ObjectType { members: target_members, .. },
+                ) => {
+                    // O(1) lookup per target member via IndexMap
+                    let mut pairs = Vec::new();
+                    for (target_name, target_prop_type) in target_members {
+                        if let Some(&source_prop_type) = source_members.get(target_name) {
+                            // An optional property may be missing where the target requires it.
+                            if self.type_table.get_property_flags(source, target_name).contains(PropertyFlags::OPTIONAL)
+                                && !self.type_table.get_property_flags(target, target_name).contains(PropertyFlags::OPTIONAL)
+                            {
+                                return false;
+                            }
+                            pairs.push((source_prop_type, *target_prop_type));
+                        } else if !self.type_table.get_property_flags(target, target_name).contains(PropertyFlags::OPTIONAL) {
+                            // Missing property — not assignable
+                            return false;
+                        }
+                    }
+                    Some(pairs)
+                }
+                _ => None,
+            }
+        };
 
-                // If check and extends are concrete, evaluate immediately
-                let check_flags = self.type_table.get(check).flags;
-                let extends_flags = self.type_table.get(extends).flags;
-                let is_concrete = |f: TypeFlags| {
-                    f.intersects(TypeFlags::STRING | TypeFlags::NUMBER | TypeFlags::BOOLEAN |
-                        TypeFlags::NULL | TypeFlags::UNDEFINED | TypeFlags::VOID |
-                        TypeFlags::NEVER | TypeFlags::STRING_LITERAL | TypeFlags::NUMBER_LITERAL |
-                        TypeFlags::BOOLEAN_LITERAL | TypeFlags::OBJECT)
-                };
-                if is_concrete(check_flags) && is_concrete(extends_flags) {
-                    return self.evaluate_conditional_type(check, extends, true_type, false_type);
+        if let Some(pairs) = member_pairs {
+            for (source_prop, target_prop) in pairs {
+                // Polymorphic `this` types on both sides are the source.
+                let source_prop = self.instantiate_this_type(source_prop, source, source);
+                let target_prop = self.instantiate_this_type(target_prop, target, source);
+                if !self.is_type_related_to(source_prop, target_prop, relation) {
+                    return false;
                 }
+            }
+            return self.index_signatures_related_to(source, target, relation)
+                && self.signatures_related_to(source, target, relation);
+        }
 
-                self.type_table.add_type(
-                    TypeFlags::CONDITIONAL,
-                    TypeKind::Conditional { check_type: check, extends_type: extends, true_type, false_type },
-                )
+        false
+    }
+
+    /// Relate the index signatures of two object types. Each target index
+    /// signature must be matched by an applicable source index signature or,
+    /// for object literal and anonymous source types, by every source
+    /// property it applies to (an implicit index signature).
+    fn index_signatures_related_to(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> bool {
+        let TypeKind::ObjectType { index_infos: target_infos, .. } = self.type_table.get(target).kind.clone() else {
+            return true;
+        };
+        for target_info in target_infos {
+            // `{ [key: string]: any }` accepts every object type.
+            if target_info.key_type == self.type_table.string_type
+                && self.type_table.get(target_info.type_id).flags.contains(TypeFlags::ANY)
+            {
+                continue;
             }
-            TypeNode::IndexedAccessType(n) => {
-                let obj = self.get_type_from_type_node(n.object_type);
-                let idx = self.get_type_from_type_node(n.index_type);
-                // Try to resolve the indexed access immediately
-                let resolved = self.resolve_indexed_access(obj, idx);
-                if resolved != self.type_table.any_type {
-                    return resolved;
+            let related = match self.get_applicable_index_info(source, target_info.key_type) {
+                Some(source_info) => self.is_type_related_to(source_info.type_id, target_info.type_id, relation),
+                None if self.is_object_type_with_inferable_index(source) => {
+                    self.members_related_to_index_info(source, &target_info, relation)
                 }
-                self.type_table.add_type(
-                    TypeFlags::INDEXED_ACCESS,
-                    TypeKind::IndexedAccess { object_type: obj, index_type: idx },
-                )
+                None => false,
+            };
+            if !related {
+                return false;
             }
-            TypeNode::TypeOperator(n) => {
-                let operand = self.get_type_from_type_node(n.type_node);
-                match n.operator {
-                    SyntaxKind::KeyOfKeyword => {
-                        // Extract member names from the operand type and create a union of string literal types
-                        let member_names = self.get_object_member_names(operand);
-                        if member_names.is_empty() {
-                            // Fallback: string | number | symbol
-                            self.create_union_type(vec![self.type_table.string_type, self.type_table.number_type, self.type_table.symbol_type])
-                        } else {
-                            let literal_types: Vec<TypeId> = member_names.iter().map(|name| {
-                                self.type_table.add_type(
-                                    TypeFlags::STRING_LITERAL,
-                                    TypeKind::StringLiteral { value: name.clone(), regular: true },
-                                )
-                            }).collect();
-                            self.create_union_type(literal_types)
-                        }
-                    }
-                    SyntaxKind::ReadonlyKeyword => operand,
-                    SyntaxKind::UniqueKeyword => self.type_table.symbol_type,
-                    _ => operand,
-                }
+        }
+        true
+    }
+
+    /// Whether an object type gets an implicit index signature from its
+    /// properties: object literal types and type literals, but not classes,
+    /// interfaces, arrays or types with signatures.
+    fn is_object_type_with_inferable_index(&self, type_id: TypeId) -> bool {
+        match &self.type_table.get(type_id).kind {
+            TypeKind::ObjectType { object_flags, call_signatures, construct_signatures, .. } => {
+                object_flags.intersects(ObjectFlags::ANONYMOUS | ObjectFlags::OBJECT_LITERAL)
+                    && !object_flags.intersects(ObjectFlags::CLASS_OR_INTERFACE | ObjectFlags::REFERENCE)
+                    && call_signatures.is_empty()
+                    && construct_signatures.is_empty()
             }
-            TypeNode::TypeLiteral(n) => {
-                let mut members: IndexMap<String, TypeId> = IndexMap::new();
-                let mut index_infos = Vec::new();
-                for member in n.members.iter() {
-                    match member {
-                        TypeElement::PropertySignature(p) => {
-                            let prop_name = self.get_property_name_text(&p.name);
-                            let prop_type = self.get_type_from_type_annotation(p.type_annotation);
-                            members.insert(prop_name, prop_type.unwrap_or(self.type_table.any_type));
-                        }
-                        TypeElement::IndexSignature(idx) => {
-                            let idx_type = self.get_type_from_type_annotation(idx.type_annotation);
-                            index_infos.push(IndexInfo {
-                                key_type: self.type_table.string_type,
-                                type_id: idx_type.unwrap_or(self.type_table.any_type),
-                                is_readonly: false,
-                            });
-                        }
-                        _ => {}
+            _ => false,
+        }
+    }
+
+    /// Relate the properties and index signatures of `source` whose keys an
+    /// index signature applies to, to its type.
+    fn members_related_to_index_info(&mut self, source: TypeId, target_info: &IndexInfo, relation: TypeRelation) -> bool {
+        let TypeKind::ObjectType { members, index_infos, .. } = self.type_table.get(source).kind.clone() else {
+            return false;
+        };
+        for (name, prop_type) in members {
+            if !self.is_property_name_applicable_to_index(&name, target_info.key_type) {
+                continue;
+            }
+            // Optional properties contribute their declared type to string keys.
+            let prop_type = if !self.exact_optional_property_types
+                && target_info.key_type != self.type_table.number_type
+                && self.type_table.get_property_flags(source, &name).contains(PropertyFlags::OPTIONAL)
+            {
+                self.remove_type_from_union(prop_type, self.type_table.undefined_type)
+            } else {
+                prop_type
+            };
+            if !self.is_type_related_to(prop_type, target_info.type_id, relation) {
+                return false;
+            }
+        }
+        for source_info in index_infos {
+            if self.is_applicable_index_type(source_info.key_type, target_info.key_type)
+                && !self.is_type_related_to(source_info.type_id, target_info.type_id, relation)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Relate tuples to tuples and arrays. An array source behaves like the
+    /// tuple `[...T[]]`. `None` when neither side is a tuple.
+    fn is_tuple_type_related_to(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> Option<bool> {
+        let source_tuple = match &self.type_table.get(source).kind {
+            TypeKind::Tuple { element_types, element_flags } => Some((element_types.clone(), element_flags.clone())),
+            _ => None,
+        };
+        let target_tuple = match &self.type_table.get(target).kind {
+            TypeKind::Tuple { element_types, element_flags } => Some((element_types.clone(), element_flags.clone())),
+            _ => None,
+        };
+        match (source_tuple, target_tuple) {
+            (Some((source_types, source_flags)), None) => {
+                self.get_array_readonly_flag(target)?;
+                let target_element = self.get_element_type_of_iterable(target);
+                for (i, &source_type) in source_types.iter().enumerate() {
+                    let related = match source_flags.get(i) {
+                        Some(ElementFlags::Variadic) => self.is_type_related_to(source_type, target, relation),
+                        _ => self.is_type_related_to(source_type, target_element, relation),
+                    };
+                    if !related {
+                        return Some(false);
                     }
                 }
-                self.type_table.add_type(
-                    TypeFlags::OBJECT,
-                    TypeKind::ObjectType {
-                        object_flags: ObjectFlags::ANONYMOUS,
-                        members, call_signatures: vec![], construct_signatures: vec![],
-                        index_infos,
-                    },
-                )
+                Some(true)
+            }
+            (source_tuple, Some((target_types, target_flags))) => {
+                let (source_types, source_flags) = match source_tuple {
+                    Some(tuple) => tuple,
+                    None => {
+                        self.get_array_readonly_flag(source)?;
+                        (vec![self.get_element_type_of_iterable(source)], vec![ElementFlags::Rest])
+                    }
+                };
+                Some(self.tuple_elements_related_to(&source_types, &source_flags, &target_types, &target_flags, relation))
             }
-            TypeNode::ParenthesizedType(n) => self.get_type_from_type_node(n.type_node),
-            TypeNode::LiteralType(n) => {
-                match n.literal {
-                    Expression::TrueKeyword(_) => self.type_table.true_type,
-                    Expression::FalseKeyword(_) => self.type_table.false_type,
-                    Expression::NullKeyword(_) => self.type_table.null_type,
-                    Expression::StringLiteral(_) => self.type_table.string_type,
-                    Expression::NumericLiteral(_) => self.type_table.number_type,
-                    _ => self.type_table.any_type,
-                }
+            (None, None) => None,
+        }
+    }
+
+    /// Relate the elements of two tuples. Source elements are matched to the
+    /// target's leading fixed elements from the start, to its trailing fixed
+    /// elements from the end, and to its rest element in between.
+    fn tuple_elements_related_to(
+        &mut self,
+        source_types: &[TypeId],
+        source_flags: &[ElementFlags],
+        target_types: &[TypeId],
+        target_flags: &[ElementFlags],
+        relation: TypeRelation,
+    ) -> bool {
+        let flag_at = |flags: &[ElementFlags], i: usize| flags.get(i).copied().unwrap_or(ElementFlags::Required);
+        let is_variable = |flag: ElementFlags| matches!(flag, ElementFlags::Rest | ElementFlags::Variadic);
+        let source_arity = source_types.len();
+        let target_arity = target_types.len();
+        let source_has_rest = (0..source_arity).any(|i| is_variable(flag_at(source_flags, i)));
+        let target_has_rest = (0..target_arity).any(|i| is_variable(flag_at(target_flags, i)));
+        let target_min_length = (0..target_arity).filter(|&i| flag_at(target_flags, i) == ElementFlags::Required).count();
+
+        // Source has too few elements, or may have more than the target allows.
+        if !source_has_rest && source_arity < target_min_length {
+            return false;
+        }
+        if !target_has_rest && (source_has_rest || target_arity < source_arity) {
+            return false;
+        }
+
+        let target_start = (0..target_arity).take_while(|&i| !is_variable(flag_at(target_flags, i))).count();
+        let target_end = (0..target_arity).rev().take_while(|&i| !is_variable(flag_at(target_flags, i))).count();
+        for (source_position, &source_type) in source_types.iter().enumerate() {
+            let source_flag = flag_at(source_flags, source_position);
+            let from_end = source_arity - 1 - source_position;
+            let target_position = if source_position < target_start {
+                source_position
+            } else if from_end < target_end {
+                target_arity - 1 - from_end
+            } else {
+                target_start
+            };
+            let target_flag = flag_at(target_flags, target_position);
+            if target_flag == ElementFlags::Variadic && source_flag != ElementFlags::Variadic {
+                return false;
             }
-            TypeNode::ThisType(_) => self.type_table.any_type,
-            TypeNode::TypeQuery(n) => {
-                // typeof expr — resolve the expression name to its declared type
-                let name = match &n.expr_name {
-                    EntityName::Identifier(id) => id.text_name.clone(),
-                    EntityName::QualifiedName(q) => q.right.text_name.clone(),
-                };
-                self.get_declared_type(&name).unwrap_or(self.type_table.any_type)
+            if source_flag == ElementFlags::Variadic && !is_variable(target_flag) {
+                return false;
+            }
+            if target_flag == ElementFlags::Required && source_flag != ElementFlags::Required {
+                return false;
+            }
+            let target_type = match (source_flag, target_flag) {
+                // A spread of `T` must fit in an array of the rest element type.
+                (ElementFlags::Variadic, ElementFlags::Rest) => self.create_array_type(target_types[target_position]),
+                _ => target_types[target_position],
+            };
+            if !self.is_type_related_to(source_type, target_type, relation) {
+                return false;
             }
-            TypeNode::InferType(_) => self.type_table.any_type,
-            TypeNode::MappedType(m) => self.evaluate_mapped_type(m),
-            TypeNode::TemplateLiteralType(t) => self.evaluate_template_literal_type(t),
-            _ => self.type_table.any_type,
         }
+        true
     }
 
-    fn get_type_from_keyword(&self, kind: SyntaxKind) -> TypeId {
-        match kind {
-            SyntaxKind::StringKeyword => self.type_table.string_type,
-            SyntaxKind::NumberKeyword => self.type_table.number_type,
-            SyntaxKind::BooleanKeyword => self.type_table.boolean_type,
-            SyntaxKind::AnyKeyword => self.type_table.any_type,
-            SyntaxKind::VoidKeyword => self.type_table.void_type,
-            SyntaxKind::NeverKeyword => self.type_table.never_type,
-            SyntaxKind::UndefinedKeyword => self.type_table.undefined_type,
-            SyntaxKind::NullKeyword => self.type_table.null_type,
-            SyntaxKind::UnknownKeyword => self.type_table.unknown_type,
-            SyntaxKind::ObjectKeyword => self.type_table.object_type,
-            SyntaxKind::BigIntKeyword => self.type_table.bigint_type,
-            SyntaxKind::SymbolKeyword => self.type_table.symbol_type,
-            _ => self.type_table.any_type,
+    /// Relate primitive, literal, enum, template literal and string mapping
+    /// targets, which have no structure to compare. `None` when the target is
+    /// none of these.
+    fn is_simple_type_related_to(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> Option<bool> {
+        let source_flags = self.type_table.get(source).flags;
+        let target_flags = self.type_table.get(target).flags;
+        if source_flags.intersects(TypeFlags::LITERAL) && target_flags.intersects(TypeFlags::LITERAL) {
+            return Some(self.is_literal_type_related_to(source, target, relation));
         }
+        // A literal relates to its primitive, never the other way around
+        let related = if target_flags.contains(TypeFlags::STRING) {
+            source_flags.intersects(TypeFlags::STRING_LIKE)
+        } else if target_flags.contains(TypeFlags::NUMBER) {
+            source_flags.intersects(TypeFlags::NUMBER_LIKE)
+        } else if target_flags.contains(TypeFlags::BOOLEAN) {
+            source_flags.intersects(TypeFlags::BOOLEAN_LIKE)
+        } else if target_flags.contains(TypeFlags::BIG_INT) {
+            source_flags.intersects(TypeFlags::BIG_INT_LIKE)
+        } else if target_flags.contains(TypeFlags::ES_SYMBOL) {
+            source_flags.intersects(TypeFlags::ES_SYMBOL_LIKE)
+        } else if target_flags.contains(TypeFlags::VOID) {
+            source_flags.intersects(TypeFlags::VOID_LIKE)
+        } else if target_flags.contains(TypeFlags::TEMPLATE_LITERAL) {
+            self.is_type_matched_by_template_literal(source, target, relation)
+        } else if target_flags.contains(TypeFlags::STRING_MAPPING) {
+            self.is_type_matched_by_string_mapping(source, target, relation)
+        } else if target_flags.intersects(TypeFlags::LITERAL) {
+            // `number` is assignable to numeric enum members
+            relation == TypeRelation::Assignable
+                && source_flags.contains(TypeFlags::NUMBER)
+                && target_flags.contains(TypeFlags::ENUM_LITERAL | TypeFlags::NUMBER_LITERAL)
+        } else if target_flags.contains(TypeFlags::NON_PRIMITIVE) {
+            source_flags.intersects(TypeFlags::OBJECT | TypeFlags::NON_PRIMITIVE)
+        } else {
+            return None;
+        };
+        Some(related)
     }
 
-    // ========================================================================
-    // Type utilities
-    // ========================================================================
+    /// Literals relate when they have the same value, except that an enum
+    /// member is only related to itself and to plain literals of its value.
+    fn is_literal_type_related_to(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> bool {
+        let source_flags = self.type_table.get(source).flags;
+        let target_flags = self.type_table.get(target).flags;
+        if target_flags.contains(TypeFlags::ENUM_LITERAL) {
+            if source_flags.contains(TypeFlags::ENUM_LITERAL) {
+                return self.get_regular_type_of_literal_type(source) == self.get_regular_type_of_literal_type(target);
+            }
+            // A number literal is assignable to a numeric enum member of the same value
+            return relation == TypeRelation::Assignable
+                && source_flags.contains(TypeFlags::NUMBER_LITERAL)
+                && target_flags.contains(TypeFlags::NUMBER_LITERAL)
+                && self.literal_values_equal(source, target);
+        }
+        self.literal_values_equal(source, target)
+    }
 
-    /// Create an Array<T> type with a numeric index signature.
-    fn create_array_type(&mut self, element_type: TypeId) -> TypeId {
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::empty(),
-                members: IndexMap::from([
-                    ("length".to_string(), self.type_table.number_type),
-                ]),
-                call_signatures: vec![],
-                construct_signatures: vec![],
-                index_infos: vec![IndexInfo {
-                    key_type: self.type_table.number_type,
-                    type_id: element_type,
-                    is_readonly: false,
-                }],
-            },
-        )
+    fn literal_values_equal(&self, a: TypeId, b: TypeId) -> bool {
+        match (&self.type_table.get(a).kind, &self.type_table.get(b).kind) {
+            (TypeKind::StringLiteral { value: a, .. }, TypeKind::StringLiteral { value: b, .. }) => a == b,
+            (TypeKind::NumberLiteral { value: a }, TypeKind::NumberLiteral { value: b }) => a == b,
+            (TypeKind::BigIntLiteral { value: a }, TypeKind::BigIntLiteral { value: b }) => a == b,
+            (TypeKind::BooleanLiteral { value: a }, TypeKind::BooleanLiteral { value: b }) => a == b,
+            _ => false,
+        }
     }
 
-    /// Extract the text name from a PropertyName node.
-    fn property_name_text(&self, name: &PropertyName<'_>) -> String {
-        match name {
-            PropertyName::Identifier(id) => id.text_name.clone(),
-            PropertyName::StringLiteral(_) => String::new(), // would need source text
-            PropertyName::NumericLiteral(_) => String::new(),
-            PropertyName::ComputedPropertyName(_) => String::new(), // dynamic
-            PropertyName::PrivateIdentifier(id) => id.text_name.clone(),
+    /// Whether a string literal matches the pattern of a template literal
+    /// type, as `"foo-1"` matches `` `foo-${number}` ``. A template literal
+    /// source must have the same texts and related placeholders.
+    fn is_type_matched_by_template_literal(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> bool {
+        let TypeKind::TemplateLiteral { texts, types } = self.type_table.get(target).kind.clone() else {
+            return false;
+        };
+        match self.type_table.get(source).kind.clone() {
+            TypeKind::StringLiteral { value, .. } => self.is_string_matched_by_template(&value, &texts, &types),
+            TypeKind::TemplateLiteral { texts: source_texts, types: source_types } => {
+                source_texts == texts
+                    && source_types.len() == types.len()
+                    && source_types.iter().zip(&types).all(|(&s, &t)| self.is_type_related_to(s, t, relation))
+            }
+            _ => false,
         }
     }
 
-    fn create_union_type(&mut self, types: Vec<TypeId>) -> TypeId {
-        // O(n) dedup using FxHashSet while preserving insertion order.
-        let mut seen = FxHashSet::default();
-        let unique: Vec<TypeId> = types.into_iter().filter(|t| seen.insert(*t)).collect();
-        if unique.is_empty() {
-            return self.type_table.never_type;
+    fn is_string_matched_by_template(&self, value: &str, texts: &[String], types: &[TypeId]) -> bool {
+        value.strip_prefix(texts[0].as_str())
+            .is_some_and(|rest| self.is_string_matched_by_template_spans(rest, &texts[1..], types))
+    }
+
+    /// Match the rest of a string against placeholders, each followed by its
+    /// text, trying every split point for each placeholder.
+    fn is_string_matched_by_template_spans(&self, value: &str, texts: &[String], types: &[TypeId]) -> bool {
+        let Some((&placeholder, rest_types)) = types.split_first() else {
+            return value.is_empty();
+        };
+        let text = texts[0].as_str();
+        value.char_indices().map(|(i, _)| i).chain(std::iter::once(value.len())).any(|i| {
+            let rest = &value[i..];
+            rest.starts_with(text)
+                && (!rest_types.is_empty() || rest.len() == text.len())
+                && self.is_value_of_placeholder_type(&value[..i], placeholder)
+                && self.is_string_matched_by_template_spans(&rest[text.len()..], &texts[1..], rest_types)
+        })
+    }
+
+    /// Whether a piece of a string literal is accepted by a template literal
+    /// placeholder of the given type.
+    fn is_value_of_placeholder_type(&self, value: &str, placeholder: TypeId) -> bool {
+        let ty = self.type_table.get(placeholder);
+        if ty.flags.intersects(TypeFlags::ANY | TypeFlags::STRING) {
+            return true;
         }
-        if unique.len() == 1 {
-            return unique[0];
+        match &ty.kind {
+            TypeKind::Union { types } => types.iter().any(|&t| self.is_value_of_placeholder_type(value, t)),
+            TypeKind::TemplateLiteral { texts, types } => self.is_string_matched_by_template(value, texts, types),
+            TypeKind::StringMapping { mapping, target } => {
+                mapping.apply(value) == value && self.is_value_of_placeholder_type(value, *target)
+            }
+            _ if ty.flags.contains(TypeFlags::NUMBER) => is_valid_number_string(value),
+            _ if ty.flags.contains(TypeFlags::BIG_INT) => is_valid_bigint_string(value),
+            _ if ty.flags.contains(TypeFlags::BOOLEAN) => value == "true" || value == "false",
+            _ => self.get_template_string_for_type(placeholder).is_some_and(|text| text == value),
         }
-        self.type_table.add_type(
-            TypeFlags::UNION,
-            TypeKind::Union { types: unique },
-        )
     }
 
-    fn create_intersection_type(&mut self, types: Vec<TypeId>) -> TypeId {
-        let mut seen = FxHashSet::default();
-        let unique: Vec<TypeId> = types.into_iter().filter(|t| seen.insert(*t)).collect();
-        if unique.is_empty() {
-            return self.type_table.any_type;
+    /// Whether a type is matched by `Uppercase<T>` and friends: a string
+    /// literal that the mapping leaves unchanged, or the same mapping of a
+    /// related type.
+    fn is_type_matched_by_string_mapping(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> bool {
+        let TypeKind::StringMapping { mapping, target: mapped } = self.type_table.get(target).kind else {
+            return false;
+        };
+        match self.type_table.get(source).kind.clone() {
+            TypeKind::StringLiteral { value, .. } => {
+                mapping.apply(&value) == value && self.is_type_related_to(source, mapped, relation)
+            }
+            TypeKind::StringMapping { mapping: source_mapping, target: source_mapped } if source_mapping == mapping => {
+                self.is_type_related_to(source_mapped, mapped, relation)
+            }
+            _ => false,
         }
-        if unique.len() == 1 { return unique[0]; }
-        self.type_table.add_type(
-            TypeFlags::INTERSECTION,
-            TypeKind::Intersection { types: unique },
+    }
+
+    fn is_fresh_object_literal(&self, type_id: TypeId) -> bool {
+        matches!(
+            &self.type_table.get(type_id).kind,
+            TypeKind::ObjectType { object_flags, .. } if object_flags.contains(ObjectFlags::FRESH_LITERAL)
         )
     }
 
-    fn get_non_nullable_type(&mut self, type_id: TypeId) -> TypeId {
-        let ty = self.type_table.get(type_id);
-        if ty.flags.contains(TypeFlags::NULL) || ty.flags.contains(TypeFlags::UNDEFINED) {
-            return self.type_table.never_type;
+    /// The same object literal type without freshness, so that it is no longer
+    /// subject to excess property checks.
+    fn get_regular_type_of_object_literal(&mut self, type_id: TypeId) -> TypeId {
+        if !self.is_fresh_object_literal(type_id) {
+            return type_id;
         }
-        if let TypeKind::Union { types } = &ty.kind {
-            let filtered: Vec<TypeId> = types.iter()
-                .filter(|&&t| {
-                    let inner = self.type_table.get(t);
-                    !inner.flags.contains(TypeFlags::NULL) && !inner.flags.contains(TypeFlags::UNDEFINED)
-                })
-                .copied()
-                .collect();
-            if filtered.is_empty() {
-                return self.type_table.never_type;
+        if let Some(&regular) = self.regular_types.get(&type_id) {
+            return regular;
+        }
+        let mut ty = self.type_table.get(type_id).clone();
+        if let TypeKind::ObjectType { object_flags, .. } = &mut ty.kind {
+            object_flags.remove(ObjectFlags::FRESH_LITERAL);
+        }
+        let regular = self.type_table.add_type(ty.flags, ty.kind);
+        self.type_table.copy_property_flags(type_id, regular);
+        self.regular_types.insert(type_id, regular);
+        regular
+    }
+
+    /// The type a declaration without a type annotation gets from its
+    /// initializer: object literals are no longer fresh and have their literal
+    /// property types widened, and array literals have their literal element
+    /// types widened. A top-level literal is left to `widen_type`.
+    fn get_widened_type(&mut self, type_id: TypeId) -> TypeId {
+        let TypeKind::ObjectType { object_flags, members, call_signatures, construct_signatures, index_infos } =
+            self.type_table.get(type_id).kind.clone()
+        else {
+            return type_id;
+        };
+        if let Some(is_readonly) = self.get_array_readonly_flag(type_id) {
+            let element_type = index_infos[0].type_id;
+            let widened = self.widen_type(element_type);
+            let widened = self.get_widened_type(widened);
+            if widened == element_type {
+                return type_id;
+            }
+            return self.create_array_type_with_readonly(widened, is_readonly);
+        }
+        if !object_flags.contains(ObjectFlags::OBJECT_LITERAL) {
+            return type_id;
+        }
+        let widened_members: IndexMap<String, TypeId> = members.iter()
+            .map(|(name, &member)| {
+                let member = self.widen_type(member);
+                (name.clone(), self.get_widened_type(member))
+            })
+            .collect();
+        if widened_members == members {
+            return self.get_regular_type_of_object_literal(type_id);
+        }
+        let widened = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: object_flags - ObjectFlags::FRESH_LITERAL,
+                members: widened_members,
+                call_signatures,
+                construct_signatures,
+                index_infos,
+            },
+        );
+        self.type_table.copy_property_flags(type_id, widened);
+        widened
+    }
+
+    /// Under exactOptionalPropertyTypes, whether `source` gives `undefined` to
+    /// an optional property of `target` that does not accept it.
+    fn has_explicit_undefined_for_exact_optional_property(&mut self, source: TypeId, target: TypeId) -> bool {
+        if !self.exact_optional_property_types {
+            return false;
+        }
+        let (TypeKind::ObjectType { members: source_members, .. }, TypeKind::ObjectType { members: target_members, .. }) =
+            (&self.type_table.get(source).kind, &self.type_table.get(target).kind)
+        else {
+            return false;
+        };
+        target_members.iter().any(|(name, &target_prop)| {
+            source_members.get(name).is_some_and(|&source_prop| {
+                self.type_table.get_property_flags(target, name).contains(PropertyFlags::OPTIONAL)
+                    && self.type_contains_undefined(source_prop)
+                    && !self.type_contains_undefined(target_prop)
+            })
+        })
+    }
+
+    /// Under exactOptionalPropertyTypes, whether assigning `source` to the
+    /// optional property `name` of `object_type`, of type `target`, fails
+    /// because `source` includes `undefined` (TS2412).
+    fn is_explicit_undefined_for_exact_optional_property(&mut self, object_type: TypeId, name: &str, source: TypeId, target: TypeId) -> bool {
+        self.exact_optional_property_types
+            && self.type_table.get_property_flags(object_type, name).contains(PropertyFlags::OPTIONAL)
+            && self.type_contains_undefined(source)
+            && !self.is_type_assignable_to(source, target)
+    }
+
+    fn type_contains_undefined(&self, type_id: TypeId) -> bool {
+        self.get_union_constituents(type_id).iter()
+            .any(|&t| self.type_table.get(t).flags.contains(TypeFlags::UNDEFINED))
+    }
+
+    /// TS2353 for the first property of a fresh object literal that the target does not declare.
+    fn get_excess_property_error(&mut self, source: TypeId, target: TypeId) -> Option<Diagnostic> {
+        if !self.is_fresh_object_literal(source) {
+            return None;
+        }
+        let name = self.find_excess_property(source, target)?;
+        let target_type = match self.type_table.get(target).kind.clone() {
+            TypeKind::Union { types } => self.find_matching_discriminant_type(source, &types).unwrap_or(target),
+            _ => target,
+        };
+        let target_name = self.format_type(target_type);
+        Some(Diagnostic::new(
+            &messages::OBJECT_LITERAL_MAY_ONLY_SPECIFY_KNOWN_PROPERTIES_AND_0_DOES_NOT_EXIST_IN_TYPE_1,
+            &[&name, &target_name],
+        ))
+    }
+
+    /// The first property of `source` that is not known in `target`. A union
+    /// target is narrowed to the member selected by the source's discriminants.
+    fn find_excess_property(&mut self, source: TypeId, target: TypeId) -> Option<String> {
+        if !self.is_excess_property_check_target(target) {
+            return None;
+        }
+        let target = match self.type_table.get(target).kind.clone() {
+            TypeKind::Union { types } => self.find_matching_discriminant_type(source, &types).unwrap_or(target),
+            _ => target,
+        };
+        let TypeKind::ObjectType { members, .. } = &self.type_table.get(source).kind else {
+            return None;
+        };
+        members.keys().find(|name| !self.is_known_property(target, name)).cloned()
+    }
+
+    /// Object types with a fixed set of properties. Empty object types and
+    /// `object` accept anything, and type parameters are not checked.
+    fn is_excess_property_check_target(&self, type_id: TypeId) -> bool {
+        match &self.type_table.get(type_id).kind {
+            TypeKind::ObjectType { members, index_infos, call_signatures, construct_signatures, .. } => {
+                !(members.is_empty() && index_infos.is_empty() && call_signatures.is_empty() && construct_signatures.is_empty())
+            }
+            TypeKind::Union { types } => types.iter().any(|&t| self.is_excess_property_check_target(t)),
+            TypeKind::Intersection { types } => types.iter().all(|&t| self.is_excess_property_check_target(t)),
+            _ => false,
+        }
+    }
+
+    fn is_known_property(&self, target: TypeId, name: &str) -> bool {
+        match &self.type_table.get(target).kind {
+            TypeKind::ObjectType { members, index_infos, .. } => {
+                members.contains_key(name)
+                    || index_infos.iter().any(|info| self.is_property_name_applicable_to_index(name, info.key_type))
             }
-            if filtered.len() == 1 {
-                return filtered[0];
+            TypeKind::Union { types } | TypeKind::Intersection { types } => {
+                types.iter().any(|&t| self.is_excess_property_check_target(t) && self.is_known_property(t, name))
             }
-            return self.create_union_type(filtered);
+            _ => false,
         }
-        type_id
     }
 
-    /// Get the element type of an iterable type (e.g., `T[]` -> `T`).
-    fn get_element_type_of_iterable(&self, iterable: TypeId) -> TypeId {
-        let ty = self.type_table.get(iterable);
-        match &ty.kind {
-            TypeKind::ObjectType { index_infos, .. } => {
-                // Array-like: has a numeric index signature
-                for info in index_infos {
-                    if info.key_type == self.type_table.number_type {
-                        return info.type_id;
-                    }
+    /// The single member of a union target whose discriminant properties (ones
+    /// with literal types in every object member) match the source's values.
+    fn find_matching_discriminant_type(&mut self, source: TypeId, target_types: &[TypeId]) -> Option<TypeId> {
+        let TypeKind::ObjectType { members: source_members, .. } = self.type_table.get(source).kind.clone() else {
+            return None;
+        };
+        for (name, source_prop) in source_members {
+            if !self.type_table.get(source_prop).flags.intersects(TypeFlags::UNIT) {
+                continue;
+            }
+            let prop_types: Vec<Option<TypeId>> = target_types.iter()
+                .map(|&t| match &self.type_table.get(t).kind {
+                    TypeKind::ObjectType { members, .. } => members.get(&name).copied(),
+                    _ => None,
+                })
+                .collect();
+            let is_discriminant = prop_types.iter().all(|p| p.is_some_and(|t| self.could_contain_literal_type(t)));
+            if !is_discriminant {
+                continue;
+            }
+            let mut matches = Vec::new();
+            for (&target_type, prop_type) in target_types.iter().zip(prop_types) {
+                if prop_type.is_some_and(|t| self.is_type_assignable_to(source_prop, t)) {
+                    matches.push(target_type);
                 }
-                self.type_table.any_type
             }
-            TypeKind::TypeReference { type_arguments, .. } => {
-                // Generic types like Array<T> — return the first type argument
-                if let Some(first) = type_arguments.first() {
-                    return *first;
-                }
-                self.type_table.any_type
+            if let [matched] = matches.as_slice() {
+                return Some(*matched);
             }
-            _ => self.type_table.any_type,
         }
+        None
     }
 
-    fn type_to_string(&self, type_id: TypeId) -> String {
-        self.type_to_string_inner(type_id, 0)
+    /// Each call signature of the target must be matched by some call
+    /// signature of the source.
+    fn signatures_related_to(&mut self, source: TypeId, target: TypeId, relation: TypeRelation) -> bool {
+        let (source_sigs, target_sigs) = match (&self.type_table.get(source).kind, &self.type_table.get(target).kind) {
+            (
+                TypeKind::ObjectType { call_signatures: source_sigs, .. },
+                TypeKind::ObjectType { call_signatures: target_sigs, .. },
+            ) if !target_sigs.is_empty() => (source_sigs.clone(), target_sigs.clone()),
+            _ => return true,
+        };
+        target_sigs.iter().all(|target_sig| {
+            source_sigs.iter().any(|source_sig| self.signature_related_to(source_sig, target_sig, relation))
+        })
     }
 
-    fn type_to_string_inner(&self, type_id: TypeId, depth: u32) -> String {
-        if depth > MAX_TYPE_TO_STRING_DEPTH {
-            return "...".to_string();
+    /// Compare two signatures. Return types are compared covariantly; parameters
+    /// contravariantly under `strictFunctionTypes`, bivariantly otherwise and
+    /// when the target is a method. The source may not require more arguments
+    /// than the target supplies.
+    fn signature_related_to(&mut self, source: &Signature, target: &Signature, relation: TypeRelation) -> bool {
+        // A generic source is first instantiated in the context of the target.
+        let instantiated;
+        let source = if !source.type_parameters.is_empty() && target.type_parameters.is_empty() {
+            instantiated = self.instantiate_signature_in_context_of(source, target);
+            &instantiated
+        } else {
+            source
+        };
+        let target_params = self.get_effective_parameters(target);
+        if target_params.rest.is_none() && source.min_argument_count as usize > target_params.types.len() {
+            return false;
         }
-        let ty = self.type_table.get(type_id);
-        match &ty.kind {
-            TypeKind::Intrinsic { name } => name.to_string(),
-            TypeKind::BooleanLiteral { value } => value.to_string(),
-            TypeKind::StringLiteral { value, .. } => format!("\"{}\"", value),
-            TypeKind::NumberLiteral { value } => value.to_string(),
-            TypeKind::Union { types } => {
-                types.iter()
-                    .map(|t| self.type_to_string_inner(*t, depth + 1))
-                    .collect::<Vec<_>>()
-                    .join(" | ")
+        if let (Some(source_this), Some(target_this)) = (source.this_type, target.this_type) {
+            if !self.type_table.get(target_this).flags.contains(TypeFlags::VOID)
+                && !self.is_type_related_to(source_this, target_this, relation)
+                && !self.is_type_related_to(target_this, source_this, relation)
+            {
+                return false;
             }
-            TypeKind::Intersection { types } => {
-                types.iter()
-                    .map(|t| self.type_to_string_inner(*t, depth + 1))
-                    .collect::<Vec<_>>()
-                    .join(" & ")
-            }
-            TypeKind::ObjectType { index_infos, members, call_signatures, .. } => {
-                // Check if it's an array type (has number index signature)
-                if let Some(idx) = index_infos.first() {
-                    if self.type_table.get(idx.key_type).flags.intersects(TypeFlags::NUMBER_LIKE) {
-                        return format!("{}[]", self.type_to_string_inner(idx.type_id, depth + 1));
-                    }
-                }
-                // Check if it's a function type (has call signatures, no members)
-                if !call_signatures.is_empty() && members.is_empty() {
-                    let sig = &call_signatures[0];
-                    let params = sig.parameters.iter()
-                        .map(|p| {
-                            let t = self.type_to_string_inner(p.type_id, depth + 1);
-                            if p.name.is_empty() { t } else { format!("{}: {}", p.name, t) }
-                        })
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    let ret = self.type_to_string_inner(sig.return_type, depth + 1);
-                    return format!("({}) => {}", params, ret);
-                }
-                // Object type with members
-                if members.is_empty() {
-                    "{}".to_string()
-                } else {
-                    let props = members.iter()
-                        .map(|(name, tid)| format!("{}: {}", name, self.type_to_string_inner(*tid, depth + 1)))
-                        .collect::<Vec<_>>()
-                        .join("; ");
-                    format!("{{ {} }}", props)
-                }
+        }
+        let source_params = self.get_effective_parameters(source);
+        let strict_variance = self.strict_function_types && !target.is_method;
+        let count = target_params.types.len().max(source_params.types.len());
+        for i in 0..count {
+            let (Some(source_param), Some(target_param)) = (source_params.type_at(i), target_params.type_at(i)) else {
+                break;
+            };
+            let related = self.is_type_related_to(target_param, source_param, relation)
+                || (!strict_variance && self.is_type_related_to(source_param, target_param, relation));
+            if !related {
+                return false;
             }
-            TypeKind::Tuple { element_types, .. } => {
-                let elems = element_types.iter()
-                    .map(|t| self.type_to_string_inner(*t, depth + 1))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("[{}]", elems)
+        }
+        if let Some(target_predicate) = &target.type_predicate {
+            if let Some(source_predicate) = &source.type_predicate {
+                return self.type_predicate_related_to(source_predicate, target_predicate, relation);
             }
-            TypeKind::TypeParameter { .. } => "T".to_string(),
-            _ => "any".to_string(),
+            // Only a type guard can stand in for a type guard.
+            if !target_predicate.is_assertion() {
+                return false;
+            }
+        }
+        let target_return = target.return_type;
+        self.type_table.get(target_return).flags.contains(TypeFlags::VOID)
+            || self.is_type_related_to(source.return_type, target_return, relation)
+    }
+
+    /// Whether a source type predicate narrows the same parameter, in the same
+    /// way, to a type related to the target predicate's type.
+    fn type_predicate_related_to(&mut self, source: &TypePredicate, target: &TypePredicate, relation: TypeRelation) -> bool {
+        if source.kind != target.kind || source.parameter_index != target.parameter_index {
+            return false;
+        }
+        match (source.type_id, target.type_id) {
+            (Some(source_type), Some(target_type)) => self.is_type_related_to(source_type, target_type, relation),
+            (source_type, target_type) => source_type == target_type,
         }
     }
 
+    /// Instantiate a generic signature with the type arguments inferred from
+    /// the parameters of a signature it is compared or passed to.
+    fn instantiate_signature_in_context_of(&mut self, sig: &Signature, context: &Signature) -> Signature {
+        let mut inferences: Vec<Option<TypeId>> = vec![None; sig.type_parameters.len()];
+        for (context_param, param) in context.parameters.iter().zip(&sig.parameters) {
+            self.infer_from_types(context_param.type_id, param.type_id, &sig.type_parameters, &mut inferences);
+        }
+        let type_args: Vec<TypeId> = inferences.iter().zip(&sig.type_parameters)
+            .map(|(&inferred, &tp)| inferred.unwrap_or_else(|| match self.type_table.get(tp).kind {
+                TypeKind::TypeParameter { constraint: Some(constraint), .. } => constraint,
+                _ => self.type_table.unknown_type,
+            }))
+            .collect();
+        let mut instantiated = self.substitute_signature(sig, &sig.type_parameters, &type_args);
+        instantiated.type_parameters.clear();
+        instantiated
+    }
+
     // ========================================================================
-    // Type relationship checking
+    // Advanced type operations
     // ========================================================================
 
-    pub fn is_type_assignable_to(&mut self, source: TypeId, target: TypeId) -> bool {
-        if source == target { return true; }
+    /// Widen a fresh literal type to its base type, for mutable locations:
+    /// `let x = "hello"` is a `string` and `let e = E.A` is an `E`. Literal
+    /// types written in annotations or produced by `as const` are regular and
+    /// are kept.
+    fn widen_type(&mut self, type_id: TypeId) -> TypeId {
+        if self.is_fresh_literal_type(type_id) {
+            return self.get_base_type_of_literal_type(type_id);
+        }
+        if self.type_table.get(type_id).flags.contains(TypeFlags::UNIQUE_ES_SYMBOL) {
+            return self.type_table.symbol_type;
+        }
+        if let TypeKind::Union { types } = &self.type_table.get(type_id).kind {
+            let types = types.clone();
+            let widened: Vec<TypeId> = types.iter().map(|&t| self.widen_type(t)).collect();
+            if widened != types {
+                return self.create_union_type(widened);
+            }
+        }
+        type_id
+    }
 
-        // Check cache — O(1) for repeated checks & breaks cycles
-        if let Some(&result) = self.assignability_cache.get(&(source, target)) {
-            return result;
+    /// Extract member names from an object type.
+    fn get_object_member_names(&self, type_id: TypeId) -> Vec<String> {
+        let ty = self.type_table.get(type_id);
+        match &ty.kind {
+            TypeKind::ObjectType { members, .. } => {
+                members.keys().cloned().collect()
+            }
+            _ => vec![],
         }
+    }
 
-        // Insert optimistic sentinel before recursing.
-        // If a cycle re-enters this (source, target) pair, it returns `true`
-        // (assumes assignable), which is the correct conservative answer for
-        // recursive / circular types.
-        self.assignability_cache.insert((source, target), true);
+    /// Resolve a mapped type node `{ readonly [P in C as N]?: X }`.
+    /// The result is an object type, or a deferred `Mapped` type while the
+    /// constraint still depends on type parameters.
+    fn evaluate_mapped_type(&mut self, node: &MappedTypeNode<'_>) -> TypeId {
+        // Get constraint type (the type we iterate keys from).
+        // `keyof T` is kept unresolved so that T's modifiers are preserved.
+        let constraint_type = match node.type_parameter.constraint {
+            Some(TypeNode::TypeOperator(op)) if op.operator == SyntaxKind::KeyOfKeyword => {
+                let target = self.get_type_from_type_node(op.type_node);
+                self.create_index_type(target)
+            }
+            Some(constraint) => self.get_type_from_type_node(constraint),
+            None => return self.type_table.any_type, // No constraint, can't evaluate
+        };
 
-        let result = self.is_type_assignable_to_worker(source, target);
+        let param_name = node.type_parameter.name.text_name.clone();
+        let type_parameter = self.create_type_parameter(&param_name, Some(constraint_type));
+        let shadowed = self.get_declared_type(&param_name);
+        self.register_type(&param_name, type_parameter);
+        let name_type = node.name_type.map(|t| self.get_type_from_type_node(t));
+        let template_type = node.type_node.map(|t| self.get_type_from_type_node(t));
+        self.pop_type_parameters(vec![(param_name, shadowed)]);
+
+        let modifier = |token: &Option<Token>| match token.as_ref().map(|t| t.data.kind) {
+            Some(SyntaxKind::MinusToken) => MappedTypeModifier::Remove,
+            Some(_) => MappedTypeModifier::Add,
+            None => MappedTypeModifier::None,
+        };
+        let modifiers_type = self.get_modifiers_type_of_constraint(constraint_type);
+        self.create_mapped_type(
+            type_parameter,
+            constraint_type,
+            name_type,
+            template_type,
+            modifiers_type,
+            modifier(&node.readonly_token),
+            modifier(&node.question_token),
+        )
+    }
 
-        // Update cache with actual result
-        self.assignability_cache.insert((source, target), result);
-        result
+    /// The type whose property modifiers a mapped type preserves: `T` for a
+    /// constraint `keyof T`, or for a type parameter constrained to `keyof T`
+    /// (as in `Pick<T, K extends keyof T>`).
+    fn get_modifiers_type_of_constraint(&self, constraint_type: TypeId) -> Option<TypeId> {
+        match &self.type_table.get(constraint_type).kind {
+            TypeKind::Index { target } => Some(*target),
+            TypeKind::TypeParameter { constraint: Some(c), .. } => match &self.type_table.get(*c).kind {
+                TypeKind::Index { target } => Some(*target),
+                _ => None,
+            },
+            _ => None,
+        }
     }
 
-    /// Core assignability logic.  Separated from the public entry point so that
-    /// the cache look-up / cycle-sentinel lives in one place.
-    fn is_type_assignable_to_worker(&mut self, source: TypeId, target: TypeId) -> bool {
-        // Extract flags upfront (TypeFlags is Copy, no borrow held)
-        let target_flags = self.type_table.get(target).flags;
-        let source_flags = self.type_table.get(source).flags;
+    /// Create a mapped type and resolve it unless its constraint is generic.
+    #[allow(clippy::too_many_arguments)]
+    fn create_mapped_type(
+        &mut self,
+        type_parameter: TypeId,
+        constraint_type: TypeId,
+        name_type: Option<TypeId>,
+        template_type: Option<TypeId>,
+        modifiers_type: Option<TypeId>,
+        readonly_modifier: MappedTypeModifier,
+        optional_modifier: MappedTypeModifier,
+    ) -> TypeId {
+        let mapped = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::Mapped {
+                type_parameter,
+                constraint_type,
+                name_type,
+                template_type,
+                modifiers_type,
+                readonly_modifier,
+                optional_modifier,
+            },
+        );
+        self.resolve_mapped_type(mapped)
+    }
 
-        if target_flags.contains(TypeFlags::ANY) || target_flags.contains(TypeFlags::UNKNOWN) {
-            return true;
+    /// Expand a mapped type into an object type, array or tuple.
+    /// Returns the mapped type itself when its keys are not yet known.
+    fn resolve_mapped_type(&mut self, mapped: TypeId) -> TypeId {
+        let TypeKind::Mapped {
+            type_parameter, constraint_type, name_type, template_type, modifiers_type,
+            readonly_modifier, optional_modifier,
+        } = self.type_table.get(mapped).kind.clone() else {
+            return mapped;
+        };
+
+        // `{ [P in keyof T]: X }` is homomorphic in T.
+        let homomorphic_source = match self.type_table.get(constraint_type).kind {
+            TypeKind::Index { target } => Some(target),
+            _ => None,
+        };
+        if self.is_generic_type(homomorphic_source.unwrap_or(constraint_type)) {
+            return mapped;
+        }
+        let template = template_type.unwrap_or(self.type_table.any_type);
+
+        let mut keys: Vec<TypeId> = Vec::new();
+        if let Some(source) = homomorphic_source {
+            let source_flags = self.type_table.get(source).flags;
+            if source_flags.intersects(TypeFlags::PRIMITIVE) && !source_flags.contains(TypeFlags::ANY) {
+                // Homomorphic mapped types over primitives produce the primitive.
+                return source;
+            }
+            if name_type.is_none() {
+                if let Some(tuple) = self.map_tuple_type(source, type_parameter, template, optional_modifier) {
+                    return tuple;
+                }
+                if let Some(is_readonly) = self.get_array_readonly_flag(source) {
+                    let number_type = self.type_table.number_type;
+                    let mut element_type = self.substitute_type_by_id(template, &[type_parameter], &[number_type]);
+                    if optional_modifier == MappedTypeModifier::Remove {
+                        element_type = self.remove_type_from_union(element_type, self.type_table.undefined_type);
+                    }
+                    let is_readonly = match readonly_modifier {
+                        MappedTypeModifier::Add => true,
+                        MappedTypeModifier::Remove => false,
+                        MappedTypeModifier::None => is_readonly,
+                    };
+                    return self.create_array_type_with_readonly(element_type, is_readonly);
+                }
+            }
+            if source_flags.contains(TypeFlags::ANY) {
+                keys.push(self.type_table.string_type);
+            }
+            for name in self.get_object_member_names(source) {
+                keys.push(self.get_literal_type_from_property_name(&name));
+            }
+            if let TypeKind::ObjectType { index_infos, .. } = &self.type_table.get(source).kind {
+                keys.extend(index_infos.iter().map(|info| info.key_type));
+            }
+        } else {
+            keys = self.get_union_constituents(constraint_type);
         }
-        if source_flags.contains(TypeFlags::ANY) { return true; }
-        if source_flags.contains(TypeFlags::NEVER) { return true; }
 
-        // null/undefined assignability
-        if !self.strict_null_checks
-            && (source_flags.contains(TypeFlags::NULL) || source_flags.contains(TypeFlags::UNDEFINED)) {
-                return true;
+        let mut members: IndexMap<String, TypeId> = IndexMap::new();
+        let mut member_flags: Vec<(String, PropertyFlags)> = Vec::new();
+        let mut index_infos: Vec<IndexInfo> = Vec::new();
+        for key in keys {
+            let key_name = self.get_literal_key_name(key);
+            let mut flags = match (modifiers_type, &key_name) {
+                (Some(source), Some(name)) => self.type_table.get_property_flags(source, name),
+                _ => PropertyFlags::empty(),
+            };
+            match optional_modifier {
+                MappedTypeModifier::Add => flags.insert(PropertyFlags::OPTIONAL),
+                MappedTypeModifier::Remove => flags.remove(PropertyFlags::OPTIONAL),
+                MappedTypeModifier::None => {}
+            }
+            match readonly_modifier {
+                MappedTypeModifier::Add => flags.insert(PropertyFlags::READONLY),
+                MappedTypeModifier::Remove => flags.remove(PropertyFlags::READONLY),
+                MappedTypeModifier::None => {}
             }
 
-        // Union target: source must be assignable to at least one constituent.
-        // Clone the types vec to release the borrow before recursive calls.
-        if let TypeKind::Union { types } = &self.type_table.get(target).kind {
-            let target_types = types.clone();
-            for t in target_types {
-                if self.is_type_assignable_to(source, t) {
-                    return true;
+            let mut prop_type = self.substitute_type_by_id(template, &[type_parameter], &[key]);
+            if flags.contains(PropertyFlags::OPTIONAL) {
+                prop_type = self.add_optionality(prop_type);
+            } else if optional_modifier == MappedTypeModifier::Remove {
+                prop_type = self.remove_type_from_union(prop_type, self.type_table.undefined_type);
+            }
+
+            // `as` clauses remap each key; keys remapped to `never` are dropped.
+            let names = match name_type {
+                Some(name_type) => {
+                    let remapped = self.substitute_type_by_id(name_type, &[type_parameter], &[key]);
+                    self.get_union_constituents(remapped)
+                }
+                None => vec![key],
+            };
+            for name in names {
+                if let Some(prop_name) = self.get_literal_key_name(name) {
+                    member_flags.push((prop_name.clone(), flags));
+                    members.insert(prop_name, prop_type);
+                } else if self.is_valid_index_key_type(name) {
+                    index_infos.push(IndexInfo {
+                        key_type: name,
+                        type_id: prop_type,
+                        is_readonly: flags.contains(PropertyFlags::READONLY),
+                    });
                 }
             }
-            return false;
         }
 
-        // Union source: each constituent must be assignable to target
-        if let TypeKind::Union { types } = &self.type_table.get(source).kind {
-            let source_types = types.clone();
-            return source_types.iter().all(|&t| self.is_type_assignable_to(t, target));
+        let resolved = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::ObjectType {
+                object_flags: ObjectFlags::ANONYMOUS | ObjectFlags::MAPPED,
+                members,
+                call_signatures: vec![],
+                construct_signatures: vec![],
+                index_infos,
+            },
+        );
+        for (member_name, flags) in member_flags {
+            self.type_table.set_property_flags(resolved, &member_name, flags);
         }
+        resolved
+    }
 
-        // Intersection source: any constituent assignable to target is sufficient
-        if let TypeKind::Intersection { types } = &self.type_table.get(source).kind {
-            let source_types = types.clone();
-            for t in source_types {
-                if self.is_type_assignable_to(t, target) { return true; }
+    /// Map a homomorphic mapped type over a tuple element-wise, keeping the
+    /// tuple shape. Returns `None` when `source` is not a tuple.
+    fn map_tuple_type(
+        &mut self,
+        source: TypeId,
+        type_parameter: TypeId,
+        template: TypeId,
+        optional_modifier: MappedTypeModifier,
+    ) -> Option<TypeId> {
+        let TypeKind::Tuple { element_types, element_flags } = self.type_table.get(source).kind.clone() else {
+            return None;
+        };
+        let mut new_types = Vec::with_capacity(element_types.len());
+        let mut new_flags = Vec::with_capacity(element_types.len());
+        for i in 0..element_types.len() {
+            let flag = element_flags.get(i).copied().unwrap_or(ElementFlags::Required);
+            let key = match flag {
+                ElementFlags::Rest | ElementFlags::Variadic => self.type_table.number_type,
+                _ => self.create_string_literal_type(i.to_string()),
+            };
+            let mut element_type = self.substitute_type_by_id(template, &[type_parameter], &[key]);
+            let flag = match (flag, optional_modifier) {
+                (ElementFlags::Required, MappedTypeModifier::Add) => ElementFlags::Optional,
+                (ElementFlags::Optional, MappedTypeModifier::Remove) => ElementFlags::Required,
+                (flag, _) => flag,
+            };
+            if optional_modifier == MappedTypeModifier::Remove {
+                element_type = self.remove_type_from_union(element_type, self.type_table.undefined_type);
             }
+            new_types.push(element_type);
+            new_flags.push(flag);
+        }
+        let mapped = self.type_table.add_type(
+            TypeFlags::OBJECT,
+            TypeKind::Tuple { element_types: new_types, element_flags: new_flags },
+        );
+        if let Some(labels) = self.type_table.get_tuple_labels(source).map(|l| l.to_vec()) {
+            self.type_table.set_tuple_labels(mapped, labels);
         }
+        Some(mapped)
+    }
 
-        // Same primitive type
-        if source_flags.intersects(TypeFlags::STRING_LIKE) && target_flags.intersects(TypeFlags::STRING_LIKE) { return true; }
-        if source_flags.intersects(TypeFlags::NUMBER_LIKE) && target_flags.intersects(TypeFlags::NUMBER_LIKE) { return true; }
-        if source_flags.intersects(TypeFlags::BOOLEAN_LIKE) && target_flags.intersects(TypeFlags::BOOLEAN_LIKE) { return true; }
-        if source_flags.contains(TypeFlags::BIG_INT) && target_flags.contains(TypeFlags::BIG_INT) { return true; }
-        if source_flags.contains(TypeFlags::ES_SYMBOL) && target_flags.contains(TypeFlags::ES_SYMBOL) { return true; }
-        if source_flags.contains(TypeFlags::VOID) && target_flags.contains(TypeFlags::VOID) { return true; }
+    /// Create a deferred `keyof T`, used for generic T and for the constraint
+    /// of homomorphic mapped types.
+    fn create_index_type(&mut self, target: TypeId) -> TypeId {
+        self.type_table.add_type(TypeFlags::INDEX, TypeKind::Index { target })
+    }
 
-        // Structural type checking for object types.
-        // IndexMap members provide O(1) property lookup per target member.
-        let member_pairs: Option<Vec<(TypeId, TypeId)>> = {
-            let source_type = self.type_table.get(source);
-            let target_type = self.type_table.get(target);
-            match (&source_type.kind, &target_type.kind) {
-                (
-                    TypeKind::ObjectType { members: source_members, .. },
-                    TypeKind::ObjectType { members: target_members, .. },
-                ) => {
-                    // O(1) lookup per target member via IndexMap
-                    let mut pairs = Vec::new();
-                    for (target_name, target_prop_type) in target_members {
-                        if let Some(&source_prop_type) = source_members.get(target_name) {
-                            pairs.push((source_prop_type, *target_prop_type));
-                        } else {
-                            // Missing property — not assignable
-                            return false;
+    /// Resolve `keyof T`: a union of T's property keys, or a deferred index
+    /// type when T is generic. Numeric property names give number literals,
+    /// symbol-keyed members their unique symbol, and index signatures their
+    /// key type (a string index also admits numbers). Under keyofStringsOnly
+    /// only string keys are produced.
+    fn get_index_type(&mut self, target: TypeId) -> TypeId {
+        if self.is_generic_type(target) {
+            return self.create_index_type(target);
+        }
+        let flags = self.type_table.get(target).flags;
+        if flags.intersects(TypeFlags::ANY | TypeFlags::NEVER) {
+            return if self.keyof_strings_only {
+                self.type_table.string_type
+            } else {
+                self.create_union_type(vec![self.type_table.string_type, self.type_table.number_type, self.type_table.symbol_type])
+            };
+        }
+        let keys = match self.type_table.get(target).kind.clone() {
+            // Only the keys every constituent has
+            TypeKind::Union { types } => {
+                let key_sets: Vec<TypeId> = types.iter().map(|&t| self.get_index_type(t)).collect();
+                let mut common = self.get_union_constituents(key_sets[0]);
+                common.retain(|&key| key_sets[1..].iter().all(|&keys| self.is_type_assignable_to(key, keys)));
+                common
+            }
+            TypeKind::Intersection { types } => types.iter().map(|&t| self.get_index_type(t)).collect(),
+            TypeKind::Tuple { element_types, .. } => {
+                let mut keys: Vec<TypeId> = (0..element_types.len())
+                    .map(|i| self.create_string_literal_type(i.to_string()))
+                    .collect();
+                keys.push(if self.keyof_strings_only { self.type_table.string_type } else { self.type_table.number_type });
+                keys.push(self.create_string_literal_type("length".to_string()));
+                keys
+            }
+            TypeKind::ObjectType { members, index_infos, .. } => {
+                let mut keys = Vec::with_capacity(members.len() + index_infos.len());
+                for name in members.keys() {
+                    let key = self.get_literal_type_from_property_name(name);
+                    let key_flags = self.type_table.get(key).flags;
+                    if !self.keyof_strings_only {
+                        keys.push(key);
+                    } else if key_flags.contains(TypeFlags::NUMBER_LITERAL) {
+                        keys.push(self.create_string_literal_type(name.clone()));
+                    } else if !key_flags.contains(TypeFlags::UNIQUE_ES_SYMBOL) {
+                        keys.push(key);
+                    }
+                }
+                for info in &index_infos {
+                    let key_flags = self.type_table.get(info.key_type).flags;
+                    if self.keyof_strings_only {
+                        if !key_flags.contains(TypeFlags::ES_SYMBOL) {
+                            keys.push(self.type_table.string_type);
                         }
+                    } else if key_flags.contains(TypeFlags::STRING) {
+                        keys.push(self.type_table.string_type);
+                        keys.push(self.type_table.number_type);
+                    } else {
+                        keys.push(info.key_type);
                     }
-                    Some(pairs)
                 }
-                _ => None,
+                keys
             }
+            _ => vec![],
         };
+        self.create_union_type(keys)
+    }
 
-        if let Some(pairs) = member_pairs {
-            for (source_prop, target_prop) in pairs {
-                if !self.is_type_assignable_to(source_prop, target_prop) {
-                    return false;
-                }
-            }
-            return true;
+    /// The key type of a property name: the unique symbol a `[name]` key was
+    /// created for, a number literal for a numeric name, or a string literal.
+    fn get_literal_type_from_property_name(&mut self, name: &str) -> TypeId {
+        if let Some(&unique_symbol) = self.unique_symbols.get(name) {
+            return unique_symbol;
+        }
+        if is_numeric_literal_name(name) {
+            return self.create_number_literal_type(parse_numeric_literal_text(name));
         }
+        self.create_string_literal_type(name.to_string())
+    }
 
-        false
+    /// The stored type of an optional property declared as `T`: `T | undefined`,
+    /// or just `T` under exactOptionalPropertyTypes, where reads add
+    /// `undefined` instead (see `get_read_type_of_property`).
+    fn add_optionality(&mut self, type_id: TypeId) -> TypeId {
+        if self.exact_optional_property_types {
+            return type_id;
+        }
+        self.create_union_type(vec![type_id, self.type_table.undefined_type])
     }
 
-    // ========================================================================
-    // Advanced type operations
-    // ========================================================================
+    /// The type read from property `name` of `object_type`, whose stored type
+    /// is `prop_type`. Under exactOptionalPropertyTypes an optional property
+    /// may be missing, so reading it may yield `undefined`.
+    fn get_read_type_of_property(&mut self, object_type: TypeId, name: &str, prop_type: TypeId) -> TypeId {
+        if self.exact_optional_property_types
+            && self.type_table.get_property_flags(object_type, name).contains(PropertyFlags::OPTIONAL)
+        {
+            return self.create_union_type(vec![prop_type, self.type_table.undefined_type]);
+        }
+        prop_type
+    }
+
+    /// The index infos declared by an index signature, one per constituent of
+    /// its key type. Key types other than `string`, `number`, `symbol` and
+    /// template literal patterns are reported (TS1268) and dropped.
+    fn get_index_infos_of_index_signature(&mut self, node: &IndexSignatureNode<'_>) -> Vec<IndexInfo> {
+        let key_type = node.parameters.iter().next()
+            .and_then(|p| self.get_type_from_type_annotation(p.type_annotation))
+            .unwrap_or(self.type_table.string_type);
+        let value_type = self.get_type_from_type_annotation(node.type_annotation)
+            .unwrap_or(self.type_table.any_type);
+        let is_readonly = node.data.modifier_flags.contains(ModifierFlags::READONLY);
+        let key_types = self.get_union_constituents(key_type);
+        if !key_types.iter().all(|&key| self.is_valid_index_key_type(key)) {
+            self.error(&messages::AN_INDEX_SIGNATURE_PARAMETER_TYPE_MUST_BE_STRING_NUMBER_SYMBOL_OR_A_TEMPLATE_LITERAL_TYPE, &[]);
+        }
+        key_types.into_iter()
+            .filter(|&key| self.is_valid_index_key_type(key))
+            .map(|key| IndexInfo { key_type: key, type_id: value_type, is_readonly })
+            .collect()
+    }
 
-    /// Widen a literal type to its base type.
-    /// TypeScript behavior: `let x = true` → type is `boolean`, `let x = "hello"` → type is `string`.
-    /// Boolean literals (true/false) widen to boolean, string literals to string,
-    /// number literals to number.
-    fn widen_type(&self, type_id: TypeId) -> TypeId {
+    /// Whether a type may be the key type of an index signature.
+    fn is_valid_index_key_type(&self, type_id: TypeId) -> bool {
         let ty = self.type_table.get(type_id);
-        match &ty.kind {
-            TypeKind::BooleanLiteral { .. } => self.type_table.boolean_type,
-            TypeKind::StringLiteral { .. } => self.type_table.string_type,
-            TypeKind::NumberLiteral { .. } => self.type_table.number_type,
-            TypeKind::BigIntLiteral { .. } => self.type_table.bigint_type,
-            _ => type_id,
+        ty.flags.intersects(TypeFlags::STRING | TypeFlags::NUMBER | TypeFlags::ES_SYMBOL)
+            || matches!(ty.kind, TypeKind::TemplateLiteral { .. })
+    }
+
+    /// Whether an index signature keyed by `info_key` applies to the property
+    /// key `name`. Numeric names are keys of both `string` and `number` index
+    /// signatures.
+    fn is_property_name_applicable_to_index(&self, name: &str, info_key: TypeId) -> bool {
+        let is_symbol_key = self.unique_symbols.contains_key(name);
+        let info = self.type_table.get(info_key);
+        match &info.kind {
+            TypeKind::TemplateLiteral { texts, types } => {
+                !is_symbol_key && self.is_string_matched_by_template(name, texts, types)
+            }
+            _ if is_symbol_key => info.flags.contains(TypeFlags::ES_SYMBOL),
+            _ if info.flags.contains(TypeFlags::STRING) => true,
+            _ if info.flags.contains(TypeFlags::NUMBER) => is_numeric_literal_name(name),
+            _ => false,
         }
     }
 
-    /// For const declarations, narrow the inferred type to a literal type when possible.
-    /// TypeScript behavior: `const x = "hello"` → type is `"hello"` (string literal),
-    /// `const n = 42` → type is `42` (number literal), etc.
-    fn narrow_to_literal(&mut self, expr: &Expression<'_>, inferred: TypeId) -> TypeId {
-        match expr {
-            Expression::TrueKeyword(_) => self.type_table.true_type,
-            Expression::FalseKeyword(_) => self.type_table.false_type,
-            Expression::NullKeyword(_) => self.type_table.null_type,
-            Expression::StringLiteral(s) => {
-                self.create_string_literal_type(s.text_name.clone())
+    /// Whether an index signature keyed by `info_key` applies to keys of type
+    /// `key_type`.
+    fn is_applicable_index_type(&mut self, key_type: TypeId, info_key: TypeId) -> bool {
+        if let Some(name) = self.get_literal_key_name(key_type) {
+            return self.is_property_name_applicable_to_index(&name, info_key);
+        }
+        if self.type_table.get(key_type).flags.contains(TypeFlags::ANY) {
+            return true;
+        }
+        self.is_type_assignable_to(key_type, info_key)
+            || (info_key == self.type_table.string_type && self.is_type_assignable_to(key_type, self.type_table.number_type))
+    }
+
+    /// The index signature of `type_id` that applies to keys of type
+    /// `key_type`. A `string` index signature is used only when no other
+    /// applies; several others combine into one with the intersection of
+    /// their types.
+    fn get_applicable_index_info(&mut self, type_id: TypeId, key_type: TypeId) -> Option<IndexInfo> {
+        let TypeKind::ObjectType { index_infos, .. } = &self.type_table.get(type_id).kind else {
+            return None;
+        };
+        let string_type = self.type_table.string_type;
+        let mut string_index_info = None;
+        let mut applicable = Vec::new();
+        for info in index_infos.clone() {
+            if info.key_type == string_type {
+                string_index_info = Some(info);
+            } else if self.is_applicable_index_type(key_type, info.key_type) {
+                applicable.push(info);
             }
-            Expression::NumericLiteral(n) => {
-                let val = n.text_name.parse::<f64>().unwrap_or(0.0);
-                self.create_number_literal_type(val)
+        }
+        match applicable.len() {
+            0 => string_index_info.filter(|_| self.is_applicable_index_type(key_type, string_type)),
+            1 => applicable.pop(),
+            _ => {
+                let is_readonly = applicable.iter().all(|info| info.is_readonly);
+                let types = applicable.into_iter().map(|info| info.type_id).collect();
+                Some(IndexInfo { key_type, type_id: self.create_intersection_type(types), is_readonly })
+            }
+        }
+    }
+
+    /// Check that every property of a declared object type fits the index
+    /// signatures that apply to its name (TS2411), and that every index
+    /// signature fits the ones that apply to its key type (TS2413).
+    fn check_index_constraints(&mut self, type_id: TypeId) {
+        let TypeKind::ObjectType { members, index_infos, .. } = self.type_table.get(type_id).kind.clone() else {
+            return;
+        };
+        if index_infos.is_empty() {
+            return;
+        }
+        for (name, prop_type) in &members {
+            for info in &index_infos {
+                if !self.is_property_name_applicable_to_index(name, info.key_type)
+                    || self.is_type_assignable_to(*prop_type, info.type_id)
+                {
+                    continue;
+                }
+                let prop_text = self.format_type(*prop_type);
+                let key_text = self.format_type(info.key_type);
+                let index_text = self.format_type(info.type_id);
+                self.error(&messages::PROPERTY_0_OF_TYPE_1_IS_NOT_ASSIGNABLE_TO_2_INDEX_TYPE_3, &[name, &prop_text, &key_text, &index_text]);
+            }
+        }
+        for info in &index_infos {
+            for other in &index_infos {
+                if info.key_type == other.key_type
+                    || !self.is_applicable_index_type(info.key_type, other.key_type)
+                    || self.is_type_assignable_to(info.type_id, other.type_id)
+                {
+                    continue;
+                }
+                let key_text = self.format_type(info.key_type);
+                let type_text = self.format_type(info.type_id);
+                let other_key_text = self.format_type(other.key_type);
+                let other_type_text = self.format_type(other.type_id);
+                self.error(&messages::_0_INDEX_TYPE_1_IS_NOT_ASSIGNABLE_TO_2_INDEX_TYPE_3, &[&key_text, &type_text, &other_key_text, &other_type_text]);
             }
-            _ => inferred,
         }
     }
 
-    /// Extract member names from an object type.
-    fn get_object_member_names(&self, type_id: TypeId) -> Vec<String> {
+    /// Resolve `T[K]`, deferring it while either side is generic.
+    fn get_indexed_access_type(&mut self, object_type: TypeId, index_type: TypeId) -> TypeId {
+        if self.is_generic_type(object_type) || self.is_generic_type(index_type) {
+            return self.type_table.add_type(
+                TypeFlags::INDEXED_ACCESS,
+                TypeKind::IndexedAccess { object_type, index_type },
+            );
+        }
+        self.resolve_indexed_access(object_type, index_type)
+    }
+
+    /// Resolve `C extends E ? T : F`, deferring it while `C` or `E` is generic.
+    fn get_conditional_type(&mut self, check: TypeId, extends: TypeId, true_type: TypeId, false_type: TypeId) -> TypeId {
+        if self.is_generic_type(check) || self.is_generic_type(extends) {
+            return self.type_table.add_type(
+                TypeFlags::CONDITIONAL,
+                TypeKind::Conditional { check_type: check, extends_type: extends, true_type, false_type },
+            );
+        }
+        if self.type_table.get(check).flags.contains(TypeFlags::ANY) {
+            return self.create_union_type(vec![true_type, false_type]);
+        }
+        self.evaluate_conditional_type(check, extends, true_type, false_type)
+    }
+
+    /// The constituents of a union, nothing for `never`, or the type itself.
+    fn get_union_constituents(&self, type_id: TypeId) -> Vec<TypeId> {
         let ty = self.type_table.get(type_id);
         match &ty.kind {
-            TypeKind::ObjectType { members, .. } => {
-                members.keys().cloned().collect()
-            }
-            _ => vec![],
+            TypeKind::Union { types } => types.clone(),
+            _ if ty.flags.contains(TypeFlags::NEVER) => vec![],
+            _ => vec![type_id],
         }
     }
 
-    /// Evaluate a mapped type `{ [K in keyof T]: V }`.
-    /// Resolves keys from the constraint, iterates them, and builds an object type.
-    fn evaluate_mapped_type(&mut self, node: &MappedTypeNode<'_>) -> TypeId {
-        // Get constraint type (the type we iterate keys from)
-        let constraint_type = if let Some(constraint) = node.type_parameter.constraint {
-            self.get_type_from_type_node(constraint)
-        } else {
-            return self.type_table.any_type; // No constraint, can't evaluate
-        };
-
-        // Resolve keys from the constraint
-        let keys = self.get_key_names_from_type(constraint_type);
+    /// The property name denoted by a string literal, number literal or
+    /// unique symbol type.
+    fn get_literal_key_name(&self, type_id: TypeId) -> Option<String> {
+        match &self.type_table.get(type_id).kind {
+            TypeKind::StringLiteral { value, .. } => Some(value.clone()),
+            TypeKind::NumberLiteral { value } => Some(number_to_string(*value)),
+            TypeKind::UniqueSymbol { name } => Some(format!("[{}]", name)),
+            _ => None,
+        }
+    }
 
-        // For each key, evaluate the value type with substitution
-        let mut members = IndexMap::new();
-        let param_name = node.type_parameter.name.text_name.clone();
+    /// The property key a computed property name denotes when it is known
+    /// statically: a literal, or a name or `a.b` reference of literal or
+    /// unique symbol type such as `Symbol.iterator`.
+    fn get_computed_property_key(&self, expr: &Expression<'_>) -> Option<String> {
+        let key_type = match expr {
+            Expression::StringLiteral(s) => return Some(s.text_name.clone()),
+            Expression::NumericLiteral(n) => return Some(number_to_string(parse_numeric_literal_text(&n.text_name))),
+            Expression::Parenthesized(p) => return self.get_computed_property_key(p.expression),
+            Expression::Identifier(id) => self.get_declared_type(&id.text_name)?,
+            Expression::PropertyAccess(p) => {
+                let (Expression::Identifier(object), MemberName::Identifier(name)) = (p.expression, &p.name) else {
+                    return None;
+                };
+                let object_type = self.get_declared_type(&object.text_name)?;
+                match &self.type_table.get(object_type).kind {
+                    TypeKind::ObjectType { members, .. } => *members.get(&name.text_name)?,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        self.get_literal_key_name(key_type)
+    }
 
-        for key in &keys {
-            // Register the key as the type parameter value
-            let key_type = self.type_table.add_type(
-                TypeFlags::STRING_LITERAL,
-                TypeKind::StringLiteral { value: key.clone(), regular: false },
-            );
-            self.register_type(&param_name, key_type);
+    /// Evaluate a template literal type node like `` `Hello ${string}` ``.
+    fn evaluate_template_literal_type(&mut self, node: &TemplateLiteralTypeNode<'_>) -> TypeId {
+        let mut texts = vec![node.head_text.clone()];
+        let mut types = Vec::with_capacity(node.template_spans.len());
+        for span in node.template_spans.iter() {
+            types.push(self.get_type_from_type_node(span.type_node));
+            texts.push(span.literal_text.clone());
+        }
+        self.get_template_literal_type(texts, types)
+    }
 
-            // Evaluate the value type
-            let value_type = if let Some(type_node) = node.type_node {
-                self.get_type_from_type_node(type_node)
-            } else {
-                self.type_table.any_type
-            };
+    /// Resolve `` `a${T}b` `` given its texts and placeholder types. Literal
+    /// placeholders are folded into the surrounding text and unions are
+    /// distributed over, so the result is a string literal (or a union of them)
+    /// once no other placeholders remain.
+    fn get_template_literal_type(&mut self, texts: Vec<String>, types: Vec<TypeId>) -> TypeId {
+        if types.iter().any(|&t| self.type_table.get(t).flags.contains(TypeFlags::NEVER)) {
+            return self.type_table.never_type;
+        }
+        let expansion = types.iter()
+            .map(|&t| match &self.type_table.get(t).kind {
+                TypeKind::Union { types } => types.len(),
+                _ => 1,
+            })
+            .try_fold(1usize, |total, n| total.checked_mul(n).filter(|&total| total <= MAX_TEMPLATE_LITERAL_EXPANSION));
+        if expansion.is_none() {
+            self.error(&messages::EXPRESSION_PRODUCES_A_UNION_TYPE_THAT_IS_TOO_COMPLEX_TO_REPRESENT, &[]);
+            return self.type_table.any_type;
+        }
+        if let Some(i) = types.iter().position(|&t| matches!(self.type_table.get(t).kind, TypeKind::Union { .. })) {
+            let results: Vec<TypeId> = self.get_union_constituents(types[i]).into_iter()
+                .map(|member| {
+                    let mut types = types.clone();
+                    types[i] = member;
+                    self.get_template_literal_type(texts.clone(), types)
+                })
+                .collect();
+            return self.create_union_type(results);
+        }
 
-            // Apply optional modifier
-            let final_type = if node.question_token.is_some() {
-                // Make the property optional by unioning with undefined
-                self.create_union_type(vec![value_type, self.type_table.undefined_type])
+        let mut new_texts = vec![texts[0].clone()];
+        let mut new_types = Vec::new();
+        for (i, &t) in types.iter().enumerate() {
+            if let Some(text) = self.get_template_string_for_type(t) {
+                new_texts.last_mut().expect("texts are never empty").push_str(&text);
+            } else if let TypeKind::TemplateLiteral { texts: inner_texts, types: inner_types } = &self.type_table.get(t).kind {
+                // A nested template literal is spliced into this one
+                new_texts.last_mut().expect("texts are never empty").push_str(&inner_texts[0]);
+                new_texts.extend(inner_texts[1..].iter().cloned());
+                new_types.extend(inner_types.iter().copied());
             } else {
-                value_type
-            };
-
-            members.insert(key.clone(), final_type);
+                new_types.push(t);
+                new_texts.push(String::new());
+            }
+            new_texts.last_mut().expect("texts are never empty").push_str(&texts[i + 1]);
+        }
+        if new_types.is_empty() {
+            return self.create_string_literal_type(new_texts.swap_remove(0));
+        }
+        // `${string}` is just `string`
+        if new_types.len() == 1
+            && new_texts.iter().all(String::is_empty)
+            && self.type_table.get(new_types[0]).flags.intersects(TypeFlags::STRING | TypeFlags::ANY)
+        {
+            return self.type_table.string_type;
         }
-
-        // Clean up the temporary type parameter registration
-        self.declared_types.remove(&param_name);
-
         self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::ANONYMOUS,
-                members,
-                call_signatures: vec![],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
+            TypeFlags::TEMPLATE_LITERAL,
+            TypeKind::TemplateLiteral { texts: new_texts, types: new_types },
         )
     }
 
-    /// Get property key names from a type (used for mapped types).
-    /// If the type is a union of string literals, returns those strings.
-    /// If the type is a keyof result, returns the keys of the target.
-    fn get_key_names_from_type(&self, type_id: TypeId) -> Vec<String> {
+    /// The text a unit type contributes to a template literal, or `None` for
+    /// types that remain placeholders.
+    fn get_template_string_for_type(&self, type_id: TypeId) -> Option<String> {
         let ty = self.type_table.get(type_id);
         match &ty.kind {
-            TypeKind::StringLiteral { value, .. } => vec![value.clone()],
+            TypeKind::StringLiteral { value, .. } => Some(value.clone()),
+            TypeKind::NumberLiteral { value } => Some(number_to_string(*value)),
+            TypeKind::BigIntLiteral { value } => Some(value.trim_end_matches('n').to_string()),
+            TypeKind::BooleanLiteral { value } => Some(value.to_string()),
+            _ if ty.flags.contains(TypeFlags::NULL) => Some("null".to_string()),
+            _ if ty.flags.contains(TypeFlags::UNDEFINED) => Some("undefined".to_string()),
+            _ => None,
+        }
+    }
+
+    /// Resolve an intrinsic string mapping like `Uppercase<T>`. String literals
+    /// are mapped and unions are distributed over; generic and non-literal
+    /// string types are kept as a deferred mapping.
+    fn get_string_mapping_type(&mut self, mapping: StringMappingKind, target: TypeId) -> TypeId {
+        let ty = self.type_table.get(target);
+        match &ty.kind {
             TypeKind::Union { types } => {
                 let types = types.clone();
-                let mut keys = Vec::new();
-                for &t in &types {
-                    let inner = self.type_table.get(t);
-                    if let TypeKind::StringLiteral { value, .. } = &inner.kind {
-                        keys.push(value.clone());
-                    }
-                }
-                keys
+                let mapped: Vec<TypeId> = types.iter().map(|&t| self.get_string_mapping_type(mapping, t)).collect();
+                self.create_union_type(mapped)
             }
-            // If it's a keyof ObjectType result, we already resolved it to a union of string literals
-            _ => {
-                // Try to get object member names if this is an object type
-                if let TypeKind::ObjectType { members, .. } = &ty.kind {
-                    members.keys().cloned().collect()
-                } else {
-                    vec![]
-                }
+            TypeKind::StringLiteral { value, .. } => {
+                let mapped = mapping.apply(value);
+                self.create_string_literal_type(mapped)
             }
-        }
-    }
-
-    /// Evaluate a template literal type like `` `Hello ${string}` ``.
-    /// If all segments are concrete string literals, produces a string literal.
-    /// Otherwise, returns `string`.
-    fn evaluate_template_literal_type(&mut self, node: &TemplateLiteralTypeNode<'_>) -> TypeId {
-        // Attempt to concatenate if all segments are literal
-        let mut result = String::new();
-        let mut all_literal = true;
-
-        // Head text (before first span)
-        // The head text comes from the template head token
-        // For simplicity, we extract what we can from spans
-        for span in node.template_spans.iter() {
-            let span_type = self.get_type_from_type_node(&span.type_node);
-            let ty = self.type_table.get(span_type);
-            match &ty.kind {
-                TypeKind::StringLiteral { value, .. } => {
-                    result.push_str(value);
-                }
-                _ => {
-                    all_literal = false;
-                    break;
-                }
+            TypeKind::StringMapping { mapping: inner, .. } if *inner == mapping => target,
+            _ if ty.flags.intersects(TypeFlags::ANY | TypeFlags::NEVER) => target,
+            _ if ty.flags.intersects(TypeFlags::STRING | TypeFlags::TEMPLATE_LITERAL | TypeFlags::STRING_MAPPING)
+                || self.is_generic_type(target) =>
+            {
+                self.type_table.add_type(TypeFlags::STRING_MAPPING, TypeKind::StringMapping { mapping, target })
             }
-        }
-
-        if all_literal && node.template_spans.is_empty() {
-            // Empty template literal → empty string literal
-            self.type_table.add_type(
-                TypeFlags::STRING_LITERAL,
-                TypeKind::StringLiteral { value: result, regular: false },
-            )
-        } else {
-            // At least one non-literal segment → return string
-            self.type_table.string_type
+            _ => target,
         }
     }
 
@@ -3449,185 +11338,302 @@ impl Checker {
     }
 
     /// Resolve an indexed access type T[K] — look up property K in T.
-    fn resolve_indexed_access(&self, object_type: TypeId, index_type: TypeId) -> TypeId {
+    fn resolve_indexed_access(&mut self, object_type: TypeId, index_type: TypeId) -> TypeId {
+        self.try_resolve_indexed_access(object_type, index_type)
+            .unwrap_or(self.type_table.any_type)
+    }
+
+    /// Resolve `T[K]` for concrete `T` and `K`, or `None` when `T` has no
+    /// property or index signature for some constituent of `K`.
+    fn try_resolve_indexed_access(&mut self, object_type: TypeId, index_type: TypeId) -> Option<TypeId> {
+        if self.type_table.get(object_type).flags.contains(TypeFlags::ANY) {
+            return Some(object_type);
+        }
+        // T[A | B] is T[A] | T[B], and (A | B)[K] is A[K] | B[K]
+        for (distributed, is_index) in [(index_type, true), (object_type, false)] {
+            if let TypeKind::Union { types } = &self.type_table.get(distributed).kind {
+                let types = types.clone();
+                let mut results = Vec::with_capacity(types.len());
+                for t in types {
+                    results.push(if is_index {
+                        self.try_resolve_indexed_access(object_type, t)?
+                    } else {
+                        self.try_resolve_indexed_access(t, index_type)?
+                    });
+                }
+                return Some(self.create_union_type(results));
+            }
+        }
+        // (A & B)[K] is the intersection of the constituents that have K
+        if let TypeKind::Intersection { types } = &self.type_table.get(object_type).kind {
+            let types = types.clone();
+            let results: Vec<TypeId> = types.into_iter()
+                .filter_map(|t| self.try_resolve_indexed_access(t, index_type))
+                .collect();
+            return match results.len() {
+                0 => None,
+                1 => Some(results[0]),
+                _ => Some(self.create_intersection_type(results)),
+            };
+        }
+
+        let key_name = self.get_literal_key_name(index_type);
         let obj = self.type_table.get(object_type);
-        let idx = self.type_table.get(index_type);
 
-        // If index is a string literal, O(1) property lookup via IndexMap
-        if let TypeKind::StringLiteral { value, .. } = &idx.kind {
-            if let TypeKind::ObjectType { members, .. } = &obj.kind {
-                if let Some(&tid) = members.get(value) {
-                    return tid;
+        if let TypeKind::Tuple { element_types, element_flags } = &obj.kind {
+            let (element_types, element_flags) = (element_types.clone(), element_flags.clone());
+            let flag_at = |i: usize| element_flags.get(i).copied().unwrap_or(ElementFlags::Required);
+            let fixed_length = (0..element_types.len())
+                .take_while(|&i| !matches!(flag_at(i), ElementFlags::Rest | ElementFlags::Variadic))
+                .count();
+            if key_name.as_deref() == Some("length") {
+                return Some(if fixed_length == element_types.len() {
+                    self.create_number_literal_type(element_types.len() as f64)
+                } else {
+                    self.type_table.number_type
+                });
+            }
+            if let Some(i) = key_name.as_deref().and_then(|k| k.parse::<usize>().ok()) {
+                if i < fixed_length {
+                    let element_type = element_types[i];
+                    return Some(if flag_at(i) == ElementFlags::Optional && self.strict_null_checks {
+                        self.create_union_type(vec![element_type, self.type_table.undefined_type])
+                    } else {
+                        element_type
+                    });
                 }
+                if fixed_length == element_types.len() {
+                    return Some(self.type_table.undefined_type);
+                }
+                // Past the fixed prefix: any element from the rest onwards.
+                let trailing: Vec<TypeId> = (fixed_length..element_types.len())
+                    .map(|j| match flag_at(j) {
+                        ElementFlags::Variadic => self.get_element_type_of_iterable(element_types[j]),
+                        _ => element_types[j],
+                    })
+                    .collect();
+                return Some(self.create_union_type(trailing));
+            }
+            if self.type_table.get(index_type).flags.contains(TypeFlags::NUMBER) {
+                return Some(self.get_spread_element_type(object_type));
             }
         }
 
-        // Check index signatures
-        if let TypeKind::ObjectType { index_infos, .. } = &obj.kind {
-            for info in index_infos {
-                if self.type_table.get(info.key_type).flags.contains(self.type_table.get(index_type).flags) {
-                    return info.type_id;
+        // If index is a literal, O(1) property lookup via IndexMap
+        if let (Some(name), TypeKind::ObjectType { members, .. }) = (&key_name, &obj.kind) {
+            if let Some(&tid) = members.get(name) {
+                return Some(self.get_read_type_of_property(object_type, name, tid));
+            }
+        }
+
+        self.get_applicable_index_info(object_type, index_type).map(|info| info.type_id)
+    }
+
+    /// Report why `T[K]` written in a type position does not resolve: `K` is
+    /// not a key type (TS2538), names a property `T` lacks (TS2339), or is a
+    /// non-literal key `T` has no index signature for (TS2536).
+    /// Object types we do not model structurally, like primitives, are not
+    /// reported.
+    fn report_invalid_indexed_access(&mut self, object_type: TypeId, index_type: TypeId) {
+        for index in self.get_union_constituents(index_type) {
+            let index_flags = self.type_table.get(index).flags;
+            let key_like = TypeFlags::STRING_LIKE | TypeFlags::NUMBER_LIKE | TypeFlags::ES_SYMBOL_LIKE;
+            if !index_flags.intersects(key_like) {
+                let index_text = self.format_type(index);
+                self.error(&messages::TYPE_0_CANNOT_BE_USED_AS_AN_INDEX_TYPE, &[&index_text]);
+                return;
+            }
+            for object in self.get_union_constituents(object_type) {
+                if !matches!(self.type_table.get(object).kind, TypeKind::ObjectType { .. } | TypeKind::Tuple { .. }) {
+                    continue;
+                }
+                if self.try_resolve_indexed_access(object, index).is_some() {
+                    continue;
+                }
+                let object_text = self.format_type(object);
+                match self.get_literal_key_name(index) {
+                    Some(property) => self.error(&messages::PROPERTY_0_DOES_NOT_EXIST_ON_TYPE_1, &[&property, &object_text]),
+                    None => {
+                        let index_text = self.format_type(index);
+                        self.error(&messages::TYPE_0_CANNOT_BE_USED_TO_INDEX_TYPE_1, &[&index_text, &object_text]);
+                    }
                 }
+                return;
             }
         }
+    }
 
-        self.type_table.any_type
+    /// Create the `unique symbol` type of the declaration `name`. Members
+    /// keyed by it are stored under the property key `[name]`.
+    fn create_unique_symbol_type(&mut self, name: &str) -> TypeId {
+        let unique_symbol = self.type_table.add_type(
+            TypeFlags::UNIQUE_ES_SYMBOL,
+            TypeKind::UniqueSymbol { name: name.to_string() },
+        );
+        self.unique_symbols.insert(format!("[{}]", name), unique_symbol);
+        unique_symbol
     }
 
-    /// Create a literal string type.
+    /// The regular string literal type with the given value.
     fn create_string_literal_type(&mut self, value: String) -> TypeId {
-        self.type_table.add_type(
+        let key = LiteralKey::String(value.clone());
+        if let Some(&id) = self.literal_types.get(&key) {
+            return id;
+        }
+        let id = self.type_table.add_type(
             TypeFlags::STRING_LITERAL,
-            TypeKind::StringLiteral { value, regular: false },
-        )
+            TypeKind::StringLiteral { value, regular: true },
+        );
+        self.literal_types.insert(key, id);
+        id
     }
 
-    /// Create a literal number type.
+    /// The regular number literal type with the given value.
     fn create_number_literal_type(&mut self, value: f64) -> TypeId {
-        self.type_table.add_type(
+        // -0 and 0 are the same literal type
+        let value = if value == 0.0 { 0.0 } else { value };
+        let key = LiteralKey::Number(value.to_bits());
+        if let Some(&id) = self.literal_types.get(&key) {
+            return id;
+        }
+        let id = self.type_table.add_type(
             TypeFlags::NUMBER_LITERAL,
             TypeKind::NumberLiteral { value },
-        )
+        );
+        self.literal_types.insert(key, id);
+        id
     }
 
-    /// Implement Partial<T> — make all properties optional (add undefined to each member type).
-    fn create_partial_type(&mut self, type_id: TypeId) -> TypeId {
-        let members = {
-            let ty = self.type_table.get(type_id);
-            if let TypeKind::ObjectType { members, .. } = &ty.kind {
-                members.clone()
-            } else {
-                return type_id;
-            }
-        };
-        let partial_members: IndexMap<String, TypeId> = members.iter().map(|(name, tid)| {
-            let optional = self.create_union_type(vec![*tid, self.type_table.undefined_type]);
-            (name.clone(), optional)
-        }).collect();
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::ANONYMOUS,
-                members: partial_members,
-                call_signatures: vec![],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
-        )
+    /// The fresh counterpart of a regular literal type: the type of the
+    /// literal expression itself, which widens at mutable locations.
+    fn get_fresh_type_of_literal_type(&mut self, type_id: TypeId) -> TypeId {
+        let ty = self.type_table.get(type_id);
+        if !ty.flags.intersects(TypeFlags::LITERAL) || self.regular_types.contains_key(&type_id) {
+            return type_id;
+        }
+        if let Some(&fresh) = self.fresh_literal_types.get(&type_id) {
+            return fresh;
+        }
+        let mut ty = ty.clone();
+        if let TypeKind::StringLiteral { regular, .. } = &mut ty.kind {
+            *regular = false;
+        }
+        let fresh = self.type_table.add_type(ty.flags, ty.kind);
+        self.fresh_literal_types.insert(type_id, fresh);
+        self.regular_types.insert(fresh, type_id);
+        if let Some(&base) = self.enum_literal_bases.get(&type_id) {
+            self.enum_literal_bases.insert(fresh, base);
+        }
+        if let Some(name) = self.enum_type_names.get(&type_id).cloned() {
+            self.enum_type_names.insert(fresh, name);
+        }
+        fresh
     }
 
-    /// Implement Required<T> — remove undefined from all member types.
-    fn create_required_type(&mut self, type_id: TypeId) -> TypeId {
-        let members = {
-            let ty = self.type_table.get(type_id);
-            if let TypeKind::ObjectType { members, .. } = &ty.kind {
-                members.clone()
-            } else {
-                return type_id;
+    fn is_fresh_literal_type(&self, type_id: TypeId) -> bool {
+        self.type_table.get(type_id).flags.intersects(TypeFlags::LITERAL) && self.regular_types.contains_key(&type_id)
+    }
+
+    /// The regular counterpart of a fresh literal type, mapped over unions.
+    fn get_regular_type_of_literal_type(&mut self, type_id: TypeId) -> TypeId {
+        if self.is_fresh_literal_type(type_id) {
+            return self.regular_types[&type_id];
+        }
+        if let TypeKind::Union { types } = &self.type_table.get(type_id).kind {
+            let types = types.clone();
+            let regular: Vec<TypeId> = types.iter().map(|&t| self.get_regular_type_of_literal_type(t)).collect();
+            if regular != types {
+                return self.create_union_type(regular);
             }
-        };
-        let required_members: IndexMap<String, TypeId> = members.iter().map(|(name, tid)| {
-            let non_null = self.get_non_nullable_type(*tid);
-            (name.clone(), non_null)
-        }).collect();
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::ANONYMOUS,
-                members: required_members,
-                call_signatures: vec![],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
-        )
+        }
+        type_id
     }
 
-    /// Implement Readonly<T> — keep all members but mark object as immutable.
-    fn create_readonly_type(&mut self, type_id: TypeId) -> TypeId {
+    /// The primitive a literal type belongs to (`"a"` is a `string`), or the
+    /// enum type of an enum literal. Mapped over unions.
+    fn get_base_type_of_literal_type(&mut self, type_id: TypeId) -> TypeId {
+        if let Some(&base) = self.enum_literal_bases.get(&type_id) {
+            return base;
+        }
         let ty = self.type_table.get(type_id);
-        if let TypeKind::ObjectType { members, call_signatures, construct_signatures, index_infos, .. } = &ty.kind {
-            let members = members.clone();
-            let call_signatures = call_signatures.clone();
-            let construct_signatures = construct_signatures.clone();
-            let index_infos = index_infos.clone();
-            self.type_table.add_type(
-                TypeFlags::OBJECT,
-                TypeKind::ObjectType {
-                    object_flags: ObjectFlags::ANONYMOUS,
-                    members,
-                    call_signatures,
-                    construct_signatures,
-                    index_infos,
-                },
-            )
+        if let TypeKind::Union { types } = &ty.kind {
+            let types = types.clone();
+            let base: Vec<TypeId> = types.iter().map(|&t| self.get_base_type_of_literal_type(t)).collect();
+            return self.create_union_type(base);
+        }
+        if ty.flags.intersects(TypeFlags::STRING_LITERAL | TypeFlags::TEMPLATE_LITERAL | TypeFlags::STRING_MAPPING) {
+            self.type_table.string_type
+        } else if ty.flags.contains(TypeFlags::NUMBER_LITERAL) {
+            self.type_table.number_type
+        } else if ty.flags.contains(TypeFlags::BIG_INT_LITERAL) {
+            self.type_table.bigint_type
+        } else if ty.flags.contains(TypeFlags::BOOLEAN_LITERAL) {
+            self.type_table.boolean_type
         } else {
             type_id
         }
     }
 
-    /// Implement Pick<T, K> — select only the specified keys.
-    fn create_pick_type(&mut self, type_id: TypeId, keys: &[TypeId]) -> TypeId {
-        let members = {
-            let ty = self.type_table.get(type_id);
-            if let TypeKind::ObjectType { members, .. } = &ty.kind {
-                members.clone()
-            } else {
-                return type_id;
-            }
-        };
-        let key_names: Vec<String> = keys.iter().filter_map(|k| {
-            if let TypeKind::StringLiteral { value, .. } = &self.type_table.get(*k).kind {
-                Some(value.clone())
-            } else {
-                None
-            }
-        }).collect();
-        let picked: IndexMap<String, TypeId> = members.iter()
-            .filter(|(name, _)| key_names.contains(name))
-            .map(|(name, tid)| (name.clone(), *tid))
-            .collect();
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::ANONYMOUS,
-                members: picked,
-                call_signatures: vec![],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
+    /// Implement Partial<T> as `{ [P in keyof T]?: T[P] }`.
+    fn create_partial_type(&mut self, type_id: TypeId) -> TypeId {
+        self.create_homomorphic_mapped_type(type_id, MappedTypeModifier::None, MappedTypeModifier::Add)
+    }
+
+    /// Implement Required<T> as `{ [P in keyof T]-?: T[P] }`.
+    fn create_required_type(&mut self, type_id: TypeId) -> TypeId {
+        self.create_homomorphic_mapped_type(type_id, MappedTypeModifier::None, MappedTypeModifier::Remove)
+    }
+
+    /// Implement Readonly<T> as `{ readonly [P in keyof T]: T[P] }`.
+    fn create_readonly_type(&mut self, type_id: TypeId) -> TypeId {
+        self.create_homomorphic_mapped_type(type_id, MappedTypeModifier::Add, MappedTypeModifier::None)
+    }
+
+    /// Create `{ [P in keyof T]: T[P] }` with the given modifiers.
+    fn create_homomorphic_mapped_type(
+        &mut self,
+        type_id: TypeId,
+        readonly_modifier: MappedTypeModifier,
+        optional_modifier: MappedTypeModifier,
+    ) -> TypeId {
+        let constraint = self.create_index_type(type_id);
+        let param = self.create_type_parameter("P", Some(constraint));
+        let template = self.get_indexed_access_type(type_id, param);
+        self.create_mapped_type(
+            param, constraint, None, Some(template), Some(type_id), readonly_modifier, optional_modifier,
         )
     }
 
-    /// Implement Omit<T, K> — remove the specified keys.
-    fn create_omit_type(&mut self, type_id: TypeId, keys: &[TypeId]) -> TypeId {
-        let members = {
-            let ty = self.type_table.get(type_id);
-            if let TypeKind::ObjectType { members, .. } = &ty.kind {
-                members.clone()
-            } else {
-                return type_id;
-            }
-        };
-        let key_names: Vec<String> = keys.iter().filter_map(|k| {
-            if let TypeKind::StringLiteral { value, .. } = &self.type_table.get(*k).kind {
-                Some(value.clone())
-            } else {
-                None
-            }
-        }).collect();
-        let omitted: IndexMap<String, TypeId> = members.iter()
-            .filter(|(name, _)| !key_names.contains(name))
-            .map(|(name, tid)| (name.clone(), *tid))
-            .collect();
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::ObjectType {
-                object_flags: ObjectFlags::ANONYMOUS,
-                members: omitted,
-                call_signatures: vec![],
-                construct_signatures: vec![],
-                index_infos: vec![],
-            },
+    /// Implement Pick<T, K> as `{ [P in K]: T[P] }`, keeping T's modifiers.
+    fn create_pick_type(&mut self, type_id: TypeId, keys: TypeId) -> TypeId {
+        let param = self.create_type_parameter("P", Some(keys));
+        let template = self.get_indexed_access_type(type_id, param);
+        self.create_mapped_type(
+            param, keys, None, Some(template), Some(type_id), MappedTypeModifier::None, MappedTypeModifier::None,
+        )
+    }
+
+    /// Implement Omit<T, K> as `{ [P in keyof T as P extends K ? never : P]: T[P] }`.
+    fn create_omit_type(&mut self, type_id: TypeId, keys: TypeId) -> TypeId {
+        let constraint = self.create_index_type(type_id);
+        let param = self.create_type_parameter("P", Some(constraint));
+        let never_type = self.type_table.never_type;
+        let name_type = self.type_table.add_type(
+            TypeFlags::CONDITIONAL,
+            TypeKind::Conditional { check_type: param, extends_type: keys, true_type: never_type, false_type: param },
+        );
+        let template = self.get_indexed_access_type(type_id, param);
+        self.create_mapped_type(
+            param, constraint, Some(name_type), Some(template), Some(type_id),
+            MappedTypeModifier::None, MappedTypeModifier::None,
+        )
+    }
+
+    /// Implement Record<K, T> as `{ [P in K]: T }`.
+    fn create_record_type(&mut self, keys: TypeId, value_type: TypeId) -> TypeId {
+        let param = self.create_type_parameter("P", Some(keys));
+        self.create_mapped_type(
+            param, keys, None, Some(value_type), None, MappedTypeModifier::None, MappedTypeModifier::None,
         )
     }
 
@@ -3643,26 +11649,37 @@ impl Checker {
     }
 
     /// Implement Parameters<T> — extract the parameter types of a function type as a tuple.
+    /// Parameter names become the tuple's labels, optional parameters optional
+    /// elements, and a rest parameter a spread of its type.
     fn get_parameters_type_of(&mut self, type_id: TypeId) -> TypeId {
-        let params = {
-            let ty = self.type_table.get(type_id);
-            if let TypeKind::ObjectType { call_signatures, .. } = &ty.kind {
-                if let Some(sig) = call_signatures.first() {
-                    sig.parameters.iter().map(|p| p.type_id).collect::<Vec<_>>()
+        let sig = match &self.type_table.get(type_id).kind {
+            TypeKind::ObjectType { call_signatures, .. } if !call_signatures.is_empty() => call_signatures[0].clone(),
+            _ => return self.type_table.any_type,
+        };
+        self.get_parameters_tuple(&sig, 0)
+    }
+
+    /// The labeled tuple of a signature's parameters from `start` onwards.
+    fn get_parameters_tuple(&mut self, sig: &Signature, start: usize) -> TypeId {
+        let last = sig.parameters.len().wrapping_sub(1);
+        let params = sig.parameters.get(start..).unwrap_or(&[]);
+        let element_flags = params.iter().enumerate()
+            .map(|(i, p)| {
+                if sig.has_rest_parameter && start + i == last {
+                    ElementFlags::Variadic
+                } else if p.optional {
+                    ElementFlags::Optional
                 } else {
-                    return self.type_table.any_type;
+                    ElementFlags::Required
                 }
-            } else {
-                return self.type_table.any_type;
-            }
-        };
-        self.type_table.add_type(
-            TypeFlags::OBJECT,
-            TypeKind::Tuple {
-                element_types: params,
-                element_flags: vec![],
-            },
-        )
+            })
+            .collect();
+        let element_types = params.iter().map(|p| p.type_id).collect();
+        // Destructured parameters have no name; label them by position.
+        let labels = params.iter().enumerate()
+            .map(|(i, p)| if p.name.is_empty() { format!("__{}", start + i) } else { p.name.clone() })
+            .collect();
+        self.create_normalized_tuple_type(element_types, element_flags, Some(labels))
     }
 }
 
@@ -3727,10 +11744,10 @@ mod tests {
     fn test_type_to_string() {
         let binder = Binder::new();
         let checker = Checker::new(binder);
-        assert_eq!(checker.type_to_string(checker.type_table.string_type), "string");
-        assert_eq!(checker.type_to_string(checker.type_table.number_type), "number");
-        assert_eq!(checker.type_to_string(checker.type_table.boolean_type), "boolean");
-        assert_eq!(checker.type_to_string(checker.type_table.void_type), "void");
-        assert_eq!(checker.type_to_string(checker.type_table.any_type), "any");
+        assert_eq!(checker.type_to_string(checker.type_table.string_type, TypeFormatFlags::NONE), "string");
+        assert_eq!(checker.type_to_string(checker.type_table.number_type, TypeFormatFlags::NONE), "number");
+        assert_eq!(checker.type_to_string(checker.type_table.boolean_type, TypeFormatFlags::NONE), "boolean");
+        assert_eq!(checker.type_to_string(checker.type_table.void_type, TypeFormatFlags::NONE), "void");
+        assert_eq!(checker.type_to_string(checker.type_table.any_type, TypeFormatFlags::NONE), "any");
     }
 }
@@ -0,0 +1,585 @@
+//! Building type nodes from types, which is how types are displayed: a type
+//! becomes a synthesized type node, which the printer writes as text.
+
+use super::*;
+use bumpalo::Bump;
+use rscript_nodebuilder::{NodeBuilder, NodeBuilderContext, NodeBuilderFlags, NodeFactory};
+
+/// The position tsc's type ids give each intrinsic type in a union. Other
+/// types follow, in the order they were created.
+fn intrinsic_union_rank(kind: &TypeKind) -> Option<u32> {
+    let rank = match kind {
+        TypeKind::Intrinsic { name } => match *name {
+            "any" => 0,
+            "unknown" => 1,
+            "undefined" => 2,
+            "null" => 3,
+            "string" => 4,
+            "number" => 5,
+            "bigint" => 6,
+            "boolean" => 9,
+            "symbol" => 10,
+            "void" => 11,
+            "never" => 12,
+            "object" => 13,
+            _ => return None,
+        },
+        TypeKind::BooleanLiteral { value: false } => 7,
+        TypeKind::BooleanLiteral { value: true } => 8,
+        _ => return None,
+    };
+    Some(rank)
+}
+
+/// The keyword naming an intrinsic type.
+fn intrinsic_keyword(name: &str) -> Option<SyntaxKind> {
+    let kind = match name {
+        "any" => SyntaxKind::AnyKeyword,
+        "unknown" => SyntaxKind::UnknownKeyword,
+        "string" => SyntaxKind::StringKeyword,
+        "number" => SyntaxKind::NumberKeyword,
+        "boolean" => SyntaxKind::BooleanKeyword,
+        "void" => SyntaxKind::VoidKeyword,
+        "undefined" => SyntaxKind::UndefinedKeyword,
+        "null" => SyntaxKind::NullKeyword,
+        "never" => SyntaxKind::NeverKeyword,
+        "bigint" => SyntaxKind::BigIntKeyword,
+        "symbol" => SyntaxKind::SymbolKeyword,
+        "object" => SyntaxKind::ObjectKeyword,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// Most dimensions of a nested array type written out, which ends the
+/// array types an array type is circularly the element of.
+const MAX_ARRAY_TYPE_DIMENSIONS: u32 = 1000;
+
+impl TypeFormatFlags {
+    fn to_node_builder_flags(self) -> NodeBuilderFlags {
+        let mut flags = NodeBuilderFlags::NONE;
+        flags.set(NodeBuilderFlags::NO_TRUNCATION, self.contains(TypeFormatFlags::NO_TRUNCATION));
+        flags.set(NodeBuilderFlags::WRITE_ARRAY_AS_GENERIC_TYPE, self.contains(TypeFormatFlags::WRITE_ARRAY_AS_GENERIC_TYPE));
+        flags.set(
+            NodeBuilderFlags::USE_SINGLE_QUOTES_FOR_STRING_LITERAL_TYPE,
+            self.contains(TypeFormatFlags::USE_SINGLE_QUOTES_FOR_STRING_LITERAL_TYPE),
+        );
+        flags.set(NodeBuilderFlags::IN_TYPE_ALIAS, self.contains(TypeFormatFlags::IN_TYPE_ALIAS));
+        flags
+    }
+}
+
+impl Checker {
+    /// The options a type is built with: those asked for, and no
+    /// truncation under noErrorTruncation.
+    fn node_builder_context(&self, flags: TypeFormatFlags) -> NodeBuilderContext {
+        let mut flags = flags.to_node_builder_flags();
+        if self.no_error_truncation {
+            flags |= NodeBuilderFlags::NO_TRUNCATION;
+        }
+        NodeBuilderContext::new(flags)
+    }
+
+    /// Format a type as text, by printing the type node built for it.
+    pub(super) fn type_to_string_worker(&self, type_id: TypeId, flags: TypeFormatFlags) -> String {
+        let arena = Bump::new();
+        let factory = self.node_builder.factory(&arena);
+        let mut context = self.node_builder_context(flags);
+        let node = self.type_to_type_node(&factory, &mut context, type_id, 0);
+        let text = self.node_builder.print_type_node(&node);
+        NodeBuilder::truncate_type_text(text, context.flags.contains(NodeBuilderFlags::NO_TRUNCATION))
+    }
+
+    /// Format a signature as text, by printing the call signature built for it.
+    pub(super) fn signature_to_string_worker(&self, sig: &Signature, flags: TypeFormatFlags) -> String {
+        let arena = Bump::new();
+        let factory = self.node_builder.factory(&arena);
+        let mut context = self.node_builder_context(flags);
+        let (type_parameters, parameters, return_type) = self.signature_to_signature_parts(&factory, &mut context, sig, 0);
+        let node = factory.call_signature(type_parameters, parameters, return_type);
+        self.node_builder.print_type_element(&node)
+    }
+
+    /// The name a type declared in a namespace is displayed by: qualified
+    /// by the namespaces it is declared in.
+    fn qualified_type_name(&self, name: &str) -> String {
+        let (declared, _) = name.split_once('.').unwrap_or((name, ""));
+        match self.declaration_namespaces.get(declared) {
+            Some(namespace) => format!("{}.{}", namespace, name),
+            None => name.to_string(),
+        }
+    }
+
+    /// A reference to a named type, such as `N.C` or `typeof E`.
+    fn named_type_reference<'a>(
+        &self,
+        f: &NodeFactory<'a>,
+        context: &mut NodeBuilderContext,
+        name: &str,
+        type_arguments: Vec<TypeNode<'a>>,
+    ) -> TypeNode<'a> {
+        context.approximate_length += name.len();
+        match name.strip_prefix("typeof ") {
+            Some(value) => f.type_query(f.entity_name(&self.qualified_type_name(value))),
+            None => f.type_reference(f.entity_name(&self.qualified_type_name(name)), type_arguments),
+        }
+    }
+
+    /// A placeholder for a type that is not written out.
+    fn elided_type_node<'a>(&self, f: &NodeFactory<'a>, context: &mut NodeBuilderContext) -> TypeNode<'a> {
+        context.approximate_length += 3;
+        f.type_reference(EntityName::Identifier(f.identifier("...")), Vec::new())
+    }
+
+    fn type_to_type_node<'a>(
+        &self,
+        f: &NodeFactory<'a>,
+        context: &mut NodeBuilderContext,
+        type_id: TypeId,
+        depth: u32,
+    ) -> TypeNode<'a> {
+        if depth > MAX_TYPE_TO_STRING_DEPTH {
+            return self.elided_type_node(f, context);
+        }
+        // Enums and their members are written by name
+        if let Some(name) = self.enum_type_names.get(&type_id) {
+            return self.named_type_reference(f, context, name, Vec::new());
+        }
+        // Standard library generic interfaces are written as references
+        if let Some((name, args)) = self.global_type_references.get(&type_id) {
+            let args = self.map_to_type_nodes(f, context, args, false, depth);
+            return self.named_type_reference(f, context, name, args);
+        }
+        // So are aliased types, except as the type of their own declaration
+        if let Some((name, args)) = self.type_alias_references.get(&type_id) {
+            let is_declaration = depth == 0 && context.flags.contains(NodeBuilderFlags::IN_TYPE_ALIAS);
+            let is_structured = self.type_table.get(type_id).flags
+                .intersects(TypeFlags::OBJECT | TypeFlags::UNION | TypeFlags::INTERSECTION | TypeFlags::ANY);
+            if !is_declaration && is_structured {
+                let outer = self.type_aliases.get(name).map_or(0, |alias| alias.outer_type_parameter_count);
+                let args = args.get(outer..).unwrap_or_default();
+                let args = self.map_to_type_nodes(f, context, args, false, depth);
+                return self.named_type_reference(f, context, name, args);
+            }
+        }
+        let ty = self.type_table.get(type_id);
+        match &ty.kind {
+            TypeKind::Intrinsic { name } => {
+                context.approximate_length += name.len();
+                match intrinsic_keyword(name) {
+                    Some(kind) => f.keyword_type(kind),
+                    None => f.type_reference(f.entity_name(name), Vec::new()),
+                }
+            }
+            TypeKind::BooleanLiteral { value } => {
+                context.approximate_length += if *value { 4 } else { 5 };
+                f.boolean_literal_type(*value)
+            }
+            TypeKind::StringLiteral { value, .. } => {
+                context.approximate_length += value.len() + 2;
+                let single_quote = context.flags.contains(NodeBuilderFlags::USE_SINGLE_QUOTES_FOR_STRING_LITERAL_TYPE);
+                f.string_literal_type(value, single_quote)
+            }
+            TypeKind::NumberLiteral { value } => {
+                let text = number_to_string(*value);
+                context.approximate_length += text.len();
+                f.numeric_literal_type(&text)
+            }
+            TypeKind::BigIntLiteral { value } => {
+                context.approximate_length += value.len();
+                f.bigint_literal_type(value)
+            }
+            TypeKind::Union { types } => {
+                let types = self.format_union_types(types);
+                let mut nodes = self.map_to_type_nodes(f, context, &types, true, depth);
+                if nodes.len() == 1 {
+                    return nodes.remove(0);
+                }
+                f.union_type(nodes)
+            }
+            TypeKind::Intersection { types } => {
+                let mut nodes = self.map_to_type_nodes(f, context, types, true, depth);
+                if nodes.len() == 1 {
+                    return nodes.remove(0);
+                }
+                f.intersection_type(nodes)
+            }
+            TypeKind::ObjectType { .. } => self.object_type_to_type_node(f, context, type_id, depth),
+            TypeKind::Tuple { element_types, element_flags } => {
+                context.approximate_length += 2;
+                let labels = self.type_table.get_tuple_labels(type_id);
+                let nodes = self.map_to_type_nodes(f, context, element_types, false, depth);
+                // A truncated tuple is just `[...]`
+                if nodes.len() != element_types.len() {
+                    return f.tuple_type(nodes);
+                }
+                let elements: Vec<_> = nodes.into_iter().enumerate()
+                    .map(|(i, node)| {
+                        let flag = element_flags.get(i).copied().unwrap_or(ElementFlags::Required);
+                        let is_variable = matches!(flag, ElementFlags::Rest | ElementFlags::Variadic);
+                        let node = if flag == ElementFlags::Rest { f.array_type(node) } else { node };
+                        match labels.map(|labels| labels[i].as_str()) {
+                            Some(label) => f.named_tuple_member(is_variable, label, flag == ElementFlags::Optional, node),
+                            None if is_variable => f.rest_type(node),
+                            None if flag == ElementFlags::Optional => f.optional_type(node),
+                            None => node,
+                        }
+                    })
+                    .collect();
+                if self.type_table.is_readonly_tuple(type_id) {
+                    context.approximate_length += 9;
+                    return f.type_operator(SyntaxKind::ReadonlyKeyword, f.tuple_type(elements));
+                }
+                f.tuple_type(elements)
+            }
+            TypeKind::TypeParameter { name, .. } => {
+                context.approximate_length += name.len();
+                if name == "this" {
+                    return f.this_type();
+                }
+                f.type_reference(EntityName::Identifier(f.identifier(name)), Vec::new())
+            }
+            TypeKind::UniqueSymbol { name } => {
+                context.approximate_length += name.len() + 7;
+                f.type_query(f.entity_name(name))
+            }
+            TypeKind::Index { target } => {
+                context.approximate_length += 6;
+                let target = self.type_to_type_node(f, context, *target, depth + 1);
+                f.type_operator(SyntaxKind::KeyOfKeyword, target)
+            }
+            TypeKind::IndexedAccess { object_type, index_type } => {
+                context.approximate_length += 2;
+                let object_type = self.type_to_type_node(f, context, *object_type, depth + 1);
+                let index_type = self.type_to_type_node(f, context, *index_type, depth + 1);
+                f.indexed_access_type(object_type, index_type)
+            }
+            TypeKind::Conditional { check_type, extends_type, true_type, false_type } => {
+                context.approximate_length += 15;
+                let check_type = self.type_to_type_node(f, context, *check_type, depth + 1);
+                let extends_type = self.type_to_type_node(f, context, *extends_type, depth + 1);
+                let true_type = self.type_to_type_node(f, context, *true_type, depth + 1);
+                let false_type = self.type_to_type_node(f, context, *false_type, depth + 1);
+                f.conditional_type(check_type, extends_type, true_type, false_type)
+            }
+            TypeKind::Mapped {
+                type_parameter, constraint_type, name_type, template_type, readonly_modifier, optional_modifier, ..
+            } => {
+                context.approximate_length += 10;
+                let readonly_modifier = match readonly_modifier {
+                    MappedTypeModifier::Add => Some(SyntaxKind::ReadonlyKeyword),
+                    MappedTypeModifier::Remove => Some(SyntaxKind::MinusToken),
+                    MappedTypeModifier::None => None,
+                };
+                let optional_modifier = match optional_modifier {
+                    MappedTypeModifier::Add => Some(SyntaxKind::QuestionToken),
+                    MappedTypeModifier::Remove => Some(SyntaxKind::MinusToken),
+                    MappedTypeModifier::None => None,
+                };
+                let parameter_name = match &self.type_table.get(*type_parameter).kind {
+                    TypeKind::TypeParameter { name, .. } => name.clone(),
+                    _ => "K".to_string(),
+                };
+                let constraint = self.type_to_type_node(f, context, *constraint_type, depth + 1);
+                let type_parameter = f.type_parameter(&parameter_name, Some(constraint), None);
+                let name_type = name_type.map(|n| self.type_to_type_node(f, context, n, depth + 1));
+                let template = template_type.unwrap_or(self.type_table.any_type);
+                let template = self.type_to_type_node(f, context, template, depth + 1);
+                f.mapped_type(readonly_modifier, type_parameter, name_type, optional_modifier, template)
+            }
+            TypeKind::TemplateLiteral { texts, types } => {
+                context.approximate_length += texts.iter().map(String::len).sum::<usize>() + 2;
+                let spans = types.iter().zip(&texts[1..])
+                    .map(|(&t, following)| (self.type_to_type_node(f, context, t, depth + 1), following.clone()))
+                    .collect();
+                f.template_literal_type(&texts[0], spans)
+            }
+            TypeKind::StringMapping { mapping, target } => {
+                let target = self.type_to_type_node(f, context, *target, depth + 1);
+                self.named_type_reference(f, context, mapping.name(), vec![target])
+            }
+            _ => {
+                context.approximate_length += 3;
+                f.keyword_type(SyntaxKind::AnyKeyword)
+            }
+        }
+    }
+
+    /// Build the nodes of a list of types. Past the truncation length the
+    /// middle of the list is elided as `... N more ...`, or the whole of a
+    /// list that is not a bare union or intersection as `...`.
+    fn map_to_type_nodes<'a>(
+        &self,
+        f: &NodeFactory<'a>,
+        context: &mut NodeBuilderContext,
+        types: &[TypeId],
+        is_bare_list: bool,
+        depth: u32,
+    ) -> Vec<TypeNode<'a>> {
+        let Some(&last) = types.last() else { return Vec::new() };
+        let elided = |count: usize| EntityName::Identifier(f.identifier(&format!("... {} more ...", count)));
+        if context.check_truncation_length() {
+            if !is_bare_list {
+                return vec![self.elided_type_node(f, context)];
+            }
+            if types.len() > 2 {
+                return vec![
+                    self.type_to_type_node(f, context, types[0], depth + 1),
+                    f.type_reference(elided(types.len() - 2), Vec::new()),
+                    self.type_to_type_node(f, context, last, depth + 1),
+                ];
+            }
+        }
+        let mut nodes = Vec::with_capacity(types.len());
+        for (i, &type_id) in types.iter().enumerate() {
+            if context.check_truncation_length() && i + 3 < types.len() - 1 {
+                nodes.push(f.type_reference(elided(types.len() - i - 1), Vec::new()));
+                nodes.push(self.type_to_type_node(f, context, last, depth + 1));
+                break;
+            }
+            context.approximate_length += 2;
+            nodes.push(self.type_to_type_node(f, context, type_id, depth + 1));
+        }
+        nodes
+    }
+
+    /// Order the members of a union the way tsc displays them: by type id,
+    /// with `true | false` written as `boolean`, all the members of an enum
+    /// as the enum, and `null` and `undefined` last.
+    fn format_union_types(&self, types: &[TypeId]) -> Vec<TypeId> {
+        let mut sorted = types.to_vec();
+        sorted.sort_by_key(|&t| match intrinsic_union_rank(&self.type_table.get(t).kind) {
+            Some(rank) => (rank, 0),
+            None => (u32::MAX, t.0),
+        });
+        let has_literal = |value: bool| {
+            sorted.iter().any(|&t| matches!(self.type_table.get(t).kind, TypeKind::BooleanLiteral { value: v } if v == value))
+        };
+        let has_boolean = has_literal(true) && has_literal(false);
+        let mut result = Vec::with_capacity(sorted.len());
+        let mut has_null = false;
+        let mut has_undefined = false;
+        for &t in &sorted {
+            let ty = self.type_table.get(t);
+            if ty.flags.contains(TypeFlags::NULL) {
+                has_null = true;
+                continue;
+            }
+            if ty.flags.contains(TypeFlags::UNDEFINED) {
+                has_undefined = true;
+                continue;
+            }
+            if has_boolean && matches!(ty.kind, TypeKind::BooleanLiteral { .. }) {
+                if !result.contains(&self.type_table.boolean_type) {
+                    result.push(self.type_table.boolean_type);
+                }
+                continue;
+            }
+            if let Some(&base) = self.enum_literal_bases.get(&t) {
+                if self.contains_all_enum_members(&sorted, base) {
+                    if !result.contains(&base) {
+                        result.push(base);
+                    }
+                    continue;
+                }
+            }
+            result.push(t);
+        }
+        if has_null {
+            result.push(self.type_table.null_type);
+        }
+        if has_undefined {
+            result.push(self.type_table.undefined_type);
+        }
+        result
+    }
+
+    /// Whether a union has a member for each member of an enum.
+    fn contains_all_enum_members(&self, types: &[TypeId], enum_type: TypeId) -> bool {
+        let TypeKind::Union { types: members } = &self.type_table.get(enum_type).kind else { return false };
+        let present = types.iter().filter(|t| self.enum_literal_bases.get(t) == Some(&enum_type)).count();
+        present >= members.len()
+    }
+
+    /// The element type of a type written as `T[]`.
+    fn mutable_array_element_type(&self, type_id: TypeId) -> Option<TypeId> {
+        if self.enum_type_names.contains_key(&type_id)
+            || self.global_type_references.contains_key(&type_id)
+            || self.type_alias_references.contains_key(&type_id)
+            || self.class_of_type.contains_key(&type_id)
+        {
+            return None;
+        }
+        let TypeKind::ObjectType { index_infos, .. } = &self.type_table.get(type_id).kind else { return None };
+        let info = index_infos.first()?;
+        let is_array = !info.is_readonly && self.type_table.get(info.key_type).flags.intersects(TypeFlags::NUMBER_LIKE);
+        is_array.then_some(info.type_id)
+    }
+
+    fn object_type_to_type_node<'a>(
+        &self,
+        f: &NodeFactory<'a>,
+        context: &mut NodeBuilderContext,
+        type_id: TypeId,
+        depth: u32,
+    ) -> TypeNode<'a> {
+        let TypeKind::ObjectType { index_infos, members, call_signatures, construct_signatures, .. } =
+            &self.type_table.get(type_id).kind
+        else {
+            unreachable!("object type node built for a type that is not an object type")
+        };
+        // Classes are written by name
+        if let Some((name, is_static)) = self.class_of_type.get(&type_id) {
+            let name = if *is_static { format!("typeof {}", name) } else { name.clone() };
+            return self.named_type_reference(f, context, &name, Vec::new());
+        }
+        // So are interfaces, with the type arguments of an instantiation
+        if let Some((name, args)) = self.interface_type_references.get(&type_id) {
+            let args = self.map_to_type_nodes(f, context, args, false, depth);
+            return self.named_type_reference(f, context, name, args);
+        }
+        // Arrays are object types with a number index signature
+        if let Some(info) = index_infos.first() {
+            if self.type_table.get(info.key_type).flags.intersects(TypeFlags::NUMBER_LIKE) {
+                if context.flags.contains(NodeBuilderFlags::WRITE_ARRAY_AS_GENERIC_TYPE) {
+                    let element = self.type_to_type_node(f, context, info.type_id, depth + 1);
+                    let name = if info.is_readonly { "ReadonlyArray" } else { "Array" };
+                    return self.named_type_reference(f, context, name, vec![element]);
+                }
+                // Arrays nested in an array, like `string[][]`, are written
+                // out however deep they are
+                let mut element_type = info.type_id;
+                let mut dimensions = 1;
+                while dimensions < MAX_ARRAY_TYPE_DIMENSIONS {
+                    let Some(inner) = self.mutable_array_element_type(element_type) else { break };
+                    element_type = inner;
+                    dimensions += 1;
+                }
+                let mut array = self.type_to_type_node(f, context, element_type, depth + 1);
+                for _ in 0..dimensions {
+                    context.approximate_length += 2;
+                    array = f.array_type(array);
+                }
+                if info.is_readonly {
+                    context.approximate_length += 9;
+                    return f.type_operator(SyntaxKind::ReadonlyKeyword, array);
+                }
+                return array;
+            }
+        }
+        // A single signature is written as a function or constructor type
+        if members.is_empty() && index_infos.is_empty() {
+            match (call_signatures.as_slice(), construct_signatures.as_slice()) {
+                ([sig], []) => {
+                    let (type_parameters, parameters, return_type) = self.signature_to_signature_parts(f, context, sig, depth);
+                    return f.function_type(type_parameters, parameters, return_type);
+                }
+                ([], [sig]) => {
+                    context.approximate_length += 4;
+                    let (type_parameters, parameters, return_type) = self.signature_to_signature_parts(f, context, sig, depth);
+                    return f.constructor_type(type_parameters, parameters, return_type);
+                }
+                _ => {}
+            }
+        }
+        context.approximate_length += 2;
+        if context.check_truncation_length() {
+            let elided = PropertyName::Identifier(f.identifier("..."));
+            return f.type_literal(vec![f.property_signature(false, elided, false, None)]);
+        }
+        let mut elements = Vec::new();
+        for sig in call_signatures {
+            let (type_parameters, parameters, return_type) = self.signature_to_signature_parts(f, context, sig, depth);
+            elements.push(f.call_signature(type_parameters, parameters, return_type));
+        }
+        for sig in construct_signatures {
+            let (type_parameters, parameters, return_type) = self.signature_to_signature_parts(f, context, sig, depth);
+            elements.push(f.construct_signature(type_parameters, parameters, return_type));
+        }
+        for info in index_infos {
+            context.approximate_length += 6;
+            let key_type = self.type_to_type_node(f, context, info.key_type, depth + 1);
+            let value_type = self.type_to_type_node(f, context, info.type_id, depth + 1);
+            elements.push(f.index_signature(info.is_readonly, f.parameter(false, "x", false, key_type), value_type));
+        }
+        let properties: Vec<(&String, TypeId)> = members.iter().map(|(name, &t)| (name, t)).collect();
+        for (i, &(name, property_type)) in properties.iter().enumerate() {
+            if context.check_truncation_length() && i + 3 < properties.len() - 1 {
+                let elided = PropertyName::Identifier(f.identifier(&format!("... {} more ...", properties.len() - i - 1)));
+                elements.push(f.property_signature(false, elided, false, None));
+                let &(last_name, last_type) = properties.last().expect("more properties follow");
+                elements.push(self.property_to_type_element(f, context, type_id, last_name, last_type, depth));
+                break;
+            }
+            elements.push(self.property_to_type_element(f, context, type_id, name, property_type, depth));
+        }
+        f.type_literal(elements)
+    }
+
+    fn property_to_type_element<'a>(
+        &self,
+        f: &NodeFactory<'a>,
+        context: &mut NodeBuilderContext,
+        object_type: TypeId,
+        name: &str,
+        property_type: TypeId,
+        depth: u32,
+    ) -> TypeElement<'a> {
+        let property_flags = self.type_table.get_property_flags(object_type, name);
+        context.approximate_length += name.len() + 1;
+        let type_node = self.type_to_type_node(f, context, property_type, depth + 1);
+        f.property_signature(
+            property_flags.contains(PropertyFlags::READONLY),
+            f.property_name(name),
+            property_flags.contains(PropertyFlags::OPTIONAL),
+            Some(type_node),
+        )
+    }
+
+    /// Build the type parameters, parameters and return type of a signature.
+    fn signature_to_signature_parts<'a>(
+        &self,
+        f: &NodeFactory<'a>,
+        context: &mut NodeBuilderContext,
+        sig: &Signature,
+        depth: u32,
+    ) -> (Vec<TypeParameterDeclaration<'a>>, Vec<ParameterDeclaration<'a>>, TypeNode<'a>) {
+        let type_parameters = sig.type_parameters.iter()
+            .map(|&tp| self.type_parameter_to_declaration(f, context, tp, depth + 1))
+            .collect();
+        let last = sig.parameters.len().saturating_sub(1);
+        let parameters = sig.parameters.iter().enumerate()
+            .map(|(i, p)| {
+                // Destructured parameters have no name of their own
+                let name = if p.name.is_empty() { format!("__{}", i) } else { p.name.clone() };
+                context.approximate_length += name.len() + 3;
+                let type_node = self.type_to_type_node(f, context, p.type_id, depth + 1);
+                f.parameter(sig.has_rest_parameter && i == last, &name, p.optional, type_node)
+            })
+            .collect();
+        let return_type = match &sig.type_predicate {
+            Some(predicate) => {
+                let type_node = predicate.type_id.map(|t| self.type_to_type_node(f, context, t, depth + 1));
+                f.type_predicate(predicate.is_assertion(), predicate.parameter_name.as_deref(), type_node)
+            }
+            None => self.type_to_type_node(f, context, sig.return_type, depth + 1),
+        };
+        (type_parameters, parameters, return_type)
+    }
+
+    fn type_parameter_to_declaration<'a>(
+        &self,
+        f: &NodeFactory<'a>,
+        context: &mut NodeBuilderContext,
+        type_parameter: TypeId,
+        depth: u32,
+    ) -> TypeParameterDeclaration<'a> {
+        let TypeKind::TypeParameter { name, constraint, default } = &self.type_table.get(type_parameter).kind else {
+            return f.type_parameter("T", None, None);
+        };
+        context.approximate_length += name.len();
+        let constraint = constraint.map(|c| self.type_to_type_node(f, context, c, depth + 1));
+        let default = default.map(|d| self.type_to_type_node(f, context, d, depth + 1));
+        f.type_parameter(name, constraint, default)
+    }
+}
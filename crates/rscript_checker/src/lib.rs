@@ -7,5 +7,8 @@
 mod checker;
 mod types;
 
-pub use checker::Checker;
-pub use types::{Type, TypeTable};
+pub use checker::{Checker, FileCheckResult};
+pub use types::{
+    IndexInfo, PropertyFlags, Signature, SignatureKind, SignatureParameter, Type, TypeFormatFlags, TypeKind,
+    TypePredicate, TypePredicateKind, TypeTable,
+};
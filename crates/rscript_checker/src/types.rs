@@ -5,6 +5,7 @@
 
 use indexmap::IndexMap;
 use rscript_ast::types::{ObjectFlags, TypeFlags, TypeId, SymbolId};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 /// A type in the TypeScript type system.
 #[derive(Debug, Clone)]
@@ -61,9 +62,14 @@ pub enum TypeKind {
     },
     /// Type parameter (T)
     TypeParameter {
+        name: String,
         constraint: Option<TypeId>,
         default: Option<TypeId>,
     },
+    /// Deferred index type (keyof T) for a generic T
+    Index {
+        target: TypeId,
+    },
     /// Indexed access type (T[K])
     IndexedAccess {
         object_type: TypeId,
@@ -76,17 +82,33 @@ pub enum TypeKind {
         true_type: TypeId,
         false_type: TypeId,
     },
-    /// Mapped type ({ [K in T]: U })
+    /// Mapped type ({ [K in T as N]: U }), kept only while its constraint is generic
     Mapped {
         type_parameter: TypeId,
         constraint_type: TypeId,
+        name_type: Option<TypeId>,
         template_type: Option<TypeId>,
+        /// The type whose property modifiers are preserved (T in `keyof T`)
+        modifiers_type: Option<TypeId>,
+        readonly_modifier: MappedTypeModifier,
+        optional_modifier: MappedTypeModifier,
     },
     /// Template literal type (`hello ${T}`)
     TemplateLiteral {
         texts: Vec<String>,
         types: Vec<TypeId>,
     },
+    /// Intrinsic string mapping (`Uppercase<T>`), kept only while its argument
+    /// is generic or a non-literal string type
+    StringMapping {
+        mapping: StringMappingKind,
+        target: TypeId,
+    },
+    /// `unique symbol` type of a const declaration or a well-known symbol,
+    /// displayed as `typeof name`
+    UniqueSymbol {
+        name: String,
+    },
     /// Substitution type (internal for conditional type distribution)
     Substitution {
         base_type: TypeId,
@@ -112,6 +134,47 @@ pub struct Signature {
     pub return_type: TypeId,
     pub min_argument_count: u32,
     pub has_rest_parameter: bool,
+    /// The type of an explicit `this` parameter.
+    pub this_type: Option<TypeId>,
+    /// Whether the signature is declared by a method or constructor. Their
+    /// parameters stay bivariant under `strictFunctionTypes`.
+    pub is_method: bool,
+    /// The type predicate in place of the return type, if any.
+    pub type_predicate: Option<TypePredicate>,
+}
+
+/// Which signatures of a type to get: those it is called with, or those
+/// it is constructed with by `new`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureKind {
+    Call,
+    Construct,
+}
+
+/// A type predicate: `x is T`, `this is T`, `asserts x is T` or `asserts x`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypePredicate {
+    pub kind: TypePredicateKind,
+    /// The narrowed parameter, for `x is T` and `asserts x`.
+    pub parameter_name: Option<String>,
+    pub parameter_index: Option<usize>,
+    /// The type narrowed to; `None` for `asserts x`, which asserts truthiness.
+    pub type_id: Option<TypeId>,
+}
+
+/// The kind of a type predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypePredicateKind {
+    This,
+    Identifier,
+    AssertsThis,
+    AssertsIdentifier,
+}
+
+impl TypePredicate {
+    pub fn is_assertion(&self) -> bool {
+        matches!(self.kind, TypePredicateKind::AssertsThis | TypePredicateKind::AssertsIdentifier)
+    }
 }
 
 /// A parameter in a signature.
@@ -130,6 +193,86 @@ pub struct IndexInfo {
     pub is_readonly: bool,
 }
 
+/// The intrinsic string manipulation types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StringMappingKind {
+    Uppercase,
+    Lowercase,
+    Capitalize,
+    Uncapitalize,
+}
+
+impl StringMappingKind {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Uppercase" => Some(Self::Uppercase),
+            "Lowercase" => Some(Self::Lowercase),
+            "Capitalize" => Some(Self::Capitalize),
+            "Uncapitalize" => Some(Self::Uncapitalize),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Uppercase => "Uppercase",
+            Self::Lowercase => "Lowercase",
+            Self::Capitalize => "Capitalize",
+            Self::Uncapitalize => "Uncapitalize",
+        }
+    }
+
+    /// Apply the mapping to a string value.
+    pub fn apply(self, value: &str) -> String {
+        let mut chars = value.chars();
+        match self {
+            Self::Uppercase => value.to_uppercase(),
+            Self::Lowercase => value.to_lowercase(),
+            Self::Capitalize => match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            },
+            Self::Uncapitalize => match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            },
+        }
+    }
+}
+
+/// A `+`/`-` modifier on a mapped type's `readonly` or `?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappedTypeModifier {
+    None,
+    Add,
+    Remove,
+}
+
+bitflags::bitflags! {
+    /// Modifiers on an individual property of an object type.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PropertyFlags: u8 {
+        const OPTIONAL = 1 << 0;
+        const READONLY = 1 << 1;
+    }
+}
+
+bitflags::bitflags! {
+    /// Options for formatting a type as text.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TypeFormatFlags: u32 {
+        const NONE = 0;
+        /// Write array types as `Array<T>` rather than `T[]`.
+        const WRITE_ARRAY_AS_GENERIC_TYPE = 1 << 0;
+        /// Quote string literal types with `'` rather than `"`.
+        const USE_SINGLE_QUOTES_FOR_STRING_LITERAL_TYPE = 1 << 1;
+        /// Print an alias declaration's own type rather than the alias name.
+        const IN_TYPE_ALIAS = 1 << 2;
+        /// Print types in full rather than eliding members past a length.
+        const NO_TRUNCATION = 1 << 3;
+    }
+}
+
 /// Element flags for tuple types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ElementFlags {
@@ -143,6 +286,17 @@ pub enum ElementFlags {
 #[derive(Debug)]
 pub struct TypeTable {
     types: Vec<Type>,
+    /// Property modifiers, keyed by object type then property name.
+    /// Properties without an entry have no modifiers.
+    property_flags: FxHashMap<TypeId, FxHashMap<String, PropertyFlags>>,
+    /// Write types of accessor properties whose setter accepts a type other
+    /// than the one the getter returns, keyed like `property_flags`.
+    write_types: FxHashMap<TypeId, FxHashMap<String, TypeId>>,
+    /// Tuple types that are `readonly`.
+    readonly_tuples: FxHashSet<TypeId>,
+    /// Element labels of labeled tuple types (`[x: number, y?: string]`),
+    /// used only for display.
+    tuple_labels: FxHashMap<TypeId, Vec<String>>,
     // Well-known types
     pub any_type: TypeId,
     pub unknown_type: TypeId,
@@ -164,6 +318,10 @@ impl TypeTable {
     pub fn new() -> Self {
         let mut table = Self {
             types: Vec::with_capacity(1024),
+            property_flags: FxHashMap::default(),
+            write_types: FxHashMap::default(),
+            readonly_tuples: FxHashSet::default(),
+            tuple_labels: FxHashMap::default(),
             any_type: TypeId(0),
             unknown_type: TypeId(1),
             string_type: TypeId(2),
@@ -226,6 +384,69 @@ impl TypeTable {
         &mut self.types[id.index()]
     }
 
+    /// Get the modifiers of a property of an object type.
+    pub fn get_property_flags(&self, id: TypeId, name: &str) -> PropertyFlags {
+        self.property_flags
+            .get(&id)
+            .and_then(|props| props.get(name))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Set the modifiers of a property of an object type.
+    pub fn set_property_flags(&mut self, id: TypeId, name: &str, flags: PropertyFlags) {
+        if flags.is_empty() {
+            if let Some(props) = self.property_flags.get_mut(&id) {
+                props.remove(name);
+            }
+            return;
+        }
+        self.property_flags.entry(id).or_default().insert(name.to_string(), flags);
+    }
+
+    /// Copy all property modifiers and write types from one object type to
+    /// another.
+    pub fn copy_property_flags(&mut self, from: TypeId, to: TypeId) {
+        if let Some(props) = self.property_flags.get(&from).cloned() {
+            self.property_flags.entry(to).or_default().extend(props);
+        }
+        if let Some(props) = self.write_types.get(&from).cloned() {
+            self.write_types.entry(to).or_default().extend(props);
+        }
+    }
+
+    /// Get the type a property accepts when written, if it differs from the
+    /// type it is read as.
+    pub fn get_write_type(&self, id: TypeId, name: &str) -> Option<TypeId> {
+        self.write_types.get(&id).and_then(|props| props.get(name)).copied()
+    }
+
+    /// Set the type a property of an object type accepts when written.
+    pub fn set_write_type(&mut self, id: TypeId, name: &str, write_type: TypeId) {
+        self.write_types.entry(id).or_default().insert(name.to_string(), write_type);
+    }
+
+    /// Whether a tuple type is `readonly`.
+    pub fn is_readonly_tuple(&self, id: TypeId) -> bool {
+        self.readonly_tuples.contains(&id)
+    }
+
+    /// Mark a tuple type as `readonly`.
+    pub fn set_readonly_tuple(&mut self, id: TypeId) {
+        self.readonly_tuples.insert(id);
+    }
+
+    /// Get the element labels of a tuple type, if it was written with them.
+    pub fn get_tuple_labels(&self, id: TypeId) -> Option<&[String]> {
+        self.tuple_labels.get(&id).map(|labels| labels.as_slice())
+    }
+
+    /// Attach element labels to a tuple type. `labels` must have one entry
+    /// per element.
+    pub fn set_tuple_labels(&mut self, id: TypeId, labels: Vec<String>) {
+        self.tuple_labels.insert(id, labels);
+    }
+
     /// Get the total number of types.
     pub fn len(&self) -> usize {
         self.types.len()
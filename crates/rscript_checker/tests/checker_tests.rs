@@ -3,24 +3,50 @@
 //! Tests the full parse -> bind -> check pipeline and verifies diagnostics.
 
 use bumpalo::Bump;
+use rscript_ast::node::Statement;
 use rscript_binder::Binder;
-use rscript_checker::Checker;
+use rscript_checker::{Checker, SignatureKind, TypeFormatFlags};
+use rscript_core::text::TextRange;
+use rscript_diagnostics::{Diagnostic, DiagnosticCategory};
 use rscript_parser::Parser;
+use rscript_tsoptions::{CompilerOptions, JsxEmit, ModuleKind, ScriptTarget};
 
-/// Helper: run the full pipeline (parse -> bind -> check) and return diagnostic messages.
-fn check_source(source: &str) -> Vec<String> {
+/// Helper: run the full pipeline (parse -> bind -> check) on a file named
+/// `file_name` and keep the checker for its diagnostics and queries. The
+/// checker is configured from compiler options when they are given.
+fn check_file(file_name: &str, source: &str, options: Option<&CompilerOptions>) -> Checker {
     let arena = Bump::new();
-    let parser = Parser::new(&arena, "test.ts", source);
+    let parser = Parser::new(&arena, file_name, source);
     let sf = parser.parse_source_file();
 
     let mut binder = Binder::new();
     binder.bind_source_file(&sf);
 
-    let mut checker = Checker::new(binder);
+    let mut checker = match options {
+        Some(options) => Checker::with_compiler_options(binder, options),
+        None => Checker::new(binder),
+    };
     checker.check_source_file(&sf);
+    checker
+}
+
+/// Helper: the messages of the diagnostics a build reports, leaving out suggestions.
+fn reported_messages(mut checker: Checker) -> Vec<String> {
+    checker.take_diagnostics().into_diagnostics().into_iter()
+        .filter(|d| d.category != DiagnosticCategory::Suggestion)
+        .map(|d| d.message_text)
+        .collect()
+}
+
+/// Helper: run the full pipeline and return the messages of the diagnostics
+/// a build reports.
+fn check_source(source: &str) -> Vec<String> {
+    reported_messages(check_file("test.ts", source, None))
+}
 
-    let diags = checker.take_diagnostics();
-    diags.into_diagnostics().into_iter().map(|d| d.message_text).collect()
+/// Helper: run the pipeline and return the full diagnostics, including related information.
+fn check_source_diagnostics(source: &str) -> Vec<Diagnostic> {
+    check_file("test.ts", source, None).take_diagnostics().into_diagnostics()
 }
 
 /// Helper: count diagnostics.
@@ -30,17 +56,25 @@ fn diagnostic_count(source: &str) -> usize {
 
 /// Helper: run the pipeline and return the checker's type_to_string for a variable's inferred type.
 fn get_inferred_type(source: &str, var_name: &str) -> String {
-    let arena = Bump::new();
-    let parser = Parser::new(&arena, "test.ts", source);
-    let sf = parser.parse_source_file();
+    check_file("test.ts", source, None).get_type_string(var_name)
+}
 
-    let mut binder = Binder::new();
-    binder.bind_source_file(&sf);
+/// Helper: like `check_source`, with a checker configured from compiler options.
+fn check_source_with_options(source: &str, options: &CompilerOptions) -> Vec<String> {
+    reported_messages(check_file("test.ts", source, Some(options)))
+}
 
-    let mut checker = Checker::new(binder);
-    checker.check_source_file(&sf);
+/// Helper: like `get_inferred_type`, with a checker configured from compiler options.
+fn get_inferred_type_with_options(source: &str, var_name: &str, options: &CompilerOptions) -> String {
+    check_file("test.ts", source, Some(options)).get_type_string(var_name)
+}
 
-    checker.get_type_string(var_name)
+/// Helper: check a .tsx file, returning the messages with their elaborations.
+fn check_tsx_source(source: &str, options: &CompilerOptions) -> Vec<String> {
+    check_file("test.tsx", source, Some(options)).take_diagnostics().into_diagnostics().into_iter()
+        .filter(|d| d.category != DiagnosticCategory::Suggestion)
+        .map(|d| d.flatten_message_text())
+        .collect()
 }
 
 // ============================================================================
@@ -1546,6 +1580,99 @@ fn test_mapped_type_no_panic() {
     assert!(diags.is_empty(), "{:?}", diags);
 }
 
+#[test]
+fn test_homomorphic_mapped_type_preserves_modifiers() {
+    let t = get_inferred_type(r#"
+        interface User { readonly id: number; name?: string; }
+        type Copy<T> = { [K in keyof T]: T[K] };
+        type X = Copy<User>;
+    "#, "X");
+    assert_eq!(t, "{ readonly id: number; name?: string | undefined; }");
+}
+
+#[test]
+fn test_mapped_type_removes_modifiers() {
+    let t = get_inferred_type(r#"
+        interface User { readonly id: number; name?: string; }
+        type Mutable<T> = { -readonly [K in keyof T]-?: T[K] };
+        type X = Mutable<User>;
+    "#, "X");
+    assert_eq!(t, "{ id: number; name: string; }");
+}
+
+#[test]
+fn test_mapped_type_key_remapping() {
+    let t = get_inferred_type(r#"
+        interface User { id: number; name: string; }
+        type WithoutId<T> = { [K in keyof T as K extends "id" ? never : K]: T[K] };
+        type X = WithoutId<User>;
+    "#, "X");
+    assert_eq!(t, "{ name: string; }");
+}
+
+#[test]
+fn test_mapped_type_deferred_until_instantiated() {
+    let src = r#"
+        interface User { id: number; name: string; }
+        type Opt<T> = Partial<T>;
+        type X = Opt<User>;
+    "#;
+    assert_eq!(get_inferred_type(src, "Opt"), "{ [P in keyof T]?: T[P]; }");
+    assert_eq!(get_inferred_type(src, "X"), "{ id?: number | undefined; name?: string | undefined; }");
+}
+
+#[test]
+fn test_mapped_type_over_tuple_and_array() {
+    let src = r#"
+        type Boxed<T> = { [K in keyof T]: T[K][] };
+        type Pair = Boxed<[string, number]>;
+        type List = Boxed<number[]>;
+        type OptPair = Partial<[string, number]>;
+        type Frozen = Readonly<string[]>;
+    "#;
+    assert_eq!(get_inferred_type(src, "Pair"), "[string[], number[]]");
+    assert_eq!(get_inferred_type(src, "List"), "number[][]");
+    assert_eq!(get_inferred_type(src, "OptPair"), "[string?, number?]");
+    assert_eq!(get_inferred_type(src, "Frozen"), "readonly string[]");
+}
+
+#[test]
+fn test_utility_types_via_mapped_types() {
+    let src = r#"
+        interface Todo { readonly id: number; title: string; done?: boolean; }
+        type P = Partial<Todo>;
+        type R = Required<Todo>;
+        type Picked = Pick<Todo, "id" | "done">;
+        type Omitted = Omit<Todo, "title">;
+        type Flags = Record<"a" | "b", boolean>;
+        type Dict = Record<string, number>;
+    "#;
+    assert_eq!(
+        get_inferred_type(src, "P"),
+        "{ readonly id?: number | undefined; title?: string | undefined; done?: boolean | undefined; }"
+    );
+    assert_eq!(get_inferred_type(src, "R"), "{ readonly id: number; title: string; done: boolean; }");
+    assert_eq!(get_inferred_type(src, "Picked"), "{ readonly id: number; done?: boolean | undefined; }");
+    assert_eq!(get_inferred_type(src, "Omitted"), "{ readonly id: number; done?: boolean | undefined; }");
+    assert_eq!(get_inferred_type(src, "Flags"), "{ a: boolean; b: boolean; }");
+    assert_eq!(get_inferred_type(src, "Dict"), "{ [x: string]: number; }");
+}
+
+#[test]
+fn test_optional_properties_may_be_missing() {
+    let diags = check_source(r#"
+        interface Config { host: string; port: number; }
+        const partial: Partial<Config> = {};
+    "#);
+    assert!(diags.is_empty(), "{:?}", diags);
+
+    let diags = check_source(r#"
+        interface Config { host?: string; }
+        const required: Required<Config> = {};
+    "#);
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+}
+
 // ============================================================================
 // Template literal types
 // ============================================================================
@@ -2403,8 +2530,7 @@ fn test_var_string_infers_string() {
 fn test_const_negative_number() {
     // const with negative number literal (unary expression)
     let t = get_inferred_type(r#"const x = -1;"#, "x");
-    // -1 is a PrefixUnary expression, not a NumericLiteral, so it stays as number
-    assert_eq!(t, "number", "const -1 should be number (prefix unary)");
+    assert_eq!(t, "-1", "const -1 should be the literal -1");
 }
 
 // ============================================================================
@@ -2454,3 +2580,2425 @@ fn test_promise_type_reference() {
     "#);
     assert!(diags.is_empty(), "Promise<string> should not produce errors: {:?}", diags);
 }
+
+// ============================================================================
+// Overload resolution
+// ============================================================================
+
+#[test]
+fn test_overload_declarations_merge_into_one_type() {
+    let source = r#"
+        function f(x: string): string;
+        function f(x: number): number;
+        function f(x: any): any { return x; }
+        const a = f("s");
+        const b = f(1);
+    "#;
+    assert!(check_source(source).is_empty(), "{:?}", check_source(source));
+    assert_eq!(get_inferred_type(source, "a"), "string");
+    assert_eq!(get_inferred_type(source, "b"), "number");
+    // The implementation signature is not visible to callers.
+    assert_eq!(get_inferred_type(source, "f"), "{ (x: string): string; (x: number): number; }");
+}
+
+#[test]
+fn test_no_overload_matches_elaborates_each_candidate() {
+    let diags = check_source_diagnostics(r#"
+        function f(x: string): string;
+        function f(x: number): number;
+        function f(x: any): any { return x; }
+        f(true);
+    "#);
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(diags[0].code, 2769);
+    assert_eq!(
+        diags[0].flatten_message_text(),
+        "No overload matches this call.\n  \
+         Overload 1 of 2, '(x: string): string', gave the following error.\n    \
+         Argument of type 'boolean' is not assignable to parameter of type 'string'.\n  \
+         Overload 2 of 2, '(x: number): number', gave the following error.\n    \
+         Argument of type 'boolean' is not assignable to parameter of type 'number'.",
+    );
+}
+
+#[test]
+fn test_assignability_errors_elaborate_incompatible_properties() {
+    let diags = check_source_diagnostics(r#"
+        interface Config { server: { port: number; host: string } }
+        declare const loaded: { server: { port: string; host: string } };
+        const config: Config = loaded;
+        declare const partial: { server: { port: number } };
+        const other: Config = partial;
+        declare function start(config: Config): void;
+        start(loaded);
+    "#);
+    let messages: Vec<String> = diags.iter().map(|d| d.flatten_message_text()).collect();
+    assert_eq!(messages, vec![
+        "Type '{ server: { port: string; host: string; }; }' is not assignable to type 'Config'.\n  \
+         Types of property 'server' are incompatible.\n    \
+         Type '{ port: string; host: string; }' is not assignable to type '{ port: number; host: string; }'.\n      \
+         Types of property 'port' are incompatible.\n        \
+         Type 'string' is not assignable to type 'number'.",
+        "Type '{ server: { port: number; }; }' is not assignable to type 'Config'.\n  \
+         Types of property 'server' are incompatible.\n    \
+         Type '{ port: number; }' is not assignable to type '{ port: number; host: string; }'.\n      \
+         Property 'host' is missing in type '{ port: number; }' but required in type '{ port: number; host: string; }'.",
+        "Argument of type '{ server: { port: string; host: string; }; }' is not assignable to parameter of type 'Config'.\n  \
+         Types of property 'server' are incompatible.\n    \
+         Type '{ port: string; host: string; }' is not assignable to type '{ port: number; host: string; }'.\n      \
+         Types of property 'port' are incompatible.\n        \
+         Type 'string' is not assignable to type 'number'.",
+    ]);
+}
+
+#[test]
+fn test_polymorphic_this_types() {
+    let source = r#"
+        class QueryBuilder {
+            clauses: string[] = [];
+            where(clause: string): this { this.clauses.push(clause); return this; }
+        }
+        class SelectBuilder extends QueryBuilder {
+            columns: string[] = [];
+            select(column: string): this { this.columns.push(column); return this.where(column); }
+        }
+        interface Chain { next(): this; value: number }
+        interface NamedChain extends Chain { name: string }
+        class Link implements Chain { value = 1; next(): this { return this; } }
+        declare const named: NamedChain;
+        const query = new SelectBuilder().where("a").select("b").where("c");
+        const columns = query.columns;
+        const name = named.next().next().name;
+        const chain: Chain = new Link();
+    "#;
+    assert_eq!(check_source(source), Vec::<String>::new());
+    assert_eq!(get_inferred_type(source, "query"), "SelectBuilder");
+    assert_eq!(get_inferred_type(source, "columns"), "string[]");
+    assert_eq!(get_inferred_type(source, "name"), "string");
+
+    let errors = check_source(r#"
+        function later(): this { return later(); }
+        class Base { copy(): this { return this; } }
+        const base: Base = new Base().copy();
+        const fail: string = new Base().copy();
+    "#);
+    assert_eq!(errors, vec![
+        "A 'this' type is available only in a non-static member of a class or interface.",
+        "Type 'Base' is not assignable to type 'string'.",
+    ]);
+}
+
+#[test]
+fn test_this_parameters_and_this_type_markers() {
+    let errors = check_source(r#"
+        function greet(this: { name: string }): string { return this.name; }
+        const person = { name: "Ada", greet };
+        person.greet();
+        greet();
+        const counter = { count: 1, greet };
+        counter.greet();
+
+        interface State { value: number }
+        interface Methods { double(): number; label(): string }
+        const methods: Methods & ThisType<State> = {
+            double() { return this.value * 2; },
+            label() { const text: string = this.value; return text; },
+        };
+    "#);
+    assert_eq!(errors, vec![
+        "The 'this' context of type 'void' is not assignable to method's 'this' of type '{ name: string; }'.",
+        "The 'this' context of type '{ count: number; greet: () => string; }' is not assignable to method's 'this' of type '{ name: string; }'.",
+        "Type 'number' is not assignable to type 'string'.",
+    ]);
+}
+
+#[test]
+fn test_type_guards_narrow_their_arguments() {
+    let source = r#"
+        declare function isString(value: unknown): value is string;
+        function isDefined<T>(value: T | undefined): value is T { return value !== undefined; }
+        interface Circle { radius: number }
+        class Shape { isCircle(): this is Circle { return false; } }
+        declare const input: string | number;
+        declare const maybe: boolean | undefined;
+        declare const shape: Shape;
+        if (isString(input)) { const guarded = input; } else { const rest = input; }
+        const afterwards = input;
+        if (isDefined(maybe)) { const defined = maybe; }
+        if (shape.isCircle()) { const radius = shape.radius; }
+        const guard = isString;
+    "#;
+    assert_eq!(check_source(source), Vec::<String>::new());
+    assert_eq!(get_inferred_type(source, "guarded"), "string");
+    assert_eq!(get_inferred_type(source, "rest"), "number");
+    assert_eq!(get_inferred_type(source, "afterwards"), "string | number");
+    assert_eq!(get_inferred_type(source, "defined"), "boolean");
+    assert_eq!(get_inferred_type(source, "radius"), "number");
+    assert_eq!(get_inferred_type(source, "guard"), "(value: unknown) => value is string");
+
+    let errors = check_source(r#"
+        declare function isString(value: unknown): value is string;
+        declare const input: string | number;
+        if (isString(input)) { const n: number = input; }
+    "#);
+    assert_eq!(errors, vec!["Type 'string' is not assignable to type 'number'."]);
+}
+
+#[test]
+fn test_assertion_signatures_narrow_the_rest_of_the_block() {
+    let source = r#"
+        declare function assertIsString(value: unknown): asserts value is string;
+        declare function assert(condition: unknown): asserts condition;
+        declare const value: string | number;
+        declare const maybe: string | null;
+        function check() {
+            assertIsString(value);
+            const asserted = value;
+            assert(maybe);
+            const present = maybe;
+        }
+        const outside = value;
+        const assertion = assertIsString;
+    "#;
+    assert_eq!(check_source(source), Vec::<String>::new());
+    assert_eq!(get_inferred_type(source, "asserted"), "string");
+    assert_eq!(get_inferred_type(source, "present"), "string");
+    assert_eq!(get_inferred_type(source, "outside"), "string | number");
+    assert_eq!(get_inferred_type(source, "assertion"), "(value: unknown) => asserts value is string");
+}
+
+#[test]
+fn test_filter_with_declared_and_inferred_type_predicates() {
+    let source = r#"
+        function isDefined<T>(value: T | undefined): value is T { return value !== undefined; }
+        declare const items: (string | undefined)[];
+        const defined = items.filter(isDefined);
+        const present = items.filter(item => item !== undefined);
+        const some = items.filter(item => item === "a");
+        const isNumber = (x: string | number) => typeof x === "number";
+        const isTruthy = (x: string | undefined) => !!x;
+        const positive = [1, 2].filter(n => n > 0);
+    "#;
+    assert_eq!(check_source(source), Vec::<String>::new());
+    assert_eq!(get_inferred_type(source, "defined"), "string[]");
+    assert_eq!(get_inferred_type(source, "present"), "string[]");
+    assert_eq!(get_inferred_type(source, "some"), "(string | undefined)[]");
+    assert_eq!(get_inferred_type(source, "isNumber"), "(x: string | number) => x is number");
+    assert_eq!(get_inferred_type(source, "isTruthy"), "(x: string | undefined) => boolean");
+    assert_eq!(get_inferred_type(source, "positive"), "number[]");
+}
+
+#[test]
+fn test_filter_with_annotated_predicate_callbacks() {
+    let source = r#"
+        const arrow = [1, undefined, 2].filter((x): x is number => x !== undefined);
+        const expression = [1, undefined, 2].filter(function (x): x is number { return x !== undefined; });
+        declare const items: (string | number)[];
+        const strings = items.filter((item): item is string => typeof item === "string");
+        const all = [1, undefined, 2].filter(x => x !== null);
+    "#;
+    assert_eq!(check_source(source), Vec::<String>::new());
+    assert_eq!(get_inferred_type(source, "arrow"), "number[]");
+    assert_eq!(get_inferred_type(source, "expression"), "number[]");
+    assert_eq!(get_inferred_type(source, "strings"), "string[]");
+    assert_eq!(get_inferred_type(source, "all"), "(number | undefined)[]");
+}
+
+#[test]
+fn test_generator_and_async_functions_infer_their_return_types() {
+    let source = r#"
+        function* count() { yield 1; yield 2; return "done"; }
+        async function* ticks() { yield Promise.resolve(1); }
+        async function fetchValue() { return 42; }
+        const fetchLater = async () => fetchValue();
+        const numbers = count();
+        const stream = ticks();
+        const value = fetchValue();
+        const later = fetchLater();
+        const idle = function* () {}();
+    "#;
+    assert_eq!(check_source(source), Vec::<String>::new());
+    assert_eq!(get_inferred_type(source, "numbers"), "Generator<number, string, unknown>");
+    assert_eq!(get_inferred_type(source, "stream"), "AsyncGenerator<number, void, unknown>");
+    assert_eq!(get_inferred_type(source, "value"), "Promise<number>");
+    assert_eq!(get_inferred_type(source, "later"), "Promise<number>");
+    assert_eq!(get_inferred_type(source, "idle"), "Generator<never, void, unknown>");
+}
+
+#[test]
+fn test_yield_is_checked_against_the_declared_generator_type() {
+    let source = r#"
+        function* numbers(): Generator<number, void, string> {
+            const reply = yield 1;
+            yield "two";
+        }
+        function* delegating(): Iterable<number> {
+            yield* numbers();
+            yield* ["three"];
+        }
+        class Countdown {
+            *[Symbol.iterator](): Iterator<number> { yield 3; yield "go"; }
+        }
+    "#;
+    let options = CompilerOptions { target: Some(ScriptTarget::ES2015), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &options), vec![
+        "Type 'string' is not assignable to type 'number'.",
+        "Type 'string' is not assignable to type 'number'.",
+        "Type 'string' is not assignable to type 'number'.",
+    ]);
+    assert_eq!(get_inferred_type_with_options(source, "reply", &options), "string");
+}
+
+#[test]
+fn test_iteration_follows_target_and_downlevel_iteration() {
+    let source = r#"
+        declare const words: Generator<string, void, unknown>;
+        declare const count: number;
+        for (const word of words) { const length: number = word.length; }
+        const spread = [...words];
+        const [first, ...rest] = words;
+        for (const char of "abc") {}
+        for (const digit of count) {}
+    "#;
+    let es2015 = CompilerOptions { target: Some(ScriptTarget::ES2015), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &es2015), vec![
+        "Type 'number' must have a '[Symbol.iterator]()' method that returns an iterator.",
+    ]);
+    assert_eq!(get_inferred_type_with_options(source, "word", &es2015), "string");
+    assert_eq!(get_inferred_type_with_options(source, "spread", &es2015), "string[]");
+    assert_eq!(get_inferred_type_with_options(source, "first", &es2015), "string");
+    assert_eq!(get_inferred_type_with_options(source, "rest", &es2015), "string[]");
+
+    let es5 = CompilerOptions::default();
+    let downlevel = "Type 'Generator<string, void, unknown>' can only be iterated through when using the '--downlevelIteration' flag or with a '--target' of 'es2015' or higher.";
+    assert_eq!(check_source_with_options(source, &es5), vec![
+        downlevel,
+        downlevel,
+        downlevel,
+        "Type 'number' is not an array type or a string type.",
+    ]);
+
+    let es5_downlevel = CompilerOptions { downlevel_iteration: Some(true), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &es5_downlevel), vec![
+        "Type 'number' is not an array type or a string type.",
+    ]);
+    assert_eq!(check_source_with_options("declare const n: number; const copy = [...n];", &es5_downlevel), vec![
+        "Type 'number' is not an array type.",
+    ]);
+}
+
+#[test]
+fn test_for_await_and_awaited_types() {
+    let source = r#"
+        declare const stream: AsyncIterable<number>;
+        declare const nested: Promise<Promise<string>>;
+        declare const maybe: number | PromiseLike<boolean>;
+        declare const count: number;
+        declare const pending: Promise<boolean>[];
+        class Range {
+            [Symbol.iterator](): Iterator<number> { return null as any; }
+        }
+        async function main() {
+            for await (const item of stream) {}
+            for await (const flag of pending) {}
+            for await (const index of new Range()) {}
+            for await (const bad of count) {}
+            const text = await nested;
+            const either = await maybe;
+            const plain = await 1;
+        }
+        type Unwrapped = Awaited<Promise<Promise<number>>>;
+        const unwrapped: Unwrapped = "no";
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Type 'number' must have a '[Symbol.asyncIterator]()' method that returns an async iterator.",
+        "Type 'string' is not assignable to type 'number'.",
+    ]);
+    assert_eq!(get_inferred_type(source, "item"), "number");
+    assert_eq!(get_inferred_type(source, "flag"), "boolean");
+    assert_eq!(get_inferred_type(source, "index"), "number");
+    assert_eq!(get_inferred_type(source, "text"), "string");
+    assert_eq!(get_inferred_type(source, "either"), "number | boolean");
+    assert_eq!(get_inferred_type(source, "plain"), "1");
+}
+
+#[test]
+fn test_subtype_pass_precedes_assignability_pass() {
+    // `any` is assignable to `{ a: number }` but only a subtype of `any`.
+    let source = r#"
+        function h(x: { a: number }): string;
+        function h(x: any): number;
+        function h(x: any): any { return x; }
+        function test(v: any) {
+            const r = h(v);
+        }
+        const s = h({ a: 1 });
+    "#;
+    assert!(check_source(source).is_empty(), "{:?}", check_source(source));
+    assert_eq!(get_inferred_type(source, "r"), "number");
+    assert_eq!(get_inferred_type(source, "s"), "string");
+}
+
+#[test]
+fn test_argument_count_errors() {
+    let diags = check_source(r#"
+        function k(a: string, b?: string): void {}
+        k();
+    "#);
+    assert_eq!(diags, vec!["Expected 1-2 arguments, but got 0."]);
+
+    let diags = check_source(r#"
+        function g(a: number): void;
+        function g(a: number, b: number, c: number): void;
+        function g(a: number, b?: number, c?: number): void {}
+        g(1, 2);
+    "#);
+    assert_eq!(
+        diags,
+        vec!["No overload expects 2 arguments, but overloads do exist that expect either 1 or 3 arguments."],
+    );
+
+    let diags = check_source(r#"
+        function r(a: string, ...rest: number[]): void {}
+        r();
+    "#);
+    assert_eq!(diags, vec!["Expected at least 1 arguments, but got 0."]);
+}
+
+#[test]
+fn test_rest_parameters_check_each_argument() {
+    let diags = check_source(r#"
+        function sum(...xs: number[]): number { return 0; }
+        sum();
+        sum(1, 2, 3);
+        sum(1, "a");
+    "#);
+    assert_eq!(diags, vec!["Argument of type 'string' is not assignable to parameter of type 'number'."]);
+}
+
+#[test]
+fn test_spread_arguments() {
+    let diags = check_source(r#"
+        function two(a: number, b: number): void {}
+        function many(...xs: number[]): void {}
+        function test(arr: number[], pair: [number, number]) {
+            two(...pair);
+            many(...arr);
+            many(1, ...arr);
+            two(...arr);
+        }
+    "#);
+    assert_eq!(diags, vec!["A spread argument must either have a tuple type or be passed to a rest parameter."]);
+}
+
+#[test]
+fn test_tuple_rest_parameters_expand_to_positions() {
+    let diags = check_source(r#"
+        function tr(...args: [string, number]): void {}
+        tr("a", 1);
+        tr("a", "b");
+        tr("a");
+    "#);
+    assert_eq!(
+        diags,
+        vec![
+            "Argument of type 'string' is not assignable to parameter of type 'number'.",
+            "Expected 2 arguments, but got 1.",
+        ],
+    );
+}
+
+#[test]
+fn test_overload_incompatible_with_implementation() {
+    let diags = check_source(r#"
+        function bad(x: string): void;
+        function bad(x: number): void {}
+    "#);
+    assert_eq!(diags, vec!["This overload signature is not compatible with its implementation signature."]);
+}
+
+#[test]
+fn test_generic_call_infers_type_arguments() {
+    let source = r#"
+        function id<T>(x: T): T { return x; }
+        function first<T>(xs: T[]): T { return xs[0]; }
+        function test(names: string[]) {
+            const n = id(1);
+            const s = first(names);
+            const e = id<string>(1);
+        }
+    "#;
+    assert_eq!(get_inferred_type(source, "n"), "1");
+    assert_eq!(get_inferred_type(source, "s"), "string");
+    assert_eq!(
+        check_source(source),
+        vec!["Argument of type 'number' is not assignable to parameter of type 'string'."],
+    );
+}
+
+#[test]
+fn test_method_and_constructor_overloads() {
+    let diags = check_source(r#"
+        interface Parser {
+            parse(x: string): number;
+            parse(x: number): string;
+        }
+        class Point {
+            constructor(x: number, y: number);
+            constructor(xy: string);
+            constructor(a: any, b?: any) {}
+        }
+        function test(p: Parser) {
+            p.parse("1");
+            p.parse(1);
+            new Point(1, 2);
+            new Point("1,2");
+            p.parse(true);
+            new Point(true);
+        }
+    "#);
+    // Only the one-parameter constructor has the right arity, so its error is reported directly.
+    assert_eq!(
+        diags,
+        vec![
+            "No overload matches this call.",
+            "Argument of type 'boolean' is not assignable to parameter of type 'string'.",
+        ],
+    );
+}
+
+// ============================================================================
+// Excess property checks
+// ============================================================================
+
+#[test]
+fn test_excess_property_in_fresh_object_literal() {
+    let diags = check_source(r#"
+        interface Options { color: string; width?: number; }
+        const o: Options = { color: "red", colour: "blue" };
+    "#);
+    assert_eq!(
+        diags,
+        vec!["Object literal may only specify known properties, and 'colour' does not exist in type 'Options'."],
+    );
+}
+
+#[test]
+fn test_freshness_is_lost_once_stored() {
+    let diags = check_source(r#"
+        interface Options { color: string; }
+        const extra = { color: "red", colour: "blue" };
+        const o: Options = extra;
+        let p: { color: string } = { color: "red" };
+    "#);
+    assert!(diags.is_empty(), "{:?}", diags);
+    let t = get_inferred_type(r#"const extra = { color: "red", size: 1 };"#, "extra");
+    assert_eq!(t, "{ color: string; size: number; }");
+}
+
+#[test]
+fn test_excess_property_in_call_argument_and_assignment() {
+    let diags = check_source(r#"
+        function configure(options: { verbose: boolean }): void {}
+        configure({ verbose: true, verbos: false });
+        let target: { a: number };
+        target = { a: 1, b: 2 };
+    "#);
+    assert_eq!(
+        diags,
+        vec![
+            "Object literal may only specify known properties, and 'verbos' does not exist in type '{ verbose: boolean; }'.",
+            "Object literal may only specify known properties, and 'b' does not exist in type '{ a: number; }'.",
+        ],
+    );
+}
+
+#[test]
+fn test_excess_property_targets_without_check() {
+    let diags = check_source(r#"
+        const a: {} = { x: 1 };
+        const b: object = { x: 1 };
+        const c: { [key: string]: number } = { x: 1, y: 2 };
+        const d: { a: number } | { b: number } = { a: 1, b: 2 };
+    "#);
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_excess_property_with_discriminated_union_target() {
+    let diags = check_source(r#"
+        type Shape =
+            | { kind: "circle"; radius: number }
+            | { kind: "square"; size: number };
+        const ok: Shape = { kind: "square", size: 2 };
+        const bad: Shape = { kind: "circle", size: 1 };
+    "#);
+    assert_eq!(
+        diags,
+        vec!["Object literal may only specify known properties, and 'size' does not exist in type '{ kind: \"circle\"; radius: number; }'."],
+    );
+}
+
+// ============================================================================
+// Class semantics
+// ============================================================================
+
+#[test]
+fn test_class_name_in_type_position_is_instance_type() {
+    let diags = check_source(r#"
+        class Point {
+            x: number = 0;
+            static origin: number = 0;
+        }
+        const p: Point = new Point();
+        const q: Point = Point;
+        const n: number = Point.origin;
+    "#);
+    assert_eq!(diags, vec!["Type 'typeof Point' is not assignable to type 'Point'."]);
+}
+
+#[test]
+fn test_derived_class_inherits_instance_and_static_members() {
+    let diags = check_source(r#"
+        class Base {
+            name: string = "";
+            static create(): Base { return new Base(); }
+        }
+        class Derived extends Base {}
+        const d = new Derived();
+        const s: string = d.name;
+        const b: Base = Derived.create();
+    "#);
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_class_incorrectly_implements_interface() {
+    let diags = check_source_diagnostics(r#"
+        interface Named { name: string; }
+        class Anonymous implements Named {}
+        class Good implements Named { name: string = "x"; }
+    "#);
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].code, 2420);
+    assert_eq!(diags[0].message_text, "Class 'Anonymous' incorrectly implements interface 'Named'.");
+    assert_eq!(
+        diags[0].flatten_message_text(),
+        "Class 'Anonymous' incorrectly implements interface 'Named'.\n  \
+         Property 'name' is missing in type 'Anonymous' but required in type 'Named'.",
+    );
+
+    let diags = check_source_diagnostics(r#"
+        interface Named { name: string; }
+        class Numbered implements Named { name: number = 1; }
+    "#);
+    assert_eq!(
+        diags[0].flatten_message_text(),
+        "Class 'Numbered' incorrectly implements interface 'Named'.\n  \
+         Types of property 'name' are incompatible.\n    \
+         Type 'number' is not assignable to type 'string'.",
+    );
+}
+
+#[test]
+fn test_abstract_classes() {
+    let diags = check_source(r#"
+        abstract class Shape {
+            abstract area(): number;
+            describe(): string { return "shape"; }
+        }
+        class Square extends Shape {
+            area(): number { return 4; }
+        }
+        class Broken extends Shape {}
+        const s = new Shape();
+        const q = new Square();
+    "#);
+    assert_eq!(
+        diags,
+        vec![
+            "Non-abstract class 'Broken' does not implement inherited abstract member 'area' from class 'Shape'.",
+            "Cannot create an instance of an abstract class.",
+        ],
+    );
+}
+
+#[test]
+fn test_private_and_protected_member_access() {
+    let diags = check_source(r#"
+        class Account {
+            private balance: number = 0;
+            protected owner: string = "";
+            deposit(other: Account): number { return other.balance; }
+        }
+        class Savings extends Account {
+            describe(): string { return this.owner; }
+        }
+        const a = new Account();
+        a.balance;
+        a.owner;
+    "#);
+    assert_eq!(
+        diags,
+        vec![
+            "Property 'balance' is private and only accessible within class 'Account'.",
+            "Property 'owner' is protected and only accessible within class 'Account' and its subclasses.",
+        ],
+    );
+}
+
+#[test]
+fn test_parameter_properties_declare_members() {
+    let diags = check_source(r#"
+        class User {
+            constructor(public name: string, private id: number) {}
+        }
+        const u = new User("a", 1);
+        const n: string = u.name;
+        u.id;
+    "#);
+    assert_eq!(diags, vec!["Property 'id' is private and only accessible within class 'User'."]);
+}
+
+#[test]
+fn test_incompatible_override_of_base_member() {
+    let diags = check_source_diagnostics(r#"
+        class Animal {
+            legs: number = 4;
+            static kind: string = "animal";
+        }
+        class Bird extends Animal {
+            legs: string = "two";
+            static kind: number = 1;
+        }
+    "#);
+    let messages: Vec<&str> = diags.iter().map(|d| d.message_text.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Property 'legs' in type 'Bird' is not assignable to the same property in base type 'Animal'.",
+            "Class static side 'typeof Bird' incorrectly extends base class static side 'typeof Animal'.",
+        ],
+    );
+    assert_eq!(
+        diags[0].flatten_message_text(),
+        "Property 'legs' in type 'Bird' is not assignable to the same property in base type 'Animal'.\n  \
+         Type 'string' is not assignable to type 'number'.",
+    );
+    assert_eq!(
+        diags[1].flatten_message_text(),
+        "Class static side 'typeof Bird' incorrectly extends base class static side 'typeof Animal'.\n  \
+         Types of property 'kind' are incompatible.\n    \
+         Type 'number' is not assignable to type 'string'.",
+    );
+}
+
+#[test]
+fn test_class_incorrectly_extends_with_less_visible_member() {
+    let diags = check_source_diagnostics(r#"
+        class Base { name: string = ""; }
+        class Hidden extends Base { private name: string = ""; }
+    "#);
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(
+        diags[0].flatten_message_text(),
+        "Class 'Hidden' incorrectly extends base class 'Base'.\n  \
+         Property 'name' is private in type 'Hidden' but not in type 'Base'.",
+    );
+}
+
+#[test]
+fn test_override_modifier_requires_base_member() {
+    let diags = check_source(r#"
+        class Base { greet(): void {} }
+        class Derived extends Base {
+            override greet(): void {}
+            override wave(): void {}
+        }
+        class Standalone {
+            override run(): void {}
+        }
+    "#);
+    assert_eq!(
+        diags,
+        vec![
+            "This member cannot have an 'override' modifier because it is not declared in the base class 'Base'.",
+            "This member cannot have an 'override' modifier because its containing class 'Standalone' does not extend another class.",
+        ],
+    );
+}
+
+#[test]
+fn test_derived_constructor_super_call() {
+    let diags = check_source(r#"
+        class Base {
+            constructor(x: number) {}
+        }
+        class MissingSuper extends Base {
+            constructor() {}
+        }
+        class ThisFirst extends Base {
+            value: number = 0;
+            constructor() {
+                this.value = 1;
+                super(1);
+            }
+        }
+        class BadArgument extends Base {
+            constructor() { super("one"); }
+        }
+        class NotDerived {
+            constructor() { super(); }
+        }
+    "#);
+    assert_eq!(
+        diags,
+        vec![
+            "Constructors for derived classes must contain a 'super' call.",
+            "'super' must be called before accessing 'this' in the constructor of a derived class.",
+            "Argument of type 'string' is not assignable to parameter of type 'number'.",
+            "'super' can only be referenced in a derived class.",
+        ],
+    );
+}
+
+#[test]
+fn test_strict_property_initialization() {
+    let diags = check_source(r#"
+        class Config {
+            name: string;
+            port: number;
+            host?: string;
+            path!: string;
+            label: string | undefined;
+            retries: number = 3;
+            constructor() {
+                this.port = 80;
+            }
+        }
+    "#);
+    assert_eq!(
+        diags,
+        vec!["Property 'name' has no initializer and is not definitely assigned in the constructor."],
+    );
+}
+
+// ============================================================================
+// Literal, enum and template literal types
+// ============================================================================
+
+#[test]
+fn test_fresh_literals_widen_at_mutable_locations() {
+    let source = r#"
+        const c = "a";
+        let l = c;
+        let b = true;
+        const arr = [1, 2];
+        const t = ["x", 1] as const;
+        let u = Math.random() > 0.5 ? "yes" : "no";
+        function f() { return "r"; }
+        const r = f();
+        class C { p = 1; readonly q = 1; }
+        const p = new C().p;
+        const q = new C().q;
+    "#;
+    assert_eq!(get_inferred_type(source, "c"), "\"a\"");
+    assert_eq!(get_inferred_type(source, "l"), "string");
+    assert_eq!(get_inferred_type(source, "b"), "boolean");
+    assert_eq!(get_inferred_type(source, "arr"), "number[]");
+    assert_eq!(get_inferred_type(source, "t"), "readonly [\"x\", 1]");
+    assert_eq!(get_inferred_type(source, "u"), "string");
+    assert_eq!(get_inferred_type(source, "r"), "string");
+    assert_eq!(get_inferred_type(source, "p"), "number");
+    assert_eq!(get_inferred_type(source, "q"), "1");
+}
+
+#[test]
+fn test_literal_relations() {
+    let diags = check_source(r#"
+        let a: "a" | "b" = "a";
+        let s: string = a;
+        let n: 1 | 2 = 3;
+        let t: "a" = s;
+        let ok: "a" | "b" | "c" = a;
+    "#);
+    assert_eq!(diags, vec![
+        "Type '3' is not assignable to type '1 | 2'.",
+        "Type 'string' is not assignable to type '\"a\"'.",
+    ]);
+}
+
+#[test]
+fn test_literal_source_reported_by_base_type() {
+    let diags = check_source(r#"let n: number = "hello";"#);
+    assert_eq!(diags, vec!["Type 'string' is not assignable to type 'number'."]);
+}
+
+#[test]
+fn test_numeric_enum_types() {
+    let source = r#"
+        enum Color { Red, Green = 5, Blue }
+        const r = Color.Red;
+        let g = Color.Green;
+        let c: Color = Color.Blue;
+        let d: Color.Red = Color.Green;
+        let n: Color = 5;
+        let num: number = Color.Blue;
+    "#;
+    assert_eq!(get_inferred_type(source, "r"), "Color.Red");
+    assert_eq!(get_inferred_type(source, "g"), "Color");
+    assert_eq!(get_inferred_type(source, "Color"), "typeof Color");
+    assert_eq!(check_source(source), vec!["Type 'Color.Green' is not assignable to type 'Color.Red'."]);
+}
+
+#[test]
+fn test_string_enum_types() {
+    let diags = check_source(r#"
+        enum Dir { Up = "UP", Down = "DOWN", Left = Up + "_LEFT" }
+        let d: Dir = Dir.Left;
+        let s: string = Dir.Up;
+        let e: Dir = "UP";
+    "#);
+    assert_eq!(diags, vec!["Type '\"UP\"' is not assignable to type 'Dir'."]);
+}
+
+#[test]
+fn test_enum_member_must_have_initializer() {
+    let diags = check_source(r#"enum E { A = "a", B }"#);
+    assert_eq!(diags, vec!["Enum member must have initializer."]);
+}
+
+#[test]
+fn test_template_literal_types() {
+    let source = r#"
+        type Lang = "en" | "fr";
+        type Greeting = `hello-${Lang}`;
+        type Id = `id-${number}`;
+        let g: Greeting = "hello-fr";
+        let i: Id = "id-42";
+        let bad: Id = "id-x";
+        let s: string = i;
+        let w: Id = `id-${1 + 1}` as any;
+    "#;
+    assert_eq!(check_source(source), vec!["Type '\"id-x\"' is not assignable to type '`id-${number}`'."]);
+    assert_eq!(get_inferred_type(source, "g"), "Greeting");
+    assert_eq!(get_inferred_type(source, "Greeting"), "\"hello-en\" | \"hello-fr\"");
+}
+
+#[test]
+fn test_string_mapping_types() {
+    let source = r#"
+        type Loud = Uppercase<"a" | "b">;
+        type Cap = Capitalize<"hello">;
+        type Deferred<T extends string> = Lowercase<T>;
+        let l: Loud = "A";
+        let c: Cap = "Hello";
+        let u: Uppercase<string> = "ABC";
+        let bad: Uppercase<string> = "abc";
+    "#;
+    assert_eq!(get_inferred_type(source, "l"), "Loud");
+    assert_eq!(get_inferred_type(source, "Loud"), "\"A\" | \"B\"");
+    assert_eq!(get_inferred_type(source, "c"), "\"Hello\"");
+    assert_eq!(check_source(source), vec!["Type '\"abc\"' is not assignable to type 'Uppercase<string>'."]);
+}
+
+#[test]
+fn test_string_intersections_reduce_in_template_and_mapping_types() {
+    let source = r#"
+        type W<K> = `get${K & string}`;
+        let w: W<"foo"> = "getfoo";
+        type Getters<T> = { [K in keyof T as `get${Capitalize<string & K>}`]: () => T[K] };
+        interface Person { foo: number; 0: boolean; }
+        declare function foo(): number;
+        const getters: Getters<Person> = { getFoo: foo };
+    "#;
+    assert!(check_source(source).is_empty(), "{:?}", check_source(source));
+    assert_eq!(get_inferred_type(source, "w"), "\"getfoo\"");
+    assert_eq!(get_inferred_type(source, "getters"), "Getters<Person>");
+    let source = r#"
+        type W<K> = `get${K & string}`;
+        type G = W<"foo" | 1>;
+        type Getters<T> = { [K in keyof T as `get${Capitalize<string & K>}`]: () => T[K] };
+        type P = Getters<{ foo: number; 0: boolean }>;
+    "#;
+    assert_eq!(get_inferred_type(source, "G"), "\"getfoo\"");
+    assert_eq!(get_inferred_type(source, "P"), "{ getFoo: () => number; }");
+}
+
+// ============================================================================
+// Tuple types
+// ============================================================================
+
+#[test]
+fn test_labeled_and_variadic_tuple_display() {
+    let source = r#"
+        type Point = [x: number, y?: number, ...rest: string[]];
+        type Spread = [boolean, ...[number, string], ...string[]];
+        type Nested<T extends unknown[]> = [first: string, ...items: T];
+        let p: Point;
+        let s: Spread;
+        let n: Nested<[number, boolean]>;
+        let a: [...number[], string?];
+    "#;
+    assert_eq!(get_inferred_type(source, "p"), "[x: number, y?: number, ...rest: string[]]");
+    assert_eq!(get_inferred_type(source, "s"), "[boolean, number, string, ...string[]]");
+    assert_eq!(get_inferred_type(source, "n"), "[string, number, boolean]");
+    assert_eq!(get_inferred_type(source, "a"), "[...(string | number)[]]");
+}
+
+#[test]
+fn test_tuple_assignability() {
+    let diags = check_source(r#"
+        declare let pair: [number, string];
+        declare let opt: [number, string?];
+        declare let rest: [number, ...string[]];
+        declare let nums: number[];
+        let a: [number, string?] = pair;
+        let b: [number, string] = opt;
+        let c: [number, ...string[]] = pair;
+        let d: [number] = pair;
+        let e: (number | string)[] = rest;
+        let f: [number, ...string[]] = nums;
+        let g: [...number[]] = nums;
+        let h: [string, number] = pair;
+    "#);
+    assert_eq!(diags, vec![
+        "Type '[number, string?]' is not assignable to type '[number, string]'.",
+        "Type '[number, string]' is not assignable to type '[number]'.",
+        "Type 'number[]' is not assignable to type '[number, ...string[]]'.",
+        "Type '[number, string]' is not assignable to type '[string, number]'.",
+    ]);
+}
+
+#[test]
+fn test_array_literal_in_tuple_context() {
+    let diags = check_source(r#"
+        const t: [number, string] = [1, "a"];
+        const u: [number, string] = [1, 2];
+        const v: [number, ...string[]] = [1, ...["a", "b"]];
+        function take(p: [string, boolean]) {}
+        take(["x", true]);
+    "#);
+    assert_eq!(diags, vec!["Type '[1, 2]' is not assignable to type '[number, string]'."]);
+}
+
+#[test]
+fn test_tuple_rest_parameters_drive_calls() {
+    let source = r#"
+        declare function emit<T extends unknown[]>(...args: T): T;
+        declare function on<A extends unknown[]>(handler: (...args: A) => void, ...args: A): A;
+        declare function concat<T extends unknown[], U extends unknown[]>(a: [...T], b: [...U]): [...T, ...U];
+        declare function fixed(...args: [name: string, count?: number]): void;
+        const r = emit(1, "a");
+        const c = concat([1, true], ["s"]);
+        const x = on((n: number, s: string) => {}, 1, "b");
+        type P = Parameters<(id: number, ...tags: string[]) => void>;
+        let p: P;
+        fixed("a");
+        fixed("a", 1);
+        fixed("a", 1, 2);
+    "#;
+    assert_eq!(get_inferred_type(source, "r"), "[number, string]");
+    assert_eq!(get_inferred_type(source, "c"), "[number, boolean, string]");
+    assert_eq!(get_inferred_type(source, "x"), "[number, string]");
+    assert_eq!(get_inferred_type(source, "p"), "[id: number, ...tags: string[]]");
+    assert_eq!(check_source(source), vec!["Expected 1-2 arguments, but got 3."]);
+}
+
+// ============================================================================
+// keyof, typeof and indexed access types
+// ============================================================================
+
+#[test]
+fn test_keyof_property_kinds() {
+    let source = r#"
+        const sym: unique symbol = Symbol();
+        interface Keys { a: string; 1: number; "b-c": boolean; [sym]: number }
+        interface Dict { [key: string]: number }
+        interface A { x: number; y: string }
+        interface B { y: string; z: boolean }
+        let k: keyof Keys;
+        let d: keyof Dict;
+        let u: keyof (A | B);
+        let i: keyof (A & B);
+        let t: keyof [string, number];
+    "#;
+    assert_eq!(get_inferred_type(source, "k"), "typeof sym | \"a\" | 1 | \"b-c\"");
+    assert_eq!(get_inferred_type(source, "d"), "string | number");
+    assert_eq!(get_inferred_type(source, "u"), "\"y\"");
+    assert_eq!(get_inferred_type(source, "i"), "\"x\" | \"y\" | \"z\"");
+    assert_eq!(get_inferred_type(source, "t"), "number | \"0\" | \"1\" | \"length\"");
+}
+
+#[test]
+fn test_indexed_access_types() {
+    let source = r#"
+        interface Person { name: string; age: number; tags: string[] }
+        let a: Person["name" | "age"];
+        let b: Person[keyof Person];
+        let c: [string, number]["length"];
+        let d: string[][number];
+        type Get<T, K extends keyof T> = T[K];
+        let e: Get<Person, "tags">;
+        let f: Person["missing"];
+        let g: Person[boolean];
+        let h: Person[string];
+    "#;
+    assert_eq!(get_inferred_type(source, "a"), "string | number");
+    assert_eq!(get_inferred_type(source, "b"), "string | number | string[]");
+    assert_eq!(get_inferred_type(source, "c"), "2");
+    assert_eq!(get_inferred_type(source, "d"), "string");
+    assert_eq!(get_inferred_type(source, "e"), "string[]");
+    assert_eq!(check_source(source), vec![
+        "Property 'missing' does not exist on type 'Person'.",
+        "Type 'boolean' cannot be used as an index type.",
+        "Type 'string' cannot be used to index type 'Person'.",
+    ]);
+}
+
+#[test]
+fn test_typeof_queries_and_instantiation_expressions() {
+    let source = r#"
+        const config = { server: { port: 8080 } };
+        declare function identity<T>(value: T): T;
+        declare function plain(value: number): number;
+        let port: typeof config.server.port;
+        let id: typeof identity<string>;
+        let bad: typeof plain<string>;
+        let missing: typeof config.client;
+    "#;
+    assert_eq!(get_inferred_type(source, "port"), "number");
+    assert_eq!(get_inferred_type(source, "id"), "(value: string) => string");
+    assert_eq!(check_source(source), vec![
+        "Type '(value: number) => number' has no signatures for which the type argument list is applicable.",
+        "Property 'client' does not exist on type '{ server: { port: number; }; }'.",
+    ]);
+}
+
+#[test]
+fn test_unique_symbols() {
+    let source = r#"
+        const key = Symbol("key");
+        const declared: unique symbol = Symbol();
+        let widened = key;
+        const obj = { [key]: 42 };
+        const value = obj[key];
+        let iter: typeof Symbol.iterator = Symbol.iterator;
+        let other: typeof key = declared;
+    "#;
+    assert_eq!(get_inferred_type(source, "key"), "typeof key");
+    assert_eq!(get_inferred_type(source, "widened"), "symbol");
+    assert_eq!(get_inferred_type(source, "value"), "number");
+    assert_eq!(check_source(source), vec!["Type 'typeof declared' is not assignable to type 'typeof key'."]);
+}
+
+// ============================================================================
+// Index signatures
+// ============================================================================
+
+#[test]
+fn test_index_signature_key_kinds() {
+    let source = r#"
+        const tag: unique symbol = Symbol();
+        interface Bag {
+            [key: string]: string | number;
+            [index: number]: number;
+            [attr: `data-${string}`]: string;
+            [sym: symbol]: boolean;
+        }
+        declare const bag: Bag;
+        declare const key: string;
+        let a = bag[0];
+        let b = bag["data-id"];
+        let c = bag.other;
+        let d = bag[tag];
+        let e = bag[key];
+        type Bad = { [key: boolean]: string };
+    "#;
+    assert_eq!(get_inferred_type(source, "a"), "number");
+    assert_eq!(get_inferred_type(source, "b"), "string");
+    assert_eq!(get_inferred_type(source, "c"), "string | number");
+    assert_eq!(get_inferred_type(source, "d"), "boolean");
+    assert_eq!(get_inferred_type(source, "e"), "string | number");
+    assert_eq!(check_source(source), vec![
+        "An index signature parameter type must be 'string', 'number', 'symbol', or a template literal type.",
+    ]);
+}
+
+#[test]
+fn test_index_signature_property_conflicts() {
+    let diags = check_source(r#"
+        interface Scores {
+            [name: string]: number;
+            total: number;
+            label: string;
+        }
+        interface Mixed {
+            [key: string]: string;
+            [index: number]: number;
+        }
+        class Registry {
+            [key: string]: boolean;
+            enabled = true;
+            count = 0;
+        }
+    "#);
+    assert_eq!(diags, vec![
+        "Property 'label' of type 'string' is not assignable to 'string' index type 'number'.",
+        "'number' index type 'number' is not assignable to 'string' index type 'string'.",
+        "Property 'count' of type 'number' is not assignable to 'string' index type 'boolean'.",
+    ]);
+}
+
+#[test]
+fn test_implicit_index_signatures() {
+    let diags = check_source(r#"
+        interface Named { name: string }
+        type Point = { x: number; y: number };
+        declare const point: Point;
+        declare const named: Named;
+        const literal = { x: 1, y: 2 };
+        let a: { [key: string]: number } = literal;
+        let b: { [key: string]: number } = point;
+        let c: { [key: string]: string } = named;
+        let d: { [key: string]: any } = named;
+        let e: { [key: string]: string } = literal;
+        let f: Record<string, number> = { x: 1 };
+    "#);
+    assert_eq!(diags, vec![
+        "Type 'Named' is not assignable to type '{ [x: string]: string; }'.",
+        "Type '{ x: number; y: number; }' is not assignable to type '{ [x: string]: string; }'.",
+    ]);
+}
+
+#[test]
+fn test_no_unchecked_indexed_access() {
+    let source = r#"
+        declare const dict: { [key: string]: number };
+        declare const list: string[];
+        declare const pair: [number, ...string[]];
+        let a = dict["k"];
+        let b = dict.k;
+        let c = list[0];
+        let d = pair[0];
+        let e = pair[1];
+        list[0] = "x";
+        dict.k = 1;
+    "#;
+    let options = CompilerOptions { strict: Some(true), no_unchecked_indexed_access: Some(true), ..Default::default() };
+    assert_eq!(get_inferred_type_with_options(source, "a", &options), "number | undefined");
+    assert_eq!(get_inferred_type_with_options(source, "b", &options), "number | undefined");
+    assert_eq!(get_inferred_type_with_options(source, "c", &options), "string | undefined");
+    assert_eq!(get_inferred_type_with_options(source, "d", &options), "number");
+    assert_eq!(get_inferred_type_with_options(source, "e", &options), "string | undefined");
+    assert_eq!(get_inferred_type(source, "a"), "number");
+    assert!(check_source_with_options(source, &options).is_empty());
+}
+
+#[test]
+fn test_exact_optional_property_types() {
+    let source = r#"
+        interface Settings { theme?: string; size?: number | undefined }
+        declare const settings: Settings;
+        let theme = settings.theme;
+        const a: Settings = { theme: undefined };
+        const b: Settings = { size: undefined };
+        settings.theme = undefined;
+        settings.size = undefined;
+    "#;
+    let options = CompilerOptions { strict: Some(true), exact_optional_property_types: Some(true), ..Default::default() };
+    assert_eq!(get_inferred_type_with_options(source, "theme", &options), "string | undefined");
+    assert_eq!(check_source_with_options(source, &options), vec![
+        "Type '{ theme: undefined; }' is not assignable to type 'Settings' with 'exactOptionalPropertyTypes: true'. Consider adding 'undefined' to the types of the target's properties.",
+        "Type 'undefined' is not assignable to type 'string' with 'exactOptionalPropertyTypes: true'. Consider adding 'undefined' to the type of the target.",
+    ]);
+    assert!(check_source(source).is_empty());
+}
+
+// ============================================================================
+// Strict family options
+// ============================================================================
+
+#[test]
+fn test_strict_function_types_method_bivariance() {
+    let source = r#"
+        interface Animal { name: string }
+        interface Dog extends Animal { bark(): void }
+        type Handler = (animal: Animal) => void;
+        interface MethodHolder { handle(animal: Animal): void }
+        declare function handleDog(dog: Dog): void;
+        const h: Handler = handleDog;
+        const m: MethodHolder = { handle: handleDog };
+    "#;
+    let strict = CompilerOptions { strict: Some(true), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &strict), vec![
+        "Type '(dog: Dog) => void' is not assignable to type 'Handler'.",
+    ]);
+    let loose = CompilerOptions { strict: Some(true), strict_function_types: Some(false), ..Default::default() };
+    assert!(check_source_with_options(source, &loose).is_empty());
+}
+
+#[test]
+fn test_strict_bind_call_apply() {
+    let source = r#"
+        function add(a: number, b: string): number { return a; }
+        function scaled(this: { factor: number }, n: number): number { return n; }
+        let c = add.call(undefined, 1, "x");
+        let p = add.apply(undefined, [1, "x"]);
+        let b = add.bind(undefined, 1);
+        add.call(undefined, "1", "x");
+        add.apply(undefined, [1, 2]);
+        scaled.call({ factor: 2 }, 3);
+        scaled.call({ scale: 2 }, 3);
+    "#;
+    let strict = CompilerOptions { strict: Some(true), ..Default::default() };
+    assert_eq!(get_inferred_type_with_options(source, "c", &strict), "number");
+    assert_eq!(get_inferred_type_with_options(source, "p", &strict), "number");
+    assert_eq!(get_inferred_type_with_options(source, "b", &strict), "(b: string) => number");
+    assert_eq!(check_source_with_options(source, &strict), vec![
+        "Argument of type 'string' is not assignable to parameter of type 'number'.",
+        "Argument of type '[1, 2]' is not assignable to parameter of type '[a: number, b: string]'.",
+        "Object literal may only specify known properties, and 'scale' does not exist in type '{ factor: number; }'.",
+    ]);
+    let loose = CompilerOptions { strict: Some(true), strict_bind_call_apply: Some(false), ..Default::default() };
+    assert_eq!(get_inferred_type_with_options(source, "c", &loose), "any");
+    assert!(check_source_with_options(source, &loose).is_empty());
+}
+
+#[test]
+fn test_no_implicit_this() {
+    let source = r#"
+        function free() { this.x; }
+        function typed(this: { id: number }) { let id = this.id; }
+        const obj = { method() { this.x; } };
+        class Counter {
+            count = 0;
+            increment() {
+                const arrow = () => this.count;
+                function inner() { this.count; }
+            }
+        }
+    "#;
+    let options = CompilerOptions { strict: Some(true), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &options), vec![
+        "'this' implicitly has type 'any' because it does not have a type annotation.",
+        "'this' implicitly has type 'any' because it does not have a type annotation.",
+    ]);
+    assert_eq!(get_inferred_type_with_options(source, "id", &options), "number");
+    let loose = CompilerOptions { strict: Some(true), no_implicit_this: Some(false), ..Default::default() };
+    assert!(check_source_with_options(source, &loose).is_empty());
+}
+
+#[test]
+fn test_use_unknown_in_catch_variables() {
+    let source = r#"
+        let caught: unknown;
+        try {} catch (e) { caught = e; }
+        try {} catch (e: any) {}
+        try {} catch (e: Error) {}
+    "#;
+    let strict = CompilerOptions { strict: Some(true), ..Default::default() };
+    let diags = check_source_with_options(source, &strict);
+    assert_eq!(diags, vec!["Catch clause variable type annotation must be 'any' or 'unknown' if specified."]);
+    let source = "let v = 0; try {} catch (err) { v = err.code; }";
+    assert_eq!(check_source_with_options(source, &strict), vec!["'err' is of type 'unknown'."]);
+    let loose = CompilerOptions { strict: Some(true), use_unknown_in_catch_variables: Some(false), ..Default::default() };
+    assert!(check_source_with_options(source, &loose).is_empty());
+}
+
+#[test]
+fn test_always_strict_grammar() {
+    let source = r#"
+        declare const target: any;
+        var eval = 1;
+        let implements = 2;
+        function f(arguments: number) {}
+        delete target;
+        with (target) {}
+    "#;
+    let strict = CompilerOptions { always_strict: Some(true), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &strict), vec![
+        "Invalid use of 'eval' in strict mode.",
+        "Identifier expected. 'implements' is a reserved word in strict mode.",
+        "Invalid use of 'arguments' in strict mode.",
+        "'delete' cannot be called on an identifier in strict mode.",
+        "'with' statements are not allowed in strict mode.",
+    ]);
+    assert!(check_source_with_options(source, &CompilerOptions::default()).is_empty());
+    // Modules, "use strict" prologues and class bodies are strict without the option.
+    assert_eq!(check_source_with_options("\"use strict\";\nvar eval = 1;", &CompilerOptions::default()).len(), 1);
+    assert_eq!(check_source_with_options("export {};\nvar eval = 1;", &CompilerOptions::default()).len(), 1);
+    assert_eq!(
+        check_source_with_options("class C { m(yield: number) {} }", &CompilerOptions::default()),
+        vec!["Identifier expected. 'yield' is a reserved word in strict mode."],
+    );
+}
+
+// ============================================================================
+// Unused declarations and reachability
+// ============================================================================
+
+#[test]
+fn test_no_unused_locals() {
+    let source = r#"
+        import { a, b } from "./m";
+        import { c, d } from "./n";
+        export function f(x: number) {
+            const unused = 1;
+            let written = 0;
+            written = 2;
+            const used = a;
+            interface Shape { size: number }
+            type Alias = string;
+            function helper() {}
+            return used + x + later();
+        }
+        function later() { return 1; }
+        function orphan() {}
+        export { orphan as renamed };
+    "#;
+    let options = CompilerOptions { no_unused_locals: Some(true), ..Default::default() };
+    let diags = check_file("test.ts", source, Some(&options)).take_diagnostics().into_diagnostics();
+    let errors: Vec<&str> = diags.iter()
+        .filter(|d| d.category == DiagnosticCategory::Error)
+        .map(|d| d.message_text.as_str())
+        .collect();
+    assert_eq!(errors, vec![
+        "'unused' is declared but its value is never read.",
+        "'written' is declared but its value is never read.",
+        "'Shape' is declared but never used.",
+        "'Alias' is declared but never used.",
+        "'helper' is declared but its value is never read.",
+        "'b' is declared but its value is never read.",
+        "All imports in import declaration are unused.",
+    ]);
+    // Each is reported at the declared name; a fully unused import at the
+    // whole import declaration.
+    let spans: Vec<&str> = diags.iter()
+        .map(|d| {
+            let span = d.span.expect("unused declarations have a location");
+            &source[span.start as usize..(span.start + span.length) as usize]
+        })
+        .collect();
+    assert_eq!(spans, vec!["unused", "written", "Shape", "Alias", "helper", "b", "import { c, d } from \"./n\";"]);
+
+    // Without noUnusedLocals, unused imports are only suggestions.
+    let diags = check_file("test.ts", source, Some(&CompilerOptions::default())).take_diagnostics().into_diagnostics();
+    let suggestions: Vec<&str> = diags.iter()
+        .filter(|d| d.category == DiagnosticCategory::Suggestion)
+        .map(|d| d.message_text.as_str())
+        .collect();
+    assert_eq!(suggestions, vec![
+        "'b' is declared but its value is never read.",
+        "All imports in import declaration are unused.",
+    ]);
+    assert!(check_source_with_options(source, &CompilerOptions::default()).is_empty());
+}
+
+#[test]
+fn test_no_unused_locals_ignores_script_globals() {
+    // Top-level declarations of a script are globals visible to other files.
+    let source = "const a = 1;\nfunction f() { const b = 2; }";
+    let options = CompilerOptions { no_unused_locals: Some(true), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &options), vec!["'b' is declared but its value is never read."]);
+}
+
+#[test]
+fn test_no_unused_parameters() {
+    let source = r#"
+        function f(used: number, unused: number, _ignored: number) { return used; }
+        const g = (value: string) => 0;
+        class C {
+            constructor(private name: string, other: number) {}
+            set size(value: number) {}
+        }
+        declare function h(x: number): void;
+        f(1, 2, 3);
+    "#;
+    let options = CompilerOptions { no_unused_parameters: Some(true), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &options), vec![
+        "'unused' is declared but its value is never read.",
+        "'value' is declared but its value is never read.",
+        "'other' is declared but its value is never read.",
+    ]);
+}
+
+#[test]
+fn test_no_implicit_returns() {
+    let source = r#"
+        function some(x: boolean) {
+            if (x) { return 1; }
+        }
+        function all(x: boolean) {
+            if (x) { return 1; } else { return 2; }
+        }
+        function bare(x: boolean): number | string {
+            if (x) { return; }
+            return 1;
+        }
+        function thrown(x: boolean): number {
+            if (x) { return 1; }
+            throw new Error("no");
+        }
+        function looping(): number {
+            while (true) { if (Math.random()) { return 1; } }
+        }
+        function voided(x: boolean): void {
+            if (x) { return; }
+        }
+        const arrow = (x: boolean) => { if (x) { return 1; } };
+        class C {
+            m(x: boolean) { if (x) { return 1; } }
+        }
+    "#;
+    let options = CompilerOptions { no_implicit_returns: Some(true), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &options), vec![
+        "Not all code paths return a value.",
+        "Not all code paths return a value.",
+        "Not all code paths return a value.",
+        "Not all code paths return a value.",
+    ]);
+    assert!(check_source_with_options(source, &CompilerOptions::default()).is_empty());
+}
+
+#[test]
+fn test_no_fallthrough_cases_in_switch() {
+    let source = r#"
+        declare function log(x: number): void;
+        function f(x: number) {
+            switch (x) {
+                case 0:
+                case 1:
+                    log(1);
+                case 2:
+                    log(2);
+                    break;
+                case 3:
+                    return;
+                case 4: {
+                    log(4);
+                }
+                default:
+                    log(5);
+            }
+        }
+    "#;
+    let options = CompilerOptions { no_fallthrough_cases_in_switch: Some(true), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &options), vec![
+        "Fallthrough case in switch.",
+        "Fallthrough case in switch.",
+    ]);
+    assert!(check_source_with_options(source, &CompilerOptions::default()).is_empty());
+}
+
+#[test]
+fn test_unreachable_code_detected() {
+    let source = r#"
+        declare function log(x: number): void;
+        function f(x: number) {
+            if (x) {
+                return;
+                log(1);
+                log(2);
+            }
+            throw new Error();
+            function hoisted() {}
+            var declaredOnly;
+            log(3);
+        }
+        function g() {
+            while (true) {
+                log(1);
+            }
+            log(2);
+        }
+    "#;
+    let options = CompilerOptions { allow_unreachable_code: Some(false), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &options), vec![
+        "Unreachable code detected.",
+        "Unreachable code detected.",
+        "Unreachable code detected.",
+    ]);
+    let suggestions = check_file("test.ts", source, Some(&CompilerOptions::default())).take_diagnostics().into_diagnostics();
+    assert_eq!(suggestions.len(), 3);
+    assert!(suggestions.iter().all(|d| d.category == DiagnosticCategory::Suggestion));
+    let options = CompilerOptions { allow_unreachable_code: Some(true), ..Default::default() };
+    assert!(check_source_with_options(source, &options).is_empty());
+}
+
+#[test]
+fn test_unused_labels() {
+    let source = r#"
+        declare function log(x: number): void;
+        outer: for (let i = 0; i < 3; i++) {
+            inner: for (let j = 0; j < 3; j++) {
+                if (j) continue outer;
+                log(j);
+            }
+        }
+        unused: {
+            log(0);
+        }
+    "#;
+    let options = CompilerOptions { allow_unused_labels: Some(false), ..Default::default() };
+    assert_eq!(check_source_with_options(source, &options), vec!["Unused label.", "Unused label."]);
+    let suggestions = check_file("test.ts", source, Some(&CompilerOptions::default())).take_diagnostics().into_diagnostics();
+    assert_eq!(suggestions.len(), 2);
+    assert!(suggestions.iter().all(|d| d.category == DiagnosticCategory::Suggestion));
+    let labels: Vec<&str> = suggestions.iter()
+        .map(|d| {
+            let span = d.span.expect("unused labels have a location");
+            &source[span.start as usize..(span.start + span.length) as usize]
+        })
+        .collect();
+    assert_eq!(labels, vec!["inner", "unused"]);
+    let options = CompilerOptions { allow_unused_labels: Some(true), ..Default::default() };
+    assert!(check_source_with_options(source, &options).is_empty());
+}
+
+#[test]
+fn test_never_returning_calls_end_the_flow() {
+    let source = r#"
+        declare function fail(message: string): never;
+        declare function assert(value: unknown): asserts value;
+        declare const process: { exit(code?: number): never };
+        declare function log(x: number): void;
+        function a(): number {
+            fail("a");
+        }
+        function b(x: number): number {
+            if (x) {
+                return 1;
+            }
+            process.exit(1);
+        }
+        function c() {
+            assert(false);
+            log(1);
+        }
+        function d() {
+            assert(true);
+            log(1);
+        }
+    "#;
+    let options = CompilerOptions {
+        allow_unreachable_code: Some(false),
+        no_implicit_returns: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(check_source_with_options(source, &options), vec!["Unreachable code detected."]);
+}
+
+#[test]
+fn test_functions_that_always_throw_return_never() {
+    let source = r#"
+        const thrower = () => { throw new Error(); };
+        const expression = function () { throw new Error(); };
+        const looping = () => { while (true) {} };
+        const empty = () => {};
+        const t = thrower();
+        const e = expression();
+        const l = looping();
+        const v = empty();
+    "#;
+    assert_eq!(get_inferred_type(source, "t"), "never");
+    assert_eq!(get_inferred_type(source, "e"), "never");
+    assert_eq!(get_inferred_type(source, "l"), "never");
+    assert_eq!(get_inferred_type(source, "v"), "void");
+}
+
+#[test]
+fn test_never_function_with_reachable_end() {
+    let source = r#"
+        declare function log(x: number): void;
+        function ok(): never {
+            throw new Error();
+        }
+        function bad(): never {
+            log(1);
+        }
+    "#;
+    assert_eq!(check_source(source), vec!["A function returning 'never' cannot have a reachable end point."]);
+}
+
+#[test]
+fn test_interface_merging_orders_later_overloads_first() {
+    let source = r#"
+        interface Parser { parse(x: string): string; }
+        interface Parser { parse(x: number): number; strict: boolean; }
+        declare const p: Parser;
+        const first = p.parse;
+        const n = p.parse(1);
+        const s = p.parse("x");
+        const b = p.strict;
+    "#;
+    assert!(check_source(source).is_empty());
+    assert_eq!(get_inferred_type(source, "first"), "{ (x: number): number; (x: string): string; }");
+    assert_eq!(get_inferred_type(source, "n"), "number");
+    assert_eq!(get_inferred_type(source, "s"), "string");
+    assert_eq!(get_inferred_type(source, "b"), "boolean");
+}
+
+#[test]
+fn test_namespaces_merge_with_functions_classes_and_enums() {
+    let source = r#"
+        function build() { return 1; }
+        namespace build { export const version = "1"; }
+        class Point { x = 0; }
+        namespace Point { export function origin() { return new Point(); } }
+        enum Color { Red }
+        namespace Color { export function parse(s: string) { return Color.Red; } }
+        namespace A.B { export const depth = 2; export interface Shape { sides: number } }
+        const called = build();
+        const version = build.version;
+        const x = Point.origin().x;
+        const red = Color.parse("red");
+        const depth = A.B.depth;
+        const shape: A.B.Shape = { sides: 3 };
+    "#;
+    assert!(check_source(source).is_empty());
+    assert_eq!(get_inferred_type(source, "called"), "number");
+    assert_eq!(get_inferred_type(source, "version"), "\"1\"");
+    assert_eq!(get_inferred_type(source, "x"), "number");
+    assert_eq!(get_inferred_type(source, "red"), "Color.Red");
+    assert_eq!(get_inferred_type(source, "depth"), "2");
+    assert_eq!(get_inferred_type(source, "shape"), "A.B.Shape");
+}
+
+#[test]
+fn test_enum_and_class_interface_merging() {
+    let source = r#"
+        enum Level { Low, High }
+        enum Level { Critical = 10, Fatal }
+        interface Widget { label: string }
+        class Widget { size = 1; }
+        interface Widget { visible: boolean }
+        const fatal = Level.Fatal;
+        const level: Level = Level.Low;
+        const w = new Widget();
+        const label = w.label;
+        const visible = w.visible;
+        const size = w.size;
+    "#;
+    assert!(check_source(source).is_empty());
+    assert_eq!(get_inferred_type(source, "fatal"), "Level.Fatal");
+    assert_eq!(get_inferred_type(source, "label"), "string");
+    assert_eq!(get_inferred_type(source, "visible"), "boolean");
+    assert_eq!(get_inferred_type(source, "size"), "number");
+}
+
+#[test]
+fn test_ambient_modules_and_augmentations() {
+    let source = r#"
+        import express, { Request } from "express";
+        import logo from "./logo.svg";
+        import * as lib from "lib";
+        import { missing } from "lib";
+        declare module "express" {
+            interface Request { body: string }
+            function express(): number;
+            export default express;
+        }
+        declare module "express" {
+            interface Request { user: string }
+        }
+        declare module "*.svg" { const content: string; export default content; }
+        declare module "lib";
+        export {};
+        declare global { interface Window { app: number } }
+        const req: Request = { body: "", user: "me" };
+        const app = express();
+        const src = logo;
+        const anything = lib;
+        declare const w: Window;
+        const count = w.app;
+    "#;
+    assert_eq!(check_source(source), Vec::<String>::new());
+    assert_eq!(get_inferred_type(source, "app"), "number");
+    assert_eq!(get_inferred_type(source, "src"), "string");
+    assert_eq!(get_inferred_type(source, "count"), "number");
+
+    let errors = check_source(r#"
+        import { Request, Response } from "express";
+        import settings from "express";
+        declare module "express" { interface Request { body: string } }
+        const req: Request = { body: 1 };
+    "#);
+    assert!(errors.contains(&"Module '\"express\"' has no exported member 'Response'.".to_string()), "{errors:?}");
+    assert!(errors.contains(&"Module '\"express\"' has no default export.".to_string()), "{errors:?}");
+    assert!(errors.contains(&"Type 'number' is not assignable to type 'string'.".to_string()), "{errors:?}");
+}
+
+#[test]
+fn test_isolated_modules_reports_single_file_transpile_hazards() {
+    let isolated = CompilerOptions { isolated_modules: Some(true), ..Default::default() };
+    let source = r#"
+        declare module "shapes" {
+            interface Shape { sides: number }
+            const unit: number;
+        }
+        declare const enum Direction { Up = 1, Down }
+        const enum Local { A }
+        interface Point { x: number }
+        type Pair = [number, number];
+        class Box {}
+        export { Point, Box };
+        export type { Pair };
+        export { Shape, unit } from "shapes";
+        const up = Direction.Up;
+        const down = Direction["Down"];
+        const a = Local.A;
+    "#;
+    assert_eq!(check_source_with_options(source, &isolated), vec![
+        "Re-exporting a type when 'isolatedModules' is enabled requires using 'export type'.".to_string(),
+        "Re-exporting a type when 'isolatedModules' is enabled requires using 'export type'.".to_string(),
+        "Cannot access ambient const enums when 'isolatedModules' is enabled.".to_string(),
+        "Cannot access ambient const enums when 'isolatedModules' is enabled.".to_string(),
+    ]);
+    assert_eq!(check_source(source), Vec::<String>::new());
+
+    assert_eq!(check_source_with_options("const x = 1;", &isolated), vec![
+        "'test.ts' cannot be compiled under '--isolatedModules' because it is considered a global script file. Add an import, export, or an empty 'export {}' statement to make it a module.".to_string(),
+    ]);
+    assert_eq!(check_source_with_options("const x = 1;\nexport {};", &isolated), Vec::<String>::new());
+}
+
+#[test]
+fn test_verbatim_module_syntax_requires_type_only_imports() {
+    let verbatim = CompilerOptions { verbatim_module_syntax: Some(true), ..Default::default() };
+    let source = r#"
+        declare module "shapes" {
+            interface Shape { sides: number }
+            const unit: number;
+            class Polygon {}
+        }
+        import { Shape, unit, Polygon } from "shapes";
+        import { type Shape as S } from "shapes";
+        import type { Shape as T } from "shapes";
+        interface Local { x: number }
+        export { Local };
+        export const count = unit;
+    "#;
+    assert_eq!(check_source_with_options(source, &verbatim), vec![
+        "'Shape' is a type and must be imported using a type-only import when 'verbatimModuleSyntax' is enabled.".to_string(),
+        "Re-exporting a type when 'verbatimModuleSyntax' is enabled requires using 'export type'.".to_string(),
+    ]);
+
+    let commonjs = CompilerOptions { module: Some(ModuleKind::CommonJS), ..verbatim };
+    assert_eq!(check_source_with_options(r#"
+        export const a = 1;
+        export function f() {}
+        export interface I {}
+        export type U = string;
+        export declare const b: number;
+        export namespace Types { export interface J {} }
+    "#, &commonjs), vec![
+        "A top-level 'export' modifier cannot be used on value declarations in a CommonJS module when 'verbatimModuleSyntax' is enabled.".to_string(),
+        "A top-level 'export' modifier cannot be used on value declarations in a CommonJS module when 'verbatimModuleSyntax' is enabled.".to_string(),
+    ]);
+}
+
+/// Helper: the range of `text` within the first appearance of `context` in `source`.
+fn range_of(source: &str, context: &str, text: &str) -> TextRange {
+    let pos = source.find(context).expect("context should occur in source")
+        + context.find(text).expect("text should occur in context");
+    TextRange::new(pos as u32, (pos + text.len()) as u32)
+}
+
+/// Helper: the range of the initializer of the first declaration of the
+/// variable statement at `index`, as the parser records it.
+fn initializer_range(source: &str, index: usize) -> TextRange {
+    let arena = Bump::new();
+    let sf = Parser::new(&arena, "test.ts", source).parse_source_file();
+    match &sf.statements[index] {
+        Statement::VariableStatement(n) => n.declaration_list.declarations[0].initializer.unwrap().data().range,
+        _ => panic!("statement {} should be a variable statement", index),
+    }
+}
+
+#[test]
+fn test_query_types_symbols_and_signatures_at_locations() {
+    let source = "interface Point { x: number; y: number }
+function dist(p: Point, scale?: number): number { return p.x * (scale ?? 1); }
+const d = dist({ x: 1, y: 2 });
+const arr = [1, 2];
+function first<T extends Point>(t: T) { return t; }";
+    let checker = check_file("test.ts", source, None);
+    let format = |checker: &Checker, t| checker.type_to_string(t, TypeFormatFlags::NONE);
+
+    let d = checker.get_type_at_location(range_of(source, "d =", "d")).unwrap();
+    assert_eq!(format(&checker, d), "number");
+
+    // A reference and the declaration name the same symbol
+    let declared = checker.get_symbol_at_location(range_of(source, "function dist", "dist")).unwrap();
+    assert_eq!(checker.get_symbol_at_location(range_of(source, "= dist", "dist")), Some(declared));
+    assert_eq!(checker.get_symbol(declared).unwrap().name_text, "dist");
+
+    let dist = checker.get_type_at_location(range_of(source, "= dist", "dist")).unwrap();
+    assert_eq!(checker.get_signatures_of_type(dist, SignatureKind::Call).len(), 1);
+    assert!(checker.get_signatures_of_type(dist, SignatureKind::Construct).is_empty());
+
+    let call = initializer_range(source, 2);
+    let signature = checker.get_resolved_signature(call).unwrap();
+    assert_eq!(signature.parameters.len(), 2);
+    assert_eq!(format(&checker, signature.return_type), "number");
+
+    let point = checker.get_type_at_location(range_of(source, "Point", "Point")).unwrap();
+    let argument = range_of(source, "{ x: 1, y: 2 }", "{ x: 1, y: 2 }");
+    assert_eq!(checker.get_contextual_type(argument), Some(point));
+    let properties: Vec<String> = checker.get_properties_of_type(point).into_iter().map(|(name, _)| name).collect();
+    assert_eq!(properties, ["x", "y"]);
+
+    // A property name has the type of the access
+    let x = checker.get_type_at_location(range_of(source, "p.x", "x")).unwrap();
+    assert_eq!(format(&checker, x), "number");
+
+    let arr = checker.get_type_at_location(range_of(source, "arr", "arr")).unwrap();
+    assert_eq!(format(&checker, arr), "number[]");
+    assert_eq!(checker.type_to_string(arr, TypeFormatFlags::WRITE_ARRAY_AS_GENERIC_TYPE), "Array<number>");
+
+    let t = checker.get_type_at_location(range_of(source, "t; }", "t")).unwrap();
+    assert_eq!(format(&checker, t), "T");
+    assert_eq!(checker.get_apparent_type(t), point);
+}
+
+#[test]
+fn test_query_apparent_types_and_properties_of_unions() {
+    let source = "interface String { length: number }
+declare const s: string;
+const lit = \"a\";
+declare const n: number;
+declare const u: { kind: \"a\"; a: number } | { kind: \"b\" };";
+    let checker = check_file("test.ts", source, None);
+    let wrapper = checker.get_type_at_location(range_of(source, "String", "String")).unwrap();
+
+    // Primitives have the members of their wrapper interfaces
+    let s = checker.get_type_at_location(range_of(source, "s:", "s")).unwrap();
+    assert_eq!(checker.get_apparent_type(s), wrapper);
+    let lit = checker.get_type_at_location(range_of(source, "lit", "lit")).unwrap();
+    assert_eq!(checker.get_apparent_type(lit), wrapper);
+    let properties: Vec<String> = checker.get_properties_of_type(s).into_iter().map(|(name, _)| name).collect();
+    assert_eq!(properties, ["length"]);
+    // `Number` is not declared
+    let n = checker.get_type_at_location(range_of(source, "n:", "n")).unwrap();
+    assert_eq!(checker.get_apparent_type(n), n);
+
+    // A union has the properties of every constituent, with their types in each
+    let u = checker.get_type_at_location(range_of(source, "u:", "u")).unwrap();
+    let properties: Vec<(String, Vec<String>)> = checker.get_properties_of_type(u).into_iter()
+        .map(|(name, types)| (name, types.into_iter().map(|t| checker.type_to_string(t, TypeFormatFlags::NONE)).collect()))
+        .collect();
+    assert_eq!(properties, [("kind".to_string(), vec!["\"a\"".to_string(), "\"b\"".to_string()])]);
+}
+
+#[test]
+fn test_query_aliases_and_module_exports() {
+    let source = "namespace Geo {
+    export function origin() { return 0; }
+    export const unit = 1;
+    const hidden = 2;
+}
+import O = Geo.origin;
+O();";
+    let checker = check_file("test.ts", source, None);
+    let geo = checker.get_symbol_at_location(range_of(source, "Geo", "Geo")).unwrap();
+    let exports: Vec<&str> = checker.get_exports_of_module(geo).into_iter()
+        .map(|id| checker.get_symbol(id).unwrap().name_text.as_str())
+        .collect();
+    assert_eq!(exports, ["origin", "unit"]);
+
+    let alias = checker.get_symbol_at_location(range_of(source, "O()", "O")).unwrap();
+    let origin = checker.get_symbol_at_location(range_of(source, "origin", "origin")).unwrap();
+    assert_eq!(checker.get_aliased_symbol(alias), Some(origin));
+    assert_eq!(checker.get_aliased_symbol(origin), None);
+}
+
+#[test]
+fn test_recursive_type_aliases() {
+    let source = r#"
+        type Json = string | number | boolean | null | Json[] | { [key: string]: Json };
+        type List<T> = { head: T; tail: List<T> | null };
+        let j: Json = { a: [1, "x", { b: null }] };
+        let badJson: Json = { a: () => 1 };
+        let l: List<number> = { head: 1, tail: { head: 2, tail: null } };
+        let badList: List<number> = { head: 1, tail: { head: "x", tail: null } };
+        let tail = l.tail;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Type '{ a: () => number; }' is not assignable to type 'Json'.",
+        "Type 'string' is not assignable to type 'number'.",
+    ]);
+    assert_eq!(get_inferred_type(source, "j"), "Json");
+    assert_eq!(get_inferred_type(source, "tail"), "List<number> | null");
+    assert_eq!(get_inferred_type(source, "Json"), "string | number | boolean | Json[] | { [x: string]: Json; } | null");
+}
+
+#[test]
+fn test_type_aliases_resolve_references_declared_later() {
+    let source = r#"
+        type A = { b: B };
+        type B = { a: A | null; n: number };
+        let a: A = { b: { a: null, n: 1 } };
+        let badA: A = { b: { a: null, n: "1" } };
+        let ba = a.b.a;
+        type C = string | C;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Type 'string' is not assignable to type 'number'.",
+        "Type alias 'C' circularly references itself.",
+    ]);
+    assert_eq!(get_inferred_type(source, "ba"), "A | null");
+}
+
+#[test]
+fn test_expanding_recursive_alias_is_instantiated_on_access() {
+    let source = r#"
+        type Deep<T> = { next: Deep<T[]>; value: T };
+        declare let d: Deep<number>;
+        let next = d.next.next;
+        let value = d.next.next.value;
+    "#;
+    assert!(check_source(source).is_empty());
+    assert_eq!(get_inferred_type(source, "next"), "Deep<number[][]>");
+    assert_eq!(get_inferred_type(source, "value"), "number[][]");
+}
+
+#[test]
+fn test_excessively_deep_type_instantiation() {
+    let source = r#"
+        type Tuple<N extends number, T extends any[] = []> = T["length"] extends N ? T : Tuple<N, [...T, any]>;
+        let three: Tuple<3> = [1, 2, 3];
+        let short: Tuple<3> = [1, 2];
+        let long: Tuple<500>;
+        let huge: Tuple<1500>;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Type '[1, 2]' is not assignable to type '[any, any, any]'.",
+        "Type instantiation is excessively deep and possibly infinite.",
+    ]);
+    assert_eq!(get_inferred_type(source, "three"), "[any, any, any]");
+}
+
+#[test]
+fn test_tail_recursive_conditional_types() {
+    let source = r#"
+        type Count<N extends number, A extends unknown[] = []> = A["length"] extends N ? A["length"] : Count<N, [...A, 0]>;
+        type Deep<T, N extends unknown[] = []> = N["length"] extends 100 ? T : Deep<T[], [...N, 0]>;
+        let sixty: Count<60> = 60;
+        declare let deep: Deep<string>;
+    "#;
+    assert!(check_source(source).is_empty());
+    assert_eq!(get_inferred_type(source, "sixty"), "60");
+    assert_eq!(get_inferred_type(source, "deep"), format!("string{}", "[]".repeat(100)));
+}
+
+#[test]
+fn test_expanding_recursive_alias_is_not_assignable_to_primitive() {
+    let source = r#"
+        type Inf<T> = { x: Inf<Inf<T>> };
+        declare const inf: Inf<number>;
+        declare const deep: Inf<Inf<Inf<Inf<number>>>>;
+        const q: number = inf.x.x.x;
+        const r: number = deep;
+        const s: Inf<string> = inf;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Type 'Inf<Inf<Inf<Inf<number>>>>' is not assignable to type 'number'.",
+        "Type 'Inf<Inf<Inf<Inf<number>>>>' is not assignable to type 'number'.",
+        "Type 'Inf<number>' is not assignable to type 'Inf<string>'.",
+    ]);
+}
+
+#[test]
+fn test_union_display_order() {
+    let source = r#"
+        enum Color { Red, Green }
+        declare let a: undefined | number | null | string;
+        declare let b: true | string | false;
+        declare let c: Color.Green | "x" | Color.Red;
+        declare let d: (() => void) | number[];
+        declare let e: (string | number)[];
+    "#;
+    assert_eq!(get_inferred_type(source, "a"), "string | number | null | undefined");
+    assert_eq!(get_inferred_type(source, "b"), "string | boolean");
+    assert_eq!(get_inferred_type(source, "c"), "Color | \"x\"");
+    assert_eq!(get_inferred_type(source, "d"), "(() => void) | number[]");
+    assert_eq!(get_inferred_type(source, "e"), "(string | number)[]");
+}
+
+#[test]
+fn test_long_types_are_truncated_in_diagnostics() {
+    let properties: String = (0..40).map(|i| format!("property{i}: number; ")).collect();
+    let source = format!("declare let wide: {{ {properties}}};\nlet n: number = wide;");
+    let errors = check_source(&source);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("property0: number; property1: number;"), "{errors:?}");
+    assert!(errors[0].contains("; ... 29 more ...; property39: number; }'"), "{errors:?}");
+
+    let options = CompilerOptions { no_error_truncation: Some(true), ..Default::default() };
+    let errors = check_source_with_options(&source, &options);
+    assert!(!errors[0].contains("more ..."), "{errors:?}");
+    assert!(errors[0].contains("property38: number; property39: number; }'"), "{errors:?}");
+}
+
+#[test]
+fn test_types_declared_in_namespaces_display_qualified() {
+    let source = r#"
+        namespace Shapes {
+            export class Circle { radius = 1; }
+            export enum Kind { Round, Square }
+            export namespace Inner { export type Pair = { left: Circle; right: Circle }; }
+        }
+        let c: Shapes.Circle = 1;
+        let k: Shapes.Kind = "round";
+        let p: Shapes.Inner.Pair = 2;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Type 'number' is not assignable to type 'Shapes.Circle'.",
+        "Type '\"round\"' is not assignable to type 'Shapes.Kind'.",
+        "Type 'number' is not assignable to type 'Shapes.Inner.Pair'.",
+    ]);
+}
+
+#[test]
+fn test_interface_types_display_by_name() {
+    let source = r#"
+        interface Cfg { host: string; port: number }
+        interface Box<T> { value: T }
+        interface Pair<A, B = string> { first: A; second: B }
+        declare const cfg: Cfg;
+        declare const nested: Box<Box<Cfg>>;
+        declare const pair: Pair<number>;
+        declare function unwrap<T>(box: Box<T>): Box<T>;
+        declare function take(box: Box<string>): void;
+        const unwrapped = unwrap(nested);
+        const c: Cfg = { host: "h", port: 1, extra: true };
+        take(unwrapped);
+    "#;
+    assert_eq!(get_inferred_type(source, "cfg"), "Cfg");
+    assert_eq!(get_inferred_type(source, "nested"), "Box<Box<Cfg>>");
+    assert_eq!(get_inferred_type(source, "pair"), "Pair<number, string>");
+    assert_eq!(get_inferred_type(source, "unwrapped"), "Box<Box<Cfg>>");
+    assert_eq!(check_source(source), vec![
+        "Object literal may only specify known properties, and 'extra' does not exist in type 'Cfg'.",
+        "Argument of type 'Box<Box<Cfg>>' is not assignable to parameter of type 'Box<string>'.",
+    ]);
+}
+
+#[test]
+fn test_empty_and_literal_sources_display() {
+    let source = r#"
+        declare const empty: {};
+        declare function takeStrings(x: string[]): void;
+        declare function takeObject(x: { a: number }): void;
+        const n: number = empty;
+        takeStrings([1]);
+        takeObject({ a: "x" });
+        const u: string | boolean = [1];
+        const v: number = { a: "x" };
+        const w: number = { k: ["a"] };
+        const keep: { k: "a" }[] | number = [{ k: "b" }];
+    "#;
+    assert_eq!(get_inferred_type(source, "empty"), "{}");
+    assert_eq!(check_source(source), vec![
+        "Type '{}' is not assignable to type 'number'.",
+        "Argument of type 'number[]' is not assignable to parameter of type 'string[]'.",
+        "Argument of type '{ a: string; }' is not assignable to parameter of type '{ a: number; }'.",
+        "Type 'number[]' is not assignable to type 'string | boolean'.",
+        "Type '{ a: string; }' is not assignable to type 'number'.",
+        "Type '{ k: string[]; }' is not assignable to type 'number'.",
+        "Type '{ k: \"b\"; }[]' is not assignable to type 'number | { k: \"a\"; }[]'.",
+    ]);
+}
+
+#[test]
+fn test_namespace_types_do_not_merge_across_namespaces() {
+    let source = r#"
+        interface Foo { x: number }
+        namespace NS { export interface Foo { a: 1 } }
+        namespace NS2 { export interface Foo { b: 1 } }
+        namespace N {
+            export interface Foo { y: string }
+            export const local: Foo = { y: "y" };
+        }
+        namespace NS { export interface Foo { c: 1 } }
+        declare const one: NS.Foo;
+        declare const two: NS2.Foo;
+        declare const global: Foo;
+        declare const n: N.Foo;
+        namespace A { export type Box<T> = { value: T } }
+        namespace B { export type Box<T> = { item: T } }
+        declare const a: A.Box<number>;
+        declare const b: B.Box<string>;
+        declare const oneKeys: keyof NS.Foo;
+        declare const twoKeys: keyof NS2.Foo;
+        declare const globalKeys: keyof Foo;
+        declare const nKeys: keyof N.Foo;
+    "#;
+    assert!(check_source(source).is_empty());
+    assert_eq!(get_inferred_type(source, "one"), "NS.Foo");
+    assert_eq!(get_inferred_type(source, "two"), "NS2.Foo");
+    assert_eq!(get_inferred_type(source, "global"), "Foo");
+    assert_eq!(get_inferred_type(source, "n"), "N.Foo");
+    assert_eq!(get_inferred_type(source, "oneKeys"), "\"a\" | \"c\"");
+    assert_eq!(get_inferred_type(source, "twoKeys"), "\"b\"");
+    assert_eq!(get_inferred_type(source, "globalKeys"), "\"x\"");
+    assert_eq!(get_inferred_type(source, "nKeys"), "\"y\"");
+    assert_eq!(get_inferred_type(source, "a"), "A.Box<number>");
+    assert_eq!(get_inferred_type(source, "b"), "B.Box<string>");
+}
+
+#[test]
+fn test_accessor_pairs_have_separate_read_and_write_types() {
+    let source = r#"
+        class Box {
+            private _size = 0;
+            get size(): number { return this._size; }
+            set size(value: string | number) { this._size = Number(value); }
+            get label() { return "box"; }
+        }
+        const box = new Box();
+        box.size = "10";
+        box.size = 10;
+        box.size = true;
+        const size = box.size;
+        const label = box.label;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Type 'boolean' is not assignable to type 'string | number'.",
+    ]);
+    assert_eq!(get_inferred_type(source, "size"), "number");
+    assert_eq!(get_inferred_type(source, "label"), "string");
+}
+
+#[test]
+fn test_object_literal_accessors() {
+    let source = r#"
+        const point = {
+            x: 1,
+            get doubled() { return this.x * 2; },
+            get name(): string { return "p"; },
+            set name(value: string | undefined) {},
+        };
+        point.name = undefined;
+        point.doubled = 4;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Cannot assign to 'doubled' because it is a read-only property.",
+    ]);
+    assert_eq!(get_inferred_type(source, "point"), "{ x: number; readonly doubled: number; name: string; }");
+}
+
+#[test]
+fn test_assignments_to_readonly_properties() {
+    let source = r#"
+        class Account {
+            readonly id: number;
+            get balance(): number { return 0; }
+            constructor(id: number) { this.id = id; }
+            reset() { this.id = 0; }
+        }
+        const account = new Account(1);
+        account.id = 2;
+        account.balance += 10;
+        const config = { mode: "dark", retries: 3 } as const;
+        config.retries++;
+        enum Color { Red }
+        Color.Red = 0;
+        const frozen: Readonly<{ count: number }> = { count: 0 };
+        frozen["count"] = 1;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Cannot assign to 'id' because it is a read-only property.",
+        "Cannot assign to 'id' because it is a read-only property.",
+        "Cannot assign to 'balance' because it is a read-only property.",
+        "Cannot assign to 'retries' because it is a read-only property.",
+        "Cannot assign to 'Red' because it is a read-only property.",
+        "Cannot assign to 'count' because it is a read-only property.",
+    ]);
+}
+
+#[test]
+fn test_writes_through_readonly_index_signatures() {
+    let source = r#"
+        const list: readonly number[] = [1, 2];
+        list[0] = 3;
+        list.length = 0;
+        const pair = [1, "a"] as const;
+        pair[0] = 5;
+        const triple: readonly [number, number, number] = [1, 2, 3];
+        triple[1]++;
+        declare const dict: { readonly [key: string]: number };
+        dict.total = 1;
+        const values = [1, 2];
+        values[0] = 3;
+    "#;
+    assert_eq!(check_source(source), vec![
+        "Index signature in type 'readonly number[]' only permits reading.",
+        "Cannot assign to 'length' because it is a read-only property.",
+        "Cannot assign to '0' because it is a read-only property.",
+        "Cannot assign to '1' because it is a read-only property.",
+        "Index signature in type '{ readonly [x: string]: number; }' only permits reading.",
+    ]);
+}
+
+#[test]
+fn test_set_accessor_cannot_have_return_type_annotation() {
+    let source = r#"
+        class Gauge {
+            get level(): number { return 0; }
+            set level(value: number): void {}
+        }
+    "#;
+    let diagnostics = check_source_diagnostics(source);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, 1095);
+
+    // Methods named `get` and `set` are not accessors.
+    let source = r#"
+        class Store {
+            get(key: string): number { return 0; }
+            set(key: string, value: number): void {}
+        }
+        const store = new Store();
+        store.set("a", store.get("a"));
+    "#;
+    assert!(check_source_diagnostics(source).is_empty());
+}
+
+// ============================================================================
+// JSX
+// ============================================================================
+
+const JSX_NAMESPACE: &str = "
+declare namespace JSX {
+    interface Element { type: string }
+    interface ElementClass { render(): Element | null }
+    interface ElementAttributesProperty { props: {} }
+    interface ElementChildrenAttribute { children: {} }
+    interface IntrinsicAttributes { key?: string }
+    interface DivProps { id?: string; hidden?: boolean; children?: unknown }
+    interface InputProps { value: string; disabled?: boolean }
+    interface IntrinsicElements { div: DivProps; input: InputProps }
+}
+";
+
+fn jsx_options() -> CompilerOptions {
+    CompilerOptions { jsx: Some(JsxEmit::Preserve), ..Default::default() }
+}
+
+fn check_jsx(source: &str) -> Vec<String> {
+    check_tsx_source(&format!("{JSX_NAMESPACE}{source}"), &jsx_options())
+}
+
+#[test]
+fn test_jsx_intrinsic_elements() {
+    assert!(check_jsx("const a = <div id=\"main\" hidden data-role=\"x\">text {1} <input value=\"v\" /></div>;").is_empty());
+    assert_eq!(check_jsx("const a = <div id={1} />;"), vec!["Type 'number' is not assignable to type 'string | undefined'."]);
+    assert_eq!(check_jsx("const a = <span />;"), vec!["Property 'span' does not exist on type 'JSX.IntrinsicElements'."]);
+    assert_eq!(check_jsx("const a = <div title=\"t\" />;"), vec![
+        "Type '{ title: string; }' is not assignable to type 'JSX.DivProps'.\n  Property 'title' does not exist on type 'JSX.DivProps'.",
+    ]);
+    assert_eq!(check_jsx("const a = <input disabled />;"), vec![
+        "Type '{ disabled: true; }' is not assignable to type 'JSX.InputProps'.\n  \
+         Property 'value' is missing in type '{ disabled: true; }' but required in type 'JSX.InputProps'.",
+    ]);
+}
+
+#[test]
+fn test_jsx_function_and_class_components() {
+    let components = "
+interface Props { name: string; count?: number; children?: string }
+function Greeting(props: Props): JSX.Element { return <div />; }
+class Counter {
+    props: { start: number };
+    constructor(props: { start: number }) { this.props = props; }
+    render(): JSX.Element { return <div />; }
+}
+function Broken(props: {}): number { return 1; }
+";
+    let check = |source: &str| check_jsx(&format!("{components}{source}"));
+    assert!(check("const a = <Greeting name=\"n\" count={2} key=\"k\">hello</Greeting>; const b = <Counter start={1} />;").is_empty());
+    assert_eq!(check("const a = <Greeting count={2} />;"), vec![
+        "Type '{ count: number; }' is not assignable to type 'JSX.IntrinsicAttributes & Props'.\n  \
+         Property 'name' is missing in type '{ count: number; }' but required in type 'Props'.",
+    ]);
+    assert_eq!(check("const a = <Greeting name={1} />;"), vec!["Type 'number' is not assignable to type 'string'."]);
+    assert_eq!(check("const a = <Greeting name=\"n\"><div /></Greeting>;"), vec!["Type 'JSX.Element' is not assignable to type 'string | undefined'."]);
+    assert_eq!(check("const a = <Greeting name=\"n\" children=\"a\">b</Greeting>;"), vec![
+        "'children' are specified twice. The attribute named 'children' will be overwritten.",
+    ]);
+    assert_eq!(check("const a = <Counter start=\"1\" />;"), vec!["Type 'string' is not assignable to type 'number'."]);
+    assert_eq!(check("const a = <Broken />;"), vec!["'Broken' cannot be used as a JSX component.\n  Its return type 'number' is not a valid JSX element."]);
+    assert_eq!(check("const a: number = <Greeting name=\"n\" />;"), vec!["Type 'JSX.Element' is not assignable to type 'number'."]);
+}
+
+#[test]
+fn test_jsx_generic_components() {
+    let components = "
+function List<T>(props: { items: T[]; first: T }): JSX.Element { return <div />; }
+";
+    let check = |source: &str| check_jsx(&format!("{components}{source}"));
+    assert!(check("const a = <List items={[1, 2]} first={1} />;").is_empty());
+    assert_eq!(check("const a = <List<string> items={[\"a\"]} first={1} />;"), vec!["Type 'number' is not assignable to type 'string'."]);
+}
+
+#[test]
+fn test_jsx_tags_reference_values() {
+    let options = CompilerOptions { jsx: Some(JsxEmit::Preserve), no_unused_locals: Some(true), ..Default::default() };
+    let source = format!("{JSX_NAMESPACE}function Used(): JSX.Element {{ return <div />; }}\nfunction Unused(): JSX.Element {{ return <div />; }}\nexport const a = <Used />;");
+    assert_eq!(check_tsx_source(&source, &options), vec!["'Unused' is declared but its value is never read."]);
+}
+
+#[test]
+fn test_jsx_spread_attributes() {
+    let components = "
+interface Props { a: string; b: number }
+function Comp(props: Props): JSX.Element { return <div />; }
+declare const partial: { a: string };
+declare const full: Props;
+declare const anything: any;
+";
+    let check = |source: &str| check_jsx(&format!("{components}{source}"));
+    assert!(check("const x = <Comp {...partial} b={1} />; const y = <Comp {...full} />; const z = <Comp {...anything} />;").is_empty());
+    assert_eq!(check("const x = <Comp {...partial} />;"), vec![
+        "Type '{ a: string; }' is not assignable to type 'JSX.IntrinsicAttributes & Props'.\n  \
+         Property 'b' is missing in type '{ a: string; }' but required in type 'Props'.",
+    ]);
+    assert_eq!(check("const x = <Comp a=\"x\" {...full} />;"), vec![
+        "'a' is specified more than once, so this usage will be overwritten.",
+    ]);
+    assert_eq!(check("const x = <Comp {...full} b=\"2\" />;"), vec!["Type 'string' is not assignable to type 'number'."]);
+}
+
+#[test]
+fn test_jsx_namespace_resolution() {
+    let runtime = "
+declare namespace JSX { interface Element { global: true } interface IntrinsicElements { div: {} } }
+declare module 'preact/jsx-runtime' {
+    namespace JSX { interface Element { preact: true } interface IntrinsicElements { p: {} } }
+}
+";
+    let automatic = CompilerOptions {
+        jsx: Some(JsxEmit::ReactJSX),
+        jsx_import_source: Some("preact".to_string()),
+        ..Default::default()
+    };
+    assert!(check_tsx_source(&format!("{runtime}const a = <p />;"), &automatic).is_empty());
+    assert_eq!(check_tsx_source(&format!("{runtime}const a = <div />;"), &automatic), vec![
+        "Property 'div' does not exist on type 'JSX.IntrinsicElements'.",
+    ]);
+    assert!(check_tsx_source(&format!("{runtime}const a = <div />;"), &jsx_options()).is_empty());
+
+    assert_eq!(check_tsx_source("const a = <div />;", &CompilerOptions::default()), vec![
+        "Cannot use JSX unless the '--jsx' flag is provided.",
+    ]);
+    let strict = CompilerOptions { jsx: Some(JsxEmit::Preserve), no_implicit_any: Some(true), ..Default::default() };
+    assert_eq!(check_tsx_source("const a = <div />;", &strict), vec![
+        "JSX element implicitly has type 'any' because the global type 'JSX.Element' does not exist.",
+        "JSX element implicitly has type 'any' because no interface 'JSX.IntrinsicElements' exists.",
+    ]);
+    assert_eq!(check_tsx_source("declare const Foo: number; const a = <Foo />;", &jsx_options()), vec![
+        "JSX element type 'Foo' does not have any construct or call signatures.",
+    ]);
+}
@@ -156,10 +156,12 @@ fn run_compile(cli: &Cli) -> i32 {
 
     let diagnostics = program.compile();
 
-    // Print diagnostics with color
+    // Print diagnostics with color; suggestions are for editors only
     let use_color = cli.pretty && atty_is_terminal();
     for diag in diagnostics.diagnostics() {
-        print_diagnostic(diag, use_color);
+        if diag.category != rscript_diagnostics::DiagnosticCategory::Suggestion {
+            print_diagnostic(diag, use_color);
+        }
     }
 
     let elapsed = start.elapsed();
@@ -442,7 +444,7 @@ fn print_diagnostic(diag: &rscript_diagnostics::Diagnostic, use_color: bool) {
         }
         eprintln!(
             "{BOLD}{color}{category}{RESET} {CYAN}TS{}{RESET}: {}",
-            diag.code, diag.message_text
+            diag.code, diag.flatten_message_text()
         );
     } else {
         eprintln!("{}", diag);
@@ -4,13 +4,20 @@
 //! and emit across all source files.
 
 use bumpalo::Bump;
-use rscript_binder::Binder;
-use rscript_checker::Checker;
+use rscript_ast::node::{CommentDirectiveType, Expression, LanguageVariant, ModuleName, ScriptKind, SourceFile, Statement};
+use rscript_ast::types::{NodeFlags, SymbolFlags};
+use rscript_binder::{get_excluded_flags, Binder};
+use rscript_checker::{Checker, FileCheckResult};
 use rscript_core::intern::StringInterner;
-use rscript_diagnostics::DiagnosticCollection;
+use rscript_core::text::{TextRange, TextSpan};
+use rscript_diagnostics::{messages, Diagnostic, DiagnosticCollection};
+use std::cell::OnceCell;
+use std::collections::HashMap;
 use rscript_emitter::{Emitter, EmitResult};
+use rscript_module::{match_pattern_or_exact, resolve_module_name, resolve_program_file, ModuleResolutionKind, ModuleResolutionOptions};
 use rscript_parser::Parser;
-use rscript_tsoptions::CompilerOptions;
+use rscript_tsoptions::{CompilerOptions, JsxEmit};
+use rscript_tspath::get_directory_path;
 
 /// The program represents the entire compilation unit.
 pub struct Program<'a> {
@@ -24,6 +31,16 @@ pub struct Program<'a> {
     interner: StringInterner,
     /// Parsed source files (stored as raw text + file name for now).
     source_files: Vec<(String, String)>,
+    /// The files parsed and checked by the first `compile` or `emit`,
+    /// shared by both until a source is added.
+    checked: OnceCell<CheckedProgram<'a>>,
+}
+
+/// The parsed files of a program, with what checking them found.
+struct CheckedProgram<'a> {
+    source_files: Vec<SourceFile<'a>>,
+    results: Vec<FileCheckResult>,
+    diagnostics: DiagnosticCollection,
 }
 
 impl<'a> Program<'a> {
@@ -35,12 +52,14 @@ impl<'a> Program<'a> {
             arena,
             interner: StringInterner::new(),
             source_files: Vec::new(),
+            checked: OnceCell::new(),
         }
     }
 
     /// Add a source file to the program.
     pub fn add_source(&mut self, file_name: String, source_text: String) {
         self.source_files.push((file_name, source_text));
+        self.checked.take();
     }
 
     /// Load all root files from disk.
@@ -49,47 +68,376 @@ impl<'a> Program<'a> {
             let content = std::fs::read_to_string(file)?;
             self.source_files.push((file.clone(), content));
         }
+        self.checked.take();
         Ok(())
     }
 
     /// Run the full compilation pipeline: parse -> bind -> check.
     /// Returns all diagnostics.
+    ///
+    /// Top-level declarations of script files (files without imports or
+    /// exports) are globals: every file sees them, and declarations of the
+    /// same name in different scripts merge or conflict. Ambient module
+    /// declarations and global augmentations of every file are visible to
+    /// all files.
     pub fn compile(&self) -> DiagnosticCollection {
-        let mut all_diagnostics = DiagnosticCollection::new();
+        self.check().diagnostics.clone()
+    }
+
+    /// Parse, bind and check the program once, keeping the result for
+    /// `compile` and `emit`.
+    fn check(&self) -> &CheckedProgram<'a> {
+        self.checked.get_or_init(|| {
+            let mut all_diagnostics = DiagnosticCollection::new();
 
-        for (file_name, source_text) in &self.source_files {
             // Parse
-            let parser = Parser::new(self.arena, file_name, source_text);
-            let source_file = parser.parse_source_file();
+            let source_files: Vec<_> = self.source_files.iter()
+                .map(|(file_name, source_text)| Parser::new(self.arena, file_name, source_text).parse_source_file())
+                .collect();
 
             // Bind
-            let mut binder = Binder::new();
-            binder.bind_source_file(&source_file);
+            let mut binders: Vec<Binder> = Vec::with_capacity(source_files.len());
+            let mut script_globals: Vec<ScriptGlobals> = Vec::new();
+            for source_file in &source_files {
+                let mut binder = Binder::new();
+                binder.bind_source_file(source_file);
+                if !source_file.is_external_module() {
+                    script_globals.push(ScriptGlobals {
+                        file_name: source_file.file_name.clone(),
+                        declarations: binder.top_level_symbols().iter()
+                            .map(|symbol| (symbol.name_text.clone(), symbol.flags, symbol.declaration_ranges.clone()))
+                            .collect(),
+                    });
+                }
+                binders.push(binder);
+            }
+            let global_conflicts = check_global_conflicts(&script_globals).into_diagnostics();
+
+            let ambient_module_names: Vec<&str> = source_files.iter()
+                .flat_map(|source_file| source_file.statements.iter())
+                .filter_map(|statement| match statement {
+                    Statement::ModuleDeclaration(n) => match &n.name {
+                        ModuleName::StringLiteral(name) if !n.data.flags.contains(NodeFlags::GLOBAL_AUGMENTATION) => Some(name.text_name.as_str()),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect();
 
             // Check
-            let mut checker = Checker::new(binder);
-            checker.check_source_file(&source_file);
+            let mut checker = self.create_global_checker(&source_files, &mut binders);
+            let mut binders: Vec<Option<Binder>> = binders.into_iter().map(Some).collect();
+            let mut results: Vec<Option<FileCheckResult>> = source_files.iter().map(|_| None).collect();
+            for index in self.get_check_order(&source_files) {
+                let source_file = &source_files[index];
+                let Some(mut binder) = binders[index].take() else { continue };
+                let mut file_diagnostics = binder.take_diagnostics();
+                for conflict in global_conflicts.iter().filter(|d| d.file.as_deref() == Some(source_file.file_name.as_str())) {
+                    file_diagnostics.add(conflict.clone());
+                }
+                file_diagnostics.extend(self.check_module_specifiers(source_file, &ambient_module_names));
+
+                let mut result = checker.check_program_file(source_file, binder);
+                file_diagnostics.extend(std::mem::take(&mut result.diagnostics));
+                all_diagnostics.extend(get_bind_and_check_diagnostics(source_file, &self.options, file_diagnostics));
+                results[index] = Some(result);
+            }
+            let results = results.into_iter().flatten().collect();
+
+            if let Some(diagnostic) = self.check_jsx_emit(&source_files) {
+                all_diagnostics.add(diagnostic);
+            }
+
+            all_diagnostics.sort();
+            CheckedProgram { source_files, results, diagnostics: all_diagnostics }
+        })
+    }
+
+    /// A checker in which the globals of every file, bound by `binders`, are
+    /// declared. Every file is checked in it, sharing its globals and types.
+    fn create_global_checker(&self, source_files: &[SourceFile<'_>], binders: &mut [Binder]) -> Checker {
+        let mut checker = Checker::with_compiler_options(Binder::new(), &self.options);
+        for (source_file, binder) in source_files.iter().zip(binders.iter_mut()) {
+            checker.add_global_declarations(source_file, binder);
+        }
+        checker
+    }
+
+    /// The indices of the files in the order they are checked: each module
+    /// file after the module files it imports from, so that its imports are
+    /// bound to what they export, except around import cycles.
+    fn get_check_order(&self, source_files: &[SourceFile<'_>]) -> Vec<usize> {
+        let indices: HashMap<&str, usize> = source_files.iter().enumerate()
+            .map(|(index, source_file)| (source_file.file_name.as_str(), index))
+            .collect();
+        let dependencies: Vec<Vec<usize>> = source_files.iter()
+            .map(|source_file| {
+                source_file.statements.iter()
+                    .filter_map(|statement| match statement {
+                        Statement::ImportDeclaration(n) => Some(n.module_specifier),
+                        Statement::ExportDeclaration(n) => n.module_specifier,
+                        _ => None,
+                    })
+                    .filter_map(|specifier| match specifier {
+                        Expression::StringLiteral(specifier) => Some(specifier.text_name.as_str()),
+                        _ => None,
+                    })
+                    .filter_map(|module_name| {
+                        let program_files = source_files.iter().map(|source_file| source_file.file_name.as_str());
+                        resolve_program_file(module_name, &source_file.file_name, program_files)
+                    })
+                    .filter_map(|file_name| indices.get(file_name).copied())
+                    .collect()
+            })
+            .collect();
 
-            let diags = checker.take_diagnostics();
-            all_diagnostics.extend(diags);
+        // A depth-first walk of the imports, listing each file after those it imports.
+        let mut order = Vec::with_capacity(source_files.len());
+        let mut visited = vec![false; source_files.len()];
+        for root in 0..source_files.len() {
+            if std::mem::replace(&mut visited[root], true) {
+                continue;
+            }
+            let mut stack = vec![(root, 0)];
+            while let Some((index, next)) = stack.last_mut() {
+                match dependencies[*index].get(*next) {
+                    Some(&dependency) => {
+                        *next += 1;
+                        if !std::mem::replace(&mut visited[dependency], true) {
+                            stack.push((dependency, 0));
+                        }
+                    }
+                    None => {
+                        order.push(*index);
+                        stack.pop();
+                    }
+                }
+            }
         }
+        order
+    }
 
-        all_diagnostics.sort();
-        all_diagnostics
+    /// Report a `jsx` mode whose transform is not implemented yet when a
+    /// `.tsx` or `.jsx` file would be emitted with it. Only `preserve` and
+    /// `react-native`, which write JSX as is, are emitted.
+    fn check_jsx_emit(&self, source_files: &[SourceFile<'_>]) -> Option<Diagnostic> {
+        let jsx = self.options.jsx?;
+        if self.options.no_emit == Some(true)
+            || !matches!(jsx, JsxEmit::React | JsxEmit::ReactJSX | JsxEmit::ReactJSXDev)
+            || !source_files.iter().any(|source_file| source_file.language_variant == LanguageVariant::JSX)
+        {
+            return None;
+        }
+        Some(Diagnostic::new(&messages::ARGUMENT_FOR_0_OPTION_MUST_BE_COLON_1, &["--jsx", "'preserve', 'react-native'"]))
     }
 
-    /// Emit output files for all source files.
+    /// Report the module specifiers of a file's imports and re-exports that
+    /// name no file of the program, no ambient module, and nothing module
+    /// resolution finds on disk. Side-effect imports are not checked.
+    fn check_module_specifiers(&self, source_file: &SourceFile<'_>, ambient_module_names: &[&str]) -> DiagnosticCollection {
+        let mut diagnostics = DiagnosticCollection::new();
+        for statement in source_file.statements.iter() {
+            let specifier = match statement {
+                Statement::ImportDeclaration(n) if n.import_clause.is_some() => Some(n.module_specifier),
+                Statement::ExportDeclaration(n) => n.module_specifier,
+                _ => None,
+            };
+            let Some(Expression::StringLiteral(specifier)) = specifier else { continue };
+            let module_name = specifier.text_name.as_str();
+            let program_files = self.source_files.iter().map(|(file_name, _)| file_name.as_str());
+            if match_pattern_or_exact(ambient_module_names.iter().copied(), module_name).is_some()
+                || resolve_program_file(module_name, &source_file.file_name, program_files).is_some()
+                || resolve_module_name(module_name, &source_file.file_name, &self.module_resolution_options(&source_file.file_name)).is_some()
+            {
+                continue;
+            }
+            let span = TextSpan::from_bounds(specifier.data.range.pos, specifier.data.range.end);
+            diagnostics.add(Diagnostic::with_location(source_file.file_name.clone(), span, &messages::CANNOT_FIND_MODULE_0, &[module_name]));
+        }
+        diagnostics
+    }
+
+    /// Module resolution options for imports of `containing_file`.
+    fn module_resolution_options(&self, containing_file: &str) -> ModuleResolutionOptions {
+        let kind = match self.options.module_resolution.as_deref().map(str::to_ascii_lowercase).as_deref() {
+            Some("classic") => ModuleResolutionKind::Classic,
+            Some("node16") => ModuleResolutionKind::Node16,
+            Some("nodenext") => ModuleResolutionKind::NodeNext,
+            Some("bundler") => ModuleResolutionKind::Bundler,
+            _ => ModuleResolutionKind::Node10,
+        };
+        ModuleResolutionOptions {
+            kind,
+            root_dir: get_directory_path(containing_file),
+            base_url: self.options.base_url.clone(),
+            paths: self.options.paths.iter().flatten()
+                .map(|(pattern, substitutions)| (pattern.clone(), substitutions.clone()))
+                .collect(),
+            root_dirs: self.options.root_dirs.clone().unwrap_or_default(),
+            type_roots: self.options.type_roots.clone(),
+            node_modules_search_dirs: Vec::new(),
+        }
+    }
+
+    /// Emit output files for all source files. The program is checked first,
+    /// so that imports never read as values are elided from each file's
+    /// JavaScript.
     pub fn emit(&self) -> Vec<EmitResult> {
-        let emitter = Emitter::new();
-        let mut results = Vec::new();
-
-        for (file_name, source_text) in &self.source_files {
-            let parser = Parser::new(self.arena, file_name, source_text);
-            let source_file = parser.parse_source_file();
-            let result = emitter.emit(&source_file, &self.interner);
-            results.push(result);
+        let mut emitter = Emitter::new();
+        emitter.always_strict = self.options.get_strict_option_value(self.options.always_strict);
+        emitter.verbatim_module_syntax = self.options.get_verbatim_module_syntax();
+        emitter.preserve_jsx = self.options.jsx == Some(JsxEmit::Preserve);
+
+        let checked = self.check();
+        checked.source_files.iter().zip(&checked.results)
+            .map(|(source_file, result)| {
+                emitter.emit_with_elided_imports(
+                    source_file,
+                    &self.interner,
+                    result.elided_import_names.clone(),
+                    result.elided_export_names.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Codes of the binder and checker errors reported in JavaScript files that
+/// are not type checked.
+const PLAIN_JS_ERRORS: &[u32] = &[2300, 2451];
+
+/// The binder and checker diagnostics of a file that are reported: none
+/// under `@ts-nocheck`, only [`PLAIN_JS_ERRORS`] for JavaScript that is
+/// neither `@ts-check`ed nor under `checkJs`, and none suppressed by a
+/// `@ts-ignore` or `@ts-expect-error` comment. Each `@ts-expect-error` that
+/// suppresses nothing is an error of its own.
+pub fn get_bind_and_check_diagnostics(
+    source_file: &SourceFile<'_>,
+    options: &CompilerOptions,
+    diagnostics: DiagnosticCollection,
+) -> DiagnosticCollection {
+    if source_file.check_js_directive.is_some_and(|directive| !directive.enabled) {
+        return DiagnosticCollection::new();
+    }
+    let mut diagnostics = diagnostics.into_diagnostics();
+    if matches!(source_file.script_kind, ScriptKind::JS | ScriptKind::JSX) {
+        match source_file.check_js_directive.map(|directive| directive.enabled).or(options.check_js) {
+            Some(true) => {}
+            Some(false) => return DiagnosticCollection::new(),
+            None => diagnostics.retain(|diagnostic| PLAIN_JS_ERRORS.contains(&diagnostic.code)),
+        }
+    }
+    get_diagnostics_with_preceding_directives(source_file, diagnostics)
+}
+
+/// Drop the diagnostics that start on a line preceded by a comment directive,
+/// allowing blank and `//` comment lines between the two, and report the
+/// `@ts-expect-error` directives that suppressed nothing.
+fn get_diagnostics_with_preceding_directives(source_file: &SourceFile<'_>, diagnostics: Vec<Diagnostic>) -> DiagnosticCollection {
+    let mut result = DiagnosticCollection::new();
+    if source_file.comment_directives.is_empty() {
+        result.extend_from_slice(&diagnostics);
+        return result;
+    }
+    let lines: Vec<String> = source_file.text.split('\n').map(str::to_string).collect();
+    let mut line_starts = Vec::with_capacity(lines.len());
+    let mut line_start = 0u32;
+    for line in &lines {
+        line_starts.push(line_start);
+        line_start += line.chars().count() as u32 + 1;
+    }
+    let line_of = |pos: u32| line_starts.partition_point(|&start| start <= pos) - 1;
+    let mut directives_by_line: HashMap<usize, (usize, bool)> = source_file.comment_directives.iter()
+        .enumerate()
+        .map(|(index, directive)| (line_of(directive.range.pos), (index, false)))
+        .collect();
+
+    for diagnostic in diagnostics {
+        let Some(span) = diagnostic.span else {
+            result.add(diagnostic);
+            continue;
+        };
+        let mut suppressed = false;
+        let mut line = line_of(span.start);
+        while line > 0 {
+            line -= 1;
+            if let Some((_, used)) = directives_by_line.get_mut(&line) {
+                *used = true;
+                suppressed = true;
+                break;
+            }
+            let line_text = lines[line].trim();
+            if !line_text.is_empty() && !line_text.starts_with("//") {
+                break;
+            }
+        }
+        if !suppressed {
+            result.add(diagnostic);
         }
+    }
+
+    let mut unused: Vec<usize> = directives_by_line.into_values()
+        .filter(|&(index, used)| !used && source_file.comment_directives[index].kind == CommentDirectiveType::ExpectError)
+        .map(|(index, _)| index)
+        .collect();
+    unused.sort();
+    for index in unused {
+        let range = source_file.comment_directives[index].range;
+        result.add(Diagnostic::with_location(
+            source_file.file_name.clone(),
+            TextSpan::from_bounds(range.pos, range.end),
+            &messages::UNUSED_TS_EXPECT_ERROR_DIRECTIVE,
+            &[],
+        ));
+    }
+    result
+}
 
-        results
+/// The top-level declarations of a script file, which are globals: their
+/// names, symbol flags and the ranges of the names of their declarations.
+struct ScriptGlobals {
+    file_name: String,
+    declarations: Vec<(String, SymbolFlags, Vec<TextRange>)>,
+}
+
+/// A global name and the declarations of it seen so far.
+struct GlobalDeclarations<'g> {
+    flags: SymbolFlags,
+    declarations: Vec<(&'g str, TextRange)>,
+    reported: bool,
+}
+
+/// Report global declarations of different script files that cannot merge,
+/// under the same rules the binder applies within a file, at the name of
+/// each declaration involved. The first conflict on a name reports the
+/// earlier declarations too.
+fn check_global_conflicts(script_globals: &[ScriptGlobals]) -> DiagnosticCollection {
+    let mut diagnostics = DiagnosticCollection::new();
+    let mut globals: HashMap<&str, GlobalDeclarations<'_>> = HashMap::new();
+    for script in script_globals {
+        for (name, flags, ranges) in &script.declarations {
+            let declarations = ranges.iter().map(|&range| (script.file_name.as_str(), range));
+            let Some(existing) = globals.get_mut(name.as_str()) else {
+                globals.insert(name, GlobalDeclarations { flags: *flags, declarations: declarations.collect(), reported: false });
+                continue;
+            };
+            if !existing.flags.intersects(get_excluded_flags(*flags)) {
+                existing.flags |= *flags;
+                existing.declarations.extend(declarations);
+                continue;
+            }
+            let message = if (existing.flags | *flags).contains(SymbolFlags::BLOCK_SCOPED_VARIABLE) {
+                &messages::CANNOT_REDECLARE_BLOCK_SCOPED_VARIABLE_0
+            } else {
+                &messages::DUPLICATE_IDENTIFIER_0
+            };
+            let earlier = if existing.reported { &[][..] } else { &existing.declarations[..] };
+            for &(file_name, range) in earlier.iter().chain(&declarations.collect::<Vec<_>>()) {
+                let span = TextSpan::from_bounds(range.pos, range.end);
+                diagnostics.add(Diagnostic::with_location(file_name.to_string(), span, message, &[name]));
+            }
+            existing.reported = true;
+        }
     }
+    diagnostics
 }
@@ -1,12 +1,11 @@
 //! Compiler integration tests.
 //!
-//! End-to-end tests for the compilation pipeline: parse -> bind -> check.
-//! Note: emit tests are limited because the printer requires a shared
-//! StringInterner with the parser, which is an architectural improvement TODO.
+//! End-to-end tests for the compilation pipeline: parse -> bind -> check,
+//! and emit.
 
 use bumpalo::Bump;
 use rscript_compiler::Program;
-use rscript_tsoptions::CompilerOptions;
+use rscript_tsoptions::{CompilerOptions, JsxEmit};
 
 /// Helper: create a program, add a source, compile, and return diagnostic count.
 fn compile_source(source: &str) -> usize {
@@ -76,6 +75,258 @@ fn test_compile_multiple_files() {
     assert_eq!(diags.len(), 0);
 }
 
+// ============================================================================
+// Strict Options
+// ============================================================================
+
+#[test]
+fn test_compile_strict_enables_family() {
+    let source = "declare const o: any;\nfunction f() { this.x = 1; }\nwith (o) {}";
+    let arena = Bump::new();
+    let options = CompilerOptions { strict: Some(true), ..Default::default() };
+    let mut program = Program::new(&arena, vec![], options);
+    program.add_source("test.ts".to_string(), source.to_string());
+    let messages: Vec<String> = program.compile().diagnostics().iter().map(|d| d.message_text.clone()).collect();
+    assert_eq!(messages, vec![
+        "'this' implicitly has type 'any' because it does not have a type annotation.",
+        "'with' statements are not allowed in strict mode.",
+    ]);
+
+    let options = CompilerOptions { strict: Some(true), no_implicit_this: Some(false), always_strict: Some(false), ..Default::default() };
+    let mut program = Program::new(&arena, vec![], options);
+    program.add_source("test.ts".to_string(), source.to_string());
+    assert_eq!(program.compile().len(), 0);
+}
+
+#[test]
+fn test_emit_always_strict_prologue() {
+    let arena = Bump::new();
+    let options = CompilerOptions { always_strict: Some(true), ..Default::default() };
+    let mut program = Program::new(&arena, vec![], options);
+    program.add_source("script.ts".to_string(), "debugger;".to_string());
+    program.add_source("module.ts".to_string(), "export {};".to_string());
+    let results = program.emit();
+    assert!(results[0].js_content.starts_with("\"use strict\";\n"));
+    assert!(!results[1].js_content.contains("use strict"));
+}
+
+#[test]
+fn test_emit_preserves_jsx() {
+    let source = [
+        "function Item(props: { label: string }): JSX.Element { return <li>{props.label}</li>; }",
+        "export const list = <ul className=\"items\" {...{}}>",
+        "  <Item label={\"a\" as string} />",
+        "  <>text</>",
+        "</ul>;",
+    ].join("\n");
+    let arena = Bump::new();
+    let options = CompilerOptions { jsx: Some(JsxEmit::Preserve), ..Default::default() };
+    let mut program = Program::new(&arena, vec![], options);
+    program.add_source("src/jsx.d.ts".to_string(), "declare namespace JSX { interface Element {} interface IntrinsicElements { [name: string]: any } }".to_string());
+    program.add_source("src/list.tsx".to_string(), source);
+    assert_eq!(program.compile().len(), 0);
+    let result = program.emit().remove(1);
+    assert_eq!(result.output_files[0].path, std::path::PathBuf::from("src/list.jsx"));
+    assert_eq!(result.js_content, [
+        "function Item(props) {",
+        "    return <li>{props.label}</li>;",
+        "}",
+        "export const list = <ul className=\"items\" {...{}}>",
+        "  <Item label={\"a\"} />",
+        "  <>text</>",
+        "</ul>;",
+        "",
+    ].join("\n"));
+}
+
+#[test]
+fn test_compile_reports_unimplemented_jsx_emit() {
+    let jsx_namespace = "declare namespace JSX { interface Element {} interface IntrinsicElements { [name: string]: any } }";
+    let source = "export const id = <T,>(value: T) => value;\nexport const a = <div>{id(1)}</div>;";
+    let compile = |jsx: JsxEmit, no_emit: Option<bool>| {
+        let arena = Bump::new();
+        let options = CompilerOptions { jsx: Some(jsx), no_emit, ..Default::default() };
+        let mut program = Program::new(&arena, vec![], options);
+        program.add_source("jsx.d.ts".to_string(), jsx_namespace.to_string());
+        program.add_source("a.tsx".to_string(), source.to_string());
+        program.compile().diagnostics().iter()
+            .map(|d| format!("{}: {}", d.code, d.flatten_message_text()))
+            .collect::<Vec<_>>()
+    };
+    for jsx in [JsxEmit::React, JsxEmit::ReactJSX, JsxEmit::ReactJSXDev] {
+        assert_eq!(compile(jsx, None), vec!["6046: Argument for '--jsx' option must be: 'preserve', 'react-native'."]);
+        assert!(compile(jsx, Some(true)).is_empty());
+    }
+    assert!(compile(JsxEmit::Preserve, None).is_empty());
+    assert!(compile(JsxEmit::ReactNative, None).is_empty());
+}
+
+#[test]
+fn test_emit_elides_or_preserves_type_only_imports() {
+    let source = [
+        "import { type A, B } from \"./ab\";",
+        "import { type C } from \"./c\";",
+        "import type { D } from \"./d\";",
+        "import E, { type F } from \"./ef\";",
+        "export { B, E };",
+        "export type { C };",
+    ].join("\n");
+    let emit = |verbatim_module_syntax: bool| {
+        let arena = Bump::new();
+        let options = CompilerOptions { verbatim_module_syntax: Some(verbatim_module_syntax), ..Default::default() };
+        let mut program = Program::new(&arena, vec![], options);
+        program.add_source("test.ts".to_string(), source.clone());
+        program.emit().remove(0).js_content
+    };
+    assert_eq!(emit(false), [
+        "import { B } from \"./ab\";",
+        "import E from \"./ef\";",
+        "export { B, E };",
+        "",
+    ].join("\n"));
+    assert_eq!(emit(true), [
+        "import { B } from \"./ab\";",
+        "import {} from \"./c\";",
+        "import E from \"./ef\";",
+        "export { B, E };",
+        "",
+    ].join("\n"));
+}
+
+#[test]
+fn test_emit_elides_imports_not_used_as_values() {
+    let source = [
+        "declare module \"shapes\" { interface Shape { sides: number } const unit: number; }",
+        "import { Shape, unit } from \"shapes\";",
+        "import { Widget, render } from \"./ui\";",
+        "import * as util from \"./util\";",
+        "import Base from \"./base\";",
+        "import \"./polyfill\";",
+        "export { Shape, type Widget };",
+        "export type * from \"./types\";",
+        "let w: Widget;",
+        "const size: typeof util = util;",
+        "class Impl implements Base {}",
+        "render(unit);",
+    ].join("\n");
+    let emit = |options: CompilerOptions| {
+        let arena = Bump::new();
+        let mut program = Program::new(&arena, vec![], options);
+        program.add_source("test.ts".to_string(), source.clone());
+        program.emit().remove(0).js_content
+    };
+    let js = emit(CompilerOptions::default());
+    let imports: Vec<&str> = js.lines().filter(|line| line.starts_with("import") || line.starts_with("export")).collect();
+    assert_eq!(imports, vec![
+        "import { unit } from \"shapes\";",
+        "import { render } from \"./ui\";",
+        "import * as util from \"./util\";",
+        "import \"./polyfill\";",
+    ]);
+
+    // The deprecated options that keep imports behave like verbatimModuleSyntax.
+    let js = emit(CompilerOptions { preserve_value_imports: Some(true), ..Default::default() });
+    assert!(js.contains("import Base from \"./base\";"), "{js}");
+    assert!(js.contains("import { Shape, unit } from \"shapes\";"), "{js}");
+    assert!(js.contains("export { Shape };"), "{js}");
+}
+
+#[test]
+fn test_compile_type_only_import_and_export_checks_across_files() {
+    let arena = Bump::new();
+    let options = CompilerOptions { verbatim_module_syntax: Some(true), ..Default::default() };
+    let mut program = Program::new(&arena, vec![], options);
+    program.add_source("src/types.ts".to_string(), "export interface Shape { sides: number }\nexport const unit = 1;".to_string());
+    program.add_source("src/index.ts".to_string(), "export * from \"./types\";".to_string());
+    program.add_source("src/app.ts".to_string(), [
+        "import { Shape, unit } from \"./index\";",
+        "import { type Shape as S } from \"./types\";",
+        "export { Shape as Figure, unit as one } from \"./types\";",
+        "export type { Shape as Outline } from \"./types\";",
+        "let s: Shape | S = { sides: unit };",
+    ].join("\n"));
+    let diagnostics: Vec<(String, String)> = program.compile().into_diagnostics().into_iter()
+        .filter(|d| d.is_error())
+        .map(|d| (d.file.unwrap_or_default(), d.message_text))
+        .collect();
+    assert_eq!(diagnostics, vec![
+        ("src/app.ts".to_string(), "'Shape' is a type and must be imported using a type-only import when 'verbatimModuleSyntax' is enabled.".to_string()),
+        ("src/app.ts".to_string(), "Re-exporting a type when 'verbatimModuleSyntax' is enabled requires using 'export type'.".to_string()),
+    ]);
+}
+
+#[test]
+fn test_compile_binds_imports_from_program_files() {
+    let arena = Bump::new();
+    let options = CompilerOptions { isolated_modules: Some(true), ..Default::default() };
+    let mut program = Program::new(&arena, vec![], options);
+    // The importing file comes first; it is checked after the files it imports.
+    program.add_source("src/app.ts".to_string(), [
+        "import { CE, E, add as plus, Point, Box, Shape } from \"./index\";",
+        "import greet from \"./types\";",
+        "const a = CE.A;",
+        "const e: E = E.B;",
+        "const n: string = plus(1, 2);",
+        "const p: Point = { x: 1, y: \"2\" };",
+        "const b: Box<number> = { value: \"s\" };",
+        "const area: string = new Shape().area;",
+        "const g: number = greet();",
+    ].join("\n"));
+    program.add_source("src/index.ts".to_string(), "export * from \"./types\";".to_string());
+    program.add_source("src/types.ts".to_string(), [
+        "export declare const enum CE { A, B }",
+        "export enum E { A, B }",
+        "export function add(a: number, b: number): number { return a + b; }",
+        "export interface Point { x: number; y: number }",
+        "export type Box<T> = { value: T };",
+        "export class Shape { area = 1; }",
+        "export default function greet(): string { return \"hi\"; }",
+    ].join("\n"));
+    let diagnostics: Vec<String> = program.compile().into_diagnostics().into_iter()
+        .filter(|d| d.is_error())
+        .map(|d| format!("{}@{}: {}", d.code, d.span.map_or(0, |span| span.start), d.message_text))
+        .collect();
+    assert_eq!(diagnostics, vec![
+        "2748@104: Cannot access ambient const enums when 'isolatedModules' is enabled.",
+        "2322@134: Type 'number' is not assignable to type 'string'.",
+        "2322@183: Type 'string' is not assignable to type 'number'.",
+        "2322@218: Type 'string' is not assignable to type 'number'.",
+        "2322@238: Type 'number' is not assignable to type 'string'.",
+        "2322@277: Type 'string' is not assignable to type 'number'.",
+    ]);
+}
+
+#[test]
+fn test_compile_reports_type_imports_at_their_specifiers() {
+    let arena = Bump::new();
+    let options = CompilerOptions { verbatim_module_syntax: Some(true), ..Default::default() };
+    let mut program = Program::new(&arena, vec![], options);
+    program.add_source("types.ts".to_string(), "export interface Shape { sides: number }\nexport const unit = 1;".to_string());
+    program.add_source("app.ts".to_string(), "import { unit, Shape as S } from \"./types\";\nlet s: S = { sides: unit };".to_string());
+    let spans: Vec<(u32, u32)> = program.compile().into_diagnostics().into_iter()
+        .filter(|d| d.code == 1484)
+        .filter_map(|d| d.span.map(|span| (span.start, span.length)))
+        .collect();
+    assert_eq!(spans, vec![(15, 10)]);
+}
+
+#[test]
+fn test_emit_elides_re_exports_of_types_from_program_files() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("src/types.ts".to_string(), "export interface T { a: number }\nexport type Id = string;\nexport const version = 1;".to_string());
+    program.add_source("src/barrel.ts".to_string(), "export * from \"./types\";".to_string());
+    program.add_source("src/index.ts".to_string(), [
+        "export { T } from \"./types\";",
+        "export { Id as Key, version } from \"./types\";",
+        "export { T as Shape } from \"./barrel\";",
+        "interface Local { b: string }",
+        "export { Local };",
+    ].join("\n"));
+    let js = program.emit().remove(2).js_content;
+    assert_eq!(js, "export { version } from \"./types\";\n");
+}
+
 // ============================================================================
 // Fixture File Compilation
 // ============================================================================
@@ -170,3 +421,217 @@ fn test_compile_many_variables() {
     }
     let _count = compile_source(&source);
 }
+
+#[test]
+fn test_compile_script_globals_merge_across_files() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), "interface Config { name: string }\nnamespace Util { export const x = 1; }".to_string());
+    program.add_source("b.ts".to_string(), "interface Config { port: number }\nconst c: Config = { name: 'a', port: 1 };\nconst n: number = Util.x;".to_string());
+    assert_eq!(program.compile().len(), 0);
+}
+
+#[test]
+fn test_compile_global_interfaces_merge_once() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), "interface Parser { parse(x: number): string }\ndeclare const p: Parser;\np.parse(true);".to_string());
+    program.add_source("b.ts".to_string(), "interface Parser { parse(x: string): string }".to_string());
+    let diagnostics = program.compile().into_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].flatten_message_text(), [
+        "No overload matches this call.",
+        "  Overload 1 of 2, '(x: string): string', gave the following error.",
+        "    Argument of type 'boolean' is not assignable to parameter of type 'string'.",
+        "  Overload 2 of 2, '(x: number): string', gave the following error.",
+        "    Argument of type 'boolean' is not assignable to parameter of type 'number'.",
+    ].join("\n"));
+}
+
+#[test]
+fn test_compile_global_overloads_declared_once() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), "declare function one(a: number): void;\none(\"x\");".to_string());
+    program.add_source("b.ts".to_string(), "declare function two(a: number): void;\ndeclare function two(a: boolean): void;".to_string());
+    program.add_source("c.ts".to_string(), "two(\"x\");".to_string());
+    let messages: Vec<(String, String)> = program.compile().into_diagnostics().into_iter()
+        .map(|d| (d.file.clone().unwrap_or_default(), d.flatten_message_text()))
+        .collect();
+    assert_eq!(messages, vec![
+        ("a.ts".to_string(), "Argument of type 'string' is not assignable to parameter of type 'number'.".to_string()),
+        ("c.ts".to_string(), [
+            "No overload matches this call.",
+            "  Overload 1 of 2, '(a: number): void', gave the following error.",
+            "    Argument of type 'string' is not assignable to parameter of type 'number'.",
+            "  Overload 2 of 2, '(a: boolean): void', gave the following error.",
+            "    Argument of type 'string' is not assignable to parameter of type 'boolean'.",
+        ].join("\n")),
+    ]);
+}
+
+#[test]
+fn test_compile_module_declarations_stay_in_their_file() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), "export {};\nconst local = 1;\ninterface Local { a: string }\ndeclare function f(a: number): void;".to_string());
+    program.add_source("b.ts".to_string(), "export {};\ninterface Local { b: number }\nconst l: Local = { b: local };\ndeclare function f(a: string): void;\nf(1);".to_string());
+    let messages: Vec<(String, String)> = program.compile().into_diagnostics().into_iter()
+        .filter(|d| d.is_error())
+        .map(|d| (d.file.unwrap_or_default(), d.message_text))
+        .collect();
+    assert_eq!(messages, vec![
+        ("b.ts".to_string(), "Cannot find name 'local'.".to_string()),
+        ("b.ts".to_string(), "Argument of type 'number' is not assignable to parameter of type 'string'.".to_string()),
+    ]);
+}
+
+#[test]
+fn test_compile_reports_excessive_instantiation_in_global_scripts() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), "type Loop<T> = T extends [] ? never : Loop<[T]>;\ndeclare let l: Loop<1>;\ndeclare let m: Loop<[[1]]>;".to_string());
+    let messages: Vec<(u32, Option<u32>)> = program.compile().into_diagnostics().into_iter()
+        .map(|d| (d.code, d.span.map(|span| span.start)))
+        .collect();
+    assert_eq!(messages, vec![(2589, Some(57)), (2589, Some(81))]);
+}
+
+#[test]
+fn test_compile_duplicate_globals_across_files() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), "let shared = 1;\nclass Thing {}".to_string());
+    program.add_source("b.ts".to_string(), "let shared = 2;\nclass Thing {}".to_string());
+    program.add_source("c.ts".to_string(), "export let shared = 3;".to_string());
+    program.add_source("d.ts".to_string(), "var shared = 4;".to_string());
+    let diagnostics: Vec<(String, u32, String)> = program.compile().into_diagnostics().into_iter()
+        .map(|d| (d.file.unwrap_or_default(), d.span.map_or(0, |span| span.start), d.message_text))
+        .collect();
+    let shared = "Cannot redeclare block-scoped variable 'shared'.".to_string();
+    let thing = "Duplicate identifier 'Thing'.".to_string();
+    assert_eq!(diagnostics, vec![
+        ("a.ts".to_string(), 4, shared.clone()),
+        ("a.ts".to_string(), 22, thing.clone()),
+        ("b.ts".to_string(), 4, shared.clone()),
+        ("b.ts".to_string(), 22, thing),
+        ("d.ts".to_string(), 4, shared),
+    ]);
+}
+
+#[test]
+fn test_compile_module_augmentation_across_files() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("types/express.d.ts".to_string(), "declare module \"express\" { interface Request { body: string } }\ndeclare module \"*.svg\" { const url: string; export default url; }".to_string());
+    program.add_source("src/augment.ts".to_string(), "export {};\ndeclare module \"express\" { interface Request { user: string } }\ndeclare global { interface Window { app: number } }".to_string());
+    program.add_source("src/app.ts".to_string(), "import { Request } from \"express\";\nimport icon from \"../assets/icon.svg\";\nimport { helper } from \"./util\";\nconst req: Request = { body: 'b', user: 'u' };\nconst src: string = icon;\ndeclare const w: Window;\nconst n: number = w.app;".to_string());
+    program.add_source("src/util.ts".to_string(), "export function helper() {}".to_string());
+    // Unused imports are editor suggestions
+    let messages: Vec<String> = program.compile().into_diagnostics().into_iter()
+        .filter(|d| d.is_error())
+        .map(|d| d.message_text)
+        .collect();
+    assert!(messages.is_empty(), "{messages:?}");
+}
+
+#[test]
+fn test_compile_reports_unresolved_modules() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), "import { b } from \"./b\";\nimport { c } from \"./c\";\nimport \"./styles.css\";\nexport * from \"not-installed-package\";".to_string());
+    program.add_source("b.ts".to_string(), "export const b = 1;".to_string());
+    // Unused imports are editor suggestions
+    let messages: Vec<String> = program.compile().into_diagnostics().into_iter()
+        .filter(|d| d.is_error())
+        .map(|d| d.message_text)
+        .collect();
+    assert_eq!(messages, vec![
+        "Cannot find module './c' or its corresponding type declarations.".to_string(),
+        "Cannot find module 'not-installed-package' or its corresponding type declarations.".to_string(),
+    ]);
+}
+
+#[test]
+fn test_compile_honors_comment_directives() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), [
+        "// @ts-ignore",
+        "const a: number = 'a';",
+        "// @ts-expect-error: strings are not numbers",
+        "",
+        "// another comment",
+        "const b: number = 'b';",
+        "// @ts-expect-error",
+        "const c: number = 1;",
+        "/* @ts-ignore */",
+        "const d: string = 1;",
+        "const e: string = 2;",
+    ].join("\n"));
+    program.add_source("b.ts".to_string(), "// @ts-nocheck\nconst f: number = 'f';".to_string());
+    program.add_source("c.js".to_string(), "// @ts-check\nlet g = 1;\ng = 'g';".to_string());
+    program.add_source("d.js".to_string(), "let h = 1;\nh = 'h';".to_string());
+    let messages: Vec<(String, u32, String)> = program.compile().into_diagnostics().into_iter()
+        .map(|d| (d.file.unwrap_or_default(), d.code, d.message_text))
+        .collect();
+    assert_eq!(messages, vec![
+        ("a.ts".to_string(), 2578, "Unused '@ts-expect-error' directive.".to_string()),
+        ("a.ts".to_string(), 2322, "Type 'number' is not assignable to type 'string'.".to_string()),
+        ("c.js".to_string(), 2322, "Type 'string' is not assignable to type 'number'.".to_string()),
+    ]);
+}
+
+#[test]
+fn test_comment_directives_inside_object_literals() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), [
+        "const o: { a: number; b: number } = {",
+        "    // @ts-expect-error",
+        "    a: 'x',",
+        "    b: 1,",
+        "};",
+        "let p: { a: number; b: string } = { a: 1, b: 'b' };",
+        "p = {",
+        "    a: 1,",
+        "    // @ts-expect-error",
+        "    b: 2,",
+        "};",
+        "const q: { a: number } = {",
+        "    a: 1,",
+        "    // @ts-expect-error",
+        "    extra: true,",
+        "};",
+    ].join("\n"));
+    assert!(program.compile().into_diagnostics().is_empty());
+}
+
+#[test]
+fn test_comment_directives_suppress_unused_declarations() {
+    let arena = Bump::new();
+    let options = CompilerOptions { no_unused_locals: Some(true), ..Default::default() };
+    let mut program = Program::new(&arena, vec![], options);
+    program.add_source("a.ts".to_string(), [
+        "export function f() {",
+        "    // @ts-ignore",
+        "    const ignored = 1;",
+        "    const reported = 2;",
+        "}",
+    ].join("\n"));
+    let messages: Vec<(u32, String)> = program.compile().into_diagnostics().into_iter()
+        .map(|d| (d.code, d.message_text))
+        .collect();
+    assert_eq!(messages, vec![(6133, "'reported' is declared but its value is never read.".to_string())]);
+}
+
+#[test]
+fn test_comment_directives_suppress_conflicting_declarations() {
+    let arena = Bump::new();
+    let mut program = Program::new(&arena, vec![], CompilerOptions::default());
+    program.add_source("a.ts".to_string(), "export {};\nlet x = 1;\n// @ts-ignore\nlet x = 2;".to_string());
+    let messages: Vec<(u32, Option<u32>, String)> = program.compile().into_diagnostics().into_iter()
+        .map(|d| (d.code, d.span.map(|span| span.start), d.message_text))
+        .collect();
+    assert_eq!(messages, vec![(2451, Some(15), "Cannot redeclare block-scoped variable 'x'.".to_string())]);
+}
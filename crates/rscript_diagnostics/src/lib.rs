@@ -54,6 +54,44 @@ pub struct Diagnostic {
     pub category: DiagnosticCategory,
     /// Related diagnostics.
     pub related_information: Vec<Diagnostic>,
+    /// Messages elaborating on `message_text`, rendered indented below it.
+    pub message_chain: Vec<DiagnosticMessageChain>,
+}
+
+/// One message of an elaboration, with the messages that elaborate on it in
+/// turn. This corresponds to TypeScript's `DiagnosticMessageChain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticMessageChain {
+    pub message_text: String,
+    pub code: u32,
+    pub category: DiagnosticCategory,
+    pub next: Vec<DiagnosticMessageChain>,
+}
+
+impl DiagnosticMessageChain {
+    pub fn new(message: &DiagnosticMessage, args: &[&str]) -> Self {
+        Self {
+            message_text: format_message(message.message, args),
+            code: message.code,
+            category: message.category,
+            next: Vec::new(),
+        }
+    }
+
+    /// Set the messages elaborating on this one.
+    pub fn with_next(mut self, next: Vec<DiagnosticMessageChain>) -> Self {
+        self.next = next;
+        self
+    }
+
+    fn flatten_into(&self, text: &mut String, indent: usize) {
+        text.push('\n');
+        text.push_str(&"  ".repeat(indent));
+        text.push_str(&self.message_text);
+        for next in &self.next {
+            next.flatten_into(text, indent + 1);
+        }
+    }
 }
 
 impl Diagnostic {
@@ -66,6 +104,7 @@ impl Diagnostic {
             code: message.code,
             category: message.category,
             related_information: Vec::new(),
+            message_chain: Vec::new(),
         }
     }
 
@@ -83,6 +122,35 @@ impl Diagnostic {
             code: message.code,
             category: message.category,
             related_information: Vec::new(),
+            message_chain: Vec::new(),
+        }
+    }
+
+    /// Set the messages elaborating on this diagnostic's message.
+    pub fn with_message_chain(mut self, message_chain: Vec<DiagnosticMessageChain>) -> Self {
+        self.message_chain = message_chain;
+        self
+    }
+
+    /// The message followed by its elaborations, each on its own line and
+    /// indented two spaces per level, like TypeScript's
+    /// `flattenDiagnosticMessageText`.
+    pub fn flatten_message_text(&self) -> String {
+        let mut text = self.message_text.clone();
+        for chain in &self.message_chain {
+            chain.flatten_into(&mut text, 1);
+        }
+        text
+    }
+
+    /// This diagnostic as one message of another diagnostic's elaboration,
+    /// keeping its own elaboration below it.
+    pub fn into_message_chain(self) -> DiagnosticMessageChain {
+        DiagnosticMessageChain {
+            message_text: self.message_text,
+            code: self.code,
+            category: self.category,
+            next: self.message_chain,
         }
     }
 
@@ -110,7 +178,7 @@ impl fmt::Display for Diagnostic {
         write!(
             f,
             "{} TS{}: {}",
-            self.category, self.code, self.message_text
+            self.category, self.code, self.flatten_message_text()
         )
     }
 }
@@ -306,7 +374,9 @@ pub mod messages {
     pub const STRING_LITERAL_EXPECTED: DiagnosticMessage = diag!(1141, Error, "String literal expected.");
     pub const LINE_BREAK_NOT_PERMITTED_HERE: DiagnosticMessage = diag!(1142, Error, "Line break not permitted here.");
     pub const OR_EXPECTED: DiagnosticMessage = diag!(1144, Error, "'{' or ';' expected.");
-    pub const _0_IS_DECLARED_BUT_ITS_VALUE_IS_NEVER_READ: DiagnosticMessage = diag!(6133, Warning, "'{0}' is declared but its value is never read.");
+    pub const _0_IS_DECLARED_BUT_ITS_VALUE_IS_NEVER_READ: DiagnosticMessage = diag!(6133, Error, "'{0}' is declared but its value is never read.");
+    pub const ALL_IMPORTS_IN_IMPORT_DECLARATION_ARE_UNUSED: DiagnosticMessage = diag!(6192, Error, "All imports in import declaration are unused.");
+    pub const _0_IS_DECLARED_BUT_NEVER_USED: DiagnosticMessage = diag!(6196, Error, "'{0}' is declared but never used.");
     pub const UNTERMINATED_TEMPLATE_LITERAL: DiagnosticMessage = diag!(1160, Error, "Unterminated template literal.");
     pub const UNTERMINATED_REGULAR_EXPRESSION_LITERAL: DiagnosticMessage = diag!(1161, Error, "Unterminated regular expression literal.");
 
@@ -322,6 +392,7 @@ pub mod messages {
     pub const BINARY_DIGIT_EXPECTED: DiagnosticMessage = diag!(1177, Error, "Binary digit expected.");
     pub const OCTAL_DIGIT_EXPECTED: DiagnosticMessage = diag!(1178, Error, "Octal digit expected.");
     pub const AN_IMPLEMENTATION_CANNOT_BE_DECLARED_IN_AMBIENT_CONTEXTS: DiagnosticMessage = diag!(1183, Error, "An implementation cannot be declared in ambient contexts.");
+    pub const CATCH_CLAUSE_VARIABLE_TYPE_ANNOTATION_MUST_BE_ANY_OR_UNKNOWN_IF_SPECIFIED: DiagnosticMessage = diag!(1196, Error, "Catch clause variable type annotation must be 'any' or 'unknown' if specified.");
     pub const AN_EXTENDED_UNICODE_ESCAPE_VALUE_MUST_BE_BETWEEN_0X0_AND_0X10FFFF: DiagnosticMessage = diag!(1198, Error, "An extended Unicode escape value must be between 0x0 and 0x10FFFF inclusive.");
     pub const UNTERMINATED_UNICODE_ESCAPE_SEQUENCE: DiagnosticMessage = diag!(1199, Error, "Unterminated Unicode escape sequence.");
     pub const LINE_TERMINATOR_NOT_PERMITTED_BEFORE_ARROW: DiagnosticMessage = diag!(1200, Error, "Line terminator not permitted before arrow.");
@@ -329,6 +400,7 @@ pub mod messages {
     pub const DECORATORS_ARE_NOT_VALID_HERE: DiagnosticMessage = diag!(1206, Error, "Decorators are not valid here.");
     pub const DECORATORS_CANNOT_BE_APPLIED_TO_MULTIPLE_GET_SET_ACCESSORS_OF_THE_SAME_NAME: DiagnosticMessage = diag!(1207, Error, "Decorators cannot be applied to multiple get/set accessors of the same name.");
     pub const ALL_DECLARATIONS_OF_AN_ABSTRACT_METHOD_MUST_BE_CONSECUTIVE: DiagnosticMessage = diag!(1227, Error, "All declarations of an abstract method must be consecutive.");
+    pub const AN_INDEX_SIGNATURE_PARAMETER_TYPE_MUST_BE_STRING_NUMBER_SYMBOL_OR_A_TEMPLATE_LITERAL_TYPE: DiagnosticMessage = diag!(1268, Error, "An index signature parameter type must be 'string', 'number', 'symbol', or a template literal type.");
     pub const CANNOT_USE_IMPORTS_EXPORTS_OR_MODULE_AUGMENTATIONS_WHEN_MODULE_IS_NONE: DiagnosticMessage = diag!(1148, Error, "Cannot use imports, exports, or module augmentations when '--module' is 'none'.");
     pub const A_NAMESPACE_DECLARATION_CANNOT_BE_IN_A_DIFFERENT_FILE_FROM_A_CLASS_OR_FUNCTION_WITH_WHICH_IT_IS_MERGED: DiagnosticMessage = diag!(2433, Error, "A namespace declaration cannot be in a different file from a class or function with which it is merged.");
     pub const A_NAMESPACE_DECLARATION_CANNOT_BE_LOCATED_PRIOR_TO_A_CLASS_OR_FUNCTION_WITH_WHICH_IT_IS_MERGED: DiagnosticMessage = diag!(2434, Error, "A namespace declaration cannot be located prior to a class or function with which it is merged.");
@@ -353,6 +425,8 @@ pub mod messages {
     pub const A_CONTINUE_STATEMENT_CAN_ONLY_BE_USED_WITHIN_AN_ENCLOSING_ITERATION_STATEMENT: DiagnosticMessage = diag!(1104, Error, "A 'continue' statement can only be used within an enclosing iteration statement.");
     pub const A_BREAK_STATEMENT_CAN_ONLY_BE_USED_WITHIN_AN_ENCLOSING_ITERATION_OR_SWITCH_STATEMENT: DiagnosticMessage = diag!(1105, Error, "A 'break' statement can only be used within an enclosing iteration statement or a switch statement.");
     pub const THE_LEFT_HAND_SIDE_OF_A_FOR_OF_STATEMENT_CANNOT_USE_A_TYPE_ANNOTATION: DiagnosticMessage = diag!(1106, Error, "The left-hand side of a 'for...of' statement cannot use a type annotation.");
+    pub const MODULE_0_HAS_NO_DEFAULT_EXPORT: DiagnosticMessage = diag!(1192, Error, "Module '{0}' has no default export.");
+    pub const IDENTIFIER_EXPECTED_0_IS_A_RESERVED_WORD_IN_STRICT_MODE: DiagnosticMessage = diag!(1212, Error, "Identifier expected. '{0}' is a reserved word in strict mode.");
     pub const EXPORT_ASSIGNMENT_IS_NOT_SUPPORTED_WHEN_MODULE_FLAG_IS_SYSTEM: DiagnosticMessage = diag!(1218, Error, "Export assignment is not supported when '--module' flag is 'system'.");
 
     // ========================================================================
@@ -369,7 +443,9 @@ pub mod messages {
     pub const GENERIC_TYPE_0_REQUIRES_1_TYPE_ARGUMENT_S: DiagnosticMessage = diag!(2314, Error, "Generic type '{0}' requires {1} type argument(s).");
     pub const TYPE_0_IS_NOT_GENERIC: DiagnosticMessage = diag!(2315, Error, "Type '{0}' is not generic.");
     pub const TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1: DiagnosticMessage = diag!(2322, Error, "Type '{0}' is not assignable to type '{1}'.");
-    pub const TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1_WITH_EXACTOPTIONALPROPERTYTYPES: DiagnosticMessage = diag!(2375, Error, "Type '{0}' is not assignable to type '{1}' with 'exactOptionalPropertyTypes: true'. Consider adding 'undefined' to the type of the target.");
+    pub const TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1_WITH_EXACTOPTIONALPROPERTYTYPES: DiagnosticMessage = diag!(2375, Error, "Type '{0}' is not assignable to type '{1}' with 'exactOptionalPropertyTypes: true'. Consider adding 'undefined' to the types of the target's properties.");
+    pub const TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1_WITH_EXACTOPTIONALPROPERTYTYPES_CONSIDER_ADDING_UNDEFINED_TO_THE_TARGET: DiagnosticMessage = diag!(2412, Error, "Type '{0}' is not assignable to type '{1}' with 'exactOptionalPropertyTypes: true'. Consider adding 'undefined' to the type of the target.");
+    pub const TYPES_OF_PROPERTY_0_ARE_INCOMPATIBLE: DiagnosticMessage = diag!(2326, Error, "Types of property '{0}' are incompatible.");
     pub const PROPERTY_0_IS_MISSING_IN_TYPE_1: DiagnosticMessage = diag!(2324, Error, "Property '{0}' is missing in type '{1}'.");
     pub const INDEX_SIGNATURE_IS_MISSING_IN_TYPE_0: DiagnosticMessage = diag!(2329, Error, "Index signature is missing in type '{0}'.");
     pub const THIS_CANNOT_BE_REFERENCED_IN_CURRENT_LOCATION: DiagnosticMessage = diag!(2332, Error, "'this' cannot be referenced in current location.");
@@ -377,6 +453,10 @@ pub mod messages {
     pub const SUPER_CANNOT_BE_REFERENCED_IN_CONSTRUCTOR_ARGUMENTS: DiagnosticMessage = diag!(2336, Error, "Super calls are not permitted outside constructors or in nested functions inside constructors.");
     pub const SUPER_PROPERTY_ACCESS_IS_PERMITTED_ONLY_IN_A_CONSTRUCTOR: DiagnosticMessage = diag!(2338, Error, "'super' property access is permitted only in a constructor, member function, or member accessor of a derived class.");
     pub const PROPERTY_0_DOES_NOT_EXIST_ON_TYPE_1: DiagnosticMessage = diag!(2339, Error, "Property '{0}' does not exist on type '{1}'.");
+    pub const PROPERTY_0_IS_PRIVATE_AND_ONLY_ACCESSIBLE_WITHIN_CLASS_1: DiagnosticMessage = diag!(2341, Error, "Property '{0}' is private and only accessible within class '{1}'.");
+    pub const PROPERTY_0_IS_PRIVATE_IN_TYPE_1_BUT_NOT_IN_TYPE_2: DiagnosticMessage = diag!(2325, Error, "Property '{0}' is private in type '{1}' but not in type '{2}'.");
+    pub const PROPERTY_0_IS_PROTECTED_IN_TYPE_1_BUT_PUBLIC_IN_TYPE_2: DiagnosticMessage = diag!(2444, Error, "Property '{0}' is protected in type '{1}' but public in type '{2}'.");
+    pub const PROPERTY_0_IS_PROTECTED_AND_ONLY_ACCESSIBLE_WITHIN_CLASS_1_AND_ITS_SUBCLASSES: DiagnosticMessage = diag!(2445, Error, "Property '{0}' is protected and only accessible within class '{1}' and its subclasses.");
     pub const EACH_MEMBER_OF_THE_UNION_TYPE_0_HAS_SIGNATURES: DiagnosticMessage = diag!(2349, Error, "This expression is not callable.");
     pub const CANNOT_INVOKE_AN_EXPRESSION_WHOSE_TYPE_LACKS_A_CALL_SIGNATURE: DiagnosticMessage = diag!(2349, Error, "This expression is not callable.");
     pub const THIS_EXPRESSION_IS_NOT_CONSTRUCTABLE: DiagnosticMessage = diag!(2351, Error, "This expression is not constructable.");
@@ -397,6 +477,9 @@ pub mod messages {
     pub const A_NAMESPACE_DECLARATION_IS_ONLY_ALLOWED_AT_THE_TOP_LEVEL_OF_A_NAMESPACE_OR_MODULE: DiagnosticMessage = diag!(2434, Error, "A namespace declaration is only allowed at the top level of a namespace or module.");
     pub const THE_TYPE_RETURNED_BY_THE_0_METHOD_OF_AN_ASYNC_ITERATOR_MUST_BE_A_PROMISE: DiagnosticMessage = diag!(2547, Error, "The type returned by the '{0}()' method of an async iterator must be a promise for a type with a 'value' property.");
     pub const TYPE_0_IS_NOT_AN_ARRAY_TYPE: DiagnosticMessage = diag!(2461, Error, "Type '{0}' is not an array type.");
+    pub const TYPE_0_MUST_HAVE_A_SYMBOL_ITERATOR_METHOD_THAT_RETURNS_AN_ITERATOR: DiagnosticMessage = diag!(2488, Error, "Type '{0}' must have a '[Symbol.iterator]()' method that returns an iterator.");
+    pub const TYPE_0_IS_NOT_AN_ARRAY_TYPE_OR_A_STRING_TYPE: DiagnosticMessage = diag!(2495, Error, "Type '{0}' is not an array type or a string type.");
+    pub const TYPE_0_MUST_HAVE_A_SYMBOL_ASYNC_ITERATOR_METHOD_THAT_RETURNS_AN_ASYNC_ITERATOR: DiagnosticMessage = diag!(2504, Error, "Type '{0}' must have a '[Symbol.asyncIterator]()' method that returns an async iterator.");
     pub const REST_TYPES_MAY_ONLY_BE_CREATED_FROM_OBJECT_TYPES: DiagnosticMessage = diag!(2700, Error, "Rest types may only be created from object types.");
 
     // ========================================================================
@@ -408,12 +491,15 @@ pub mod messages {
     pub const THE_RIGHT_HAND_SIDE_OF_AN_IN_EXPRESSION_MUST_NOT_BE_A_PRIMITIVE: DiagnosticMessage = diag!(2361, Error, "The right-hand side of an 'in' expression must not be a primitive.");
     pub const ARGUMENT_OF_TYPE_0_IS_NOT_ASSIGNABLE_TO_PARAMETER_OF_TYPE_1: DiagnosticMessage = diag!(2345, Error, "Argument of type '{0}' is not assignable to parameter of type '{1}'.");
     pub const RETURN_TYPE_OF_PUBLIC_METHOD_FROM_EXPORTED_CLASS_HAS_OR_IS_USING_NAME_0: DiagnosticMessage = diag!(4055, Error, "Return type of public method from exported class has or is using name '{0}' from external module {1} but cannot be named.");
+    pub const CANNOT_REDECLARE_BLOCK_SCOPED_VARIABLE_0: DiagnosticMessage = diag!(2451, Error, "Cannot redeclare block-scoped variable '{0}'.");
     pub const VARIABLE_0_IS_USED_BEFORE_BEING_ASSIGNED: DiagnosticMessage = diag!(2454, Error, "Variable '{0}' is used before being assigned.");
     pub const TYPE_OF_AWAIT_OPERAND_MUST_EITHER_BE_A_VALID_PROMISE: DiagnosticMessage = diag!(2770, Error, "Type of 'await' operand must either be a valid promise or must not contain a callable 'then' member.");
     pub const TYPE_0_CAN_ONLY_BE_ITERATED_THROUGH_WHEN_USING_DOWNLEVEL_ITERATION: DiagnosticMessage = diag!(2802, Error, "Type '{0}' can only be iterated through when using the '--downlevelIteration' flag or with a '--target' of 'es2015' or higher.");
+    pub const OBJECT_IS_OF_TYPE_UNKNOWN: DiagnosticMessage = diag!(2571, Error, "Object is of type 'unknown'.");
     pub const OBJECT_IS_POSSIBLY_NULL: DiagnosticMessage = diag!(2531, Error, "Object is possibly 'null'.");
     pub const OBJECT_IS_POSSIBLY_UNDEFINED: DiagnosticMessage = diag!(2532, Error, "Object is possibly 'undefined'.");
     pub const OBJECT_IS_POSSIBLY_NULL_OR_UNDEFINED: DiagnosticMessage = diag!(2533, Error, "Object is possibly 'null' or 'undefined'.");
+    pub const A_FUNCTION_RETURNING_NEVER_CANNOT_HAVE_A_REACHABLE_END_POINT: DiagnosticMessage = diag!(2534, Error, "A function returning 'never' cannot have a reachable end point.");
     pub const A_FUNCTION_THAT_IS_CALLED_WITH_THE_NEW_KEYWORD_CANNOT_HAVE_A_THIS_TYPE_THAT_IS_VOID: DiagnosticMessage = diag!(2679, Error, "A function that is called with the 'new' keyword cannot have a 'this' type that is 'void'.");
     pub const EXPECTED_0_ARGUMENTS_BUT_GOT_1: DiagnosticMessage = diag!(2554, Error, "Expected {0} arguments, but got {1}.");
     pub const EXPECTED_AT_LEAST_0_ARGUMENTS_BUT_GOT_1: DiagnosticMessage = diag!(2555, Error, "Expected at least {0} arguments, but got {1}.");
@@ -422,6 +508,8 @@ pub mod messages {
     pub const VALUE_OF_TYPE_0_HAS_NO_PROPERTIES_IN_COMMON_WITH_TYPE_1: DiagnosticMessage = diag!(2560, Error, "Value of type '{0}' has no properties in common with type '{1}'. Did you mean to call it?");
     pub const BASE_CONSTRUCTORS_MUST_ALL_HAVE_THE_SAME_RETURN_TYPE: DiagnosticMessage = diag!(2510, Error, "Base constructors must all have the same return type.");
     pub const CANNOT_ASSIGN_TO_0_BECAUSE_IT_IS_A_READ_ONLY_PROPERTY: DiagnosticMessage = diag!(2540, Error, "Cannot assign to '{0}' because it is a read-only property.");
+    pub const INDEX_SIGNATURE_IN_TYPE_0_ONLY_PERMITS_READING: DiagnosticMessage = diag!(2542, Error, "Index signature in type '{0}' only permits reading.");
+    pub const PROPERTY_0_OF_TYPE_1_IS_NOT_ASSIGNABLE_TO_2_INDEX_TYPE_3: DiagnosticMessage = diag!(2411, Error, "Property '{0}' of type '{1}' is not assignable to '{2}' index type '{3}'.");
     pub const _0_INDEX_TYPE_1_IS_NOT_ASSIGNABLE_TO_2_INDEX_TYPE_3: DiagnosticMessage = diag!(2413, Error, "'{0}' index type '{1}' is not assignable to '{2}' index type '{3}'.");
     pub const CLASS_0_INCORRECTLY_EXTENDS_BASE_CLASS_1: DiagnosticMessage = diag!(2415, Error, "Class '{0}' incorrectly extends base class '{1}'.");
     pub const CLASS_0_INCORRECTLY_IMPLEMENTS_INTERFACE_1: DiagnosticMessage = diag!(2420, Error, "Class '{0}' incorrectly implements interface '{1}'.");
@@ -429,9 +517,18 @@ pub mod messages {
     pub const CLASS_STATIC_SIDE_0_INCORRECTLY_EXTENDS_BASE_CLASS_STATIC_SIDE_1: DiagnosticMessage = diag!(2417, Error, "Class static side '{0}' incorrectly extends base class static side '{1}'.");
     pub const TYPE_NAME_0_IN_EXTENDS_CLAUSE_DOES_NOT_REFERENCE_CONSTRUCTOR_FUNCTION_FOR_0: DiagnosticMessage = diag!(2419, Error, "Type name '{0}' in extends clause does not reference constructor function for '{0}'.");
     pub const CANNOT_ASSIGN_TO_0_BECAUSE_IT_IS_NOT_A_VARIABLE: DiagnosticMessage = diag!(2539, Error, "Cannot assign to '{0}' because it is not a variable.");
+    pub const UNUSED_TS_EXPECT_ERROR_DIRECTIVE: DiagnosticMessage = diag!(2578, Error, "Unused '@ts-expect-error' directive.");
     pub const CANNOT_ASSIGN_TO_0_BECAUSE_IT_IS_A_CONSTANT: DiagnosticMessage = diag!(2588, Error, "Cannot assign to '{0}' because it is a constant.");
+    pub const TYPE_INSTANTIATION_IS_EXCESSIVELY_DEEP_AND_POSSIBLY_INFINITE: DiagnosticMessage = diag!(2589, Error, "Type instantiation is excessively deep and possibly infinite.");
+    pub const TYPE_0_CANNOT_BE_USED_TO_INDEX_TYPE_1: DiagnosticMessage = diag!(2536, Error, "Type '{0}' cannot be used to index type '{1}'.");
+    pub const TYPE_0_CANNOT_BE_USED_AS_AN_INDEX_TYPE: DiagnosticMessage = diag!(2538, Error, "Type '{0}' cannot be used as an index type.");
+    pub const TYPE_0_HAS_NO_SIGNATURES_FOR_WHICH_THE_TYPE_ARGUMENT_LIST_IS_APPLICABLE: DiagnosticMessage = diag!(2635, Error, "Type '{0}' has no signatures for which the type argument list is applicable.");
+    pub const EXPRESSION_PRODUCES_A_UNION_TYPE_THAT_IS_TOO_COMPLEX_TO_REPRESENT: DiagnosticMessage = diag!(2590, Error, "Expression produces a union type that is too complex to represent.");
     pub const THE_OPERAND_OF_AN_INCREMENT_OR_DECREMENT_OPERATOR_MUST_BE_A_VARIABLE_OR_A_PROPERTY_ACCESS: DiagnosticMessage = diag!(2357, Error, "The operand of an increment or decrement operator must be a variable or a property access.");
     pub const NO_OVERLOAD_MATCHES_THIS_CALL: DiagnosticMessage = diag!(2769, Error, "No overload matches this call.");
+    pub const OVERLOAD_0_OF_1_2_GAVE_THE_FOLLOWING_ERROR: DiagnosticMessage = diag!(2772, Error, "Overload {0} of {1}, '{2}', gave the following error.");
+    pub const A_SPREAD_ARGUMENT_MUST_EITHER_HAVE_A_TUPLE_TYPE_OR_BE_PASSED_TO_A_REST_PARAMETER: DiagnosticMessage = diag!(2556, Error, "A spread argument must either have a tuple type or be passed to a rest parameter.");
+    pub const THIS_OVERLOAD_SIGNATURE_IS_NOT_COMPATIBLE_WITH_ITS_IMPLEMENTATION_SIGNATURE: DiagnosticMessage = diag!(2394, Error, "This overload signature is not compatible with its implementation signature.");
     pub const PROPERTY_0_IS_MISSING_IN_TYPE_1_BUT_REQUIRED_IN_TYPE_2: DiagnosticMessage = diag!(2741, Error, "Property '{0}' is missing in type '{1}' but required in type '{2}'.");
     pub const TYPE_0_HAS_NO_CALL_SIGNATURES: DiagnosticMessage = diag!(2757, Error, "Type '{0}' has no call signatures.");
     pub const TYPE_0_HAS_NO_CONSTRUCT_SIGNATURES: DiagnosticMessage = diag!(2761, Error, "Type '{0}' has no construct signatures.");
@@ -449,6 +546,16 @@ pub mod messages {
     pub const JSX_EXPRESSIONS_MUST_HAVE_ONE_PARENT_ELEMENT: DiagnosticMessage = diag!(2657, Error, "JSX expressions must have one parent element.");
     pub const CANNOT_USE_JSX_UNLESS_THE_JSX_FLAG_IS_PROVIDED: DiagnosticMessage = diag!(17004, Error, "Cannot use JSX unless the '--jsx' flag is provided.");
     pub const THE_RETURN_TYPE_OF_A_JSX_ELEMENT_CONSTRUCTOR_MUST_RETURN_AN_OBJECT_TYPE: DiagnosticMessage = diag!(2601, Error, "The return type of a JSX element constructor must return an object type.");
+    pub const JSX_ELEMENT_IMPLICITLY_HAS_TYPE_ANY_BECAUSE_THE_GLOBAL_TYPE_JSX_ELEMENT_DOES_NOT_EXIST: DiagnosticMessage = diag!(2602, Error, "JSX element implicitly has type 'any' because the global type 'JSX.Element' does not exist.");
+    pub const JSX_ELEMENT_TYPE_0_DOES_NOT_HAVE_ANY_CONSTRUCT_OR_CALL_SIGNATURES: DiagnosticMessage = diag!(2604, Error, "JSX element type '{0}' does not have any construct or call signatures.");
+    pub const THE_GLOBAL_TYPE_JSX_0_MAY_NOT_HAVE_MORE_THAN_ONE_PROPERTY: DiagnosticMessage = diag!(2608, Error, "The global type 'JSX.{0}' may not have more than one property.");
+    pub const _0_ARE_SPECIFIED_TWICE_THE_ATTRIBUTE_NAMED_0_WILL_BE_OVERWRITTEN: DiagnosticMessage = diag!(2710, Error, "'{0}' are specified twice. The attribute named '{0}' will be overwritten.");
+    pub const _0_IS_SPECIFIED_MORE_THAN_ONCE_SO_THIS_USAGE_WILL_BE_OVERWRITTEN: DiagnosticMessage = diag!(2783, Error, "'{0}' is specified more than once, so this usage will be overwritten.");
+    pub const _0_CANNOT_BE_USED_AS_A_JSX_COMPONENT: DiagnosticMessage = diag!(2786, Error, "'{0}' cannot be used as a JSX component.");
+    pub const ITS_RETURN_TYPE_0_IS_NOT_A_VALID_JSX_ELEMENT: DiagnosticMessage = diag!(2787, Error, "Its return type '{0}' is not a valid JSX element.");
+    pub const ITS_INSTANCE_TYPE_0_IS_NOT_A_VALID_JSX_ELEMENT: DiagnosticMessage = diag!(2788, Error, "Its instance type '{0}' is not a valid JSX element.");
+    pub const JSX_ATTRIBUTES_MUST_ONLY_BE_ASSIGNED_A_NON_EMPTY_EXPRESSION: DiagnosticMessage = diag!(17000, Error, "JSX attributes must only be assigned a non-empty 'expression'.");
+    pub const JSX_ELEMENTS_CANNOT_HAVE_MULTIPLE_ATTRIBUTES_WITH_THE_SAME_NAME: DiagnosticMessage = diag!(17001, Error, "JSX elements cannot have multiple attributes with the same name.");
 
     // ========================================================================
     // Module errors (2700-2799)
@@ -456,6 +563,11 @@ pub mod messages {
     pub const CANNOT_FIND_MODULE_0_DID_YOU_MEAN_TO_SET_THE_MODULE_RESOLUTION_OPTION_TO_NODENEXT: DiagnosticMessage = diag!(2792, Error, "Cannot find module '{0}'. Did you mean to set the 'moduleResolution' option to 'nodenext', or to add aliases to the 'paths' option?");
     pub const THE_CURRENT_FILE_IS_A_COMMONJS_MODULE_WHOSE_IMPORTS_WILL_PRODUCE_REQUIRE_CALLS: DiagnosticMessage = diag!(1479, Error, "The current file is a CommonJS module whose imports will produce 'require' calls; however, the referenced file is an ECMAScript module and cannot be imported with 'require'.");
     pub const ESM_SYNTAX_IS_NOT_ALLOWED_IN_A_COMMONJS_MODULE_WHEN_MODULE_IS_SET_TO_PRESERVE: DiagnosticMessage = diag!(1293, Error, "ESM syntax is not allowed in a CommonJS module when 'module' is set to 'preserve'.");
+    pub const RE_EXPORTING_A_TYPE_WHEN_0_IS_ENABLED_REQUIRES_USING_EXPORT_TYPE: DiagnosticMessage = diag!(1205, Error, "Re-exporting a type when '{0}' is enabled requires using 'export type'.");
+    pub const _0_CANNOT_BE_COMPILED_UNDER_ISOLATEDMODULES_BECAUSE_IT_IS_CONSIDERED_A_GLOBAL_SCRIPT_FILE: DiagnosticMessage = diag!(1208, Error, "'{0}' cannot be compiled under '--isolatedModules' because it is considered a global script file. Add an import, export, or an empty 'export {}' statement to make it a module.");
+    pub const A_TOP_LEVEL_EXPORT_MODIFIER_CANNOT_BE_USED_ON_VALUE_DECLARATIONS_IN_A_COMMONJS_MODULE: DiagnosticMessage = diag!(1287, Error, "A top-level 'export' modifier cannot be used on value declarations in a CommonJS module when 'verbatimModuleSyntax' is enabled.");
+    pub const _0_IS_A_TYPE_AND_MUST_BE_IMPORTED_USING_A_TYPE_ONLY_IMPORT_WHEN_VERBATIMMODULESYNTAX_IS_ENABLED: DiagnosticMessage = diag!(1484, Error, "'{0}' is a type and must be imported using a type-only import when 'verbatimModuleSyntax' is enabled.");
+    pub const CANNOT_ACCESS_AMBIENT_CONST_ENUMS_WHEN_0_IS_ENABLED: DiagnosticMessage = diag!(2748, Error, "Cannot access ambient const enums when '{0}' is enabled.");
 
     // ========================================================================
     // Suggestion diagnostics (6000+)
@@ -466,9 +578,12 @@ pub mod messages {
     pub const NEW_EXPRESSION_WHOSE_TARGET_LACKS_A_CONSTRUCT_SIGNATURE_IMPLICITLY_HAS_AN_ANY_TYPE: DiagnosticMessage = diag!(7009, Error, "'new' expression, whose target lacks a construct signature, implicitly has an 'any' type.");
     pub const _0_WHICH_LACKS_RETURN_TYPE_ANNOTATION_IMPLICITLY_HAS_AN_1_RETURN_TYPE: DiagnosticMessage = diag!(7010, Error, "'{0}', which lacks return-type annotation, implicitly has an '{1}' return type.");
     pub const FUNCTION_EXPRESSION_WHICH_LACKS_RETURN_TYPE_ANNOTATION_IMPLICITLY_HAS_AN_0_RETURN_TYPE: DiagnosticMessage = diag!(7011, Error, "Function expression, which lacks return-type annotation, implicitly has an '{0}' return type.");
+    pub const THE_THIS_CONTEXT_OF_TYPE_0_IS_NOT_ASSIGNABLE_TO_METHOD_S_THIS_OF_TYPE_1: DiagnosticMessage = diag!(2684, Error, "The 'this' context of type '{0}' is not assignable to method's 'this' of type '{1}'.");
+    pub const A_THIS_TYPE_IS_AVAILABLE_ONLY_IN_A_NON_STATIC_MEMBER_OF_A_CLASS_OR_INTERFACE: DiagnosticMessage = diag!(2526, Error, "A 'this' type is available only in a non-static member of a class or interface.");
     pub const THIS_IMPLICITLY_HAS_TYPE_ANY_BECAUSE_IT_DOES_NOT_HAVE_A_TYPE_ANNOTATION: DiagnosticMessage = diag!(2683, Error, "'this' implicitly has type 'any' because it does not have a type annotation.");
     pub const ELEMENT_IMPLICITLY_HAS_AN_ANY_TYPE_BECAUSE_EXPRESSION_OF_TYPE_0_CANT_BE_USED_TO_INDEX_TYPE_1: DiagnosticMessage = diag!(7053, Error, "Element implicitly has an 'any' type because expression of type '{0}' can't be used to index type '{1}'.");
     pub const ELEMENT_IMPLICITLY_HAS_AN_ANY_TYPE_BECAUSE_TYPE_0_HAS_NO_INDEX_SIGNATURE: DiagnosticMessage = diag!(7017, Error, "Element implicitly has an 'any' type because type '{0}' has no index signature.");
+    pub const JSX_ELEMENT_IMPLICITLY_HAS_TYPE_ANY_BECAUSE_NO_INTERFACE_JSX_0_EXISTS: DiagnosticMessage = diag!(7026, Error, "JSX element implicitly has type 'any' because no interface 'JSX.{0}' exists.");
     pub const OBJECT_LITERAL_MAY_ONLY_SPECIFY_KNOWN_PROPERTIES_AND_0_DOES_NOT_EXIST_IN_TYPE_1: DiagnosticMessage = diag!(2353, Error, "Object literal may only specify known properties, and '{0}' does not exist in type '{1}'.");
     pub const NO_OVERLOAD_EXPECTS_0_ARGUMENTS: DiagnosticMessage = diag!(2575, Error, "No overload expects {0} arguments, but overloads do exist that expect either {1} or {2} arguments.");
     pub const _0_IS_DEFINED_AS_AN_ACCESSOR_IN_CLASS_1_BUT_IS_OVERRIDDEN_HERE_IN_2_AS_AN_INSTANCE_PROPERTY: DiagnosticMessage = diag!(2610, Error, "'{0}' is defined as an accessor in class '{1}', but is overridden here in '{2}' as an instance property.");
@@ -496,6 +611,8 @@ pub mod messages {
     pub const CANNOT_FIND_A_TSCONFIG_JSON_FILE_AT_THE_SPECIFIED_DIRECTORY_0: DiagnosticMessage = diag!(5057, Error, "Cannot find a tsconfig.json file at the specified directory: '{0}'.");
     pub const THE_FILES_LIST_IN_CONFIG_FILE_0_IS_EMPTY: DiagnosticMessage = diag!(18002, Error, "The 'files' list in config file '{0}' is empty.");
     pub const NO_INPUTS_WERE_FOUND_IN_CONFIG_FILE_0: DiagnosticMessage = diag!(18003, Error, "No inputs were found in config file '{0}'. Specified 'include' paths were '{1}' and 'exclude' paths were '{2}'.");
+    pub const _0_IS_OF_TYPE_UNKNOWN: DiagnosticMessage = diag!(18046, Error, "'{0}' is of type 'unknown'.");
+    pub const ARGUMENT_FOR_0_OPTION_MUST_BE_COLON_1: DiagnosticMessage = diag!(6046, Error, "Argument for '{0}' option must be: {1}.");
     pub const FILE_0_NOT_FOUND: DiagnosticMessage = diag!(6053, Error, "File '{0}' not found.");
     pub const FILE_0_HAS_AN_UNSUPPORTED_EXTENSION: DiagnosticMessage = diag!(6054, Error, "File '{0}' has an unsupported extension. The only supported extensions are {1}.");
 
@@ -521,20 +638,24 @@ pub mod messages {
     pub const EXPERIMENTAL_SUPPORT_FOR_DECORATORS_IS_A_FEATURE_THAT_IS_SUBJECT_TO_CHANGE: DiagnosticMessage = diag!(1219, Error, "Experimental support for decorators is a feature that is subject to change in a future release. Set the 'experimentalDecorators' option in your 'tsconfig' or 'jsconfig' to remove this warning.");
 
     // ========================================================================
-    // Abstract class errors
+    // Abstract class and inheritance errors
     // ========================================================================
     pub const ABSTRACT_METHOD_0_IN_CLASS_1_CANNOT_BE_ACCESSED_VIA_SUPER_EXPRESSION: DiagnosticMessage = diag!(2513, Error, "Abstract method '{0}' in class '{1}' cannot be accessed via super expression.");
     pub const NON_ABSTRACT_CLASS_0_DOES_NOT_IMPLEMENT_INHERITED_ABSTRACT_MEMBER_1_FROM_CLASS_2: DiagnosticMessage = diag!(2515, Error, "Non-abstract class '{0}' does not implement inherited abstract member '{1}' from class '{2}'.");
     pub const ABSTRACT_METHODS_CAN_ONLY_APPEAR_WITHIN_AN_ABSTRACT_CLASS: DiagnosticMessage = diag!(1244, Error, "Abstract methods can only appear within an abstract class.");
     pub const ABSTRACT_METHOD_0_CANNOT_HAVE_AN_IMPLEMENTATION: DiagnosticMessage = diag!(1245, Error, "Method '{0}' cannot have an implementation because it is marked abstract.");
     pub const CANNOT_CREATE_AN_INSTANCE_OF_AN_ABSTRACT_CLASS: DiagnosticMessage = diag!(2511, Error, "Cannot create an instance of an abstract class.");
+    pub const SUPER_MUST_BE_CALLED_BEFORE_ACCESSING_THIS_IN_THE_CONSTRUCTOR_OF_A_DERIVED_CLASS: DiagnosticMessage = diag!(17009, Error, "'super' must be called before accessing 'this' in the constructor of a derived class.");
+    pub const THIS_MEMBER_CANNOT_HAVE_AN_OVERRIDE_MODIFIER_BECAUSE_IT_IS_NOT_DECLARED_IN_THE_BASE_CLASS_0: DiagnosticMessage = diag!(4113, Error, "This member cannot have an 'override' modifier because it is not declared in the base class '{0}'.");
+    pub const THIS_MEMBER_MUST_HAVE_AN_OVERRIDE_MODIFIER_BECAUSE_IT_OVERRIDES_A_MEMBER_IN_THE_BASE_CLASS_0: DiagnosticMessage = diag!(4114, Error, "This member must have an 'override' modifier because it overrides a member in the base class '{0}'.");
+    pub const THIS_MEMBER_CANNOT_HAVE_AN_OVERRIDE_MODIFIER_BECAUSE_ITS_CONTAINING_CLASS_0_DOES_NOT_EXTEND_ANOTHER_CLASS: DiagnosticMessage = diag!(4112, Error, "This member cannot have an 'override' modifier because its containing class '{0}' does not extend another class.");
 
     // ========================================================================
     // Control flow errors
     // ========================================================================
     pub const NOT_ALL_CODE_PATHS_RETURN_A_VALUE: DiagnosticMessage = diag!(7030, Error, "Not all code paths return a value.");
     pub const UNREACHABLE_CODE_DETECTED: DiagnosticMessage = diag!(7027, Error, "Unreachable code detected.");
-    pub const UNUSED_LABEL: DiagnosticMessage = diag!(7028, Warning, "Unused label.");
+    pub const UNUSED_LABEL: DiagnosticMessage = diag!(7028, Error, "Unused label.");
     pub const FALLTHROUGH_CASE_IN_SWITCH: DiagnosticMessage = diag!(7029, Error, "Fallthrough case in switch.");
 }
 
@@ -574,6 +695,21 @@ mod tests {
         assert!(display.contains("foo"));
     }
 
+    #[test]
+    fn test_flatten_message_chain() {
+        let diag = Diagnostic::new(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &["{ a: string }", "A"])
+            .with_message_chain(vec![
+                DiagnosticMessageChain::new(&messages::TYPES_OF_PROPERTY_0_ARE_INCOMPATIBLE, &["a"]).with_next(vec![
+                    DiagnosticMessageChain::new(&messages::TYPE_0_IS_NOT_ASSIGNABLE_TO_TYPE_1, &["string", "number"]),
+                ]),
+            ]);
+        assert_eq!(
+            diag.flatten_message_text(),
+            "Type '{ a: string }' is not assignable to type 'A'.\n  Types of property 'a' are incompatible.\n    Type 'string' is not assignable to type 'number'."
+        );
+        assert!(format!("{}", diag).ends_with("\n    Type 'string' is not assignable to type 'number'."));
+    }
+
     #[test]
     fn test_diagnostic_without_location() {
         let diag = Diagnostic::new(&messages::UNEXPECTED_TOKEN, &[]);
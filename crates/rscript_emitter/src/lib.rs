@@ -6,9 +6,10 @@
 //! 3. Generate source maps
 //! 4. Write output files
 
-use rscript_ast::node::SourceFile;
+use rscript_ast::node::{LanguageVariant, SourceFile};
 use rscript_core::intern::StringInterner;
 use rscript_printer::{Printer, PrinterOptions};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 /// The emitter produces output files from the AST.
@@ -19,6 +20,14 @@ pub struct Emitter {
     pub emit_source_map: bool,
     /// Whether to strip type annotations (emit JS).
     pub strip_types: bool,
+    /// Whether to emit a `"use strict"` prologue in scripts (alwaysStrict).
+    /// ES modules are strict mode code already.
+    pub always_strict: bool,
+    /// Whether imports and exports are emitted as written, less their
+    /// types (verbatimModuleSyntax).
+    pub verbatim_module_syntax: bool,
+    /// Whether JSX is emitted as written, into `.jsx` files (jsx: preserve).
+    pub preserve_jsx: bool,
     /// Output directory override.
     pub out_dir: Option<PathBuf>,
     /// Root directory for calculating relative paths.
@@ -54,6 +63,9 @@ impl Emitter {
             emit_declaration: false,
             emit_source_map: false,
             strip_types: true,
+            always_strict: false,
+            verbatim_module_syntax: false,
+            preserve_jsx: false,
             out_dir: None,
             root_dir: None,
         }
@@ -61,6 +73,19 @@ impl Emitter {
 
     /// Emit a source file to JavaScript (and optionally .d.ts and source map).
     pub fn emit(&self, source_file: &SourceFile<'_>, interner: &StringInterner) -> EmitResult {
+        self.emit_with_elided_imports(source_file, interner, HashSet::new(), HashSet::new())
+    }
+
+    /// Emit a checked source file, dropping from the JavaScript the import
+    /// bindings the checker found are never read as values, and the exports
+    /// it found re-export only types.
+    pub fn emit_with_elided_imports(
+        &self,
+        source_file: &SourceFile<'_>,
+        interner: &StringInterner,
+        elided_imports: HashSet<String>,
+        elided_exports: HashSet<String>,
+    ) -> EmitResult {
         let mut output_files = Vec::new();
 
         // Print JS output (with types stripped)
@@ -70,13 +95,22 @@ impl Emitter {
                 indent_str: "    ".to_string(),
                 new_line: "\n".to_string(),
                 trailing_newline: true,
+                verbatim_module_syntax: self.verbatim_module_syntax,
+                elided_imports,
+                elided_exports,
             });
-            printer.print_source_file(source_file)
+            let js = printer.print_source_file(source_file);
+            if self.always_strict && !source_file.is_external_module() && !source_file.has_use_strict_directive() {
+                format!("\"use strict\";\n{}", js)
+            } else {
+                js
+            }
         };
 
         // Calculate output path
         let source_path = Path::new(&source_file.file_name);
-        let js_path = self.get_output_path(source_path, ".js");
+        let js_extension = if self.preserve_jsx && source_file.language_variant == LanguageVariant::JSX { ".jsx" } else { ".js" };
+        let js_path = self.get_output_path(source_path, js_extension);
 
         output_files.push(OutputFile {
             path: js_path,
@@ -90,6 +124,9 @@ impl Emitter {
                 indent_str: "    ".to_string(),
                 new_line: "\n".to_string(),
                 trailing_newline: true,
+                verbatim_module_syntax: false,
+                elided_imports: HashSet::new(),
+                elided_exports: HashSet::new(),
             });
             let dts = printer.print_source_file(source_file);
             let dts_path = self.get_output_path(source_path, ".d.ts");
@@ -172,6 +209,7 @@ mod tests {
     fn test_emitter_creation() {
         let emitter = Emitter::new();
         assert!(emitter.strip_types);
+        assert!(!emitter.always_strict);
         assert!(!emitter.emit_declaration);
         assert!(!emitter.emit_source_map);
     }
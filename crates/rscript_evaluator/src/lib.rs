@@ -141,8 +141,8 @@ mod tests {
         let expr = create_numeric_literal("42");
         assert_eq!(evaluate_constant_numeric_expression(&expr), Some(42.0));
         
-        let expr = create_numeric_literal("3.14");
-        assert_eq!(evaluate_constant_numeric_expression(&expr), Some(3.14));
+        let expr = create_numeric_literal("2.5");
+        assert_eq!(evaluate_constant_numeric_expression(&expr), Some(2.5));
         
         let expr = create_numeric_literal("-10");
         assert_eq!(evaluate_constant_numeric_expression(&expr), Some(-10.0));
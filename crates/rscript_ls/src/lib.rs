@@ -4,53 +4,72 @@
 //! diagnostics, references, etc. The language service is consumed by the LSP server.
 
 use bumpalo::Bump;
-use rscript_binder::Binder;
-use rscript_checker::Checker;
+use rscript_ast::types::{SymbolFlags, SymbolId, TypeId};
+use rscript_binder::{Binder, Symbol};
+use rscript_checker::{Checker, SignatureKind, TypeFormatFlags};
+use rscript_core::text::TextRange;
+use rscript_compiler::get_bind_and_check_diagnostics;
 use rscript_parser::Parser;
+use rscript_tsoptions::CompilerOptions;
+use std::cell::OnceCell;
 use std::collections::HashMap;
 
 /// A document tracked by the language service.
-#[derive(Debug)]
 struct Document {
-    /// The file name.
-    file_name: String,
     /// The current source text.
     text: String,
     /// Version number for incremental updates.
     version: i32,
-    /// Cached diagnostics for this version (avoids re-parse/bind/check).
-    cached_diagnostics: Option<CachedDiagnostics>,
+    /// The check of this version, made by the first request that needs it.
+    checked: OnceCell<CheckedDocument>,
 }
 
-/// Cached compilation results for a document at a specific version.
-#[derive(Debug, Clone)]
-struct CachedDiagnostics {
-    /// The version this cache was computed for.
-    version: i32,
-    /// The cached diagnostics.
+/// The results of checking a document, shared by the requests made on one
+/// version of it (avoids re-parse/bind/check).
+struct CheckedDocument {
+    checker: Checker,
     diagnostics: Vec<rscript_diagnostics::Diagnostic>,
+    /// The top-level symbols, in source order.
+    top_level_symbols: Vec<SymbolId>,
 }
 
 /// Language service providing IDE features.
 pub struct LanguageService {
     /// Open documents tracked by URI.
     documents: HashMap<String, Document>,
+    /// The options of the project the documents belong to.
+    compiler_options: CompilerOptions,
 }
 
 impl LanguageService {
     pub fn new() -> Self {
+        Self::with_compiler_options(CompilerOptions::default())
+    }
+
+    /// Create a language service that checks documents with the options of
+    /// their project, e.g. those of its tsconfig.json.
+    pub fn with_compiler_options(compiler_options: CompilerOptions) -> Self {
         Self {
             documents: HashMap::new(),
+            compiler_options,
+        }
+    }
+
+    /// Change the project options, re-checking open documents on their next
+    /// request.
+    pub fn set_compiler_options(&mut self, compiler_options: CompilerOptions) {
+        self.compiler_options = compiler_options;
+        for doc in self.documents.values_mut() {
+            doc.checked = OnceCell::new();
         }
     }
 
     /// Open or update a document.
     pub fn open_document(&mut self, uri: String, text: String, version: i32) {
-        self.documents.insert(uri.clone(), Document {
-            file_name: uri,
+        self.documents.insert(uri, Document {
             text,
             version,
-            cached_diagnostics: None, // invalidate cache on open
+            checked: OnceCell::new(), // invalidate cache on open
         });
     }
 
@@ -59,7 +78,7 @@ impl LanguageService {
         if let Some(doc) = self.documents.get_mut(uri) {
             doc.text = text;
             doc.version = version;
-            doc.cached_diagnostics = None; // invalidate cache on update
+            doc.checked = OnceCell::new(); // invalidate cache on update
         }
     }
 
@@ -74,47 +93,19 @@ impl LanguageService {
     }
 
     /// Get diagnostics for a file.
-    /// Uses cached diagnostics if the document version hasn't changed,
-    /// avoiding re-parse/bind/check on every request.
-    pub fn get_diagnostics(&mut self, file_name: &str) -> Vec<rscript_diagnostics::Diagnostic> {
-        // Check if we have a valid cache for the current version
-        if let Some(doc) = self.documents.get(file_name) {
-            if let Some(cached) = &doc.cached_diagnostics {
-                if cached.version == doc.version {
-                    return cached.diagnostics.clone();
-                }
-            }
-        }
-
-        // No cache hit — compute diagnostics
-        let text = match self.documents.get(file_name) {
-            Some(doc) => doc.text.clone(),
-            None => return Vec::new(),
-        };
-        let version = self.documents.get(file_name).map(|d| d.version).unwrap_or(0);
-
-        let arena = Bump::new();
-        let parser = Parser::new(&arena, file_name, &text);
-        let source_file = parser.parse_source_file();
-
-        let mut binder = Binder::new();
-        binder.bind_source_file(&source_file);
-
-        let mut checker = Checker::new(binder);
-        checker.check_source_file(&source_file);
-
-        let diags = checker.take_diagnostics();
-        let diagnostics = diags.into_diagnostics();
-
-        // Store in cache
-        if let Some(doc) = self.documents.get_mut(file_name) {
-            doc.cached_diagnostics = Some(CachedDiagnostics {
-                version,
-                diagnostics: diagnostics.clone(),
-            });
-        }
+    /// The check of the document's current version is cached, avoiding
+    /// re-parse/bind/check on every request.
+    pub fn get_diagnostics(&self, file_name: &str) -> Vec<rscript_diagnostics::Diagnostic> {
+        self.get_checked_document(file_name)
+            .map(|checked| checked.diagnostics.clone())
+            .unwrap_or_default()
+    }
 
-        diagnostics
+    /// The check of a document's current version, made with the project
+    /// options the first time it is needed.
+    fn get_checked_document(&self, file_name: &str) -> Option<&CheckedDocument> {
+        let doc = self.documents.get(file_name)?;
+        Some(doc.checked.get_or_init(|| check_document(file_name, &doc.text, &self.compiler_options)))
     }
 
     /// Get completions at a position.
@@ -171,60 +162,44 @@ impl LanguageService {
             });
         }
 
+        let range = get_identifier_range_at_position(text, position)?;
+        let checker = &self.get_checked_document(file_name)?.checker;
+        let Some(id) = checker.get_symbol_at_location(range) else {
+            // The name of an accessed property has no symbol of its own
+            let type_id = checker.get_type_at_location(range).filter(|_| is_property_access_name(text, range));
+            let contents = match type_id {
+                Some(t) => format!("(property) {}: {}", word, checker.type_to_string(t, TypeFormatFlags::NONE)),
+                None => format!("(identifier) {}", word),
+            };
+            return Some(HoverInfo { contents, range: Some(range.to_span()) });
+        };
+        let symbol = checker.get_symbol(id)?;
         Some(HoverInfo {
-            contents: format!("(identifier) {}", word),
-            range: None,
+            contents: get_symbol_display(checker, id, symbol, checker.get_type_at_location(range)),
+            range: Some(range.to_span()),
         })
     }
 
-    /// Get the definition location of a symbol at a position.
-    pub fn get_definition(&self, _file_name: &str, _position: u32) -> Vec<DefinitionInfo> {
-        // Requires source map from AST nodes to positions
-        Vec::new()
+    /// Get the definition location of a symbol at a position. Aliases
+    /// resolve to the declarations they refer to.
+    pub fn get_definition(&self, file_name: &str, position: u32) -> Vec<DefinitionInfo> {
+        let Some(text) = self.get_document_text(file_name) else { return Vec::new() };
+        let Some(range) = get_identifier_range_at_position(text, position) else { return Vec::new() };
+        let Some(checked) = self.get_checked_document(file_name) else { return Vec::new() };
+        let checker = &checked.checker;
+        let Some(symbol) = checker.get_symbol_at_location(range) else { return Vec::new() };
+        let symbol = checker.get_aliased_symbol(symbol).unwrap_or(symbol);
+        checker.get_symbol(symbol)
+            .map(|symbol| symbol.declaration_ranges.iter()
+                .map(|range| DefinitionInfo { file_name: file_name.to_string(), span: range.to_span() })
+                .collect())
+            .unwrap_or_default()
     }
 
-    /// Find all references to a symbol at a position.
+    /// Find all references to a symbol at a position, its declarations included.
     pub fn get_references(&self, file_name: &str, position: u32) -> Vec<ReferenceInfo> {
-        let text = match self.documents.get(file_name) {
-            Some(doc) => &doc.text,
-            None => return Vec::new(),
-        };
-
-        let word = get_word_at_position(text, position);
-        if word.is_empty() { return Vec::new(); }
-
-        // Simple text-based search for references
-        let mut references = Vec::new();
-        let bytes = text.as_bytes();
-        let word_bytes = word.as_bytes();
-        let word_len = word_bytes.len();
-
-        let mut i = 0;
-        while i + word_len <= bytes.len() {
-            if &bytes[i..i + word_len] == word_bytes {
-                let before_ok = i == 0 || !is_identifier_char(bytes[i - 1]);
-                let after_ok = i + word_len >= bytes.len() || !is_identifier_char(bytes[i + word_len]);
-                if before_ok && after_ok {
-                    references.push(ReferenceInfo {
-                        file_name: file_name.to_string(),
-                        span: rscript_core::text::TextSpan::new(i as u32, word_len as u32),
-                        is_definition: false,
-                    });
-                }
-            }
-            i += 1;
-        }
-
-        references
-    }
-
-    /// Get document symbols (outline).
-    pub fn get_document_symbols(&self, file_name: &str) -> Vec<DocumentSymbol> {
-        let text = match self.documents.get(file_name) {
-            Some(doc) => &doc.text,
-            None => return Vec::new(),
-        };
-
+        let Some(text) = self.get_document_text(file_name) else { return Vec::new() };
+        let Some(range) = get_identifier_range_at_position(text, position) else { return Vec::new() };
         let arena = Bump::new();
         let parser = Parser::new(&arena, file_name, text);
         let source_file = parser.parse_source_file();
@@ -232,18 +207,28 @@ impl LanguageService {
         let mut binder = Binder::new();
         binder.bind_source_file(&source_file);
 
-        let mut symbols = Vec::new();
-        for symbol in binder.get_symbols() {
-            let kind = symbol_to_document_symbol_kind(&symbol.flags);
-            symbols.push(DocumentSymbol {
-                name: format!("symbol_{}", symbol.id.index()),
-                kind,
-                range: rscript_core::text::TextSpan::new(0, 0),
-                selection_range: rscript_core::text::TextSpan::new(0, 0),
-                children: vec![],
-            });
-        }
+        let Some(symbol) = binder.get_symbol_at_location(range) else { return Vec::new() };
+        let declarations = binder.get_symbol(symbol).map(|s| s.declaration_ranges.as_slice()).unwrap_or_default();
+        let mut references: Vec<ReferenceInfo> = binder.symbol_locations()
+            .filter(|&(_, id)| id == symbol)
+            .map(|(range, _)| ReferenceInfo {
+                file_name: file_name.to_string(),
+                span: range.to_span(),
+                is_definition: declarations.contains(&range),
+            })
+            .collect();
+        references.sort_by_key(|reference| reference.span.start);
+        references
+    }
 
+    /// Get document symbols (outline): the top-level declarations in source
+    /// order, with the exports of namespaces as children.
+    pub fn get_document_symbols(&self, file_name: &str) -> Vec<DocumentSymbol> {
+        let Some(checked) = self.get_checked_document(file_name) else { return Vec::new() };
+        let mut symbols: Vec<DocumentSymbol> = checked.top_level_symbols.iter()
+            .filter_map(|&id| get_document_symbol(&checked.checker, id))
+            .collect();
+        symbols.sort_by_key(|symbol| symbol.range.start);
         symbols
     }
 }
@@ -331,9 +316,91 @@ pub enum DocumentSymbolKind {
 
 // Helper functions
 
+/// Parse, bind and check a document on its own.
+fn check_document(file_name: &str, text: &str, options: &CompilerOptions) -> CheckedDocument {
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, file_name, text);
+    let source_file = parser.parse_source_file();
+
+    let mut binder = Binder::new();
+    binder.bind_source_file(&source_file);
+    let top_level_symbols = binder.top_level_symbols().iter().map(|symbol| symbol.id).collect();
+
+    let mut checker = Checker::with_compiler_options(binder, options);
+    checker.check_source_file(&source_file);
+
+    let diagnostics = get_bind_and_check_diagnostics(&source_file, options, checker.take_diagnostics()).into_diagnostics();
+    CheckedDocument { checker, diagnostics, top_level_symbols }
+}
+
+/// Describe a symbol as hover shows it, e.g. `function f(x: number): string`.
+fn get_symbol_display(checker: &Checker, id: SymbolId, symbol: &Symbol, type_id: Option<TypeId>) -> String {
+    let name = &symbol.name_text;
+    let flags = symbol.flags;
+    let type_text = type_id.map(|t| checker.type_to_string(t, TypeFormatFlags::NONE));
+    let with_type = |prefix: &str| match &type_text {
+        Some(type_text) => format!("{} {}: {}", prefix, name, type_text),
+        None => format!("{} {}", prefix, name),
+    };
+    if flags.intersects(SymbolFlags::FUNCTION | SymbolFlags::METHOD) {
+        let prefix = if flags.contains(SymbolFlags::FUNCTION) { "function" } else { "(method)" };
+        let signatures = type_id.map(|t| checker.get_signatures_of_type(t, SignatureKind::Call)).unwrap_or_default();
+        if !signatures.is_empty() {
+            return signatures.iter()
+                .map(|sig| format!("{} {}{}", prefix, name, checker.signature_to_string(sig, TypeFormatFlags::NONE)))
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+        return with_type(prefix);
+    }
+    if flags.contains(SymbolFlags::CLASS) { return format!("class {}", name); }
+    if flags.contains(SymbolFlags::INTERFACE) { return format!("interface {}", name); }
+    if flags.intersects(SymbolFlags::ENUM) { return format!("enum {}", name); }
+    if flags.intersects(SymbolFlags::MODULE) { return format!("namespace {}", name); }
+    if flags.contains(SymbolFlags::TYPE_ALIAS) {
+        return match type_id {
+            Some(t) => format!("type {} = {}", name, checker.type_to_string(t, TypeFormatFlags::IN_TYPE_ALIAS)),
+            None => format!("type {}", name),
+        };
+    }
+    if flags.contains(SymbolFlags::TYPE_PARAMETER) { return format!("(type parameter) {}", name); }
+    if flags.contains(SymbolFlags::ENUM_MEMBER) { return format!("(enum member) {}", name); }
+    if flags.contains(SymbolFlags::PROPERTY) { return with_type("(property)"); }
+    if flags.contains(SymbolFlags::ALIAS) { return with_type("(alias)"); }
+    if checker.is_parameter(id) { return with_type("(parameter)"); }
+    with_type("(variable)")
+}
+
+/// The outline entry of a declared symbol, located at its first declaration.
+fn get_document_symbol(checker: &Checker, id: SymbolId) -> Option<DocumentSymbol> {
+    let symbol = checker.get_symbol(id)?;
+    let span = symbol.declaration_ranges.first()?.to_span();
+    let children = if symbol.flags.intersects(SymbolFlags::MODULE) {
+        checker.get_exports_of_module(id).into_iter()
+            .filter_map(|export| get_document_symbol(checker, export))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    Some(DocumentSymbol {
+        name: symbol.name_text.clone(),
+        kind: symbol_to_document_symbol_kind(&symbol.flags),
+        range: span,
+        selection_range: span,
+        children,
+    })
+}
+
 fn get_word_at_position(text: &str, position: u32) -> String {
+    get_identifier_range_at_position(text, position)
+        .map(|range| text[range.to_range()].to_string())
+        .unwrap_or_default()
+}
+
+/// The range of the identifier at or just before `position`.
+fn get_identifier_range_at_position(text: &str, position: u32) -> Option<TextRange> {
     let pos = position as usize;
-    if pos >= text.len() { return String::new(); }
+    if pos >= text.len() { return None; }
 
     let bytes = text.as_bytes();
     let mut start = pos;
@@ -346,7 +413,12 @@ fn get_word_at_position(text: &str, position: u32) -> String {
         end += 1;
     }
 
-    text[start..end].to_string()
+    (start < end).then(|| TextRange::new(start as u32, end as u32))
+}
+
+/// Whether the identifier at `range` follows a `.`, naming a property.
+fn is_property_access_name(text: &str, range: TextRange) -> bool {
+    text[..range.pos as usize].trim_end().ends_with('.')
 }
 
 fn is_identifier_char(ch: u8) -> bool {
@@ -370,11 +442,11 @@ fn is_keyword(word: &str) -> bool {
     )
 }
 
-fn symbol_to_document_symbol_kind(flags: &rscript_ast::types::SymbolFlags) -> DocumentSymbolKind {
-    if flags.contains(rscript_ast::types::SymbolFlags::FUNCTION) { DocumentSymbolKind::Function }
-    else if flags.contains(rscript_ast::types::SymbolFlags::CLASS) { DocumentSymbolKind::Class }
-    else if flags.contains(rscript_ast::types::SymbolFlags::INTERFACE) { DocumentSymbolKind::Interface }
-    else if flags.contains(rscript_ast::types::SymbolFlags::ENUM) { DocumentSymbolKind::Enum }
-    else if flags.contains(rscript_ast::types::SymbolFlags::MODULE) { DocumentSymbolKind::Module }
+fn symbol_to_document_symbol_kind(flags: &SymbolFlags) -> DocumentSymbolKind {
+    if flags.contains(SymbolFlags::FUNCTION) { DocumentSymbolKind::Function }
+    else if flags.contains(SymbolFlags::CLASS) { DocumentSymbolKind::Class }
+    else if flags.contains(SymbolFlags::INTERFACE) { DocumentSymbolKind::Interface }
+    else if flags.intersects(SymbolFlags::ENUM) { DocumentSymbolKind::Enum }
+    else if flags.intersects(SymbolFlags::MODULE) { DocumentSymbolKind::Module }
     else { DocumentSymbolKind::Variable }
 }
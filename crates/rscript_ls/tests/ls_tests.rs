@@ -0,0 +1,95 @@
+//! Language service integration tests.
+//!
+//! Opens documents and queries hover, definitions, references and symbols.
+
+use rscript_ls::LanguageService;
+use rscript_tsoptions::CompilerOptions;
+
+/// Helper: a language service with `source` open as `test.ts`.
+fn open(source: &str) -> LanguageService {
+    let mut service = LanguageService::new();
+    service.open_document("test.ts".to_string(), source.to_string(), 1);
+    service
+}
+
+/// Helper: the position of the `occurrence`th (from 0) appearance of `text`.
+fn position_of(source: &str, text: &str, occurrence: usize) -> u32 {
+    source.match_indices(text).nth(occurrence).expect("text should occur in source").0 as u32
+}
+
+#[test]
+fn test_hover_shows_symbol_kinds_and_types() {
+    let source = "function add(a: number, b: number): number { return a + b; }
+const total = add(1, 2);
+interface Shape { area: number }";
+    let service = open(source);
+    let hover = |text, occurrence| service.get_hover("test.ts", position_of(source, text, occurrence)).unwrap().contents;
+    assert_eq!(hover("add", 1), "function add(a: number, b: number): number");
+    assert_eq!(hover("total", 0), "(variable) total: number");
+    assert_eq!(hover("Shape", 0), "interface Shape");
+    assert_eq!(hover("const", 0), "(keyword) const");
+}
+
+#[test]
+fn test_hover_resolves_shadowed_names_parameters_and_properties() {
+    let source = "const x = \"outer\";
+function f(x: number) { return x; }
+const obj = { a: 1 };
+const n = obj.a;";
+    let service = open(source);
+    let hover = |text, occurrence| service.get_hover("test.ts", position_of(source, text, occurrence)).unwrap().contents;
+    assert_eq!(hover("x", 0), "(variable) x: \"outer\"");
+    assert_eq!(hover("x", 1), "(parameter) x: number");
+    assert_eq!(hover("x", 2), "(parameter) x: number");
+    assert_eq!(hover("a", 1), "(property) a: number");
+}
+
+#[test]
+fn test_definition_and_references_follow_symbols() {
+    let source = "let count = 0;
+function bump() { let count = 10; return count; }
+count = count + 1;
+namespace Geo { export function origin() {} }
+import O = Geo.origin;";
+    let service = open(source);
+
+    // The inner `count` is a different symbol from the outer one
+    let references: Vec<(u32, bool)> = service.get_references("test.ts", position_of(source, "count", 0))
+        .into_iter()
+        .map(|r| (r.span.start, r.is_definition))
+        .collect();
+    let expected = [0, 3, 4].map(|occurrence| (position_of(source, "count", occurrence), occurrence == 0));
+    assert_eq!(references, expected);
+
+    let definitions = service.get_definition("test.ts", position_of(source, "count", 2));
+    assert_eq!(definitions.len(), 1);
+    assert_eq!(definitions[0].span.start, position_of(source, "count", 1));
+
+    // An alias resolves to the declaration it refers to
+    let definitions = service.get_definition("test.ts", position_of(source, "O =", 0));
+    assert_eq!(definitions[0].span.start, position_of(source, "origin", 0));
+
+    let symbols = service.get_document_symbols("test.ts");
+    let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["count", "bump", "Geo", "O"]);
+    assert_eq!(symbols[2].children.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(), ["origin"]);
+}
+
+#[test]
+fn test_documents_are_checked_with_project_options() {
+    let source = "let s: string | undefined;
+const t: string = undefined;";
+    let mut service = open(source);
+    assert!(service.get_diagnostics("test.ts").is_empty());
+
+    // Changing the options re-checks open documents
+    let options = CompilerOptions { strict: Some(true), ..Default::default() };
+    service.set_compiler_options(options.clone());
+    let codes: Vec<u32> = service.get_diagnostics("test.ts").iter().map(|d| d.code).collect();
+    assert_eq!(codes, [2322]);
+
+    let mut service = LanguageService::with_compiler_options(options);
+    service.open_document("test.ts".to_string(), source.to_string(), 1);
+    assert_eq!(service.get_hover("test.ts", position_of(source, "s", 0)).unwrap().contents, "(variable) s: string | undefined");
+    assert_eq!(service.get_diagnostics("test.ts").len(), 1);
+}
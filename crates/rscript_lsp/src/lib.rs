@@ -65,7 +65,7 @@ impl RscriptLspServer {
 
     async fn publish_diagnostics(&self, uri: Url) {
         let diagnostics = {
-            let mut ls = self.language_service.lock().unwrap();
+            let ls = self.language_service.lock().unwrap();
             let key = Self::uri_to_key(&uri);
             let diags = ls.get_diagnostics(&key);
             let text = ls.get_document_text(&key).unwrap_or("").to_string();
@@ -80,16 +80,23 @@ impl RscriptLspServer {
                 } else {
                     Position::new(0, 0)
                 };
+                // Unused declarations and labels are shown greyed out
+                let is_suggestion = d.category == rscript_diagnostics::DiagnosticCategory::Suggestion;
+                let tags = matches!(d.code, 6133 | 6192 | 6196 | 7028)
+                    .then(|| vec![DiagnosticTag::UNNECESSARY]);
                 Diagnostic {
                     range: Range::new(start_pos, end_pos),
                     severity: Some(if d.is_error() {
                         DiagnosticSeverity::ERROR
+                    } else if is_suggestion {
+                        DiagnosticSeverity::HINT
                     } else {
                         DiagnosticSeverity::WARNING
                     }),
+                    tags,
                     code: Some(NumberOrString::Number(d.code as i32)),
                     source: Some("rsc".to_string()),
-                    message: d.message_text,
+                    message: d.flatten_message_text(),
                     ..Default::default()
                 }
             }).collect::<Vec<_>>()
@@ -101,7 +108,19 @@ impl RscriptLspServer {
 
 #[tower_lsp::async_trait]
 impl LanguageServer for RscriptLspServer {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        // Documents are checked with the options of the workspace's tsconfig.json
+        let root = params.workspace_folders.as_ref()
+            .and_then(|folders| folders.first())
+            .map(|folder| &folder.uri)
+            .or(params.root_uri.as_ref())
+            .and_then(|uri| uri.to_file_path().ok());
+        let compiler_options = root
+            .and_then(|root| rscript_tsoptions::parse_tsconfig_file(&root.join("tsconfig.json").to_string_lossy()).ok())
+            .and_then(|config| config.compiler_options);
+        if let Some(compiler_options) = compiler_options {
+            self.language_service.lock().unwrap().set_compiler_options(compiler_options);
+        }
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Options(
@@ -6,7 +6,7 @@
 //! - Bundler (for bundler-like resolution)
 //! - Classic (TypeScript's original resolution)
 
-use rscript_tspath::Extension;
+use rscript_tspath::{combine_paths, get_directory_path, Extension};
 use std::path::{Path, PathBuf};
 use std::collections::HashMap;
 use std::sync::Mutex;
@@ -152,6 +152,86 @@ pub fn resolve_module_name(
     result
 }
 
+/// Find the pattern matching a module name, as for ambient module
+/// declarations like `declare module "*.svg"`. An exact pattern wins;
+/// otherwise the wildcard pattern (one `*`) with the longest prefix does.
+pub fn match_pattern_or_exact<'p>(
+    patterns: impl IntoIterator<Item = &'p str>,
+    module_name: &str,
+) -> Option<&'p str> {
+    let mut best: Option<(&'p str, usize)> = None;
+    for pattern in patterns {
+        if pattern == module_name {
+            return Some(pattern);
+        }
+        let Some((prefix, suffix)) = pattern.split_once('*') else { continue };
+        let matches = module_name.len() >= prefix.len() + suffix.len()
+            && module_name.starts_with(prefix)
+            && module_name.ends_with(suffix);
+        if matches && best.is_none_or(|(_, len)| prefix.len() > len) {
+            best = Some((pattern, prefix.len()));
+        }
+    }
+    best.map(|(pattern, _)| pattern)
+}
+
+/// Find the file among `file_names` that a relative module name imported
+/// by `containing_file` refers to, as a module resolution over the files of
+/// a program rather than the disk would.
+pub fn resolve_program_file<'f>(
+    module_name: &str,
+    containing_file: &str,
+    file_names: impl IntoIterator<Item = &'f str>,
+) -> Option<&'f str> {
+    if !module_name.starts_with("./") && !module_name.starts_with("../") {
+        return None;
+    }
+    let candidate = resolve_relative_path(&get_directory_path(containing_file), module_name);
+    let candidate = candidate.strip_suffix(".js").unwrap_or(&candidate);
+    let candidates = [
+        candidate.to_string(),
+        format!("{candidate}.ts"),
+        format!("{candidate}.tsx"),
+        format!("{candidate}.d.ts"),
+        format!("{candidate}/index.ts"),
+        format!("{candidate}/index.tsx"),
+        format!("{candidate}/index.d.ts"),
+    ];
+    file_names.into_iter().find(|file_name| {
+        if is_normalized_path(file_name) {
+            candidates.iter().any(|candidate| candidate == file_name)
+        } else {
+            candidates.contains(&resolve_relative_path("", file_name))
+        }
+    })
+}
+
+/// Whether a path has no `.` or `..` segments and no empty segments
+/// besides a leading `/`, so that resolving it leaves it unchanged.
+fn is_normalized_path(path: &str) -> bool {
+    path.split('/').enumerate().all(|(i, segment)| match segment {
+        "." | ".." => false,
+        "" => i == 0 && path.len() > 1,
+        _ => true,
+    })
+}
+
+/// Join `relative` onto the directory `base`, resolving `.` and `..`
+/// segments.
+fn resolve_relative_path(base: &str, relative: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    let combined = combine_paths(base, relative);
+    for segment in combined.split('/') {
+        match segment {
+            "." | "" => {}
+            ".." if segments.last().is_some_and(|last| *last != "..") => { segments.pop(); }
+            _ => segments.push(segment),
+        }
+    }
+    let path = segments.join("/");
+    if combined.starts_with('/') { format!("/{path}") } else { path }
+}
+
 /// Try to resolve using tsconfig paths mappings.
 fn try_path_mappings(
     module_name: &str,
@@ -581,7 +661,7 @@ fn resolve_node_modules_esm(
                                     // Subpath export: "package/subpath"
                                     if let Some(export_path) = resolve_conditional_exports(
                                         exports,
-                                        &subpath,
+                                        subpath,
                                         is_esm_context,
                                         &package_dir,
                                     ) {
@@ -991,6 +1071,16 @@ mod tests {
         assert_eq!(detect_extension("foo.jsx"), Extension::Jsx);
     }
 
+    #[test]
+    fn test_match_pattern_or_exact() {
+        let patterns = ["*.svg", "icons/*.svg", "lodash", "*"];
+        assert_eq!(match_pattern_or_exact(patterns, "lodash"), Some("lodash"));
+        assert_eq!(match_pattern_or_exact(patterns, "./logo.svg"), Some("*.svg"));
+        assert_eq!(match_pattern_or_exact(patterns, "icons/home.svg"), Some("icons/*.svg"));
+        assert_eq!(match_pattern_or_exact(patterns, "react"), Some("*"));
+        assert_eq!(match_pattern_or_exact(["*.svg"], "logo.png"), None);
+    }
+
     #[test]
     fn test_is_excluded() {
         assert!(is_excluded("/project/node_modules/foo", &["**/node_modules/**".to_string()]));
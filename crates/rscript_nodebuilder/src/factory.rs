@@ -0,0 +1,523 @@
+//! Construction of synthesized type nodes.
+//!
+//! Synthesized nodes have no source position. Like the nodes tsc's factory
+//! creates, they are parenthesized where their parent requires it, so that
+//! printing them gives text that parses back to the same type.
+
+use bumpalo::Bump;
+use rscript_ast::node::*;
+use rscript_ast::syntax_kind::SyntaxKind;
+use rscript_ast::types::{ModifierFlags, TokenFlags};
+use rscript_core::intern::{InternedString, StringInterner};
+
+/// Creates type nodes in an arena.
+pub struct NodeFactory<'a> {
+    arena: &'a Bump,
+    interner: &'a StringInterner,
+}
+
+impl<'a> NodeFactory<'a> {
+    pub fn new(arena: &'a Bump, interner: &'a StringInterner) -> Self {
+        Self { arena, interner }
+    }
+
+    fn data(kind: SyntaxKind) -> NodeData {
+        NodeData::new(kind, 0, 0)
+    }
+
+    fn alloc<T>(&self, value: T) -> &'a T {
+        self.arena.alloc(value)
+    }
+
+    fn list<T>(&self, items: Vec<T>) -> NodeList<'a, T> {
+        self.arena.alloc_slice_fill_iter(items)
+    }
+
+    fn token(kind: SyntaxKind) -> Token {
+        Token::new(kind, 0, 0)
+    }
+
+    // ========================================================================
+    // Names
+    // ========================================================================
+
+    pub fn identifier(&self, text: &str) -> Identifier {
+        Identifier {
+            data: Self::data(SyntaxKind::Identifier),
+            text: InternedString::dummy(),
+            text_name: text.to_string(),
+            original_keyword_kind: None,
+        }
+    }
+
+    /// An entity name from dotted text: `N.C` becomes a qualified name.
+    pub fn entity_name(&self, text: &str) -> EntityName<'a> {
+        let mut parts = text.split('.');
+        let mut name = EntityName::Identifier(self.identifier(parts.next().unwrap_or_default()));
+        for part in parts {
+            name = EntityName::QualifiedName(self.alloc(QualifiedName {
+                data: Self::data(SyntaxKind::QualifiedName),
+                left: name,
+                right: self.identifier(part),
+            }));
+        }
+        name
+    }
+
+    /// A property name, quoted unless it is an identifier or a number.
+    pub fn property_name(&self, text: &str) -> PropertyName<'a> {
+        if is_identifier_text(text) {
+            PropertyName::Identifier(self.identifier(text))
+        } else if is_numeric_text(text) {
+            PropertyName::NumericLiteral(self.numeric_literal(text))
+        } else {
+            PropertyName::StringLiteral(self.string_literal(text, false))
+        }
+    }
+
+    // ========================================================================
+    // Literals
+    // ========================================================================
+
+    fn string_literal(&self, value: &str, is_single_quote: bool) -> StringLiteral {
+        StringLiteral {
+            data: Self::data(SyntaxKind::StringLiteral),
+            text: InternedString::dummy(),
+            text_name: value.to_string(),
+            is_single_quote,
+        }
+    }
+
+    fn numeric_literal(&self, text: &str) -> NumericLiteral {
+        NumericLiteral {
+            data: Self::data(SyntaxKind::NumericLiteral),
+            text: InternedString::dummy(),
+            text_name: text.to_string(),
+            numeric_literal_flags: TokenFlags::NONE,
+        }
+    }
+
+    fn literal_type(&self, literal: Expression<'a>) -> TypeNode<'a> {
+        TypeNode::LiteralType(LiteralTypeNode {
+            data: Self::data(SyntaxKind::LiteralType),
+            literal: self.alloc(literal),
+        })
+    }
+
+    pub fn string_literal_type(&self, value: &str, is_single_quote: bool) -> TypeNode<'a> {
+        self.literal_type(Expression::StringLiteral(self.string_literal(value, is_single_quote)))
+    }
+
+    /// A number literal type; negative numbers are a minus applied to the
+    /// literal.
+    pub fn numeric_literal_type(&self, text: &str) -> TypeNode<'a> {
+        let literal = match text.strip_prefix('-') {
+            Some(magnitude) => Expression::PrefixUnary(PrefixUnaryExpression {
+                data: Self::data(SyntaxKind::PrefixUnaryExpression),
+                operator: SyntaxKind::MinusToken,
+                operand: self.alloc(Expression::NumericLiteral(self.numeric_literal(magnitude))),
+            }),
+            None => Expression::NumericLiteral(self.numeric_literal(text)),
+        };
+        self.literal_type(literal)
+    }
+
+    /// A bigint literal type, from its text including the `n` suffix.
+    pub fn bigint_literal_type(&self, text: &str) -> TypeNode<'a> {
+        self.literal_type(Expression::BigIntLiteral(BigIntLiteral {
+            data: Self::data(SyntaxKind::BigIntLiteral),
+            text: self.interner.intern(text),
+        }))
+    }
+
+    pub fn boolean_literal_type(&self, value: bool) -> TypeNode<'a> {
+        self.literal_type(if value {
+            Expression::TrueKeyword(Self::data(SyntaxKind::TrueKeyword))
+        } else {
+            Expression::FalseKeyword(Self::data(SyntaxKind::FalseKeyword))
+        })
+    }
+
+    // ========================================================================
+    // Types
+    // ========================================================================
+
+    pub fn keyword_type(&self, kind: SyntaxKind) -> TypeNode<'a> {
+        TypeNode::KeywordType(KeywordTypeNode { data: Self::data(kind) })
+    }
+
+    pub fn this_type(&self) -> TypeNode<'a> {
+        TypeNode::ThisType(ThisTypeNode { data: Self::data(SyntaxKind::ThisType) })
+    }
+
+    pub fn type_reference(&self, type_name: EntityName<'a>, type_arguments: Vec<TypeNode<'a>>) -> TypeNode<'a> {
+        TypeNode::TypeReference(TypeReferenceNode {
+            data: Self::data(SyntaxKind::TypeReference),
+            type_name,
+            type_arguments: if type_arguments.is_empty() { None } else { Some(self.list(type_arguments)) },
+        })
+    }
+
+    pub fn type_query(&self, expr_name: EntityName<'a>) -> TypeNode<'a> {
+        TypeNode::TypeQuery(TypeQueryNode {
+            data: Self::data(SyntaxKind::TypeQuery),
+            expr_name,
+            type_arguments: None,
+        })
+    }
+
+    pub fn parenthesized_type(&self, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        TypeNode::ParenthesizedType(ParenthesizedTypeNode {
+            data: Self::data(SyntaxKind::ParenthesizedType),
+            type_node: self.alloc(type_node),
+        })
+    }
+
+    pub fn array_type(&self, element_type: TypeNode<'a>) -> TypeNode<'a> {
+        TypeNode::ArrayType(ArrayTypeNode {
+            data: Self::data(SyntaxKind::ArrayType),
+            element_type: self.alloc(self.parenthesize_postfix_operand(element_type)),
+        })
+    }
+
+    pub fn tuple_type(&self, elements: Vec<TypeNode<'a>>) -> TypeNode<'a> {
+        TypeNode::TupleType(TupleTypeNode {
+            data: Self::data(SyntaxKind::TupleType),
+            elements: self.list(elements),
+        })
+    }
+
+    pub fn optional_type(&self, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        TypeNode::OptionalType(OptionalTypeNode {
+            data: Self::data(SyntaxKind::OptionalType),
+            type_node: self.alloc(self.parenthesize_postfix_operand(type_node)),
+        })
+    }
+
+    pub fn rest_type(&self, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        TypeNode::RestType(RestTypeNode {
+            data: Self::data(SyntaxKind::RestType),
+            type_node: self.alloc(type_node),
+        })
+    }
+
+    pub fn named_tuple_member(&self, is_rest: bool, name: &str, is_optional: bool, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        TypeNode::NamedTupleMember(NamedTupleMemberNode {
+            data: Self::data(SyntaxKind::NamedTupleMember),
+            dot_dot_dot_token: is_rest.then(|| Self::token(SyntaxKind::DotDotDotToken)),
+            name: self.identifier(name),
+            question_token: is_optional.then(|| Self::token(SyntaxKind::QuestionToken)),
+            type_node: self.alloc(type_node),
+        })
+    }
+
+    pub fn union_type(&self, types: Vec<TypeNode<'a>>) -> TypeNode<'a> {
+        let types = types.into_iter().map(|t| self.parenthesize_union_constituent(t)).collect();
+        TypeNode::UnionType(UnionTypeNode {
+            data: Self::data(SyntaxKind::UnionType),
+            types: self.list(types),
+        })
+    }
+
+    pub fn intersection_type(&self, types: Vec<TypeNode<'a>>) -> TypeNode<'a> {
+        let types = types.into_iter().map(|t| self.parenthesize_intersection_constituent(t)).collect();
+        TypeNode::IntersectionType(IntersectionTypeNode {
+            data: Self::data(SyntaxKind::IntersectionType),
+            types: self.list(types),
+        })
+    }
+
+    /// A `keyof`, `readonly` or `unique` type operator.
+    pub fn type_operator(&self, operator: SyntaxKind, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        TypeNode::TypeOperator(TypeOperatorNode {
+            data: Self::data(SyntaxKind::TypeOperator),
+            operator,
+            type_node: self.alloc(self.parenthesize_type_operator_operand(type_node)),
+        })
+    }
+
+    pub fn indexed_access_type(&self, object_type: TypeNode<'a>, index_type: TypeNode<'a>) -> TypeNode<'a> {
+        TypeNode::IndexedAccessType(IndexedAccessTypeNode {
+            data: Self::data(SyntaxKind::IndexedAccessType),
+            object_type: self.alloc(self.parenthesize_postfix_operand(object_type)),
+            index_type: self.alloc(index_type),
+        })
+    }
+
+    pub fn conditional_type(
+        &self,
+        check_type: TypeNode<'a>,
+        extends_type: TypeNode<'a>,
+        true_type: TypeNode<'a>,
+        false_type: TypeNode<'a>,
+    ) -> TypeNode<'a> {
+        let check_type = match check_type {
+            TypeNode::FunctionType(_) | TypeNode::ConstructorType(_) | TypeNode::ConditionalType(_) => {
+                self.parenthesized_type(check_type)
+            }
+            _ => check_type,
+        };
+        let extends_type = match extends_type {
+            TypeNode::ConditionalType(_) => self.parenthesized_type(extends_type),
+            _ => extends_type,
+        };
+        TypeNode::ConditionalType(ConditionalTypeNode {
+            data: Self::data(SyntaxKind::ConditionalType),
+            check_type: self.alloc(check_type),
+            extends_type: self.alloc(extends_type),
+            true_type: self.alloc(true_type),
+            false_type: self.alloc(false_type),
+        })
+    }
+
+    pub fn function_type(
+        &self,
+        type_parameters: Vec<TypeParameterDeclaration<'a>>,
+        parameters: Vec<ParameterDeclaration<'a>>,
+        return_type: TypeNode<'a>,
+    ) -> TypeNode<'a> {
+        TypeNode::FunctionType(FunctionTypeNode {
+            data: Self::data(SyntaxKind::FunctionType),
+            type_parameters: self.type_parameter_list(type_parameters),
+            parameters: self.list(parameters),
+            return_type: Some(self.alloc(return_type)),
+        })
+    }
+
+    pub fn constructor_type(
+        &self,
+        type_parameters: Vec<TypeParameterDeclaration<'a>>,
+        parameters: Vec<ParameterDeclaration<'a>>,
+        return_type: TypeNode<'a>,
+    ) -> TypeNode<'a> {
+        TypeNode::ConstructorType(ConstructorTypeNode {
+            data: Self::data(SyntaxKind::ConstructorType),
+            type_parameters: self.type_parameter_list(type_parameters),
+            parameters: self.list(parameters),
+            return_type: Some(self.alloc(return_type)),
+        })
+    }
+
+    /// A type predicate: `x is T`, `asserts x is T` or `asserts x`.
+    pub fn type_predicate(&self, asserts: bool, parameter_name: Option<&str>, type_node: Option<TypeNode<'a>>) -> TypeNode<'a> {
+        TypeNode::TypePredicate(TypePredicateNode {
+            data: Self::data(SyntaxKind::TypePredicate),
+            asserts_modifier: asserts.then(|| Self::token(SyntaxKind::AssertsKeyword)),
+            parameter_name: match parameter_name {
+                Some(name) => TypePredicateParameterName::Identifier(self.identifier(name)),
+                None => TypePredicateParameterName::ThisType(ThisTypeNode { data: Self::data(SyntaxKind::ThisType) }),
+            },
+            type_node: type_node.map(|t| self.alloc(t)),
+        })
+    }
+
+    pub fn type_literal(&self, members: Vec<TypeElement<'a>>) -> TypeNode<'a> {
+        TypeNode::TypeLiteral(TypeLiteralNode {
+            data: Self::data(SyntaxKind::TypeLiteral),
+            members: self.list(members),
+        })
+    }
+
+    /// A mapped type. The modifiers are `ReadonlyKeyword` or
+    /// `QuestionToken` to add, `PlusToken` to add explicitly and
+    /// `MinusToken` to remove.
+    pub fn mapped_type(
+        &self,
+        readonly_modifier: Option<SyntaxKind>,
+        type_parameter: TypeParameterDeclaration<'a>,
+        name_type: Option<TypeNode<'a>>,
+        question_modifier: Option<SyntaxKind>,
+        type_node: TypeNode<'a>,
+    ) -> TypeNode<'a> {
+        TypeNode::MappedType(MappedTypeNode {
+            data: Self::data(SyntaxKind::MappedType),
+            readonly_token: readonly_modifier.map(Self::token),
+            type_parameter: self.alloc(type_parameter),
+            name_type: name_type.map(|t| self.alloc(t)),
+            question_token: question_modifier.map(Self::token),
+            type_node: Some(self.alloc(type_node)),
+            members: None,
+        })
+    }
+
+    /// A template literal type from its head text and each placeholder
+    /// with the text following it.
+    pub fn template_literal_type(&self, head_text: &str, spans: Vec<(TypeNode<'a>, String)>) -> TypeNode<'a> {
+        let spans = spans.into_iter()
+            .map(|(type_node, literal_text)| TemplateLiteralTypeSpan {
+                data: Self::data(SyntaxKind::TemplateLiteralTypeSpan),
+                type_node: self.alloc(type_node),
+                literal: Self::token(SyntaxKind::TemplateTail),
+                literal_text,
+            })
+            .collect();
+        TypeNode::TemplateLiteralType(TemplateLiteralTypeNode {
+            data: Self::data(SyntaxKind::TemplateLiteralType),
+            head: Self::token(SyntaxKind::TemplateHead),
+            head_text: head_text.to_string(),
+            template_spans: self.list(spans),
+        })
+    }
+
+    // ========================================================================
+    // Declarations
+    // ========================================================================
+
+    pub fn type_parameter(
+        &self,
+        name: &str,
+        constraint: Option<TypeNode<'a>>,
+        default: Option<TypeNode<'a>>,
+    ) -> TypeParameterDeclaration<'a> {
+        TypeParameterDeclaration {
+            data: Self::data(SyntaxKind::TypeParameter),
+            name: self.identifier(name),
+            constraint: constraint.map(|t| self.alloc(t)),
+            default: default.map(|t| self.alloc(t)),
+        }
+    }
+
+    fn type_parameter_list(
+        &self,
+        type_parameters: Vec<TypeParameterDeclaration<'a>>,
+    ) -> Option<NodeList<'a, TypeParameterDeclaration<'a>>> {
+        if type_parameters.is_empty() { None } else { Some(self.list(type_parameters)) }
+    }
+
+    pub fn parameter(&self, is_rest: bool, name: &str, is_optional: bool, type_node: TypeNode<'a>) -> ParameterDeclaration<'a> {
+        ParameterDeclaration {
+            data: Self::data(SyntaxKind::Parameter),
+            dot_dot_dot_token: is_rest.then(|| Self::token(SyntaxKind::DotDotDotToken)),
+            name: BindingName::Identifier(self.identifier(name)),
+            question_token: is_optional.then(|| Self::token(SyntaxKind::QuestionToken)),
+            type_annotation: Some(self.alloc(type_node)),
+            initializer: None,
+        }
+    }
+
+    /// A property signature; one without a type prints as just its name,
+    /// which is how elided members are shown.
+    pub fn property_signature(
+        &self,
+        is_readonly: bool,
+        name: PropertyName<'a>,
+        is_optional: bool,
+        type_node: Option<TypeNode<'a>>,
+    ) -> TypeElement<'a> {
+        let mut data = Self::data(SyntaxKind::PropertySignature);
+        if is_readonly {
+            data.modifier_flags |= ModifierFlags::READONLY;
+        }
+        TypeElement::PropertySignature(PropertySignatureNode {
+            data,
+            name,
+            question_token: is_optional.then(|| Self::token(SyntaxKind::QuestionToken)),
+            type_annotation: type_node.map(|t| self.alloc(t)),
+        })
+    }
+
+    pub fn call_signature(
+        &self,
+        type_parameters: Vec<TypeParameterDeclaration<'a>>,
+        parameters: Vec<ParameterDeclaration<'a>>,
+        return_type: TypeNode<'a>,
+    ) -> TypeElement<'a> {
+        TypeElement::CallSignature(CallSignatureNode {
+            data: Self::data(SyntaxKind::CallSignature),
+            type_parameters: self.type_parameter_list(type_parameters),
+            parameters: self.list(parameters),
+            return_type: Some(self.alloc(return_type)),
+        })
+    }
+
+    pub fn construct_signature(
+        &self,
+        type_parameters: Vec<TypeParameterDeclaration<'a>>,
+        parameters: Vec<ParameterDeclaration<'a>>,
+        return_type: TypeNode<'a>,
+    ) -> TypeElement<'a> {
+        TypeElement::ConstructSignature(ConstructSignatureNode {
+            data: Self::data(SyntaxKind::ConstructSignature),
+            type_parameters: self.type_parameter_list(type_parameters),
+            parameters: self.list(parameters),
+            return_type: Some(self.alloc(return_type)),
+        })
+    }
+
+    pub fn index_signature(
+        &self,
+        is_readonly: bool,
+        parameter: ParameterDeclaration<'a>,
+        type_node: TypeNode<'a>,
+    ) -> TypeElement<'a> {
+        let mut data = Self::data(SyntaxKind::IndexSignature);
+        if is_readonly {
+            data.modifier_flags |= ModifierFlags::READONLY;
+        }
+        TypeElement::IndexSignature(IndexSignatureNode {
+            data,
+            parameters: self.list(vec![parameter]),
+            type_annotation: Some(self.alloc(type_node)),
+        })
+    }
+
+    // ========================================================================
+    // Parenthesization
+    // ========================================================================
+
+    /// Parenthesize the operand of `[]`, `?` or an indexed access.
+    fn parenthesize_postfix_operand(&self, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        match type_node {
+            TypeNode::FunctionType(_)
+            | TypeNode::ConstructorType(_)
+            | TypeNode::ConditionalType(_)
+            | TypeNode::UnionType(_)
+            | TypeNode::IntersectionType(_)
+            | TypeNode::InferType(_)
+            | TypeNode::TypeOperator(_) => self.parenthesized_type(type_node),
+            _ => type_node,
+        }
+    }
+
+    fn parenthesize_type_operator_operand(&self, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        match type_node {
+            TypeNode::FunctionType(_)
+            | TypeNode::ConstructorType(_)
+            | TypeNode::ConditionalType(_)
+            | TypeNode::UnionType(_)
+            | TypeNode::IntersectionType(_) => self.parenthesized_type(type_node),
+            _ => type_node,
+        }
+    }
+
+    fn parenthesize_union_constituent(&self, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        match type_node {
+            TypeNode::FunctionType(_)
+            | TypeNode::ConstructorType(_)
+            | TypeNode::ConditionalType(_)
+            | TypeNode::UnionType(_) => self.parenthesized_type(type_node),
+            _ => type_node,
+        }
+    }
+
+    fn parenthesize_intersection_constituent(&self, type_node: TypeNode<'a>) -> TypeNode<'a> {
+        match type_node {
+            TypeNode::IntersectionType(_) => self.parenthesized_type(type_node),
+            _ => self.parenthesize_union_constituent(type_node),
+        }
+    }
+}
+
+/// Whether text can be written as an identifier.
+fn is_identifier_text(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Whether text is a number as JavaScript would print it, which a property
+/// name can be written as without quotes.
+fn is_numeric_text(text: &str) -> bool {
+    text.bytes().all(|b| b.is_ascii_digit() || b == b'.')
+        && text.parse::<f64>().is_ok_and(|value| value.is_finite() && value.to_string() == text)
+}
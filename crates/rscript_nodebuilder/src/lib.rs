@@ -3,11 +3,120 @@
 //! Creates AST nodes for type display in error messages and
 //! declaration emit (.d.ts generation).
 
-pub struct NodeBuilder;
+mod factory;
+
+pub use factory::NodeFactory;
+
+use bumpalo::Bump;
+use rscript_ast::node::{TypeElement, TypeNode};
+use rscript_core::intern::StringInterner;
+use rscript_printer::Printer;
+
+/// How long the text of a type may grow, approximately, before the rest of
+/// its members are elided.
+pub const DEFAULT_MAXIMUM_TRUNCATION_LENGTH: usize = 160;
+
+/// The same limit with truncation turned off (noErrorTruncation), which
+/// only guards against runaway output.
+pub const NO_TRUNCATION_MAXIMUM_TRUNCATION_LENGTH: usize = 1_000_000;
+
+bitflags::bitflags! {
+    /// Options for building the type node of a type.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct NodeBuilderFlags: u32 {
+        const NONE = 0;
+        /// Print types in full rather than eliding members past the
+        /// truncation length.
+        const NO_TRUNCATION = 1 << 0;
+        /// Write array types as `Array<T>` rather than `T[]`.
+        const WRITE_ARRAY_AS_GENERIC_TYPE = 1 << 1;
+        /// Quote string literal types with `'` rather than `"`.
+        const USE_SINGLE_QUOTES_FOR_STRING_LITERAL_TYPE = 1 << 2;
+        /// Build an alias declaration's own type rather than the alias name.
+        const IN_TYPE_ALIAS = 1 << 3;
+    }
+}
+
+/// The state of building one type node: its options and how long its text
+/// has grown so far.
+#[derive(Debug, Clone)]
+pub struct NodeBuilderContext {
+    pub flags: NodeBuilderFlags,
+    /// The approximate length of the text of the nodes built so far.
+    pub approximate_length: usize,
+    truncating: bool,
+}
+
+impl NodeBuilderContext {
+    pub fn new(flags: NodeBuilderFlags) -> Self {
+        Self { flags, approximate_length: 0, truncating: false }
+    }
+
+    /// Whether the text has grown past the truncation length, after which
+    /// lists of members are elided. Once truncating, it stays so.
+    pub fn check_truncation_length(&mut self) -> bool {
+        if !self.truncating {
+            let maximum = if self.flags.contains(NodeBuilderFlags::NO_TRUNCATION) {
+                NO_TRUNCATION_MAXIMUM_TRUNCATION_LENGTH
+            } else {
+                DEFAULT_MAXIMUM_TRUNCATION_LENGTH
+            };
+            self.truncating = self.approximate_length > maximum;
+        }
+        self.truncating
+    }
+}
+
+/// Builds and prints synthesized nodes.
+pub struct NodeBuilder {
+    /// Interns the text of synthesized nodes that need it, for printing.
+    interner: StringInterner,
+}
 
 impl NodeBuilder {
     pub fn new() -> Self {
-        Self
+        Self { interner: StringInterner::new() }
+    }
+
+    /// A factory creating nodes in `arena` that this builder can print.
+    pub fn factory<'a>(&'a self, arena: &'a Bump) -> NodeFactory<'a> {
+        NodeFactory::new(arena, &self.interner)
+    }
+
+    /// Print a type node on one line.
+    pub fn print_type_node(&self, node: &TypeNode<'_>) -> String {
+        Printer::new(&self.interner).print_type(node)
+    }
+
+    /// Print a type member on one line, without its trailing `;`.
+    pub fn print_type_element(&self, node: &TypeElement<'_>) -> String {
+        Printer::new(&self.interner).print_type_member(node)
+    }
+
+    /// Cut the text of a type at twice the truncation length, which bounds
+    /// it even when elision left it long.
+    ///
+    /// # Example
+    /// ```
+    /// use rscript_nodebuilder::NodeBuilder;
+    /// let text = NodeBuilder::truncate_type_text("x".repeat(400), false);
+    /// assert_eq!(text.len(), 320);
+    /// assert!(text.ends_with("..."));
+    /// ```
+    pub fn truncate_type_text(text: String, no_truncation: bool) -> String {
+        let maximum = 2 * if no_truncation {
+            NO_TRUNCATION_MAXIMUM_TRUNCATION_LENGTH
+        } else {
+            DEFAULT_MAXIMUM_TRUNCATION_LENGTH
+        };
+        if text.len() < maximum {
+            return text;
+        }
+        let mut end = maximum - "...".len();
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...", &text[..end])
     }
 
     /// Generate a type declaration string from a name and type.
@@ -102,6 +211,45 @@ impl Default for NodeBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rscript_ast::node::EntityName;
+    use rscript_ast::syntax_kind::SyntaxKind;
+
+    #[test]
+    fn test_print_synthesized_type_nodes() {
+        let builder = NodeBuilder::new();
+        let arena = Bump::new();
+        let f = builder.factory(&arena);
+        let function = f.function_type(Vec::new(), Vec::new(), f.keyword_type(SyntaxKind::VoidKeyword));
+        let union = f.union_type(vec![function, f.numeric_literal_type("-1"), f.bigint_literal_type("10n")]);
+        assert_eq!(builder.print_type_node(&union), "(() => void) | -1 | 10n");
+
+        let element = f.union_type(vec![f.keyword_type(SyntaxKind::StringKeyword), f.boolean_literal_type(true)]);
+        let array = f.type_operator(SyntaxKind::ReadonlyKeyword, f.array_type(element));
+        assert_eq!(builder.print_type_node(&array), "readonly (string | true)[]");
+
+        let literal = f.type_literal(vec![
+            f.property_signature(true, f.property_name("a-b"), true, Some(f.string_literal_type("x", true))),
+            f.property_signature(false, f.property_name("0"), false, Some(f.type_reference(f.entity_name("N.C"), Vec::new()))),
+        ]);
+        assert_eq!(builder.print_type_node(&literal), "{ readonly \"a-b\"?: 'x'; 0: N.C; }");
+
+        let elided = f.type_reference(EntityName::Identifier(f.identifier("... 3 more ...")), Vec::new());
+        assert_eq!(builder.print_type_node(&elided), "... 3 more ...");
+    }
+
+    #[test]
+    fn test_truncation_length() {
+        let mut context = NodeBuilderContext::new(NodeBuilderFlags::NONE);
+        context.approximate_length = DEFAULT_MAXIMUM_TRUNCATION_LENGTH + 1;
+        assert!(context.check_truncation_length());
+        context.approximate_length = 0;
+        assert!(context.check_truncation_length());
+
+        let mut context = NodeBuilderContext::new(NodeBuilderFlags::NO_TRUNCATION);
+        context.approximate_length = DEFAULT_MAXIMUM_TRUNCATION_LENGTH + 1;
+        assert!(!context.check_truncation_length());
+        assert_eq!(NodeBuilder::truncate_type_text("x".repeat(400), true).len(), 400);
+    }
 
     #[test]
     fn test_build_declaration() {
@@ -39,6 +39,42 @@ fn alloc_vec_in<T>(arena: &Bump, vec: Vec<T>) -> &[T] {
     slice
 }
 
+/// Mark a function expression or arrow function parsed after `async` as async.
+fn with_async_modifier(mut expr: Expression<'_>) -> Expression<'_> {
+    match &mut expr {
+        Expression::FunctionExpression(n) => n.data.modifier_flags |= ModifierFlags::ASYNC,
+        Expression::ArrowFunction(n) => n.data.modifier_flags |= ModifierFlags::ASYNC,
+        _ => {}
+    }
+    expr
+}
+
+/// Find the last `@ts-check` or `@ts-nocheck` pragma in the comments before
+/// the first token, which starts at character `first_token_pos`.
+fn get_check_js_directive(source_text: &str, first_token_pos: usize) -> Option<CheckJsDirective> {
+    let mut directive = None;
+    let mut line_start = 0;
+    let leading: Vec<char> = source_text.chars().take(first_token_pos).collect();
+    for line in leading.split(|&ch| ch == '\n') {
+        let text: String = line.iter().collect();
+        let pragma = text.trim_start().trim_start_matches(['/', '*']).trim_start();
+        let enabled = if pragma.starts_with("@ts-check") {
+            Some(true)
+        } else if pragma.starts_with("@ts-nocheck") {
+            Some(false)
+        } else {
+            None
+        };
+        let name_len = if enabled == Some(true) { "@ts-check".len() } else { "@ts-nocheck".len() };
+        if let Some(enabled) = enabled.filter(|_| pragma[name_len..].chars().next().is_none_or(char::is_whitespace)) {
+            let end = line_start + line.len();
+            directive = Some(CheckJsDirective { enabled, range: rscript_core::text::TextRange::new(line_start as u32, end as u32) });
+        }
+        line_start += line.len() + 1;
+    }
+    directive
+}
+
 /// The parser produces a SourceFile AST from TypeScript source text.
 pub struct Parser<'a> {
     arena: &'a Bump,
@@ -46,6 +82,8 @@ pub struct Parser<'a> {
     file_name: String,
     source_text: String,
     context_flags: NodeFlags,
+    /// Whether `<` starts a JSX element (.tsx and .jsx files).
+    language_variant: LanguageVariant,
     diagnostics: DiagnosticCollection,
     /// Tracks recursion depth to prevent stack overflow on deeply nested input.
     recursion_depth: u32,
@@ -54,12 +92,18 @@ pub struct Parser<'a> {
 impl<'a> Parser<'a> {
     pub fn new(arena: &'a Bump, file_name: &str, source_text: &str) -> Self {
         let scanner = Scanner::new(source_text);
+        let language_variant = if file_name.ends_with(".tsx") || file_name.ends_with(".jsx") {
+            LanguageVariant::JSX
+        } else {
+            LanguageVariant::Standard
+        };
         Self {
             arena,
             scanner,
             file_name: file_name.to_string(),
             source_text: source_text.to_string(),
             context_flags: NodeFlags::NONE,
+            language_variant,
             diagnostics: DiagnosticCollection::new(),
             recursion_depth: 0,
         }
@@ -68,6 +112,7 @@ impl<'a> Parser<'a> {
     pub fn parse_source_file(mut self) -> SourceFile<'a> {
         self.scanner.skip_shebang();
         self.next_token();
+        let check_js_directive = get_check_js_directive(&self.source_text, self.token_pos() as usize);
 
         let pos = 0u32;
         let statements = self.parse_statements();
@@ -79,7 +124,7 @@ impl<'a> Parser<'a> {
         let is_ts = self.file_name.ends_with(".ts") || is_tsx;
         let is_dts = self.file_name.ends_with(".d.ts");
 
-        let language_variant = if is_tsx || is_jsx { LanguageVariant::JSX } else { LanguageVariant::Standard };
+        let language_variant = self.language_variant;
         let script_kind = if is_tsx { ScriptKind::TSX } else if is_jsx { ScriptKind::JSX } else if is_ts { ScriptKind::TS } else { ScriptKind::JS };
 
         SourceFile {
@@ -92,6 +137,8 @@ impl<'a> Parser<'a> {
             script_kind,
             is_declaration_file: is_dts,
             has_no_default_lib: false,
+            comment_directives: self.scanner.take_comment_directives(),
+            check_js_directive,
         }
     }
 
@@ -167,6 +214,16 @@ impl<'a> Parser<'a> {
         ));
     }
 
+    fn error_at(&mut self, pos: u32, end: u32, msg: &rscript_diagnostics::DiagnosticMessage, args: &[&str]) {
+        let span = rscript_core::text::TextSpan::from_bounds(pos, end);
+        self.diagnostics.add(rscript_diagnostics::Diagnostic::with_location(
+            self.file_name.clone(),
+            span,
+            msg,
+            args,
+        ));
+    }
+
     /// Check if identifier text matches (without interning - using scanner token_value).
     fn is_identifier_text(&self, text: &str) -> bool {
         self.current_token() == SyntaxKind::Identifier && self.token_value() == text
@@ -259,6 +316,7 @@ impl<'a> Parser<'a> {
             SyntaxKind::TypeKeyword => self.parse_type_alias_declaration(),
             SyntaxKind::EnumKeyword => self.parse_enum_declaration(),
             SyntaxKind::NamespaceKeyword => self.parse_module_declaration(),
+            SyntaxKind::ModuleKeyword if self.is_module_declaration() => self.parse_module_declaration(),
             SyntaxKind::ExportKeyword => self.parse_export_declaration_or_assignment(),
             SyntaxKind::ImportKeyword => self.parse_import_declaration(),
             SyntaxKind::ThrowKeyword => self.parse_throw_statement(),
@@ -291,6 +349,17 @@ impl<'a> Parser<'a> {
         result
     }
 
+    /// Look ahead: `module` followed by a name on the same line starts a
+    /// module declaration rather than an expression like `module.exports`.
+    fn is_module_declaration(&mut self) -> bool {
+        let saved = self.scanner.save_state();
+        let next = self.scanner.scan();
+        let result = !self.scanner.has_preceding_line_break()
+            && matches!(next, SyntaxKind::Identifier | SyntaxKind::StringLiteral);
+        self.scanner.restore_state(saved);
+        result
+    }
+
     /// Look ahead: `await using` is an await-using variable declaration (TS 5.2+).
     fn is_await_using(&mut self) -> bool {
         let saved = self.scanner.save_state();
@@ -318,7 +387,41 @@ impl<'a> Parser<'a> {
     /// Parse `const enum Foo { ... }` — skip `const` and delegate to enum parser.
     fn parse_const_enum_declaration(&mut self) -> Statement<'a> {
         self.next_token(); // skip 'const'
-        self.parse_enum_declaration()
+        let mut declaration = self.parse_enum_declaration();
+        declaration.data_mut().modifier_flags |= ModifierFlags::CONST;
+        declaration
+    }
+
+    /// Look ahead: `<` after a callee starts a type argument list when it is
+    /// closed by a matching `>` that is immediately followed by `(`.
+    fn is_type_arguments_of_call(&mut self) -> bool {
+        let saved = self.scanner.save_state();
+        let mut angle_depth: u32 = 1;
+        let mut brace_depth: u32 = 0;
+        let result = loop {
+            let closed: u32 = match self.scanner.scan() {
+                SyntaxKind::LessThanToken => { angle_depth += 1; 0 }
+                SyntaxKind::GreaterThanToken => 1,
+                SyntaxKind::OpenBraceToken => { brace_depth += 1; 0 }
+                SyntaxKind::CloseBraceToken if brace_depth > 0 => { brace_depth -= 1; 0 }
+                SyntaxKind::SemicolonToken if brace_depth > 0 => 0,
+                SyntaxKind::CloseBraceToken
+                | SyntaxKind::SemicolonToken
+                | SyntaxKind::EqualsToken
+                | SyntaxKind::AmpersandAmpersandToken
+                | SyntaxKind::BarBarToken
+                | SyntaxKind::EndOfFileToken => break false,
+                _ => 0,
+            };
+            if closed > 0 {
+                angle_depth -= closed;
+                if angle_depth == 0 {
+                    break self.scanner.scan() == SyntaxKind::OpenParenToken;
+                }
+            }
+        };
+        self.scanner.restore_state(saved);
+        result
     }
 
     fn is_labeled_statement(&mut self) -> bool {
@@ -344,7 +447,9 @@ impl<'a> Parser<'a> {
             self.parse_left_hand_side_expression();
         }
 
-        // Skip modifiers: declare, abstract, async, export, default
+        // Modifiers: declare, abstract, async, export, default
+        let mut is_abstract = false;
+        let mut modifiers = ModifierFlags::NONE;
         while matches!(
             self.current_token(),
             SyntaxKind::DeclareKeyword
@@ -353,22 +458,48 @@ impl<'a> Parser<'a> {
                 | SyntaxKind::ExportKeyword
                 | SyntaxKind::DefaultKeyword
         ) {
+            is_abstract |= self.current_token() == SyntaxKind::AbstractKeyword;
+            modifiers |= match self.current_token() {
+                SyntaxKind::DeclareKeyword => ModifierFlags::AMBIENT,
+                SyntaxKind::AsyncKeyword => ModifierFlags::ASYNC,
+                SyntaxKind::ExportKeyword => ModifierFlags::EXPORT,
+                SyntaxKind::DefaultKeyword => ModifierFlags::DEFAULT,
+                _ => ModifierFlags::NONE,
+            };
             self.next_token();
         }
 
-        match self.current_token() {
+        let mut declaration = match self.current_token() {
             SyntaxKind::FunctionKeyword => self.parse_function_declaration(false),
-            SyntaxKind::ClassKeyword => self.parse_class_declaration(false),
+            SyntaxKind::ClassKeyword => self.parse_class_declaration(is_abstract),
             SyntaxKind::InterfaceKeyword => self.parse_interface_declaration(),
             SyntaxKind::EnumKeyword => self.parse_enum_declaration(),
+            SyntaxKind::ConstKeyword if self.is_const_enum() => self.parse_const_enum_declaration(),
             SyntaxKind::TypeKeyword => self.parse_type_alias_declaration(),
+            SyntaxKind::NamespaceKeyword | SyntaxKind::ModuleKeyword => self.parse_module_declaration(),
+            SyntaxKind::GlobalKeyword => {
+                let pos = self.token_pos();
+                Statement::ModuleDeclaration(self.parse_module_declaration_rest(pos, NodeFlags::GLOBAL_AUGMENTATION))
+            }
             SyntaxKind::VarKeyword | SyntaxKind::LetKeyword | SyntaxKind::ConstKeyword | SyntaxKind::UsingKeyword => self.parse_variable_statement(),
             _ => {
                 let end = self.token_end();
                 self.next_token();
                 Statement::MissingDeclaration(NodeData::new(SyntaxKind::MissingDeclaration, pos, end))
             }
-        }
+        };
+        declaration.data_mut().modifier_flags |= modifiers;
+        declaration
+    }
+
+    /// Parse the body of a function, in which `yield` is an expression only
+    /// if the function is a generator.
+    fn parse_function_block(&mut self, is_generator: bool) -> Block<'a> {
+        let saved_context = self.context_flags;
+        self.context_flags.set(NodeFlags::YIELD_CONTEXT, is_generator);
+        let block = self.parse_block();
+        self.context_flags = saved_context;
+        block
     }
 
     fn parse_block(&mut self) -> Block<'a> {
@@ -631,8 +762,8 @@ impl<'a> Parser<'a> {
         } else { None };
 
         // Check for `in` or `of`
-        if self.is_identifier_text("of") || self.current_token() == SyntaxKind::InKeyword {
-            let is_for_of = self.is_identifier_text("of");
+        if self.current_token() == SyntaxKind::OfKeyword || self.current_token() == SyntaxKind::InKeyword {
+            let is_for_of = self.current_token() == SyntaxKind::OfKeyword;
             self.next_token();
             let expression = self.parse_expression_and_alloc();
             self.expect_token(SyntaxKind::CloseParenToken);
@@ -859,7 +990,7 @@ impl<'a> Parser<'a> {
         let (parameters, return_type) = self.parse_parameter_list_and_return_type();
 
         let body = if self.current_token() == SyntaxKind::OpenBraceToken {
-            Some(self.parse_block())
+            Some(self.parse_function_block(asterisk_token.is_some()))
         } else {
             self.parse_expected_semicolon();
             None
@@ -871,7 +1002,7 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_class_declaration(&mut self, _is_abstract: bool) -> Statement<'a> {
+    fn parse_class_declaration(&mut self, is_abstract: bool) -> Statement<'a> {
         let pos = self.token_pos();
         self.expect_token(SyntaxKind::ClassKeyword);
         let name = if self.current_token() == SyntaxKind::Identifier || self.current_token().is_keyword() {
@@ -881,9 +1012,12 @@ impl<'a> Parser<'a> {
         let heritage_clauses = self.parse_heritage_clauses();
         let members = self.parse_class_members();
         let end = self.token_end();
+        let mut data = NodeData::new(SyntaxKind::ClassDeclaration, pos, end);
+        if is_abstract {
+            data.modifier_flags |= ModifierFlags::ABSTRACT;
+        }
         Statement::ClassDeclaration(ClassDeclaration {
-            data: NodeData::new(SyntaxKind::ClassDeclaration, pos, end),
-            name, type_parameters, heritage_clauses, members,
+            data, name, type_parameters, heritage_clauses, members,
         })
     }
 
@@ -946,28 +1080,37 @@ impl<'a> Parser<'a> {
         }
 
         // Parse modifiers (public, private, protected, static, abstract, readonly, override, accessor)
-        let mut _is_static = false;
-        let mut _is_abstract = false;
-        let mut _is_readonly = false;
+        let mut modifiers = ModifierFlags::NONE;
         loop {
-            match self.current_token() {
-                SyntaxKind::PublicKeyword | SyntaxKind::PrivateKeyword | SyntaxKind::ProtectedKeyword => { self.next_token(); }
-                SyntaxKind::StaticKeyword => { _is_static = true; self.next_token(); }
-                SyntaxKind::AbstractKeyword => { _is_abstract = true; self.next_token(); }
-                SyntaxKind::ReadonlyKeyword => { _is_readonly = true; self.next_token(); }
-                SyntaxKind::OverrideKeyword | SyntaxKind::DeclareKeyword => { self.next_token(); }
-                SyntaxKind::AsyncKeyword => { self.next_token(); }
-                _ if self.is_identifier_text("accessor") => { self.next_token(); }
+            let flag = match self.current_token() {
+                SyntaxKind::PublicKeyword => ModifierFlags::PUBLIC,
+                SyntaxKind::PrivateKeyword => ModifierFlags::PRIVATE,
+                SyntaxKind::ProtectedKeyword => ModifierFlags::PROTECTED,
+                SyntaxKind::StaticKeyword => ModifierFlags::STATIC,
+                SyntaxKind::AbstractKeyword => ModifierFlags::ABSTRACT,
+                SyntaxKind::ReadonlyKeyword => ModifierFlags::READONLY,
+                SyntaxKind::OverrideKeyword => ModifierFlags::OVERRIDE,
+                SyntaxKind::DeclareKeyword => ModifierFlags::AMBIENT,
+                SyntaxKind::AsyncKeyword => ModifierFlags::ASYNC,
+                _ if self.is_identifier_text("accessor") => ModifierFlags::ACCESSOR,
                 _ => break,
-            }
+            };
+            modifiers |= flag;
+            self.next_token();
         }
+        let mut element = self.parse_class_member_after_modifiers(pos);
+        element.data_mut().modifier_flags |= modifiers;
+        element
+    }
+
+    fn parse_class_member_after_modifiers(&mut self, pos: u32) -> ClassElement<'a> {
 
         // constructor
         if self.current_token() == SyntaxKind::ConstructorKeyword || self.is_identifier_text("constructor") {
             self.next_token();
             let type_params = self.try_parse_type_parameters();
             let (params, _ret) = self.parse_parameter_list_and_return_type();
-            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_block()) } else { self.parse_expected_semicolon(); None };
+            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_function_block(false)) } else { self.parse_expected_semicolon(); None };
             let end = self.token_end();
             return ClassElement::Constructor(ConstructorDeclaration {
                 data: NodeData::new(SyntaxKind::Constructor, pos, end),
@@ -975,34 +1118,33 @@ impl<'a> Parser<'a> {
             });
         }
 
-        // get/set accessor
-        if self.is_identifier_text("get") || self.current_token() == SyntaxKind::GetKeyword {
-            let next_is_paren = false; // simplified
-            if !next_is_paren {
-                self.next_token();
-                let name = self.parse_property_name();
-                let tp = self.try_parse_type_parameters();
-                let (params, ret) = self.parse_parameter_list_and_return_type();
-                let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_block()) } else { self.parse_expected_semicolon(); None };
-                let end = self.token_end();
-                return ClassElement::GetAccessor(GetAccessorDeclaration {
-                    data: NodeData::new(SyntaxKind::GetAccessor, pos, end),
-                    name, type_parameters: tp, parameters: params, return_type: ret, body,
-                });
-            }
-        }
-
-        if self.is_identifier_text("set") || self.current_token() == SyntaxKind::SetKeyword {
+        // get/set accessor, unless `get`/`set` is itself the member name
+        if self.is_start_of_accessor() {
+            let is_getter = self.is_identifier_text("get") || self.current_token() == SyntaxKind::GetKeyword;
             self.next_token();
             let name = self.parse_property_name();
             let tp = self.try_parse_type_parameters();
-            let (params, _ret) = self.parse_parameter_list_and_return_type();
-            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_block()) } else { self.parse_expected_semicolon(); None };
+            let (params, ret) = self.parse_parameter_list_and_return_type();
+            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_function_block(false)) } else { self.parse_expected_semicolon(); None };
             let end = self.token_end();
-            return ClassElement::SetAccessor(SetAccessorDeclaration {
-                data: NodeData::new(SyntaxKind::SetAccessor, pos, end),
-                name, type_parameters: tp, parameters: params, body,
-            });
+            return if is_getter {
+                ClassElement::GetAccessor(GetAccessorDeclaration {
+                    data: NodeData::new(SyntaxKind::GetAccessor, pos, end),
+                    name, type_parameters: tp, parameters: params, return_type: ret, body,
+                })
+            } else {
+                ClassElement::SetAccessor(SetAccessorDeclaration {
+                    data: NodeData::new(SyntaxKind::SetAccessor, pos, end),
+                    name, type_parameters: tp, parameters: params, return_type: ret, body,
+                })
+            };
+        }
+
+        // Index signature
+        if self.current_token() == SyntaxKind::OpenBracketToken && self.is_start_of_index_signature() {
+            let signature = self.parse_index_signature(pos);
+            self.parse_expected_semicolon();
+            return ClassElement::IndexSignature(signature);
         }
 
         // Generator method
@@ -1019,7 +1161,7 @@ impl<'a> Parser<'a> {
             // Method
             let tp = self.try_parse_type_parameters();
             let (params, ret) = self.parse_parameter_list_and_return_type();
-            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_block()) } else { self.parse_expected_semicolon(); None };
+            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_function_block(asterisk_token.is_some())) } else { self.parse_expected_semicolon(); None };
             let end = self.token_end();
             ClassElement::MethodDeclaration(MethodDeclaration {
                 data: NodeData::new(SyntaxKind::MethodDeclaration, pos, end),
@@ -1047,14 +1189,24 @@ impl<'a> Parser<'a> {
             SyntaxKind::StringLiteral => {
                 let pos = self.token_pos();
                 let end = self.token_end();
+                let value = self.token_value().to_string();
                 self.next_token();
-                PropertyName::StringLiteral(Token::new(SyntaxKind::StringLiteral, pos, end))
+                PropertyName::StringLiteral(StringLiteral {
+                    data: NodeData::new(SyntaxKind::StringLiteral, pos, end),
+                    text: InternedString::dummy(), text_name: value, is_single_quote: false,
+                })
             }
             SyntaxKind::NumericLiteral => {
                 let pos = self.token_pos();
                 let end = self.token_end();
+                let value = self.token_value().to_string();
                 self.next_token();
-                PropertyName::NumericLiteral(Token::new(SyntaxKind::NumericLiteral, pos, end))
+                PropertyName::NumericLiteral(NumericLiteral {
+                    data: NodeData::new(SyntaxKind::NumericLiteral, pos, end),
+                    text: InternedString::dummy(),
+                    text_name: value,
+                    numeric_literal_flags: TokenFlags::NONE,
+                })
             }
             SyntaxKind::OpenBracketToken => {
                 let pos = self.token_pos();
@@ -1110,52 +1262,18 @@ impl<'a> Parser<'a> {
     fn parse_type_member(&mut self) -> TypeElement<'a> {
         let pos = self.token_pos();
 
-        // Skip readonly
-        let _readonly = self.optional_token(SyntaxKind::ReadonlyKeyword).is_some();
+        let modifier_flags = if self.optional_token(SyntaxKind::ReadonlyKeyword).is_some() {
+            ModifierFlags::READONLY
+        } else {
+            ModifierFlags::NONE
+        };
 
-        // Index signature: [key: type]: type
-        if self.current_token() == SyntaxKind::OpenBracketToken {
-            // Could be index signature or computed property
-            self.next_token();
-            let param_name = self.parse_identifier();
-            if self.current_token() == SyntaxKind::ColonToken {
-                // Index signature: [key: type]: type
-                self.next_token();
-                let param_type = self.parse_type_and_alloc();
-                self.expect_token(SyntaxKind::CloseBracketToken);
-                let type_annotation = if self.optional_token(SyntaxKind::ColonToken).is_some() {
-                    Some(self.parse_type_and_alloc())
-                } else { None };
-                let end = self.token_end();
-                let param = ParameterDeclaration {
-                    data: NodeData::new(SyntaxKind::Parameter, pos, end),
-                    dot_dot_dot_token: None,
-                    name: BindingName::Identifier(param_name),
-                    question_token: None,
-                    type_annotation: Some(param_type),
-                    initializer: None,
-                };
-                return TypeElement::IndexSignature(IndexSignatureNode {
-                    data: NodeData::new(SyntaxKind::IndexSignature, pos, end),
-                    parameters: alloc_vec_in(self.arena, vec![param]),
-                    type_annotation,
-                });
-            }
-            // If we see 'in' keyword, this is a mapped type that should have been caught earlier.
-            // Skip until ] to recover.
-            if self.current_token() == SyntaxKind::InKeyword {
-                // Consume tokens until we hit ] to avoid infinite loop
-                while self.current_token() != SyntaxKind::CloseBracketToken
-                    && self.current_token() != SyntaxKind::CloseBraceToken
-                    && self.current_token() != SyntaxKind::EndOfFileToken
-                {
-                    self.next_token();
-                }
-            }
-            if self.current_token() == SyntaxKind::CloseBracketToken {
-                self.next_token();
-            }
-            // Fall through to property signature
+        // Index signature: [key: type]: type. Anything else in brackets is a
+        // computed property name and is left to parse_property_name.
+        if self.current_token() == SyntaxKind::OpenBracketToken && self.is_start_of_index_signature() {
+            let mut signature = self.parse_index_signature(pos);
+            signature.data.modifier_flags = modifier_flags;
+            return TypeElement::IndexSignature(signature);
         }
 
         let name = self.parse_property_name();
@@ -1176,8 +1294,10 @@ impl<'a> Parser<'a> {
                 Some(self.parse_type_and_alloc())
             } else { None };
             let end = self.token_end();
+            let mut data = NodeData::new(SyntaxKind::PropertySignature, pos, end);
+            data.modifier_flags = modifier_flags;
             TypeElement::PropertySignature(PropertySignatureNode {
-                data: NodeData::new(SyntaxKind::PropertySignature, pos, end),
+                data,
                 name, question_token, type_annotation,
             })
         }
@@ -1228,22 +1348,62 @@ impl<'a> Parser<'a> {
     fn parse_module_declaration(&mut self) -> Statement<'a> {
         let pos = self.token_pos();
         self.next_token(); // namespace/module keyword
+        Statement::ModuleDeclaration(self.parse_module_declaration_rest(pos, NodeFlags::NONE))
+    }
+
+    /// Parse a namespace name and its body. A dotted name `A.B.C` becomes
+    /// nested declarations, each inner one flagged `NESTED_NAMESPACE`. A
+    /// string name declares an ambient module, whose body may be omitted.
+    fn parse_module_declaration_rest(&mut self, pos: u32, flags: NodeFlags) -> ModuleDeclaration<'a> {
+        if self.current_token() == SyntaxKind::StringLiteral {
+            let name_pos = self.token_pos();
+            let name_end = self.token_end();
+            let value = self.token_value().to_string();
+            self.next_token();
+            let name = ModuleName::StringLiteral(StringLiteral {
+                data: NodeData::new(SyntaxKind::StringLiteral, name_pos, name_end),
+                text: InternedString::dummy(), text_name: value, is_single_quote: false,
+            });
+            let body = if self.current_token() == SyntaxKind::OpenBraceToken {
+                Some(self.parse_module_block())
+            } else {
+                self.parse_expected_semicolon();
+                None
+            };
+            let end = self.token_end();
+            return ModuleDeclaration { data: NodeData::new(SyntaxKind::ModuleDeclaration, pos, end), name, body };
+        }
         let name_id = self.parse_identifier();
+        if self.optional_token(SyntaxKind::DotToken).is_some() {
+            let inner_pos = self.token_pos();
+            let inner = self.parse_module_declaration_rest(inner_pos, NodeFlags::NESTED_NAMESPACE);
+            let end = self.token_end();
+            let mut data = NodeData::new(SyntaxKind::ModuleDeclaration, pos, end);
+            data.flags |= flags;
+            return ModuleDeclaration {
+                data,
+                name: ModuleName::Identifier(name_id),
+                body: Some(ModuleBody::ModuleDeclaration(self.arena.alloc(inner))),
+            };
+        }
         let body = if self.current_token() == SyntaxKind::OpenBraceToken {
-            let block_pos = self.token_pos();
-            self.expect_token(SyntaxKind::OpenBraceToken);
-            let stmts = self.parse_statements();
-            let block_end = self.token_end();
-            self.expect_token(SyntaxKind::CloseBraceToken);
-            Some(ModuleBody::ModuleBlock(ModuleBlock {
-                data: NodeData::new(SyntaxKind::ModuleBlock, block_pos, block_end),
-                statements: stmts,
-            }))
+            Some(self.parse_module_block())
         } else { None };
         let end = self.token_end();
-        Statement::ModuleDeclaration(ModuleDeclaration {
-            data: NodeData::new(SyntaxKind::ModuleDeclaration, pos, end),
-            name: ModuleName::Identifier(name_id), body,
+        let mut data = NodeData::new(SyntaxKind::ModuleDeclaration, pos, end);
+        data.flags |= flags;
+        ModuleDeclaration { data, name: ModuleName::Identifier(name_id), body }
+    }
+
+    fn parse_module_block(&mut self) -> ModuleBody<'a> {
+        let block_pos = self.token_pos();
+        self.expect_token(SyntaxKind::OpenBraceToken);
+        let stmts = self.parse_statements();
+        let block_end = self.token_end();
+        self.expect_token(SyntaxKind::CloseBraceToken);
+        ModuleBody::ModuleBlock(ModuleBlock {
+            data: NodeData::new(SyntaxKind::ModuleBlock, block_pos, block_end),
+            statements: stmts,
         })
     }
 
@@ -1274,6 +1434,16 @@ impl<'a> Parser<'a> {
             false
         };
 
+        // import x = require("module") or import x = N.y
+        if self.current_token() == SyntaxKind::Identifier {
+            let saved = self.scanner.save_state();
+            let next = self.scanner.scan();
+            self.scanner.restore_state(saved);
+            if next == SyntaxKind::EqualsToken {
+                return self.parse_import_equals_declaration(pos, is_type_only_import);
+            }
+        }
+
         // Side-effect import: import 'module'
         if self.current_token() == SyntaxKind::StringLiteral {
             let module_specifier = self.parse_expression_and_alloc();
@@ -1303,6 +1473,37 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_import_equals_declaration(&mut self, pos: u32, is_type_only: bool) -> Statement<'a> {
+        let name = self.parse_identifier();
+        self.expect_token(SyntaxKind::EqualsToken);
+        let is_external_module_reference = self.current_token() == SyntaxKind::RequireKeyword && {
+            let saved = self.scanner.save_state();
+            let next = self.scanner.scan();
+            self.scanner.restore_state(saved);
+            next == SyntaxKind::OpenParenToken
+        };
+        let module_reference = if is_external_module_reference {
+            let reference_pos = self.token_pos();
+            self.next_token(); // consume 'require'
+            self.expect_token(SyntaxKind::OpenParenToken);
+            let expression = self.parse_expression_and_alloc();
+            let reference_end = self.token_end();
+            self.expect_token(SyntaxKind::CloseParenToken);
+            ModuleReference::ExternalModuleReference(ExternalModuleReference {
+                data: NodeData::new(SyntaxKind::ExternalModuleReference, reference_pos, reference_end),
+                expression,
+            })
+        } else {
+            ModuleReference::EntityName(self.parse_entity_name())
+        };
+        let end = self.token_end();
+        self.parse_expected_semicolon();
+        Statement::ImportEqualsDeclaration(ImportEqualsDeclaration {
+            data: NodeData::new(SyntaxKind::ImportEqualsDeclaration, pos, end),
+            is_type_only, name, module_reference,
+        })
+    }
+
     fn parse_import_clause(&mut self) -> ImportClause<'a> {
         self.parse_import_clause_inner(false)
     }
@@ -1364,30 +1565,14 @@ impl<'a> Parser<'a> {
         let mut elements = Vec::new();
         while self.current_token() != SyntaxKind::CloseBraceToken && self.current_token() != SyntaxKind::EndOfFileToken {
             let spos = self.token_pos();
-            // Per-specifier type-only: `import { type Foo }` (TS 4.5+)
-            // Check if current token is `type` followed by an identifier (not `as` or `}`)
-            let is_type_only = if self.is_identifier_text("type") {
-                let saved = self.scanner.save_state();
-                let next = self.scanner.scan();
-                let is_type_spec = matches!(next, SyntaxKind::Identifier)
-                    || next.is_keyword(); // `type default` etc.
-                self.scanner.restore_state(saved);
-                if is_type_spec {
-                    self.next_token(); // consume `type`
-                    true
-                } else {
-                    false
-                }
-            } else {
-                false
-            };
+            let is_type_only = self.parse_type_only_specifier_modifier();
             let first = self.parse_identifier();
             let (property_name, name) = if self.optional_token(SyntaxKind::AsKeyword).is_some() {
                 (Some(first), self.parse_identifier())
             } else {
                 (None, first)
             };
-            let send = self.token_end();
+            let send = name.data.range.end;
             elements.push(ImportSpecifier {
                 data: NodeData::new(SyntaxKind::ImportSpecifier, spos, send),
                 is_type_only, property_name, name,
@@ -1402,6 +1587,25 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Per-specifier type-only modifier: `import { type Foo }` and
+    /// `export { type Foo }` (TS 4.5+). `type` is the modifier when a name
+    /// follows it, not `as` or `}`.
+    fn parse_type_only_specifier_modifier(&mut self) -> bool {
+        if self.current_token() != SyntaxKind::TypeKeyword {
+            return false;
+        }
+        let saved = self.scanner.save_state();
+        let next = self.scanner.scan();
+        // `type as X` names a binding called `type`
+        let is_type_spec = matches!(next, SyntaxKind::Identifier)
+            || next.is_keyword() && next != SyntaxKind::AsKeyword; // `type default` etc.
+        self.scanner.restore_state(saved);
+        if is_type_spec {
+            self.next_token(); // consume `type`
+        }
+        is_type_spec
+    }
+
     fn parse_export_declaration_or_assignment(&mut self) -> Statement<'a> {
         let pos = self.token_pos();
         self.expect_token(SyntaxKind::ExportKeyword);
@@ -1409,11 +1613,14 @@ impl<'a> Parser<'a> {
         // export default
         if self.current_token() == SyntaxKind::DefaultKeyword {
             self.next_token();
-            if self.current_token() == SyntaxKind::FunctionKeyword {
-                return self.parse_function_declaration(false);
-            }
-            if self.current_token() == SyntaxKind::ClassKeyword {
-                return self.parse_class_declaration(false);
+            if matches!(self.current_token(), SyntaxKind::FunctionKeyword | SyntaxKind::ClassKeyword) {
+                let mut declaration = if self.current_token() == SyntaxKind::FunctionKeyword {
+                    self.parse_function_declaration(false)
+                } else {
+                    self.parse_class_declaration(false)
+                };
+                declaration.data_mut().modifier_flags |= ModifierFlags::EXPORT_DEFAULT;
+                return declaration;
             }
             let expr = self.parse_assignment_expression_and_alloc();
             let end = self.token_end();
@@ -1488,13 +1695,15 @@ impl<'a> Parser<'a> {
             self.parse_expected_semicolon();
             return Statement::ExportDeclaration(ExportDeclaration {
                 data: NodeData::new(SyntaxKind::ExportDeclaration, pos, end),
-                is_type_only: false, export_clause,
+                is_type_only: is_type_only_export, export_clause,
                 module_specifier: Some(module_specifier), attributes: None,
             });
         }
 
         // export declaration (function, class, var, etc.)
-        self.parse_statement()
+        let mut declaration = self.parse_statement();
+        declaration.data_mut().modifier_flags |= ModifierFlags::EXPORT;
+        declaration
     }
 
     fn parse_named_exports(&mut self) -> NamedExports<'a> {
@@ -1503,6 +1712,7 @@ impl<'a> Parser<'a> {
         let mut elements = Vec::new();
         while self.current_token() != SyntaxKind::CloseBraceToken && self.current_token() != SyntaxKind::EndOfFileToken {
             let spos = self.token_pos();
+            let is_type_only = self.parse_type_only_specifier_modifier();
             let first = self.parse_identifier();
             let (property_name, name) = if self.optional_token(SyntaxKind::AsKeyword).is_some() {
                 (Some(first), self.parse_identifier())
@@ -1512,7 +1722,7 @@ impl<'a> Parser<'a> {
             let send = self.token_end();
             elements.push(ExportSpecifier {
                 data: NodeData::new(SyntaxKind::ExportSpecifier, spos, send),
-                is_type_only: false, property_name, name,
+                is_type_only, property_name, name,
             });
             if self.optional_token(SyntaxKind::CommaToken).is_none() { break; }
         }
@@ -1547,7 +1757,12 @@ impl<'a> Parser<'a> {
                 data: NodeData::new(SyntaxKind::TypeParameter, tpos, tend),
                 name, constraint, default,
             });
-            if self.optional_token(SyntaxKind::CommaToken).is_none() { break; }
+            // A trailing comma is allowed, as in the `<T,>` of a generic arrow in a `.tsx` file.
+            if self.optional_token(SyntaxKind::CommaToken).is_none()
+                || self.current_token() == SyntaxKind::GreaterThanToken
+            {
+                break;
+            }
         }
         self.expect_token(SyntaxKind::GreaterThanToken);
         Some(alloc_vec_in(self.arena, params))
@@ -1575,22 +1790,25 @@ impl<'a> Parser<'a> {
         self.expect_token(SyntaxKind::CloseParenToken);
         let parameters = alloc_vec_in(self.arena, params);
         let return_type = if self.optional_token(SyntaxKind::ColonToken).is_some() {
-            Some(self.parse_type_and_alloc())
+            Some(self.parse_return_type_and_alloc())
         } else { None };
         (parameters, return_type)
     }
 
     fn parse_parameter(&mut self) -> ParameterDeclaration<'a> {
         let pos = self.token_pos();
-        // Skip modifiers (public, private, protected, readonly, override)
-        while matches!(
-            self.current_token(),
-            SyntaxKind::PublicKeyword
-                | SyntaxKind::PrivateKeyword
-                | SyntaxKind::ProtectedKeyword
-                | SyntaxKind::ReadonlyKeyword
-                | SyntaxKind::OverrideKeyword
-        ) {
+        // Parameter property modifiers (public, private, protected, readonly, override)
+        let mut modifiers = ModifierFlags::NONE;
+        loop {
+            let flag = match self.current_token() {
+                SyntaxKind::PublicKeyword => ModifierFlags::PUBLIC,
+                SyntaxKind::PrivateKeyword => ModifierFlags::PRIVATE,
+                SyntaxKind::ProtectedKeyword => ModifierFlags::PROTECTED,
+                SyntaxKind::ReadonlyKeyword => ModifierFlags::READONLY,
+                SyntaxKind::OverrideKeyword => ModifierFlags::OVERRIDE,
+                _ => break,
+            };
+            modifiers |= flag;
             self.next_token();
         }
         let dot_dot_dot_token = self.optional_token(SyntaxKind::DotDotDotToken);
@@ -1603,9 +1821,10 @@ impl<'a> Parser<'a> {
             Some(self.parse_assignment_expression_and_alloc())
         } else { None };
         let end = self.token_end();
+        let mut data = NodeData::new(SyntaxKind::Parameter, pos, end);
+        data.modifier_flags = modifiers;
         ParameterDeclaration {
-            data: NodeData::new(SyntaxKind::Parameter, pos, end),
-            dot_dot_dot_token, name, question_token, type_annotation, initializer,
+            data, dot_dot_dot_token, name, question_token, type_annotation, initializer,
         }
     }
 
@@ -1618,6 +1837,69 @@ impl<'a> Parser<'a> {
         self.arena.alloc(ty)
     }
 
+    /// Parse the return type of a signature, which may be a type predicate:
+    /// `x is T`, `this is T`, `asserts x` or `asserts x is T`.
+    fn parse_return_type_and_alloc(&mut self) -> &'a TypeNode<'a> {
+        let pos = self.token_pos();
+        let asserts_modifier = if self.current_token() == SyntaxKind::AssertsKeyword && self.is_asserts_predicate() {
+            self.optional_token(SyntaxKind::AssertsKeyword)
+        } else {
+            None
+        };
+        if asserts_modifier.is_none() && !self.is_type_predicate_prefix() {
+            return self.parse_type_and_alloc();
+        }
+        let parameter_name = if self.current_token() == SyntaxKind::ThisKeyword {
+            let this_pos = self.token_pos();
+            let this_end = self.token_end();
+            self.next_token();
+            TypePredicateParameterName::ThisType(ThisTypeNode { data: NodeData::new(SyntaxKind::ThisType, this_pos, this_end) })
+        } else {
+            TypePredicateParameterName::Identifier(self.parse_identifier())
+        };
+        let type_node = if self.optional_token(SyntaxKind::IsKeyword).is_some() {
+            Some(self.parse_type_and_alloc())
+        } else {
+            None
+        };
+        let end = match (type_node, &parameter_name) {
+            (Some(type_node), _) => type_node.data().range.end,
+            (None, TypePredicateParameterName::Identifier(id)) => id.data.range.end,
+            (None, TypePredicateParameterName::ThisType(this)) => this.data.range.end,
+        };
+        self.arena.alloc(TypeNode::TypePredicate(TypePredicateNode {
+            data: NodeData::new(SyntaxKind::TypePredicate, pos, end),
+            asserts_modifier,
+            parameter_name,
+            type_node,
+        }))
+    }
+
+    /// Look ahead: `asserts` followed on the same line by a parameter name
+    /// or `this` starts an assertion predicate.
+    fn is_asserts_predicate(&mut self) -> bool {
+        let saved = self.scanner.save_state();
+        let next = self.scanner.scan();
+        let result = !self.scanner.has_preceding_line_break()
+            && (next == SyntaxKind::Identifier || next == SyntaxKind::ThisKeyword || next.is_keyword());
+        self.scanner.restore_state(saved);
+        result
+    }
+
+    /// Look ahead: a parameter name or `this` followed on the same line by
+    /// `is` starts a type predicate.
+    fn is_type_predicate_prefix(&mut self) -> bool {
+        let current = self.current_token();
+        if current != SyntaxKind::Identifier && current != SyntaxKind::ThisKeyword && !current.is_keyword() {
+            return false;
+        }
+        let saved = self.scanner.save_state();
+        let next = self.scanner.scan();
+        let result = next == SyntaxKind::IsKeyword && !self.scanner.has_preceding_line_break();
+        self.scanner.restore_state(saved);
+        result
+    }
+
     fn parse_type(&mut self) -> TypeNode<'a> {
         self.recursion_depth += 1;
         if self.recursion_depth > MAX_RECURSION_DEPTH {
@@ -1745,7 +2027,9 @@ impl<'a> Parser<'a> {
             | SyntaxKind::AnyKeyword | SyntaxKind::VoidKeyword | SyntaxKind::NeverKeyword
             | SyntaxKind::UndefinedKeyword | SyntaxKind::NullKeyword | SyntaxKind::UnknownKeyword
             | SyntaxKind::ObjectKeyword | SyntaxKind::BigIntKeyword | SyntaxKind::SymbolKeyword
-            | SyntaxKind::IntrinsicKeyword => {
+            | SyntaxKind::IntrinsicKeyword
+            // `const` in `as const` assertions
+            | SyntaxKind::ConstKeyword => {
                 let pos = self.token_pos();
                 let kind = self.current_token();
                 let end = self.token_end();
@@ -1933,6 +2217,26 @@ impl<'a> Parser<'a> {
         self.expect_token(SyntaxKind::OpenBracketToken);
         let mut elements = Vec::new();
         while self.current_token() != SyntaxKind::CloseBracketToken && self.current_token() != SyntaxKind::EndOfFileToken {
+            // Labeled member: `name: T`, `name?: T` or `...name: T`
+            if self.is_start_of_named_tuple_member() {
+                let mpos = self.token_pos();
+                let dot_dot_dot_token = self.optional_token(SyntaxKind::DotDotDotToken);
+                let name = self.parse_identifier();
+                let question_token = self.optional_token(SyntaxKind::QuestionToken);
+                self.expect_token(SyntaxKind::ColonToken);
+                let inner = self.parse_type();
+                let inner_ref = self.arena.alloc(inner);
+                let mend = self.token_end();
+                elements.push(TypeNode::NamedTupleMember(NamedTupleMemberNode {
+                    data: NodeData::new(SyntaxKind::NamedTupleMember, mpos, mend),
+                    dot_dot_dot_token,
+                    name,
+                    question_token,
+                    type_node: inner_ref,
+                }));
+                if self.optional_token(SyntaxKind::CommaToken).is_none() { break; }
+                continue;
+            }
             // Handle rest type: ...T
             if self.current_token() == SyntaxKind::DotDotDotToken {
                 let rpos = self.token_pos();
@@ -1970,6 +2274,64 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Whether the current tuple element is labeled (`name:`, `name?:` or
+    /// `...name:`), decided by scanning ahead without consuming tokens.
+    /// Parse `[key: K]: T` after any modifiers; `pos` is the member start.
+    fn parse_index_signature(&mut self, pos: u32) -> IndexSignatureNode<'a> {
+        self.expect_token(SyntaxKind::OpenBracketToken);
+        let param_name = self.parse_identifier();
+        self.expect_token(SyntaxKind::ColonToken);
+        let param_type = self.parse_type_and_alloc();
+        self.expect_token(SyntaxKind::CloseBracketToken);
+        let type_annotation = if self.optional_token(SyntaxKind::ColonToken).is_some() {
+            Some(self.parse_type_and_alloc())
+        } else { None };
+        let end = self.token_end();
+        let param = ParameterDeclaration {
+            data: NodeData::new(SyntaxKind::Parameter, pos, end),
+            dot_dot_dot_token: None,
+            name: BindingName::Identifier(param_name),
+            question_token: None,
+            type_annotation: Some(param_type),
+            initializer: None,
+        };
+        IndexSignatureNode {
+            data: NodeData::new(SyntaxKind::IndexSignature, pos, end),
+            parameters: alloc_vec_in(self.arena, vec![param]),
+            type_annotation,
+        }
+    }
+
+    /// Disambiguate `[key: T]` (index signature) from `[expr]` (computed
+    /// property name) at the start of a type member.
+    fn is_start_of_index_signature(&mut self) -> bool {
+        let saved = self.scanner.save_state();
+        let tok = self.scanner.scan();
+        let result = (tok == SyntaxKind::Identifier || tok.is_keyword())
+            && self.scanner.scan() == SyntaxKind::ColonToken;
+        self.scanner.restore_state(saved);
+        result
+    }
+
+    fn is_start_of_named_tuple_member(&mut self) -> bool {
+        let saved = self.scanner.save_state();
+        let mut tok = self.current_token();
+        if tok == SyntaxKind::DotDotDotToken {
+            tok = self.scanner.scan();
+        }
+        let result = if tok == SyntaxKind::Identifier || tok.is_keyword() {
+            match self.scanner.scan() {
+                SyntaxKind::ColonToken => true,
+                SyntaxKind::QuestionToken => self.scanner.scan() == SyntaxKind::ColonToken,
+                _ => false,
+            }
+        } else {
+            false
+        };
+        self.scanner.restore_state(saved);
+        result
+    }
+
     /// Disambiguate `(T)` (parenthesized type) from `(a: T) => U` (function type).
     ///
     /// Uses a scanner look-ahead: skip tokens to the matching `)`, then check
@@ -1985,7 +2347,7 @@ impl<'a> Parser<'a> {
             self.next_token();
             if self.current_token() == SyntaxKind::EqualsGreaterThanToken {
                 self.next_token();
-                let return_type = self.parse_type_and_alloc();
+                let return_type = self.parse_return_type_and_alloc();
                 let end = self.token_end();
                 return TypeNode::FunctionType(FunctionTypeNode {
                     data: NodeData::new(SyntaxKind::FunctionType, pos, end),
@@ -2018,7 +2380,7 @@ impl<'a> Parser<'a> {
             }
             self.expect_token(SyntaxKind::CloseParenToken);
             self.expect_token(SyntaxKind::EqualsGreaterThanToken);
-            let return_type = self.parse_type_and_alloc();
+            let return_type = self.parse_return_type_and_alloc();
             let parameters = alloc_vec_in(self.arena, params);
             let end = self.token_end();
             return TypeNode::FunctionType(FunctionTypeNode {
@@ -2271,16 +2633,19 @@ impl<'a> Parser<'a> {
         let pos = self.token_pos();
         if self.current_token() == SyntaxKind::NoSubstitutionTemplateLiteral {
             let end = self.token_end();
+            let head_text = self.token_value().to_string();
             self.next_token();
             return TypeNode::TemplateLiteralType(TemplateLiteralTypeNode {
                 data: NodeData::new(SyntaxKind::TemplateLiteralType, pos, end),
                 head: Token::new(SyntaxKind::TemplateHead, pos, end),
+                head_text,
                 template_spans: &[],
             });
         }
         // TemplateHead
         let head_end = self.token_end();
         let head = Token::new(SyntaxKind::TemplateHead, pos, head_end);
+        let head_text = self.token_value().to_string();
         self.next_token();
 
         let mut spans = Vec::new();
@@ -2288,23 +2653,24 @@ impl<'a> Parser<'a> {
             let spos = self.token_pos();
             let type_node = self.parse_type_and_alloc();
             // Expect } and scan template middle/tail
-            let lit_token = if self.current_token() == SyntaxKind::CloseBraceToken {
+            let (lit_token, literal_text) = if self.current_token() == SyntaxKind::CloseBraceToken {
                 let kind = self.scanner.rescan_template_token();
                 let lpos = self.token_pos();
                 let lend = self.token_end();
+                let text = self.token_value().to_string();
                 self.next_token();
-                Token::new(kind, lpos, lend)
+                (Token::new(kind, lpos, lend), text)
             } else {
                 let lpos = self.token_pos();
                 let lend = self.token_end();
                 self.next_token();
-                Token::new(SyntaxKind::TemplateTail, lpos, lend)
+                (Token::new(SyntaxKind::TemplateTail, lpos, lend), String::new())
             };
             let is_tail = lit_token.data.kind == SyntaxKind::TemplateTail;
             let send = self.token_end();
             spans.push(TemplateLiteralTypeSpan {
                 data: NodeData::new(SyntaxKind::TemplateLiteralTypeSpan, spos, send),
-                type_node, literal: lit_token,
+                type_node, literal: lit_token, literal_text,
             });
             if is_tail || self.current_token() == SyntaxKind::EndOfFileToken { break; }
         }
@@ -2312,7 +2678,7 @@ impl<'a> Parser<'a> {
         let end = self.token_end();
         TypeNode::TemplateLiteralType(TemplateLiteralTypeNode {
             data: NodeData::new(SyntaxKind::TemplateLiteralType, pos, end),
-            head, template_spans: alloc_vec_in(self.arena, spans),
+            head, head_text, template_spans: alloc_vec_in(self.arena, spans),
         })
     }
 
@@ -2451,6 +2817,20 @@ impl<'a> Parser<'a> {
                 break;
             }
 
+            // `x as T` binds like a relational operator but takes a type operand
+            if self.current_token() == SyntaxKind::AsKeyword {
+                let pos = left.data().range.pos;
+                self.next_token();
+                let type_node = self.parse_type_and_alloc();
+                let expr_ref = self.arena.alloc(left);
+                let end = self.token_end();
+                left = Expression::As(AsExpression {
+                    data: NodeData::new(SyntaxKind::AsExpression, pos, end),
+                    expression: expr_ref, type_node,
+                });
+                continue;
+            }
+
             let pos = left.data().range.pos;
             let op_token = Token::new(self.current_token(), self.token_pos(), self.token_end());
             self.next_token();
@@ -2648,6 +3028,19 @@ impl<'a> Parser<'a> {
                         type_arguments: None, arguments,
                     });
                 }
+                SyntaxKind::LessThanToken if self.is_type_arguments_of_call() => {
+                    // f<T>(...)
+                    let pos = expr.data().range.pos;
+                    let type_arguments = self.try_parse_type_arguments();
+                    let arguments = self.parse_argument_list();
+                    let end = self.token_end();
+                    let expr_ref = self.arena.alloc(expr);
+                    expr = Expression::Call(CallExpression {
+                        data: NodeData::new(SyntaxKind::CallExpression, pos, end),
+                        expression: expr_ref, question_dot_token: None,
+                        type_arguments, arguments,
+                    });
+                }
                 SyntaxKind::NoSubstitutionTemplateLiteral | SyntaxKind::TemplateHead => {
                     // Tagged template
                     let pos = expr.data().range.pos;
@@ -2778,6 +3171,13 @@ impl<'a> Parser<'a> {
             SyntaxKind::NullKeyword => { let pos = self.token_pos(); let end = self.token_end(); self.next_token(); Expression::NullKeyword(NodeData::new(SyntaxKind::NullKeyword, pos, end)) }
             SyntaxKind::ThisKeyword => { let pos = self.token_pos(); let end = self.token_end(); self.next_token(); Expression::ThisKeyword(NodeData::new(SyntaxKind::ThisKeyword, pos, end)) }
             SyntaxKind::SuperKeyword => { let pos = self.token_pos(); let end = self.token_end(); self.next_token(); Expression::SuperKeyword(NodeData::new(SyntaxKind::SuperKeyword, pos, end)) }
+            SyntaxKind::LessThanToken if self.is_generic_arrow_function() => {
+                let pos = self.token_pos();
+                self.parse_parenthesized_arrow_function(pos)
+            }
+            SyntaxKind::LessThanToken if self.language_variant == LanguageVariant::JSX => {
+                self.parse_jsx_element_or_self_closing_element_or_fragment(true)
+            }
             SyntaxKind::OpenParenToken => self.parse_parenthesized_expression(),
             SyntaxKind::OpenBracketToken => self.parse_array_literal(),
             SyntaxKind::OpenBraceToken => self.parse_object_literal(),
@@ -2802,20 +3202,21 @@ impl<'a> Parser<'a> {
                 let pos = self.token_pos();
                 self.next_token();
                 if self.current_token() == SyntaxKind::FunctionKeyword && !self.scanner.has_preceding_line_break() {
-                    return self.parse_function_expression();
+                    return with_async_modifier(self.parse_function_expression());
                 }
                 // Async arrow: async (params) => body
-                if self.current_token() == SyntaxKind::OpenParenToken && !self.scanner.has_preceding_line_break() {
-                    if self.is_parenthesized_arrow_function() {
-                        return self.parse_parenthesized_arrow_function(pos);
-                    }
+                if self.current_token() == SyntaxKind::OpenParenToken
+                    && !self.scanner.has_preceding_line_break()
+                    && self.is_parenthesized_arrow_function()
+                {
+                    return with_async_modifier(self.parse_parenthesized_arrow_function(pos));
                 }
                 // Async arrow: async x => body
                 if self.current_token() == SyntaxKind::Identifier && !self.scanner.has_preceding_line_break() {
                     let saved = self.scanner.save_state();
                     let id = self.parse_identifier();
                     if self.current_token() == SyntaxKind::EqualsGreaterThanToken && !self.scanner.has_preceding_line_break() {
-                        return self.parse_arrow_function_after_identifier(id);
+                        return with_async_modifier(self.parse_arrow_function_after_identifier(id));
                     }
                     // Not an arrow — restore and return `async` as identifier
                     self.scanner.restore_state(saved);
@@ -2826,6 +3227,14 @@ impl<'a> Parser<'a> {
                     original_keyword_kind: Some(SyntaxKind::AsyncKeyword),
                 })
             }
+            // Contextual keywords like `undefined`, `type` or `of` are identifiers here.
+            kind if kind.is_keyword() && kind as u16 > SyntaxKind::LAST_RESERVED_WORD as u16 => {
+                let id = self.parse_identifier();
+                if self.current_token() == SyntaxKind::EqualsGreaterThanToken && !self.scanner.has_preceding_line_break() {
+                    return self.parse_arrow_function_after_identifier(id);
+                }
+                Expression::Identifier(id)
+            }
             _ => self.parse_missing_expression(),
         }
     }
@@ -2961,6 +3370,46 @@ impl<'a> Parser<'a> {
         false
     }
 
+    /// Lookahead to determine if a `<` starts the type parameters of an arrow
+    /// function. Saves and restores scanner state.
+    fn is_generic_arrow_function(&mut self) -> bool {
+        let saved = self.scanner.save_state();
+        let result = self.is_generic_arrow_function_inner();
+        self.scanner.restore_state(saved);
+        result
+    }
+
+    fn is_generic_arrow_function_inner(&mut self) -> bool {
+        debug_assert_eq!(self.scanner.token(), SyntaxKind::LessThanToken);
+        if self.scanner.scan() != SyntaxKind::Identifier {
+            return false;
+        }
+        if self.language_variant == LanguageVariant::JSX {
+            // As in tsc, `<T,` and `<T extends U` start a generic arrow, while
+            // `<T extends=`, `<T extends>` and `<T extends/` are JSX elements
+            // with an `extends` attribute.
+            return match self.scanner.scan() {
+                SyntaxKind::CommaToken | SyntaxKind::EqualsToken => true,
+                SyntaxKind::ExtendsKeyword => !matches!(
+                    self.scanner.scan(),
+                    SyntaxKind::EqualsToken | SyntaxKind::GreaterThanToken | SyntaxKind::SlashToken
+                ),
+                _ => false,
+            };
+        }
+        // Skip to the matching `>`, then check for an arrow parameter list.
+        let mut depth: u32 = 1;
+        while depth > 0 {
+            match self.scanner.scan() {
+                SyntaxKind::LessThanToken => depth += 1,
+                SyntaxKind::GreaterThanToken => depth -= 1,
+                SyntaxKind::SemicolonToken | SyntaxKind::EndOfFileToken => return false,
+                _ => {}
+            }
+        }
+        self.scanner.scan() == SyntaxKind::OpenParenToken && self.is_parenthesized_arrow_function_inner()
+    }
+
     /// After `)` and `:`, skip past a type annotation and check if `=>` follows.
     fn skip_type_annotation_and_check_arrow(&mut self) -> bool {
         // We're past the `:` — skip tokens until we find `=>` or `{` at depth 0.
@@ -2975,7 +3424,7 @@ impl<'a> Parser<'a> {
                     depth += 1;
                 }
                 SyntaxKind::CloseParenToken | SyntaxKind::GreaterThanToken | SyntaxKind::CloseBracketToken => {
-                    if depth > 0 { depth -= 1; }
+                    depth = depth.saturating_sub(1);
                 }
                 SyntaxKind::OpenBraceToken if depth == 0 => return false,
                 SyntaxKind::SemicolonToken | SyntaxKind::EndOfFileToken => return false,
@@ -2996,17 +3445,20 @@ impl<'a> Parser<'a> {
         }
         self.expect_token(SyntaxKind::CloseParenToken);
         let return_type = if self.optional_token(SyntaxKind::ColonToken).is_some() {
-            Some(self.parse_type_and_alloc())
+            Some(self.parse_return_type_and_alloc())
         } else {
             None
         };
         let parameters = alloc_vec_in(self.arena, params);
         let eq_token = self.expect_token(SyntaxKind::EqualsGreaterThanToken);
         let body = if self.current_token() == SyntaxKind::OpenBraceToken {
-            let block = self.parse_block();
+            let block = self.parse_function_block(false);
             ArrowFunctionBody::Block(self.arena.alloc(block))
         } else {
+            let saved_context = self.context_flags;
+            self.context_flags.remove(NodeFlags::YIELD_CONTEXT);
             let expr = self.parse_assignment_expression();
+            self.context_flags = saved_context;
             ArrowFunctionBody::Expression(self.arena.alloc(expr))
         };
         let end = self.token_end();
@@ -3056,10 +3508,13 @@ impl<'a> Parser<'a> {
     fn parse_arrow_function_body(&mut self, pos: u32, parameters: &'a [ParameterDeclaration<'a>], return_type: Option<&'a TypeNode<'a>>) -> Expression<'a> {
         let eq_token = self.expect_token(SyntaxKind::EqualsGreaterThanToken);
         let body = if self.current_token() == SyntaxKind::OpenBraceToken {
-            let block = self.parse_block();
+            let block = self.parse_function_block(false);
             ArrowFunctionBody::Block(self.arena.alloc(block))
         } else {
+            let saved_context = self.context_flags;
+            self.context_flags.remove(NodeFlags::YIELD_CONTEXT);
             let expr = self.parse_assignment_expression();
+            self.context_flags = saved_context;
             ArrowFunctionBody::Expression(self.arena.alloc(expr))
         };
         let end = self.token_end();
@@ -3130,15 +3585,46 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Whether the current `get` or `set` starts an accessor: it must be
+    /// followed by the accessor's property name.
+    fn is_start_of_accessor(&mut self) -> bool {
+        if !(self.is_identifier_text("get") || self.is_identifier_text("set")
+            || matches!(self.current_token(), SyntaxKind::GetKeyword | SyntaxKind::SetKeyword))
+        {
+            return false;
+        }
+        let saved = self.scanner.save_state();
+        let tok = self.scanner.scan();
+        self.scanner.restore_state(saved);
+        tok == SyntaxKind::Identifier
+            || tok.is_keyword()
+            || matches!(tok, SyntaxKind::StringLiteral | SyntaxKind::NumericLiteral
+                | SyntaxKind::OpenBracketToken | SyntaxKind::HashToken)
+    }
+
     fn parse_object_literal_element(&mut self) -> ObjectLiteralElement<'a> {
         let pos = self.token_pos();
 
-        // Method: get/set accessor or async/generator method
-        if (self.is_identifier_text("get") || self.is_identifier_text("set"))
-            && self.current_token() == SyntaxKind::Identifier
-        {
-            // Could be get/set accessor or shorthand property
-            // Simplified: treat as property for now unless followed by identifier + (
+        // get/set accessor, unless `get`/`set` is itself the property name
+        if self.is_start_of_accessor() {
+            let is_getter = self.is_identifier_text("get") || self.current_token() == SyntaxKind::GetKeyword;
+            self.next_token();
+            let name = self.parse_property_name();
+            let tp = self.try_parse_type_parameters();
+            let (params, ret) = self.parse_parameter_list_and_return_type();
+            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_function_block(false)) } else { None };
+            let end = self.token_end();
+            return if is_getter {
+                ObjectLiteralElement::GetAccessor(GetAccessorDeclaration {
+                    data: NodeData::new(SyntaxKind::GetAccessor, pos, end),
+                    name, type_parameters: tp, parameters: params, return_type: ret, body,
+                })
+            } else {
+                ObjectLiteralElement::SetAccessor(SetAccessorDeclaration {
+                    data: NodeData::new(SyntaxKind::SetAccessor, pos, end),
+                    name, type_parameters: tp, parameters: params, return_type: ret, body,
+                })
+            };
         }
 
         let name = self.parse_property_name();
@@ -3147,7 +3633,7 @@ impl<'a> Parser<'a> {
             // Method
             let tp = self.try_parse_type_parameters();
             let (params, ret) = self.parse_parameter_list_and_return_type();
-            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_block()) } else { None };
+            let body = if self.current_token() == SyntaxKind::OpenBraceToken { Some(self.parse_function_block(false)) } else { None };
             let end = self.token_end();
             return ObjectLiteralElement::MethodDeclaration(MethodDeclaration {
                 data: NodeData::new(SyntaxKind::MethodDeclaration, pos, end),
@@ -3250,7 +3736,7 @@ impl<'a> Parser<'a> {
         } else { None };
         let type_parameters = self.try_parse_type_parameters();
         let (parameters, return_type) = self.parse_parameter_list_and_return_type();
-        let body = self.parse_block();
+        let body = self.parse_function_block(asterisk_token.is_some());
         let body_ref = self.arena.alloc(body);
         let end = self.token_end();
         Expression::FunctionExpression(FunctionExpression {
@@ -3292,6 +3778,313 @@ impl<'a> Parser<'a> {
             asterisk_token, expression,
         })
     }
+
+    // ========================================================================
+    // JSX
+    // ========================================================================
+
+    /// Parse a JSX element, self-closing element or fragment at `<`. In an
+    /// expression context the token after it is scanned as usual, and as a
+    /// child as JSX text.
+    fn parse_jsx_element_or_self_closing_element_or_fragment(&mut self, in_expression_context: bool) -> Expression<'a> {
+        let pos = self.token_pos();
+        self.next_token();
+        let result = if self.current_token() == SyntaxKind::GreaterThanToken {
+            let opening_fragment = NodeData::new(SyntaxKind::JsxOpeningFragment, pos, self.token_end());
+            self.scan_jsx_child_token();
+            let children = self.parse_jsx_children(None);
+            let closing_pos = self.token_pos();
+            self.next_token();
+            let end = self.parse_jsx_closing_greater_than(in_expression_context);
+            Expression::JsxFragment(JsxFragment {
+                data: NodeData::new(SyntaxKind::JsxFragment, pos, end),
+                opening_fragment,
+                children,
+                closing_fragment: NodeData::new(SyntaxKind::JsxClosingFragment, closing_pos, end),
+            })
+        } else {
+            let tag_name = self.parse_jsx_tag_name();
+            let type_arguments = self.try_parse_type_arguments();
+            let attributes = self.parse_jsx_attributes();
+            if self.current_token() == SyntaxKind::SlashToken {
+                self.next_token();
+                let end = self.parse_jsx_closing_greater_than(in_expression_context);
+                Expression::JsxSelfClosingElement(JsxSelfClosingElement {
+                    data: NodeData::new(SyntaxKind::JsxSelfClosingElement, pos, end),
+                    tag_name, type_arguments, attributes,
+                })
+            } else {
+                let opening_end = self.parse_jsx_closing_greater_than(false);
+                let tag_text = tag_name.text();
+                let opening_element = JsxOpeningElement {
+                    data: NodeData::new(SyntaxKind::JsxOpeningElement, pos, opening_end),
+                    tag_name, type_arguments, attributes,
+                };
+                let children = self.parse_jsx_children(Some(&opening_element.tag_name));
+                let closing_element = self.parse_jsx_closing_element(&tag_text, in_expression_context);
+                Expression::JsxElement(JsxElement {
+                    data: NodeData::new(SyntaxKind::JsxElement, pos, closing_element.data.range.end),
+                    opening_element: self.arena.alloc(opening_element),
+                    children,
+                    closing_element: self.arena.alloc(closing_element),
+                })
+            }
+        };
+        // Adjacent elements like `<a /><b />` are an error; they are parsed
+        // as a comma expression.
+        if in_expression_context && self.current_token() == SyntaxKind::LessThanToken && self.is_start_of_jsx_element() {
+            let sibling_pos = self.token_pos();
+            let sibling = self.parse_jsx_element_or_self_closing_element_or_fragment(true);
+            let sibling_end = sibling.data().range.end;
+            self.error_at(sibling_pos, sibling_end, &rscript_diagnostics::messages::JSX_EXPRESSIONS_MUST_HAVE_ONE_PARENT_ELEMENT, &[]);
+            let left = self.arena.alloc(result);
+            return Expression::Binary(BinaryExpression {
+                data: NodeData::new(SyntaxKind::BinaryExpression, pos, sibling_end),
+                left,
+                operator_token: Token::new(SyntaxKind::CommaToken, sibling_pos, sibling_pos),
+                right: self.arena.alloc(sibling),
+            });
+        }
+        result
+    }
+
+    /// Whether the `<` at the current token starts an element or fragment.
+    fn is_start_of_jsx_element(&mut self) -> bool {
+        let saved = self.scanner.save_state();
+        let next = self.next_token();
+        self.scanner.restore_state(saved);
+        next == SyntaxKind::Identifier || next == SyntaxKind::GreaterThanToken || next.is_keyword()
+    }
+
+    /// Scan the next token among the children of an element: text up to a
+    /// `{` or `<`, or else one of those or `</`.
+    fn scan_jsx_child_token(&mut self) -> SyntaxKind {
+        let saved = self.scanner.save_state();
+        if self.scanner.scan_jsx_text() == SyntaxKind::JsxText {
+            return SyntaxKind::JsxText;
+        }
+        self.scanner.restore_state(saved);
+        self.scanner.scan_jsx_token()
+    }
+
+    /// Parse the `>` ending a tag, and return the tag's end.
+    fn parse_jsx_closing_greater_than(&mut self, in_expression_context: bool) -> u32 {
+        if self.current_token() != SyntaxKind::GreaterThanToken {
+            self.error(&rscript_diagnostics::messages::_0_EXPECTED, &[">"]);
+            return self.token_pos();
+        }
+        let end = self.token_end();
+        if in_expression_context {
+            self.next_token();
+        } else {
+            self.scan_jsx_child_token();
+        }
+        end
+    }
+
+    /// Parse a tag name: an identifier (which may contain hyphens), `this`,
+    /// a property access like `Foo.Bar`, or a namespaced name.
+    fn parse_jsx_tag_name(&mut self) -> JsxTagName<'a> {
+        let pos = self.token_pos();
+        if self.current_token() == SyntaxKind::ThisKeyword {
+            let end = self.token_end();
+            self.next_token();
+            let mut expr = Expression::ThisKeyword(NodeData::new(SyntaxKind::ThisKeyword, pos, end));
+            expr = self.parse_jsx_tag_name_property_accesses(expr);
+            return JsxTagName::Expression(self.arena.alloc(expr));
+        }
+        self.scanner.rescan_jsx_identifier();
+        let name = self.parse_identifier();
+        if self.current_token() == SyntaxKind::ColonToken {
+            return JsxTagName::NamespacedName(self.arena.alloc(self.parse_jsx_namespaced_name(name)));
+        }
+        let expr = self.parse_jsx_tag_name_property_accesses(Expression::Identifier(name));
+        JsxTagName::Expression(self.arena.alloc(expr))
+    }
+
+    fn parse_jsx_tag_name_property_accesses(&mut self, mut expr: Expression<'a>) -> Expression<'a> {
+        while self.current_token() == SyntaxKind::DotToken {
+            let pos = expr.data().range.pos;
+            self.next_token();
+            let name = self.parse_identifier();
+            let end = name.data.range.end;
+            let expr_ref = self.arena.alloc(expr);
+            expr = Expression::PropertyAccess(PropertyAccessExpression {
+                data: NodeData::new(SyntaxKind::PropertyAccessExpression, pos, end),
+                expression: expr_ref, question_dot_token: None,
+                name: MemberName::Identifier(name),
+            });
+        }
+        expr
+    }
+
+    /// Parse the rest of `namespace:name` at the `:`.
+    fn parse_jsx_namespaced_name(&mut self, namespace: Identifier) -> JsxNamespacedName {
+        self.next_token();
+        self.scanner.rescan_jsx_identifier();
+        let name = self.parse_identifier();
+        JsxNamespacedName {
+            data: NodeData::new(SyntaxKind::JsxNamespacedName, namespace.data.range.pos, name.data.range.end),
+            namespace,
+            name,
+        }
+    }
+
+    fn parse_jsx_attributes(&mut self) -> JsxAttributes<'a> {
+        let pos = self.token_pos();
+        let mut properties = Vec::new();
+        let mut end = pos;
+        loop {
+            match self.current_token() {
+                SyntaxKind::OpenBraceToken => {
+                    let attr_pos = self.token_pos();
+                    self.next_token();
+                    self.expect_token(SyntaxKind::DotDotDotToken);
+                    let expression = self.parse_assignment_expression_and_alloc();
+                    end = self.token_end();
+                    self.expect_token(SyntaxKind::CloseBraceToken);
+                    properties.push(JsxAttributeLike::SpreadAttribute(JsxSpreadAttribute {
+                        data: NodeData::new(SyntaxKind::JsxSpreadAttribute, attr_pos, end),
+                        expression,
+                    }));
+                }
+                kind if kind == SyntaxKind::Identifier || kind.is_keyword() => {
+                    let attribute = self.parse_jsx_attribute();
+                    end = attribute.data.range.end;
+                    properties.push(JsxAttributeLike::Attribute(self.arena.alloc(attribute)));
+                }
+                _ => break,
+            }
+        }
+        JsxAttributes {
+            data: NodeData::new(SyntaxKind::JsxAttributes, pos, end),
+            properties: alloc_vec_in(self.arena, properties),
+        }
+    }
+
+    fn parse_jsx_attribute(&mut self) -> JsxAttribute<'a> {
+        let pos = self.token_pos();
+        self.scanner.rescan_jsx_identifier();
+        let identifier = self.parse_identifier();
+        let name = if self.current_token() == SyntaxKind::ColonToken {
+            JsxAttributeName::NamespacedName(self.parse_jsx_namespaced_name(identifier))
+        } else {
+            JsxAttributeName::Identifier(identifier)
+        };
+        let mut end = name.data().range.end;
+        let initializer = if self.current_token() == SyntaxKind::EqualsToken {
+            self.next_token();
+            let value = match self.current_token() {
+                SyntaxKind::StringLiteral => {
+                    let value_pos = self.token_pos();
+                    let value_end = self.token_end();
+                    // Attribute strings are not unescaped
+                    let text = self.scanner.get_text_slice(value_pos as usize + 1, value_end as usize - 1);
+                    let is_single_quote = self.scanner.get_text_slice(value_pos as usize, value_pos as usize + 1) == "'";
+                    self.next_token();
+                    JsxAttributeValue::StringLiteral(StringLiteral {
+                        data: NodeData::new(SyntaxKind::StringLiteral, value_pos, value_end),
+                        text: InternedString::dummy(), text_name: text, is_single_quote,
+                    })
+                }
+                SyntaxKind::OpenBraceToken => JsxAttributeValue::Expression(self.parse_jsx_expression(true)),
+                SyntaxKind::LessThanToken => {
+                    let element = self.parse_jsx_element_or_self_closing_element_or_fragment(true);
+                    JsxAttributeValue::Element(self.arena.alloc(element))
+                }
+                _ => {
+                    self.error(&rscript_diagnostics::messages::EXPRESSION_EXPECTED, &[]);
+                    return JsxAttribute { data: NodeData::new(SyntaxKind::JsxAttribute, pos, end), name, initializer: None };
+                }
+            };
+            end = match &value {
+                JsxAttributeValue::StringLiteral(n) => n.data.range.end,
+                JsxAttributeValue::Expression(n) => n.data.range.end,
+                JsxAttributeValue::Element(n) => n.data().range.end,
+            };
+            Some(value)
+        } else {
+            None
+        };
+        JsxAttribute { data: NodeData::new(SyntaxKind::JsxAttribute, pos, end), name, initializer }
+    }
+
+    /// Parse `{expression}`, `{...expression}` or `{}`.
+    fn parse_jsx_expression(&mut self, in_expression_context: bool) -> JsxExpression<'a> {
+        let pos = self.token_pos();
+        self.next_token();
+        let dot_dot_dot_token = self.optional_token(SyntaxKind::DotDotDotToken);
+        let expression = (self.current_token() != SyntaxKind::CloseBraceToken).then(|| self.parse_expression_and_alloc());
+        let end = self.token_end();
+        if self.current_token() != SyntaxKind::CloseBraceToken {
+            self.error(&rscript_diagnostics::messages::_0_EXPECTED, &["}"]);
+        } else if in_expression_context {
+            self.next_token();
+        } else {
+            self.scan_jsx_child_token();
+        }
+        JsxExpression { data: NodeData::new(SyntaxKind::JsxExpression, pos, end), dot_dot_dot_token, expression }
+    }
+
+    /// Parse the children of an element or fragment, up to the `</` of its
+    /// closing tag.
+    fn parse_jsx_children(&mut self, open_tag_name: Option<&JsxTagName<'a>>) -> &'a [JsxChild<'a>] {
+        let mut children = Vec::new();
+        loop {
+            match self.current_token() {
+                SyntaxKind::JsxText => {
+                    let pos = self.token_pos();
+                    let end = self.token_end();
+                    let text = self.token_value().to_string();
+                    let contains_only_trivia_white_spaces = text.trim().is_empty() && text.contains('\n');
+                    children.push(JsxChild::Text(JsxText {
+                        data: NodeData::new(SyntaxKind::JsxText, pos, end),
+                        text,
+                        contains_only_trivia_white_spaces,
+                    }));
+                    self.scan_jsx_child_token();
+                }
+                SyntaxKind::OpenBraceToken => children.push(JsxChild::Expression(self.parse_jsx_expression(false))),
+                SyntaxKind::LessThanToken => {
+                    let element = self.parse_jsx_element_or_self_closing_element_or_fragment(false);
+                    children.push(JsxChild::Element(self.arena.alloc(element)));
+                }
+                SyntaxKind::LessThanSlashToken => break,
+                _ => {
+                    if let Some(tag_name) = open_tag_name {
+                        let range = tag_name.data().range;
+                        let text = tag_name.text();
+                        self.error_at(range.pos, range.end, &rscript_diagnostics::messages::JSX_ELEMENT_0_HAS_NO_CORRESPONDING_CLOSING_TAG, &[&text]);
+                    } else {
+                        self.error(&rscript_diagnostics::messages::_0_EXPECTED, &["</"]);
+                    }
+                    break;
+                }
+            }
+        }
+        alloc_vec_in(self.arena, children)
+    }
+
+    /// Parse `</Tag>` at the `</`, reporting a tag name that does not match
+    /// the opening tag's.
+    fn parse_jsx_closing_element(&mut self, open_tag_text: &str, in_expression_context: bool) -> JsxClosingElement<'a> {
+        let pos = self.token_pos();
+        if self.current_token() != SyntaxKind::LessThanSlashToken {
+            let tag_name = JsxTagName::Expression(self.arena.alloc(Expression::Identifier(Identifier {
+                data: NodeData::new(SyntaxKind::Identifier, pos, pos),
+                text: InternedString::dummy(), text_name: String::new(), original_keyword_kind: None,
+            })));
+            return JsxClosingElement { data: NodeData::new(SyntaxKind::JsxClosingElement, pos, pos), tag_name };
+        }
+        self.next_token();
+        let tag_name = self.parse_jsx_tag_name();
+        if tag_name.text() != open_tag_text {
+            let range = tag_name.data().range;
+            self.error_at(range.pos, range.end, &rscript_diagnostics::messages::EXPECTED_CORRESPONDING_JSX_CLOSING_TAG_FOR_0, &[open_tag_text]);
+        }
+        let end = self.parse_jsx_closing_greater_than(in_expression_context);
+        JsxClosingElement { data: NodeData::new(SyntaxKind::JsxClosingElement, pos, end), tag_name }
+    }
 }
 
 // ========================================================================
@@ -3339,6 +4132,9 @@ impl HasNodeData for Expression<'_> {
             Expression::NonNull(n) => &n.data,
             Expression::MetaProperty(n) => &n.data,
             Expression::Satisfies(n) => &n.data,
+            Expression::JsxElement(n) => &n.data,
+            Expression::JsxSelfClosingElement(n) => &n.data,
+            Expression::JsxFragment(n) => &n.data,
             Expression::ThisKeyword(n) | Expression::SuperKeyword(n)
             | Expression::NullKeyword(n) | Expression::TrueKeyword(n)
             | Expression::FalseKeyword(n) => n,
@@ -901,6 +901,36 @@ fn test_parse_import_type_namespace() {
     assert!(count >= 1);
 }
 
+#[test]
+fn test_parse_inline_type_import_specifiers() {
+    assert_statement_count(r#"import { type Foo, Bar } from "./foo"; const x = 1;"#, 2);
+    assert_statement_count(r#"import { type as alias } from "./foo"; const x = 1;"#, 2);
+}
+
+#[test]
+fn test_parse_type_only_export_specifiers_and_star() {
+    assert_statement_count(r#"export { type Foo, Bar }; const x = 1;"#, 2);
+    assert_statement_count(r#"export type * from "./types"; export type * as ns from "./types";"#, 2);
+}
+
+#[test]
+fn test_parse_import_equals_declarations() {
+    use rscript_ast::node::{ModuleReference, Statement};
+    let arena = Bump::new();
+    let source = r#"import fs = require("fs"); import O = Geo.origin; import type T = Geo.Point;"#;
+    let sf = Parser::new(&arena, "test.ts", source).parse_source_file();
+    let kinds: Vec<_> = sf.statements.iter()
+        .map(|s| match s {
+            Statement::ImportEqualsDeclaration(n) => match n.module_reference {
+                ModuleReference::ExternalModuleReference(_) => (n.name.text_name.as_str(), "require", n.is_type_only),
+                ModuleReference::EntityName(_) => (n.name.text_name.as_str(), "entity", n.is_type_only),
+            },
+            _ => ("", "other", false),
+        })
+        .collect();
+    assert_eq!(kinds, [("fs", "require", false), ("O", "entity", false), ("T", "entity", true)]);
+}
+
 #[test]
 fn test_parse_export_type_named() {
     let src = r#"export type { Foo, Bar } from "./foo";"#;
@@ -1495,10 +1525,14 @@ fn test_parse_method_shorthand() {
 
 #[test]
 fn test_parse_getter_setter_in_object() {
-    // Getter/setter in object literals is complex parsing; verify no panic
-    let src = "const obj = { get x() { return 1; }, set x(v) {} };";
-    let count = parse(src);
-    assert!(count >= 1, "Expected at least 1 statement, got {}", count);
+    let src = "const obj = { get x() { return 1; }, set x(v) {}, get ['y']() { return 2; } };";
+    assert_statement_count(src, 1);
+}
+
+#[test]
+fn test_parse_get_set_as_object_property_names() {
+    let src = "const obj = { get: 1, set() {}, get };";
+    assert_statement_count(src, 1);
 }
 
 #[test]
@@ -1655,3 +1689,418 @@ fn test_asi_at_eof() {
     let src = "const x = 1";
     assert_statement_count(src, 1);
 }
+
+// ============================================================================
+// Type arguments in call expressions
+// ============================================================================
+
+#[test]
+fn test_parse_call_with_type_arguments() {
+    use rscript_ast::node::{Expression, Statement};
+
+    let arena = Bump::new();
+    let parser = Parser::new(&arena, "test.ts", "f<string, number>(x);\na < b > c;");
+    let sf = parser.parse_source_file();
+    assert_eq!(sf.statements.len(), 2);
+    match &sf.statements[0] {
+        Statement::ExpressionStatement(s) => match s.expression {
+            Expression::Call(call) => {
+                assert_eq!(call.type_arguments.map(|args| args.len()), Some(2));
+                assert_eq!(call.arguments.len(), 1);
+            }
+            other => panic!("expected call expression, got {:?}", other),
+        },
+        other => panic!("expected expression statement, got {:?}", other),
+    }
+    // Comparisons not followed by `(` stay binary expressions.
+    match &sf.statements[1] {
+        Statement::ExpressionStatement(s) => assert!(matches!(s.expression, Expression::Binary(_))),
+        other => panic!("expected expression statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_class_modifiers_are_recorded() {
+    use rscript_ast::node::{ClassElement, Statement};
+    use rscript_ast::types::ModifierFlags;
+
+    let arena = Bump::new();
+    let source = "abstract class A {\n  private static readonly x: number = 1;\n  protected abstract m(): void;\n  constructor(public y: string) {}\n}";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    let Statement::ClassDeclaration(class) = &sf.statements[0] else {
+        panic!("expected class declaration, got {:?}", sf.statements[0]);
+    };
+    assert!(class.data.modifier_flags.contains(ModifierFlags::ABSTRACT));
+    assert_eq!(
+        class.members[0].data().modifier_flags,
+        ModifierFlags::PRIVATE | ModifierFlags::STATIC | ModifierFlags::READONLY,
+    );
+    assert_eq!(
+        class.members[1].data().modifier_flags,
+        ModifierFlags::PROTECTED | ModifierFlags::ABSTRACT,
+    );
+    match &class.members[2] {
+        ClassElement::Constructor(c) => assert_eq!(c.parameters[0].data.modifier_flags, ModifierFlags::PUBLIC),
+        other => panic!("expected constructor, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_as_expression_and_template_literal_type() {
+    use rscript_ast::node::{Expression, Statement, TypeNode};
+
+    let arena = Bump::new();
+    let source = "const x = y as const;\ntype T = `a-${number}-b`;";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    let Statement::VariableStatement(var) = &sf.statements[0] else {
+        panic!("expected variable statement, got {:?}", sf.statements[0]);
+    };
+    let initializer = var.declaration_list.declarations[0].initializer;
+    assert!(matches!(initializer, Some(Expression::As(_))), "got {:?}", initializer);
+    let Statement::TypeAliasDeclaration(alias) = &sf.statements[1] else {
+        panic!("expected type alias, got {:?}", sf.statements[1]);
+    };
+    let TypeNode::TemplateLiteralType(template) = alias.type_node else {
+        panic!("expected template literal type, got {:?}", alias.type_node);
+    };
+    assert_eq!(template.head_text, "a-");
+    assert_eq!(template.template_spans[0].literal_text, "-b");
+}
+
+#[test]
+fn test_parse_named_tuple_members() {
+    use rscript_ast::node::{Statement, TypeNode};
+
+    let arena = Bump::new();
+    let source = "type T = [x: number, y?: string, ...rest: boolean[]];\ntype U = [number, ...string[]];";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    let Statement::TypeAliasDeclaration(alias) = &sf.statements[0] else {
+        panic!("expected type alias, got {:?}", sf.statements[0]);
+    };
+    let TypeNode::TupleType(tuple) = alias.type_node else {
+        panic!("expected tuple type, got {:?}", alias.type_node);
+    };
+    let members: Vec<(&str, bool, bool)> = tuple.elements.iter()
+        .map(|e| match e {
+            TypeNode::NamedTupleMember(m) => {
+                (m.name.text_name.as_str(), m.question_token.is_some(), m.dot_dot_dot_token.is_some())
+            }
+            other => panic!("expected named tuple member, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(members, vec![("x", false, false), ("y", true, false), ("rest", false, true)]);
+    let Statement::TypeAliasDeclaration(alias) = &sf.statements[1] else {
+        panic!("expected type alias, got {:?}", sf.statements[1]);
+    };
+    let TypeNode::TupleType(tuple) = alias.type_node else {
+        panic!("expected tuple type, got {:?}", alias.type_node);
+    };
+    assert!(matches!(tuple.elements[1], TypeNode::RestType(_)), "got {:?}", tuple.elements[1]);
+}
+
+#[test]
+fn test_parse_computed_type_member_names() {
+    use rscript_ast::node::{PropertyName, Statement, TypeElement};
+
+    let arena = Bump::new();
+    let source = "interface I { [key: string]: number; [Symbol.iterator](): void; [sym]: boolean }";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    let Statement::InterfaceDeclaration(decl) = &sf.statements[0] else {
+        panic!("expected interface, got {:?}", sf.statements[0]);
+    };
+    assert!(matches!(decl.members[0], TypeElement::IndexSignature(_)), "got {:?}", decl.members[0]);
+    let TypeElement::MethodSignature(method) = &decl.members[1] else {
+        panic!("expected method signature, got {:?}", decl.members[1]);
+    };
+    assert!(matches!(method.name, PropertyName::ComputedPropertyName(_)));
+    let TypeElement::PropertySignature(property) = &decl.members[2] else {
+        panic!("expected property signature, got {:?}", decl.members[2]);
+    };
+    assert!(matches!(property.name, PropertyName::ComputedPropertyName(_)));
+}
+
+#[test]
+fn test_parse_class_index_signature_and_for_of() {
+    use rscript_ast::node::{ClassElement, Statement};
+
+    let arena = Bump::new();
+    let source = "class C { [key: string]: boolean; enabled = true; }\nfor (const item of items) {}";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    let Statement::ClassDeclaration(class) = &sf.statements[0] else {
+        panic!("expected class declaration, got {:?}", sf.statements[0]);
+    };
+    assert!(matches!(class.members[0], ClassElement::IndexSignature(_)), "got {:?}", class.members[0]);
+    assert!(matches!(class.members[1], ClassElement::PropertyDeclaration(_)), "got {:?}", class.members[1]);
+    assert!(matches!(sf.statements[1], Statement::ForOfStatement(_)), "got {:?}", sf.statements[1]);
+}
+
+#[test]
+fn test_parse_get_set_as_class_member_names() {
+    use rscript_ast::node::{ClassElement, Statement};
+
+    let arena = Bump::new();
+    let source = "class M { get(k: string): number { return 0; } set(k: string, v: number): void {} get; get #x() { return 1; } set size(v: number) {} }";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    let Statement::ClassDeclaration(class) = &sf.statements[0] else {
+        panic!("expected class declaration, got {:?}", sf.statements[0]);
+    };
+    assert_eq!(class.members.len(), 5);
+    assert!(matches!(class.members[0], ClassElement::MethodDeclaration(_)), "got {:?}", class.members[0]);
+    assert!(matches!(class.members[1], ClassElement::MethodDeclaration(_)), "got {:?}", class.members[1]);
+    assert!(matches!(class.members[2], ClassElement::PropertyDeclaration(_)), "got {:?}", class.members[2]);
+    assert!(matches!(class.members[3], ClassElement::GetAccessor(_)), "got {:?}", class.members[3]);
+    assert!(matches!(class.members[4], ClassElement::SetAccessor(_)), "got {:?}", class.members[4]);
+}
+
+#[test]
+fn test_parse_declaration_export_and_declare_modifiers() {
+    use rscript_ast::types::ModifierFlags;
+
+    let arena = Bump::new();
+    let source = "export function f() {}\nexport default class C {}\ndeclare const x: number;\nexport declare let y: string;\nlet z = 1;";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    let flags: Vec<ModifierFlags> = sf.statements.iter().map(|s| s.data().modifier_flags).collect();
+    assert_eq!(flags, vec![
+        ModifierFlags::EXPORT,
+        ModifierFlags::EXPORT_DEFAULT,
+        ModifierFlags::AMBIENT,
+        ModifierFlags::EXPORT | ModifierFlags::AMBIENT,
+        ModifierFlags::NONE,
+    ]);
+    assert!(sf.is_external_module());
+}
+
+#[test]
+fn test_parse_type_predicates() {
+    use rscript_ast::node::{Statement, TypeNode, TypePredicateParameterName};
+
+    let arena = Bump::new();
+    let source = "declare function a(x: unknown): x is string;\ndeclare function b(x: unknown): asserts x;\ndeclare function c(x: unknown): asserts x is number;\ndeclare function d(asserts: unknown): asserts;";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    let predicates: Vec<Option<(bool, bool, bool)>> = sf.statements.iter()
+        .map(|s| match s {
+            Statement::FunctionDeclaration(f) => match f.return_type {
+                Some(TypeNode::TypePredicate(p)) => Some((
+                    p.asserts_modifier.is_some(),
+                    matches!(p.parameter_name, TypePredicateParameterName::Identifier(ref id) if id.text_name == "x"),
+                    p.type_node.is_some(),
+                )),
+                _ => None,
+            },
+            _ => panic!("expected function declaration, got {:?}", s),
+        })
+        .collect();
+    assert_eq!(predicates, vec![
+        Some((false, true, true)),
+        Some((true, true, false)),
+        Some((true, true, true)),
+        None,
+    ]);
+}
+
+#[test]
+fn test_parse_namespace_declaration_forms() {
+    use rscript_ast::node::{ModuleBody, ModuleName, Statement};
+    use rscript_ast::types::{ModifierFlags, NodeFlags};
+
+    let arena = Bump::new();
+    let source = "namespace A.B.C { }\nmodule M { }\ndeclare namespace D { }\nexport declare const enum E { X }\nmodule.exports = 1;";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    assert_eq!(sf.statements.len(), 5);
+
+    let Statement::ModuleDeclaration(outer) = &sf.statements[0] else {
+        panic!("expected namespace, got {:?}", sf.statements[0]);
+    };
+    let mut names = Vec::new();
+    let mut current = Some(outer);
+    while let Some(decl) = current {
+        let ModuleName::Identifier(id) = &decl.name else { panic!("expected identifier name") };
+        names.push((id.text_name.clone(), decl.data.flags.contains(NodeFlags::NESTED_NAMESPACE)));
+        current = match &decl.body {
+            Some(ModuleBody::ModuleDeclaration(inner)) => Some(inner),
+            _ => None,
+        };
+    }
+    assert_eq!(names, vec![("A".to_string(), false), ("B".to_string(), true), ("C".to_string(), true)]);
+
+    assert!(matches!(sf.statements[1], Statement::ModuleDeclaration(_)));
+    let Statement::ModuleDeclaration(ambient) = &sf.statements[2] else {
+        panic!("expected namespace, got {:?}", sf.statements[2]);
+    };
+    assert!(ambient.data.modifier_flags.contains(ModifierFlags::AMBIENT));
+    let Statement::EnumDeclaration(e) = &sf.statements[3] else {
+        panic!("expected enum, got {:?}", sf.statements[3]);
+    };
+    assert!(e.data.modifier_flags.contains(ModifierFlags::CONST | ModifierFlags::EXPORT | ModifierFlags::AMBIENT));
+    assert!(matches!(sf.statements[4], Statement::ExpressionStatement(_)));
+}
+
+#[test]
+fn test_parse_ambient_module_and_global_augmentation() {
+    use rscript_ast::node::{ModuleBody, ModuleName, Statement};
+    use rscript_ast::types::NodeFlags;
+
+    let arena = Bump::new();
+    let source = "declare module \"express\" { interface Request { user: string } }\ndeclare module \"*.svg\";\nexport {};\ndeclare global { interface Window { app: number } }";
+    let parser = Parser::new(&arena, "test.ts", source);
+    let sf = parser.parse_source_file();
+    assert_eq!(sf.statements.len(), 4);
+
+    let Statement::ModuleDeclaration(express) = &sf.statements[0] else {
+        panic!("expected module, got {:?}", sf.statements[0]);
+    };
+    assert!(matches!(&express.name, ModuleName::StringLiteral(name) if name.text_name == "express"));
+    assert!(matches!(express.body, Some(ModuleBody::ModuleBlock(_))));
+    let Statement::ModuleDeclaration(svg) = &sf.statements[1] else {
+        panic!("expected module, got {:?}", sf.statements[1]);
+    };
+    assert!(matches!(&svg.name, ModuleName::StringLiteral(name) if name.text_name == "*.svg"));
+    assert!(svg.body.is_none());
+    let Statement::ModuleDeclaration(global) = &sf.statements[3] else {
+        panic!("expected global augmentation, got {:?}", sf.statements[3]);
+    };
+    assert!(global.data.flags.contains(NodeFlags::GLOBAL_AUGMENTATION));
+    assert!(matches!(&global.name, ModuleName::Identifier(id) if id.text_name == "global"));
+}
+
+// ============================================================================
+// JSX
+// ============================================================================
+
+#[test]
+fn test_parse_jsx_elements() {
+    use rscript_ast::node::{
+        Expression, JsxAttributeLike, JsxAttributeValue, JsxChild, JsxTagName, Statement,
+    };
+
+    let arena = Bump::new();
+    let source = "const a = <div id=\"main\" data-role='x' hidden {...rest} onClick={() => go()}>\n  Hello {name}!\n  <Foo.Bar n={1} />\n  {/* note */}\n</div>;\nconst b = <><svg:rect /></>;";
+    let parser = Parser::new(&arena, "test.tsx", source);
+    let sf = parser.parse_source_file();
+    assert_eq!(sf.statements.len(), 2);
+    let Statement::VariableStatement(var) = &sf.statements[0] else {
+        panic!("expected variable statement, got {:?}", sf.statements[0]);
+    };
+    let Some(Expression::JsxElement(element)) = var.declaration_list.declarations[0].initializer else {
+        panic!("expected JSX element, got {:?}", var.declaration_list.declarations[0].initializer);
+    };
+    let opening = &element.opening_element;
+    assert_eq!(opening.tag_name.text(), "div");
+    assert_eq!(element.closing_element.tag_name.text(), "div");
+    let attributes: Vec<String> = opening.attributes.properties.iter()
+        .map(|property| match property {
+            JsxAttributeLike::Attribute(attr) => match &attr.initializer {
+                Some(JsxAttributeValue::StringLiteral(value)) => format!("{}={}", attr.name.text(), value.text_name),
+                Some(JsxAttributeValue::Expression(_)) => format!("{}={{}}", attr.name.text()),
+                Some(JsxAttributeValue::Element(_)) => format!("{}=<>", attr.name.text()),
+                None => attr.name.text(),
+            },
+            JsxAttributeLike::SpreadAttribute(_) => "...".to_string(),
+        })
+        .collect();
+    assert_eq!(attributes, vec!["id=main", "data-role=x", "hidden", "...", "onClick={}"]);
+    let children: Vec<String> = element.children.iter()
+        .map(|child| match child {
+            JsxChild::Text(text) if text.contains_only_trivia_white_spaces => "ws".to_string(),
+            JsxChild::Text(text) => format!("text:{}", text.text.trim()),
+            JsxChild::Expression(e) => format!("expr:{}", e.expression.is_some()),
+            JsxChild::Element(Expression::JsxSelfClosingElement(e)) => format!("<{} />", e.tag_name.text()),
+            JsxChild::Element(other) => panic!("unexpected child {:?}", other),
+        })
+        .collect();
+    assert_eq!(children, vec!["text:Hello", "expr:true", "text:!", "<Foo.Bar />", "ws", "expr:false", "ws"]);
+
+    let Statement::VariableStatement(var) = &sf.statements[1] else {
+        panic!("expected variable statement, got {:?}", sf.statements[1]);
+    };
+    let Some(Expression::JsxFragment(fragment)) = var.declaration_list.declarations[0].initializer else {
+        panic!("expected JSX fragment, got {:?}", var.declaration_list.declarations[0].initializer);
+    };
+    let JsxChild::Element(Expression::JsxSelfClosingElement(rect)) = &fragment.children[0] else {
+        panic!("expected self-closing element, got {:?}", fragment.children[0]);
+    };
+    assert!(matches!(&rect.tag_name, JsxTagName::NamespacedName(name) if name.text() == "svg:rect"));
+}
+
+#[test]
+fn test_parse_jsx_recovery() {
+    use rscript_ast::node::{Expression, Statement};
+
+    let arena = Bump::new();
+    // Mismatched and unterminated tags still produce elements.
+    for source in ["const a = <div><span></div></span>;", "const a = <p>text"] {
+        let sf = Parser::new(&arena, "test.tsx", source).parse_source_file();
+        let Statement::VariableStatement(var) = &sf.statements[0] else {
+            panic!("expected variable statement, got {:?}", sf.statements[0]);
+        };
+        assert!(matches!(var.declaration_list.declarations[0].initializer, Some(Expression::JsxElement(_))));
+    }
+
+    // Adjacent elements are joined with a comma so both are still visited.
+    let sf = Parser::new(&arena, "test.tsx", "const a = <a /><b />;").parse_source_file();
+    let Statement::VariableStatement(var) = &sf.statements[0] else {
+        panic!("expected variable statement, got {:?}", sf.statements[0]);
+    };
+    let Some(Expression::Binary(binary)) = var.declaration_list.declarations[0].initializer else {
+        panic!("expected comma expression, got {:?}", var.declaration_list.declarations[0].initializer);
+    };
+    assert!(matches!(binary.left, Expression::JsxSelfClosingElement(_)));
+    assert!(matches!(binary.right, Expression::JsxSelfClosingElement(_)));
+
+    // Outside .tsx files `<` still starts a type assertion.
+    let sf = Parser::new(&arena, "test.ts", "const a = <any>b;").parse_source_file();
+    let Statement::VariableStatement(var) = &sf.statements[0] else {
+        panic!("expected variable statement, got {:?}", sf.statements[0]);
+    };
+    assert!(!matches!(var.declaration_list.declarations[0].initializer, Some(Expression::JsxElement(_))));
+}
+
+#[test]
+fn test_parse_generic_arrow_functions_in_tsx() {
+    use rscript_ast::node::{Expression, Statement};
+
+    let arena = Bump::new();
+    let initializer = |file_name: &str, source: &str| {
+        let sf = Parser::new(&arena, file_name, source).parse_source_file();
+        let Statement::VariableStatement(var) = &sf.statements[0] else {
+            panic!("expected variable statement, got {:?}", sf.statements[0]);
+        };
+        var.declaration_list.declarations[0].initializer
+    };
+
+    for source in [
+        "const g = <T,>(v: T) => v;",
+        "const g = <T extends object>(v: T): T => v;",
+        "const g = <T, U = T>(v: T, u: U) => u;",
+    ] {
+        let Some(Expression::ArrowFunction(arrow)) = initializer("test.tsx", source) else {
+            panic!("expected arrow function for {source}");
+        };
+        assert!(arrow.type_parameters.is_some_and(|params| !params.is_empty()), "{source}");
+        assert!(!arrow.parameters.is_empty(), "{source}");
+    }
+    assert!(matches!(
+        initializer("test.ts", "const g = <T>(v: T) => v;"),
+        Some(Expression::ArrowFunction(_))
+    ));
+
+    // An `extends` attribute still makes a JSX element.
+    for source in ["const a = <T extends=\"x\" />;", "const a = <T extends />;", "const a = <T>text</T>;"] {
+        assert!(
+            matches!(
+                initializer("test.tsx", source),
+                Some(Expression::JsxSelfClosingElement(_) | Expression::JsxElement(_))
+            ),
+            "{source}"
+        );
+    }
+}
@@ -5,8 +5,9 @@
 
 use rscript_ast::node::*;
 use rscript_ast::syntax_kind::SyntaxKind;
-use rscript_ast::types::ModifierFlags;
+use rscript_ast::types::{ModifierFlags, NodeFlags};
 use rscript_core::intern::StringInterner;
+use std::collections::HashSet;
 
 /// Options for the printer.
 pub struct PrinterOptions {
@@ -18,6 +19,18 @@ pub struct PrinterOptions {
     pub new_line: String,
     /// Whether to emit a trailing newline.
     pub trailing_newline: bool,
+    /// Whether imports and exports are kept as written when types are
+    /// stripped (verbatimModuleSyntax): only `type` modifiers are removed,
+    /// so an import of nothing but types still loads its module.
+    pub verbatim_module_syntax: bool,
+    /// Import bindings to drop when types are stripped because they are
+    /// never read as values, along with local re-exports of them. An import
+    /// left with no bindings is dropped, unless under verbatimModuleSyntax.
+    pub elided_imports: HashSet<String>,
+    /// Names of exports to drop when types are stripped because they
+    /// re-export only types. An export left with no specifiers is dropped,
+    /// unless under verbatimModuleSyntax.
+    pub elided_exports: HashSet<String>,
 }
 
 impl Default for PrinterOptions {
@@ -27,6 +40,9 @@ impl Default for PrinterOptions {
             indent_str: "    ".to_string(),
             new_line: "\n".to_string(),
             trailing_newline: true,
+            verbatim_module_syntax: false,
+            elided_imports: HashSet::new(),
+            elided_exports: HashSet::new(),
         }
     }
 }
@@ -67,8 +83,13 @@ impl<'i> Printer<'i> {
 
     /// Extract text from a token using the source text.
     fn token_text(&self, token: &rscript_ast::node::Token) -> String {
+        self.node_text(&token.data)
+    }
+
+    /// Extract the source text a node was parsed from.
+    fn node_text(&self, data: &rscript_ast::node::NodeData) -> String {
         if let Some(source) = self.source_text {
-            let range = token.data.range.to_range();
+            let range = data.range.to_range();
             if range.end <= source.len() {
                 source[range].to_string()
             } else {
@@ -87,10 +108,17 @@ impl<'i> Printer<'i> {
         // and we only use source_text during this method call
         let source_text_ptr: *const str = &*source_file.text;
         self.source_text = Some(unsafe { &*source_text_ptr });
-        for (i, stmt) in source_file.statements.iter().enumerate() {
-            if i > 0 { self.write_newline(); }
+        for stmt in source_file.statements.iter() {
+            // Statements that print nothing, like elided imports, leave no
+            // blank line behind.
+            let start = self.output.len();
+            if start > 0 { self.write_newline(); }
             self.write_indent();
+            let statement_start = self.output.len();
             self.print_statement(stmt);
+            if self.output.len() == statement_start {
+                self.output.truncate(start);
+            }
         }
         if self.options.trailing_newline && !self.output.is_empty() {
             self.write_newline();
@@ -104,6 +132,24 @@ impl<'i> Printer<'i> {
     // Statement printing
     // ========================================================================
 
+    /// Print a type node on its own, such as one synthesized to display a
+    /// type.
+    pub fn print_type(&mut self, node: &TypeNode<'_>) -> String {
+        self.output.clear();
+        self.print_type_node(node);
+        std::mem::take(&mut self.output)
+    }
+
+    /// Print a type member on its own, without its trailing `;`.
+    pub fn print_type_member(&mut self, member: &TypeElement<'_>) -> String {
+        self.output.clear();
+        self.print_type_element(member);
+        if self.output.ends_with(';') {
+            self.output.pop();
+        }
+        std::mem::take(&mut self.output)
+    }
+
     fn print_statement(&mut self, stmt: &Statement<'_>) {
         match stmt {
             Statement::EmptyStatement(_) => self.write(";"),
@@ -384,6 +430,10 @@ impl<'i> Printer<'i> {
         if !node.members.is_empty() {
             self.increase_indent();
             for member in node.members.iter() {
+                // Index signatures are type-only.
+                if self.options.strip_types && matches!(member, ClassElement::IndexSignature(_)) {
+                    continue;
+                }
                 self.write_newline();
                 self.write_indent();
                 self.print_class_element(member);
@@ -763,64 +813,117 @@ impl<'i> Printer<'i> {
     }
 
     fn print_import_declaration(&mut self, node: &ImportDeclaration<'_>) {
-        let is_type_only = node.import_clause.as_ref().is_some_and(|c| c.is_type_only);
-        if self.options.strip_types && is_type_only { return; }
+        let Some(clause) = &node.import_clause else {
+            self.write("import ");
+            self.print_expression(node.module_specifier);
+            self.write(";");
+            return;
+        };
+        let strip_types = self.options.strip_types;
+        if strip_types && clause.is_type_only { return; }
+        let is_kept = |name: &Identifier, is_type_only: bool| {
+            !strip_types
+                || !is_type_only && (self.options.verbatim_module_syntax || !self.options.elided_imports.contains(&name.text_name))
+        };
+        let default_name = clause.name.as_ref().filter(|name| is_kept(name, false));
+        let namespace = match &clause.named_bindings {
+            Some(NamedImportBindings::NamespaceImport(ns)) => Some(&ns.name).filter(|name| is_kept(name, false)),
+            _ => None,
+        };
+        let named_elements: Option<Vec<&ImportSpecifier>> = match &clause.named_bindings {
+            Some(NamedImportBindings::NamedImports(named)) => {
+                Some(named.elements.iter().filter(|spec| is_kept(&spec.name, spec.is_type_only)).collect())
+            }
+            _ => None,
+        };
+        let has_named_elements = named_elements.as_ref().is_some_and(|elements| !elements.is_empty());
+        // Without verbatimModuleSyntax an import that is left with no
+        // bindings is elided; with it, the module is still loaded.
+        if strip_types && !self.options.verbatim_module_syntax
+            && default_name.is_none() && namespace.is_none() && !has_named_elements
+        {
+            return;
+        }
         self.write("import ");
-        if !self.options.strip_types && is_type_only { self.write("type "); }
-        if let Some(ref clause) = node.import_clause {
-            if let Some(ref name) = clause.name {
-                self.print_identifier(name);
-                if clause.named_bindings.is_some() { self.write(", "); }
-            }
-            if let Some(ref bindings) = clause.named_bindings {
-                match bindings {
-                    NamedImportBindings::NamespaceImport(ns) => {
-                        self.write("* as ");
-                        self.print_identifier(&ns.name);
-                    }
-                    NamedImportBindings::NamedImports(named) => {
-                        self.write("{ ");
-                        for (i, spec) in named.elements.iter().enumerate() {
-                            if i > 0 { self.write(", "); }
-                            if !self.options.strip_types && spec.is_type_only { self.write("type "); }
-                            if let Some(ref prop) = spec.property_name {
-                                self.print_identifier(prop);
-                                self.write(" as ");
-                            }
-                            self.print_identifier(&spec.name);
-                        }
-                        self.write(" }");
+        if !strip_types && clause.is_type_only { self.write("type "); }
+        if let Some(name) = default_name {
+            self.print_identifier(name);
+        }
+        if let Some(name) = namespace {
+            if default_name.is_some() { self.write(", "); }
+            self.write("* as ");
+            self.print_identifier(name);
+        }
+        if let Some(elements) = named_elements.filter(|elements| !elements.is_empty() || default_name.is_none()) {
+            if default_name.is_some() { self.write(", "); }
+            if elements.is_empty() {
+                self.write("{}");
+            } else {
+                self.write("{ ");
+                for (i, spec) in elements.iter().enumerate() {
+                    if i > 0 { self.write(", "); }
+                    if !strip_types && spec.is_type_only { self.write("type "); }
+                    if let Some(ref prop) = spec.property_name {
+                        self.print_identifier(prop);
+                        self.write(" as ");
                     }
+                    self.print_identifier(&spec.name);
                 }
+                self.write(" }");
             }
-            self.write(" from ");
         }
+        self.write(" from ");
         self.print_expression(node.module_specifier);
         self.write(";");
     }
 
     fn print_export_declaration(&mut self, node: &ExportDeclaration<'_>) {
         if self.options.strip_types && node.is_type_only { return; }
+        let strip_types = self.options.strip_types;
+        // Local re-exports of elided imports go with them.
+        let is_local = node.module_specifier.is_none();
+        let is_kept = |spec: &ExportSpecifier| {
+            let local_name = &spec.property_name.as_ref().unwrap_or(&spec.name).text_name;
+            !strip_types
+                || !(spec.is_type_only
+                    || is_local && self.options.elided_imports.contains(local_name)
+                    || self.options.elided_exports.contains(&spec.name.text_name))
+        };
+        let named_elements: Option<Vec<&ExportSpecifier>> = match &node.export_clause {
+            Some(NamedExportBindings::NamedExports(named)) => Some(named.elements.iter().filter(|spec| is_kept(spec)).collect()),
+            _ => None,
+        };
+        if let Some(NamedExportBindings::NamedExports(named)) = &node.export_clause {
+            let all_elided = !named.elements.is_empty() && named_elements.as_ref().is_some_and(Vec::is_empty);
+            if all_elided && !self.options.verbatim_module_syntax {
+                return;
+            }
+        }
         self.write("export ");
-        if !self.options.strip_types && node.is_type_only { self.write("type "); }
+        if !strip_types && node.is_type_only { self.write("type "); }
         if let Some(ref clause) = node.export_clause {
             match clause {
                 NamedExportBindings::NamespaceExport(ns) => {
                     self.write("* as ");
                     self.print_identifier(&ns.name);
                 }
-                NamedExportBindings::NamedExports(named) => {
-                    self.write("{ ");
-                    for (i, spec) in named.elements.iter().enumerate() {
-                        if i > 0 { self.write(", "); }
-                        if !self.options.strip_types && spec.is_type_only { self.write("type "); }
-                        self.print_identifier(&spec.name);
-                        if let Some(ref prop) = spec.property_name {
-                            self.write(" as ");
-                            self.print_identifier(prop);
+                NamedExportBindings::NamedExports(_) => {
+                    let elements = named_elements.unwrap_or_default();
+                    if elements.is_empty() {
+                        self.write("{}");
+                    } else {
+                        self.write("{ ");
+                        for (i, spec) in elements.iter().enumerate() {
+                            if i > 0 { self.write(", "); }
+                            if !strip_types && spec.is_type_only { self.write("type "); }
+                            if let Some(ref prop) = spec.property_name {
+                                self.print_identifier(prop);
+                                self.write(" as ");
+                            }
+                            self.print_identifier(&spec.name);
                         }
+                        self.write(" }");
                     }
-                    self.write(" }");
                 }
             }
         } else {
@@ -837,22 +940,29 @@ impl<'i> Printer<'i> {
         let mf = node.data.modifier_flags;
         if mf.contains(ModifierFlags::AMBIENT) { self.write("declare "); }
         if mf.contains(ModifierFlags::EXPORT) { self.write("export "); }
-        // Determine module/namespace/global from kind
-        match &node.name {
-            ModuleName::StringLiteral(_) => self.write("module "),
-            ModuleName::Identifier(_) => {
-                // Check the syntax kind for GlobalKeyword
-                if node.data.kind == SyntaxKind::ModuleDeclaration {
-                    self.write("namespace ");
-                } else {
-                    self.write("module ");
-                }
+        // Determine module/namespace/global from the name and flags
+        if node.data.flags.contains(NodeFlags::GLOBAL_AUGMENTATION) {
+            self.write("global");
+        } else {
+            match &node.name {
+                ModuleName::StringLiteral(_) => self.write("module "),
+                ModuleName::Identifier(_) => self.write("namespace "),
             }
+            self.print_module_name(&node.name);
         }
-        self.print_module_name(&node.name);
-        if let Some(ref body) = node.body {
-            self.write(" ");
-            self.print_module_body(body);
+        // Dotted names `A.B.C` are nested declarations sharing one header.
+        let mut body = node.body.as_ref();
+        while let Some(ModuleBody::ModuleDeclaration(inner)) = body {
+            self.write(".");
+            self.print_module_name(&inner.name);
+            body = inner.body.as_ref();
+        }
+        match body {
+            Some(body) => {
+                self.write(" ");
+                self.print_module_body(body);
+            }
+            None => self.write(";"),
         }
     }
 
@@ -861,8 +971,7 @@ impl<'i> Printer<'i> {
             ModuleName::Identifier(id) => self.print_identifier(id),
             ModuleName::StringLiteral(s) => {
                 self.write("\"");
-                let text = self.resolve(s.text);
-                self.write_owned(text.to_string());
+                self.write_owned(s.text_name.clone());
                 self.write("\"");
             }
         }
@@ -896,16 +1005,10 @@ impl<'i> Printer<'i> {
     fn print_expression(&mut self, expr: &Expression<'_>) {
         match expr {
             Expression::Identifier(id) => self.print_identifier(id),
-            Expression::NumericLiteral(n) => {
-                let text = self.resolve(n.text);
-                self.write_owned(text.to_string());
-            }
+            Expression::NumericLiteral(n) => self.write_owned(n.text_name.clone()),
             Expression::StringLiteral(n) => {
-                let quote = if n.is_single_quote { "'" } else { "\"" };
-                self.write(quote);
-                let text = self.resolve(n.text);
-                self.write_owned(text.to_string());
-                self.write(quote);
+                let quote = if n.is_single_quote { '\'' } else { '"' };
+                self.write_owned(escape_string_literal(&n.text_name, quote));
             }
             Expression::BigIntLiteral(n) => {
                 let text = self.resolve(n.text);
@@ -1178,9 +1281,88 @@ impl<'i> Printer<'i> {
                 self.print_identifier(&n.name);
             }
             Expression::OmittedExpression(_) => {}
+            Expression::JsxElement(n) => {
+                self.print_jsx_opening(&n.opening_element.tag_name, &n.opening_element.attributes, false);
+                self.print_jsx_children(n.children);
+                self.write("</");
+                self.print_jsx_tag_name(&n.closing_element.tag_name);
+                self.write(">");
+            }
+            Expression::JsxSelfClosingElement(n) => self.print_jsx_opening(&n.tag_name, &n.attributes, true),
+            Expression::JsxFragment(n) => {
+                self.write("<>");
+                self.print_jsx_children(n.children);
+                self.write("</>");
+            }
+        }
+    }
+
+    // JSX is emitted as written (`jsx: preserve`); type arguments are erased.
+    fn print_jsx_opening(&mut self, tag_name: &JsxTagName<'_>, attributes: &JsxAttributes<'_>, self_closing: bool) {
+        self.write("<");
+        self.print_jsx_tag_name(tag_name);
+        for property in attributes.properties.iter() {
+            self.write(" ");
+            match property {
+                JsxAttributeLike::Attribute(attr) => {
+                    self.write_owned(attr.name.text());
+                    match &attr.initializer {
+                        Some(JsxAttributeValue::StringLiteral(value)) => {
+                            let quote = if value.is_single_quote { "'" } else { "\"" };
+                            self.write("=");
+                            self.write(quote);
+                            self.write_owned(value.text_name.clone());
+                            self.write(quote);
+                        }
+                        Some(JsxAttributeValue::Expression(expression)) => {
+                            self.write("=");
+                            self.print_jsx_expression(expression);
+                        }
+                        Some(JsxAttributeValue::Element(element)) => {
+                            self.write("=");
+                            self.print_expression(element);
+                        }
+                        None => {}
+                    }
+                }
+                JsxAttributeLike::SpreadAttribute(spread) => {
+                    self.write("{...");
+                    self.print_expression(spread.expression);
+                    self.write("}");
+                }
+            }
+        }
+        self.write(if self_closing { " />" } else { ">" });
+    }
+
+    fn print_jsx_tag_name(&mut self, tag_name: &JsxTagName<'_>) {
+        match tag_name {
+            JsxTagName::Expression(expression) => self.print_expression(expression),
+            JsxTagName::NamespacedName(name) => self.write_owned(name.text()),
+        }
+    }
+
+    fn print_jsx_children(&mut self, children: &[JsxChild<'_>]) {
+        for child in children {
+            match child {
+                JsxChild::Text(text) => self.write_owned(text.text.clone()),
+                JsxChild::Expression(expression) => self.print_jsx_expression(expression),
+                JsxChild::Element(element) => self.print_expression(element),
+            }
         }
     }
 
+    fn print_jsx_expression(&mut self, node: &JsxExpression<'_>) {
+        self.write("{");
+        if node.dot_dot_dot_token.is_some() {
+            self.write("...");
+        }
+        if let Some(expression) = node.expression {
+            self.print_expression(expression);
+        }
+        self.write("}");
+    }
+
     fn print_object_literal(&mut self, node: &ObjectLiteralExpression<'_>) {
         if node.properties.is_empty() {
             self.write("{}");
@@ -1359,16 +1541,16 @@ impl<'i> Printer<'i> {
                     self.write(": ");
                     self.print_type_node(ty);
                 }
-                self.write(" }");
+                self.write("; }");
             }
+            TypeNode::TypeLiteral(n) if n.members.is_empty() => self.write("{}"),
             TypeNode::TypeLiteral(n) => {
                 self.write("{ ");
                 for (i, member) in n.members.iter().enumerate() {
                     if i > 0 { self.write(" "); }
                     self.print_type_element(member);
                 }
-                if !n.members.is_empty() { self.write(" "); }
-                self.write("}");
+                self.write(" }");
             }
             TypeNode::ParenthesizedType(n) => {
                 self.write("(");
@@ -1394,9 +1576,16 @@ impl<'i> Printer<'i> {
                 self.write("infer ");
                 self.print_identifier(&n.type_parameter.name);
             }
-            TypeNode::TemplateLiteralType(_n) => {
-                // Simplified: template literal types need source text
-                self.write("`...`");
+            TypeNode::TemplateLiteralType(n) => {
+                self.write("`");
+                self.write(&n.head_text);
+                for span in n.template_spans.iter() {
+                    self.write("${");
+                    self.print_type_node(span.type_node);
+                    self.write("}");
+                    self.write(&span.literal_text);
+                }
+                self.write("`");
             }
             TypeNode::TypePredicate(n) => {
                 if n.asserts_modifier.is_some() { self.write("asserts "); }
@@ -1452,8 +1641,7 @@ impl<'i> Printer<'i> {
     // ========================================================================
 
     fn print_identifier(&mut self, id: &Identifier) {
-        let text = self.resolve(id.text);
-        self.write_owned(text.to_string());
+        self.write_owned(id.text_name.clone());
     }
 
     fn print_entity_name(&mut self, name: &EntityName<'_>) {
@@ -1470,16 +1658,24 @@ impl<'i> Printer<'i> {
     fn print_property_name(&mut self, name: &PropertyName<'_>) {
         match name {
             PropertyName::Identifier(id) => self.print_identifier(id),
-            PropertyName::StringLiteral(tok) => {
-                // Extract text from token
-                let text = self.token_text(tok);
-                // String literal tokens include quotes, so use them as-is
-                self.write_owned(text);
+            PropertyName::StringLiteral(lit) => {
+                // The source text includes the quotes, so use it as-is.
+                // Synthesized names have none and are quoted here.
+                let text = self.node_text(&lit.data);
+                if text.is_empty() {
+                    let quote = if lit.is_single_quote { '\'' } else { '"' };
+                    self.write_owned(escape_string_literal(&lit.text_name, quote));
+                } else {
+                    self.write_owned(text);
+                }
             }
-            PropertyName::NumericLiteral(tok) => {
-                // Extract text from token
-                let text = self.token_text(tok);
-                self.write_owned(text);
+            PropertyName::NumericLiteral(lit) => {
+                let text = self.node_text(&lit.data);
+                if text.is_empty() {
+                    self.write_owned(lit.text_name.clone());
+                } else {
+                    self.write_owned(text);
+                }
             }
             PropertyName::ComputedPropertyName(c) => {
                 self.write("[");
@@ -1548,10 +1744,10 @@ impl<'i> Printer<'i> {
         if flags.is_empty() || flags == ModifierFlags::NONE { return; }
         if flags.contains(ModifierFlags::EXPORT) { self.write("export "); }
         if !self.options.strip_types && flags.contains(ModifierFlags::AMBIENT) { self.write("declare "); }
-        if flags.contains(ModifierFlags::ABSTRACT) { self.write("abstract "); }
+        if !self.options.strip_types && flags.contains(ModifierFlags::ABSTRACT) { self.write("abstract "); }
         if flags.contains(ModifierFlags::STATIC) { self.write("static "); }
         if !self.options.strip_types && flags.contains(ModifierFlags::READONLY) { self.write("readonly "); }
-        if flags.contains(ModifierFlags::OVERRIDE) { self.write("override "); }
+        if !self.options.strip_types && flags.contains(ModifierFlags::OVERRIDE) { self.write("override "); }
         if flags.contains(ModifierFlags::ACCESSOR) { self.write("accessor "); }
         if !self.options.strip_types && flags.contains(ModifierFlags::PUBLIC) { self.write("public "); }
         if !self.options.strip_types && flags.contains(ModifierFlags::PRIVATE) { self.write("private "); }
@@ -1666,6 +1862,30 @@ fn keyword_to_string(kind: SyntaxKind) -> &'static str {
     }
 }
 
+/// Quote the value of a string literal, escaping what cannot appear
+/// literally between `quote`s.
+fn escape_string_literal(value: &str, quote: char) -> String {
+    let mut text = String::with_capacity(value.len() + 2);
+    text.push(quote);
+    for ch in value.chars() {
+        match ch {
+            '\\' => text.push_str("\\\\"),
+            '\n' => text.push_str("\\n"),
+            '\r' => text.push_str("\\r"),
+            '\t' => text.push_str("\\t"),
+            '\u{2028}' => text.push_str("\\u2028"),
+            '\u{2029}' => text.push_str("\\u2029"),
+            _ if ch == quote => {
+                text.push('\\');
+                text.push(ch);
+            }
+            _ => text.push(ch),
+        }
+    }
+    text.push(quote);
+    text
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1683,4 +1903,11 @@ mod tests {
         assert_eq!(keyword_to_string(SyntaxKind::NumberKeyword), "number");
         assert_eq!(keyword_to_string(SyntaxKind::NeverKeyword), "never");
     }
+
+    #[test]
+    fn test_escape_string_literal() {
+        assert_eq!(escape_string_literal("a'b", '\''), "'a\\'b'");
+        assert_eq!(escape_string_literal("a'b", '"'), "\"a'b\"");
+        assert_eq!(escape_string_literal("line\n\\", '"'), "\"line\\n\\\\\"");
+    }
 }
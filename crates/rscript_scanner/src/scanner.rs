@@ -5,8 +5,10 @@
 
 use crate::char_codes::*;
 use crate::token::TokenInfo;
+use rscript_ast::node::{CommentDirective, CommentDirectiveType};
 use rscript_ast::syntax_kind::SyntaxKind;
 use rscript_ast::types::TokenFlags;
+use rscript_core::text::TextRange;
 use rscript_diagnostics::{Diagnostic, DiagnosticCollection};
 
 /// Saved scanner state for lookahead.
@@ -36,6 +38,8 @@ pub struct Scanner {
     in_jsx: bool,
     /// Accumulated diagnostics.
     diagnostics: DiagnosticCollection,
+    /// `@ts-ignore` and `@ts-expect-error` comments seen so far.
+    comment_directives: Vec<CommentDirective>,
 }
 
 impl Scanner {
@@ -50,6 +54,7 @@ impl Scanner {
             token_flags: TokenFlags::NONE,
             in_jsx: false,
             diagnostics: DiagnosticCollection::new(),
+            comment_directives: Vec::new(),
         }
     }
 
@@ -155,6 +160,11 @@ impl Scanner {
         std::mem::take(&mut self.diagnostics)
     }
 
+    /// Take the comment directives collected while scanning.
+    pub fn take_comment_directives(&mut self) -> Vec<CommentDirective> {
+        std::mem::take(&mut self.comment_directives)
+    }
+
     /// Get a TokenInfo for the current token.
     pub fn token_info(&self) -> TokenInfo {
         TokenInfo {
@@ -248,6 +258,7 @@ impl Scanner {
                     self.pos += 1;
                 }
                 '/' => {
+                    let comment_start = self.pos;
                     if self.char_at(1) == Some('/') {
                         // Single-line comment
                         self.pos += 2;
@@ -257,9 +268,11 @@ impl Scanner {
                             }
                             self.pos += 1;
                         }
+                        self.append_if_comment_directive(comment_start, false);
                     } else if self.char_at(1) == Some('*') {
                         // Multi-line comment
                         self.pos += 2;
+                        let mut last_line_start = comment_start;
                         while !self.is_eof() {
                             if self.text[self.pos] == '*' && self.char_at(1) == Some('/') {
                                 self.pos += 2;
@@ -267,9 +280,11 @@ impl Scanner {
                             }
                             if is_line_break(self.text[self.pos]) {
                                 self.token_flags |= TokenFlags::PRECEDING_LINE_BREAK;
+                                last_line_start = self.pos + 1;
                             }
                             self.pos += 1;
                         }
+                        self.append_if_comment_directive(last_line_start, true);
                     } else {
                         return;
                     }
@@ -285,6 +300,37 @@ impl Scanner {
         }
     }
 
+    /// Record the comment (or last line of a multi-line comment) from `start`
+    /// to the current position if it begins with `@ts-expect-error` or
+    /// `@ts-ignore`. Comments scanned again after a lookahead are recorded
+    /// once.
+    fn append_if_comment_directive(&mut self, start: usize, multi_line: bool) {
+        if self.comment_directives.last().is_some_and(|directive| directive.range.pos as usize >= start) {
+            return;
+        }
+        let line = &self.text[start..self.pos];
+        let text_start = if multi_line {
+            line.iter().position(|&ch| ch != '/' && ch != '*').unwrap_or(line.len())
+        } else if line.get(2) == Some(&'/') {
+            3
+        } else {
+            2
+        };
+        let text: String = line[text_start..].iter().collect();
+        let text = text.trim_start();
+        let kind = if text.starts_with("@ts-expect-error") {
+            CommentDirectiveType::ExpectError
+        } else if text.starts_with("@ts-ignore") {
+            CommentDirectiveType::Ignore
+        } else {
+            return;
+        };
+        self.comment_directives.push(CommentDirective {
+            range: TextRange::new(start as u32, self.pos as u32),
+            kind,
+        });
+    }
+
     /// Scan the next token and return its kind.
     pub fn scan(&mut self) -> SyntaxKind {
         self.token_flags = TokenFlags::NONE;
@@ -1122,6 +1168,23 @@ impl Scanner {
         self.token
     }
 
+    /// Extend the identifier or keyword just scanned into a JSX identifier,
+    /// which may contain hyphens (`data-id`, `aria-label`).
+    pub fn rescan_jsx_identifier(&mut self) -> SyntaxKind {
+        if self.token == SyntaxKind::Identifier || self.token.is_keyword() {
+            while let Some(c) = self.current_char() {
+                if c == '-' || is_identifier_part(c) {
+                    self.pos += 1;
+                } else {
+                    break;
+                }
+            }
+            self.token_value = self.text[self.token_start..self.pos].iter().collect();
+            self.token = SyntaxKind::Identifier;
+        }
+        self.token
+    }
+
     /// Scan JSX attribute value (string in quotes).
     pub fn scan_jsx_attribute_value(&mut self) -> SyntaxKind {
         self.token_start = self.pos;
@@ -1330,6 +1393,21 @@ mod tests {
         assert_eq!(scanner.token_value(), "x");
     }
 
+    #[test]
+    fn test_scan_comment_directives() {
+        let mut scanner = Scanner::new("// @ts-ignore\nlet x;\n/// @ts-expect-error: reason\n/* @ts-ignore */ x\n/*\n * @ts-ignore */\n// not @ts-ignore\n");
+        scanner.look_ahead(|scanner| while scanner.scan() != SyntaxKind::EndOfFileToken {});
+        while scanner.scan() != SyntaxKind::EndOfFileToken {}
+        let directives: Vec<_> = scanner.take_comment_directives().iter()
+            .map(|directive| (directive.range.pos, directive.kind))
+            .collect();
+        assert_eq!(directives, vec![
+            (0, CommentDirectiveType::Ignore),
+            (21, CommentDirectiveType::ExpectError),
+            (50, CommentDirectiveType::Ignore),
+        ]);
+    }
+
     #[test]
     fn test_scan_arrow_function() {
         let mut scanner = Scanner::new("=> ??= &&=");
@@ -1477,6 +1555,18 @@ mod tests {
         assert_eq!(scanner.token_value(), "data-id");
     }
 
+    #[test]
+    fn test_rescan_jsx_identifier() {
+        let mut scanner = Scanner::new("aria-label class-name=");
+        assert_eq!(scanner.scan(), SyntaxKind::Identifier);
+        assert_eq!(scanner.rescan_jsx_identifier(), SyntaxKind::Identifier);
+        assert_eq!(scanner.token_value(), "aria-label");
+        assert_eq!(scanner.scan(), SyntaxKind::ClassKeyword);
+        assert_eq!(scanner.rescan_jsx_identifier(), SyntaxKind::Identifier);
+        assert_eq!(scanner.token_value(), "class-name");
+        assert_eq!(scanner.scan(), SyntaxKind::EqualsToken);
+    }
+
     #[test]
     fn test_look_ahead() {
         let mut scanner = Scanner::new("let x = 1;");
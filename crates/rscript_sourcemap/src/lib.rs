@@ -153,7 +153,7 @@ impl SourceMapBuilder {
         }
 
         // Build JSON
-        let mut json = format!("{{\"version\":3,\"sources\":[");
+        let mut json = "{\"version\":3,\"sources\":[".to_string();
         for (i, source) in self.sources.iter().enumerate() {
             if i > 0 {
                 json.push(',');
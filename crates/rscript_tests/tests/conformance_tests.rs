@@ -118,9 +118,7 @@ impl ConformanceTestSuite {
             for failure in failures.iter().take(10) {
                 println!("  [{}] {}: {}", failure.category, failure.name, {
                     failure
-                        .parse_error
-                        .as_ref()
-                        .map(|e| e.as_str())
+                        .parse_error.as_deref()
                         .unwrap_or("Unknown error")
                 });
             }
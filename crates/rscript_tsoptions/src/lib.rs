@@ -14,6 +14,7 @@ pub struct CompilerOptions {
     pub module: Option<ModuleKind>,
     pub lib: Option<Vec<String>>,
     pub jsx: Option<JsxEmit>,
+    pub jsx_import_source: Option<String>,
     pub declaration: Option<bool>,
     pub declaration_map: Option<bool>,
     pub source_map: Option<bool>,
@@ -25,6 +26,7 @@ pub struct CompilerOptions {
     pub ts_build_info_file: Option<String>,
     pub remove_comments: Option<bool>,
     pub no_emit: Option<bool>,
+    pub downlevel_iteration: Option<bool>,
 
     // -- Strict Type-Checking Options --
     pub strict: Option<bool>,
@@ -59,6 +61,11 @@ pub struct CompilerOptions {
     pub no_unused_parameters: Option<bool>,
     pub no_implicit_returns: Option<bool>,
     pub no_fallthrough_cases_in_switch: Option<bool>,
+    pub no_implicit_override: Option<bool>,
+    pub no_unchecked_indexed_access: Option<bool>,
+    pub exact_optional_property_types: Option<bool>,
+    pub allow_unreachable_code: Option<bool>,
+    pub allow_unused_labels: Option<bool>,
 
     // -- Experimental Options --
     pub experimental_decorators: Option<bool>,
@@ -70,14 +77,35 @@ pub struct CompilerOptions {
     pub allow_js: Option<bool>,
     pub check_js: Option<bool>,
     pub no_resolve: Option<bool>,
-    pub isolate_modules: Option<bool>,
-    #[serde(rename = "isolatedModules")]
+    pub keyof_strings_only: Option<bool>,
+    pub no_error_truncation: Option<bool>,
     pub isolated_modules: Option<bool>,
     pub verbatim_module_syntax: Option<bool>,
+    pub imports_not_used_as_values: Option<ImportsNotUsedAsValues>,
+    pub preserve_value_imports: Option<bool>,
 }
 
-/// Script target version.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl CompilerOptions {
+    /// Resolve a member of the `strict` family: an explicit setting wins,
+    /// otherwise the flag follows `strict`.
+    pub fn get_strict_option_value(&self, flag: Option<bool>) -> bool {
+        flag.unwrap_or(self.strict.unwrap_or(false))
+    }
+
+    /// Whether imports and exports are emitted as written, less their types.
+    /// The deprecated `preserveValueImports` and `importsNotUsedAsValues`
+    /// settings that keep imports map onto verbatimModuleSyntax, unless it is
+    /// set explicitly.
+    pub fn get_verbatim_module_syntax(&self) -> bool {
+        self.verbatim_module_syntax.unwrap_or_else(|| {
+            self.preserve_value_imports == Some(true)
+                || matches!(self.imports_not_used_as_values, Some(ImportsNotUsedAsValues::Preserve | ImportsNotUsedAsValues::Error))
+        })
+    }
+}
+
+/// Script target version, ordered from oldest to newest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ScriptTarget {
     ES3,
     ES5,
@@ -112,17 +140,38 @@ pub enum ModuleKind {
     Preserve,
 }
 
+/// What emit does with imports only used as types (importsNotUsedAsValues).
+/// Deprecated in favor of verbatimModuleSyntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportsNotUsedAsValues {
+    Remove,
+    Preserve,
+    Error,
+}
+
 /// JSX emit mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum JsxEmit {
     None,
     Preserve,
     React,
     ReactNative,
+    #[serde(rename = "react-jsx")]
     ReactJSX,
+    #[serde(rename = "react-jsxdev")]
     ReactJSXDev,
 }
 
+impl JsxEmit {
+    /// Whether elements compile to calls into `<jsxImportSource>/jsx-runtime`,
+    /// whose `JSX` namespace then types them.
+    pub fn is_automatic_runtime(self) -> bool {
+        matches!(self, JsxEmit::ReactJSX | JsxEmit::ReactJSXDev)
+    }
+}
+
 /// The tsconfig.json file structure.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]